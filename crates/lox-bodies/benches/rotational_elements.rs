@@ -0,0 +1,66 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Compares computing the rotational elements for a long history of epochs with per-epoch calls of
+//! [RotationalElements::rotational_elements] against a single call of
+//! [RotationalElements::rotational_elements_slice].
+//!
+//! Run with `cargo bench -p lox-bodies --bench rotational_elements`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use lox_bodies::{Earth, Jupiter, Moon, Neptune, RotationalElements};
+
+const EPOCHS: usize = 100_000;
+
+fn bench(name: &str, f: impl Fn() -> f64) -> Duration {
+    // Warm-up
+    black_box(f());
+    let start = Instant::now();
+    black_box(f());
+    let elapsed = start.elapsed();
+    println!(
+        "{name:<10} {:>12.3?} total {:>10.1?} per epoch",
+        elapsed,
+        elapsed / EPOCHS as u32
+    );
+    elapsed
+}
+
+fn compare(name: &str, body: impl RotationalElements, epochs: &[f64]) {
+    println!("{name}");
+    let scalar = bench("  scalar", || {
+        epochs
+            .iter()
+            .map(|&t| body.rotational_elements(black_box(t)))
+            .collect::<Vec<_>>()
+            .iter()
+            .map(|elements| elements.2)
+            .sum()
+    });
+    let slice = bench("  slice", || {
+        body.rotational_elements_slice(black_box(epochs))
+            .iter()
+            .map(|elements| elements.2)
+            .sum()
+    });
+    println!(
+        "  speed-up {:>12.2}x",
+        scalar.as_secs_f64() / slice.as_secs_f64()
+    );
+}
+
+fn main() {
+    // One day apart, starting at J2000
+    let epochs: Vec<f64> = (0..EPOCHS).map(|i| i as f64 * 86400.0).collect();
+    compare("Earth", Earth, &epochs);
+    compare("Moon", Moon, &epochs);
+    compare("Jupiter", Jupiter, &epochs);
+    compare("Neptune", Neptune, &epochs);
+}
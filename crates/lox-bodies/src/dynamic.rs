@@ -659,8 +659,14 @@ impl FromStr for DynOrigin {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use float_eq::assert_float_eq;
     use rstest::rstest;
 
+    use crate::{
+        Earth, MeanRadius, Moon, PointMass, Spheroid, Sun, TryMeanRadius, TryPointMass,
+        TryRotationalElements, TrySpheroid,
+    };
+
     #[rstest]
     #[case(DynOrigin::Sun)]
     #[case(DynOrigin::Mercury)]
@@ -873,4 +879,43 @@ mod tests {
     fn test_dyn_origin_unknown_id() {
         assert_eq!(DynOrigin::try_from(666), Err(UnknownOriginId(666)))
     }
+
+    // Canonical values of `gm_de440.tpc`
+    #[rstest]
+    #[case(DynOrigin::Sun, Sun.gravitational_parameter(), 1.3271244004127942e11)]
+    #[case(DynOrigin::Earth, Earth.gravitational_parameter(), 3.9860043550702266e5)]
+    #[case(DynOrigin::Moon, Moon.gravitational_parameter(), 4.90280011845755e3)]
+    fn test_dyn_origin_de440_gravitational_parameter(
+        #[case] origin: DynOrigin,
+        #[case] static_gm: f64,
+        #[case] exp: f64,
+    ) {
+        assert_eq!(origin.try_gravitational_parameter(), Ok(exp));
+        assert_eq!(static_gm, exp);
+    }
+
+    #[test]
+    fn test_dyn_origin_earth_constants() {
+        let origin = DynOrigin::Earth;
+        assert_eq!(origin.try_mean_radius(), Ok(Earth.mean_radius()));
+        assert_eq!(origin.try_equatorial_radius(), Ok(6378.1366));
+        assert_eq!(origin.try_polar_radius(), Ok(6356.7519));
+        assert_eq!(origin.try_flattening(), Ok(Earth.flattening()));
+        assert_float_eq!(Earth.flattening(), 1.0 / 298.257, rel <= 1e-4);
+        assert_float_eq!(
+            origin
+                .try_rotation_rate(0.0)
+                .unwrap()
+                .to_radians_per_second(),
+            7.292115e-5,
+            rel <= 1e-6
+        );
+    }
+
+    #[test]
+    fn test_dyn_origin_undefined_gravitational_parameter() {
+        // DE440 does not provide a gravitational parameter for Elara
+        assert!(DynOrigin::Elara.try_gravitational_parameter().is_err());
+        assert!(DynOrigin::Elara.try_mean_radius().is_ok());
+    }
 }
@@ -0,0 +1,103 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module ellipsoid provides oblate reference ellipsoids for geodetic computations.
+
+use crate::Spheroid;
+
+/// An oblate ellipsoid of revolution defined by its semi-major axis in km and its flattening.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReferenceEllipsoid {
+    semi_major_axis: f64,
+    flattening: f64,
+}
+
+impl ReferenceEllipsoid {
+    /// The World Geodetic System 1984 ellipsoid.
+    ///
+    /// NIMA TR8350.2, 2000, Table 3.1
+    pub const WGS84: Self = Self::from_flattening(6378.137, 1.0 / 298.257223563);
+
+    /// The Geodetic Reference System 1980 ellipsoid.
+    ///
+    /// Moritz, 2000, "Geodetic Reference System 1980", Journal of Geodesy 74
+    pub const GRS80: Self = Self::from_flattening(6378.137, 1.0 / 298.257222101);
+
+    /// Creates a new ellipsoid from its `semi_major_axis` in km and its `flattening`.
+    pub const fn from_flattening(semi_major_axis: f64, flattening: f64) -> Self {
+        Self {
+            semi_major_axis,
+            flattening,
+        }
+    }
+
+    /// Creates a new ellipsoid from the equatorial and polar radii of `body`.
+    pub fn from_spheroid<T: Spheroid>(body: &T) -> Self {
+        Self::from_flattening(body.equatorial_radius(), body.flattening())
+    }
+
+    /// Returns the semi-major axis in km.
+    pub const fn semi_major_axis(&self) -> f64 {
+        self.semi_major_axis
+    }
+
+    /// Returns the semi-minor axis in km.
+    pub const fn semi_minor_axis(&self) -> f64 {
+        self.semi_major_axis * (1.0 - self.flattening)
+    }
+
+    pub const fn flattening(&self) -> f64 {
+        self.flattening
+    }
+
+    /// Returns the square of the first eccentricity, i.e. f (2 - f).
+    pub const fn eccentricity_squared(&self) -> f64 {
+        self.flattening * (2.0 - self.flattening)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    use crate::{Mars, Moon};
+
+    use super::*;
+
+    #[test]
+    fn test_reference_ellipsoid_wgs84() {
+        let wgs84 = ReferenceEllipsoid::WGS84;
+        assert_eq!(wgs84.semi_major_axis(), 6378.137);
+        assert_float_eq!(wgs84.semi_minor_axis(), 6356.752314245179, abs <= 1e-9);
+        assert_float_eq!(wgs84.eccentricity_squared(), 6.69437999014e-3, abs <= 1e-14);
+    }
+
+    #[test]
+    fn test_reference_ellipsoid_grs80() {
+        let grs80 = ReferenceEllipsoid::GRS80;
+        assert_float_eq!(grs80.semi_minor_axis(), 6356.752314140356, abs <= 1e-9);
+        assert_float_eq!(grs80.eccentricity_squared(), 6.69438002290e-3, abs <= 1e-14);
+        // The semi-minor axes of WGS-84 and GRS-80 differ by about 0.1 mm
+        let delta = ReferenceEllipsoid::WGS84.semi_minor_axis() - grs80.semi_minor_axis();
+        assert_float_eq!(delta, 1.048e-7, abs <= 1e-10);
+    }
+
+    #[test]
+    fn test_reference_ellipsoid_from_spheroid() {
+        let mars = ReferenceEllipsoid::from_spheroid(&Mars);
+        assert_eq!(mars.semi_major_axis(), 3396.19);
+        assert_float_eq!(mars.semi_minor_axis(), 3376.2, abs <= 1e-9);
+        assert_eq!(
+            mars,
+            ReferenceEllipsoid::from_flattening(3396.19, Mars.flattening())
+        );
+        let moon = ReferenceEllipsoid::from_spheroid(&Moon);
+        assert_eq!(moon.flattening(), 0.0);
+        assert_eq!(moon.eccentricity_squared(), 0.0);
+    }
+}
@@ -26,6 +26,7 @@ use crate::TryRotationalElements;
 use crate::TrySpheroid;
 use crate::TryTriaxialEllipsoid;
 use crate::UndefinedOriginPropertyError;
+use crate::rotational_elements_slice;
 use std::fmt::Display;
 use std::fmt::Formatter;
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -96,6 +97,9 @@ impl RotationalElements for Sun {
             ROTATION_SUN.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(&RIGHT_ASCENSION_SUN, &DECLINATION_SUN, &ROTATION_SUN, t)
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Mercury;
@@ -212,6 +216,14 @@ impl RotationalElements for Mercury {
             ROTATION_MERCURY.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_MERCURY,
+            &DECLINATION_MERCURY,
+            &ROTATION_MERCURY,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Venus;
@@ -286,6 +298,14 @@ impl RotationalElements for Venus {
             ROTATION_VENUS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_VENUS,
+            &DECLINATION_VENUS,
+            &ROTATION_VENUS,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Earth;
@@ -360,6 +380,14 @@ impl RotationalElements for Earth {
             ROTATION_EARTH.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_EARTH,
+            &DECLINATION_EARTH,
+            &ROTATION_EARTH,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Mars;
@@ -626,6 +654,9 @@ impl RotationalElements for Mars {
             ROTATION_MARS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(&RIGHT_ASCENSION_MARS, &DECLINATION_MARS, &ROTATION_MARS, t)
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Jupiter;
@@ -830,6 +861,14 @@ impl RotationalElements for Jupiter {
             ROTATION_JUPITER.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_JUPITER,
+            &DECLINATION_JUPITER,
+            &ROTATION_JUPITER,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Saturn;
@@ -904,6 +943,14 @@ impl RotationalElements for Saturn {
             ROTATION_SATURN.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_SATURN,
+            &DECLINATION_SATURN,
+            &ROTATION_SATURN,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Uranus;
@@ -978,6 +1025,14 @@ impl RotationalElements for Uranus {
             ROTATION_URANUS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_URANUS,
+            &DECLINATION_URANUS,
+            &ROTATION_URANUS,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Neptune;
@@ -1133,6 +1188,14 @@ impl RotationalElements for Neptune {
             ROTATION_NEPTUNE.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_NEPTUNE,
+            &DECLINATION_NEPTUNE,
+            &ROTATION_NEPTUNE,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Pluto;
@@ -1207,6 +1270,14 @@ impl RotationalElements for Pluto {
             ROTATION_PLUTO.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_PLUTO,
+            &DECLINATION_PLUTO,
+            &ROTATION_PLUTO,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct SolarSystemBarycenter;
@@ -1607,6 +1678,9 @@ impl RotationalElements for Moon {
             ROTATION_MOON.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(&RIGHT_ASCENSION_MOON, &DECLINATION_MOON, &ROTATION_MOON, t)
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Phobos;
@@ -1728,6 +1802,14 @@ impl RotationalElements for Phobos {
             ROTATION_PHOBOS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_PHOBOS,
+            &DECLINATION_PHOBOS,
+            &ROTATION_PHOBOS,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Deimos;
@@ -1900,6 +1982,14 @@ impl RotationalElements for Deimos {
             ROTATION_DEIMOS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_DEIMOS,
+            &DECLINATION_DEIMOS,
+            &ROTATION_DEIMOS,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Io;
@@ -2018,6 +2108,9 @@ impl RotationalElements for Io {
             ROTATION_IO.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(&RIGHT_ASCENSION_IO, &DECLINATION_IO, &ROTATION_IO, t)
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Europa;
@@ -2163,6 +2256,14 @@ impl RotationalElements for Europa {
             ROTATION_EUROPA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_EUROPA,
+            &DECLINATION_EUROPA,
+            &ROTATION_EUROPA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Ganymede;
@@ -2300,6 +2401,14 @@ impl RotationalElements for Ganymede {
             ROTATION_GANYMEDE.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_GANYMEDE,
+            &DECLINATION_GANYMEDE,
+            &ROTATION_GANYMEDE,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Callisto;
@@ -2455,6 +2564,14 @@ impl RotationalElements for Callisto {
             ROTATION_CALLISTO.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_CALLISTO,
+            &DECLINATION_CALLISTO,
+            &ROTATION_CALLISTO,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Amalthea;
@@ -2627,6 +2744,14 @@ impl RotationalElements for Amalthea {
             ROTATION_AMALTHEA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_AMALTHEA,
+            &DECLINATION_AMALTHEA,
+            &ROTATION_AMALTHEA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Himalia;
@@ -3012,6 +3137,14 @@ impl RotationalElements for Thebe {
             ROTATION_THEBE.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_THEBE,
+            &DECLINATION_THEBE,
+            &ROTATION_THEBE,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Adrastea;
@@ -3085,6 +3218,14 @@ impl RotationalElements for Adrastea {
             ROTATION_ADRASTEA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_ADRASTEA,
+            &DECLINATION_ADRASTEA,
+            &ROTATION_ADRASTEA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Metis;
@@ -3158,6 +3299,14 @@ impl RotationalElements for Metis {
             ROTATION_METIS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_METIS,
+            &DECLINATION_METIS,
+            &ROTATION_METIS,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Callirrhoe;
@@ -3837,6 +3986,14 @@ impl RotationalElements for Mimas {
             ROTATION_MIMAS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_MIMAS,
+            &DECLINATION_MIMAS,
+            &ROTATION_MIMAS,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Enceladus;
@@ -3910,6 +4067,14 @@ impl RotationalElements for Enceladus {
             ROTATION_ENCELADUS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_ENCELADUS,
+            &DECLINATION_ENCELADUS,
+            &ROTATION_ENCELADUS,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Tethys;
@@ -4064,6 +4229,14 @@ impl RotationalElements for Tethys {
             ROTATION_TETHYS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_TETHYS,
+            &DECLINATION_TETHYS,
+            &ROTATION_TETHYS,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Dione;
@@ -4137,6 +4310,14 @@ impl RotationalElements for Dione {
             ROTATION_DIONE.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_DIONE,
+            &DECLINATION_DIONE,
+            &ROTATION_DIONE,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Rhea;
@@ -4291,6 +4472,9 @@ impl RotationalElements for Rhea {
             ROTATION_RHEA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(&RIGHT_ASCENSION_RHEA, &DECLINATION_RHEA, &ROTATION_RHEA, t)
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Titan;
@@ -4418,6 +4602,14 @@ impl RotationalElements for Titan {
             ROTATION_TITAN.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_TITAN,
+            &DECLINATION_TITAN,
+            &ROTATION_TITAN,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Hyperion;
@@ -4522,6 +4714,14 @@ impl RotationalElements for Iapetus {
             ROTATION_IAPETUS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_IAPETUS,
+            &DECLINATION_IAPETUS,
+            &ROTATION_IAPETUS,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Phoebe;
@@ -4595,6 +4795,14 @@ impl RotationalElements for Phoebe {
             ROTATION_PHOEBE.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_PHOEBE,
+            &DECLINATION_PHOEBE,
+            &ROTATION_PHOEBE,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Janus;
@@ -4749,6 +4957,14 @@ impl RotationalElements for Janus {
             ROTATION_JANUS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_JANUS,
+            &DECLINATION_JANUS,
+            &ROTATION_JANUS,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Epimetheus;
@@ -4903,6 +5119,14 @@ impl RotationalElements for Epimetheus {
             ROTATION_EPIMETHEUS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_EPIMETHEUS,
+            &DECLINATION_EPIMETHEUS,
+            &ROTATION_EPIMETHEUS,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Helene;
@@ -4976,6 +5200,14 @@ impl RotationalElements for Helene {
             ROTATION_HELENE.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_HELENE,
+            &DECLINATION_HELENE,
+            &ROTATION_HELENE,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Telesto;
@@ -5044,6 +5276,14 @@ impl RotationalElements for Telesto {
             ROTATION_TELESTO.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_TELESTO,
+            &DECLINATION_TELESTO,
+            &ROTATION_TELESTO,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Calypso;
@@ -5112,6 +5352,14 @@ impl RotationalElements for Calypso {
             ROTATION_CALYPSO.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_CALYPSO,
+            &DECLINATION_CALYPSO,
+            &ROTATION_CALYPSO,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Atlas;
@@ -5185,6 +5433,14 @@ impl RotationalElements for Atlas {
             ROTATION_ATLAS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_ATLAS,
+            &DECLINATION_ATLAS,
+            &ROTATION_ATLAS,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Prometheus;
@@ -5258,6 +5514,14 @@ impl RotationalElements for Prometheus {
             ROTATION_PROMETHEUS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_PROMETHEUS,
+            &DECLINATION_PROMETHEUS,
+            &ROTATION_PROMETHEUS,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Pandora;
@@ -5331,6 +5595,14 @@ impl RotationalElements for Pandora {
             ROTATION_PANDORA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_PANDORA,
+            &DECLINATION_PANDORA,
+            &ROTATION_PANDORA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Pan;
@@ -5399,6 +5671,9 @@ impl RotationalElements for Pan {
             ROTATION_PAN.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(&RIGHT_ASCENSION_PAN, &DECLINATION_PAN, &ROTATION_PAN, t)
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Ymir;
@@ -6184,6 +6459,14 @@ impl RotationalElements for Ariel {
             ROTATION_ARIEL.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_ARIEL,
+            &DECLINATION_ARIEL,
+            &ROTATION_ARIEL,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Umbriel;
@@ -6393,6 +6676,14 @@ impl RotationalElements for Umbriel {
             ROTATION_UMBRIEL.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_UMBRIEL,
+            &DECLINATION_UMBRIEL,
+            &ROTATION_UMBRIEL,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Titania;
@@ -6611,6 +6902,14 @@ impl RotationalElements for Titania {
             ROTATION_TITANIA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_TITANIA,
+            &DECLINATION_TITANIA,
+            &ROTATION_TITANIA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Oberon;
@@ -6838,6 +7137,14 @@ impl RotationalElements for Oberon {
             ROTATION_OBERON.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_OBERON,
+            &DECLINATION_OBERON,
+            &ROTATION_OBERON,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Miranda;
@@ -7082,6 +7389,14 @@ impl RotationalElements for Miranda {
             ROTATION_MIRANDA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_MIRANDA,
+            &DECLINATION_MIRANDA,
+            &ROTATION_MIRANDA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Cordelia;
@@ -7322,6 +7637,14 @@ impl RotationalElements for Cordelia {
             ROTATION_CORDELIA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_CORDELIA,
+            &DECLINATION_CORDELIA,
+            &ROTATION_CORDELIA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Ophelia;
@@ -7562,6 +7885,14 @@ impl RotationalElements for Ophelia {
             ROTATION_OPHELIA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_OPHELIA,
+            &DECLINATION_OPHELIA,
+            &ROTATION_OPHELIA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Bianca;
@@ -7802,6 +8133,14 @@ impl RotationalElements for Bianca {
             ROTATION_BIANCA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_BIANCA,
+            &DECLINATION_BIANCA,
+            &ROTATION_BIANCA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Cressida;
@@ -8042,6 +8381,14 @@ impl RotationalElements for Cressida {
             ROTATION_CRESSIDA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_CRESSIDA,
+            &DECLINATION_CRESSIDA,
+            &ROTATION_CRESSIDA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Desdemona;
@@ -8282,6 +8629,14 @@ impl RotationalElements for Desdemona {
             ROTATION_DESDEMONA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_DESDEMONA,
+            &DECLINATION_DESDEMONA,
+            &ROTATION_DESDEMONA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Juliet;
@@ -8522,6 +8877,14 @@ impl RotationalElements for Juliet {
             ROTATION_JULIET.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_JULIET,
+            &DECLINATION_JULIET,
+            &ROTATION_JULIET,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Portia;
@@ -8762,6 +9125,14 @@ impl RotationalElements for Portia {
             ROTATION_PORTIA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_PORTIA,
+            &DECLINATION_PORTIA,
+            &ROTATION_PORTIA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Rosalind;
@@ -9002,6 +9373,14 @@ impl RotationalElements for Rosalind {
             ROTATION_ROSALIND.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_ROSALIND,
+            &DECLINATION_ROSALIND,
+            &ROTATION_ROSALIND,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Belinda;
@@ -9242,6 +9621,14 @@ impl RotationalElements for Belinda {
             ROTATION_BELINDA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_BELINDA,
+            &DECLINATION_BELINDA,
+            &ROTATION_BELINDA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Puck;
@@ -9482,6 +9869,9 @@ impl RotationalElements for Puck {
             ROTATION_PUCK.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(&RIGHT_ASCENSION_PUCK, &DECLINATION_PUCK, &ROTATION_PUCK, t)
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Caliban;
@@ -9898,6 +10288,14 @@ impl RotationalElements for Triton {
             ROTATION_TRITON.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_TRITON,
+            &DECLINATION_TRITON,
+            &ROTATION_TRITON,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Nereid;
@@ -10160,6 +10558,14 @@ impl RotationalElements for Naiad {
             ROTATION_NAIAD.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_NAIAD,
+            &DECLINATION_NAIAD,
+            &ROTATION_NAIAD,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Thalassa;
@@ -10396,6 +10802,14 @@ impl RotationalElements for Thalassa {
             ROTATION_THALASSA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_THALASSA,
+            &DECLINATION_THALASSA,
+            &ROTATION_THALASSA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Despina;
@@ -10632,6 +11046,14 @@ impl RotationalElements for Despina {
             ROTATION_DESPINA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_DESPINA,
+            &DECLINATION_DESPINA,
+            &ROTATION_DESPINA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Galatea;
@@ -10868,6 +11290,14 @@ impl RotationalElements for Galatea {
             ROTATION_GALATEA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_GALATEA,
+            &DECLINATION_GALATEA,
+            &ROTATION_GALATEA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Larissa;
@@ -11104,6 +11534,14 @@ impl RotationalElements for Larissa {
             ROTATION_LARISSA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_LARISSA,
+            &DECLINATION_LARISSA,
+            &ROTATION_LARISSA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Proteus;
@@ -11339,6 +11777,14 @@ impl RotationalElements for Proteus {
             ROTATION_PROTEUS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_PROTEUS,
+            &DECLINATION_PROTEUS,
+            &ROTATION_PROTEUS,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Halimede;
@@ -11488,6 +11934,14 @@ impl RotationalElements for Charon {
             ROTATION_CHARON.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_CHARON,
+            &DECLINATION_CHARON,
+            &ROTATION_CHARON,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Nix;
@@ -11636,6 +12090,14 @@ impl RotationalElements for Gaspra {
             ROTATION_GASPRA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_GASPRA,
+            &DECLINATION_GASPRA,
+            &ROTATION_GASPRA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Ida;
@@ -11704,6 +12166,9 @@ impl RotationalElements for Ida {
             ROTATION_IDA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(&RIGHT_ASCENSION_IDA, &DECLINATION_IDA, &ROTATION_IDA, t)
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Dactyl;
@@ -11793,6 +12258,14 @@ impl RotationalElements for Ceres {
             ROTATION_CERES.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_CERES,
+            &DECLINATION_CERES,
+            &ROTATION_CERES,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Pallas;
@@ -11856,6 +12329,14 @@ impl RotationalElements for Pallas {
             ROTATION_PALLAS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_PALLAS,
+            &DECLINATION_PALLAS,
+            &ROTATION_PALLAS,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Vesta;
@@ -11924,6 +12405,14 @@ impl RotationalElements for Vesta {
             ROTATION_VESTA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_VESTA,
+            &DECLINATION_VESTA,
+            &ROTATION_VESTA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Psyche;
@@ -12022,6 +12511,14 @@ impl RotationalElements for Lutetia {
             ROTATION_LUTETIA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_LUTETIA,
+            &DECLINATION_LUTETIA,
+            &ROTATION_LUTETIA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Kleopatra;
@@ -12110,6 +12607,9 @@ impl RotationalElements for Eros {
             ROTATION_EROS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(&RIGHT_ASCENSION_EROS, &DECLINATION_EROS, &ROTATION_EROS, t)
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Davida;
@@ -12183,6 +12683,14 @@ impl RotationalElements for Davida {
             ROTATION_DAVIDA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_DAVIDA,
+            &DECLINATION_DAVIDA,
+            &ROTATION_DAVIDA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Mathilde;
@@ -12276,6 +12784,14 @@ impl RotationalElements for Steins {
             ROTATION_STEINS.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_STEINS,
+            &DECLINATION_STEINS,
+            &ROTATION_STEINS,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Braille;
@@ -12389,6 +12905,14 @@ impl RotationalElements for Itokawa {
             ROTATION_ITOKAWA.angle_dot(t),
         )
     }
+    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+        rotational_elements_slice(
+            &RIGHT_ASCENSION_ITOKAWA,
+            &DECLINATION_ITOKAWA,
+            &ROTATION_ITOKAWA,
+            t,
+        )
+    }
 }
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Bennu;
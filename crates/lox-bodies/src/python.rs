@@ -105,26 +105,26 @@ impl PyOrigin {
     }
 
     pub fn right_ascension(&self, et: Seconds) -> PyResult<f64> {
-        Ok(self.0.try_right_ascension(et)?)
+        Ok(self.0.try_right_ascension(et)?.to_radians())
     }
 
     pub fn right_ascension_rate(&self, et: Seconds) -> PyResult<f64> {
-        Ok(self.0.try_right_ascension_rate(et)?)
+        Ok(self.0.try_right_ascension_rate(et)?.to_radians_per_second())
     }
 
     pub fn declination(&self, et: Seconds) -> PyResult<f64> {
-        Ok(self.0.try_declination(et)?)
+        Ok(self.0.try_declination(et)?.to_radians())
     }
 
     pub fn declination_rate(&self, et: Seconds) -> PyResult<f64> {
-        Ok(self.0.try_declination_rate(et)?)
+        Ok(self.0.try_declination_rate(et)?.to_radians_per_second())
     }
 
     pub fn rotation_angle(&self, et: Seconds) -> PyResult<f64> {
-        Ok(self.0.try_rotation_angle(et)?)
+        Ok(self.0.try_rotation_angle(et)?.to_radians())
     }
 
     pub fn rotation_rate(&self, et: Seconds) -> PyResult<f64> {
-        Ok(self.0.try_rotation_rate(et)?)
+        Ok(self.0.try_rotation_rate(et)?.to_radians_per_second())
     }
 }
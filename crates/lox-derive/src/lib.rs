@@ -55,24 +55,61 @@ fn generate_call_to_deserializer_for_kvn_type(
 
     match type_name {
         "String" | "f64" | "i32" | "u64" | "NonNegativeDouble" | "NegativeDouble"
-        | "PositiveDouble" => {
+        | "PositiveDouble" | "Angle" => {
             let parser = match type_name {
                 "String" => quote! {
-                    crate::ndm::kvn::parser::parse_kvn_string_line(
+                    match crate::ndm::kvn::parser::parse_kvn_string_line(
                         next_line
-                    ).map_err(|x| crate::ndm::kvn::KvnDeserializerErr::from(x))?
+                    ).map_err(|x| crate::ndm::kvn::KvnDeserializerErr::from(x)) {
+                        Ok(kvn_value) => kvn_value,
+                        Err(e) => kvn_context.recover(e)?,
+                    }
                 },
                 "f64" | "NonNegativeDouble" | "NegativeDouble" | "PositiveDouble" => quote! {
-                    crate::ndm::kvn::parser::parse_kvn_numeric_line(
+                    match crate::ndm::kvn::parser::parse_kvn_numeric_line(
                         next_line,
                         true, //@TODO
-                    ).map_err(|x| crate::ndm::kvn::KvnDeserializerErr::from(x))?
+                        kvn_context.mode(),
+                    ).map_err(|x| crate::ndm::kvn::KvnDeserializerErr::from(x)) {
+                        Ok(kvn_value) => kvn_value,
+                        Err(e) => kvn_context.recover(e)?,
+                    }
+                },
+                // Angles without a unit are in degrees, see `to_angle`
+                "Angle" => quote! {
+                    {
+                        let kvn_value = crate::ndm::kvn::parser::parse_kvn_numeric_line(
+                            next_line,
+                            true,
+                            kvn_context.mode(),
+                        ).map_err(|x| crate::ndm::kvn::KvnDeserializerErr::from(x));
+                        let kvn_value = match kvn_value {
+                            Ok(kvn_value) => kvn_value,
+                            Err(e) => kvn_context.recover(e)?,
+                        };
+
+                        crate::ndm::kvn::KvnValue {
+                            value: match kvn_value.to_angle() {
+                                Ok(angle) => angle,
+                                Err(_) => kvn_context.recover(
+                                    crate::ndm::kvn::KvnDeserializerErr::<String>::InvalidUnit {
+                                        input: next_line.to_string(),
+                                    },
+                                )?,
+                            },
+                            unit: kvn_value.unit,
+                        }
+                    }
                 },
                 "i32" | "u64" => quote! {
-                    crate::ndm::kvn::parser::parse_kvn_integer_line(
+                    match crate::ndm::kvn::parser::parse_kvn_integer_line(
                         next_line,
                         true, //@TODO
-                    ).map_err(|x| crate::ndm::kvn::KvnDeserializerErr::from(x))?
+                        kvn_context.mode(),
+                    ).map_err(|x| crate::ndm::kvn::KvnDeserializerErr::from(x)) {
+                        Ok(kvn_value) => kvn_value,
+                        Err(e) => kvn_context.recover(e)?,
+                    }
                 },
                 // Assumes the match list here exhaustively matches the one from above
                 _ => unreachable!(),
@@ -122,14 +159,16 @@ fn generate_call_to_deserializer_for_kvn_type(
                 Some(next_line) => {
                     let result = crate::ndm::kvn::parser::parse_kvn_state_vector(
                         next_line,
-                    ).map_err(|x| match crate::ndm::kvn::KvnDeserializerErr::from(x) {
-                        crate::ndm::kvn::KvnDeserializerErr::InvalidStateVectorFormat { .. } => crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedKeyword {
+                    ).map_err(|x| match x {
+                        crate::ndm::kvn::parser::KvnStateVectorParserErr::InvalidFormat { .. } => crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedKeyword {
                             // This is empty because we just want to tell the
                             // vector iterator to stop the iteration.
                             found: "".to_string(),
                             expected: "".to_string(),
                         },
-                        e => e,
+                        // The line starts with an epoch, so it is a malformed
+                        // ephemeris data line rather than the end of the list.
+                        e => crate::ndm::kvn::KvnDeserializerErr::from(e),
                     }).map(|x| x.into());
 
                     if result.is_ok() {
@@ -145,7 +184,7 @@ fn generate_call_to_deserializer_for_kvn_type(
                 let has_next_line = crate::ndm::kvn::parser::get_next_nonempty_line(lines).is_some();
 
                 let result = if has_next_line {
-                    #type_name_new::deserialize(lines)
+                    #type_name_new::deserialize_with_context(lines, kvn_context)
                 } else {
                     Err(crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedEndOfInput {
                           keyword: #expected_kvn_name.to_string()
@@ -203,7 +242,7 @@ fn generate_call_to_deserializer_for_option_type(
     )?;
 
     let condition_shortcut = match type_name.as_str() {
-        "String" | "f64" | "i32" | "u64" => quote! {},
+        "String" | "f64" | "i32" | "u64" | "Angle" => quote! {},
         _ => quote! { ! #type_ident::should_check_key_match() || },
     };
 
@@ -380,7 +419,10 @@ fn handle_version_field(
     )?;
 
     Ok((
-        quote! { let #field_name = #parser?; },
+        quote! {
+            let #field_name = #parser?;
+            has_parsed_fields = true;
+        },
         quote! { #field_name, },
     ))
 }
@@ -392,9 +434,40 @@ fn deserializer_for_struct_with_named_fields(
     struct_level_prefix_and_postfix_keyword: Option<(String, String)>,
 ) -> proc_macro2::TokenStream {
     if &type_name.to_string() == "UserDefinedType" {
-        //@TODO
         return quote! {
-            Ok(Default::default())
+            let user_defined = crate::ndm::kvn::parser::parse_kvn_user_defined_lines(lines)?;
+
+            if user_defined.is_empty() {
+                // This tells the option deserializer that the section is absent
+                return Err(crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedKeyword {
+                    found: crate::ndm::kvn::parser::get_next_nonempty_line(lines)
+                        .unwrap_or_default()
+                        .to_string(),
+                    expected: crate::ndm::kvn::parser::USER_DEFINED_PREFIX.to_string(),
+                });
+            }
+
+            let mut user_defined: Vec<_> = user_defined.into_iter().collect();
+            user_defined.sort_by(|a, b| a.0.cmp(&b.0));
+
+            // Repeated parameters are kept in the order in which they appear
+            let user_defined_list: Vec<UserDefinedParameterType> = user_defined
+                .into_iter()
+                .flat_map(|(parameter, values)| {
+                    values.into_iter().map(move |value| UserDefinedParameterType {
+                        base: match value.unit {
+                            Some(unit) => format!("{} [{}]", value.value, unit),
+                            None => value.value,
+                        },
+                        parameter: parameter.clone(),
+                    })
+                })
+                .collect();
+
+            Ok(#type_name {
+                comment_list: Vec::new(),
+                user_defined_list,
+            })
         };
     }
 
@@ -545,7 +618,7 @@ fn deserializer_for_struct_with_named_fields(
                 }
 
                 let parser = match field_main_type.as_str() {
-                    "String" | "f64" | "i32" => {
+                    "String" | "f64" | "i32" | "Angle" => {
                         let deserializer_for_kvn_type = generate_call_to_deserializer_for_kvn_type(
                             &field_main_type,
                             field_type_new,
@@ -565,6 +638,9 @@ fn deserializer_for_struct_with_named_fields(
                         )?
                     }
                     "Vec" => generate_call_to_deserializer_for_vec_type(&expected_kvn_name, field)?,
+                    "HashMap" => quote! {
+                        crate::ndm::kvn::parser::parse_kvn_user_defined_lines(lines)?
+                    },
                     _ => {
 
                         let condition_shortcut = match field_main_type.as_str() {
@@ -584,7 +660,7 @@ fn deserializer_for_struct_with_named_fields(
                                     )?;
 
                                     if #condition_shortcut line_matches {
-                                        #field_type_new::deserialize(lines)?
+                                        #field_type_new::deserialize_with_context(lines, kvn_context)?
                                     } else {
                                         Err(crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedKeyword {
                                             found: next_line.to_string(),
@@ -599,10 +675,58 @@ fn deserializer_for_struct_with_named_fields(
 
                 let field_prefix_and_postfix_keyword_checks = get_prefix_and_postfix_keyword(&field.attrs);
 
-                let wrapped_parser = add_prefix_and_postfix_keyword_checks(field_prefix_and_postfix_keyword_checks, parser, true);
+                let wrapped_parser = add_prefix_and_postfix_keyword_checks(
+                    field_prefix_and_postfix_keyword_checks,
+                    parser,
+                    true,
+                    field_main_type == "Option",
+                );
+
+                let field_type = &field.ty;
+
+                // 7.5.1 All mandatory keywords shall be present. Once the
+                // first keyword of a section has been found, a missing
+                // mandatory keyword is an error and not an omitted section.
+                // `COMMENT` lines do not count because they may belong to
+                // the next section.
+                let field_deserializer = match field_main_type.as_str() {
+                    "Vec" if expected_kvn_name.trim_end_matches("_LIST") == "COMMENT" => quote! {
+                        let #field_name = #wrapped_parser;
+                    },
+                    "Option" => quote! {
+                        let #field_name = #wrapped_parser;
+                        if #field_name.is_some() {
+                            has_parsed_fields = true;
+                        }
+                    },
+                    "Vec" | "HashMap" => quote! {
+                        let #field_name = #wrapped_parser;
+                        if !#field_name.is_empty() {
+                            has_parsed_fields = true;
+                        }
+                    },
+                    _ => quote! {
+                        // The closure catches the errors of the `?` operators in the parser
+                        #[allow(clippy::needless_question_mark)]
+                        let #field_name = match (|| -> Result<#field_type, crate::ndm::kvn::KvnDeserializerErr<String>> {
+                            Ok(#wrapped_parser)
+                        })() {
+                            Ok(value) => {
+                                has_parsed_fields = true;
+                                value
+                            }
+                            Err(crate::ndm::kvn::KvnDeserializerErr::UnexpectedKeyword { expected, .. })
+                                if has_parsed_fields && !expected.is_empty() =>
+                            {
+                                Err(crate::ndm::kvn::KvnDeserializerErr::<String>::KeywordNotFound { expected })?
+                            }
+                            Err(e) => Err(e)?,
+                        };
+                    },
+                };
 
                 Ok((
-                    quote! { let #field_name = #wrapped_parser; },
+                    field_deserializer,
                     quote! { #field_name, }
                 ))
              })
@@ -665,6 +789,8 @@ fn deserializer_for_struct_with_named_fields(
             field_deserializers.into_iter().unzip();
 
         let parser_to_wrap = quote! {
+            let mut has_parsed_fields = false;
+
             #(#field_deserializers)*
 
             Ok(#type_name {
@@ -676,6 +802,7 @@ fn deserializer_for_struct_with_named_fields(
             struct_level_prefix_and_postfix_keyword,
             parser_to_wrap,
             false,
+            false,
         );
 
         quote! {
@@ -688,10 +815,25 @@ fn add_prefix_and_postfix_keyword_checks(
     prefix_and_postfix_keyword: Option<(String, String)>,
     parser_to_wrap: proc_macro2::TokenStream,
     is_field: bool,
+    is_optional: bool,
 ) -> proc_macro2::TokenStream {
     match prefix_and_postfix_keyword {
         None => parser_to_wrap,
         Some((prefix_keyword, postfix_keyword)) => {
+            // Like any other optional field, an optional block is absent if the input ends before
+            // it. All other blocks must be present.
+            let end_of_input_handler = if is_optional {
+                quote! { None }
+            } else {
+                quote! {
+                    Err(
+                        crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedEndOfInput {
+                            keyword: #prefix_keyword.to_string(),
+                        },
+                    )?
+                }
+            };
+
             let mismatch_handler = if is_field {
                 quote! { Default::default() }
             } else {
@@ -708,11 +850,7 @@ fn add_prefix_and_postfix_keyword_checks(
             quote! {
 
                 match crate::ndm::kvn::parser::get_next_nonempty_line(lines) {
-                    None => Err(
-                        crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedEndOfInput {
-                            keyword: #prefix_keyword.to_string(),
-                        },
-                    )?,
+                    None => #end_of_input_handler,
 
                     Some(next_line) => {
                         let line_matches = crate::ndm::kvn::parser::kvn_line_matches_key(
@@ -738,12 +876,13 @@ fn add_prefix_and_postfix_keyword_checks(
                                         next_line,
                                     )?;
 
+                                    // Once the block has been opened, a missing terminator is
+                                    // an error and must not end a list of blocks silently.
                                     if line_matches {
                                         lines.next().unwrap();
                                     } else {
                                         Err(
-                                            crate::ndm::kvn::KvnDeserializerErr::<String>::UnexpectedKeyword {
-                                                found: next_line.to_string(),
+                                            crate::ndm::kvn::KvnDeserializerErr::<String>::KeywordNotFound {
                                                 expected: #postfix_keyword.to_string(),
                                             },
                                         )?
@@ -772,12 +911,16 @@ fn deserializers_for_struct_with_unnamed_fields(
 
     if &type_name.to_string() == "EpochType" {
         return quote! {
-            Ok(#type_name (
-                crate::ndm::kvn::parser::parse_kvn_datetime_line(
-                    lines.next().unwrap()
-                ).map_err(|x| crate::ndm::kvn::KvnDeserializerErr::from(x))
-                .map(|x| x)?.full_value
-            ))
+            let next_line = lines.next().unwrap();
+            let kvn_value = match crate::ndm::kvn::parser::parse_kvn_datetime_line(
+                next_line,
+                kvn_context.mode(),
+            ).map_err(|x| crate::ndm::kvn::KvnDeserializerErr::from(x)) {
+                Ok(kvn_value) => kvn_value,
+                Err(e) => kvn_context.recover(e)?,
+            };
+
+            Ok(#type_name (kvn_value.full_value))
         };
     }
 
@@ -853,8 +996,10 @@ pub fn derive_kvn_deserialize(item: proc_macro::TokenStream) -> proc_macro::Toke
         impl #impl_generics crate::ndm::kvn::KvnDeserializer for #type_name #type_generics
         #where_clause
         {
-            fn deserialize<'a>(lines: &mut ::std::iter::Peekable<impl Iterator<Item = &'a str>>)
-            -> Result<#type_name, crate::ndm::kvn::KvnDeserializerErr<String>> {
+            fn deserialize_with_context<'a>(
+                lines: &mut ::std::iter::Peekable<impl Iterator<Item = &'a str>>,
+                kvn_context: &crate::ndm::kvn::KvnContext,
+            ) -> Result<#type_name, crate::ndm::kvn::KvnDeserializerErr<String>> {
                 #struct_deserializer
             }
 
@@ -0,0 +1,87 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Compares the cost of the nutation [Model]s, in particular of the full IAU 2000A series against
+//! the truncated IAU 2000B series, over two centuries of epochs, and the cost of evaluating the
+//! series from precomputed [NutationArguments] against computing the arguments for every call.
+//!
+//! Run with `cargo bench -p lox-earth --bench nutation`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use lox_earth::nutation::{Model, NutationArguments, nutation, nutation_from_arguments};
+use lox_time::Time;
+use lox_time::deltas::TimeDelta;
+use lox_time::time_scales::Tdb;
+
+const EPOCHS: usize = 10_000;
+
+fn bench(name: &str, f: impl Fn() -> f64) -> Duration {
+    // Warm-up
+    black_box(f());
+    let start = Instant::now();
+    black_box(f());
+    let elapsed = start.elapsed();
+    println!(
+        "{name:<10} {:>12.3?} total {:>10.1?} per epoch",
+        elapsed,
+        elapsed / EPOCHS as u32
+    );
+    elapsed
+}
+
+fn main() {
+    // Evenly spaced epochs between 1900 and 2100
+    let century = 36525.0 * 86400.0;
+    let epochs: Vec<Time<Tdb>> = (0..EPOCHS)
+        .map(|i| {
+            Time::j2000(Tdb)
+                + TimeDelta::from_decimal_seconds(
+                    -century + 2.0 * century * i as f64 / EPOCHS as f64,
+                )
+        })
+        .collect();
+
+    let models = [
+        ("IAU1980", Model::IAU1980),
+        ("IAU2000A", Model::IAU2000A),
+        ("IAU2000B", Model::IAU2000B),
+        ("IAU2006A", Model::IAU2006A),
+    ];
+    let durations: Vec<Duration> = models
+        .iter()
+        .map(|&(name, model)| {
+            let direct = bench(name, || {
+                epochs
+                    .iter()
+                    .map(|&time| nutation(model, time).longitude)
+                    .sum()
+            });
+            let arguments: Vec<NutationArguments> = epochs
+                .iter()
+                .map(|&time| NutationArguments::new(model, time))
+                .collect();
+            let precomputed = bench("  reused", || {
+                arguments
+                    .iter()
+                    .map(|args| nutation_from_arguments(args).longitude)
+                    .sum()
+            });
+            println!(
+                "  speed-up {:>12.2}x",
+                direct.as_secs_f64() / precomputed.as_secs_f64()
+            );
+            direct
+        })
+        .collect();
+    println!(
+        "IAU2000A / IAU2000B {:>6.1}x",
+        durations[1].as_secs_f64() / durations[2].as_secs_f64()
+    );
+}
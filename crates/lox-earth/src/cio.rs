@@ -9,3 +9,40 @@
 //! Module cio exposes functions for calculating the Celestial Intermediate Origin (CIO) locator, s.
 
 pub mod s06;
+
+use glam::DVec2;
+
+use lox_math::types::units::{JulianCenturies, Radians};
+
+/// Calculates the Celestial Intermediate Origin (CIO) locator s in radians, given the (X, Y)
+/// coordinates of the Celestial Intermediate Pole (CIP) in radians, e.g. from
+/// [cip_xy](crate::cip::cip_xy).
+///
+/// The series for s + XY/2 is consistent with IAU 2006 precession and IAU 2000A nutation, see
+/// IERS Conventions (2010), Section 5.5.6, as implemented by [s06::s].
+pub fn cio_locator_s(centuries_since_j2000_tt: JulianCenturies, x: Radians, y: Radians) -> Radians {
+    s06::s(centuries_since_j2000_tt, DVec2::new(x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    use crate::cip::cip_xy;
+
+    use super::*;
+
+    // One microarcsecond in radians
+    const MICROARCSECOND: Radians = 4.84813681109536e-12;
+
+    #[test]
+    fn test_cio_locator_s() {
+        // MJD 53736.0 TT, reference values from the SOFA/ERFA test suite
+        let t = (53736.0 - 51544.5) / 36525.0;
+        let s = cio_locator_s(t, 5.791308486706011e-4, 4.020579816732961e-5);
+        assert_float_eq!(s, -1.220032213076463e-8, abs <= 1e-3 * MICROARCSECOND);
+
+        let (x, y) = cip_xy(t);
+        assert_float_eq!(cio_locator_s(t, x, y), s, abs <= 1e-3 * MICROARCSECOND);
+    }
+}
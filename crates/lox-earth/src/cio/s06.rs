@@ -21,7 +21,7 @@ use lox_math::types::units::{JulianCenturies, Radians};
 mod terms;
 
 /// l, l', F, D, Ω, LVe, LE and pA.
-type FundamentalArgs = [Radians; 8];
+pub(crate) type FundamentalArgs = [Radians; 8];
 
 /// Computes the Celestial Intermediate Origin (CIO) locator s, in radians, given the (X, Y)
 /// coordinates of the Celestial Intermediate Pole (CIP).
@@ -33,7 +33,7 @@ pub fn s(centuries_since_j2000_tdb: JulianCenturies, xy: DVec2) -> Radians {
     radians - xy[0] * xy[1] / 2.0
 }
 
-fn fundamental_args(centuries_since_j2000_tdb: JulianCenturies) -> FundamentalArgs {
+pub(crate) fn fundamental_args(centuries_since_j2000_tdb: JulianCenturies) -> FundamentalArgs {
     // The output of the CIO calculation is dependent on the ordering of these arguments. DO NOT
     // EDIT.
     [
@@ -10,3 +10,33 @@
 //! Celestial Intermediate Pole (CIP).
 
 pub mod xy06;
+
+use lox_math::types::units::{JulianCenturies, Radians};
+
+/// Calculates the (X, Y) coordinates of the Celestial Intermediate Pole (CIP) in radians.
+///
+/// The coordinates are evaluated from the IAU 2006 precession and IAU 2000A nutation series of
+/// IERS Conventions (2010), Section 5.5.4, as implemented by [xy06::xy].
+pub fn cip_xy(centuries_since_j2000_tt: JulianCenturies) -> (Radians, Radians) {
+    let xy = xy06::xy(centuries_since_j2000_tt);
+    (xy.x, xy.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    use super::*;
+
+    // One microarcsecond in radians
+    const MICROARCSECOND: Radians = 4.84813681109536e-12;
+
+    #[test]
+    fn test_cip_xy() {
+        // MJD 53736.0 TT, reference values from the SOFA/ERFA test suite
+        let t = (53736.0 - 51544.5) / 36525.0;
+        let (x, y) = cip_xy(t);
+        assert_float_eq!(x, 5.791308486706011e-4, abs <= 1e-3 * MICROARCSECOND);
+        assert_float_eq!(y, 4.020579816732958e-5, abs <= 1e-3 * MICROARCSECOND);
+    }
+}
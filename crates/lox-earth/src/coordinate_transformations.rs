@@ -183,6 +183,31 @@ mod tests {
         assert_mat3_eq(&expected, &actual)
     }
 
+    #[test]
+    fn test_polar_motion_matrix_erfa() {
+        // Reference values from the ERFA test suite for `eraPom00`
+        let pole_coords = DVec2 {
+            x: 2.55060238e-7,
+            y: 1.860359247e-6,
+        };
+        let sp = -1.3671745807288915e-11;
+        let expected = [
+            0.9999999999999675,
+            1.4146249479570297e-11,
+            -2.5506023797412153e-07,
+            -1.367174580728847e-11,
+            0.9999999999982695,
+            1.860359247002414e-06,
+            2.550602379999972e-07,
+            -1.8603592469988663e-06,
+            0.999999999998237,
+        ];
+        let actual = polar_motion_matrix(pole_coords, sp).to_cols_array();
+        for i in 0..9 {
+            assert_float_eq!(expected[i], actual[i], abs <= 1e-15);
+        }
+    }
+
     fn assert_mat3_eq(expected: &[f64; 9], actual: &[f64; 9]) {
         for i in 0..9 {
             assert_float_eq!(
@@ -0,0 +1,286 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module eop exposes [EopProvider], which describes an API for providing the Earth Orientation
+//! Parameters required by the transformation between the celestial and terrestrial reference
+//! systems.
+//!
+//! [IersEop] is the default implementation of [EopProvider], which parses Earth Orientation
+//! Parameters from an IERS CSV file.
+
+use std::path::Path;
+
+use thiserror::Error;
+
+use lox_io::iers::{EarthOrientationParams, ParseFinalsCsvError};
+use lox_math::series::{Series, SeriesError};
+use lox_math::types::units::Arcseconds;
+use lox_time::Time;
+use lox_time::calendar_dates::{CalendarDate, Date};
+use lox_time::constants::julian_dates::SECONDS_BETWEEN_MJD_AND_J2000;
+use lox_time::deltas::TimeDelta;
+use lox_time::julian_dates::JulianDate;
+use lox_time::time_scales::Tai;
+use lox_time::ut1::DeltaUt1TaiProvider;
+use lox_time::utc::{LeapSecondsProvider, Utc};
+
+const SECONDS_PER_DAY: i64 = 86400;
+
+/// Implementers of `EopProvider` provide the Earth Orientation Parameters at an instant in TAI.
+///
+/// The difference between UT1 and TAI is provided by the [DeltaUt1TaiProvider] supertrait.
+pub trait EopProvider: DeltaUt1TaiProvider {
+    /// Returns the coordinates of the Celestial Intermediate Pole with respect to the ITRS,
+    /// (x_p, y_p), in arcseconds at the given TAI instant.
+    fn polar_motion(&self, tai: TimeDelta) -> Result<(Arcseconds, Arcseconds), Self::Error>;
+
+    /// Returns the offsets of the Celestial Intermediate Pole with respect to the IAU 2006/2000A
+    /// precession-nutation model, (dX, dY), in arcseconds at the given TAI instant.
+    fn celestial_pole_offsets(
+        &self,
+        tai: TimeDelta,
+    ) -> Result<(Arcseconds, Arcseconds), Self::Error>;
+}
+
+/// Error type returned when [IersEop] instantiation fails.
+#[derive(Clone, Debug, Error)]
+pub enum IersEopError {
+    #[error(transparent)]
+    Csv(#[from] ParseFinalsCsvError),
+    #[error(transparent)]
+    Series(#[from] SeriesError),
+}
+
+/// Error type indicating that an input date to [IersEop] was outside the range of available
+/// Earth Orientation Parameters.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[error(
+    "Earth Orientation Parameters are only available between {min_date} and {max_date}, but were requested for {req_date}"
+)]
+pub struct EopOutOfRange {
+    req_date: Date,
+    min_date: Date,
+    max_date: Date,
+}
+
+impl EopOutOfRange {
+    fn new(t0: f64, tn: f64, t: f64) -> Self {
+        let date =
+            |seconds: f64| Time::from_delta(Tai, TimeDelta::from_decimal_seconds(seconds)).date();
+        Self {
+            req_date: date(t),
+            min_date: date(t0),
+            max_date: date(tn),
+        }
+    }
+}
+
+/// Provides a standard implementation of [EopProvider] based on linear interpolation between the
+/// daily entries of IERS Earth Orientation Parameters.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IersEop {
+    x_pole: Series<Vec<f64>, Vec<f64>>,
+    y_pole: Series<Vec<f64>, Vec<f64>>,
+    delta_ut1_tai: Series<Vec<f64>, Vec<f64>>,
+    dx: Series<Vec<f64>, Vec<f64>>,
+    dy: Series<Vec<f64>, Vec<f64>>,
+}
+
+impl IersEop {
+    /// Instantiates a new [IersEop] provider from a path to an IERS Earth Orientation Parameters
+    /// finals CSV and a [LeapSecondsProvider].
+    ///
+    /// `ls` should provide leap second data for the full range of the EOP data.
+    ///
+    /// # Errors
+    ///
+    /// - [IersEopError::Csv] if the CSV file could not be parsed.
+    /// - [IersEopError::Series] if construction of the interpolated series fails.
+    pub fn new<P: AsRef<Path>>(
+        path: P,
+        ls: &impl LeapSecondsProvider,
+    ) -> Result<Self, IersEopError> {
+        let eop = EarthOrientationParams::parse_finals_csv(path)?;
+        let (seconds, delta_utc_tai): (Vec<f64>, Vec<f64>) = eop
+            .mjd()
+            .iter()
+            .map(|&mjd| {
+                let delta = TimeDelta::from_seconds(
+                    mjd as i64 * SECONDS_PER_DAY - SECONDS_BETWEEN_MJD_AND_J2000,
+                );
+                let delta_utc_tai = ls
+                    .delta_utc_tai(Utc::from_delta(delta))
+                    .unwrap()
+                    .to_decimal_seconds();
+                (delta.to_decimal_seconds() - delta_utc_tai, delta_utc_tai)
+            })
+            .unzip();
+        let delta_ut1_tai: Vec<f64> = eop
+            .delta_ut1_utc()
+            .iter()
+            .zip(delta_utc_tai)
+            .map(|(delta_ut1_utc, delta_utc_tai)| delta_ut1_utc + delta_utc_tai)
+            .collect();
+        let mas_to_arcsec = |mas: &[f64]| mas.iter().map(|v| v / 1000.0).collect::<Vec<f64>>();
+        Ok(Self {
+            x_pole: Series::new(seconds.clone(), eop.x_pole().to_vec())?,
+            y_pole: Series::new(seconds.clone(), eop.y_pole().to_vec())?,
+            delta_ut1_tai: Series::new(seconds.clone(), delta_ut1_tai)?,
+            dx: Series::new(seconds.clone(), mas_to_arcsec(eop.dx()))?,
+            dy: Series::new(seconds, mas_to_arcsec(eop.dy()))?,
+        })
+    }
+
+    fn check_range(&self, seconds: f64) -> Result<(), EopOutOfRange> {
+        let (t0, _) = self.x_pole.first();
+        let (tn, _) = self.x_pole.last();
+        if seconds < t0 || seconds > tn {
+            return Err(EopOutOfRange::new(t0, tn, seconds));
+        }
+        Ok(())
+    }
+}
+
+impl DeltaUt1TaiProvider for IersEop {
+    type Error = EopOutOfRange;
+
+    fn delta_ut1_tai(&self, tai: TimeDelta) -> Result<TimeDelta, Self::Error> {
+        let seconds = tai.seconds_since_j2000();
+        self.check_range(seconds)?;
+        Ok(TimeDelta::from_decimal_seconds(
+            self.delta_ut1_tai.interpolate(seconds),
+        ))
+    }
+
+    fn delta_tai_ut1(&self, ut1: TimeDelta) -> Result<TimeDelta, Self::Error> {
+        let seconds = ut1.seconds_since_j2000();
+        self.check_range(seconds)?;
+        // Use the UT1 offset as an initial guess even though the table is based on TAI
+        let mut val = self.delta_ut1_tai.interpolate(seconds);
+        // Interpolate again with the adjusted offsets
+        for _ in 0..2 {
+            val = self.delta_ut1_tai.interpolate(seconds - val);
+        }
+        Ok(-TimeDelta::from_decimal_seconds(val))
+    }
+}
+
+impl EopProvider for IersEop {
+    fn polar_motion(&self, tai: TimeDelta) -> Result<(Arcseconds, Arcseconds), Self::Error> {
+        let seconds = tai.seconds_since_j2000();
+        self.check_range(seconds)?;
+        Ok((
+            self.x_pole.interpolate(seconds),
+            self.y_pole.interpolate(seconds),
+        ))
+    }
+
+    fn celestial_pole_offsets(
+        &self,
+        tai: TimeDelta,
+    ) -> Result<(Arcseconds, Arcseconds), Self::Error> {
+        let seconds = tai.seconds_since_j2000();
+        self.check_range(seconds)?;
+        Ok((self.dx.interpolate(seconds), self.dy.interpolate(seconds)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::OnceLock;
+
+    use float_eq::assert_float_eq;
+    use lox_time::deltas::ToDelta;
+    use lox_time::time;
+    use lox_time::utc::leap_seconds::BuiltinLeapSeconds;
+
+    use super::*;
+
+    #[test]
+    fn test_iers_eop_daily_entry() {
+        // MJD 54195, 2007-04-05 00:00 UTC
+        let tai = time!(Tai, 2007, 4, 5, 0, 0, 33.0).unwrap().to_delta();
+        let provider = iers_eop();
+        let (xp, yp) = provider.polar_motion(tai).unwrap();
+        let (dx, dy) = provider.celestial_pole_offsets(tai).unwrap();
+        let delta_ut1_tai = provider.delta_ut1_tai(tai).unwrap().to_decimal_seconds();
+        let expected = entry(54195);
+        assert_float_eq!(xp, expected[0], abs <= 1e-12);
+        assert_float_eq!(yp, expected[1], abs <= 1e-12);
+        assert_float_eq!(delta_ut1_tai, expected[2] - 33.0, abs <= 1e-9);
+        assert_float_eq!(dx, expected[3] / 1000.0, abs <= 1e-12);
+        assert_float_eq!(dy, expected[4] / 1000.0, abs <= 1e-12);
+    }
+
+    #[test]
+    fn test_iers_eop_linear_interpolation() {
+        // 2007-04-05 12:00 UTC lies halfway between MJD 54195 and 54196
+        let tai = time!(Tai, 2007, 4, 5, 12, 0, 33.0).unwrap().to_delta();
+        let provider = iers_eop();
+        let (xp, yp) = provider.polar_motion(tai).unwrap();
+        let (dx, dy) = provider.celestial_pole_offsets(tai).unwrap();
+        let e0 = entry(54195);
+        let e1 = entry(54196);
+        assert_float_eq!(xp, (e0[0] + e1[0]) / 2.0, abs <= 1e-12);
+        assert_float_eq!(yp, (e0[1] + e1[1]) / 2.0, abs <= 1e-12);
+        assert_float_eq!(dx, (e0[3] + e1[3]) / 2000.0, abs <= 1e-12);
+        assert_float_eq!(dy, (e0[4] + e1[4]) / 2000.0, abs <= 1e-12);
+
+        let delta_ut1_tai = provider.delta_ut1_tai(tai).unwrap();
+        let ut1 = tai + delta_ut1_tai;
+        let delta_tai_ut1 = provider.delta_tai_ut1(ut1).unwrap();
+        assert_float_eq!(
+            delta_tai_ut1.to_decimal_seconds(),
+            -delta_ut1_tai.to_decimal_seconds(),
+            abs <= 1e-9
+        );
+    }
+
+    #[test]
+    fn test_iers_eop_out_of_range() {
+        let tai = time!(Tai, 1972, 1, 1).unwrap().to_delta();
+        let provider = iers_eop();
+        let expected = EopOutOfRange {
+            req_date: Date::new(1972, 1, 1).unwrap(),
+            min_date: Date::new(1973, 1, 2).unwrap(),
+            max_date: Date::new(2025, 3, 15).unwrap(),
+        };
+        assert_eq!(provider.polar_motion(tai), Err(expected.clone()));
+        assert_eq!(provider.celestial_pole_offsets(tai), Err(expected.clone()));
+        assert_eq!(provider.delta_ut1_tai(tai), Err(expected.clone()));
+        assert_eq!(
+            expected.to_string(),
+            "Earth Orientation Parameters are only available between 1973-01-02 and 2025-03-15, but were requested for 1972-01-01"
+        );
+    }
+
+    /// Returns x_p, y_p, UT1-UTC, dX, and dY for the given MJD from the finals CSV.
+    fn entry(mjd: i32) -> [f64; 5] {
+        let eop = EarthOrientationParams::parse_finals_csv(path()).unwrap();
+        let i = eop.mjd().iter().position(|&m| m == mjd).unwrap();
+        [
+            eop.x_pole()[i],
+            eop.y_pole()[i],
+            eop.delta_ut1_utc()[i],
+            eop.dx()[i],
+            eop.dy()[i],
+        ]
+    }
+
+    fn path() -> String {
+        format!(
+            "{}/../../data/finals2000A.all.csv",
+            env!("CARGO_MANIFEST_DIR")
+        )
+    }
+
+    fn iers_eop() -> &'static IersEop {
+        static PROVIDER: OnceLock<IersEop> = OnceLock::new();
+        PROVIDER.get_or_init(|| IersEop::new(path(), &BuiltinLeapSeconds).unwrap())
+    }
+}
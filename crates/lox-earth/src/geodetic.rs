@@ -0,0 +1,195 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module geodetic exposes functions for converting between body-fixed Cartesian coordinates and
+//! geodetic latitude, longitude, and altitude on a reference ellipsoid such as WGS-84.
+
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_6};
+
+use glam::DVec3;
+
+use lox_bodies::ReferenceEllipsoid;
+use lox_math::types::units::Radians;
+
+/// Converts the body-fixed Cartesian position `r` in km to geodetic latitude and longitude in
+/// radians and altitude above `ellipsoid` in km.
+///
+/// The longitude is in the range [-π, π]. On the polar axis, the longitude is zero and the
+/// latitude is ±π/2.
+pub fn cartesian_to_geodetic(r: DVec3, ellipsoid: &ReferenceEllipsoid) -> (Radians, Radians, f64) {
+    to_geodetic(r, ellipsoid.semi_major_axis(), ellipsoid.flattening())
+}
+
+/// Converts geodetic latitude and longitude in radians and altitude above `ellipsoid` in km to a
+/// body-fixed Cartesian position in km.
+pub fn geodetic_to_cartesian(
+    latitude: Radians,
+    longitude: Radians,
+    altitude: f64,
+    ellipsoid: &ReferenceEllipsoid,
+) -> DVec3 {
+    to_cartesian(
+        latitude,
+        longitude,
+        altitude,
+        ellipsoid.semi_major_axis(),
+        ellipsoid.flattening(),
+    )
+}
+
+fn to_geodetic(r: DVec3, a: f64, f: f64) -> (Radians, Radians, f64) {
+    let e2 = f * (2.0 - f);
+    let e4 = e2.powi(2);
+    let rho = r.x.hypot(r.y);
+
+    if rho == 0.0 {
+        let latitude = FRAC_PI_2.copysign(r.z);
+        return (latitude, 0.0, r.z.abs() - a * (1.0 - f));
+    }
+
+    let longitude = r.y.atan2(r.x);
+
+    // Vermeille, 2011, "An analytical method to transform geocentric into geodetic
+    // coordinates", which is valid everywhere including inside the evolute of the ellipse
+    let p = (rho / a).powi(2);
+    let q = (1.0 - e2) * (r.z / a).powi(2);
+    let rr = (p + q - e4) / 6.0;
+    let evolute_border = 8.0 * rr.powi(3) + e4 * p * q;
+
+    if evolute_border <= 0.0 && q == 0.0 {
+        // The point lies within the singular disc in the equatorial plane
+        let cos_lat = (p * (1.0 - e2) / (e2 * (e2 - p))).sqrt().min(1.0);
+        let latitude = cos_lat.acos();
+        let n = a / (1.0 - e2 * latitude.sin().powi(2)).sqrt();
+        return (latitude, longitude, -(1.0 - e2) * n);
+    }
+
+    let u = if evolute_border > 0.0 {
+        let rad1 = evolute_border.sqrt();
+        let rad2 = (e4 * p * q).sqrt();
+        if evolute_border > 10.0 * e2 {
+            let rad3 = (rad1 + rad2).powi(2).cbrt();
+            rr + 0.5 * rad3 + 2.0 * rr.powi(2) / rad3
+        } else {
+            rr + 0.5 * (rad1 + rad2).powi(2).cbrt() + 0.5 * (rad1 - rad2).powi(2).cbrt()
+        }
+    } else {
+        let rad1 = (-evolute_border).sqrt();
+        let rad2 = (-8.0 * rr.powi(3)).sqrt();
+        let rad3 = (e4 * p * q).sqrt();
+        let angle = 2.0 * rad3.atan2(rad1 + rad2) / 3.0;
+        -4.0 * rr * angle.sin() * (FRAC_PI_6 + angle).cos()
+    };
+    let v = (u.powi(2) + e4 * q).sqrt();
+    let w = e2 * (u + v - q) / (2.0 * v);
+    let k = (u + v) / ((w.powi(2) + u + v).sqrt() + w);
+    let d = k * rho / (k + e2);
+    let hypot = d.hypot(r.z);
+    let latitude = 2.0 * r.z.atan2(hypot + d);
+    let altitude = (k + e2 - 1.0) * hypot / k;
+    (latitude, longitude, altitude)
+}
+
+fn to_cartesian(latitude: Radians, longitude: Radians, altitude: f64, a: f64, f: f64) -> DVec3 {
+    let e2 = f * (2.0 - f);
+    let (sin_lat, cos_lat) = latitude.sin_cos();
+    let (sin_lon, cos_lon) = longitude.sin_cos();
+    let n = a / (1.0 - e2 * sin_lat.powi(2)).sqrt();
+    DVec3::new(
+        (n + altitude) * cos_lat * cos_lon,
+        (n + altitude) * cos_lat * sin_lon,
+        (n * (1.0 - e2) + altitude) * sin_lat,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+    use rstest::rstest;
+
+    use super::*;
+
+    // One millimetre in km
+    const MILLIMETRE: f64 = 1e-6;
+
+    const WGS84: ReferenceEllipsoid = ReferenceEllipsoid::WGS84;
+
+    #[test]
+    fn test_geodetic_round_trip() {
+        for lat in (-90..=90).step_by(15) {
+            for lon in (-180..=180).step_by(30) {
+                for alt in [-10.0, 0.0, 0.5, 400.0, 35786.0] {
+                    let latitude = (lat as f64).to_radians();
+                    let longitude = (lon as f64).to_radians();
+                    let r = geodetic_to_cartesian(latitude, longitude, alt, &WGS84);
+                    let (lat_act, lon_act, alt_act) = cartesian_to_geodetic(r, &WGS84);
+                    let r_act = geodetic_to_cartesian(lat_act, lon_act, alt_act, &WGS84);
+                    assert_close!(r_act, r, MILLIMETRE, 0.0);
+                    assert_float_eq!(alt_act, alt, abs <= MILLIMETRE);
+                    assert_float_eq!(lat_act, latitude, abs <= 1e-12);
+                }
+            }
+        }
+    }
+
+    #[rstest]
+    #[case::north_pole(DVec3::new(0.0, 0.0, 6400.0), FRAC_PI_2, 6400.0 - 6356.752314245179)]
+    #[case::south_pole(DVec3::new(0.0, 0.0, -6400.0), -FRAC_PI_2, 6400.0 - 6356.752314245179)]
+    #[case::center(DVec3::ZERO, FRAC_PI_2, -6356.752314245179)]
+    fn test_geodetic_polar_axis(
+        #[case] r: DVec3,
+        #[case] expected_lat: Radians,
+        #[case] expected_alt: f64,
+    ) {
+        let (lat, lon, alt) = cartesian_to_geodetic(r, &WGS84);
+        assert_eq!(lat, expected_lat);
+        assert_eq!(lon, 0.0);
+        assert_float_eq!(alt, expected_alt, abs <= 1e-9);
+    }
+
+    #[rstest]
+    #[case::equatorial_plane(DVec3::new(1.0, 0.0, 0.0))]
+    #[case::near_center(DVec3::new(1e-3, 1e-3, 1e-3))]
+    #[case::inside_evolute(DVec3::new(20.0, 0.0, 5.0))]
+    #[case::equatorial_disc(DVec3::new(20.0, 10.0, 0.0))]
+    #[case::near_pole(DVec3::new(1e-9, 0.0, 6356.752))]
+    fn test_geodetic_no_nan(#[case] r: DVec3) {
+        let (lat, lon, alt) = cartesian_to_geodetic(r, &WGS84);
+        assert!(!lat.is_nan() && !lon.is_nan() && !alt.is_nan());
+        assert_close!(
+            geodetic_to_cartesian(lat, lon, alt, &WGS84),
+            r,
+            MILLIMETRE,
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_geodetic_other_ellipsoids() {
+        let mars = ReferenceEllipsoid::from_spheroid(&lox_bodies::Mars);
+        let latitude = 30f64.to_radians();
+        let longitude = -60f64.to_radians();
+        let r = geodetic_to_cartesian(latitude, longitude, 10.0, &mars);
+        let (lat, lon, alt) = cartesian_to_geodetic(r, &mars);
+        assert_float_eq!(lat, latitude, abs <= 1e-12);
+        assert_float_eq!(lon, longitude, abs <= 1e-12);
+        assert_float_eq!(alt, 10.0, abs <= MILLIMETRE);
+
+        // On a sphere, geodetic and geocentric coordinates coincide
+        let moon = ReferenceEllipsoid::from_flattening(1737.4, 0.0);
+        let (lat, _, alt) = cartesian_to_geodetic(DVec3::new(1000.0, 0.0, 1000.0), &moon);
+        assert_float_eq!(lat, 45f64.to_radians(), abs <= 1e-12);
+        assert_float_eq!(alt, 2f64.sqrt() * 1000.0 - 1737.4, abs <= MILLIMETRE);
+
+        // The GRS-80 polar radius is about 0.1 mm shorter than the WGS-84 one
+        let (_, _, alt) = cartesian_to_geodetic(DVec3::Z * 6400.0, &ReferenceEllipsoid::GRS80);
+        assert_float_eq!(alt, 6400.0 - 6356.752314140356, abs <= 1e-9);
+    }
+}
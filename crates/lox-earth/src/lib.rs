@@ -9,8 +9,12 @@
 pub mod cio;
 pub mod cip;
 pub mod coordinate_transformations;
+pub mod eop;
+pub mod geodetic;
 pub mod nutation;
+pub mod precession;
 pub mod rotation_angle;
+pub mod sidereal_time;
 #[allow(dead_code)]
 pub mod tides;
 pub mod tio;
@@ -3,7 +3,7 @@ use std::f64::consts::TAU;
 use lox_math::math::{arcsec_to_rad, normalize_two_pi};
 use lox_math::types::units::{Arcseconds, JulianCenturies, Radians};
 
-use crate::nutation::{Nutation, point1_milliarcsec_to_rad};
+use crate::nutation::{DelaunayArguments, Nutation, point1_milliarcsec_to_rad};
 
 struct Coefficients {
     /// Coefficients of l, l', F, D and Ω.
@@ -23,11 +23,27 @@ struct Coefficients {
 }
 
 pub(crate) fn nutation_iau1980(centuries_since_j2000_tdb: JulianCenturies) -> Nutation {
-    let l = l(centuries_since_j2000_tdb);
-    let lp = lp(centuries_since_j2000_tdb);
-    let f = f(centuries_since_j2000_tdb);
-    let d = d(centuries_since_j2000_tdb);
-    let om = omega(centuries_since_j2000_tdb);
+    nutation_iau1980_from_arguments(
+        centuries_since_j2000_tdb,
+        &arguments_iau1980(centuries_since_j2000_tdb),
+    )
+}
+
+pub(crate) fn arguments_iau1980(centuries_since_j2000_tdb: JulianCenturies) -> DelaunayArguments {
+    DelaunayArguments {
+        l: l(centuries_since_j2000_tdb),
+        lp: lp(centuries_since_j2000_tdb),
+        f: f(centuries_since_j2000_tdb),
+        d: d(centuries_since_j2000_tdb),
+        om: omega(centuries_since_j2000_tdb),
+    }
+}
+
+pub(crate) fn nutation_iau1980_from_arguments(
+    centuries_since_j2000_tdb: JulianCenturies,
+    args: &DelaunayArguments,
+) -> Nutation {
+    let DelaunayArguments { l, lp, f, d, om } = *args;
 
     let mut nutation = COEFFICIENTS
         .iter()
@@ -1,10 +1,15 @@
 use std::f64::consts::TAU;
 
-pub(crate) use iau2000a::nutation_iau2000a;
-pub(crate) use iau2000b::nutation_iau2000b;
+pub(crate) use iau2000a::{
+    PlanetaryArguments, luni_solar_arguments_iau2000a, nutation_iau2000a,
+    nutation_iau2000a_from_arguments, planetary_arguments_iau2000a,
+};
+pub(crate) use iau2000b::{
+    luni_solar_arguments_iau2000b, nutation_iau2000b, nutation_iau2000b_from_arguments,
+};
 use lox_math::types::units::JulianCenturies;
 
-use crate::nutation::{Nutation, point1_microarcsec_to_rad};
+use crate::nutation::{DelaunayArguments, Nutation, point1_microarcsec_to_rad};
 
 mod iau2000a;
 mod iau2000b;
@@ -29,14 +34,6 @@ struct LuniSolarCoefficients {
     sin_eps: f64,
 }
 
-struct DelaunayArguments {
-    l: f64,
-    lp: f64,
-    f: f64,
-    d: f64,
-    om: f64,
-}
-
 /// Calculate the luni-solar nutation for `t` given `args` and coefficients for either models A or
 /// B.
 fn luni_solar_nutation(
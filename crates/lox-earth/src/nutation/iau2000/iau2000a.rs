@@ -15,8 +15,8 @@ use lox_bodies::fundamental::mhb2000::{
 use lox_bodies::*;
 use lox_math::types::units::JulianCenturies;
 
-use crate::nutation::iau2000::{DelaunayArguments, luni_solar_nutation};
-use crate::nutation::{Nutation, point1_microarcsec_to_rad};
+use crate::nutation::iau2000::luni_solar_nutation;
+use crate::nutation::{DelaunayArguments, Nutation, point1_microarcsec_to_rad};
 
 mod luni_solar;
 mod planetary;
@@ -50,36 +50,82 @@ struct PlanetaryCoefficients {
     cos_eps: f64,
 }
 
+/// The arguments of the planetary terms of IAU 2000A, i.e. the Delaunay arguments of the MHB2000
+/// model and the mean longitudes of the planets and the general precession in longitude.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct PlanetaryArguments {
+    delaunay: DelaunayArguments,
+    mercury: f64,
+    venus: f64,
+    earth: f64,
+    mars: f64,
+    jupiter: f64,
+    saturn: f64,
+    uranus: f64,
+    neptune: f64,
+    pa: f64,
+}
+
 pub(crate) fn nutation_iau2000a(centuries_since_j2000_tdb: JulianCenturies) -> Nutation {
-    let luni_solar_args = DelaunayArguments {
+    nutation_iau2000a_from_arguments(
+        centuries_since_j2000_tdb,
+        &luni_solar_arguments_iau2000a(centuries_since_j2000_tdb),
+        &planetary_arguments_iau2000a(centuries_since_j2000_tdb),
+    )
+}
+
+pub(crate) fn luni_solar_arguments_iau2000a(
+    centuries_since_j2000_tdb: JulianCenturies,
+) -> DelaunayArguments {
+    DelaunayArguments {
         l: Moon.mean_anomaly_iers03(centuries_since_j2000_tdb),
         lp: Sun.mean_anomaly_mhb2000(centuries_since_j2000_tdb),
         f: Moon
             .mean_longitude_minus_ascending_node_mean_longitude_iers03(centuries_since_j2000_tdb),
         d: mean_moon_sun_elongation_mhb2000_luni_solar(centuries_since_j2000_tdb),
         om: Moon.ascending_node_mean_longitude_iers03(centuries_since_j2000_tdb),
-    };
+    }
+}
 
-    let planetary_args = DelaunayArguments {
-        l: Moon.mean_anomaly_mhb2000(centuries_since_j2000_tdb),
-        lp: 0.0, // unused
-        f: Moon
-            .mean_longitude_minus_ascending_node_mean_longitude_mhb2000(centuries_since_j2000_tdb),
-        d: mean_moon_sun_elongation_mhb2000_planetary(centuries_since_j2000_tdb),
-        om: Moon.ascending_node_mean_longitude_mhb2000(centuries_since_j2000_tdb),
-    };
+pub(crate) fn planetary_arguments_iau2000a(
+    centuries_since_j2000_tdb: JulianCenturies,
+) -> PlanetaryArguments {
+    PlanetaryArguments {
+        delaunay: DelaunayArguments {
+            l: Moon.mean_anomaly_mhb2000(centuries_since_j2000_tdb),
+            lp: 0.0, // unused
+            f: Moon.mean_longitude_minus_ascending_node_mean_longitude_mhb2000(
+                centuries_since_j2000_tdb,
+            ),
+            d: mean_moon_sun_elongation_mhb2000_planetary(centuries_since_j2000_tdb),
+            om: Moon.ascending_node_mean_longitude_mhb2000(centuries_since_j2000_tdb),
+        },
+        mercury: Mercury.mean_longitude_iers03(centuries_since_j2000_tdb),
+        venus: Venus.mean_longitude_iers03(centuries_since_j2000_tdb),
+        earth: Earth.mean_longitude_iers03(centuries_since_j2000_tdb),
+        mars: Mars.mean_longitude_iers03(centuries_since_j2000_tdb),
+        jupiter: Jupiter.mean_longitude_iers03(centuries_since_j2000_tdb),
+        saturn: Saturn.mean_longitude_iers03(centuries_since_j2000_tdb),
+        uranus: Uranus.mean_longitude_iers03(centuries_since_j2000_tdb),
+        neptune: Neptune.mean_longitude_mhb2000(centuries_since_j2000_tdb),
+        pa: general_accum_precession_in_longitude_iers03(centuries_since_j2000_tdb),
+    }
+}
 
+pub(crate) fn nutation_iau2000a_from_arguments(
+    centuries_since_j2000_tdb: JulianCenturies,
+    luni_solar_args: &DelaunayArguments,
+    planetary_args: &PlanetaryArguments,
+) -> Nutation {
     luni_solar_nutation(
         centuries_since_j2000_tdb,
-        &luni_solar_args,
+        luni_solar_args,
         &luni_solar::COEFFICIENTS,
-    ) + planetary_nutation(centuries_since_j2000_tdb, planetary_args)
+    ) + planetary_nutation(planetary_args)
 }
 
-fn planetary_nutation(
-    centuries_since_j2000_tdb: JulianCenturies,
-    args: DelaunayArguments,
-) -> Nutation {
+fn planetary_nutation(args: &PlanetaryArguments) -> Nutation {
+    let delaunay = &args.delaunay;
     let mut nutation = planetary::COEFFICIENTS
         .iter()
         // The coefficients are given by descending magnitude but folded by ascending
@@ -87,20 +133,19 @@ fn planetary_nutation(
         .rev()
         .fold(Nutation::default(), |mut nut, coeff| {
             // Form argument for current term.
-            let arg = (coeff.l * args.l
-                + coeff.f * args.f
-                + coeff.d * args.d
-                + coeff.om * args.om
-                + coeff.mercury * Mercury.mean_longitude_iers03(centuries_since_j2000_tdb)
-                + coeff.venus * Venus.mean_longitude_iers03(centuries_since_j2000_tdb)
-                + coeff.earth * Earth.mean_longitude_iers03(centuries_since_j2000_tdb)
-                + coeff.mars * Mars.mean_longitude_iers03(centuries_since_j2000_tdb)
-                + coeff.jupiter * Jupiter.mean_longitude_iers03(centuries_since_j2000_tdb)
-                + coeff.saturn * Saturn.mean_longitude_iers03(centuries_since_j2000_tdb)
-                + coeff.uranus * Uranus.mean_longitude_iers03(centuries_since_j2000_tdb)
-                + coeff.neptune * Neptune.mean_longitude_mhb2000(centuries_since_j2000_tdb)
-                + coeff.pa
-                    * general_accum_precession_in_longitude_iers03(centuries_since_j2000_tdb))
+            let arg = (coeff.l * delaunay.l
+                + coeff.f * delaunay.f
+                + coeff.d * delaunay.d
+                + coeff.om * delaunay.om
+                + coeff.mercury * args.mercury
+                + coeff.venus * args.venus
+                + coeff.earth * args.earth
+                + coeff.mars * args.mars
+                + coeff.jupiter * args.jupiter
+                + coeff.saturn * args.saturn
+                + coeff.uranus * args.uranus
+                + coeff.neptune * args.neptune
+                + coeff.pa * args.pa)
                 % TAU;
 
             // Accumulate current term.
@@ -10,24 +10,38 @@ use lox_bodies::fundamental::simon1994::mean_moon_sun_elongation_simon1994;
 use lox_bodies::*;
 use lox_math::types::units::JulianCenturies;
 
-use crate::nutation::Nutation;
-use crate::nutation::iau2000::{DelaunayArguments, luni_solar_nutation};
+use crate::nutation::iau2000::luni_solar_nutation;
+use crate::nutation::{DelaunayArguments, Nutation};
 
 mod luni_solar;
 mod planetary;
 
 pub(crate) fn nutation_iau2000b(centuries_since_j2000_tdb: JulianCenturies) -> Nutation {
-    let luni_solar_args = DelaunayArguments {
+    nutation_iau2000b_from_arguments(
+        centuries_since_j2000_tdb,
+        &luni_solar_arguments_iau2000b(centuries_since_j2000_tdb),
+    )
+}
+
+pub(crate) fn luni_solar_arguments_iau2000b(
+    centuries_since_j2000_tdb: JulianCenturies,
+) -> DelaunayArguments {
+    DelaunayArguments {
         l: Moon.mean_anomaly_simon1994(centuries_since_j2000_tdb),
         lp: Sun.mean_anomaly_simon1994(centuries_since_j2000_tdb),
         f: Moon.mean_argument_of_latitude_simon1994(centuries_since_j2000_tdb),
         d: mean_moon_sun_elongation_simon1994(centuries_since_j2000_tdb),
         om: Moon.ascending_node_mean_longitude_simon1994(centuries_since_j2000_tdb),
-    };
+    }
+}
 
+pub(crate) fn nutation_iau2000b_from_arguments(
+    centuries_since_j2000_tdb: JulianCenturies,
+    luni_solar_args: &DelaunayArguments,
+) -> Nutation {
     luni_solar_nutation(
         centuries_since_j2000_tdb,
-        &luni_solar_args,
+        luni_solar_args,
         &luni_solar::COEFFICIENTS,
     ) + planetary::OFFSETS
 }
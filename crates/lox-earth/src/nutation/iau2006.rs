@@ -16,7 +16,17 @@ use lox_math::types::units::JulianCenturies;
 /// The IAU 2000A nutation model adjusted to match the IAU 2006 precession model per
 /// Wallace & Capitaine, 2006.
 pub fn nutation_iau2006a(centuries_since_j2000_tdb: JulianCenturies) -> Nutation {
-    let mut nutation = nutation_iau2000a(centuries_since_j2000_tdb);
+    adjust_iau2006(
+        centuries_since_j2000_tdb,
+        nutation_iau2000a(centuries_since_j2000_tdb),
+    )
+}
+
+/// Adjusts the IAU 2000A `nutation` to match the IAU 2006 precession model.
+pub(crate) fn adjust_iau2006(
+    centuries_since_j2000_tdb: JulianCenturies,
+    mut nutation: Nutation,
+) -> Nutation {
     let j2_correction = Earth::j2_correction_factor(centuries_since_j2000_tdb);
 
     nutation.longitude += nutation.longitude * (0.4697e-6 + j2_correction);
@@ -0,0 +1,188 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module precession exposes a function for calculating the precession matrix from the mean
+//! equator and equinox of J2000.0 to the mean equator and equinox of date using a number of IAU
+//! precession models.
+
+use glam::DMat3;
+
+use lox_math::math::arcsec_to_rad;
+use lox_math::types::units::JulianCenturies;
+
+/// The supported IAU precession models.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Model {
+    /// The IAU 1976 model of Lieske et al. (1977), whose precession rate in longitude is too large
+    /// by about 0.3"/century, such that it deviates from IAU 2006 by about 0.3" after one century.
+    IAU1976,
+    /// The IAU 2006 (P03) model of Capitaine et al. (2003), which is consistent with dynamical
+    /// theory to about 0.1 mas/century.
+    #[default]
+    IAU2006,
+}
+
+/// Computes the precession matrix from the mean equator and equinox of J2000.0 to the mean equator
+/// and equinox of date using the given [Model].
+///
+/// The frame bias between the GCRS and the mean equator and equinox of J2000.0 is not included.
+pub fn precession_matrix(model: Model, centuries_since_j2000_tt: JulianCenturies) -> DMat3 {
+    match model {
+        Model::IAU1976 => precession_matrix_iau1976(centuries_since_j2000_tt),
+        Model::IAU2006 => precession_matrix_iau2006(centuries_since_j2000_tt),
+    }
+}
+
+/// Lieske et al., 1977, Astronomy and Astrophysics 58, Table 5
+fn precession_matrix_iau1976(t: JulianCenturies) -> DMat3 {
+    let zeta = arcsec_to_rad(fast_polynomial::poly_array(
+        t,
+        &[0.0, 2306.2181, 0.30188, 0.017998],
+    ));
+    let z = arcsec_to_rad(fast_polynomial::poly_array(
+        t,
+        &[0.0, 2306.2181, 1.09468, 0.018203],
+    ));
+    let theta = arcsec_to_rad(fast_polynomial::poly_array(
+        t,
+        &[0.0, 2004.3109, -0.42665, -0.041833],
+    ));
+    // The signs of all angles are reversed relative to ERFA, which uses left-handed
+    // coordinates, whereas glam is right-handed.
+    DMat3::from_rotation_z(z) * DMat3::from_rotation_y(-theta) * DMat3::from_rotation_z(zeta)
+}
+
+/// IERS Conventions (2010), Eq. 5.39 and 5.40
+fn precession_matrix_iau2006(t: JulianCenturies) -> DMat3 {
+    let eps0 = arcsec_to_rad(84381.406);
+    let psi = arcsec_to_rad(fast_polynomial::poly_array(
+        t,
+        &[
+            0.0,
+            5038.481507,
+            -1.0790069,
+            -0.00114045,
+            0.000132851,
+            -0.0000000951,
+        ],
+    ));
+    let omega = arcsec_to_rad(fast_polynomial::poly_array(
+        t,
+        &[
+            84381.406,
+            -0.025754,
+            0.0512623,
+            -0.00772503,
+            -0.000000467,
+            0.0000003337,
+        ],
+    ));
+    let chi = arcsec_to_rad(fast_polynomial::poly_array(
+        t,
+        &[
+            0.0,
+            10.556403,
+            -2.3814292,
+            -0.00121197,
+            0.000170663,
+            -0.0000000560,
+        ],
+    ));
+    // The signs of all angles are reversed relative to ERFA, which uses left-handed
+    // coordinates, whereas glam is right-handed.
+    DMat3::from_rotation_z(-chi)
+        * DMat3::from_rotation_x(omega)
+        * DMat3::from_rotation_z(psi)
+        * DMat3::from_rotation_x(-eps0)
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use glam::DVec3;
+    use lox_math::math::RADIANS_IN_ARCSECOND;
+
+    use super::*;
+
+    // MJD 50123.9999 TT
+    const CENTURIES: JulianCenturies = (50123.9999 - 51544.5) / 36525.0;
+
+    /// Returns the angle of the rotation between `a` and `b` in radians.
+    fn angle_between(a: DMat3, b: DMat3) -> f64 {
+        let m = a * b.transpose();
+        let cos = (m.x_axis.x + m.y_axis.y + m.z_axis.z - 1.0) / 2.0;
+        let axis = DVec3::new(
+            m.y_axis.z - m.z_axis.y,
+            m.z_axis.x - m.x_axis.z,
+            m.x_axis.y - m.y_axis.x,
+        );
+        (axis.length() / 2.0).atan2(cos)
+    }
+
+    #[test]
+    fn test_precession_matrix_iau1976() {
+        // Reference values from the SOFA/ERFA test suite
+        let expected = DMat3::from_cols_array(&[
+            0.9999995504328351,
+            -0.0008696632209485112,
+            -0.0003779153474950335,
+            0.0008696632209480961,
+            0.9999996218428561,
+            -1.643306746147367e-7,
+            0.00037791534749598884,
+            -1.6432847761118864e-7,
+            0.999999928589979,
+        ]);
+        let actual = precession_matrix(Model::IAU1976, CENTURIES);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_float_eq!(actual.col(i)[j], expected.col(i)[j], abs <= 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_precession_matrix_iau2006() {
+        // Reference values for the precession matrix of `eraBp06` from the SOFA/ERFA test suite
+        let expected = DMat3::from_cols_array(&[
+            0.999999550486496,
+            -0.0008696112560510187,
+            -0.0003778929335557603,
+            0.0008696112578855405,
+            0.9999996218880459,
+            -1.594554040786495e-7,
+            0.000377892929334139,
+            -1.6916461689418963e-7,
+            0.9999999285984501,
+        ]);
+        let actual = precession_matrix(Model::IAU2006, CENTURIES);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_float_eq!(actual.col(i)[j], expected.col(i)[j], abs <= 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_precession_models() {
+        for model in [Model::IAU1976, Model::IAU2006] {
+            assert!(precession_matrix(model, 0.0).abs_diff_eq(DMat3::IDENTITY, 1e-15));
+        }
+        // The models differ by about 15 mas five years after J2000.0 and by about 0.3" after a
+        // century
+        let difference = |t| {
+            angle_between(
+                precession_matrix(Model::IAU1976, t),
+                precession_matrix(Model::IAU2006, t),
+            ) / RADIANS_IN_ARCSECOND
+        };
+        assert!(difference(0.05) < 0.02);
+        let after_a_century = difference(1.0);
+        assert!(after_a_century > 0.2 && after_a_century < 0.4);
+    }
+}
@@ -0,0 +1,304 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module sidereal_time exposes functions for calculating Greenwich Mean and Apparent Sidereal
+//! Time consistent with IAU 2006 precession and IAU 2000A nutation.
+
+use glam::{DMat3, DVec2};
+
+use lox_bodies::Earth;
+use lox_math::math::{arcsec_to_rad, mod_two_pi};
+use lox_math::types::units::{Days, JulianCenturies, Microarcseconds, Radians};
+
+use crate::cio::s06::{fundamental_args, s};
+use crate::nutation::iau2006::nutation_iau2006a;
+use crate::rotation_angle::RotationAngle;
+
+/// Computes Greenwich Mean Sidereal Time (GMST) in radians using the IAU 2006 expression.
+///
+/// GMST is a function of both UT1, through the Earth Rotation Angle, and TT, through the
+/// accumulated precession in right ascension.
+pub fn gmst(days_since_j2000_ut1: Days, centuries_since_j2000_tt: JulianCenturies) -> Radians {
+    let era = Earth::rotation_angle_00(days_since_j2000_ut1);
+    let precession = fast_polynomial::poly_array(
+        centuries_since_j2000_tt,
+        &[
+            0.014506,
+            4612.156534,
+            1.3915817,
+            -0.00000044,
+            -0.000029956,
+            -0.0000000368,
+        ],
+    );
+    mod_two_pi(era + arcsec_to_rad(precession))
+}
+
+/// Computes Greenwich Apparent Sidereal Time (GAST) in radians consistent with IAU 2006
+/// precession and IAU 2000A nutation.
+pub fn gast(days_since_j2000_ut1: Days, centuries_since_j2000_tt: JulianCenturies) -> Radians {
+    let era = Earth::rotation_angle_00(days_since_j2000_ut1);
+    mod_two_pi(era - equation_of_origins(centuries_since_j2000_tt))
+}
+
+/// Computes the equation of origins (EO) in radians, i.e. the distance from the true equinox to
+/// the Celestial Intermediate Origin along the equator of the Celestial Intermediate Pole.
+pub fn equation_of_origins(centuries_since_j2000_tt: JulianCenturies) -> Radians {
+    let npb = npb_matrix(centuries_since_j2000_tt);
+    let r = |i: usize, j: usize| npb.col(j)[i];
+    let cip = DVec2::new(r(2, 0), r(2, 1));
+    let cio_locator = s(centuries_since_j2000_tt, cip);
+
+    // Wallace & Capitaine, 2006, Eq. 16
+    let x = r(2, 0);
+    let ax = x / (1.0 + r(2, 2));
+    let xs = 1.0 - ax * x;
+    let ys = -ax * r(2, 1);
+    let zs = -x;
+    let p = r(0, 0) * xs + r(0, 1) * ys + r(0, 2) * zs;
+    let q = r(1, 0) * xs + r(1, 1) * ys + r(1, 2) * zs;
+    if p != 0.0 || q != 0.0 {
+        cio_locator - q.atan2(p)
+    } else {
+        cio_locator
+    }
+}
+
+/// Computes the equation of the equinoxes (EE) in radians, i.e. the difference between GAST and
+/// GMST, from the IAU 2006/2000A nutation in longitude and the complementary terms.
+///
+/// IERS Conventions (2010), Eq. 5.35
+pub fn equation_of_equinoxes(centuries_since_j2000_tt: JulianCenturies) -> Radians {
+    let t = centuries_since_j2000_tt;
+    let nutation = nutation_iau2006a(t);
+    nutation.longitude * mean_obliquity(t).cos() + equation_of_equinoxes_complementary_terms(t)
+}
+
+/// Computes the complementary terms of the equation of the equinoxes in radians.
+///
+/// IERS Conventions (2010), Table 5.2e
+pub fn equation_of_equinoxes_complementary_terms(
+    centuries_since_j2000_tt: JulianCenturies,
+) -> Radians {
+    let args = fundamental_args(centuries_since_j2000_tt);
+    let evaluate = |terms: &[ComplementaryTerm]| -> f64 {
+        terms
+            .iter()
+            .map(|term| {
+                let a: f64 = term
+                    .multipliers
+                    .iter()
+                    .zip(args)
+                    .map(|(&m, arg)| m as f64 * arg)
+                    .sum();
+                let (sin, cos) = a.sin_cos();
+                term.sin * sin + term.cos * cos
+            })
+            .sum()
+    };
+    let microarcsec = evaluate(&COMPLEMENTARY_TERMS_0)
+        + evaluate(&COMPLEMENTARY_TERMS_1) * centuries_since_j2000_tt;
+    arcsec_to_rad(microarcsec * 1e-6)
+}
+
+struct ComplementaryTerm {
+    /// Multipliers of the fundamental arguments l, l', F, D, Ω, LVe, LE, and pA.
+    multipliers: [i8; 8],
+    /// Coefficient of the sine term in microarcseconds.
+    sin: Microarcseconds,
+    /// Coefficient of the cosine term in microarcseconds.
+    cos: Microarcseconds,
+}
+
+const fn term(
+    multipliers: [i8; 8],
+    sin: Microarcseconds,
+    cos: Microarcseconds,
+) -> ComplementaryTerm {
+    ComplementaryTerm {
+        multipliers,
+        sin,
+        cos,
+    }
+}
+
+const COMPLEMENTARY_TERMS_0: [ComplementaryTerm; 33] = [
+    term([0, 0, 0, 0, 1, 0, 0, 0], 2640.96, -0.39),
+    term([0, 0, 0, 0, 2, 0, 0, 0], 63.52, -0.02),
+    term([0, 0, 2, -2, 3, 0, 0, 0], 11.75, 0.01),
+    term([0, 0, 2, -2, 1, 0, 0, 0], 11.21, 0.01),
+    term([0, 0, 2, -2, 2, 0, 0, 0], -4.55, 0.00),
+    term([0, 0, 2, 0, 3, 0, 0, 0], 2.02, 0.00),
+    term([0, 0, 2, 0, 1, 0, 0, 0], 1.98, 0.00),
+    term([0, 0, 0, 0, 3, 0, 0, 0], -1.72, 0.00),
+    term([0, 1, 0, 0, 1, 0, 0, 0], -1.41, -0.01),
+    term([0, 1, 0, 0, -1, 0, 0, 0], -1.26, -0.01),
+    term([1, 0, 0, 0, -1, 0, 0, 0], -0.63, 0.00),
+    term([1, 0, 0, 0, 1, 0, 0, 0], -0.63, 0.00),
+    term([0, 1, 2, -2, 3, 0, 0, 0], 0.46, 0.00),
+    term([0, 1, 2, -2, 1, 0, 0, 0], 0.45, 0.00),
+    term([0, 0, 4, -4, 4, 0, 0, 0], 0.36, 0.00),
+    term([0, 0, 1, -1, 1, -8, 12, 0], -0.24, -0.12),
+    term([0, 0, 2, 0, 0, 0, 0, 0], 0.32, 0.00),
+    term([0, 0, 2, 0, 2, 0, 0, 0], 0.28, 0.00),
+    term([1, 0, 2, 0, 3, 0, 0, 0], 0.27, 0.00),
+    term([1, 0, 2, 0, 1, 0, 0, 0], 0.26, 0.00),
+    term([0, 0, 2, -2, 0, 0, 0, 0], -0.21, 0.00),
+    term([0, 1, -2, 2, -3, 0, 0, 0], 0.19, 0.00),
+    term([0, 1, -2, 2, -1, 0, 0, 0], 0.18, 0.00),
+    term([0, 0, 0, 0, 0, 8, -13, -1], -0.10, 0.05),
+    term([0, 0, 0, 2, 0, 0, 0, 0], 0.15, 0.00),
+    term([2, 0, -2, 0, -1, 0, 0, 0], -0.14, 0.00),
+    term([1, 0, 0, -2, 1, 0, 0, 0], 0.14, 0.00),
+    term([0, 1, 2, -2, 2, 0, 0, 0], -0.14, 0.00),
+    term([1, 0, 0, -2, -1, 0, 0, 0], 0.14, 0.00),
+    term([0, 0, 4, -2, 4, 0, 0, 0], 0.13, 0.00),
+    term([0, 0, 2, -2, 4, 0, 0, 0], -0.11, 0.00),
+    term([1, 0, -2, 0, -3, 0, 0, 0], 0.11, 0.00),
+    term([1, 0, -2, 0, -1, 0, 0, 0], 0.11, 0.00),
+];
+
+const COMPLEMENTARY_TERMS_1: [ComplementaryTerm; 1] = [term([0, 0, 0, 0, 1, 0, 0, 0], -0.87, 0.00)];
+
+/// Computes the IAU 2006 mean obliquity of the ecliptic in radians.
+fn mean_obliquity(centuries_since_j2000_tt: JulianCenturies) -> Radians {
+    arcsec_to_rad(fast_polynomial::poly_array(
+        centuries_since_j2000_tt,
+        &[
+            84381.406,
+            -46.836769,
+            -0.0001831,
+            0.0020034,
+            -0.000000576,
+            -0.0000000434,
+        ],
+    ))
+}
+
+/// Computes the IAU 2006/2000A bias-precession-nutation matrix from the Fukushima-Williams
+/// angles.
+fn npb_matrix(centuries_since_j2000_tt: JulianCenturies) -> DMat3 {
+    let t = centuries_since_j2000_tt;
+    let gamb = arcsec_to_rad(fast_polynomial::poly_array(
+        t,
+        &[
+            -0.052928,
+            10.556378,
+            0.4932044,
+            -0.00031238,
+            -0.000002788,
+            0.0000000260,
+        ],
+    ));
+    let phib = arcsec_to_rad(fast_polynomial::poly_array(
+        t,
+        &[
+            84381.412819,
+            -46.811016,
+            0.0511268,
+            0.00053289,
+            -0.00000044,
+            -0.0000000176,
+        ],
+    ));
+    let psib = arcsec_to_rad(fast_polynomial::poly_array(
+        t,
+        &[
+            -0.041775,
+            5038.481484,
+            1.5584175,
+            -0.00018522,
+            -0.000026452,
+            -0.0000000148,
+        ],
+    ));
+    let epsa = mean_obliquity(t);
+    let nutation = nutation_iau2006a(t);
+    let psi = psib + nutation.longitude;
+    let eps = epsa + nutation.obliquity;
+
+    // The signs of all angles are reversed relative to ERFA, which uses left-handed
+    // coordinates, whereas glam is right-handed.
+    DMat3::from_rotation_x(eps)
+        * DMat3::from_rotation_z(psi)
+        * DMat3::from_rotation_x(-phib)
+        * DMat3::from_rotation_z(-gamb)
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    use super::*;
+
+    // MJD 53736.0 in both UT1 and TT
+    const DAYS: Days = 53736.0 - 51544.5;
+    const CENTURIES: JulianCenturies = DAYS / 36525.0;
+
+    // One microsecond of time in radians
+    const MICROSECOND: Radians = 7.27220521664304e-11;
+
+    // The reference values are taken from the ERFA test suite
+    #[test]
+    fn test_gmst() {
+        let expected = 1.754174971870091;
+        assert_float_eq!(gmst(DAYS, CENTURIES), expected, abs <= MICROSECOND);
+    }
+
+    #[test]
+    fn test_gast() {
+        let expected = 1.754166137675019;
+        assert_float_eq!(gast(DAYS, CENTURIES), expected, abs <= MICROSECOND);
+    }
+
+    #[test]
+    fn test_equation_of_origins() {
+        let expected = -1.332882371941834e-3;
+        assert_float_eq!(equation_of_origins(CENTURIES), expected, abs <= 1e-15);
+    }
+
+    #[test]
+    fn test_equation_of_equinoxes() {
+        // ERFA computes the rigorous difference between GAST and GMST, which agrees with the
+        // classical series to within 0.2 µas
+        let expected = -8.83419507204379e-6;
+        assert_float_eq!(equation_of_equinoxes(CENTURIES), expected, abs <= 1e-12);
+    }
+
+    #[test]
+    fn test_equation_of_equinoxes_complementary_terms() {
+        let expected = 2.046085004885125e-9;
+        assert_float_eq!(
+            equation_of_equinoxes_complementary_terms(CENTURIES),
+            expected,
+            abs <= 1e-20
+        );
+    }
+
+    #[test]
+    fn test_gast_equinox_and_cio_based() {
+        // GAST from GMST and the equation of the equinoxes agrees with GAST from the Earth
+        // Rotation Angle and the equation of origins
+        for days in [-36525.0, -0.5, 0.0, DAYS, 9000.7] {
+            let centuries = days / 36525.0;
+            let equinox_based = gmst(days, centuries) + equation_of_equinoxes(centuries);
+            let cio_based = gast(days, centuries);
+            assert_float_eq!(mod_two_pi(equinox_based), cio_based, abs <= 1e-11);
+        }
+    }
+
+    #[test]
+    fn test_sidereal_time_range() {
+        for days in [-36525.0, -0.5, 0.0, 0.3, 1.0, 365.25, 9000.7] {
+            let centuries = days / 36525.0;
+            for angle in [gmst(days, centuries), gast(days, centuries)] {
+                assert!((0.0..std::f64::consts::TAU).contains(&angle));
+            }
+        }
+    }
+}
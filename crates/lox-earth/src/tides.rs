@@ -19,7 +19,14 @@ use lox_math::types::units::{Arcseconds, Microarcseconds, Radians, Seconds};
 
 use crate::tides::constants::{LUNI_SOLAR_TIDAL_TERMS, OCEANIC_TIDAL_TERMS};
 
+pub use crate::tides::pole::{
+    OceanPoleTideCoefficients, mean_pole, ocean_pole_tide_displacement, pole_tide_displacement,
+};
+pub use crate::tides::solid::solid_earth_tide_displacement;
+
 mod constants;
+mod pole;
+mod solid;
 
 #[derive(Clone, Copy, Debug, Error, PartialEq)]
 #[error(
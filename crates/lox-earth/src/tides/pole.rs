@@ -0,0 +1,230 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::f64::consts::PI;
+
+use glam::DVec3;
+
+use lox_math::math::RADIANS_IN_ARCSECOND;
+use lox_math::types::units::Arcseconds;
+use lox_time::utc::Utc;
+
+use crate::tides::solid::{MILLIMETRES_TO_KM, Station};
+
+/// Returns the coordinates x̄ₚ and ȳₚ of the IERS mean pole at `time` in arcseconds.
+///
+/// The mean pole is modelled by a cubic polynomial until 2010.0 and by a linear polynomial
+/// afterwards.
+///
+/// IERS Conventions (2010), Section 7.1.4, Table 7.7
+pub fn mean_pole(time: Utc) -> (Arcseconds, Arcseconds) {
+    let t = time.to_time().julian_epoch() - 2000.0;
+    let (x, y) = if t < 10.0 {
+        (
+            fast_polynomial::poly_array(t, &[55.974, 1.8243, 0.18413, 0.007024]),
+            fast_polynomial::poly_array(t, &[346.346, 1.7896, -0.10729, -0.000908]),
+        )
+    } else {
+        (
+            fast_polynomial::poly_array(t, &[23.513, 7.6141]),
+            fast_polynomial::poly_array(t, &[358.891, -0.6287]),
+        )
+    };
+    // Milliarcseconds to arcseconds
+    (x * 1e-3, y * 1e-3)
+}
+
+/// Returns the displacement in km of the Earth-fixed `station` due to the solid Earth pole tide,
+/// i.e. the deformation caused by the centrifugal effect of the polar motion `x_p` and `y_p` in
+/// arcseconds relative to the [mean_pole] at `time`.
+///
+/// The station position is in km. The loading of the ocean pole tide is given by
+/// [ocean_pole_tide_displacement].
+///
+/// IERS Conventions (2010), Section 7.1.4, Eq. 7.26
+pub fn pole_tide_displacement(
+    station: DVec3,
+    x_p: Arcseconds,
+    y_p: Arcseconds,
+    time: Utc,
+) -> DVec3 {
+    let (x_mean, y_mean) = mean_pole(time);
+    let m1 = x_p - x_mean;
+    let m2 = -(y_p - y_mean);
+
+    let station = Station::new(station);
+    // The latitude is the complement of the colatitude θ of the conventions
+    let sin_2theta = 2.0 * station.sin_lat * station.cos_lat;
+    let cos_2theta = station.sin_lat.powi(2) - station.cos_lat.powi(2);
+    let cos_theta = station.sin_lat;
+    let (sin_lon, cos_lon) = (station.sin_lon, station.cos_lon);
+
+    let radial = -33.0 * sin_2theta * (m1 * cos_lon + m2 * sin_lon);
+    let south = -9.0 * cos_2theta * (m1 * cos_lon + m2 * sin_lon);
+    let east = 9.0 * cos_theta * (m1 * sin_lon - m2 * cos_lon);
+    station.local_to_cartesian(radial, east, -south) * MILLIMETRES_TO_KM
+}
+
+/// Real part of γ₂ = 1 + k₂ − h₂ for the ocean pole tide.
+const GAMMA2_REAL: f64 = 0.6870;
+
+/// Imaginary part of γ₂ = 1 + k₂ − h₂ for the ocean pole tide.
+const GAMMA2_IMAG: f64 = 0.0036;
+
+/// Mean angular velocity of the Earth in rad/s.
+const ANGULAR_VELOCITY: f64 = 7.292115e-5;
+
+/// Equatorial radius of the Earth in m.
+const EQUATORIAL_RADIUS: f64 = 6378136.6;
+
+/// Geocentric gravitational constant in m³/s².
+const GM: f64 = 3.986004418e14;
+
+/// Constant of gravitation in m³/(kg s²).
+const G: f64 = 6.67428e-11;
+
+/// Mean equatorial gravity in m/s².
+const EQUATORIAL_GRAVITY: f64 = 9.7803278;
+
+/// Density of sea water in kg/m³.
+const SEA_WATER_DENSITY: f64 = 1025.0;
+
+/// The ocean pole load tide coefficients of Desai (2002) at a station, i.e. the real and
+/// imaginary parts of the displacement per unit of wobble as radial, north, and east components.
+///
+/// The IERS provides the coefficients on a 0.5° × 0.5° grid in the file
+/// `opoleloadcoefcmcor.txt`, which are interpolated to the location of the station.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct OceanPoleTideCoefficients {
+    pub real: DVec3,
+    pub imaginary: DVec3,
+}
+
+/// Returns the displacement in km of the Earth-fixed `station` due to the loading of the ocean
+/// pole tide, i.e. the response of the oceans to the centrifugal effect of the polar motion `x_p`
+/// and `y_p` in arcseconds relative to the [mean_pole] at `time`.
+///
+/// The station position is in km and `coefficients` are the Desai (2002) coefficients at its
+/// location. The solid Earth pole tide is given by [pole_tide_displacement].
+///
+/// IERS Conventions (2010), Section 7.1.5, Eq. 7.29
+pub fn ocean_pole_tide_displacement(
+    station: DVec3,
+    coefficients: &OceanPoleTideCoefficients,
+    x_p: Arcseconds,
+    y_p: Arcseconds,
+    time: Utc,
+) -> DVec3 {
+    let (x_mean, y_mean) = mean_pole(time);
+    let m1 = (x_p - x_mean) * RADIANS_IN_ARCSECOND;
+    let m2 = -(y_p - y_mean) * RADIANS_IN_ARCSECOND;
+
+    let h_p = (8.0 * PI / 15.0).sqrt() * ANGULAR_VELOCITY.powi(2) * EQUATORIAL_RADIUS.powi(4) / GM;
+    let k = 4.0 * PI * G * EQUATORIAL_RADIUS * SEA_WATER_DENSITY * h_p / (3.0 * EQUATORIAL_GRAVITY);
+
+    // Radial, north, and east displacement in m
+    let displacement = k
+        * ((m1 * GAMMA2_REAL + m2 * GAMMA2_IMAG) * coefficients.real
+            + (m2 * GAMMA2_REAL - m1 * GAMMA2_IMAG) * coefficients.imaginary);
+    let station = Station::new(station);
+    station.local_to_cartesian(displacement.x, displacement.z, displacement.y) * 1e-3
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    use float_eq::assert_float_eq;
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+    use lox_time::utc;
+
+    use super::*;
+
+    #[test]
+    fn test_mean_pole() {
+        let (x, y) = mean_pole(utc!(2000, 1, 1, 12).unwrap());
+        assert_float_eq!(x, 0.055974, abs <= 1e-8);
+        assert_float_eq!(y, 0.346346, abs <= 1e-8);
+
+        let (x, y) = mean_pole(utc!(2015, 1, 1).unwrap());
+        assert_float_eq!(x, 0.13771929687724985, abs <= 1e-12);
+        assert_float_eq!(y, 0.34946092962441694, abs <= 1e-12);
+    }
+
+    #[test]
+    fn test_mean_pole_continuity() {
+        let before = mean_pole(utc!(2009, 12, 31, 23, 59, 0.0).unwrap());
+        let after = mean_pole(utc!(2010, 1, 1, 0, 1, 0.0).unwrap());
+        // The rounded coefficients leave a gap of about 1 µas at 2010.0
+        assert_float_eq!(before.0, after.0, abs <= 1e-5);
+        assert_float_eq!(before.1, after.1, abs <= 1e-5);
+    }
+
+    #[test]
+    fn test_pole_tide_displacement() {
+        let time = utc!(2015, 1, 1).unwrap();
+        let (x_mean, y_mean) = mean_pole(time);
+        // A station at 45° latitude on the prime meridian with m1 = 0.1" and m2 = 0.2"
+        let station = DVec3::new(FRAC_1_SQRT_2, 0.0, FRAC_1_SQRT_2) * 6378.0;
+        let displacement = pole_tide_displacement(station, x_mean + 0.1, y_mean - 0.2, time);
+        let radial = -3.3 * MILLIMETRES_TO_KM;
+        let east = -9.0 * FRAC_1_SQRT_2 * 0.2 * MILLIMETRES_TO_KM;
+        let expected = DVec3::new(radial * FRAC_1_SQRT_2, east, radial * FRAC_1_SQRT_2);
+        assert_close!(displacement, expected, 1e-12, 0.0);
+    }
+
+    #[test]
+    fn test_ocean_pole_tide_displacement() {
+        let time = utc!(2015, 1, 1).unwrap();
+        let (x_mean, y_mean) = mean_pole(time);
+        // A station on the equator and the prime meridian with m1 = 0.3" and m2 = 0.4"
+        let station = DVec3::new(6378.0, 0.0, 0.0);
+        let coefficients = OceanPoleTideCoefficients {
+            real: DVec3::new(0.02, -0.01, 0.005),
+            imaginary: DVec3::new(-0.003, 0.004, 0.001),
+        };
+        let displacement =
+            ocean_pole_tide_displacement(station, &coefficients, x_mean + 0.3, y_mean - 0.4, time);
+        // K in m from the constants of the conventions
+        let k = 5340.4286;
+        let (m1, m2) = (0.3 * RADIANS_IN_ARCSECOND, 0.4 * RADIANS_IN_ARCSECOND);
+        let local = k
+            * ((0.6870 * m1 + 0.0036 * m2) * coefficients.real
+                + (0.6870 * m2 - 0.0036 * m1) * coefficients.imaginary);
+        // Radial is along x, east along y, and north along z
+        let expected = DVec3::new(local.x, local.z, local.y) * 1e-3;
+        assert_close!(displacement, expected, 1e-12, 0.0);
+    }
+
+    #[test]
+    fn test_ocean_pole_tide_displacement_mean_pole() {
+        let time = utc!(2015, 1, 1).unwrap();
+        let (x_mean, y_mean) = mean_pole(time);
+        let station = DVec3::new(4075.578385, 931.852890, 4801.570154);
+        let coefficients = OceanPoleTideCoefficients {
+            real: DVec3::new(0.02, -0.01, 0.005),
+            imaginary: DVec3::new(-0.003, 0.004, 0.001),
+        };
+        assert_eq!(
+            ocean_pole_tide_displacement(station, &coefficients, x_mean, y_mean, time),
+            DVec3::ZERO
+        );
+    }
+
+    #[test]
+    fn test_pole_tide_displacement_mean_pole() {
+        let time = utc!(2015, 1, 1).unwrap();
+        let (x_mean, y_mean) = mean_pole(time);
+        let station = DVec3::new(4075.578385, 931.852890, 4801.570154);
+        assert_eq!(
+            pole_tide_displacement(station, x_mean, y_mean, time),
+            DVec3::ZERO
+        );
+    }
+}
@@ -0,0 +1,438 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use glam::DVec3;
+
+use lox_math::types::units::JulianCenturies;
+use lox_time::julian_dates::JulianDate;
+use lox_time::time_of_day::CivilTime;
+use lox_time::time_scales::Tt;
+use lox_time::utc::Utc;
+
+/// Nominal degree-2 Love number h₂⁽⁰⁾.
+const H20: f64 = 0.6078;
+
+/// Nominal degree-2 Shida number l₂⁽⁰⁾.
+const L20: f64 = 0.0847;
+
+/// Degree-3 Love number h₃.
+const H3: f64 = 0.292;
+
+/// Degree-3 Shida number l₃.
+const L3: f64 = 0.015;
+
+/// Imaginary part of h₂ in the diurnal band.
+const H2_IMAG_DIURNAL: f64 = -0.0025;
+
+/// Imaginary part of h₂ in the semi-diurnal band.
+const H2_IMAG_SEMI_DIURNAL: f64 = -0.0022;
+
+/// Imaginary part of l₂ in the diurnal and semi-diurnal bands.
+const L2_IMAG: f64 = -0.0007;
+
+/// Latitude-dependent Shida number l⁽¹⁾ in the diurnal band.
+const L1_DIURNAL: f64 = 0.0012;
+
+/// Latitude-dependent Shida number l⁽¹⁾ in the semi-diurnal band.
+const L1_SEMI_DIURNAL: f64 = 0.0024;
+
+/// Ratio of the masses of the Sun and the Earth.
+const MASS_RATIO_SUN: f64 = 332946.0482;
+
+/// Ratio of the masses of the Moon and the Earth.
+const MASS_RATIO_MOON: f64 = 0.0123000371;
+
+/// Equatorial radius of the Earth in km.
+const EQUATORIAL_RADIUS: f64 = 6378.1366;
+
+/// Returns the displacement in km of the Earth-fixed `station` due to the solid Earth tides raised
+/// by the Sun and the Moon.
+///
+/// The positions of the `station`, the `sun`, and the `moon` are in km and must be expressed in the
+/// same Earth-fixed frame, e.g. ITRF. The model includes the in-phase and out-of-phase degree-2
+/// and degree-3 tides, the latitude dependence of the Love and Shida numbers, and the frequency
+/// dependent corrections in the diurnal and long-period bands. The permanent tide is not removed,
+/// i.e. the displacement refers to the conventional tide-free station position.
+///
+/// IERS Conventions (2010), Section 7.1.1 and the reference implementation `DEHANTTIDEINEL`
+pub fn solid_earth_tide_displacement(station: DVec3, sun: DVec3, moon: DVec3, time: Utc) -> DVec3 {
+    let station = Station::new(station);
+    let sun = TideRaisingBody::new(sun, MASS_RATIO_SUN);
+    let moon = TideRaisingBody::new(moon, MASS_RATIO_MOON);
+
+    let hours = time.hour() as f64 + time.minute() as f64 / 60.0 + time.decimal_seconds() / 3600.0;
+    let args = DoodsonArguments::new(time.to_scale(Tt).centuries_since_j2000(), hours);
+
+    let mut displacement = DVec3::ZERO;
+    for body in [&sun, &moon] {
+        displacement += station.in_phase(body) + station.step1_corrections(body);
+    }
+    displacement + station.diurnal_corrections(&args) + station.long_period_corrections(&args)
+}
+
+struct TideRaisingBody {
+    position: DVec3,
+    distance: f64,
+    /// The degree-2 scale factor of the tidal displacement in km.
+    factor: f64,
+}
+
+impl TideRaisingBody {
+    fn new(position: DVec3, mass_ratio: f64) -> Self {
+        let distance = position.length();
+        Self {
+            position,
+            distance,
+            factor: mass_ratio * EQUATORIAL_RADIUS * (EQUATORIAL_RADIUS / distance).powi(3),
+        }
+    }
+}
+
+pub(super) struct Station {
+    position: DVec3,
+    distance: f64,
+    pub(super) sin_lat: f64,
+    pub(super) cos_lat: f64,
+    pub(super) sin_lon: f64,
+    pub(super) cos_lon: f64,
+}
+
+impl Station {
+    pub(super) fn new(position: DVec3) -> Self {
+        let distance = position.length();
+        let rho = position.x.hypot(position.y);
+        let (sin_lon, cos_lon) = if rho == 0.0 {
+            (0.0, 1.0)
+        } else {
+            (position.y / rho, position.x / rho)
+        };
+        Self {
+            position,
+            distance,
+            sin_lat: position.z / distance,
+            cos_lat: rho / distance,
+            sin_lon,
+            cos_lon,
+        }
+    }
+
+    /// Converts the radial, eastward, and northward displacement components to Cartesian
+    /// coordinates.
+    pub(super) fn local_to_cartesian(&self, radial: f64, east: f64, north: f64) -> DVec3 {
+        DVec3::new(
+            radial * self.cos_lon * self.cos_lat
+                - east * self.sin_lon
+                - north * self.sin_lat * self.cos_lon,
+            radial * self.sin_lon * self.cos_lat + east * self.cos_lon
+                - north * self.sin_lat * self.sin_lon,
+            radial * self.sin_lat + north * self.cos_lat,
+        )
+    }
+
+    /// Returns the in-phase degree-2 and degree-3 displacement with the nominal Love and Shida
+    /// numbers, including the latitude dependence of the degree-2 numbers.
+    fn in_phase(&self, body: &TideRaisingBody) -> DVec3 {
+        let body_unit = body.position / body.distance;
+        let station_unit = self.position / self.distance;
+        let cos_psi = station_unit.dot(body_unit);
+
+        let p20 = 1.0 - 1.5 * self.cos_lat.powi(2);
+        let h2 = H20 - 0.0006 * p20;
+        let l2 = L20 + 0.0002 * p20;
+
+        let p2 = 3.0 * (h2 / 2.0 - l2) * cos_psi.powi(2) - h2 / 2.0;
+        let p3 = 2.5 * (H3 - 3.0 * L3) * cos_psi.powi(3) + 1.5 * (L3 - H3) * cos_psi;
+        let x2 = 3.0 * l2 * cos_psi;
+        let x3 = 1.5 * L3 * (5.0 * cos_psi.powi(2) - 1.0);
+
+        let factor3 = body.factor * EQUATORIAL_RADIUS / body.distance;
+        body.factor * (x2 * body_unit + p2 * station_unit)
+            + factor3 * (x3 * body_unit + p3 * station_unit)
+    }
+
+    /// Returns the corrections for the out-of-phase parts of the degree-2 Love and Shida numbers
+    /// and for the latitude dependence of the Shida number l⁽¹⁾ in the diurnal and semi-diurnal
+    /// bands.
+    fn step1_corrections(&self, body: &TideRaisingBody) -> DVec3 {
+        let DVec3 { x, y, z } = body.position;
+        let scale = body.factor / body.distance.powi(2);
+        let (sin_lat, cos_lat) = (self.sin_lat, self.cos_lat);
+        let (sin_lon, cos_lon) = (self.sin_lon, self.cos_lon);
+        let cos_2lat = cos_lat.powi(2) - sin_lat.powi(2);
+        let cos_2lon = cos_lon.powi(2) - sin_lon.powi(2);
+        let sin_2lon = 2.0 * cos_lon * sin_lon;
+
+        // Diurnal band
+        let a = scale * z * (x * sin_lon - y * cos_lon);
+        let b = scale * z * (x * cos_lon + y * sin_lon);
+        let mut radial = -3.0 * H2_IMAG_DIURNAL * sin_lat * cos_lat * a;
+        let mut north = -3.0 * L2_IMAG * cos_2lat * a;
+        let mut east = -3.0 * L2_IMAG * sin_lat * b;
+        north -= 3.0 * L1_DIURNAL * sin_lat.powi(2) * b;
+        east += 3.0 * L1_DIURNAL * sin_lat * cos_2lat * a;
+
+        // Semi-diurnal band
+        let c = scale * ((x.powi(2) - y.powi(2)) * sin_2lon - 2.0 * x * y * cos_2lon);
+        let d = scale * ((x.powi(2) - y.powi(2)) * cos_2lon + 2.0 * x * y * sin_2lon);
+        radial -= 0.75 * H2_IMAG_SEMI_DIURNAL * cos_lat.powi(2) * c;
+        north += 1.5 * L2_IMAG * sin_lat * cos_lat * c;
+        east -= 1.5 * L2_IMAG * cos_lat * d;
+        north -= 1.5 * L1_SEMI_DIURNAL * sin_lat * cos_lat * d;
+        east -= 1.5 * L1_SEMI_DIURNAL * sin_lat.powi(2) * cos_lat * c;
+
+        self.local_to_cartesian(radial, east, north)
+    }
+
+    /// Returns the corrections for the frequency dependence of the Love and Shida numbers in the
+    /// diurnal band.
+    ///
+    /// IERS Conventions (2010), Table 7.3a
+    fn diurnal_corrections(&self, args: &DoodsonArguments) -> DVec3 {
+        let lon = self.sin_lon.atan2(self.cos_lon);
+        let sin_2lat = 2.0 * self.sin_lat * self.cos_lat;
+        let cos_2lat = self.cos_lat.powi(2) - self.sin_lat.powi(2);
+        DIURNAL_TERMS
+            .iter()
+            .map(|term| {
+                let theta = (args.tau + args.dot(&term.multipliers)).to_radians();
+                let (sin, cos) = (theta + lon).sin_cos();
+                let (r_ip, r_op) = term.radial;
+                let (t_ip, t_op) = term.transverse;
+                let radial = sin_2lat * (r_ip * sin + r_op * cos);
+                let north = cos_2lat * (t_ip * sin + t_op * cos);
+                let east = self.sin_lat * (t_ip * cos - t_op * sin);
+                self.local_to_cartesian(radial, east, north)
+            })
+            .sum::<DVec3>()
+            * MILLIMETRES_TO_KM
+    }
+
+    /// Returns the corrections for the frequency dependence of the Love and Shida numbers in the
+    /// long-period band.
+    ///
+    /// IERS Conventions (2010), Table 7.3b
+    fn long_period_corrections(&self, args: &DoodsonArguments) -> DVec3 {
+        let p20 = 1.5 * self.sin_lat.powi(2) - 0.5;
+        let sin_2lat = 2.0 * self.sin_lat * self.cos_lat;
+        LONG_PERIOD_TERMS
+            .iter()
+            .map(|term| {
+                let (sin, cos) = args.dot(&term.multipliers).to_radians().sin_cos();
+                let (r_ip, r_op) = term.radial;
+                let (t_ip, t_op) = term.transverse;
+                let radial = p20 * (r_ip * cos + r_op * sin);
+                let north = sin_2lat * (t_ip * cos + t_op * sin);
+                self.local_to_cartesian(radial, 0.0, north)
+            })
+            .sum::<DVec3>()
+            * MILLIMETRES_TO_KM
+    }
+}
+
+pub(super) const MILLIMETRES_TO_KM: f64 = 1e-6;
+
+/// The fundamental arguments of the tidal constituents in degrees, i.e. the mean lunar time τ, the
+/// mean longitudes of the Moon s, the Sun h, the lunar perigee p, the negative of the longitude
+/// of the lunar ascending node N', and the solar perigee ps.
+struct DoodsonArguments {
+    tau: f64,
+    s: f64,
+    h: f64,
+    p: f64,
+    n: f64,
+    ps: f64,
+}
+
+impl DoodsonArguments {
+    fn new(t: JulianCenturies, hours_utc: f64) -> Self {
+        let s = fast_polynomial::poly_array(
+            t,
+            &[218.31664563, 481267.88194, -0.0014663889, 0.00000185139],
+        );
+        let tau = fast_polynomial::poly_array(
+            t,
+            &[280.4606184, 36000.7700536, 0.00038793, -0.0000000258],
+        ) + hours_utc * 15.0
+            - s;
+        let precession = fast_polynomial::poly_array(
+            t,
+            &[0.0, 1.396971278, 0.000308889, 0.000000021, 0.000000007],
+        );
+        let s = s + precession;
+        let h = fast_polynomial::poly_array(
+            t,
+            &[
+                280.46645,
+                36000.7697489,
+                0.00030322222,
+                0.000000020,
+                -0.00000000654,
+            ],
+        );
+        let p = fast_polynomial::poly_array(
+            t,
+            &[
+                83.35324312,
+                4069.01363525,
+                -0.01032172222,
+                -0.0000124991,
+                0.00000005263,
+            ],
+        );
+        let n = fast_polynomial::poly_array(
+            t,
+            &[
+                234.95544499,
+                1934.13626197,
+                -0.00207561111,
+                -0.00000213944,
+                0.00000001650,
+            ],
+        );
+        let ps = fast_polynomial::poly_array(
+            t,
+            &[
+                282.93734098,
+                1.71945766667,
+                0.00045688889,
+                -0.00000001778,
+                -0.00000000334,
+            ],
+        );
+        Self {
+            tau: tau % 360.0,
+            s: s % 360.0,
+            h: h % 360.0,
+            p: p % 360.0,
+            n: n % 360.0,
+            ps: ps % 360.0,
+        }
+    }
+
+    /// Returns the linear combination of s, h, p, N', and ps with `multipliers`.
+    fn dot(&self, multipliers: &[f64; 5]) -> f64 {
+        multipliers[0] * self.s
+            + multipliers[1] * self.h
+            + multipliers[2] * self.p
+            + multipliers[3] * self.n
+            + multipliers[4] * self.ps
+    }
+}
+
+struct FrequencyDependenceTerm {
+    /// Multipliers of the fundamental arguments s, h, p, N', and ps.
+    multipliers: [f64; 5],
+    /// In-phase and out-of-phase radial corrections in mm.
+    radial: (f64, f64),
+    /// In-phase and out-of-phase transverse corrections in mm.
+    transverse: (f64, f64),
+}
+
+const fn term(
+    multipliers: [f64; 5],
+    radial: (f64, f64),
+    transverse: (f64, f64),
+) -> FrequencyDependenceTerm {
+    FrequencyDependenceTerm {
+        multipliers,
+        radial,
+        transverse,
+    }
+}
+
+/// The diurnal terms of Table 7.3a in the order and with the values of `DEHANTTIDEINEL`.
+const DIURNAL_TERMS: [FrequencyDependenceTerm; 31] = [
+    term([-3.0, 0.0, 2.0, 0.0, 0.0], (-0.01, 0.0), (0.0, 0.0)),
+    term([-3.0, 2.0, 0.0, 0.0, 0.0], (-0.01, 0.0), (0.0, 0.0)),
+    term([-2.0, 0.0, 1.0, -1.0, 0.0], (-0.02, 0.0), (0.0, 0.0)),
+    term([-2.0, 0.0, 1.0, 0.0, 0.0], (-0.08, 0.0), (-0.01, 0.01)),
+    term([-2.0, 2.0, -1.0, 0.0, 0.0], (-0.02, 0.0), (0.0, 0.0)),
+    term([-1.0, 0.0, 0.0, -1.0, 0.0], (-0.10, 0.0), (0.0, 0.0)),
+    term([-1.0, 0.0, 0.0, 0.0, 0.0], (-0.51, 0.0), (-0.02, 0.03)),
+    term([-1.0, 2.0, 0.0, 0.0, 0.0], (0.01, 0.0), (0.0, 0.0)),
+    term([0.0, -2.0, 1.0, 0.0, 0.0], (0.01, 0.0), (0.0, 0.0)),
+    term([0.0, 0.0, -1.0, 0.0, 0.0], (0.02, 0.0), (0.0, 0.0)),
+    term([0.0, 0.0, 1.0, 0.0, 0.0], (0.06, 0.0), (0.0, 0.0)),
+    term([0.0, 0.0, 1.0, 1.0, 0.0], (0.01, 0.0), (0.0, 0.0)),
+    term([0.0, 2.0, -1.0, 0.0, 0.0], (0.01, 0.0), (0.0, 0.0)),
+    term([1.0, -3.0, 0.0, 0.0, 1.0], (-0.06, 0.0), (0.0, 0.0)),
+    term([1.0, -2.0, 0.0, -1.0, 0.0], (0.01, 0.0), (0.0, 0.0)),
+    term([1.0, -2.0, 0.0, 0.0, 0.0], (-1.23, -0.07), (0.06, 0.01)),
+    term([1.0, -1.0, 0.0, 0.0, -1.0], (0.02, 0.0), (0.0, 0.0)),
+    term([1.0, -1.0, 0.0, 0.0, 1.0], (0.04, 0.0), (0.0, 0.0)),
+    term([1.0, 0.0, 0.0, -1.0, 0.0], (-0.22, 0.01), (0.01, 0.0)),
+    term([1.0, 0.0, 0.0, 0.0, 0.0], (12.00, -0.80), (-0.67, -0.03)),
+    term([1.0, 0.0, 0.0, 1.0, 0.0], (1.73, -0.12), (-0.10, 0.0)),
+    term([1.0, 0.0, 0.0, 2.0, 0.0], (-0.04, 0.0), (0.0, 0.0)),
+    term([1.0, 1.0, 0.0, 0.0, -1.0], (-0.50, -0.01), (0.03, 0.0)),
+    term([1.0, 1.0, 0.0, 0.0, 1.0], (0.01, 0.0), (0.0, 0.0)),
+    term([0.0, 1.0, 0.0, 1.0, -1.0], (-0.01, 0.0), (0.0, 0.0)),
+    term([1.0, 2.0, -2.0, 0.0, 0.0], (-0.01, 0.0), (0.0, 0.0)),
+    term([1.0, 2.0, 0.0, 0.0, 0.0], (-0.11, 0.01), (0.01, 0.0)),
+    term([2.0, -2.0, 1.0, 0.0, 0.0], (-0.01, 0.0), (0.0, 0.0)),
+    term([2.0, 0.0, -1.0, 0.0, 0.0], (-0.02, 0.0), (0.0, 0.0)),
+    term([3.0, 0.0, 0.0, 0.0, 0.0], (0.0, 0.0), (0.0, 0.0)),
+    term([3.0, 0.0, 0.0, 1.0, 0.0], (0.0, 0.0), (0.0, 0.0)),
+];
+
+/// The long-period terms of Table 7.3b.
+const LONG_PERIOD_TERMS: [FrequencyDependenceTerm; 5] = [
+    term([0.0, 0.0, 0.0, 1.0, 0.0], (0.47, 0.16), (0.23, 0.07)),
+    term([0.0, 2.0, 0.0, 0.0, 0.0], (-0.20, -0.11), (-0.12, -0.05)),
+    term([1.0, 0.0, -1.0, 0.0, 0.0], (-0.11, -0.09), (-0.08, -0.04)),
+    term([2.0, 0.0, 0.0, 0.0, 0.0], (-0.13, -0.15), (-0.11, -0.07)),
+    term([2.0, 0.0, 0.0, 1.0, 0.0], (-0.05, -0.06), (-0.05, -0.03)),
+];
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use lox_time::utc;
+    use rstest::rstest;
+
+    use super::*;
+
+    /// The test cases of the IERS reference implementation `DEHANTTIDEINEL` with positions and
+    /// displacements in metres.
+    #[rstest]
+    #[case(
+        [4075578.385, 931852.890, 4801570.154],
+        [137859926952.015, 54228127881.4350, 23509422341.6960],
+        [-179996231.920342, -312468450.131567, -169288918.592160],
+        utc!(2009, 4, 13).unwrap(),
+        [0.07700420357108126, 0.06304056321824968, 0.05516568152597247],
+    )]
+    #[case(
+        [1112189.660, -4842955.026, 3985352.284],
+        [-54537460436.2357, 130244288385.279, 56463429031.5996],
+        [300396716.912, 243238281.451, 120548075.939],
+        utc!(2012, 7, 13).unwrap(),
+        [-0.02036831479592076, 0.056582547762259724, -0.07597679676871742],
+    )]
+    #[case(
+        [1112200.5696, -4842957.8511, 3985345.9122],
+        [100210282451.6279, 103055630398.316, 56855096480.4475],
+        [369817604.4348, 1897917.5258, 120804980.8284],
+        utc!(2015, 7, 15).unwrap(),
+        [0.0050957086917236384, 0.08286630259835287, -0.06366349254041896],
+    )]
+    fn test_solid_earth_tide_displacement(
+        #[case] station: [f64; 3],
+        #[case] sun: [f64; 3],
+        #[case] moon: [f64; 3],
+        #[case] time: Utc,
+        #[case] expected: [f64; 3],
+    ) {
+        let km = |r: [f64; 3]| DVec3::from_array(r) / 1000.0;
+        let displacement =
+            solid_earth_tide_displacement(km(station), km(sun), km(moon), time) * 1000.0;
+        for (act, exp) in displacement.to_array().into_iter().zip(expected) {
+            assert_float_eq!(act, exp, abs <= 1e-12);
+        }
+    }
+}
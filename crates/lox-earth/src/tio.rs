@@ -36,4 +36,12 @@ mod tests {
         let expected = -2.812961699849694e-8;
         assert_float_eq!(expected, sp_00(t), rel <= 1e-12);
     }
+
+    #[test]
+    fn test_sp_00_erfa() {
+        // MJD 52541.0 TT, reference value from the ERFA test suite for `eraSp00`
+        let t = (52541.0 - 51544.5) / 36525.0;
+        let expected = -6.216698469981019e-12;
+        assert_float_eq!(expected, sp_00(t), abs <= 1e-24);
+    }
 }
@@ -19,6 +19,54 @@ pub trait Ephemeris {
         origin: Body,
         target: Body,
     ) -> Result<(Position, Velocity), Self::Error>;
+
+    /// Returns the position of `target` with respect to the explicit `center`, e.g. the Sun with
+    /// respect to the Earth (399) rather than the solar-system barycentre (0).
+    ///
+    /// Unlike [Ephemeris::position], which requires a direct segment between both bodies, the
+    /// position is obtained by chaining the segments along the path through their common
+    /// barycentres as returned by [path_from_ids].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the segments along the path is not available, i.e. if the
+    /// position with respect to `center` cannot be derived from the loaded data.
+    fn position_wrt(
+        &self,
+        epoch: Epoch,
+        center: Body,
+        target: Body,
+    ) -> Result<Position, Self::Error> {
+        let mut position = (0.0, 0.0, 0.0);
+        for ids in path_from_ids(center, target).windows(2) {
+            let (x, y, z) = self.position(epoch, ids[0], ids[1])?;
+            position = (position.0 + x, position.1 + y, position.2 + z);
+        }
+        Ok(position)
+    }
+
+    /// Returns the state of `target` with respect to the explicit `center` by chaining the
+    /// segments along the path through their common barycentres.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the state with respect to `center` cannot be derived from the loaded
+    /// data. See [Ephemeris::position_wrt].
+    fn state_wrt(
+        &self,
+        epoch: Epoch,
+        center: Body,
+        target: Body,
+    ) -> Result<(Position, Velocity), Self::Error> {
+        let mut position = (0.0, 0.0, 0.0);
+        let mut velocity = (0.0, 0.0, 0.0);
+        for ids in path_from_ids(center, target).windows(2) {
+            let ((x, y, z), (vx, vy, vz)) = self.state(epoch, ids[0], ids[1])?;
+            position = (position.0 + x, position.1 + y, position.2 + z);
+            velocity = (velocity.0 + vx, velocity.1 + vy, velocity.2 + vz);
+        }
+        Ok((position, velocity))
+    }
 }
 
 fn ancestors(id: i32) -> Vec<i32> {
@@ -7,12 +7,13 @@
  */
 
 use std::collections::HashMap;
+use std::path::Path;
 
 use lox_math::types::julian_dates::Epoch;
 
 use crate::{Body, Ephemeris, Position, Velocity};
 
-use super::parser::{DafSpkError, Spk, SpkSegment, SpkType2Array, SpkType2Coefficients};
+use super::parser::{DafSpkError, Spk, SpkArray, SpkSegment, parse_daf_spk};
 
 impl Spk {
     fn find_segment(
@@ -20,39 +21,37 @@ impl Spk {
         origin: Body,
         target: Body,
     ) -> Result<(&SpkSegment, isize), DafSpkError> {
-        let mut sign = 1;
-
-        let mut target = target;
-        let mut origin = origin;
-        if target < origin {
-            (origin, target) = (target, origin);
-            sign = -1;
-        }
-
         // An SPK file may contain any number of segments. A single file may contain overlapping segments:
         // segments containing data for the same body over a common interval. When this happens, the
         // latest segment in a file supersedes any competing segments earlier in the file.
-        let segment = self
-            .segments
-            .get(&origin)
-            .ok_or(DafSpkError::UnableToFindMatchingSegment)?
-            .get(&target)
-            .ok_or(DafSpkError::UnableToFindMatchingSegment)?
-            .last()
-            .ok_or(DafSpkError::UnableToFindMatchingSegment)?;
-
-        Ok((segment, sign))
+        let lookup = |center: Body, target: Body| {
+            self.segments
+                .get(&center)
+                .and_then(|targets| targets.get(&target))
+                .and_then(|segments| segments.last())
+        };
+
+        // Segments are stored with respect to their center, i.e. the state of the center relative
+        // to the target is obtained by reversing the sign
+        if let Some(segment) = lookup(origin, target) {
+            return Ok((segment, 1));
+        }
+        lookup(target, origin)
+            .map(|segment| (segment, -1))
+            .ok_or(DafSpkError::UnableToFindMatchingSegment)
     }
 
-    fn find_record<'a>(
+    fn find_record<'a, C>(
         &'a self,
-        array: &'a SpkType2Array,
+        records: &'a [Vec<C>],
+        intlen: u32,
+        n: u32,
         initial_epoch: Epoch,
         epoch: Epoch,
-    ) -> Result<(&'a Vec<SpkType2Coefficients>, f64), DafSpkError> {
+    ) -> Result<(&'a Vec<C>, f64), DafSpkError> {
         let seconds_from_record_start = epoch - initial_epoch;
 
-        let intlen = array.intlen as f64;
+        let intlen = intlen as f64;
         let mut record_number = (seconds_from_record_start / intlen).floor() as usize;
         let mut fraction = seconds_from_record_start % intlen;
 
@@ -60,13 +59,12 @@ impl Spk {
         // can safely take the end of the interval from the next record. But this implies
         // special handling of the last record, where there's no next record that we can
         // draw from.
-        if record_number == array.n as usize {
+        if record_number == n as usize {
             record_number -= 1;
-            fraction = array.intlen as f64;
+            fraction = intlen;
         }
 
-        let record = array
-            .records
+        let record = records
             .get(record_number)
             .ok_or(DafSpkError::UnableToFindMatchingRecord)?;
 
@@ -77,32 +75,52 @@ impl Spk {
         &self.segments
     }
 
-    fn get_chebyshev_polynomial<'a>(
-        &'a self,
-        epoch: Epoch,
-        segment: &'a SpkSegment,
-    ) -> Result<(Vec<f64>, &'a Vec<SpkType2Coefficients>), DafSpkError> {
-        let (coefficients, record) = match &segment.data {
-            super::parser::SpkArray::Type2(array) => {
-                let (record, fraction) = self.find_record(array, segment.initial_epoch, epoch)?;
+    /// Reads and parses the SPK file at `path`.
+    ///
+    /// Only segments of type 2 (Chebyshev position only) and type 3 (Chebyshev position and
+    /// velocity) are supported.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, DafSpkError> {
+        let contents = std::fs::read(path).map_err(|err| DafSpkError::Io(err.to_string()))?;
+        parse_daf_spk(&contents)
+    }
+}
 
-                let degree_of_polynomial = array.degree_of_polynomial() as usize;
-                let mut coefficients = Vec::<f64>::with_capacity(degree_of_polynomial);
+/// Evaluates the Chebyshev polynomials up to `degree` at the normalized time within the record.
+fn chebyshev_polynomials(fraction: f64, intlen: u32, degree: usize) -> Vec<f64> {
+    let mut polynomials = Vec::<f64>::with_capacity(degree);
 
-                coefficients.push(1f64);
-                coefficients.push(2f64 * fraction / array.intlen as f64 - 1f64);
+    polynomials.push(1f64);
+    polynomials.push(2f64 * fraction / intlen as f64 - 1f64);
 
-                for i in 2..degree_of_polynomial {
-                    coefficients
-                        .push(2f64 * coefficients[1] * coefficients[i - 1] - coefficients[i - 2]);
-                }
+    for i in 2..degree {
+        polynomials.push(2f64 * polynomials[1] * polynomials[i - 1] - polynomials[i - 2]);
+    }
 
-                (coefficients, record)
-            }
-        };
+    polynomials
+}
+
+/// Evaluates the time derivatives of the Chebyshev polynomials up to `degree`.
+fn chebyshev_derivatives(polynomials: &[f64], intlen: u32, degree: usize) -> Vec<f64> {
+    let mut derivatives = Vec::<f64>::with_capacity(degree);
+
+    derivatives.push(0f64);
+    derivatives.push(1f64);
+
+    if degree > 2 {
+        derivatives.push(4f64 * polynomials[1]);
+        for i in 3..degree {
+            let x = 2f64 * polynomials[1] * derivatives[i - 1] - derivatives[i - 2]
+                + polynomials[i - 1]
+                + polynomials[i - 1];
 
-        Ok((coefficients, record))
+            derivatives.push(x);
+        }
     }
+
+    derivatives
+        .iter()
+        .map(|d| 2.0 * d / intlen as f64)
+        .collect()
 }
 
 impl Ephemeris for Spk {
@@ -118,19 +136,43 @@ impl Ephemeris for Spk {
         let mut x = 0f64;
         let mut y = 0f64;
         let mut z = 0f64;
+        let sign = sign as f64;
 
         match &segment.data {
-            super::parser::SpkArray::Type2(array) => {
-                let (polynomial, record) = self.get_chebyshev_polynomial(epoch, segment)?;
-                let sign = sign as f64;
+            SpkArray::Type2(array) => {
+                let (record, fraction) = self.find_record(
+                    &array.records,
+                    array.intlen,
+                    array.n,
+                    segment.initial_epoch,
+                    epoch,
+                )?;
+                let degree_of_polynomial = array.degree_of_polynomial() as usize;
+                let polynomial =
+                    chebyshev_polynomials(fraction, array.intlen, degree_of_polynomial);
 
+                for (c, t) in record.iter().zip(polynomial) {
+                    x += sign * c.x * t;
+                    y += sign * c.y * t;
+                    z += sign * c.z * t;
+                }
+            }
+            SpkArray::Type3(array) => {
+                let (record, fraction) = self.find_record(
+                    &array.records,
+                    array.intlen,
+                    array.n,
+                    segment.initial_epoch,
+                    epoch,
+                )?;
                 let degree_of_polynomial = array.degree_of_polynomial() as usize;
+                let polynomial =
+                    chebyshev_polynomials(fraction, array.intlen, degree_of_polynomial);
 
-                #[allow(clippy::needless_range_loop)]
-                for i in 0..degree_of_polynomial {
-                    x += sign * record[i].x * polynomial[i];
-                    y += sign * record[i].y * polynomial[i];
-                    z += sign * record[i].z * polynomial[i];
+                for (c, t) in record.iter().zip(polynomial) {
+                    x += sign * c.x * t;
+                    y += sign * c.y * t;
+                    z += sign * c.z * t;
                 }
             }
         }
@@ -148,40 +190,46 @@ impl Ephemeris for Spk {
         let mut x = 0f64;
         let mut y = 0f64;
         let mut z = 0f64;
+        let sign = sign as f64;
 
         match &segment.data {
-            super::parser::SpkArray::Type2(array) => {
-                let (polynomial, record) = self.get_chebyshev_polynomial(epoch, segment)?;
-                let sign = sign as f64;
-
+            SpkArray::Type2(array) => {
+                let (record, fraction) = self.find_record(
+                    &array.records,
+                    array.intlen,
+                    array.n,
+                    segment.initial_epoch,
+                    epoch,
+                )?;
                 let degree_of_polynomial = array.degree_of_polynomial() as usize;
-
-                let mut derivative = Vec::<f64>::with_capacity(degree_of_polynomial);
-
-                derivative.push(0f64);
-                derivative.push(1f64);
-
-                if degree_of_polynomial > 2 {
-                    derivative.push(4f64 * polynomial[1]);
-                    for i in 3..degree_of_polynomial {
-                        let x = 2f64 * polynomial[1] * derivative[i - 1] - derivative[i - 2]
-                            + polynomial[i - 1]
-                            + polynomial[i - 1];
-
-                        derivative.push(x);
-                    }
+                let polynomial =
+                    chebyshev_polynomials(fraction, array.intlen, degree_of_polynomial);
+                let derivative =
+                    chebyshev_derivatives(&polynomial, array.intlen, degree_of_polynomial);
+
+                for (c, dt) in record.iter().zip(derivative) {
+                    x += sign * c.x * dt;
+                    y += sign * c.y * dt;
+                    z += sign * c.z * dt;
                 }
+            }
+            SpkArray::Type3(array) => {
+                // Type 3 segments contain separate Chebyshev expansions of the velocity
+                let (record, fraction) = self.find_record(
+                    &array.records,
+                    array.intlen,
+                    array.n,
+                    segment.initial_epoch,
+                    epoch,
+                )?;
+                let degree_of_polynomial = array.degree_of_polynomial() as usize;
+                let polynomial =
+                    chebyshev_polynomials(fraction, array.intlen, degree_of_polynomial);
 
-                let derivative: Vec<f64> = derivative
-                    .iter()
-                    .map(|d| 2.0 * d / array.intlen as f64)
-                    .collect();
-
-                #[allow(clippy::needless_range_loop)]
-                for i in 0..degree_of_polynomial {
-                    x += sign * record[i].x * derivative[i];
-                    y += sign * record[i].y * derivative[i];
-                    z += sign * record[i].z * derivative[i];
+                for (c, t) in record.iter().zip(polynomial) {
+                    x += sign * c.vx * t;
+                    y += sign * c.vy * t;
+                    z += sign * c.vz * t;
                 }
             }
         }
@@ -204,11 +252,20 @@ impl Ephemeris for Spk {
 
 #[cfg(test)]
 mod test {
-    use crate::spk::parser::parse_daf_spk;
+    use std::iter::zip;
+    use std::path::PathBuf;
+
+    use float_eq::assert_float_eq;
+
     use crate::spk::parser::test::{FILE_CONTENTS, get_expected_segments};
+    use crate::spk::parser::{DafComponents, DafSummary, parse_daf_spk, parse_spk_segment};
 
     use super::*;
 
+    fn data_dir() -> PathBuf {
+        PathBuf::from(format!("{}/../../data", env!("CARGO_MANIFEST_DIR")))
+    }
+
     #[test]
     fn test_unable_to_find_segment() {
         let spk = parse_daf_spk(&FILE_CONTENTS).expect("Unable to parse DAF/SPK");
@@ -260,6 +317,425 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_open() {
+        let spk = Spk::open(data_dir().join("de440s.bsp")).unwrap();
+        assert_eq!(spk.file_record.locidw, "DAF/SPK");
+        assert!(spk.get_segments()[&3].contains_key(&399));
+
+        let err = Spk::open(data_dir().join("missing.bsp")).unwrap_err();
+        assert!(matches!(err, DafSpkError::Io(_)));
+    }
+
+    // Reference states of DE440s as (epoch, center, target, [x, y, z, vx, vy, vz]) in km and km/s.
+    // CSPICE is not available in the test environment, so the states were computed from
+    // `de440s.bsp` with an independent Python port of the CSPICE type 2 evaluation (SPKR02 and the
+    // Clenshaw recurrence of CHBINT), which does not share any code with this module.
+    const DE440S_REFERENCE: [(f64, i32, i32, [f64; 6]); 20] = [
+        (
+            -1e9,
+            0,
+            3,
+            [
+                -123101794.51170221,
+                -78891925.12273566,
+                -34217509.118687585,
+                16.493834072453577,
+                -22.551978309772274,
+                -9.779473646391418,
+            ],
+        ),
+        (
+            -1e9,
+            3,
+            399,
+            [
+                -4839.589526031285,
+                -3.369460963539211,
+                164.43191270948708,
+                -0.0006164415575393324,
+                -0.010551893849973919,
+                -0.0056909839299556,
+            ],
+        ),
+        (
+            -1e9,
+            3,
+            301,
+            [
+                393461.37842514983,
+                273.9390909358917,
+                -13368.407937028926,
+                0.05011704890329252,
+                0.8578749658158015,
+                0.46268022724479957,
+            ],
+        ),
+        (
+            -1e9,
+            0,
+            10,
+            [
+                571997.7425724803,
+                -213216.87848410784,
+                -98740.71783691116,
+                0.005839325981523217,
+                0.007990608420087036,
+                0.0033003327192824682,
+            ],
+        ),
+        (
+            -1e9,
+            0,
+            5,
+            [
+                -738334579.2784822,
+                291735979.77901125,
+                143064511.1737301,
+                -5.407206602502042,
+                -10.48353961089521,
+                -4.362240474305703,
+            ],
+        ),
+        (
+            0.0,
+            0,
+            3,
+            [
+                -27570283.695094064,
+                132358140.38814957,
+                57417728.59655908,
+                -29.7771282160576,
+                -5.037847169534867,
+                -2.1843063524408897,
+            ],
+        ),
+        (
+            0.0,
+            3,
+            399,
+            [
+                3543.2122880199777,
+                3240.7653939449465,
+                924.6896922398622,
+                -0.007819282437845184,
+                0.00809335462057777,
+                0.0036612834090198384,
+            ],
+        ),
+        (
+            0.0,
+            3,
+            301,
+            [
+                -288065.17234541546,
+                -263476.06800028845,
+                -75177.79740766216,
+                0.6357121052811876,
+                -0.6579943294710526,
+                -0.29766442157325324,
+            ],
+        ),
+        (
+            0.0,
+            0,
+            10,
+            [
+                -1067706.8053809535,
+                -396036.18479594623,
+                -138065.18428688092,
+                0.009312571926520472,
+                -0.01170150612817771,
+                -0.005251266205200356,
+            ],
+        ),
+        (
+            0.0,
+            0,
+            5,
+            [
+                597499876.792548,
+                408990313.93175864,
+                160756281.9387201,
+                -7.900525116640771,
+                10.171796309237909,
+                4.552467787262923,
+            ],
+        ),
+        (
+            517881636.0,
+            0,
+            3,
+            [
+                -53108024.81562093,
+                -129805136.8394399,
+                -56297954.18730104,
+                27.37462166366576,
+                -9.762294798141841,
+                -4.232169082827931,
+            ],
+        ),
+        (
+            517881636.0,
+            3,
+            399,
+            [
+                -4466.650177545317,
+                561.3299594426601,
+                275.2608850965909,
+                -0.0011680634488398492,
+                -0.012117712236438863,
+                -0.004032368489885916,
+            ],
+        ),
+        (
+            517881636.0,
+            3,
+            301,
+            [
+                363141.19748108566,
+                -45636.44466243825,
+                -22378.8663675051,
+                0.0949642221094415,
+                0.9851768903670695,
+                0.3278338495061857,
+            ],
+        ),
+        (
+            517881636.0,
+            0,
+            10,
+            [
+                560738.8447134758,
+                346197.81785468315,
+                123561.30072568056,
+                -0.0011231588991871986,
+                0.01111622101401083,
+                0.004825620917679764,
+            ],
+        ),
+        (
+            517881636.0,
+            0,
+            5,
+            [
+                -809149239.0043726,
+                66164056.51319708,
+                48047287.96751628,
+                -1.4344055052853957,
+                -11.397138635434768,
+                -4.85018255350827,
+            ],
+        ),
+        (
+            1e9,
+            0,
+            3,
+            [
+                146036732.5220699,
+                -32671330.870596215,
+                -14148445.321131464,
+                6.582324184761806,
+                26.44383144859994,
+                11.462687039789415,
+            ],
+        ),
+        (
+            1e9,
+            3,
+            399,
+            [
+                -331.6030641290005,
+                -4573.7188132536685,
+                -1709.8031044167344,
+                0.011710253686342093,
+                -0.0013376045824933097,
+                0.0003678055522739551,
+            ],
+        ),
+        (
+            1e9,
+            3,
+            301,
+            [
+                26959.517537677326,
+                371845.9384028751,
+                139007.96393596046,
+                -0.9520502787174947,
+                0.10874801261238459,
+                -0.029902800394894152,
+            ],
+        ),
+        (
+            1e9,
+            0,
+            10,
+            [
+                -342338.37861708476,
+                175982.13491912818,
+                90900.70946805396,
+                -0.009149002239224175,
+                -0.0017856493443188868,
+                -0.0005821008331136438,
+            ],
+        ),
+        (
+            1e9,
+            0,
+            5,
+            [
+                8559716.597893843,
+                -721646001.6643436,
+                -309515804.9649461,
+                12.910730919015014,
+                0.8092614335703766,
+                0.032571232190917114,
+            ],
+        ),
+    ];
+
+    #[test]
+    fn test_de440s_reference_states() {
+        let spk = Spk::open(data_dir().join("de440s.bsp")).unwrap();
+        for (epoch, center, target, expected) in DE440S_REFERENCE {
+            let ((x, y, z), (vx, vy, vz)) = spk.state(epoch, center, target).unwrap();
+            // Metre-level agreement of the positions and µm/s-level agreement of the velocities
+            for (actual, expected) in zip([x, y, z], &expected[..3]) {
+                assert_float_eq!(actual, *expected, abs <= 1e-3);
+            }
+            for (actual, expected) in zip([vx, vy, vz], &expected[3..]) {
+                assert_float_eq!(actual, *expected, abs <= 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_de440s_earth_moon_mass_ratio() {
+        // The Earth and the Moon revolve around their common barycentre, i.e. their barycentric
+        // positions are antiparallel and scaled by the Earth-Moon mass ratio of DE440
+        let emrat = 81.30056822149722;
+        let spk = Spk::open(data_dir().join("de440s.bsp")).unwrap();
+        for epoch in [-1e9, 0.0, 517881636.0, 1e9] {
+            let earth = spk.position(epoch, 3, 399).unwrap();
+            let moon = spk.position(epoch, 3, 301).unwrap();
+            for (e, m) in [(earth.0, moon.0), (earth.1, moon.1), (earth.2, moon.2)] {
+                assert_float_eq!(m, -emrat * e, abs <= 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_de440s_explicit_center() {
+        let spk = Spk::open(data_dir().join("de440s.bsp")).unwrap();
+        let epoch = 517881636.0;
+        let norm = |(x, y, z): Position| (x * x + y * y + z * z).sqrt();
+
+        let sun_wrt_ssb = spk.position_wrt(epoch, 0, 10).unwrap();
+        let sun_wrt_earth = spk.position_wrt(epoch, 399, 10).unwrap();
+        let earth_wrt_ssb = spk.position_wrt(epoch, 0, 399).unwrap();
+        assert_eq!(sun_wrt_ssb, spk.position(epoch, 0, 10).unwrap());
+
+        // The barycentric Sun is within a few solar radii of the barycentre while the geocentric
+        // Sun is about one astronomical unit away
+        assert!(norm(sun_wrt_ssb) < 3e6);
+        assert_float_eq!(norm(sun_wrt_earth), 1.496e8, rel <= 0.02);
+        assert_float_eq!(
+            sun_wrt_earth.0,
+            sun_wrt_ssb.0 - earth_wrt_ssb.0,
+            abs <= 1e-6
+        );
+        assert_float_eq!(
+            sun_wrt_earth.1,
+            sun_wrt_ssb.1 - earth_wrt_ssb.1,
+            abs <= 1e-6
+        );
+        assert_float_eq!(
+            sun_wrt_earth.2,
+            sun_wrt_ssb.2 - earth_wrt_ssb.2,
+            abs <= 1e-6
+        );
+
+        let (position, velocity) = spk.state_wrt(epoch, 399, 10).unwrap();
+        assert_eq!(position, sun_wrt_earth);
+        assert_float_eq!(norm(velocity), 29.8, rel <= 0.05);
+
+        assert_eq!(
+            spk.position_wrt(epoch, 399, -1000),
+            Err(DafSpkError::UnableToFindMatchingSegment)
+        );
+    }
+
+    #[test]
+    fn test_de440s_continuity() {
+        // Adjacent records must agree at the patch points to well below a metre and the
+        // velocity must be consistent with the derivative of the position
+        let spk = Spk::open(data_dir().join("de440s.bsp")).unwrap();
+        let (segment, _) = spk.find_segment(0, 3).unwrap();
+        let SpkArray::Type2(array) = &segment.data else {
+            panic!("DE440 uses type 2 segments");
+        };
+        let intlen = array.intlen as f64;
+        let epoch = segment.initial_epoch + 1000.0 * intlen;
+        let degree = array.degree_of_polynomial() as usize;
+        let before = chebyshev_polynomials(intlen, array.intlen, degree);
+        let after = chebyshev_polynomials(0.0, array.intlen, degree);
+        let records = &array.records;
+        let x_before: f64 = zip(&records[999], &before).map(|(c, t)| c.x * t).sum();
+        let x_after: f64 = zip(&records[1000], &after).map(|(c, t)| c.x * t).sum();
+        assert_float_eq!(x_before, x_after, abs <= 1e-4);
+
+        let h = 1.0;
+        let (x0, _, _) = spk.position(epoch - h, 0, 3).unwrap();
+        let (x1, _, _) = spk.position(epoch + h, 0, 3).unwrap();
+        let (vx, _, _) = spk.velocity(epoch, 0, 3).unwrap();
+        assert_float_eq!(vx, (x1 - x0) / (2.0 * h), abs <= 1e-3);
+    }
+
+    #[test]
+    fn test_type3_segment() {
+        // Two records of 100 s with three coefficients per component
+        let (init, intlen, rsize, n) = (0.0, 100.0, 20.0, 2.0);
+        let record = |offset: f64| {
+            let mut record = vec![intlen * offset + intlen / 2.0, intlen / 2.0];
+            for component in 0..6 {
+                let c = offset * 10.0 + component as f64;
+                record.extend([c, 1.0, 0.5]);
+            }
+            record
+        };
+        let words: Vec<f64> = [record(0.0), record(1.0), vec![init, intlen, rsize, n]].concat();
+        let bytes: Vec<u8> = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+        let summary = DafSummary {
+            name: "TYPE3".to_string(),
+            components: DafComponents {
+                double_precision_components: vec![0.0, 200.0],
+                integer_components: vec![-1000, 399, 1, 3],
+            },
+            initial_address: 1,
+            final_address: words.len(),
+        };
+        let segment = parse_spk_segment(&summary, &bytes, nom::number::Endianness::Little).unwrap();
+        let SpkArray::Type3(array) = &segment.data else {
+            panic!("expected a type 3 segment");
+        };
+        assert_eq!(array.degree_of_polynomial(), 3);
+        assert_eq!(array.records.len(), 2);
+
+        let mut spk = parse_daf_spk(&FILE_CONTENTS).unwrap();
+        spk.segments = HashMap::from([(399, HashMap::from([(-1000, vec![segment])]))]);
+
+        // T0 = 1, T1 = s, T2 = 2s² - 1 with s = 0.5 at 75 s into the second record
+        let expected = |c: f64| c + 0.5 + 0.5 * (2.0 * 0.25 - 1.0);
+        let (x, y, z) = spk.position(175.0, 399, -1000).unwrap();
+        let (vx, vy, vz) = spk.velocity(175.0, 399, -1000).unwrap();
+        assert_eq!(
+            [x, y, z, vx, vy, vz],
+            [10.0, 11.0, 12.0, 13.0, 14.0, 15.0].map(expected)
+        );
+        let (x, _, _) = spk.position(175.0, -1000, 399).unwrap();
+        assert_eq!(x, -expected(10.0));
+    }
+
     #[test]
     fn test_get_segments() {
         let spk = parse_daf_spk(&FILE_CONTENTS).expect("Unable to parse DAF/SPK");
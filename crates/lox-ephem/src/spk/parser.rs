@@ -62,6 +62,8 @@ pub enum DafSpkError {
     UnableToFindMatchingSegment,
     #[error("unable to find record for a given date")]
     UnableToFindMatchingRecord,
+    #[error("unable to read SPK file: {0}")]
+    Io(String),
 }
 
 #[derive(Debug, PartialEq)]
@@ -86,9 +88,36 @@ impl SpkType2Array {
     }
 }
 
+#[derive(Debug, PartialEq)]
+pub struct SpkType3Coefficients {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub vx: f64,
+    pub vy: f64,
+    pub vz: f64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SpkType3Array {
+    pub records: Vec<Vec<SpkType3Coefficients>>,
+    pub init: u32,
+    pub intlen: u32,
+    pub rsize: u32,
+    pub n: u32,
+}
+
+impl SpkType3Array {
+    pub fn degree_of_polynomial(&self) -> u32 {
+        // Type 3 records contain separate coefficients for position and velocity
+        (self.rsize - 2) / 6
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum SpkArray {
     Type2(SpkType2Array),
+    Type3(SpkType3Array),
 }
 
 #[derive(Debug, PartialEq)]
@@ -275,7 +304,7 @@ pub fn parse_daf_summary_and_name_record_pair(
     let summary_record_input = input;
     let mut name_record_input = &input[RECORD_SIZE as usize..];
 
-    let nc = 8 * (nd + (ni + 1) / 2);
+    let nc = 8 * (nd + ni.div_ceil(2));
 
     // 1. The record number of the next summary record in the file. (Zero if this is
     // the final summary record.)
@@ -440,6 +469,70 @@ fn degree_of_chebyshev_polynomial(rsize: u32) -> u32 {
     (rsize - 2) / 3
 }
 
+type ChebyshevRecords = (u32, u32, u32, u32, Vec<Vec<Vec<f64>>>);
+
+/// Parses the records of a Chebyshev segment (SPK types 2 and 3), which contain `components`
+/// sets of coefficients each, e.g. x, y, and z for type 2.
+fn parse_chebyshev_records(
+    full_input: &[u8],
+    endianness: nom::number::Endianness,
+    initial_address: usize,
+    final_address: usize,
+    components: u32,
+) -> Result<ChebyshevRecords, DafSpkError> {
+    let size_of_f64 = std::mem::size_of::<f64>();
+
+    // Words are 1-indexed
+    let start_word = initial_address - 1;
+    let initial_byte_address = start_word * size_of_f64;
+
+    let final_word = final_address;
+    let final_byte_address = final_word * size_of_f64;
+
+    let directory_initial_address = final_byte_address - 4 * size_of_f64;
+    let directory_data = &full_input[directory_initial_address..final_byte_address];
+
+    let f64_parser = nn::f64::<&[u8], nom::error::Error<_>>(endianness);
+
+    let (directory_data, init) = f64_parser(directory_data)?;
+    let (directory_data, intlen) = f64_parser(directory_data)?;
+    let (directory_data, rsize) = f64_parser(directory_data)?;
+    let (_, n) = f64_parser(directory_data)?;
+
+    let init = init as u32;
+    let intlen = intlen as u32;
+    let rsize = rsize as u32;
+    let n = n as u32;
+
+    let degree_of_polynomial = ((rsize - 2) / components) as usize;
+
+    let mut segment_data = &full_input[initial_byte_address..final_byte_address];
+
+    let mut records: Vec<Vec<Vec<f64>>> = Vec::with_capacity(n as usize);
+
+    for _ in 0..n {
+        // MID and RADIUS seem to be generally ignored in the industry
+        (segment_data, _) = f64_parser(segment_data)?;
+        (segment_data, _) = f64_parser(segment_data)?;
+
+        let mut record = Vec::with_capacity(components as usize);
+        for _ in 0..components {
+            // Possibly not the most efficient way of parsing since this can likely trigger
+            // tons of allocations
+            let coefficients;
+            (segment_data, coefficients) =
+                nom::multi::many_m_n(degree_of_polynomial, degree_of_polynomial, f64_parser)(
+                    segment_data,
+                )?;
+            record.push(coefficients);
+        }
+
+        records.push(record);
+    }
+
+    Ok((init, intlen, rsize, n, records))
+}
+
 pub fn parse_spk_segment(
     summary: &DafSummary,
     full_input: &[u8],
@@ -463,71 +556,18 @@ pub fn parse_spk_segment(
 
     let data = match data_type {
         2 => {
-            let size_of_f64 = std::mem::size_of::<f64>();
-
-            // Words are 1-indexed
-            let start_word = initial_address - 1;
-            let initial_byte_address = start_word * size_of_f64;
-
-            let final_word = final_address;
-            let final_byte_address = final_word * size_of_f64;
-
-            let directory_initial_address = final_byte_address - 4 * size_of_f64;
-            let directory_data = &full_input[directory_initial_address..final_byte_address];
-
-            let f64_parser = nn::f64::<&[u8], nom::error::Error<_>>(endianness);
-
-            let (directory_data, init) = f64_parser(directory_data)?;
-            let (directory_data, intlen) = f64_parser(directory_data)?;
-            let (directory_data, rsize) = f64_parser(directory_data)?;
-            let (_, n) = f64_parser(directory_data)?;
-
-            let init = init as u32;
-            let intlen = intlen as u32;
-            let rsize = rsize as u32;
-            let n = n as u32;
-
-            let degree_of_polynomial = degree_of_chebyshev_polynomial(rsize);
-
-            let mut segment_data = &full_input[initial_byte_address..final_byte_address];
-
-            let mut records: Vec<Vec<SpkType2Coefficients>> = Vec::with_capacity(n as usize);
-
-            for _ in 0..n {
-                // MID and RADIUS seem to be generally ignored in the industry
-                (segment_data, _) = f64_parser(segment_data)?;
-                (segment_data, _) = f64_parser(segment_data)?;
-
-                // Possibly not the most efficient way of parsing since this can likely trigger
-                // tons of allocations
-                let x_coeff;
-                (segment_data, x_coeff) = nom::multi::many_m_n(
-                    degree_of_polynomial as usize,
-                    degree_of_polynomial as usize,
-                    f64_parser,
-                )(segment_data)?;
-
-                let y_coeff;
-                (segment_data, y_coeff) = nom::multi::many_m_n(
-                    degree_of_polynomial as usize,
-                    degree_of_polynomial as usize,
-                    f64_parser,
-                )(segment_data)?;
-
-                let z_coeff;
-                (segment_data, z_coeff) = nom::multi::many_m_n(
-                    degree_of_polynomial as usize,
-                    degree_of_polynomial as usize,
-                    f64_parser,
-                )(segment_data)?;
-
-                let zipped_coefficients: Vec<_> = zip(x_coeff, y_coeff)
-                    .zip(z_coeff)
-                    .map(|((x, y), z)| SpkType2Coefficients { x, y, z })
-                    .collect();
-
-                records.push(zipped_coefficients);
-            }
+            let (init, intlen, rsize, n, records) =
+                parse_chebyshev_records(full_input, endianness, initial_address, final_address, 3)?;
+            let records = records
+                .into_iter()
+                .map(|coefficients| {
+                    let [x, y, z] = <[Vec<f64>; 3]>::try_from(coefficients).unwrap();
+                    zip(x, y)
+                        .zip(z)
+                        .map(|((x, y), z)| SpkType2Coefficients { x, y, z })
+                        .collect()
+                })
+                .collect();
 
             SpkArray::Type2(SpkType2Array {
                 records,
@@ -537,6 +577,34 @@ pub fn parse_spk_segment(
                 n,
             })
         }
+        3 => {
+            let (init, intlen, rsize, n, records) =
+                parse_chebyshev_records(full_input, endianness, initial_address, final_address, 6)?;
+            let records = records
+                .into_iter()
+                .map(|coefficients| {
+                    let [x, y, z, vx, vy, vz] = <[Vec<f64>; 6]>::try_from(coefficients).unwrap();
+                    (0..x.len())
+                        .map(|i| SpkType3Coefficients {
+                            x: x[i],
+                            y: y[i],
+                            z: z[i],
+                            vx: vx[i],
+                            vy: vy[i],
+                            vz: vz[i],
+                        })
+                        .collect()
+                })
+                .collect();
+
+            SpkArray::Type3(SpkType3Array {
+                records,
+                init,
+                intlen,
+                rsize,
+                n,
+            })
+        }
         _ => return Err(DafSpkError::UnsupportedSpkArrayType { data_type }),
     };
 
@@ -17,13 +17,15 @@ use thiserror::Error;
 #[derive(Copy, Clone, Debug, Error, PartialEq, Eq)]
 pub enum EopError {
     #[error(
-        "input vectors for EarthOrientationParams must have equal lengths, but got mjd.len()={len_mjd}, x_pole.len()={len_x_pole}, y_pole.len()={len_y_pole}, delta_ut1_utc.len()={len_delta_ut1_utc}"
+        "input vectors for EarthOrientationParams must have equal lengths, but got mjd.len()={len_mjd}, x_pole.len()={len_x_pole}, y_pole.len()={len_y_pole}, delta_ut1_utc.len()={len_delta_ut1_utc}, dx.len()={len_dx}, dy.len()={len_dy}"
     )]
     DimensionMismatch {
         len_mjd: usize,
         len_x_pole: usize,
         len_y_pole: usize,
         len_delta_ut1_utc: usize,
+        len_dx: usize,
+        len_dy: usize,
     },
     #[error("EarthOrientationParams cannot be empty, but empty input vectors were provided")]
     NoData,
@@ -36,6 +38,8 @@ pub struct EarthOrientationParams {
     x_pole: Vec<f64>,
     y_pole: Vec<f64>,
     delta_ut1_utc: Vec<f64>,
+    dx: Vec<f64>,
+    dy: Vec<f64>,
 }
 
 impl EarthOrientationParams {
@@ -44,16 +48,22 @@ impl EarthOrientationParams {
         x_pole: Vec<f64>,
         y_pole: Vec<f64>,
         delta_ut1_utc: Vec<f64>,
+        dx: Vec<f64>,
+        dy: Vec<f64>,
     ) -> Result<Self, EopError> {
         if mjd.len() != x_pole.len()
             || mjd.len() != y_pole.len()
             || mjd.len() != delta_ut1_utc.len()
+            || mjd.len() != dx.len()
+            || mjd.len() != dy.len()
         {
             return Err(EopError::DimensionMismatch {
                 len_mjd: mjd.len(),
                 len_x_pole: x_pole.len(),
                 len_y_pole: y_pole.len(),
                 len_delta_ut1_utc: delta_ut1_utc.len(),
+                len_dx: dx.len(),
+                len_dy: dy.len(),
             });
         }
 
@@ -66,6 +76,8 @@ impl EarthOrientationParams {
             x_pole,
             y_pole,
             delta_ut1_utc,
+            dx,
+            dy,
         })
     }
 
@@ -75,6 +87,8 @@ impl EarthOrientationParams {
         let mut x_pole = Vec::new();
         let mut y_pole = Vec::new();
         let mut delta_ut1_utc = Vec::new();
+        let mut dx = Vec::new();
+        let mut dy = Vec::new();
 
         for (i, result) in reader.deserialize().enumerate() {
             let record: Record = result?;
@@ -101,11 +115,16 @@ impl EarthOrientationParams {
             x_pole.push(record_x_pole);
             y_pole.push(record_y_pole);
             delta_ut1_utc.push(record_delta_ut1_utc);
+            // Celestial pole offsets are not predicted and are assumed to be zero when missing.
+            dx.push(record.dx.unwrap_or_default());
+            dy.push(record.dy.unwrap_or_default());
         }
 
-        Self::new(mjd, x_pole, y_pole, delta_ut1_utc).map_err(|e| ParseFinalsCsvError::InvalidEop {
-            path: path.as_ref().to_path_buf(),
-            source: e,
+        Self::new(mjd, x_pole, y_pole, delta_ut1_utc, dx, dy).map_err(|e| {
+            ParseFinalsCsvError::InvalidEop {
+                path: path.as_ref().to_path_buf(),
+                source: e,
+            }
         })
     }
 
@@ -124,6 +143,18 @@ impl EarthOrientationParams {
     pub fn delta_ut1_utc(&self) -> &[f64] {
         &self.delta_ut1_utc
     }
+
+    /// Returns the celestial pole offsets dX with respect to the IAU 2006/2000A
+    /// precession-nutation model in milliarcseconds.
+    pub fn dx(&self) -> &[f64] {
+        &self.dx
+    }
+
+    /// Returns the celestial pole offsets dY with respect to the IAU 2006/2000A
+    /// precession-nutation model in milliarcseconds.
+    pub fn dy(&self) -> &[f64] {
+        &self.dy
+    }
 }
 
 #[derive(Clone, Debug, Error, PartialEq)]
@@ -152,6 +183,10 @@ struct Record {
     y_pole: Option<f64>,
     #[serde(rename = "UT1-UTC")]
     delta_ut1_utc: Option<f64>,
+    #[serde(rename = "dX")]
+    dx: Option<f64>,
+    #[serde(rename = "dY")]
+    dy: Option<f64>,
 }
 
 #[cfg(test)]
@@ -295,6 +330,25 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::finals1980("finals.all.csv", 0.0, 0.0)]
+    #[case::finals2000a("finals2000A.all.csv", -0.766, -0.720)]
+    fn test_parse_finals_csv_celestial_pole_offsets(
+        #[case] path: &str,
+        #[case] expected_first_dx: f64,
+        #[case] expected_first_dy: f64,
+    ) {
+        let path = Path::new(TEST_DATA_DIR).join(path);
+        let eop = EarthOrientationParams::parse_finals_csv(path).unwrap();
+        assert_eq!(eop.dx().len(), eop.mjd().len());
+        assert_eq!(eop.dy().len(), eop.mjd().len());
+        assert_eq!(*eop.dx().first().unwrap(), expected_first_dx);
+        assert_eq!(*eop.dy().first().unwrap(), expected_first_dy);
+        // Predictions do not include celestial pole offsets
+        assert_eq!(*eop.dx().last().unwrap(), 0.0);
+        assert_eq!(*eop.dy().last().unwrap(), 0.0);
+    }
+
     #[rstest]
     #[case::csv_no_such_file("missing.csv", ParseFinalsCsvError::Csv("No such file or directory (os error 2)".to_string()))]
     #[case::csv_parse_failure("finals_type_error.csv", ParseFinalsCsvError::Csv("CSV deserialize error: record 1 (line: 2, byte: 265): field 0: invalid digit found in string".to_string()))]
@@ -33,15 +33,24 @@
 //!
 //! The KVN parsing currently does not support user-defined fields.
 //!
+//! If the encoding of a message is not known in advance, the
+//! [`format::FromNdmStr`] trait detects it and dispatches to the KVN or XML
+//! deserializer, both of which produce the same message types.
+//!
 //! Check the respective submodules for more information.
 
 pub mod json;
 pub mod kvn;
 pub mod xml;
 
+pub mod aem;
+pub mod apm;
+pub mod cdm;
 pub mod common;
+pub mod format;
 pub mod ndm_ci;
 pub mod ocm;
 pub mod oem;
 pub mod omm;
 pub mod opm;
+pub mod tdm;
@@ -0,0 +1,443 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Deserializer for KVN CCSDS Attitude Ephemeris Message
+//!
+//! The message layout follows version 1.0 of the standard
+//! ([CCSDS 504.0-B-1](https://public.ccsds.org/Pubs/504x0b1c1.pdf)). The
+//! meaning of the values of the attitude data lines depends on the
+//! `ATTITUDE_TYPE` of the segment. Use [AemSegment::quaternions] to obtain
+//! the quaternions in a uniform order.
+//!
+//! ```
+//! # let kvn = r#"CCSDS_AEM_VERS = 1.0
+//! # CREATION_DATE = 2002-11-04T17:22:31
+//! # ORIGINATOR = NASA/JPL
+//! # META_START
+//! # OBJECT_NAME = MARS GLOBAL SURVEYOR
+//! # OBJECT_ID = 1996-062A
+//! # CENTER_NAME = MARS BARYCENTER
+//! # REF_FRAME_A = EME2000
+//! # REF_FRAME_B = SC_BODY_1
+//! # ATTITUDE_DIR = A2B
+//! # TIME_SYSTEM = UTC
+//! # START_TIME = 1996-11-28T21:29:07.2555
+//! # STOP_TIME = 1996-11-28T22:08:03.5555
+//! # ATTITUDE_TYPE = QUATERNION
+//! # QUATERNION_TYPE = LAST
+//! # META_STOP
+//! # DATA_START
+//! # 1996-11-28T21:29:07.2555 0.56748 0.03146 0.45689 0.68427
+//! # 1996-11-28T22:08:03.5555 0.42319 -0.45697 0.23784 0.74533
+//! # DATA_STOP"#;
+//! #
+//! # use lox_io::ndm::aem::AemType;
+//! use lox_io::ndm::kvn::KvnDeserializer;
+//!
+//! let message: AemType = AemType::from_kvn_str(kvn).unwrap();
+//! for segment in &message.body.segment_list {
+//!     let direction = segment.metadata.direction().unwrap();
+//!     for (epoch, quaternion) in segment.quaternions().unwrap() {
+//!         // ...
+//!     }
+//! }
+//! ```
+
+use serde;
+
+use super::common;
+use super::common::{AdmError, AttitudeDirection};
+use super::kvn::parser::{KvnStateVectorParserErr, get_next_nonempty_line, parse_kvn_data_line};
+use super::kvn::{KvnContext, KvnDeserializer, KvnDeserializerErr};
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct AemType {
+    #[serde(rename = "@id")]
+    // Marked as option for the KVN deserializer
+    pub id: Option<String>,
+    #[serde(rename = "@version")]
+    pub version: String,
+    #[serde(rename = "header")]
+    pub header: common::OdmHeader,
+    #[serde(rename = "body")]
+    pub body: AemBody,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct AemBody {
+    #[serde(rename = "segment")]
+    pub segment_list: Vec<AemSegment>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct AemSegment {
+    #[serde(rename = "metadata")]
+    pub metadata: AemMetadata,
+    #[serde(rename = "data")]
+    pub data: AemData,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+#[kvn(prefix_and_postfix_keyword = "META")]
+pub struct AemMetadata {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "OBJECT_NAME")]
+    pub object_name: String,
+    #[serde(rename = "OBJECT_ID")]
+    pub object_id: String,
+    #[serde(rename = "CENTER_NAME")]
+    pub center_name: Option<String>,
+    #[serde(rename = "REF_FRAME_A")]
+    pub ref_frame_a: String,
+    #[serde(rename = "REF_FRAME_B")]
+    pub ref_frame_b: String,
+    #[serde(rename = "ATTITUDE_DIR")]
+    pub attitude_dir: String,
+    #[serde(rename = "TIME_SYSTEM")]
+    pub time_system: String,
+    #[serde(rename = "START_TIME")]
+    pub start_time: common::EpochType,
+    #[serde(rename = "USEABLE_START_TIME")]
+    pub useable_start_time: Option<common::EpochType>,
+    #[serde(rename = "USEABLE_STOP_TIME")]
+    pub useable_stop_time: Option<common::EpochType>,
+    #[serde(rename = "STOP_TIME")]
+    pub stop_time: common::EpochType,
+    #[serde(rename = "ATTITUDE_TYPE")]
+    pub attitude_type: String,
+    #[serde(rename = "QUATERNION_TYPE")]
+    pub quaternion_type: Option<String>,
+    #[serde(rename = "EULER_ROT_SEQ")]
+    pub euler_rot_seq: Option<String>,
+    #[serde(rename = "RATE_FRAME")]
+    pub rate_frame: Option<String>,
+    #[serde(rename = "INTERPOLATION_METHOD")]
+    pub interpolation_method: Option<String>,
+    #[serde(rename = "INTERPOLATION_DEGREE")]
+    pub interpolation_degree: Option<u64>,
+}
+
+impl AemMetadata {
+    pub fn direction(&self) -> Result<AttitudeDirection, AdmError> {
+        self.attitude_dir.parse()
+    }
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+#[kvn(prefix_and_postfix_keyword = "DATA")]
+pub struct AemData {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "attitudeState")]
+    pub attitude_state_list: Vec<AemAttitudeState>,
+}
+
+/// A single attitude data line of an AEM segment.
+///
+/// The number and meaning of the values depend on the `ATTITUDE_TYPE` of
+/// the segment, e.g. four quaternion components for `QUATERNION`.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct AemAttitudeState {
+    pub epoch: common::EpochType,
+    pub values: Vec<f64>,
+}
+
+impl KvnDeserializer for AemAttitudeState {
+    fn deserialize_with_context<'a>(
+        lines: &mut std::iter::Peekable<impl Iterator<Item = &'a str>>,
+        _context: &KvnContext,
+    ) -> Result<Self, KvnDeserializerErr<String>> {
+        let next_line =
+            get_next_nonempty_line(lines).ok_or(KvnDeserializerErr::UnexpectedEndOfInput {
+                keyword: "DATA_STOP".to_string(),
+            })?;
+
+        let (epoch, values) = parse_kvn_data_line(next_line).map_err(|err| match err {
+            // This is empty because we just want to tell the vector iterator
+            // to stop the iteration.
+            KvnStateVectorParserErr::InvalidFormat { .. } => {
+                KvnDeserializerErr::UnexpectedKeyword {
+                    found: "".to_string(),
+                    expected: "".to_string(),
+                }
+            }
+            err => KvnDeserializerErr::from(err),
+        })?;
+        lines.next();
+
+        Ok(Self {
+            epoch: common::EpochType(epoch.full_value),
+            values,
+        })
+    }
+
+    fn should_check_key_match() -> bool {
+        false
+    }
+}
+
+impl AemSegment {
+    /// Returns the epochs and the quaternions of the attitude data lines in
+    /// scalar-first order `[qc, q1, q2, q3]`, regardless of the
+    /// `QUATERNION_TYPE` of the segment.
+    ///
+    /// # Errors
+    ///
+    /// - [AdmError::UnsupportedAttitudeType] if the segment does not contain
+    ///   quaternions.
+    /// - [AdmError::InvalidQuaternionType] if `QUATERNION_TYPE` is neither
+    ///   `FIRST` nor `LAST`.
+    /// - [AdmError::InvalidValueCount] if a data line does not have the
+    ///   number of values required by the `ATTITUDE_TYPE`.
+    pub fn quaternions(&self) -> Result<Vec<(common::EpochType, [f64; 4])>, AdmError> {
+        let metadata = &self.metadata;
+        // The quaternion is followed by its derivative or the angular rates
+        let expected = match metadata.attitude_type.trim().to_uppercase().as_str() {
+            "QUATERNION" => 4,
+            "QUATERNION/DERIVATIVE" => 8,
+            "QUATERNION/RATE" => 7,
+            _ => {
+                return Err(AdmError::UnsupportedAttitudeType(
+                    metadata.attitude_type.clone(),
+                ));
+            }
+        };
+        let quaternion_type = metadata.quaternion_type.as_deref().unwrap_or_default();
+        let scalar_first = match quaternion_type.trim().to_uppercase().as_str() {
+            "FIRST" => true,
+            "LAST" => false,
+            _ => {
+                return Err(AdmError::InvalidQuaternionType(quaternion_type.to_string()));
+            }
+        };
+
+        self.data
+            .attitude_state_list
+            .iter()
+            .map(|state| {
+                if state.values.len() != expected {
+                    return Err(AdmError::InvalidValueCount {
+                        epoch: state.epoch.0.clone(),
+                        expected,
+                        actual: state.values.len(),
+                    });
+                }
+                let v = &state.values;
+                let quaternion = if scalar_first {
+                    [v[0], v[1], v[2], v[3]]
+                } else {
+                    [v[3], v[0], v[1], v[2]]
+                };
+                Ok((state.epoch.clone(), quaternion))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const AEM: &str = r#"CCSDS_AEM_VERS = 1.0
+CREATION_DATE = 2002-11-04T17:22:31
+ORIGINATOR = NASA/JPL
+
+META_START
+COMMENT This file was produced by M.R. Somebody, MSOO NAV/JPL, 2002 OCT 04.
+OBJECT_NAME = MARS GLOBAL SURVEYOR
+OBJECT_ID = 1996-062A
+CENTER_NAME = MARS BARYCENTER
+REF_FRAME_A = EME2000
+REF_FRAME_B = SC_BODY_1
+ATTITUDE_DIR = A2B
+TIME_SYSTEM = UTC
+START_TIME = 1996-11-28T21:29:07.2555
+USEABLE_START_TIME = 1996-11-28T22:08:02.5555
+USEABLE_STOP_TIME = 1996-11-30T01:18:02.5555
+STOP_TIME = 1996-11-30T01:28:02.5555
+ATTITUDE_TYPE = QUATERNION
+QUATERNION_TYPE = LAST
+INTERPOLATION_METHOD = hermite
+INTERPOLATION_DEGREE = 7
+META_STOP
+
+DATA_START
+COMMENT Attitude data
+1996-11-28T21:29:07.2555 0.56748 0.03146 0.45689 0.68427
+1996-11-28T22:08:03.5555 0.42319 -0.45697 0.23784 0.74533
+1996-11-28T22:08:04.5555 -0.84532 0.26974 -0.06532 0.45652
+DATA_STOP
+
+META_START
+OBJECT_NAME = MARS GLOBAL SURVEYOR
+OBJECT_ID = 1996-062A
+REF_FRAME_A = EME2000
+REF_FRAME_B = SC_BODY_1
+ATTITUDE_DIR = B2A
+TIME_SYSTEM = UTC
+START_TIME = 1996-12-18T12:05:00.5555
+STOP_TIME = 1996-12-18T12:10:00.5555
+ATTITUDE_TYPE = QUATERNION/RATE
+QUATERNION_TYPE = FIRST
+META_STOP
+
+DATA_START
+1996-12-18T12:05:00.5555 0.68427 0.56748 0.03146 0.45689 0.1 0.2 0.3
+1996-12-18T12:10:00.5555 0.74533 0.42319 -0.45697 0.23784 0.1 0.2 0.3
+DATA_STOP
+"#;
+
+    #[test]
+    fn test_parse_aem_message_kvn() {
+        let message = AemType::from_kvn_str(AEM).unwrap();
+
+        assert_eq!(message.version, "1.0");
+        assert_eq!(message.header.originator, "NASA/JPL");
+        assert_eq!(message.body.segment_list.len(), 2);
+
+        let segment = &message.body.segment_list[0];
+        assert_eq!(
+            segment.metadata,
+            AemMetadata {
+                comment_list: vec![
+                    "This file was produced by M.R. Somebody, MSOO NAV/JPL, 2002 OCT 04."
+                        .to_string()
+                ],
+                object_name: "MARS GLOBAL SURVEYOR".to_string(),
+                object_id: "1996-062A".to_string(),
+                center_name: Some("MARS BARYCENTER".to_string()),
+                ref_frame_a: "EME2000".to_string(),
+                ref_frame_b: "SC_BODY_1".to_string(),
+                attitude_dir: "A2B".to_string(),
+                time_system: "UTC".to_string(),
+                start_time: common::EpochType("1996-11-28T21:29:07.2555".to_string()),
+                useable_start_time: Some(common::EpochType("1996-11-28T22:08:02.5555".to_string())),
+                useable_stop_time: Some(common::EpochType("1996-11-30T01:18:02.5555".to_string())),
+                stop_time: common::EpochType("1996-11-30T01:28:02.5555".to_string()),
+                attitude_type: "QUATERNION".to_string(),
+                quaternion_type: Some("LAST".to_string()),
+                euler_rot_seq: None,
+                rate_frame: None,
+                interpolation_method: Some("hermite".to_string()),
+                interpolation_degree: Some(7),
+            }
+        );
+        assert_eq!(segment.data.comment_list, vec!["Attitude data"]);
+        assert_eq!(segment.data.attitude_state_list.len(), 3);
+        assert_eq!(
+            segment.data.attitude_state_list[0],
+            AemAttitudeState {
+                epoch: common::EpochType("1996-11-28T21:29:07.2555".to_string()),
+                values: vec![0.56748, 0.03146, 0.45689, 0.68427],
+            }
+        );
+        assert_eq!(segment.metadata.direction(), Ok(AttitudeDirection::AToB));
+        let quaternions = segment.quaternions().unwrap();
+        assert_eq!(quaternions.len(), 3);
+        assert_eq!(quaternions[0].1, [0.68427, 0.56748, 0.03146, 0.45689]);
+
+        let segment = &message.body.segment_list[1];
+        assert_eq!(segment.metadata.direction(), Ok(AttitudeDirection::BToA));
+        assert_eq!(segment.data.attitude_state_list[1].values.len(), 7);
+        let quaternions = segment.quaternions().unwrap();
+        assert_eq!(
+            quaternions[1],
+            (
+                common::EpochType("1996-12-18T12:10:00.5555".to_string()),
+                [0.74533, 0.42319, -0.45697, 0.23784]
+            )
+        );
+    }
+
+    #[test]
+    fn test_aem_quaternions_errors() {
+        let message = AemType::from_kvn_str(AEM).unwrap();
+        let mut segment = message.body.segment_list[0].clone();
+
+        segment.metadata.quaternion_type = Some("MIDDLE".to_string());
+        assert_eq!(
+            segment.quaternions(),
+            Err(AdmError::InvalidQuaternionType("MIDDLE".to_string()))
+        );
+
+        segment.metadata.quaternion_type = Some("LAST".to_string());
+        segment.metadata.attitude_type = "QUATERNION/DERIVATIVE".to_string();
+        assert_eq!(
+            segment.quaternions(),
+            Err(AdmError::InvalidValueCount {
+                epoch: "1996-11-28T21:29:07.2555".to_string(),
+                expected: 8,
+                actual: 4,
+            })
+        );
+
+        segment.metadata.attitude_type = "EULER_ANGLE".to_string();
+        assert_eq!(
+            segment.quaternions(),
+            Err(AdmError::UnsupportedAttitudeType("EULER_ANGLE".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_aem_message_kvn_malformed_data_line() {
+        let kvn = AEM.replace("0.23784 0.74533", "0.23784 0.7x533");
+        assert_eq!(
+            AemType::from_kvn_str(&kvn),
+            Err(KvnDeserializerErr::InvalidNumberFormat {
+                input: "1996-11-28T22:08:03.5555 0.42319 -0.45697 0.23784 0.7x533".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,550 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Deserializer for KVN CCSDS Attitude Parameter Message
+//!
+//! The message layout follows version 1.0 of the standard
+//! ([CCSDS 504.0-B-1](https://public.ccsds.org/Pubs/504x0b1c1.pdf)), where
+//! the quaternion block is mandatory and the Euler angle, spin, spacecraft
+//! parameter, and maneuver blocks are optional.
+//!
+//! ```
+//! # let kvn = r#"CCSDS_APM_VERS = 1.0
+//! # CREATION_DATE = 2003-09-30T14:28:15.1172
+//! # ORIGINATOR = GSFC
+//! # OBJECT_NAME = TRMM
+//! # OBJECT_ID = 1997-009A
+//! # CENTER_NAME = EARTH
+//! # TIME_SYSTEM = UTC
+//! # EPOCH = 2003-09-30T14:28:15.1172
+//! # Q_FRAME_A = SC_BODY_1
+//! # Q_FRAME_B = ITRF-97
+//! # Q_DIR = A2B
+//! # Q1 = 0.25678
+//! # Q2 = 0.00005
+//! # Q3 = 0.87543
+//! # QC = 0.40949"#;
+//! #
+//! # use lox_io::ndm::apm::ApmType;
+//! use lox_io::ndm::kvn::KvnDeserializer;
+//!
+//! let message: ApmType = ApmType::from_kvn_str(kvn).unwrap();
+//! let quaternion = message.body.segment.data.quaternion_state.quaternion();
+//! ```
+
+use lox_math::types::units::Angle;
+use serde;
+
+use super::common;
+use super::common::{AdmError, AttitudeDirection, EulerRotationSequence};
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct ApmType {
+    #[serde(rename = "@id")]
+    // Marked as option for the KVN deserializer
+    pub id: Option<String>,
+    #[serde(rename = "@version")]
+    pub version: String,
+    #[serde(rename = "header")]
+    pub header: common::OdmHeader,
+    #[serde(rename = "body")]
+    pub body: ApmBody,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct ApmBody {
+    #[serde(rename = "segment")]
+    pub segment: ApmSegment,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct ApmSegment {
+    #[serde(rename = "metadata")]
+    pub metadata: ApmMetadata,
+    #[serde(rename = "data")]
+    pub data: ApmData,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct ApmMetadata {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "OBJECT_NAME")]
+    pub object_name: String,
+    #[serde(rename = "OBJECT_ID")]
+    pub object_id: String,
+    #[serde(rename = "CENTER_NAME")]
+    pub center_name: Option<String>,
+    #[serde(rename = "TIME_SYSTEM")]
+    pub time_system: String,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct ApmData {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "quaternionState")]
+    pub quaternion_state: ApmQuaternionState,
+    #[serde(rename = "eulerAngleState")]
+    pub euler_angle_state: Option<ApmEulerAngleState>,
+    #[serde(rename = "spinState")]
+    pub spin_state: Option<ApmSpinState>,
+    #[serde(rename = "spacecraftParameters")]
+    pub spacecraft_parameters: Option<ApmSpacecraftParameters>,
+    #[serde(rename = "maneuverParameters")]
+    pub maneuver_parameters_list: Vec<ApmManeuverParameters>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct ApmQuaternionState {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "EPOCH")]
+    pub epoch: common::EpochType,
+    #[serde(rename = "Q_FRAME_A")]
+    pub q_frame_a: String,
+    #[serde(rename = "Q_FRAME_B")]
+    pub q_frame_b: String,
+    #[serde(rename = "Q_DIR")]
+    pub q_dir: String,
+    #[serde(rename = "Q1")]
+    pub q1: f64,
+    #[serde(rename = "Q2")]
+    pub q2: f64,
+    #[serde(rename = "Q3")]
+    pub q3: f64,
+    #[serde(rename = "QC")]
+    pub qc: f64,
+    #[serde(rename = "Q1_DOT")]
+    pub q1_dot: Option<f64>,
+    #[serde(rename = "Q2_DOT")]
+    pub q2_dot: Option<f64>,
+    #[serde(rename = "Q3_DOT")]
+    pub q3_dot: Option<f64>,
+    #[serde(rename = "QC_DOT")]
+    pub qc_dot: Option<f64>,
+}
+
+impl ApmQuaternionState {
+    /// Returns the quaternion in scalar-first order `[qc, q1, q2, q3]`.
+    pub fn quaternion(&self) -> [f64; 4] {
+        [self.qc, self.q1, self.q2, self.q3]
+    }
+
+    /// Returns the time derivative of the quaternion in scalar-first order
+    /// if all of its components are present.
+    pub fn quaternion_derivative(&self) -> Option<[f64; 4]> {
+        Some([self.qc_dot?, self.q1_dot?, self.q2_dot?, self.q3_dot?])
+    }
+
+    pub fn direction(&self) -> Result<AttitudeDirection, AdmError> {
+        self.q_dir.parse()
+    }
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct ApmEulerAngleState {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "EULER_FRAME_A")]
+    pub euler_frame_a: String,
+    #[serde(rename = "EULER_FRAME_B")]
+    pub euler_frame_b: String,
+    #[serde(rename = "EULER_DIR")]
+    pub euler_dir: String,
+    #[serde(rename = "EULER_ROT_SEQ")]
+    pub euler_rot_seq: String,
+    #[serde(rename = "RATE_FRAME")]
+    pub rate_frame: Option<String>,
+    #[serde(rename = "X_ANGLE")]
+    pub x_angle: common::AngleType,
+    #[serde(rename = "Y_ANGLE")]
+    pub y_angle: common::AngleType,
+    #[serde(rename = "Z_ANGLE")]
+    pub z_angle: common::AngleType,
+    #[serde(rename = "X_RATE")]
+    pub x_rate: Option<common::AngleRateType>,
+    #[serde(rename = "Y_RATE")]
+    pub y_rate: Option<common::AngleRateType>,
+    #[serde(rename = "Z_RATE")]
+    pub z_rate: Option<common::AngleRateType>,
+}
+
+impl ApmEulerAngleState {
+    pub fn direction(&self) -> Result<AttitudeDirection, AdmError> {
+        self.euler_dir.parse()
+    }
+
+    pub fn rotation_sequence(&self) -> Result<EulerRotationSequence, AdmError> {
+        self.euler_rot_seq.parse()
+    }
+
+    /// Returns the rotation angles about the X, Y, and Z axes.
+    pub fn angles(&self) -> Result<[Angle; 3], AdmError> {
+        Ok([
+            self.x_angle.to_angle()?,
+            self.y_angle.to_angle()?,
+            self.z_angle.to_angle()?,
+        ])
+    }
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct ApmSpinState {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "SPIN_FRAME_A")]
+    pub spin_frame_a: String,
+    #[serde(rename = "SPIN_FRAME_B")]
+    pub spin_frame_b: String,
+    #[serde(rename = "SPIN_DIR")]
+    pub spin_dir: String,
+    #[serde(rename = "SPIN_ALPHA")]
+    pub spin_alpha: common::AngleType,
+    #[serde(rename = "SPIN_DELTA")]
+    pub spin_delta: common::AngleType,
+    #[serde(rename = "SPIN_ANGLE")]
+    pub spin_angle: common::AngleType,
+    #[serde(rename = "SPIN_ANGLE_VEL")]
+    pub spin_angle_vel: common::AngleRateType,
+    #[serde(rename = "NUTATION")]
+    pub nutation: Option<common::AngleType>,
+    #[serde(rename = "NUTATION_PER")]
+    pub nutation_per: Option<common::DurationType>,
+    #[serde(rename = "NUTATION_PHASE")]
+    pub nutation_phase: Option<common::AngleType>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct ApmSpacecraftParameters {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "INERTIA_REF_FRAME")]
+    pub inertia_ref_frame: Option<String>,
+    #[serde(rename = "I11")]
+    pub i11: common::MomentType,
+    #[serde(rename = "I22")]
+    pub i22: common::MomentType,
+    #[serde(rename = "I33")]
+    pub i33: common::MomentType,
+    #[serde(rename = "I12")]
+    pub i12: common::MomentType,
+    #[serde(rename = "I13")]
+    pub i13: common::MomentType,
+    #[serde(rename = "I23")]
+    pub i23: common::MomentType,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    lox_derive::KvnDeserialize,
+)]
+#[serde(default)]
+pub struct ApmManeuverParameters {
+    #[serde(rename = "COMMENT")]
+    pub comment_list: Vec<String>,
+    #[serde(rename = "MAN_EPOCH_START")]
+    pub man_epoch_start: common::EpochType,
+    #[serde(rename = "MAN_DURATION")]
+    pub man_duration: common::DurationType,
+    #[serde(rename = "MAN_REF_FRAME")]
+    pub man_ref_frame: String,
+    /// The torque about the first axis of the maneuver reference frame in N*m
+    #[serde(rename = "MAN_TOR_1")]
+    pub man_tor_1: f64,
+    #[serde(rename = "MAN_TOR_2")]
+    pub man_tor_2: f64,
+    #[serde(rename = "MAN_TOR_3")]
+    pub man_tor_3: f64,
+}
+
+#[cfg(test)]
+mod test {
+    use float_eq::assert_float_eq;
+
+    use crate::ndm::kvn::KvnDeserializer;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_apm_message_kvn_quaternion() {
+        let kvn = r#"CCSDS_APM_VERS = 1.0
+CREATION_DATE = 2003-09-30T14:28:15.1172
+ORIGINATOR = GSFC
+OBJECT_NAME = TRMM
+OBJECT_ID = 1997-009A
+CENTER_NAME = EARTH
+TIME_SYSTEM = UTC
+COMMENT GEOCENTRIC, CARTESIAN, EARTH FIXED
+EPOCH = 2003-09-30T14:28:15.1172
+Q_FRAME_A = SC_BODY_1
+Q_FRAME_B = ITRF-97
+Q_DIR = A2B
+Q1 = 0.25678
+Q2 = 0.00005
+Q3 = 0.87543
+QC = 0.40949
+Q1_DOT = 0.05
+Q2_DOT = -0.01
+Q3_DOT = 0.0
+QC_DOT = 0.001"#;
+
+        let message = ApmType::from_kvn_str(kvn).unwrap();
+
+        assert_eq!(message.version, "1.0");
+        assert_eq!(message.header.originator, "GSFC");
+        assert_eq!(
+            message.body.segment.metadata,
+            ApmMetadata {
+                comment_list: vec![],
+                object_name: "TRMM".to_string(),
+                object_id: "1997-009A".to_string(),
+                center_name: Some("EARTH".to_string()),
+                time_system: "UTC".to_string(),
+            }
+        );
+
+        let data = &message.body.segment.data;
+        assert_eq!(
+            data.comment_list,
+            vec!["GEOCENTRIC, CARTESIAN, EARTH FIXED".to_string()]
+        );
+        let quaternion_state = &data.quaternion_state;
+        assert_eq!(
+            quaternion_state.epoch,
+            common::EpochType("2003-09-30T14:28:15.1172".to_string())
+        );
+        assert_eq!(quaternion_state.q_frame_a, "SC_BODY_1");
+        assert_eq!(quaternion_state.q_frame_b, "ITRF-97");
+        assert_eq!(quaternion_state.direction(), Ok(AttitudeDirection::AToB));
+        assert_eq!(
+            quaternion_state.quaternion(),
+            [0.40949, 0.25678, 0.00005, 0.87543]
+        );
+        assert_eq!(
+            quaternion_state.quaternion_derivative(),
+            Some([0.001, 0.05, -0.01, 0.0])
+        );
+        assert!(data.euler_angle_state.is_none());
+        assert!(data.spin_state.is_none());
+        assert!(data.spacecraft_parameters.is_none());
+        assert!(data.maneuver_parameters_list.is_empty());
+    }
+
+    #[test]
+    fn test_parse_apm_message_kvn_all_blocks() {
+        let kvn = r#"CCSDS_APM_VERS = 1.0
+CREATION_DATE = 2004-02-14T19:23:57
+ORIGINATOR = JPL
+OBJECT_NAME = MARS SPIRIT
+OBJECT_ID = 2003-027A
+TIME_SYSTEM = UTC
+EPOCH = 2004-02-14T14:28:15.1172
+Q_FRAME_A = INSTRUMENT_A
+Q_FRAME_B = ITRF-97
+Q_DIR = A2B
+Q1 = 0.03123
+Q2 = 0.78543
+Q3 = 0.39158
+QC = 0.47832
+COMMENT Euler angles
+EULER_FRAME_A = INSTRUMENT_A
+EULER_FRAME_B = ITRF-97
+EULER_DIR = A2B
+EULER_ROT_SEQ = 312
+RATE_FRAME = EULER_FRAME_A
+X_ANGLE = -26.78 [deg]
+Y_ANGLE = 46.26 [deg]
+Z_ANGLE = 144.10 [deg]
+X_RATE = 0.1 [deg/s]
+Y_RATE = 0.2 [deg/s]
+Z_RATE = 0.3 [deg/s]
+SPIN_FRAME_A = INSTRUMENT_A
+SPIN_FRAME_B = ITRF-97
+SPIN_DIR = A2B
+SPIN_ALPHA = 26.78 [deg]
+SPIN_DELTA = 46.26 [deg]
+SPIN_ANGLE = 144.10 [deg]
+SPIN_ANGLE_VEL = 0.5 [deg/s]
+INERTIA_REF_FRAME = INSTRUMENT_A
+I11 = 1443.10 [kg*m**2]
+I22 = 1600.00 [kg*m**2]
+I33 = 1400.00 [kg*m**2]
+I12 = 0.0 [kg*m**2]
+I13 = 20.0 [kg*m**2]
+I23 = 0.0 [kg*m**2]
+COMMENT First maneuver
+MAN_EPOCH_START = 2004-02-14T14:29:00.5098
+MAN_DURATION = 3 [s]
+MAN_REF_FRAME = INSTRUMENT_A
+MAN_TOR_1 = -1.25 [N*m]
+MAN_TOR_2 = -0.5 [N*m]
+MAN_TOR_3 = 0.5 [N*m]
+MAN_EPOCH_START = 2004-02-14T14:35:00.5098
+MAN_DURATION = 2.5 [s]
+MAN_REF_FRAME = INSTRUMENT_A
+MAN_TOR_1 = 1.25 [N*m]
+MAN_TOR_2 = 0.5 [N*m]
+MAN_TOR_3 = -0.5 [N*m]"#;
+
+        let message = ApmType::from_kvn_str(kvn).unwrap();
+        let data = message.body.segment.data;
+
+        assert_eq!(message.body.segment.metadata.center_name, None);
+
+        let euler_angle_state = data.euler_angle_state.unwrap();
+        assert_eq!(euler_angle_state.comment_list, vec!["Euler angles"]);
+        assert_eq!(euler_angle_state.direction(), Ok(AttitudeDirection::AToB));
+        assert_eq!(
+            euler_angle_state.rotation_sequence().unwrap().axes(),
+            [3, 1, 2]
+        );
+        assert_eq!(
+            euler_angle_state.rate_frame,
+            Some("EULER_FRAME_A".to_string())
+        );
+        let [x, y, z] = euler_angle_state.angles().unwrap();
+        assert_float_eq!(x.to_degrees(), -26.78, rel <= 1e-15);
+        assert_float_eq!(y.to_degrees(), 46.26, rel <= 1e-15);
+        assert_float_eq!(z.to_degrees(), 144.10, rel <= 1e-15);
+        assert_eq!(euler_angle_state.z_rate.unwrap().base, 0.3);
+
+        let spin_state = data.spin_state.unwrap();
+        assert_eq!(spin_state.spin_angle_vel.base, 0.5);
+        assert_eq!(spin_state.nutation, None);
+
+        let spacecraft_parameters = data.spacecraft_parameters.unwrap();
+        assert_eq!(
+            spacecraft_parameters.inertia_ref_frame,
+            Some("INSTRUMENT_A".to_string())
+        );
+        assert_eq!(spacecraft_parameters.i11.base, 1443.1);
+        assert_eq!(spacecraft_parameters.i13.base, 20.0);
+
+        assert_eq!(data.maneuver_parameters_list.len(), 2);
+        let maneuver = &data.maneuver_parameters_list[0];
+        assert_eq!(maneuver.comment_list, vec!["First maneuver"]);
+        assert_eq!(maneuver.man_duration.base.0, 3.0);
+        assert_eq!(maneuver.man_tor_1, -1.25);
+        assert_eq!(data.maneuver_parameters_list[1].man_tor_3, -0.5);
+    }
+
+    #[test]
+    fn test_parse_attitude_direction() {
+        assert_eq!("A2B".parse(), Ok(AttitudeDirection::AToB));
+        assert_eq!("b2a".parse(), Ok(AttitudeDirection::BToA));
+        assert_eq!(
+            "A2C".parse::<AttitudeDirection>(),
+            Err(AdmError::InvalidAttitudeDirection("A2C".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_euler_rotation_sequence() {
+        assert_eq!(
+            "312".parse::<EulerRotationSequence>().unwrap().axes(),
+            [3, 1, 2]
+        );
+        assert_eq!(
+            "ZXZ".parse::<EulerRotationSequence>().unwrap().axes(),
+            [3, 1, 3]
+        );
+        for sequence in ["3122", "311", "ZZX", "412", ""] {
+            assert_eq!(
+                sequence.parse::<EulerRotationSequence>(),
+                Err(AdmError::InvalidRotationSequence(sequence.to_string()))
+            );
+        }
+    }
+}
@@ -0,0 +1,396 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Deserializer for KVN CCSDS Conjunction Data Message
+//!
+//! The message structure follows
+//! [CCSDS 508.0-B-1](https://public.ccsds.org/Pubs/508x0b1e2c2.pdf). Only the
+//! KVN encoding is supported and of the covariance matrix only the 6x6
+//! position and velocity block in the RTN frame is read.
+//!
+//! ```
+//! # let kvn = std::fs::read_to_string(concat!(
+//! #     env!("CARGO_MANIFEST_DIR"),
+//! #     "/../../data/cdm.txt"
+//! # )).unwrap();
+//! # use lox_io::ndm::cdm::CdmType;
+//! use lox_io::ndm::kvn::KvnDeserializer;
+//!
+//! let message: CdmType = KvnDeserializer::from_kvn_str(&kvn).unwrap();
+//!
+//! let tca = &message.body.relative_metadata_data.tca;
+//! let miss_distance = &message.body.relative_metadata_data.miss_distance;
+//! ```
+
+use super::common::{self, AreaUnits, DayIntervalUnits, PercentageUnits, WkgUnits};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct M2kgUnits(pub String);
+
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+#[kvn(value_unit_struct)]
+pub struct CdmDayIntervalType {
+    pub base: f64,
+    pub units: Option<DayIntervalUnits>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+#[kvn(value_unit_struct)]
+pub struct CdmPercentageType {
+    pub base: f64,
+    pub units: Option<PercentageUnits>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+#[kvn(value_unit_struct)]
+pub struct CdmAreaType {
+    pub base: f64,
+    pub units: Option<AreaUnits>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+#[kvn(value_unit_struct)]
+pub struct M2kgType {
+    pub base: f64,
+    pub units: Option<M2kgUnits>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+#[kvn(value_unit_struct)]
+pub struct WkgType {
+    pub base: f64,
+    pub units: Option<WkgUnits>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+pub struct CdmType {
+    pub version: String,
+    pub header: CdmHeader,
+    pub body: CdmBody,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+pub struct CdmHeader {
+    pub comment_list: Vec<String>,
+    pub creation_date: common::EpochType,
+    pub originator: String,
+    pub message_for: Option<String>,
+    pub message_id: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+pub struct CdmBody {
+    pub relative_metadata_data: RelativeMetadataDataType,
+    pub object1: CdmSegment,
+    pub object2: CdmSegment,
+}
+
+/// The relative metadata and data which describe the conjunction
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+pub struct RelativeMetadataDataType {
+    pub comment_list: Vec<String>,
+    /// Time of closest approach
+    pub tca: common::EpochType,
+    pub miss_distance: common::DistanceType,
+    pub relative_speed: Option<common::VelocityType>,
+    pub relative_position_r: Option<common::DistanceType>,
+    pub relative_position_t: Option<common::DistanceType>,
+    pub relative_position_n: Option<common::DistanceType>,
+    pub relative_velocity_r: Option<common::VelocityType>,
+    pub relative_velocity_t: Option<common::VelocityType>,
+    pub relative_velocity_n: Option<common::VelocityType>,
+    pub start_screen_period: Option<common::EpochType>,
+    pub stop_screen_period: Option<common::EpochType>,
+    pub screen_volume_frame: Option<String>,
+    pub screen_volume_shape: Option<String>,
+    pub screen_volume_x: Option<common::DistanceType>,
+    pub screen_volume_y: Option<common::DistanceType>,
+    pub screen_volume_z: Option<common::DistanceType>,
+    pub screen_entry_time: Option<common::EpochType>,
+    pub screen_exit_time: Option<common::EpochType>,
+    pub collision_probability: Option<f64>,
+    pub collision_probability_method: Option<String>,
+}
+
+impl RelativeMetadataDataType {
+    /// Returns the position of object 2 relative to object 1 in the RTN frame
+    /// of object 1 if all components are present.
+    pub fn relative_position(&self) -> Option<[f64; 3]> {
+        match (
+            &self.relative_position_r,
+            &self.relative_position_t,
+            &self.relative_position_n,
+        ) {
+            (Some(r), Some(t), Some(n)) => Some([r.base, t.base, n.base]),
+            _ => None,
+        }
+    }
+
+    /// Returns the velocity of object 2 relative to object 1 in the RTN frame
+    /// of object 1 if all components are present.
+    pub fn relative_velocity(&self) -> Option<[f64; 3]> {
+        match (
+            &self.relative_velocity_r,
+            &self.relative_velocity_t,
+            &self.relative_velocity_n,
+        ) {
+            (Some(r), Some(t), Some(n)) => Some([r.base, t.base, n.base]),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+pub struct CdmSegment {
+    pub metadata: CdmMetadata,
+    pub data: CdmData,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+pub struct CdmMetadata {
+    pub comment_list: Vec<String>,
+    /// Either `OBJECT1` or `OBJECT2`
+    pub object: String,
+    pub object_designator: String,
+    pub catalog_name: String,
+    pub object_name: String,
+    pub international_designator: String,
+    pub object_type: Option<String>,
+    pub operator_contact_position: Option<String>,
+    pub operator_organization: Option<String>,
+    pub operator_phone: Option<String>,
+    pub operator_email: Option<String>,
+    pub ephemeris_name: String,
+    pub covariance_method: String,
+    pub maneuverable: String,
+    pub orbit_center: Option<String>,
+    pub ref_frame: String,
+    pub gravity_model: Option<String>,
+    pub atmospheric_model: Option<String>,
+    pub n_body_perturbations: Option<String>,
+    pub solar_rad_pressure: Option<String>,
+    pub earth_tides: Option<String>,
+    pub intrack_thrust: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+pub struct CdmData {
+    pub comment_list: Vec<String>,
+    pub od_parameters: OdParametersType,
+    pub additional_parameters: AdditionalParametersType,
+    pub state_vector: CdmStateVectorType,
+    pub covariance_matrix: CdmCovarianceMatrixType,
+}
+
+/// The orbit determination parameters. All keywords are optional.
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+pub struct OdParametersType {
+    pub comment_list: Vec<String>,
+    pub time_lastob_start: Option<common::EpochType>,
+    pub time_lastob_end: Option<common::EpochType>,
+    pub recommended_od_span: Option<CdmDayIntervalType>,
+    pub actual_od_span: Option<CdmDayIntervalType>,
+    pub obs_available: Option<u64>,
+    pub obs_used: Option<u64>,
+    pub tracks_available: Option<u64>,
+    pub tracks_used: Option<u64>,
+    pub residuals_accepted: Option<CdmPercentageType>,
+    pub weighted_rms: Option<f64>,
+}
+
+/// The additional parameters. All keywords are optional.
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+pub struct AdditionalParametersType {
+    pub comment_list: Vec<String>,
+    pub area_pc: Option<CdmAreaType>,
+    pub area_drg: Option<CdmAreaType>,
+    pub area_srp: Option<CdmAreaType>,
+    pub mass: Option<common::MassType>,
+    pub cd_area_over_mass: Option<M2kgType>,
+    pub cr_area_over_mass: Option<M2kgType>,
+    pub thrust_acceleration: Option<common::AccType>,
+    pub sedr: Option<WkgType>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+pub struct CdmStateVectorType {
+    pub comment_list: Vec<String>,
+    pub x: common::PositionType,
+    pub y: common::PositionType,
+    pub z: common::PositionType,
+    pub x_dot: common::VelocityType,
+    pub y_dot: common::VelocityType,
+    pub z_dot: common::VelocityType,
+}
+
+impl CdmStateVectorType {
+    pub fn position(&self) -> [f64; 3] {
+        [self.x.base, self.y.base, self.z.base]
+    }
+
+    pub fn velocity(&self) -> [f64; 3] {
+        [self.x_dot.base, self.y_dot.base, self.z_dot.base]
+    }
+}
+
+/// The lower triangle of the position and velocity covariance in the RTN
+/// frame of the object
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+pub struct CdmCovarianceMatrixType {
+    pub comment_list: Vec<String>,
+    pub cr_r: common::PositionCovarianceType,
+    pub ct_r: common::PositionCovarianceType,
+    pub ct_t: common::PositionCovarianceType,
+    pub cn_r: common::PositionCovarianceType,
+    pub cn_t: common::PositionCovarianceType,
+    pub cn_n: common::PositionCovarianceType,
+    pub crdot_r: common::PositionVelocityCovarianceType,
+    pub crdot_t: common::PositionVelocityCovarianceType,
+    pub crdot_n: common::PositionVelocityCovarianceType,
+    pub crdot_rdot: common::VelocityCovarianceType,
+    pub ctdot_r: common::PositionVelocityCovarianceType,
+    pub ctdot_t: common::PositionVelocityCovarianceType,
+    pub ctdot_n: common::PositionVelocityCovarianceType,
+    pub ctdot_rdot: common::VelocityCovarianceType,
+    pub ctdot_tdot: common::VelocityCovarianceType,
+    pub cndot_r: common::PositionVelocityCovarianceType,
+    pub cndot_t: common::PositionVelocityCovarianceType,
+    pub cndot_n: common::PositionVelocityCovarianceType,
+    pub cndot_rdot: common::VelocityCovarianceType,
+    pub cndot_tdot: common::VelocityCovarianceType,
+    pub cndot_ndot: common::VelocityCovarianceType,
+}
+
+impl CdmCovarianceMatrixType {
+    /// Returns the full, symmetric 6x6 covariance matrix ordered as
+    /// R, T, N, R_DOT, T_DOT, N_DOT.
+    pub fn to_matrix(&self) -> [[f64; 6]; 6] {
+        let lower = [
+            vec![self.cr_r.base],
+            vec![self.ct_r.base, self.ct_t.base],
+            vec![self.cn_r.base, self.cn_t.base, self.cn_n.base],
+            vec![
+                self.crdot_r.base,
+                self.crdot_t.base,
+                self.crdot_n.base,
+                self.crdot_rdot.base,
+            ],
+            vec![
+                self.ctdot_r.base,
+                self.ctdot_t.base,
+                self.ctdot_n.base,
+                self.ctdot_rdot.base,
+                self.ctdot_tdot.base,
+            ],
+            vec![
+                self.cndot_r.base,
+                self.cndot_t.base,
+                self.cndot_n.base,
+                self.cndot_rdot.base,
+                self.cndot_tdot.base,
+                self.cndot_ndot.base,
+            ],
+        ];
+
+        let mut matrix = [[0.0; 6]; 6];
+        for (i, row) in lower.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                matrix[i][j] = *value;
+                matrix[j][i] = *value;
+            }
+        }
+        matrix
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ndm::kvn::KvnDeserializer;
+
+    use super::*;
+
+    const CDM: &str = include_str!("../../../../data/cdm.txt");
+
+    #[test]
+    fn test_parse_cdm_message_kvn() {
+        let message: CdmType = KvnDeserializer::from_kvn_str(CDM).unwrap();
+
+        assert_eq!(message.version, "1.0");
+        assert_eq!(message.header.originator, "JSPOC");
+        assert_eq!(message.header.message_for, Some("SATELLITE A".to_string()));
+        assert_eq!(message.header.message_id, "201113719185");
+
+        let relative = &message.body.relative_metadata_data;
+        assert_eq!(
+            relative.tca,
+            common::EpochType("2010-03-13T22:37:52.618".to_string())
+        );
+        assert_eq!(
+            relative.miss_distance,
+            common::DistanceType {
+                base: 715.0,
+                units: Some(common::PositionUnits("m".to_string())),
+            }
+        );
+        assert_eq!(relative.relative_position(), Some([27.4, -70.2, 711.8]));
+        assert_eq!(
+            relative.relative_velocity(),
+            Some([-7.2, -14692.0, -1437.2])
+        );
+        assert_eq!(relative.collision_probability, Some(4.835e-5));
+        assert_eq!(
+            relative.collision_probability_method,
+            Some("FOSTER-1992".to_string())
+        );
+
+        let object1 = &message.body.object1;
+        assert_eq!(object1.metadata.object, "OBJECT1");
+        assert_eq!(object1.metadata.object_designator, "12345");
+        assert_eq!(object1.metadata.ref_frame, "EME2000");
+        assert_eq!(
+            object1.data.od_parameters.residuals_accepted,
+            Some(CdmPercentageType {
+                base: 97.8,
+                units: Some(PercentageUnits("%".to_string())),
+            })
+        );
+        assert_eq!(
+            object1.data.additional_parameters.cd_area_over_mass,
+            Some(M2kgType {
+                base: 0.045663,
+                units: Some(M2kgUnits("m**2/kg".to_string())),
+            })
+        );
+        assert_eq!(
+            object1.data.state_vector.position(),
+            [2570.097065, 2244.654904, 6281.497978]
+        );
+        assert_eq!(
+            object1.data.state_vector.velocity(),
+            [4.418769571, 4.833547743, -3.526774282]
+        );
+        let covariance = object1.data.covariance_matrix.to_matrix();
+        assert_eq!(covariance[0][0], 4.142e1);
+        assert_eq!(covariance[1][0], -8.579);
+        assert_eq!(covariance[0][1], -8.579);
+        assert_eq!(covariance[5][5], 5.529e-5);
+
+        let object2 = &message.body.object2;
+        assert_eq!(object2.metadata.object, "OBJECT2");
+        assert_eq!(object2.metadata.object_designator, "30337");
+        assert_eq!(
+            object2.data.state_vector.position(),
+            [2569.540800, 2245.093614, 6281.599946]
+        );
+        assert_eq!(
+            object2.data.state_vector.velocity(),
+            [-2.888612500, -6.007247516, 3.328770172]
+        );
+        assert_eq!(object2.data.covariance_matrix.cndot_ndot.base, 6.223e-4);
+    }
+}
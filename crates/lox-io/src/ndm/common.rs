@@ -8,7 +8,10 @@
 
 //! Data types shared between different NDM message types
 
+use std::str::FromStr;
+
 use serde;
+use thiserror::Error;
 
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
 #[serde(default)]
@@ -440,6 +443,7 @@ pub struct DayIntervalType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(value_unit_struct)]
 pub struct OcmDayIntervalType {
     #[serde(rename = "$text")]
     pub base: NonNegativeDouble,
@@ -520,6 +524,7 @@ pub struct LengthType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(value_unit_struct)]
 pub struct OcmLengthType {
     #[serde(rename = "$text")]
     pub base: f64,
@@ -776,6 +781,7 @@ pub struct DurationType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(value_unit_struct)]
 pub struct TimeOffsetType {
     #[serde(rename = "$text")]
     pub base: f64,
@@ -810,6 +816,7 @@ pub struct PercentageType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(value_unit_struct)]
 pub struct ManeuverFreqType {
     #[serde(rename = "$text")]
     pub base: NonNegativeDouble,
@@ -827,6 +834,7 @@ pub struct ManeuverFreqType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(value_unit_struct)]
 pub struct ThrustType {
     #[serde(rename = "$text")]
     pub base: f64,
@@ -844,6 +852,7 @@ pub struct ThrustType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(value_unit_struct)]
 pub struct GeomagType {
     #[serde(rename = "$text")]
     pub base: f64,
@@ -861,6 +870,7 @@ pub struct GeomagType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(value_unit_struct)]
 pub struct SolarFluxType {
     #[serde(rename = "$text")]
     pub base: f64,
@@ -1075,3 +1085,151 @@ pub struct UserDefinedParameterType {
     #[serde(rename = "@parameter")]
     pub parameter: String,
 }
+
+/// The time systems defined by the SANA time systems registry which can be
+/// used as the value of the `TIME_SYSTEM` keyword
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeSystem {
+    Gmst,
+    Gps,
+    Met,
+    Mrt,
+    Sclk,
+    Tai,
+    Tcb,
+    Tcg,
+    Tdb,
+    Tt,
+    Ut1,
+    Utc,
+}
+
+impl TimeSystem {
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            TimeSystem::Gmst => "GMST",
+            TimeSystem::Gps => "GPS",
+            TimeSystem::Met => "MET",
+            TimeSystem::Mrt => "MRT",
+            TimeSystem::Sclk => "SCLK",
+            TimeSystem::Tai => "TAI",
+            TimeSystem::Tcb => "TCB",
+            TimeSystem::Tcg => "TCG",
+            TimeSystem::Tdb => "TDB",
+            TimeSystem::Tt => "TT",
+            TimeSystem::Ut1 => "UT1",
+            TimeSystem::Utc => "UTC",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+#[error("unknown time system `{0}`")]
+pub struct UnknownTimeSystemError(pub String);
+
+impl FromStr for TimeSystem {
+    type Err = UnknownTimeSystemError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_uppercase().as_str() {
+            "GMST" => Ok(TimeSystem::Gmst),
+            "GPS" => Ok(TimeSystem::Gps),
+            "MET" => Ok(TimeSystem::Met),
+            "MRT" => Ok(TimeSystem::Mrt),
+            "SCLK" => Ok(TimeSystem::Sclk),
+            "TAI" => Ok(TimeSystem::Tai),
+            "TCB" => Ok(TimeSystem::Tcb),
+            "TCG" => Ok(TimeSystem::Tcg),
+            // TDB was formerly also known as ET (ephemeris time)
+            "TDB" | "ET" => Ok(TimeSystem::Tdb),
+            "TT" => Ok(TimeSystem::Tt),
+            "UT1" => Ok(TimeSystem::Ut1),
+            "UTC" => Ok(TimeSystem::Utc),
+            _ => Err(UnknownTimeSystemError(s.to_owned())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum AdmError {
+    #[error("invalid attitude direction `{0}`, expected `A2B` or `B2A`")]
+    InvalidAttitudeDirection(String),
+    #[error("invalid Euler rotation sequence `{0}`")]
+    InvalidRotationSequence(String),
+    #[error("invalid quaternion type `{0}`, expected `FIRST` or `LAST`")]
+    InvalidQuaternionType(String),
+    #[error("attitude type `{0}` is not supported")]
+    UnsupportedAttitudeType(String),
+    #[error("the attitude data line at {epoch} has {actual} values but {expected} were expected")]
+    InvalidValueCount {
+        epoch: String,
+        expected: usize,
+        actual: usize,
+    },
+    #[error(transparent)]
+    UnitError(#[from] crate::ndm::kvn::UnitError),
+}
+
+/// The direction of the transformation described by the attitude parameters
+/// of an attitude data message, i.e. the value of the `Q_DIR`, `EULER_DIR`,
+/// `SPIN_DIR`, and `ATTITUDE_DIR` keywords
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttitudeDirection {
+    /// The attitude transforms the coordinates of vectors from frame A to
+    /// frame B
+    AToB,
+    /// The attitude transforms the coordinates of vectors from frame B to
+    /// frame A
+    BToA,
+}
+
+impl FromStr for AttitudeDirection {
+    type Err = AdmError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_uppercase().as_str() {
+            "A2B" => Ok(AttitudeDirection::AToB),
+            "B2A" => Ok(AttitudeDirection::BToA),
+            _ => Err(AdmError::InvalidAttitudeDirection(s.to_owned())),
+        }
+    }
+}
+
+/// The sequence of rotation axes of Euler angles, i.e. the value of the
+/// `EULER_ROT_SEQ` keyword
+///
+/// The axes are numbered from 1 to 3 for the X, Y, and Z axes. Both the
+/// numeric form, e.g. `312`, and the form with axis names, e.g. `ZXY`, are
+/// accepted. Consecutive rotations must be about different axes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EulerRotationSequence([u8; 3]);
+
+impl EulerRotationSequence {
+    /// Returns the axes in the order in which the rotations are applied.
+    pub fn axes(&self) -> [u8; 3] {
+        self.0
+    }
+}
+
+impl FromStr for EulerRotationSequence {
+    type Err = AdmError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let axes: Vec<u8> = s
+            .trim()
+            .to_uppercase()
+            .chars()
+            .map(|axis| match axis {
+                '1' | 'X' => Some(1),
+                '2' | 'Y' => Some(2),
+                '3' | 'Z' => Some(3),
+                _ => None,
+            })
+            .collect::<Option<Vec<u8>>>()
+            .ok_or(AdmError::InvalidRotationSequence(s.to_owned()))?;
+        match axes[..] {
+            [a1, a2, a3] if a1 != a2 && a2 != a3 => Ok(Self([a1, a2, a3])),
+            _ => Err(AdmError::InvalidRotationSequence(s.to_owned())),
+        }
+    }
+}
@@ -0,0 +1,242 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Format-agnostic parsing of NDM messages
+//!
+//! The same message types are produced by the KVN and the XML deserializers,
+//! so callers that do not care about the encoding can use [`FromNdmStr`] and
+//! let the input decide which deserializer is used.
+//!
+//! # Example
+//!
+//! ```
+//! # let kvn = r#"CCSDS_OPM_VERS = 3.0
+//! # CREATION_DATE = 2021-06-03T05:33:00.123
+//! # ORIGINATOR = GSOC
+//! # OBJECT_NAME = EUTELSAT W4
+//! # OBJECT_ID = 2021-028A
+//! # CENTER_NAME = EARTH
+//! # REF_FRAME = TOD
+//! # TIME_SYSTEM = UTC
+//! # EPOCH = 2021-06-03T00:00:00.000
+//! # X = 6655.9942 [km]
+//! # Y = -40218.5751 [km]
+//! # Z = -82.9177 [km]
+//! # X_DOT = 3.11548208 [km/s]
+//! # Y_DOT = 0.47042605 [km/s]
+//! # Z_DOT = -0.00101495 [km/s]"#;
+//! # use lox_io::ndm::opm::OpmType;
+//! use lox_io::ndm::format::FromNdmStr;
+//!
+//! // `kvn` could just as well hold the XML representation of the message
+//! let message = OpmType::from_ndm_str(kvn).unwrap();
+//! ```
+
+use thiserror::Error;
+
+use super::kvn::{KvnDeserializer, KvnDeserializerErr};
+use super::xml::{FromXmlStr, XmlDeserializationError};
+
+/// The encoding of an NDM message
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NdmFormat {
+    Kvn,
+    Xml,
+}
+
+impl NdmFormat {
+    /// Detects the encoding of `input`. Messages starting with an XML
+    /// declaration (`<?xml`) are XML, everything else is treated as KVN.
+    /// Leading whitespace and a UTF-8 byte order mark are ignored.
+    pub fn detect(input: &str) -> Self {
+        let input = input.trim_start_matches('\u{feff}').trim_start();
+        if input.starts_with("<?xml") {
+            NdmFormat::Xml
+        } else {
+            NdmFormat::Kvn
+        }
+    }
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum NdmDeserializationError {
+    #[error("invalid KVN message: {0:?}")]
+    Kvn(KvnDeserializerErr<String>),
+    #[error("invalid XML message: {0}")]
+    Xml(XmlDeserializationError),
+}
+
+impl From<KvnDeserializerErr<String>> for NdmDeserializationError {
+    fn from(err: KvnDeserializerErr<String>) -> Self {
+        NdmDeserializationError::Kvn(err)
+    }
+}
+
+impl From<XmlDeserializationError> for NdmDeserializationError {
+    fn from(err: XmlDeserializationError) -> Self {
+        NdmDeserializationError::Xml(err)
+    }
+}
+
+/// Parse an NDM message from a string in either KVN or XML encoding
+pub trait FromNdmStr: Sized {
+    fn from_ndm_str(input: &str) -> Result<Self, NdmDeserializationError>;
+}
+
+impl<T> FromNdmStr for T
+where
+    T: KvnDeserializer + for<'a> FromXmlStr<'a>,
+{
+    fn from_ndm_str(input: &str) -> Result<Self, NdmDeserializationError> {
+        match NdmFormat::detect(input) {
+            NdmFormat::Kvn => Ok(T::from_kvn_str(input)?),
+            NdmFormat::Xml => Ok(T::from_xml_str(input)?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::ndm::oem::OemType;
+    use crate::ndm::omm::OmmType;
+    use crate::ndm::opm::OpmType;
+
+    const OPM_KVN: &str = r#"CCSDS_OPM_VERS = 3.0
+COMMENT Generated by GSOC, R. Kiehling
+CREATION_DATE = 2021-06-03T05:33:00.123
+ORIGINATOR = GSOC
+OBJECT_NAME = EUTELSAT W4
+OBJECT_ID = 2021-028A
+CENTER_NAME = EARTH
+REF_FRAME = TOD
+TIME_SYSTEM = UTC
+COMMENT State Vector
+EPOCH = 2021-06-03T00:00:00.000
+X = 6655.9942 [km]
+Y = -40218.5751 [km]
+Z = -82.9177 [km]
+X_DOT = 3.11548208 [km/s]
+Y_DOT = 0.47042605 [km/s]
+Z_DOT = -0.00101495 [km/s]
+COMMENT Keplerian elements
+SEMI_MAJOR_AXIS = 41399.5123 [km]
+ECCENTRICITY = 0.020842611
+INCLINATION = 0.117746 [deg]
+RA_OF_ASC_NODE = 17.604721 [deg]
+ARG_OF_PERICENTER = 218.242943 [deg]
+TRUE_ANOMALY = 41.922339 [deg]
+GM = 398600.4415 [km**3/s**2]
+COMMENT Spacecraft parameters
+MASS = 1913.000 [kg]
+SOLAR_RAD_AREA = 10.000 [m**2]
+SOLAR_RAD_COEFF = 1.300
+DRAG_AREA = 10.000 [m**2]
+DRAG_COEFF = 2.300
+MAN_EPOCH_IGNITION = 2021-06-03T09:00:34.1
+MAN_DURATION = 132.60 [s]
+MAN_DELTA_MASS = -18.418 [kg]
+MAN_REF_FRAME = EME2000
+MAN_DV_1 = -0.02325700 [km/s]
+MAN_DV_2 = 0.01683160 [km/s]
+MAN_DV_3 = -0.00893444 [km/s]"#;
+
+    const OPM_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<opm id="CCSDS_OPM_VERS" version="3.0">
+    <header>
+        <COMMENT>Generated by GSOC, R. Kiehling</COMMENT>
+        <CREATION_DATE>2021-06-03T05:33:00.123</CREATION_DATE>
+        <ORIGINATOR>GSOC</ORIGINATOR>
+    </header>
+    <body>
+    <segment>
+        <metadata>
+            <OBJECT_NAME>EUTELSAT W4</OBJECT_NAME>
+            <OBJECT_ID>2021-028A</OBJECT_ID>
+            <CENTER_NAME>EARTH</CENTER_NAME>
+            <REF_FRAME>TOD</REF_FRAME>
+            <TIME_SYSTEM>UTC</TIME_SYSTEM>
+        </metadata>
+        <data>
+            <COMMENT>State Vector</COMMENT>
+            <stateVector>
+                <EPOCH>2021-06-03T00:00:00.000</EPOCH>
+                <X units="km">6655.9942</X>
+                <Y units="km">-40218.5751</Y>
+                <Z units="km">-82.9177</Z>
+                <X_DOT units="km/s">3.11548208</X_DOT>
+                <Y_DOT units="km/s">0.47042605</Y_DOT>
+                <Z_DOT units="km/s">-0.00101495</Z_DOT>
+            </stateVector>
+            <keplerianElements>
+                <COMMENT>Keplerian elements</COMMENT>
+                <SEMI_MAJOR_AXIS units="km">41399.5123</SEMI_MAJOR_AXIS>
+                <ECCENTRICITY>0.020842611</ECCENTRICITY>
+                <INCLINATION units="deg">0.117746</INCLINATION>
+                <RA_OF_ASC_NODE units="deg">17.604721</RA_OF_ASC_NODE>
+                <ARG_OF_PERICENTER units="deg">218.242943</ARG_OF_PERICENTER>
+                <TRUE_ANOMALY units="deg">41.922339</TRUE_ANOMALY>
+                <GM units="km**3/s**2">398600.4415</GM>
+            </keplerianElements>
+            <spacecraftParameters>
+                <COMMENT>Spacecraft parameters</COMMENT>
+                <MASS units="kg">1913.000</MASS>
+                <SOLAR_RAD_AREA units="m**2">10.000</SOLAR_RAD_AREA>
+                <SOLAR_RAD_COEFF>1.300</SOLAR_RAD_COEFF>
+                <DRAG_AREA units="m**2">10.000</DRAG_AREA>
+                <DRAG_COEFF>2.300</DRAG_COEFF>
+            </spacecraftParameters>
+            <maneuverParameters>
+                <MAN_EPOCH_IGNITION>2021-06-03T09:00:34.1</MAN_EPOCH_IGNITION>
+                <MAN_DURATION units="s">132.60</MAN_DURATION>
+                <MAN_DELTA_MASS units="kg">-18.418</MAN_DELTA_MASS>
+                <MAN_REF_FRAME>EME2000</MAN_REF_FRAME>
+                <MAN_DV_1 units="km/s">-0.02325700</MAN_DV_1>
+                <MAN_DV_2 units="km/s">0.01683160</MAN_DV_2>
+                <MAN_DV_3 units="km/s">-0.00893444</MAN_DV_3>
+            </maneuverParameters>
+        </data>
+    </segment>
+    </body>
+</opm>"#;
+
+    #[test]
+    fn test_detect_format() {
+        assert_eq!(NdmFormat::detect(OPM_KVN), NdmFormat::Kvn);
+        assert_eq!(NdmFormat::detect(OPM_XML), NdmFormat::Xml);
+        assert_eq!(
+            NdmFormat::detect("\u{feff}\n  <?xml version=\"1.0\"?>"),
+            NdmFormat::Xml
+        );
+    }
+
+    #[test]
+    fn test_opm_kvn_xml_equivalence() {
+        let from_kvn = OpmType::from_ndm_str(OPM_KVN).unwrap();
+        let mut from_xml = OpmType::from_ndm_str(OPM_XML).unwrap();
+
+        // The `id` attribute only exists in the XML encoding
+        assert_eq!(from_xml.id, Some("CCSDS_OPM_VERS".to_string()));
+        from_xml.id = None;
+
+        assert_eq!(from_kvn, from_xml);
+    }
+
+    #[test]
+    fn test_from_ndm_str_errors() {
+        assert!(matches!(
+            OmmType::from_ndm_str("CCSDS_OMM_VERS = 3.0\nCREATION_DATE = foo"),
+            Err(NdmDeserializationError::Kvn(_))
+        ));
+        assert!(matches!(
+            OemType::from_ndm_str("<?xml version=\"1.0\"?>\n<oem><header>"),
+            Err(NdmDeserializationError::Xml(_))
+        ));
+    }
+}
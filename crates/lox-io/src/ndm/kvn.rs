@@ -2,5 +2,8 @@
 
 mod deserializer;
 pub(crate) mod parser;
+mod units;
 
-pub use deserializer::{KvnDeserializer, KvnDeserializerErr};
+pub use deserializer::{KvnContext, KvnDeserializer, KvnDeserializerErr, KvnLineErr};
+pub use parser::{KvnParserMode, KvnValue};
+pub use units::{UnitError, si_conversion_factor, to_angle};
@@ -6,12 +6,90 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::cell::{Cell, RefCell};
+
 use nom::error::ErrorKind;
 
+use super::parser::KvnParserMode;
+
+/// A deserialization error together with the 1-based number of the line on which it occurred.
+pub type KvnLineErr = (usize, KvnDeserializerErr<String>);
+
+/// The state which is threaded through the deserialization of a KVN message.
+#[derive(Debug, Default)]
+pub struct KvnContext {
+    mode: KvnParserMode,
+    errors: Option<RefCell<Vec<KvnLineErr>>>,
+    // The number of lines which have been read from the message so far, which is the number of
+    // the line being parsed because the deserializers only peek one line ahead
+    line_number: Cell<usize>,
+}
+
+impl KvnContext {
+    pub fn new(mode: KvnParserMode) -> Self {
+        Self {
+            mode,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a context which collects recoverable errors instead of failing on the first.
+    pub fn collecting(mode: KvnParserMode) -> Self {
+        Self {
+            mode,
+            errors: Some(RefCell::new(Vec::new())),
+            ..Default::default()
+        }
+    }
+
+    pub fn mode(&self) -> KvnParserMode {
+        self.mode
+    }
+
+    /// Handles the line-level error `err` which occurred while parsing the current line.
+    ///
+    /// If the context is collecting errors, the error is recorded and a default value is returned
+    /// such that the deserialization can continue. Otherwise the error is returned.
+    pub fn recover<T: Default>(
+        &self,
+        err: KvnDeserializerErr<String>,
+    ) -> Result<T, KvnDeserializerErr<String>> {
+        match &self.errors {
+            Some(errors) => {
+                errors.borrow_mut().push((self.line_number.get(), err));
+                Ok(T::default())
+            }
+            None => Err(err),
+        }
+    }
+}
+
 pub trait KvnDeserializer {
     fn deserialize<'a>(
         lines: &mut std::iter::Peekable<impl Iterator<Item = &'a str>>,
     ) -> Result<Self, KvnDeserializerErr<String>>
+    where
+        Self: Sized,
+    {
+        Self::deserialize_with_mode(lines, KvnParserMode::Strict)
+    }
+
+    /// Deserializes the value from `lines`, parsing value lines according to `mode`.
+    fn deserialize_with_mode<'a>(
+        lines: &mut std::iter::Peekable<impl Iterator<Item = &'a str>>,
+        mode: KvnParserMode,
+    ) -> Result<Self, KvnDeserializerErr<String>>
+    where
+        Self: Sized,
+    {
+        Self::deserialize_with_context(lines, &KvnContext::new(mode))
+    }
+
+    /// Deserializes the value from `lines` within `context`.
+    fn deserialize_with_context<'a>(
+        lines: &mut std::iter::Peekable<impl Iterator<Item = &'a str>>,
+        context: &KvnContext,
+    ) -> Result<Self, KvnDeserializerErr<String>>
     where
         Self: Sized;
 
@@ -22,6 +100,49 @@ pub trait KvnDeserializer {
         Self::deserialize(&mut kvn.lines().peekable())
     }
 
+    /// Deserializes the value from the KVN string `kvn`, parsing value lines according to
+    /// `mode`.
+    fn from_kvn_str_with_mode(
+        kvn: &str,
+        mode: KvnParserMode,
+    ) -> Result<Self, KvnDeserializerErr<String>>
+    where
+        Self: Sized,
+    {
+        Self::deserialize_with_mode(&mut kvn.lines().peekable(), mode)
+    }
+
+    /// Deserializes the value from the KVN string `kvn` and returns all errors together with
+    /// their line numbers instead of failing on the first.
+    ///
+    /// Line-level errors, such as malformed numbers or dates, are recorded and the
+    /// deserialization continues. Structural errors, such as a missing mandatory keyword or block
+    /// terminator, end the deserialization and are reported at the line where it stopped.
+    fn deserialize_collecting(kvn: &str) -> Result<Self, Vec<KvnLineErr>>
+    where
+        Self: Sized,
+    {
+        let context = KvnContext::collecting(KvnParserMode::Strict);
+        let line_number = &context.line_number;
+        let mut lines = kvn
+            .lines()
+            .inspect(|_| line_number.set(line_number.get() + 1))
+            .peekable();
+        let result = Self::deserialize_with_context(&mut lines, &context);
+        // Peeking reads the line at which the deserialization stopped, if any
+        lines.peek();
+        let stopped_at = line_number.get();
+        let mut errors = context.errors.map(RefCell::into_inner).unwrap_or_default();
+        match result {
+            Ok(value) if errors.is_empty() => Ok(value),
+            Ok(_) => Err(errors),
+            Err(err) => {
+                errors.push((stopped_at, err));
+                Err(errors)
+            }
+        }
+    }
+
     fn should_check_key_match() -> bool;
 }
 
@@ -29,14 +150,18 @@ pub trait KvnDeserializer {
 pub enum KvnDeserializerErr<I> {
     InvalidDateTimeFormat { input: I },
     InvalidNumberFormat { input: I },
+    InvalidUnit { input: I },
     InvalidStringFormat { input: I },
     InvalidStateVectorFormat { input: I },
     InvalidCovarianceMatrixFormat { input: I },
+    InvalidTrackingDataFormat { input: I },
+    InvalidEphemerisLine { line_number: usize, input: I },
     KeywordNotFound { expected: I },
     // Has a second meaning: it stops the iterator for vector type deserializers
     UnexpectedKeyword { found: I, expected: I },
     EmptyKeyword { input: I },
     EmptyValue { input: I },
     UnexpectedEndOfInput { keyword: I },
+    IoError { message: I },
     GeneralParserError(I, ErrorKind),
 }
@@ -9,6 +9,8 @@
 // This parser handles the Keyword Value Notation (KVN) defined in section
 // 7.4 of CCSDS 502.0-B-3 (https://public.ccsds.org/Pubs/502x0b3e1.pdf).
 
+use std::collections::HashMap;
+
 use regex::Regex;
 
 use super::deserializer::KvnDeserializerErr;
@@ -23,6 +25,14 @@ pub enum KvnStringParserErr<I> {
 #[derive(Debug, PartialEq)]
 pub enum KvnStateVectorParserErr<I> {
     InvalidFormat { input: I },
+    InvalidItemCount { input: I },
+    MalformedNumber { input: I },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum KvnTrackingDataParserErr<I> {
+    InvalidFormat { input: I },
+    MalformedNumber { input: I },
 }
 
 #[derive(Debug, PartialEq)]
@@ -54,11 +64,34 @@ pub enum KvnDateTimeParserErr<I> {
 impl From<KvnStateVectorParserErr<&str>> for KvnDeserializerErr<String> {
     fn from(value: KvnStateVectorParserErr<&str>) -> Self {
         match value {
-            KvnStateVectorParserErr::InvalidFormat { input } => {
+            KvnStateVectorParserErr::InvalidFormat { input }
+            | KvnStateVectorParserErr::InvalidItemCount { input } => {
                 KvnDeserializerErr::InvalidStateVectorFormat {
                     input: input.to_string(),
                 }
             }
+            KvnStateVectorParserErr::MalformedNumber { input } => {
+                KvnDeserializerErr::InvalidNumberFormat {
+                    input: input.to_string(),
+                }
+            }
+        }
+    }
+}
+
+impl From<KvnTrackingDataParserErr<&str>> for KvnDeserializerErr<String> {
+    fn from(value: KvnTrackingDataParserErr<&str>) -> Self {
+        match value {
+            KvnTrackingDataParserErr::InvalidFormat { input } => {
+                KvnDeserializerErr::InvalidTrackingDataFormat {
+                    input: input.to_string(),
+                }
+            }
+            KvnTrackingDataParserErr::MalformedNumber { input } => {
+                KvnDeserializerErr::InvalidNumberFormat {
+                    input: input.to_string(),
+                }
+            }
         }
     }
 }
@@ -131,7 +164,7 @@ impl From<KvnNumberParserErr<&str>> for KvnDeserializerErr<String> {
                 input: input.to_string(),
             },
             KvnNumberParserErr::InvalidFormat { input } => {
-                KvnDeserializerErr::InvalidDateTimeFormat {
+                KvnDeserializerErr::InvalidNumberFormat {
                     input: input.to_string(),
                 }
             }
@@ -147,6 +180,41 @@ impl From<KvnKeywordNotFoundErr<&str>> for KvnDeserializerErr<String> {
     }
 }
 
+/// Controls how strictly KVN value lines are parsed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KvnParserMode {
+    /// Only accept value lines as specified in CCSDS 502.0-B-3. Dates and times must be within
+    /// their calendar ranges.
+    #[default]
+    Strict,
+    /// Like Orekit, tolerate whitespace-separated trailing tokens after numbers and dates, e.g.
+    /// `EPOCH_TZERO = 2021-06-03T05:33:00 UTC` or `MASS = 1913.0 kg`. For numbers, the first
+    /// trailing token is captured as the unit if there is no bracketed unit. All other trailing
+    /// tokens are ignored. Dates and times are only checked for the expected number of digits.
+    Lenient,
+}
+
+/// The trailing tokens tolerated in [KvnParserMode::Lenient].
+const LENIENT_TRAILING_TOKENS: &str = r"(?:\s+(?<trailing>[^\[\s].*?))?";
+
+impl KvnParserMode {
+    fn trailing_tokens(&self) -> &'static str {
+        match self {
+            KvnParserMode::Strict => "",
+            KvnParserMode::Lenient => LENIENT_TRAILING_TOKENS,
+        }
+    }
+}
+
+/// Returns the bracketed unit, or the first trailing token in [KvnParserMode::Lenient].
+fn capture_unit(captures: &regex::Captures) -> Option<String> {
+    captures
+        .name("unit")
+        .or_else(|| captures.name("trailing"))
+        .and_then(|x| x.as_str().split_whitespace().next())
+        .map(|x| x.to_string())
+}
+
 #[derive(PartialEq, Debug, Default)]
 pub struct KvnValue<V, U> {
     pub value: V,
@@ -191,6 +259,13 @@ pub struct KvnStateVectorValue {
     pub z_ddot: Option<f64>,
 }
 
+#[derive(PartialEq, Debug, Default)]
+pub struct KvnTrackingDataValue {
+    pub keyword: String,
+    pub epoch: KvnDateTimeValue,
+    pub value: f64,
+}
+
 #[derive(PartialEq, Debug, Default)]
 pub struct KvnCovarianceMatrixValue {
     pub cx_x: f64,
@@ -255,64 +330,149 @@ pub fn kvn_line_matches_key<'a>(
     Ok(captured_keyword == key)
 }
 
+/// The keyword prefix of user-defined parameters.
+pub const USER_DEFINED_PREFIX: &str = "USER_DEFINED_";
+
+/// The values of user-defined parameters by parameter name.
+pub type KvnUserDefined = HashMap<String, Vec<KvnValue<String, String>>>;
+
+/// Collects all consecutive `USER_DEFINED_*` lines into a map from the parameter name, i.e. the
+/// keyword without the `USER_DEFINED_` prefix, to its values in the order in which they appear,
+/// such that repeated keywords are preserved.
+///
+/// A trailing bracketed unit, e.g. `USER_DEFINED_MASS = 12 [kg]`, is split off the value. The
+/// collection stops at the first line with a different keyword, which is not consumed.
+pub fn parse_kvn_user_defined_lines<'a>(
+    lines: &mut std::iter::Peekable<impl Iterator<Item = &'a str>>,
+) -> Result<KvnUserDefined, KvnDeserializerErr<String>> {
+    let re = Regex::new(r"^(?:\s*)(?<keyword>[0-9A-Z_]*)").unwrap();
+    let unit_re = Regex::new(r"^(?<value>.*?)(?:\s+)\[(?<unit>[0-9A-Za-z/_*%]*)\]$").unwrap();
+
+    let mut items = KvnUserDefined::new();
+
+    while let Some(next_line) = get_next_nonempty_line(lines) {
+        // This unwrap is okay because the keyword uses * so it will always capture
+        let keyword = re
+            .captures(next_line)
+            .unwrap()
+            .name("keyword")
+            .unwrap()
+            .as_str();
+
+        let Some(parameter) = keyword.strip_prefix(USER_DEFINED_PREFIX) else {
+            break;
+        };
+
+        let KvnValue { value, .. } = parse_kvn_string_line(next_line)?;
+        let value = match unit_re.captures(&value) {
+            // These unwraps are okay because the groups are mandatory in the regex
+            Some(captures) => KvnValue {
+                value: captures.name("value").unwrap().as_str().to_string(),
+                unit: Some(captures.name("unit").unwrap().as_str().to_string()),
+            },
+            None => KvnValue { value, unit: None },
+        };
+
+        items.entry(parameter.to_string()).or_default().push(value);
+
+        lines.next();
+    }
+
+    Ok(items)
+}
+
+/// Parses a bare epoch without a keyword, e.g. the time tag of a data line.
+pub fn parse_kvn_epoch(input: &str) -> Option<KvnDateTimeValue> {
+    // Modified from Figure F-5: CCSDS 502.0-B-3 with extension for ddd
+    let re = Regex::new(r"^(?<full_date_value>(?<yr>(?:\d{4}))-((?<mo>(?:\d{1,2}))-(?<dy>(?:\d{1,2})))?(?<ddd>(?:\d{3}))?T(?<hr>(?:\d{1,2})):(?<mn>(?:\d{1,2})):(?<sc>(?:\d{0,2}(?:\.\d*)?)))$").unwrap();
+
+    re.captures(input)
+        .map(|captures| handle_datetime_capture(&captures))
+}
+
+/// Parses a data line consisting of an epoch followed by any number of
+/// whitespace-separated values, e.g. the ephemeris data lines of an OEM or
+/// the attitude data lines of an AEM.
+pub fn parse_kvn_data_line(
+    input: &str,
+) -> Result<(KvnDateTimeValue, Vec<f64>), KvnStateVectorParserErr<&str>> {
+    let mut items = input.split_whitespace();
+
+    // A line which does not start with an epoch is not a data line and
+    // terminates the list of data lines.
+    let epoch = items
+        .next()
+        .ok_or(KvnStateVectorParserErr::InvalidFormat { input })?;
+
+    let datetime =
+        parse_kvn_epoch(epoch).ok_or(KvnStateVectorParserErr::InvalidFormat { input })?;
+
+    let values = items
+        .map(|item| item.parse::<f64>())
+        .collect::<Result<Vec<f64>, _>>()
+        .map_err(|_| KvnStateVectorParserErr::MalformedNumber { input })?;
+
+    Ok((datetime, values))
+}
+
 pub fn parse_kvn_state_vector(
     input: &str,
 ) -> Result<KvnStateVectorValue, KvnStateVectorParserErr<&str>> {
-    // This line is written in regex hell
+    let (datetime, values) = parse_kvn_data_line(input)?;
+
+    // 5.2.4.2 The position and velocity terms shall be mandatory;
+    // acceleration terms may be provided
+    let (x_ddot, y_ddot, z_ddot) = match values.len() {
+        6 => (None, None, None),
+        9 => (Some(values[6]), Some(values[7]), Some(values[8])),
+        _ => return Err(KvnStateVectorParserErr::InvalidItemCount { input }),
+    };
+
+    Ok(KvnStateVectorValue {
+        epoch: datetime,
+        x: values[0],
+        y: values[1],
+        z: values[2],
+        x_dot: values[3],
+        y_dot: values[4],
+        z_dot: values[5],
+        x_ddot,
+        y_ddot,
+        z_ddot,
+    })
+}
+
+/// Parses a tracking data line of the form `KEYWORD = epoch value` as
+/// defined in section 4.3 of CCSDS 503.0-B-2.
+pub fn parse_kvn_tracking_data_line(
+    input: &str,
+) -> Result<KvnTrackingDataValue, KvnTrackingDataParserErr<&str>> {
     let re = Regex::new(
-        r"^(?:\s*)?(?<full_date_value>(?<yr>(?:\d{4}))-(?<mo>(?:\d{1,2}))-(?<dy>(?:\d{1,2}))T(?<hr>(?:\d{1,2})):(?<mn>(?:\d{1,2})):(?<sc>(?:\d{0,2}(?:\.\d*)?)))(?:\s+)(?<x>(?:(?:[^ ]*)?))(?:\s+)(?<y>(?:(?:[^ ]*)?))(?:\s+)(?<z>(?:(?:[^ ]*)?))(?:\s+)(?<x_dot>(?:(?:[^ ]*)?))(?:\s+)(?<y_dot>(?:(?:[^ ]*)?))(?:\s+)(?<z_dot>(?:(?:[^ ]*)?))((?:\s+)(?<x_ddot>(?:(?:[^ ]*)?))(?:\s+)(?<y_ddot>(?:(?:[^ ]*)?))(?:\s+)(?<z_ddot>(?:(?:[^ ]*)?)))?(?:\s*)$",)
+        r"^(?:\s*)(?<keyword>[0-9A-Z_]+)(?:\s*)=(?:\s*)(?<epoch>\S+)(?:\s+)(?<value>\S+)(?:\s*)$",
+    )
     .unwrap();
 
     let captures = re
         .captures(input)
-        .ok_or(KvnStateVectorParserErr::InvalidFormat { input })?;
+        .ok_or(KvnTrackingDataParserErr::InvalidFormat { input })?;
 
-    let datetime = handle_datetime_capture(&captures);
+    // These unwraps are okay because the groups are mandatory in the regex
+    let keyword = captures.name("keyword").unwrap().as_str().to_string();
 
-    let x = captures.name("x").unwrap().as_str().parse::<f64>().unwrap();
-    let y = captures.name("y").unwrap().as_str().parse::<f64>().unwrap();
-    let z = captures.name("z").unwrap().as_str().parse::<f64>().unwrap();
+    let epoch = parse_kvn_epoch(captures.name("epoch").unwrap().as_str())
+        .ok_or(KvnTrackingDataParserErr::InvalidFormat { input })?;
 
-    let x_dot = captures
-        .name("x_dot")
-        .unwrap()
-        .as_str()
-        .parse::<f64>()
-        .unwrap();
-    let y_dot = captures
-        .name("y_dot")
-        .unwrap()
-        .as_str()
-        .parse::<f64>()
-        .unwrap();
-    let z_dot = captures
-        .name("z_dot")
+    let value = captures
+        .name("value")
         .unwrap()
         .as_str()
         .parse::<f64>()
-        .unwrap();
+        .map_err(|_| KvnTrackingDataParserErr::MalformedNumber { input })?;
 
-    let x_ddot = captures
-        .name("x_ddot")
-        .map(|x| x.as_str().parse::<f64>().unwrap());
-    let y_ddot = captures
-        .name("y_ddot")
-        .map(|x| x.as_str().parse::<f64>().unwrap());
-    let z_ddot = captures
-        .name("z_ddot")
-        .map(|x| x.as_str().parse::<f64>().unwrap());
-
-    Ok(KvnStateVectorValue {
-        epoch: datetime,
-        x,
-        y,
-        z,
-        x_dot,
-        y_dot,
-        z_dot,
-        x_ddot,
-        y_ddot,
-        z_ddot,
+    Ok(KvnTrackingDataValue {
+        keyword,
+        epoch,
+        value,
     })
 }
 
@@ -482,6 +642,7 @@ pub fn parse_kvn_string_line(
 pub fn parse_kvn_integer_line<T>(
     input: &str,
     with_unit: bool,
+    mode: KvnParserMode,
 ) -> Result<KvnValue<T, String>, KvnNumberParserErr<&str>>
 where
     T: std::str::FromStr,
@@ -490,14 +651,20 @@ where
         Err(KvnNumberParserErr::EmptyValue { input })?
     };
 
-    let regex_pattern = if with_unit {
-        r"^(?:\s*)(?<keyword>[0-9A-Za-z_]*)(?:\s*)=(?:\s*)(?<value>(?:[-+]?)(?:[0-9]+)(?:\.\d*)?)(?:(?:\s*)(?:\[(?<unit>[0-9A-Za-z/_*]*)\]?))?(?:\s*)?$"
+    let unit_pattern = if with_unit {
+        r"(?:(?:\s*)(?:\[(?<unit>[0-9A-Za-z/_*%]*)\]?))?"
     } else {
-        r"^(?:\s*)(?<keyword>[0-9A-Za-z_]*)(?:\s*)=(?:\s*)(?<value>(?:[-+]?)(?:[0-9]+)(?:\.\d*)?)(?:\s*)$"
+        ""
     };
 
     // Modified from Figure F-9: CCSDS 502.0-B-3
-    let re = Regex::new(regex_pattern).unwrap();
+    let regex_pattern = format!(
+        r"^(?:\s*)(?<keyword>[0-9A-Za-z_]*)(?:\s*)=(?:\s*)(?<value>(?:[-+]?)(?:[0-9]+)(?:\.\d*)?){}{}(?:\s*)?$",
+        unit_pattern,
+        mode.trailing_tokens(),
+    );
+
+    let re = Regex::new(&regex_pattern).unwrap();
 
     let captures = re
         .captures(input)
@@ -517,7 +684,11 @@ where
 
     // This unwrap is okay because the value uses * so it will always capture
     let value = captures.name("value").unwrap().as_str();
-    let unit = captures.name("unit").map(|x| x.as_str().to_string());
+    let unit = if with_unit {
+        capture_unit(&captures)
+    } else {
+        None
+    };
 
     let value = value
         .parse::<T>()
@@ -528,7 +699,7 @@ where
 
 fn is_empty_value(input: &str) -> bool {
     let re = Regex::new(
-        r"^(?:\s*)(?<keyword>[0-9A-Za-z_]*)(?:\s*)=(?:\s*)(?:\[(?<unit>[0-9A-Za-z/_*]*)\]?)?$",
+        r"^(?:\s*)(?<keyword>[0-9A-Za-z_]*)(?:\s*)=(?:\s*)(?:\[(?<unit>[0-9A-Za-z/_*%]*)\]?)?$",
     )
     .unwrap();
 
@@ -538,19 +709,26 @@ fn is_empty_value(input: &str) -> bool {
 pub fn parse_kvn_numeric_line(
     input: &str,
     with_unit: bool,
+    mode: KvnParserMode,
 ) -> Result<KvnValue<f64, String>, KvnNumberParserErr<&str>> {
     if is_empty_value(input) {
         Err(KvnNumberParserErr::EmptyValue { input })?
     };
 
-    let regex_pattern = if with_unit {
-        // Figure F-9: CCSDS 502.0-B-3
-        r"^(?:\s*)(?<keyword>[0-9A-Za-z_]*)(?:\s*)=(?:\s*)(?<value>(?:[-+]?)(?:[0-9]+)(?:\.\d*)?(?:[eE][+-]?(?:\d+))?)(?:(?:\s*)(?:\[(?<unit>[0-9A-Za-z/_*]*)\]?))?(?:\s*)?$"
+    let unit_pattern = if with_unit {
+        r"(?:(?:\s*)(?:\[(?<unit>[0-9A-Za-z/_*%]*)\]?))?"
     } else {
-        r"^(?:\s*)(?<keyword>[0-9A-Za-z_]*)(?:\s*)=(?:\s*)(?<value>(?:[-+]?)(?:[0-9]+)(?:\.\d*)?(?:[eE][+-]?(?:\d+))?)(?:\s*)?$"
+        ""
     };
 
-    let re = Regex::new(regex_pattern).unwrap();
+    // Figure F-9: CCSDS 502.0-B-3
+    let regex_pattern = format!(
+        r"^(?:\s*)(?<keyword>[0-9A-Za-z_]*)(?:\s*)=(?:\s*)(?<value>(?:[-+]?)(?:[0-9]+)(?:\.\d*)?(?:[eE][+-]?(?:\d+))?){}{}(?:\s*)?$",
+        unit_pattern,
+        mode.trailing_tokens(),
+    );
+
+    let re = Regex::new(&regex_pattern).unwrap();
 
     let captures = re
         .captures(input)
@@ -570,7 +748,11 @@ pub fn parse_kvn_numeric_line(
 
     // This unwrap is okay because the value uses * so it will always capture
     let value = captures.name("value").unwrap().as_str();
-    let unit = captures.name("unit").map(|x| x.as_str().to_string());
+    let unit = if with_unit {
+        capture_unit(&captures)
+    } else {
+        None
+    };
 
     let value = value
         .parse::<f64>()
@@ -589,8 +771,9 @@ pub fn handle_datetime_capture(captures: &regex::Captures) -> KvnDateTimeValue {
         .parse::<u16>()
         .unwrap();
 
-    // We don't do full validation of the date values. We only care if they
-    // have the expected number of digits
+    // We don't do full validation of the date values here. We only care if
+    // they have the expected number of digits. The ranges are checked by
+    // parse_kvn_datetime_line in strict mode.
 
     let date_in_year = if let Some(day) = captures.name("ddd") {
         let day = day.as_str().parse::<u16>().unwrap();
@@ -618,16 +801,15 @@ pub fn handle_datetime_capture(captures: &regex::Captures) -> KvnDateTimeValue {
 
     // sc is a mandatory decimal in the regex so we expect the capture to be
     // always there and unwrap is fine
-    let full_second = captures
-        .name("sc")
-        .unwrap()
-        .as_str()
-        .parse::<f64>()
-        .unwrap();
+    let full_second = captures.name("sc").unwrap().as_str();
+
+    // Split the digits instead of parsing the full value as a float so that the
+    // precision of the fraction does not depend on the magnitude of the seconds
+    let (whole_second, fraction_digits) = full_second.split_once('.').unwrap_or((full_second, ""));
 
-    let second = full_second.floor() as u8;
+    let second = whole_second.parse::<u8>().unwrap_or_default();
 
-    let fractional_second = full_second.fract();
+    let fractional_second = format!("0.{fraction_digits}0").parse::<f64>().unwrap();
 
     let full_value = captures
         .name("full_date_value")
@@ -648,13 +830,19 @@ pub fn handle_datetime_capture(captures: &regex::Captures) -> KvnDateTimeValue {
 
 pub fn parse_kvn_datetime_line(
     input: &str,
+    mode: KvnParserMode,
 ) -> Result<KvnDateTimeValue, KvnDateTimeParserErr<&str>> {
     if is_empty_value(input) {
         Err(KvnDateTimeParserErr::EmptyValue { input })?
     };
 
     // Modified from Figure F-5: CCSDS 502.0-B-3 with extension for ddd
-    let re = Regex::new(r"^(?:\s*)?(?<keyword>[0-9A-Z_]*)(?:\s*)?=(?:\s*)?(?<full_date_value>(?<yr>(?:\d{4}))-((?<mo>(?:\d{1,2}))-(?<dy>(?:\d{1,2})))?(?<ddd>(?:\d{3}))?T(?<hr>(?:\d{1,2})):(?<mn>(?:\d{1,2})):(?<sc>(?:\d{0,2}(?:\.\d*)?)))(?:\s*)?$").unwrap();
+    let regex_pattern = format!(
+        r"^(?:\s*)?(?<keyword>[0-9A-Z_]*)(?:\s*)?=(?:\s*)?(?<full_date_value>(?<yr>(?:\d{{4}}))-((?<mo>(?:\d{{1,2}}))-(?<dy>(?:\d{{1,2}})))?(?<ddd>(?:\d{{3}}))?T(?<hr>(?:\d{{1,2}})):(?<mn>(?:\d{{1,2}})):(?<sc>(?:\d{{0,2}}(?:\.\d*)?))){}(?:\s*)?$",
+        mode.trailing_tokens(),
+    );
+
+    let re = Regex::new(&regex_pattern).unwrap();
 
     let captures = re
         .captures(input)
@@ -672,7 +860,47 @@ pub fn parse_kvn_datetime_line(
         return Err(KvnDateTimeParserErr::EmptyKeyword { input });
     }
 
-    Ok(handle_datetime_capture(&captures))
+    let datetime = handle_datetime_capture(&captures);
+
+    // The regex only checks the number of digits. In strict mode the values
+    // must also form a valid date and time of day.
+    if mode == KvnParserMode::Strict && !is_valid_datetime(&datetime) {
+        return Err(KvnDateTimeParserErr::InvalidFormat { input });
+    }
+
+    Ok(datetime)
+}
+
+fn is_leap_year(year: u16) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Checks that the components of `datetime` are within their calendar ranges.
+/// A second value of 60 is permitted to allow for leap seconds.
+fn is_valid_datetime(datetime: &KvnDateTimeValue) -> bool {
+    let is_valid_date = match datetime.date_in_year {
+        DateOfYear::DayOfMonth { month, day } => {
+            (1..=12).contains(&month) && (1..=days_in_month(datetime.year, month)).contains(&day)
+        }
+        DateOfYear::DayOfYear { day } => {
+            let days_in_year = if is_leap_year(datetime.year) {
+                366
+            } else {
+                365
+            };
+            (1..=days_in_year).contains(&day)
+        }
+    };
+    is_valid_date && datetime.hour <= 23 && datetime.minute <= 59 && datetime.second <= 60
 }
 
 #[cfg(test)]
@@ -775,7 +1003,11 @@ mod test {
         // a) there must be at least one blank character between the value and the units text;
         // b) the units must be enclosed within square brackets (e.g., ‘[m]’);
         assert_eq!(
-            parse_kvn_integer_line("SCLK_OFFSET_AT_EPOCH = 28800 [s]", true),
+            parse_kvn_integer_line(
+                "SCLK_OFFSET_AT_EPOCH = 28800 [s]",
+                true,
+                KvnParserMode::Strict
+            ),
             Ok(KvnValue {
                 value: 28800,
                 unit: Some("s".to_string())
@@ -785,7 +1017,11 @@ mod test {
         // 7.4.7 Any white space immediately preceding the end of line shall not be significant.
 
         assert_eq!(
-            parse_kvn_integer_line("SCLK_OFFSET_AT_EPOCH = 28800             [s]", true),
+            parse_kvn_integer_line(
+                "SCLK_OFFSET_AT_EPOCH = 28800             [s]",
+                true,
+                KvnParserMode::Strict
+            ),
             Ok(KvnValue {
                 value: 28800,
                 unit: Some("s".to_string())
@@ -793,7 +1029,11 @@ mod test {
         );
 
         assert_eq!(
-            parse_kvn_integer_line("SCLK_OFFSET_AT_EPOCH = 28800             ", false),
+            parse_kvn_integer_line(
+                "SCLK_OFFSET_AT_EPOCH = 28800             ",
+                false,
+                KvnParserMode::Strict
+            ),
             Ok(KvnValue {
                 value: 28800,
                 unit: None
@@ -803,7 +1043,11 @@ mod test {
         // 7.4.5 Any white space immediately preceding or following the keyword shall not be significant.
 
         assert_eq!(
-            parse_kvn_integer_line("          SCLK_OFFSET_AT_EPOCH = 28800", false),
+            parse_kvn_integer_line(
+                "          SCLK_OFFSET_AT_EPOCH = 28800",
+                false,
+                KvnParserMode::Strict
+            ),
             Ok(KvnValue {
                 value: 28800,
                 unit: None
@@ -811,7 +1055,11 @@ mod test {
         );
 
         assert_eq!(
-            parse_kvn_integer_line("SCLK_OFFSET_AT_EPOCH = 00028800 [s]", true),
+            parse_kvn_integer_line(
+                "SCLK_OFFSET_AT_EPOCH = 00028800 [s]",
+                true,
+                KvnParserMode::Strict
+            ),
             Ok(KvnValue {
                 value: 28800,
                 unit: Some("s".to_string())
@@ -819,7 +1067,11 @@ mod test {
         );
 
         assert_eq!(
-            parse_kvn_integer_line("SCLK_OFFSET_AT_EPOCH = -28800 [s]", true),
+            parse_kvn_integer_line(
+                "SCLK_OFFSET_AT_EPOCH = -28800 [s]",
+                true,
+                KvnParserMode::Strict
+            ),
             Ok(KvnValue {
                 value: -28800,
                 unit: Some("s".to_string())
@@ -827,7 +1079,7 @@ mod test {
         );
 
         assert_eq!(
-            parse_kvn_integer_line("SCLK_OFFSET_AT_EPOCH = -28800", true),
+            parse_kvn_integer_line("SCLK_OFFSET_AT_EPOCH = -28800", true, KvnParserMode::Strict),
             Ok(KvnValue {
                 value: -28800,
                 unit: None
@@ -835,7 +1087,11 @@ mod test {
         );
 
         assert_eq!(
-            parse_kvn_integer_line("SCLK_OFFSET_AT_EPOCH = 28800 [s]", true),
+            parse_kvn_integer_line(
+                "SCLK_OFFSET_AT_EPOCH = 28800 [s]",
+                true,
+                KvnParserMode::Strict
+            ),
             Ok(KvnValue {
                 value: 28800,
                 unit: Some("s".to_string())
@@ -843,53 +1099,73 @@ mod test {
         );
 
         assert_eq!(
-            parse_kvn_integer_line::<u32>("SCLK_OFFSET_AT_EPOCH = 28800 [s]", false),
+            parse_kvn_integer_line::<u32>(
+                "SCLK_OFFSET_AT_EPOCH = 28800 [s]",
+                false,
+                KvnParserMode::Strict
+            ),
             Err(KvnNumberParserErr::InvalidFormat {
                 input: "SCLK_OFFSET_AT_EPOCH = 28800 [s]"
             })
         );
 
         assert_eq!(
-            parse_kvn_integer_line::<u32>("SCLK_OFFSET_AT_EPOCH = -asd", true),
+            parse_kvn_integer_line::<u32>(
+                "SCLK_OFFSET_AT_EPOCH = -asd",
+                true,
+                KvnParserMode::Strict
+            ),
             Err(KvnNumberParserErr::InvalidFormat {
                 input: "SCLK_OFFSET_AT_EPOCH = -asd"
             })
         );
 
         assert_eq!(
-            parse_kvn_integer_line::<u32>("SCLK_OFFSET_AT_EPOCH = [s]", true),
+            parse_kvn_integer_line::<u32>(
+                "SCLK_OFFSET_AT_EPOCH = [s]",
+                true,
+                KvnParserMode::Strict
+            ),
             Err(KvnNumberParserErr::EmptyValue {
                 input: "SCLK_OFFSET_AT_EPOCH = [s]"
             })
         );
 
         assert_eq!(
-            parse_kvn_integer_line::<u32>("SCLK_OFFSET_AT_EPOCH =    ", false),
+            parse_kvn_integer_line::<u32>(
+                "SCLK_OFFSET_AT_EPOCH =    ",
+                false,
+                KvnParserMode::Strict
+            ),
             Err(KvnNumberParserErr::EmptyValue {
                 input: "SCLK_OFFSET_AT_EPOCH =    "
             })
         );
         assert_eq!(
-            parse_kvn_integer_line::<u32>("SCLK_OFFSET_AT_EPOCH = ", false),
+            parse_kvn_integer_line::<u32>("SCLK_OFFSET_AT_EPOCH = ", false, KvnParserMode::Strict),
             Err(KvnNumberParserErr::EmptyValue {
                 input: "SCLK_OFFSET_AT_EPOCH = "
             })
         );
         assert_eq!(
-            parse_kvn_integer_line::<u32>("SCLK_OFFSET_AT_EPOCH =", false),
+            parse_kvn_integer_line::<u32>("SCLK_OFFSET_AT_EPOCH =", false, KvnParserMode::Strict),
             Err(KvnNumberParserErr::EmptyValue {
                 input: "SCLK_OFFSET_AT_EPOCH ="
             })
         );
 
         assert_eq!(
-            parse_kvn_integer_line::<u32>("SCLK_OFFSET_AT_EPOCH   [km]", true),
+            parse_kvn_integer_line::<u32>(
+                "SCLK_OFFSET_AT_EPOCH   [km]",
+                true,
+                KvnParserMode::Strict
+            ),
             Err(KvnNumberParserErr::InvalidFormat {
                 input: "SCLK_OFFSET_AT_EPOCH   [km]"
             })
         );
         assert_eq!(
-            parse_kvn_integer_line::<u32>(" = 123 [km]", true),
+            parse_kvn_integer_line::<u32>(" = 123 [km]", true, KvnParserMode::Strict),
             Err(KvnNumberParserErr::EmptyKeyword {
                 input: " = 123 [km]"
             })
@@ -901,7 +1177,7 @@ mod test {
         // a) there must be at least one blank character between the value and the units text;
         // b) the units must be enclosed within square brackets (e.g., ‘[m]’);
         assert_eq!(
-            parse_kvn_numeric_line("X = 66559942 [km]", true),
+            parse_kvn_numeric_line("X = 66559942 [km]", true, KvnParserMode::Strict),
             Ok(KvnValue {
                 value: 66559942f64,
                 unit: Some("km".to_string())
@@ -911,7 +1187,7 @@ mod test {
         // 7.4.7 Any white space immediately preceding the end of line shall not be significant.
 
         assert_eq!(
-            parse_kvn_numeric_line("X = 66559942             [km]", true),
+            parse_kvn_numeric_line("X = 66559942             [km]", true, KvnParserMode::Strict),
             Ok(KvnValue {
                 value: 66559942f64,
                 unit: Some("km".to_string())
@@ -919,7 +1195,7 @@ mod test {
         );
 
         assert_eq!(
-            parse_kvn_numeric_line("X = 66559942             ", false),
+            parse_kvn_numeric_line("X = 66559942             ", false, KvnParserMode::Strict),
             Ok(KvnValue {
                 value: 66559942f64,
                 unit: None
@@ -929,7 +1205,7 @@ mod test {
         // 7.4.5 Any white space immediately preceding or following the keyword shall not be significant.
 
         assert_eq!(
-            parse_kvn_numeric_line("          X = 66559942", false),
+            parse_kvn_numeric_line("          X = 66559942", false, KvnParserMode::Strict),
             Ok(KvnValue {
                 value: 66559942f64,
                 unit: None
@@ -937,7 +1213,7 @@ mod test {
         );
 
         assert_eq!(
-            parse_kvn_numeric_line("X = 6655.9942 [km]", true),
+            parse_kvn_numeric_line("X = 6655.9942 [km]", true, KvnParserMode::Strict),
             Ok(KvnValue {
                 value: 6655.9942,
                 unit: Some("km".to_string())
@@ -945,7 +1221,7 @@ mod test {
         );
 
         assert_eq!(
-            parse_kvn_numeric_line("CX_X =  5.801003223606e-05", true),
+            parse_kvn_numeric_line("CX_X =  5.801003223606e-05", true, KvnParserMode::Strict),
             Ok(KvnValue {
                 value: 5.801003223606e-05,
                 unit: None
@@ -953,34 +1229,34 @@ mod test {
         );
 
         assert_eq!(
-            parse_kvn_numeric_line("X = -asd", true),
+            parse_kvn_numeric_line("X = -asd", true, KvnParserMode::Strict),
             Err(KvnNumberParserErr::InvalidFormat { input: "X = -asd" })
         );
 
         assert_eq!(
-            parse_kvn_numeric_line("X = [s]", true),
+            parse_kvn_numeric_line("X = [s]", true, KvnParserMode::Strict),
             Err(KvnNumberParserErr::EmptyValue { input: "X = [s]" })
         );
 
         assert_eq!(
-            parse_kvn_numeric_line("X =    ", false),
+            parse_kvn_numeric_line("X =    ", false, KvnParserMode::Strict),
             Err(KvnNumberParserErr::EmptyValue { input: "X =    " })
         );
         assert_eq!(
-            parse_kvn_numeric_line("X = ", false),
+            parse_kvn_numeric_line("X = ", false, KvnParserMode::Strict),
             Err(KvnNumberParserErr::EmptyValue { input: "X = " })
         );
         assert_eq!(
-            parse_kvn_numeric_line("X =", false),
+            parse_kvn_numeric_line("X =", false, KvnParserMode::Strict),
             Err(KvnNumberParserErr::EmptyValue { input: "X =" })
         );
 
         assert_eq!(
-            parse_kvn_numeric_line("X   [km]", true),
+            parse_kvn_numeric_line("X   [km]", true, KvnParserMode::Strict),
             Err(KvnNumberParserErr::InvalidFormat { input: "X   [km]" })
         );
         assert_eq!(
-            parse_kvn_numeric_line(" = 123 [km]", true),
+            parse_kvn_numeric_line(" = 123 [km]", true, KvnParserMode::Strict),
             Err(KvnNumberParserErr::EmptyKeyword {
                 input: " = 123 [km]"
             })
@@ -990,7 +1266,10 @@ mod test {
     #[test]
     fn test_parse_kvn_datetime_line() {
         assert_eq!(
-            parse_kvn_datetime_line("CREATION_DATE = 2021-06-03T05:33:00.123"),
+            parse_kvn_datetime_line(
+                "CREATION_DATE = 2021-06-03T05:33:00.123",
+                KvnParserMode::Strict
+            ),
             Ok(KvnDateTimeValue {
                 year: 2021,
                 date_in_year: DateOfYear::DayOfMonth { month: 6, day: 3 },
@@ -1002,8 +1281,20 @@ mod test {
             })
         );
 
+        // Digits beyond the precision of the seconds must be preserved in the fraction
+        let datetime = parse_kvn_datetime_line(
+            "CREATION_DATE = 2021-06-03T05:33:59.123456789012",
+            KvnParserMode::Strict,
+        )
+        .unwrap();
+        assert_eq!(datetime.second, 59);
+        assert_eq!(
+            (datetime.fractional_second * 1e12).round() as u64,
+            123456789012
+        );
+
         assert_eq!(
-            parse_kvn_datetime_line("CREATION_DATE = 2021-06-03T05:33:01"),
+            parse_kvn_datetime_line("CREATION_DATE = 2021-06-03T05:33:01", KvnParserMode::Strict),
             Ok(KvnDateTimeValue {
                 year: 2021,
                 date_in_year: DateOfYear::DayOfMonth { month: 6, day: 3 },
@@ -1018,7 +1309,10 @@ mod test {
         // 7.4.7 Any white space immediately preceding the end of line shall not be significant.
 
         assert_eq!(
-            parse_kvn_datetime_line("CREATION_DATE = 2021-06-03T05:33:01           "),
+            parse_kvn_datetime_line(
+                "CREATION_DATE = 2021-06-03T05:33:01           ",
+                KvnParserMode::Strict
+            ),
             Ok(KvnDateTimeValue {
                 year: 2021,
                 date_in_year: DateOfYear::DayOfMonth { month: 6, day: 3 },
@@ -1033,7 +1327,10 @@ mod test {
         // 7.4.5 Any white space immediately preceding or following the keyword shall not be significant.
 
         assert_eq!(
-            parse_kvn_datetime_line("          CREATION_DATE = 2021-06-03T05:33:01"),
+            parse_kvn_datetime_line(
+                "          CREATION_DATE = 2021-06-03T05:33:01",
+                KvnParserMode::Strict
+            ),
             Ok(KvnDateTimeValue {
                 year: 2021,
                 date_in_year: DateOfYear::DayOfMonth { month: 6, day: 3 },
@@ -1046,7 +1343,7 @@ mod test {
         );
 
         assert_eq!(
-            parse_kvn_datetime_line("CREATION_DATE = 2021-090T05:33:01"),
+            parse_kvn_datetime_line("CREATION_DATE = 2021-090T05:33:01", KvnParserMode::Strict),
             Ok(KvnDateTimeValue {
                 year: 2021,
                 date_in_year: DateOfYear::DayOfYear { day: 90 },
@@ -1059,7 +1356,8 @@ mod test {
         );
 
         assert_eq!(
-            parse_kvn_datetime_line("CREATION_DATE = 2021-366T05:33:01"),
+            // 2021 is not a leap year, so day 366 is only accepted in lenient mode
+            parse_kvn_datetime_line("CREATION_DATE = 2021-366T05:33:01", KvnParserMode::Lenient),
             Ok(KvnDateTimeValue {
                 year: 2021,
                 date_in_year: DateOfYear::DayOfYear { day: 366 },
@@ -1072,48 +1370,51 @@ mod test {
         );
 
         assert_eq!(
-            parse_kvn_datetime_line("CREATION_DATE = 2021,06,03Q05!33!00-123"),
+            parse_kvn_datetime_line(
+                "CREATION_DATE = 2021,06,03Q05!33!00-123",
+                KvnParserMode::Strict
+            ),
             Err(KvnDateTimeParserErr::InvalidFormat {
                 input: "CREATION_DATE = 2021,06,03Q05!33!00-123"
             })
         );
 
         assert_eq!(
-            parse_kvn_datetime_line("CREATION_DATE = asdffggg"),
+            parse_kvn_datetime_line("CREATION_DATE = asdffggg", KvnParserMode::Strict),
             Err(KvnDateTimeParserErr::InvalidFormat {
                 input: "CREATION_DATE = asdffggg"
             })
         );
 
         assert_eq!(
-            parse_kvn_datetime_line("CREATION_DATE = "),
+            parse_kvn_datetime_line("CREATION_DATE = ", KvnParserMode::Strict),
             Err(KvnDateTimeParserErr::EmptyValue {
                 input: "CREATION_DATE = "
             })
         );
 
         assert_eq!(
-            parse_kvn_datetime_line("CREATION_DATE =    "),
+            parse_kvn_datetime_line("CREATION_DATE =    ", KvnParserMode::Strict),
             Err(KvnDateTimeParserErr::EmptyValue {
                 input: "CREATION_DATE =    "
             })
         );
 
         assert_eq!(
-            parse_kvn_datetime_line("CREATION_DATE ="),
+            parse_kvn_datetime_line("CREATION_DATE =", KvnParserMode::Strict),
             Err(KvnDateTimeParserErr::EmptyValue {
                 input: "CREATION_DATE ="
             })
         );
 
         assert_eq!(
-            parse_kvn_datetime_line("CREATION_DATE     "),
+            parse_kvn_datetime_line("CREATION_DATE     ", KvnParserMode::Strict),
             Err(KvnDateTimeParserErr::InvalidFormat {
                 input: "CREATION_DATE     "
             })
         );
         assert_eq!(
-            parse_kvn_datetime_line(" = 2021-06-03T05:33:01"),
+            parse_kvn_datetime_line(" = 2021-06-03T05:33:01", KvnParserMode::Strict),
             Err(KvnDateTimeParserErr::EmptyKeyword {
                 input: " = 2021-06-03T05:33:01"
             })
@@ -1156,6 +1457,290 @@ mod test {
         )
     }
 
+    #[rstest::rstest]
+    #[case::month_zero("2021-00-03T05:33:00")]
+    #[case::month_thirteen("2021-13-03T05:33:00")]
+    #[case::day_zero("2021-06-00T05:33:00")]
+    #[case::day_after_end_of_month("2021-06-31T05:33:00")]
+    #[case::february_29_in_common_year("2021-02-29T05:33:00")]
+    #[case::february_29_in_century("1900-02-29T05:33:00")]
+    #[case::day_of_year_zero("2021-000T05:33:00")]
+    #[case::day_of_year_366_in_common_year("2021-366T05:33:00")]
+    #[case::hour("2021-06-03T24:33:00")]
+    #[case::minute("2021-06-03T05:60:00")]
+    #[case::second("2021-06-03T05:33:61")]
+    #[case::everything("2021-13-40T25:61:61")]
+    fn test_parse_kvn_datetime_line_invalid_ranges(#[case] value: &str) {
+        let input = format!("CREATION_DATE = {value}");
+        assert_eq!(
+            parse_kvn_datetime_line(&input, KvnParserMode::Strict),
+            Err(KvnDateTimeParserErr::InvalidFormat {
+                input: input.as_str()
+            })
+        );
+        assert_eq!(
+            parse_kvn_datetime_line(&input, KvnParserMode::Lenient)
+                .unwrap()
+                .full_value,
+            value
+        );
+    }
+
+    #[rstest::rstest]
+    #[case::leap_day("2020-02-29T05:33:00")]
+    #[case::leap_day_in_400th_year("2000-02-29T05:33:00")]
+    #[case::day_of_year_366_in_leap_year("2020-366T05:33:00")]
+    #[case::leap_second("2016-12-31T23:59:60.5")]
+    fn test_parse_kvn_datetime_line_valid_ranges(#[case] value: &str) {
+        let input = format!("CREATION_DATE = {value}");
+        assert_eq!(
+            parse_kvn_datetime_line(&input, KvnParserMode::Strict)
+                .unwrap()
+                .full_value,
+            value
+        );
+    }
+
+    #[test]
+    fn test_parse_lenient_mode() {
+        let input = "EPOCH_TZERO = 2021-06-03T05:33:00 UTC";
+        assert_eq!(
+            parse_kvn_datetime_line(input, KvnParserMode::Strict),
+            Err(KvnDateTimeParserErr::InvalidFormat { input })
+        );
+        assert_eq!(
+            parse_kvn_datetime_line(input, KvnParserMode::Lenient)
+                .unwrap()
+                .full_value,
+            "2021-06-03T05:33:00"
+        );
+
+        let input = "MASS = 1913.0 kg";
+        assert_eq!(
+            parse_kvn_numeric_line(input, true, KvnParserMode::Strict),
+            Err(KvnNumberParserErr::InvalidFormat { input })
+        );
+        assert_eq!(
+            parse_kvn_numeric_line(input, true, KvnParserMode::Lenient),
+            Ok(KvnValue {
+                value: 1913.0,
+                unit: Some("kg".to_string())
+            })
+        );
+        assert_eq!(
+            parse_kvn_numeric_line("MASS = 1913.0 [kg] nominal", true, KvnParserMode::Lenient),
+            Ok(KvnValue {
+                value: 1913.0,
+                unit: Some("kg".to_string())
+            })
+        );
+        assert_eq!(
+            parse_kvn_numeric_line(input, false, KvnParserMode::Lenient),
+            Ok(KvnValue {
+                value: 1913.0,
+                unit: None
+            })
+        );
+
+        let input = "SCLK_OFFSET_AT_EPOCH = 28800 s";
+        assert_eq!(
+            parse_kvn_integer_line::<i32>(input, true, KvnParserMode::Strict),
+            Err(KvnNumberParserErr::InvalidFormat { input })
+        );
+        assert_eq!(
+            parse_kvn_integer_line::<i32>(input, true, KvnParserMode::Lenient),
+            Ok(KvnValue {
+                value: 28800,
+                unit: Some("s".to_string())
+            })
+        );
+
+        // Values must still be separated from trailing tokens by whitespace
+        let input = "MASS = 1913.0kg";
+        assert_eq!(
+            parse_kvn_numeric_line(input, true, KvnParserMode::Lenient),
+            Err(KvnNumberParserErr::InvalidFormat { input })
+        );
+    }
+
+    #[test]
+    fn test_deserialize_lenient_mode() {
+        use crate::ndm::kvn::KvnDeserializer;
+
+        let kvn = r#"CCSDS_ASD_VERS = 3.0
+        SEMI_MAJOR_AXIS = 41399.5123 km
+        ASDFG = 12333.5123 extra"#;
+
+        assert_eq!(
+            AsdType::from_kvn_str_with_mode(kvn, KvnParserMode::Strict),
+            Err(KvnDeserializerErr::InvalidNumberFormat {
+                input: "        SEMI_MAJOR_AXIS = 41399.5123 km".to_string()
+            })
+        );
+        assert_eq!(
+            AsdType::from_kvn_str_with_mode(kvn, KvnParserMode::Lenient),
+            Ok(AsdType {
+                semi_major_axis: DistanceType {
+                    base: 41399.5123,
+                    units: Some(PositionUnits("km".to_string())),
+                },
+                asdfg: 12333.5123f64,
+                version: "3.0".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_deserialize_collecting() {
+        use crate::ndm::kvn::KvnDeserializer;
+
+        let kvn = r#"CCSDS_ASD_VERS = 3.0
+        SEMI_MAJOR_AXIS = 4139x.5123 [km]
+        ASDFG = 1233y.5123"#;
+
+        assert_eq!(
+            AsdType::deserialize_collecting(kvn),
+            Err(vec![
+                (
+                    2,
+                    KvnDeserializerErr::InvalidNumberFormat {
+                        input: "        SEMI_MAJOR_AXIS = 4139x.5123 [km]".to_string()
+                    }
+                ),
+                (
+                    3,
+                    KvnDeserializerErr::InvalidNumberFormat {
+                        input: "        ASDFG = 1233y.5123".to_string()
+                    }
+                ),
+            ])
+        );
+
+        // Empty lines are counted
+        let kvn =
+            "CCSDS_ASD_VERS = 3.0\n\nSEMI_MAJOR_AXIS = 41399.5123 [km]\n\n\nASDFG = 1233y.5123";
+
+        assert_eq!(
+            AsdType::deserialize_collecting(kvn),
+            Err(vec![(
+                6,
+                KvnDeserializerErr::InvalidNumberFormat {
+                    input: "ASDFG = 1233y.5123".to_string()
+                }
+            )])
+        );
+
+        // Structural errors end the deserialization
+        let kvn = r#"CCSDS_ASD_VERS = 3.0
+        ASDFG = 1233y.5123
+        SEMI_MAJOR_AXIS = 41399.5123 [km]"#;
+
+        assert_eq!(
+            AsdType::deserialize_collecting(kvn),
+            Err(vec![(
+                2,
+                KvnDeserializerErr::KeywordNotFound {
+                    expected: "SEMI_MAJOR_AXIS".to_string(),
+                }
+            )])
+        );
+
+        let kvn = r#"CCSDS_ASD_VERS = 3.0
+        SEMI_MAJOR_AXIS = 41399.5123 [km]
+        ASDFG = 12333.5123"#;
+
+        assert_eq!(
+            AsdType::deserialize_collecting(kvn),
+            Ok(AsdType {
+                semi_major_axis: DistanceType {
+                    base: 41399.5123,
+                    units: Some(PositionUnits("km".to_string())),
+                },
+                asdfg: 12333.5123f64,
+                version: "3.0".to_string(),
+            })
+        );
+    }
+
+    #[derive(KvnDeserialize, Default, Debug, PartialEq)]
+    struct UserDefinedTestType {
+        pub asdfg: f64,
+        pub user_defined: HashMap<String, Vec<KvnValue<String, String>>>,
+    }
+
+    #[test]
+    fn test_parse_user_defined_lines() {
+        let kvn = r#"ASDFG = 12333.5123
+        USER_DEFINED_FOO = foo enters
+        USER_DEFINED_MASS = 12.5 [kg]
+        USER_DEFINED_BAR = a [bar]x"#;
+
+        let expected = HashMap::from([
+            (
+                "FOO".to_string(),
+                vec![KvnValue {
+                    value: "foo enters".to_string(),
+                    unit: None,
+                }],
+            ),
+            (
+                "MASS".to_string(),
+                vec![KvnValue {
+                    value: "12.5".to_string(),
+                    unit: Some("kg".to_string()),
+                }],
+            ),
+            (
+                "BAR".to_string(),
+                vec![KvnValue {
+                    value: "a [bar]x".to_string(),
+                    unit: None,
+                }],
+            ),
+        ]);
+
+        assert_eq!(
+            crate::ndm::kvn::KvnDeserializer::deserialize(&mut kvn.lines().peekable()),
+            Ok(UserDefinedTestType {
+                asdfg: 12333.5123,
+                user_defined: expected,
+            })
+        );
+
+        let kvn = r#"ASDFG = 12333.5123"#;
+
+        assert_eq!(
+            crate::ndm::kvn::KvnDeserializer::deserialize(&mut kvn.lines().peekable()),
+            Ok(UserDefinedTestType {
+                asdfg: 12333.5123,
+                user_defined: HashMap::new(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_user_defined_lines_repeated() {
+        let kvn = r#"USER_DEFINED_FOO = 1
+        USER_DEFINED_BAR = 2
+        USER_DEFINED_FOO = 3 [km]"#;
+
+        let value = |value: &str, unit: Option<&str>| KvnValue {
+            value: value.to_string(),
+            unit: unit.map(str::to_string),
+        };
+
+        assert_eq!(
+            parse_kvn_user_defined_lines(&mut kvn.lines().peekable()),
+            Ok(HashMap::from([
+                (
+                    "FOO".to_string(),
+                    vec![value("1", None), value("3", Some("km"))]
+                ),
+                ("BAR".to_string(), vec![value("2", None)]),
+            ]))
+        );
+    }
+
     #[test]
     fn test_state_vector_parser() {
         // 5.2.4.1 Each set of ephemeris data, including the time tag, must be
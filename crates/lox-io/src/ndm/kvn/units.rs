@@ -0,0 +1,209 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Conversion of the units used in NDM messages to SI units
+
+use std::f64::consts::PI;
+
+use lox_math::types::units::Angle;
+use thiserror::Error;
+
+use super::parser::KvnValue;
+use crate::ndm::common::{AngleType, InclinationType};
+
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum UnitError {
+    #[error("the value has no unit")]
+    MissingUnit,
+    #[error("unknown unit `{0}`")]
+    UnknownUnit(String),
+    #[error("`{0}` is not a unit of angle")]
+    NotAnAngle(String),
+}
+
+/// Returns the factor that converts a value given in `unit` to the
+/// corresponding SI unit, e.g. 1000.0 for `km`.
+///
+/// Angles are normalized to radians. Unit strings are compared
+/// case-insensitively.
+pub fn si_conversion_factor(unit: &str) -> Result<f64, UnitError> {
+    let factor = match unit.trim().to_lowercase().as_str() {
+        "m" | "m/s" | "m/s**2" | "m**2" | "m**3/s**2" => 1.0,
+        "km" | "km/s" | "km/s**2" => 1e3,
+        "km**2" => 1e6,
+        "km**3/s**2" => 1e9,
+        "rad" | "rad/s" | "s" | "kg" => 1.0,
+        "deg" | "deg/s" => PI / 180.0,
+        "d" => SECONDS_PER_DAY,
+        _ => return Err(UnitError::UnknownUnit(unit.to_string())),
+    };
+    Ok(factor)
+}
+
+impl KvnValue<f64, String> {
+    /// Returns the value converted to SI units.
+    ///
+    /// Values without a unit are rejected because their default unit
+    /// depends on the keyword.
+    pub fn to_si(&self) -> Result<f64, UnitError> {
+        let unit = self.unit.as_deref().ok_or(UnitError::MissingUnit)?;
+        Ok(self.value * si_conversion_factor(unit)?)
+    }
+
+    /// Returns the value as an [Angle], where values without a unit are in degrees.
+    pub fn to_angle(&self) -> Result<Angle, UnitError> {
+        to_angle(self.value, self.unit.as_deref())
+    }
+}
+
+/// Converts `value` given in the angular `unit` to an [Angle].
+///
+/// Angles without a unit are in degrees, which is the default unit of all angular keywords in
+/// CCSDS 502.0-B-3.
+pub fn to_angle(value: f64, unit: Option<&str>) -> Result<Angle, UnitError> {
+    match unit.map(|unit| unit.trim().to_lowercase()).as_deref() {
+        None | Some("deg") => Ok(Angle::from_degrees(value)),
+        Some("rad") => Ok(Angle::from_radians(value)),
+        Some(_) => Err(UnitError::NotAnAngle(unit.unwrap_or_default().to_string())),
+    }
+}
+
+impl AngleType {
+    /// Returns the value as an [Angle], where values without a unit are in degrees.
+    pub fn to_angle(&self) -> Result<Angle, UnitError> {
+        to_angle(self.base, self.units.as_ref().map(|units| units.0.as_str()))
+    }
+}
+
+impl InclinationType {
+    /// Returns the value as an [Angle], where values without a unit are in degrees.
+    pub fn to_angle(&self) -> Result<Angle, UnitError> {
+        to_angle(self.base, self.units.as_ref().map(|units| units.0.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(41399.5, "km", 41399500.0)]
+    #[case(41399.5, "m", 41399.5)]
+    #[case(3.11548208, "km/s", 3115.48208)]
+    #[case(180.0, "deg", PI)]
+    #[case(1.5, "rad", 1.5)]
+    #[case(132.6, "s", 132.6)]
+    #[case(1913.0, "kg", 1913.0)]
+    #[case(398600.4415, "km**3/s**2", 3.986004415e14)]
+    #[case(10.0, "KM", 10000.0)]
+    fn test_kvn_value_to_si(#[case] value: f64, #[case] unit: &str, #[case] expected: f64) {
+        let kvn_value = KvnValue {
+            value,
+            unit: Some(unit.to_string()),
+        };
+        assert_float_eq!(kvn_value.to_si().unwrap(), expected, rel <= 1e-15);
+    }
+
+    #[test]
+    fn test_kvn_value_to_si_errors() {
+        let kvn_value = KvnValue {
+            value: 1.0,
+            unit: Some("furlong".to_string()),
+        };
+        assert_eq!(
+            kvn_value.to_si(),
+            Err(UnitError::UnknownUnit("furlong".to_string()))
+        );
+
+        let kvn_value: KvnValue<f64, String> = KvnValue {
+            value: 1.0,
+            unit: None,
+        };
+        assert_eq!(kvn_value.to_si(), Err(UnitError::MissingUnit));
+    }
+
+    #[rstest]
+    #[case(Some("deg"), 180.0, PI)]
+    #[case(Some("DEG"), 90.0, PI / 2.0)]
+    #[case(Some("rad"), 1.5, 1.5)]
+    #[case(None, 51.6, 51.6f64.to_radians())]
+    fn test_to_angle(#[case] unit: Option<&str>, #[case] value: f64, #[case] expected: f64) {
+        let angle = to_angle(value, unit).unwrap();
+        assert_float_eq!(angle.to_radians(), expected, rel <= 1e-15);
+    }
+
+    #[test]
+    fn test_to_angle_not_an_angle() {
+        assert_eq!(
+            to_angle(1.0, Some("km")),
+            Err(UnitError::NotAnAngle("km".to_string()))
+        );
+    }
+
+    #[derive(Debug, PartialEq, lox_derive::KvnDeserialize)]
+    struct AngleTestType {
+        inclination: Angle,
+        ra_of_asc_node: Angle,
+        arg_of_pericenter: Option<Angle>,
+        eccentricity: f64,
+    }
+
+    #[test]
+    fn test_kvn_deserialize_angle() {
+        use crate::ndm::kvn::KvnDeserializer;
+
+        // Units may be omitted for some keywords and given for others
+        let kvn = "INCLINATION = 51.6 [deg]
+RA_OF_ASC_NODE = 120.0
+ARG_OF_PERICENTER = 1.5 [rad]
+ECCENTRICITY = 0.001";
+        let actual = AngleTestType::from_kvn_str(kvn).unwrap();
+        assert_eq!(
+            actual,
+            AngleTestType {
+                inclination: Angle::from_degrees(51.6),
+                ra_of_asc_node: Angle::from_degrees(120.0),
+                arg_of_pericenter: Some(Angle::from_radians(1.5)),
+                eccentricity: 0.001,
+            }
+        );
+
+        let kvn = "INCLINATION = 51.6 [km]
+RA_OF_ASC_NODE = 120.0
+ECCENTRICITY = 0.001";
+        assert_eq!(
+            AngleTestType::from_kvn_str(kvn),
+            Err(crate::ndm::kvn::KvnDeserializerErr::InvalidUnit {
+                input: "INCLINATION = 51.6 [km]".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_angle_type_to_angle() {
+        let angle = AngleType {
+            base: 180.0,
+            units: Some(crate::ndm::common::AngleUnits("deg".to_string())),
+        };
+        assert_float_eq!(angle.to_angle().unwrap().to_radians(), PI, rel <= 1e-15);
+        let inclination = InclinationType {
+            base: 90.0,
+            units: None,
+        };
+        assert_float_eq!(
+            inclination.to_angle().unwrap().to_degrees(),
+            90.0,
+            rel <= 1e-15
+        );
+    }
+}
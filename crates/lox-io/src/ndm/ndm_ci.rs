@@ -1331,4 +1331,105 @@ mod test {
             },
         );
     }
+
+    #[test]
+    fn test_parse_combined_ndm_opm_and_oem() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ndm xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"
+     xsi:noNamespaceSchemaLocation="https://sanaregistry.org/r/ndmxml/ndmxml-1.0-master.xsd">
+<opm id="CCSDS_OPM_VERS" version="3.0">
+<header>
+   <CREATION_DATE>2021-06-03T05:33:00.123</CREATION_DATE>
+   <ORIGINATOR>GSOC</ORIGINATOR>
+</header>
+<body>
+   <segment>
+      <metadata>
+         <OBJECT_NAME>EUTELSAT W4</OBJECT_NAME>
+         <OBJECT_ID>2021-028A</OBJECT_ID>
+         <CENTER_NAME>EARTH</CENTER_NAME>
+         <REF_FRAME>TOD</REF_FRAME>
+         <TIME_SYSTEM>UTC</TIME_SYSTEM>
+      </metadata>
+      <data>
+         <stateVector>
+            <EPOCH>2021-06-03T00:00:00.000</EPOCH>
+            <X units="km">6655.9942</X>
+            <Y units="km">-40218.5751</Y>
+            <Z units="km">-82.9177</Z>
+            <X_DOT units="km/s">3.11548208</X_DOT>
+            <Y_DOT units="km/s">0.47042605</Y_DOT>
+            <Z_DOT units="km/s">-0.00101495</Z_DOT>
+         </stateVector>
+      </data>
+   </segment>
+</body>
+</opm>
+<oem id="CCSDS_OEM_VERS" version="3.0">
+<header>
+   <CREATION_DATE>1996-11-04T17:22:31</CREATION_DATE>
+   <ORIGINATOR>NASA/JPL</ORIGINATOR>
+</header>
+<body>
+   <segment>
+      <metadata>
+         <OBJECT_NAME>MARS GLOBAL SURVEYOR</OBJECT_NAME>
+         <OBJECT_ID>1996-062A</OBJECT_ID>
+         <CENTER_NAME>MARS BARYCENTER</CENTER_NAME>
+         <REF_FRAME>EME2000</REF_FRAME>
+         <TIME_SYSTEM>UTC</TIME_SYSTEM>
+         <START_TIME>1996-12-18T12:00:00.331</START_TIME>
+         <STOP_TIME>1996-12-18T12:01:00.331</STOP_TIME>
+      </metadata>
+      <data>
+         <stateVector>
+            <EPOCH>1996-12-18T12:00:00.331</EPOCH>
+            <X>2789.619</X>
+            <Y>-280.045</Y>
+            <Z>-1746.755</Z>
+            <X_DOT>4.73372</X_DOT>
+            <Y_DOT>-2.49586</Y_DOT>
+            <Z_DOT>-1.04195</Z_DOT>
+         </stateVector>
+         <stateVector>
+            <EPOCH>1996-12-18T12:01:00.331</EPOCH>
+            <X>2783.419</X>
+            <Y>-308.143</Y>
+            <Z>-1877.071</Z>
+            <X_DOT>5.18604</X_DOT>
+            <Y_DOT>-2.42124</Y_DOT>
+            <Z_DOT>-1.99608</Z_DOT>
+         </stateVector>
+      </data>
+   </segment>
+</body>
+</oem>
+</ndm>"#;
+
+        let message = NdmType::from_xml_str(xml).unwrap();
+
+        assert_eq!(message.child_list.len(), 2);
+        let NdmChildChoice::Opm(opm) = &message.child_list[0] else {
+            panic!("expected an OPM, got {:?}", message.child_list[0]);
+        };
+        assert_eq!(opm.body.segment.metadata.object_name, "EUTELSAT W4");
+        assert_eq!(opm.body.segment.data.state_vector.x.base, 6655.9942);
+
+        let NdmChildChoice::Oem(oem) = &message.child_list[1] else {
+            panic!("expected an OEM, got {:?}", message.child_list[1]);
+        };
+        let segment = &oem.body.segment_list[0];
+        assert_eq!(segment.metadata.object_name, "MARS GLOBAL SURVEYOR");
+        assert_eq!(segment.data.state_vector_list.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_combined_ndm_unsupported_message() {
+        let xml = r#"<ndm>
+<tdm id="CCSDS_TDM_VERS" version="2.0">
+</tdm>
+</ndm>"#;
+
+        assert!(NdmType::from_xml_str(xml).is_err());
+    }
 }
@@ -86,14 +86,48 @@
 //!
 //! let message = OcmType::from_xml_str(xml).unwrap();
 //! ```
+//!
+//! To deserialize a KVN message:
+//!
+//! ```
+//! # let kvn = r#"CCSDS_OCM_VERS = 3.0
+//! # CREATION_DATE = 1998-11-06T09:23:57
+//! # ORIGINATOR = JAXA
+//! # META_START
+//! # TIME_SYSTEM = UTC
+//! # EPOCH_TZERO = 1998-12-18T00:00:00.0000
+//! # META_STOP
+//! # TRAJ_START
+//! # CENTER_NAME = EARTH
+//! # TRAJ_REF_FRAME = ITRF2000
+//! # TRAJ_TYPE = CARTPV
+//! # 0.0 2854.5 -2916.2 -5360.7 5.90 4.86 0.52
+//! # TRAJ_STOP"#;
+//! #
+//! # use lox_io::ndm::ocm::OcmType;
+//! use lox_io::ndm::kvn::KvnDeserializer;
+//!
+//! let message: OcmType = KvnDeserializer::from_kvn_str(&kvn).unwrap();
+//! ```
+//!
+//! The data lines of the trajectory and covariance blocks are parsed into
+//! [OcmDataLine]s whose time tag is either an absolute epoch or the elapsed
+//! time since `EPOCH_TZERO`. Maneuver data lines are kept as strings and are
+//! only supported in XML messages.
 
 // This file is partially generated with xml-schema-derive from the XSD schema
 // published by CCSDS. Adaptations have been made to simplify the types or
 // allow to simplify the implementation of the KVN parser.
 
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
 use serde;
+use thiserror::Error;
 
 use super::common;
+use super::kvn::parser::{KvnStateVectorParserErr, get_next_nonempty_line, parse_kvn_epoch};
+use super::kvn::{KvnContext, KvnDeserializer, KvnDeserializerErr};
 
 #[derive(
     Clone,
@@ -106,14 +140,15 @@ use super::common;
 )]
 #[serde(default)]
 pub struct OcmType {
+    #[serde(rename = "@id")]
+    // Marked as option for the KVN deserializer
+    pub id: Option<String>,
+    #[serde(rename = "@version")]
+    pub version: String,
     #[serde(rename = "header")]
     pub header: common::OdmHeader,
     #[serde(rename = "body")]
     pub body: OcmBody,
-    #[serde(rename = "@id")]
-    pub id: String,
-    #[serde(rename = "@version")]
-    pub version: String,
 }
 
 impl crate::ndm::xml::FromXmlStr<'_> for OcmType {}
@@ -160,6 +195,7 @@ pub struct OcmSegment {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(prefix_and_postfix_keyword = "META")]
 pub struct OcmMetadata {
     #[serde(rename = "COMMENT")]
     pub comment_list: Vec<String>,
@@ -259,6 +295,13 @@ pub struct OcmMetadata {
     pub celestial_source: Option<String>,
 }
 
+impl OcmMetadata {
+    /// Parses the value of the `TIME_SYSTEM` keyword.
+    pub fn parse_time_system(&self) -> Result<common::TimeSystem, common::UnknownTimeSystemError> {
+        self.time_system.parse()
+    }
+}
+
 #[derive(
     Clone,
     Debug,
@@ -283,6 +326,7 @@ pub struct OcmData {
     #[serde(rename = "od")]
     pub od: Option<OcmOdParametersType>,
     #[serde(rename = "user")]
+    #[kvn(prefix_and_postfix_keyword = "USER")]
     pub user: Option<common::UserDefinedType>,
 }
 
@@ -296,6 +340,7 @@ pub struct OcmData {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(prefix_and_postfix_keyword = "TRAJ")]
 pub struct OcmTrajStateType {
     #[serde(rename = "COMMENT")]
     pub comment_list: Vec<String>,
@@ -336,7 +381,132 @@ pub struct OcmTrajStateType {
     #[serde(rename = "TRAJ_UNITS")]
     pub traj_units: Option<String>,
     #[serde(rename = "trajLine")]
-    pub traj_line_list: Vec<String>,
+    pub traj_line_list: Vec<OcmDataLine>,
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum OcmError {
+    #[error("invalid OCM data line `{0}`")]
+    InvalidDataLine(String),
+}
+
+/// The time tag in the first column of an OCM data line
+#[derive(Clone, Debug, PartialEq)]
+pub enum OcmTimeTag {
+    /// An absolute epoch in the `TIME_SYSTEM` of the segment
+    Epoch(common::EpochType),
+    /// The elapsed time in seconds since `EPOCH_TZERO`
+    Relative(f64),
+}
+
+impl Display for OcmTimeTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcmTimeTag::Epoch(epoch) => write!(f, "{}", epoch.0),
+            OcmTimeTag::Relative(seconds) => write!(f, "{}", seconds),
+        }
+    }
+}
+
+/// A single data line of an OCM trajectory or covariance block, e.g.
+/// `0.0 2854.5 -2916.2 -5360.7 5.90 4.86 0.52`
+///
+/// The number and meaning of the values depend on the `TRAJ_TYPE` or
+/// `COV_TYPE` of the block.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct OcmDataLine {
+    pub time_tag: OcmTimeTag,
+    pub values: Vec<f64>,
+}
+
+fn parse_ocm_data_line(input: &str) -> Result<OcmDataLine, KvnStateVectorParserErr<&str>> {
+    let mut items = input.split_whitespace();
+
+    // A line which does not start with a time tag is not a data line and
+    // terminates the list of data lines.
+    let time_tag = items
+        .next()
+        .and_then(|item| match item.parse::<f64>() {
+            Ok(seconds) => Some(OcmTimeTag::Relative(seconds)),
+            Err(_) => parse_kvn_epoch(item)
+                .map(|epoch| OcmTimeTag::Epoch(common::EpochType(epoch.full_value))),
+        })
+        .ok_or(KvnStateVectorParserErr::InvalidFormat { input })?;
+
+    let values = items
+        .map(|item| item.parse::<f64>())
+        .collect::<Result<Vec<f64>, _>>()
+        .map_err(|_| KvnStateVectorParserErr::MalformedNumber { input })?;
+
+    if values.is_empty() {
+        return Err(KvnStateVectorParserErr::InvalidItemCount { input });
+    }
+
+    Ok(OcmDataLine { time_tag, values })
+}
+
+impl FromStr for OcmDataLine {
+    type Err = OcmError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_ocm_data_line(s).map_err(|_| OcmError::InvalidDataLine(s.to_owned()))
+    }
+}
+
+impl TryFrom<String> for OcmDataLine {
+    type Error = OcmError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for OcmDataLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.time_tag)?;
+        for value in &self.values {
+            write!(f, " {}", value)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<OcmDataLine> for String {
+    fn from(value: OcmDataLine) -> Self {
+        value.to_string()
+    }
+}
+
+impl KvnDeserializer for OcmDataLine {
+    fn deserialize_with_context<'a>(
+        lines: &mut std::iter::Peekable<impl Iterator<Item = &'a str>>,
+        _context: &KvnContext,
+    ) -> Result<Self, KvnDeserializerErr<String>> {
+        let next_line =
+            get_next_nonempty_line(lines).ok_or(KvnDeserializerErr::UnexpectedEndOfInput {
+                keyword: "TRAJ_STOP".to_string(),
+            })?;
+
+        let line = parse_ocm_data_line(next_line).map_err(|err| match err {
+            // This is empty because we just want to tell the vector iterator
+            // to stop the iteration.
+            KvnStateVectorParserErr::InvalidFormat { .. } => {
+                KvnDeserializerErr::UnexpectedKeyword {
+                    found: "".to_string(),
+                    expected: "".to_string(),
+                }
+            }
+            err => KvnDeserializerErr::from(err),
+        })?;
+        lines.next();
+
+        Ok(line)
+    }
+
+    fn should_check_key_match() -> bool {
+        false
+    }
 }
 
 #[derive(
@@ -349,6 +519,7 @@ pub struct OcmTrajStateType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(prefix_and_postfix_keyword = "PHYS")]
 pub struct OcmPhysicalDescriptionType {
     #[serde(rename = "COMMENT")]
     pub comment_list: Vec<String>,
@@ -464,6 +635,7 @@ pub struct OcmPhysicalDescriptionType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(prefix_and_postfix_keyword = "COV")]
 pub struct OcmCovarianceMatrixType {
     #[serde(rename = "COMMENT")]
     pub comment_list: Vec<String>,
@@ -494,7 +666,7 @@ pub struct OcmCovarianceMatrixType {
     #[serde(rename = "COV_UNITS")]
     pub cov_units: Option<String>,
     #[serde(rename = "covLine")]
-    pub cov_line_list: Vec<String>,
+    pub cov_line_list: Vec<OcmDataLine>,
 }
 
 #[derive(
@@ -507,6 +679,7 @@ pub struct OcmCovarianceMatrixType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(prefix_and_postfix_keyword = "MAN")]
 pub struct OcmManeuverParametersType {
     #[serde(rename = "COMMENT")]
     pub comment_list: Vec<String>,
@@ -584,6 +757,7 @@ pub struct OcmManeuverParametersType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(prefix_and_postfix_keyword = "PERT")]
 pub struct OcmPerturbationsType {
     #[serde(rename = "COMMENT")]
     pub comment_list: Vec<String>,
@@ -657,6 +831,7 @@ pub struct OcmPerturbationsType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(prefix_and_postfix_keyword = "OD")]
 pub struct OcmOdParametersType {
     #[serde(rename = "COMMENT")]
     pub comment_list: Vec<String>,
@@ -799,6 +974,7 @@ mod test {
 
         let message = OcmType::from_xml_str(xml).unwrap();
 
+        #[rustfmt::skip]
         assert_eq!(message, OcmType {
             header: common::OdmHeader {
                 comment_list: vec![
@@ -932,7 +1108,22 @@ mod test {
                                 orb_averaging: None,
                                 traj_units: None,
                                 traj_line_list: vec![
-                                    "0.0  2854.5 -2916.2 -5360.7 5.90 4.86 0.52 0.0037 -0.0038 -0.0070".to_string(),
+                                    OcmDataLine {
+                                        time_tag: OcmTimeTag::Relative(
+                                            0.0,
+                                        ),
+                                        values: vec![
+                                            2854.5,
+                                            -2916.2,
+                                            -5360.7,
+                                            5.90,
+                                            4.86,
+                                            0.52,
+                                            0.0037,
+                                            -0.0038,
+                                            -0.0070,
+                                        ],
+                                    },
                                 ],
                             },
                         ],
@@ -1157,8 +1348,178 @@ mod test {
                     },
                 },
             },
-            id: "CCSDS_OCM_VERS".to_string(),
+            id: Some(
+                "CCSDS_OCM_VERS".to_string(),
+            ),
             version: "3.0".to_string(),
         });
     }
+
+    #[test]
+    fn test_parse_ocm_message_kvn() {
+        let kvn = r#"CCSDS_OCM_VERS = 3.0
+COMMENT This OCM reflects the latest conditions post-maneuver A67Z
+CREATION_DATE = 1998-11-06T09:23:57
+ORIGINATOR = JAXA
+MESSAGE_ID = OCM 201113719185
+
+META_START
+COMMENT OCM example with space object characteristics and perturbations.
+OBJECT_NAME = GODZILLA 5
+INTERNATIONAL_DESIGNATOR = 1998-999A
+TIME_SYSTEM = UTC
+EPOCH_TZERO = 1998-12-18T00:00:00.0000
+TAIMUTC_AT_TZERO = 36 [s]
+META_STOP
+
+TRAJ_START
+COMMENT GEOCENTRIC, CARTESIAN, EARTH FIXED
+TRAJ_ID = TRAJ_1
+TRAJ_BASIS = PREDICTED
+CENTER_NAME = EARTH
+TRAJ_REF_FRAME = ITRF2000
+TRAJ_TYPE = CARTPV
+0.0 2854.5 -2916.2 -5360.7 5.90 4.86 0.52
+60.0 3206.3 -2610.4 -5322.6 5.82 5.34 1.16
+TRAJ_STOP
+
+TRAJ_START
+TRAJ_ID = TRAJ_2
+TRAJ_PREV_ID = TRAJ_1
+CENTER_NAME = EARTH
+TRAJ_REF_FRAME = EME2000
+TRAJ_TYPE = KEPLERIAN
+1998-12-18T00:02:00 6655.9942 0.0012 28.5 120.0 45.0 10.0
+TRAJ_STOP
+
+PHYS_START
+COMMENT Spacecraft Physical Characteristics
+WET_MASS = 100.0 [kg]
+OEB_Q1 = 0.03123
+OEB_Q2 = 0.78543
+OEB_Q3 = 0.39158
+OEB_QC = 0.47832
+OEB_MAX = 2.0 [m]
+OEB_INT = 1.0 [m]
+OEB_MIN = 0.5 [m]
+PHYS_STOP
+
+PERT_START
+ATMOSPHERIC_MODEL = NRLMSIS00
+GRAVITY_MODEL = EGM-96: 36D 36O
+PERT_STOP
+
+USER_START
+USER_DEFINED_EARTH_MODEL = WGS-84
+USER_STOP
+"#;
+
+        let message: OcmType = KvnDeserializer::from_kvn_str(kvn).unwrap();
+
+        assert_eq!(message.version, "3.0");
+        assert_eq!(message.header.originator, "JAXA");
+
+        let metadata = &message.body.segment.metadata;
+        assert_eq!(metadata.object_name.as_deref(), Some("GODZILLA 5"));
+        assert_eq!(
+            metadata.parse_time_system().unwrap(),
+            common::TimeSystem::Utc
+        );
+        assert_eq!(metadata.epoch_tzero.0, "1998-12-18T00:00:00.0000");
+        assert_eq!(metadata.taimutc_at_tzero.as_ref().unwrap().base, 36.0);
+
+        let data = &message.body.segment.data;
+        assert_eq!(data.traj_list.len(), 2);
+
+        let traj = &data.traj_list[0];
+        assert_eq!(
+            traj.comment_list,
+            vec!["GEOCENTRIC, CARTESIAN, EARTH FIXED"]
+        );
+        assert_eq!(traj.center_name, "EARTH");
+        assert_eq!(traj.traj_ref_frame, "ITRF2000");
+        assert_eq!(traj.traj_type, "CARTPV");
+        assert_eq!(
+            traj.traj_line_list,
+            vec![
+                OcmDataLine {
+                    time_tag: OcmTimeTag::Relative(0.0),
+                    values: vec![2854.5, -2916.2, -5360.7, 5.90, 4.86, 0.52],
+                },
+                OcmDataLine {
+                    time_tag: OcmTimeTag::Relative(60.0),
+                    values: vec![3206.3, -2610.4, -5322.6, 5.82, 5.34, 1.16],
+                },
+            ]
+        );
+
+        let traj = &data.traj_list[1];
+        assert_eq!(traj.traj_prev_id.as_deref(), Some("TRAJ_1"));
+        assert_eq!(traj.traj_ref_frame, "EME2000");
+        assert_eq!(
+            traj.traj_line_list,
+            vec![OcmDataLine {
+                time_tag: OcmTimeTag::Epoch(common::EpochType("1998-12-18T00:02:00".to_string())),
+                values: vec![6655.9942, 0.0012, 28.5, 120.0, 45.0, 10.0],
+            }]
+        );
+
+        let phys = data.phys.as_ref().unwrap();
+        assert_eq!(
+            phys.comment_list,
+            vec!["Spacecraft Physical Characteristics"]
+        );
+        assert_eq!(phys.wet_mass.as_ref().unwrap().base.0, 100.0);
+        assert_eq!(phys.oeb_qc, Some(0.47832));
+        assert_eq!(phys.oeb_min.as_ref().unwrap().base, 0.5);
+
+        assert!(data.cov_list.is_empty());
+        assert_eq!(
+            data.pert.as_ref().unwrap().atmospheric_model.as_deref(),
+            Some("NRLMSIS00")
+        );
+        assert_eq!(
+            data.user.as_ref().unwrap().user_defined_list[0].base,
+            "WGS-84"
+        );
+    }
+
+    #[test]
+    fn test_parse_ocm_message_kvn_missing_traj_stop() {
+        let kvn = r#"CCSDS_OCM_VERS = 3.0
+CREATION_DATE = 1998-11-06T09:23:57
+ORIGINATOR = JAXA
+META_START
+TIME_SYSTEM = UTC
+EPOCH_TZERO = 1998-12-18T00:00:00.0000
+META_STOP
+TRAJ_START
+CENTER_NAME = EARTH
+TRAJ_REF_FRAME = ITRF2000
+TRAJ_TYPE = CARTPV
+0.0 2854.5 -2916.2 -5360.7 5.90 4.86 0.52
+PHYS_START
+WET_MASS = 100.0 [kg]
+PHYS_STOP
+"#;
+
+        let message: Result<OcmType, _> = KvnDeserializer::from_kvn_str(kvn);
+
+        assert_eq!(
+            message,
+            Err(KvnDeserializerErr::KeywordNotFound {
+                expected: "TRAJ_STOP".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_ocm_data_line_round_trip() {
+        let line: OcmDataLine = "1998-12-18T00:02:00 6655.9942 0.0012".parse().unwrap();
+        assert_eq!(line.to_string(), "1998-12-18T00:02:00 6655.9942 0.0012");
+        assert_eq!(
+            "EARTH 1.0".parse::<OcmDataLine>(),
+            Err(OcmError::InvalidDataLine("EARTH 1.0".to_string()))
+        );
+    }
 }
@@ -101,9 +101,18 @@
 // published by CCSDS. Adaptations have been made to simplify the types or
 // allow to simplify the implementation of the KVN parser.
 
+use std::io::{self, BufRead, Write};
+
 use serde;
 
-use super::{common, kvn::parser::KvnStateVectorValue};
+use super::{
+    common,
+    kvn::parser::{
+        KvnStateVectorValue, get_next_nonempty_line, kvn_line_matches_key, parse_kvn_state_vector,
+        parse_kvn_string_line,
+    },
+    kvn::{KvnDeserializer, KvnDeserializerErr},
+};
 
 #[derive(
     Clone,
@@ -264,8 +273,469 @@ impl From<KvnStateVectorValue> for crate::ndm::common::StateVectorAccType {
     }
 }
 
+/// A single ephemeris data line of an OEM segment.
+///
+/// The values are given in the units mandated by the standard, i.e. km, km/s
+/// and km/s**2.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OemStateVector {
+    pub epoch: common::EpochType,
+    pub position: [f64; 3],
+    pub velocity: [f64; 3],
+    pub acceleration: Option<[f64; 3]>,
+}
+
+impl From<KvnStateVectorValue> for OemStateVector {
+    fn from(value: KvnStateVectorValue) -> Self {
+        let acceleration = match (value.x_ddot, value.y_ddot, value.z_ddot) {
+            (Some(x_ddot), Some(y_ddot), Some(z_ddot)) => Some([x_ddot, y_ddot, z_ddot]),
+            _ => None,
+        };
+
+        Self {
+            epoch: common::EpochType(value.epoch.full_value),
+            position: [value.x, value.y, value.z],
+            velocity: [value.x_dot, value.y_dot, value.z_dot],
+            acceleration,
+        }
+    }
+}
+
+impl From<&common::StateVectorAccType> for OemStateVector {
+    fn from(value: &common::StateVectorAccType) -> Self {
+        let acceleration = match (&value.x_ddot, &value.y_ddot, &value.z_ddot) {
+            (Some(x_ddot), Some(y_ddot), Some(z_ddot)) => {
+                Some([x_ddot.base, y_ddot.base, z_ddot.base])
+            }
+            _ => None,
+        };
+
+        Self {
+            epoch: value.epoch.clone(),
+            position: [value.x.base, value.y.base, value.z.base],
+            velocity: [value.x_dot.base, value.y_dot.base, value.z_dot.base],
+            acceleration,
+        }
+    }
+}
+
+impl OemData {
+    /// Returns the ephemeris data lines of the segment.
+    pub fn state_vectors(&self) -> Vec<OemStateVector> {
+        self.state_vector_list
+            .iter()
+            .map(OemStateVector::from)
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum OemKvnBlock {
+    Header,
+    Metadata,
+    Data,
+    Covariance,
+}
+
+/// Parses the ephemeris data lines of all segments of a KVN OEM message.
+///
+/// The header, metadata and covariance blocks are skipped. Each element of
+/// the returned vector holds the state vectors of one segment in the order
+/// in which they appear in the message.
+///
+/// In contrast to [crate::ndm::kvn::KvnDeserializer::from_kvn_str], the
+/// errors for malformed ephemeris data lines carry the line number.
+pub fn parse_kvn_oem_state_vectors(
+    kvn: &str,
+) -> Result<Vec<Vec<OemStateVector>>, KvnDeserializerErr<String>> {
+    let mut segments: Vec<Vec<OemStateVector>> = Vec::new();
+    let mut block = OemKvnBlock::Header;
+
+    for (index, line) in kvn.lines().enumerate() {
+        let trimmed = line.trim();
+
+        // 7.8.5 All comment lines shall begin with the ‘COMMENT’ keyword
+        // followed by at least one space.
+        if trimmed.is_empty() || trimmed == "COMMENT" || trimmed.starts_with("COMMENT ") {
+            continue;
+        }
+
+        match trimmed {
+            "META_START" => block = OemKvnBlock::Metadata,
+            "META_STOP" => {
+                block = OemKvnBlock::Data;
+                segments.push(Vec::new());
+            }
+            "COVARIANCE_START" => block = OemKvnBlock::Covariance,
+            _ if block == OemKvnBlock::Data => {
+                let state_vector = parse_kvn_state_vector(line).map_err(|_| {
+                    KvnDeserializerErr::InvalidEphemerisLine {
+                        line_number: index + 1,
+                        input: line.to_string(),
+                    }
+                })?;
+
+                // Unwrap is okay because the data block can only be entered
+                // through `META_STOP` which adds a new segment
+                segments.last_mut().unwrap().push(state_vector.into());
+            }
+            _ => {}
+        }
+    }
+
+    Ok(segments)
+}
+
+/// Streaming reader for the ephemeris data of KVN OEM messages.
+///
+/// The header is parsed when the reader is created and the metadata of each
+/// segment is parsed as soon as it is encountered, while the ephemeris data
+/// lines are only parsed one at a time when the iterator is advanced. This
+/// keeps the memory use independent of the size of the message.
+///
+/// A malformed ephemeris data line yields an
+/// [KvnDeserializerErr::InvalidEphemerisLine] error but does not end the
+/// iteration. Use [OemKvnReader::is_segment_start] and
+/// [OemKvnReader::metadata] to detect segment boundaries, e.g. to reset the
+/// interpolation context:
+///
+/// ```
+/// # let kvn = r#"CCSDS_OEM_VERS = 3.0
+/// # CREATION_DATE = 1996-11-04T17:22:31
+/// # ORIGINATOR = NASA/JPL
+/// # META_START
+/// # OBJECT_NAME = MARS GLOBAL SURVEYOR
+/// # OBJECT_ID = 1996-062A
+/// # CENTER_NAME = MARS BARYCENTER
+/// # REF_FRAME = J2000
+/// # TIME_SYSTEM = TAI
+/// # START_TIME = 1996-12-18T12:00:00.331
+/// # STOP_TIME = 1996-12-28T21:28:00.331
+/// # META_STOP
+/// # 1996-12-18T12:00:00.331 2789.619 -280.045 -1746.755 4.73372 -2.49586 -1.04195"#;
+/// use lox_io::ndm::oem::OemKvnReader;
+///
+/// let mut reader = OemKvnReader::new(kvn.as_bytes()).unwrap();
+/// while let Some(state_vector) = reader.next() {
+///     if reader.is_segment_start() {
+///         let metadata = reader.metadata().unwrap();
+///         // ...
+///     }
+///     let state_vector = state_vector.unwrap();
+///     // ...
+/// }
+/// ```
+pub struct OemKvnReader<R> {
+    reader: R,
+    buffer: String,
+    line_number: usize,
+    version: String,
+    header: common::OdmHeader,
+    block: OemKvnBlock,
+    metadata: Option<OemMetadata>,
+    segment_index: Option<usize>,
+    pending_segment_start: bool,
+    is_segment_start: bool,
+}
+
+impl<R: BufRead> OemKvnReader<R> {
+    /// Creates a new reader and parses the header of the message, i.e. all
+    /// lines up to the first `META_START` keyword.
+    pub fn new(reader: R) -> Result<Self, KvnDeserializerErr<String>> {
+        let mut oem_reader = Self {
+            reader,
+            buffer: String::new(),
+            line_number: 0,
+            version: String::new(),
+            header: common::OdmHeader::default(),
+            block: OemKvnBlock::Header,
+            metadata: None,
+            segment_index: None,
+            pending_segment_start: false,
+            is_segment_start: false,
+        };
+
+        let mut header_lines = Vec::new();
+        while oem_reader.read_line()? {
+            if oem_reader.buffer.trim() == "META_START" {
+                oem_reader.block = OemKvnBlock::Metadata;
+                break;
+            }
+            header_lines.push(oem_reader.buffer.clone());
+        }
+
+        let mut lines = header_lines.iter().map(|line| line.as_str()).peekable();
+
+        let version_line =
+            get_next_nonempty_line(&mut lines).ok_or(KvnDeserializerErr::UnexpectedEndOfInput {
+                keyword: "CCSDS_OEM_VERS".to_string(),
+            })?;
+        if !kvn_line_matches_key("CCSDS_OEM_VERS", version_line)? {
+            return Err(KvnDeserializerErr::UnexpectedKeyword {
+                found: version_line.to_string(),
+                expected: "CCSDS_OEM_VERS".to_string(),
+            });
+        }
+        oem_reader.version = parse_kvn_string_line(version_line)?.value;
+        lines.next();
+
+        oem_reader.header = common::OdmHeader::deserialize(&mut lines)?;
+
+        if oem_reader.block == OemKvnBlock::Metadata {
+            oem_reader.read_metadata()?;
+        }
+
+        Ok(oem_reader)
+    }
+
+    /// Returns the value of the `CCSDS_OEM_VERS` keyword.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    pub fn header(&self) -> &common::OdmHeader {
+        &self.header
+    }
+
+    /// Returns the metadata of the current segment.
+    pub fn metadata(&self) -> Option<&OemMetadata> {
+        self.metadata.as_ref()
+    }
+
+    /// Returns the zero-based index of the current segment.
+    pub fn segment_index(&self) -> Option<usize> {
+        self.segment_index
+    }
+
+    /// Returns `true` if the item returned by the last call to `next` was the
+    /// first ephemeris data line of a new segment.
+    pub fn is_segment_start(&self) -> bool {
+        self.is_segment_start
+    }
+
+    fn read_line(&mut self) -> Result<bool, KvnDeserializerErr<String>> {
+        self.buffer.clear();
+        let bytes_read =
+            self.reader
+                .read_line(&mut self.buffer)
+                .map_err(|err| KvnDeserializerErr::IoError {
+                    message: err.to_string(),
+                })?;
+        self.line_number += 1;
+        let trimmed_len = self.buffer.trim_end_matches(['\r', '\n']).len();
+        self.buffer.truncate(trimmed_len);
+        Ok(bytes_read > 0)
+    }
+
+    fn read_metadata(&mut self) -> Result<(), KvnDeserializerErr<String>> {
+        let mut metadata_lines = vec!["META_START".to_string()];
+        while self.read_line()? {
+            metadata_lines.push(self.buffer.clone());
+            if self.buffer.trim() == "META_STOP" {
+                break;
+            }
+        }
+
+        self.block = OemKvnBlock::Data;
+        self.segment_index = Some(self.segment_index.map_or(0, |index| index + 1));
+        self.pending_segment_start = true;
+        self.metadata = None;
+
+        let mut lines = metadata_lines.iter().map(|line| line.as_str()).peekable();
+        self.metadata = Some(OemMetadata::deserialize(&mut lines)?);
+
+        Ok(())
+    }
+}
+
+impl<R: BufRead> Iterator for OemKvnReader<R> {
+    type Item = Result<OemStateVector, KvnDeserializerErr<String>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.read_line() {
+                Ok(true) => {}
+                Ok(false) => return None,
+                Err(err) => return Some(Err(err)),
+            }
+
+            let trimmed = self.buffer.trim();
+
+            if trimmed.is_empty() || trimmed == "COMMENT" || trimmed.starts_with("COMMENT ") {
+                continue;
+            }
+
+            match trimmed {
+                "META_START" => {
+                    if let Err(err) = self.read_metadata() {
+                        return Some(Err(err));
+                    }
+                }
+                "COVARIANCE_START" => self.block = OemKvnBlock::Covariance,
+                _ if self.block == OemKvnBlock::Data => {
+                    self.is_segment_start = self.pending_segment_start;
+                    self.pending_segment_start = false;
+
+                    return Some(
+                        parse_kvn_state_vector(&self.buffer)
+                            .map(OemStateVector::from)
+                            .map_err(|_| KvnDeserializerErr::InvalidEphemerisLine {
+                                line_number: self.line_number,
+                                input: self.buffer.clone(),
+                            }),
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Writer for KVN OEM messages.
+///
+/// The header is written when the writer is created, followed by one
+/// `META_START`/`META_STOP` block and the ephemeris data lines for every call
+/// to [OemKvnWriter::write_segment]. The components of the state vectors are
+/// formatted in fixed-width scientific notation with 17 significant digits,
+/// which ensures that they are read back without loss of precision:
+///
+/// ```
+/// use lox_io::ndm::common::{EpochType, OdmHeader};
+/// use lox_io::ndm::oem::{OemKvnWriter, OemMetadata, OemStateVector, parse_kvn_oem_state_vectors};
+///
+/// let header = OdmHeader {
+///     creation_date: EpochType("2024-01-01T00:00:00".to_string()),
+///     originator: "LOX".to_string(),
+///     ..Default::default()
+/// };
+/// let metadata = OemMetadata {
+///     object_name: "ISS".to_string(),
+///     object_id: "1998-067A".to_string(),
+///     center_name: "EARTH".to_string(),
+///     ref_frame: "ICRF".to_string(),
+///     time_system: "TAI".to_string(),
+///     start_time: EpochType("2024-01-01T00:00:00.000".to_string()),
+///     stop_time: EpochType("2024-01-01T00:00:00.000".to_string()),
+///     ..Default::default()
+/// };
+/// let state_vectors = vec![OemStateVector {
+///     epoch: EpochType("2024-01-01T00:00:00.000".to_string()),
+///     position: [6068.27927, -1692.84394, -2516.61918],
+///     velocity: [-0.660415582, 5.495938726, -5.303093233],
+///     acceleration: None,
+/// }];
+///
+/// let mut writer = OemKvnWriter::new(Vec::new(), "3.0", &header).unwrap();
+/// writer.write_segment(&metadata, &state_vectors).unwrap();
+/// let kvn = String::from_utf8(writer.into_inner()).unwrap();
+///
+/// assert_eq!(parse_kvn_oem_state_vectors(&kvn).unwrap(), vec![state_vectors]);
+/// ```
+pub struct OemKvnWriter<W> {
+    writer: W,
+}
+
+impl<W: Write> OemKvnWriter<W> {
+    /// Creates a new writer and writes the `CCSDS_OEM_VERS` keyword with
+    /// `version` followed by the `header`.
+    pub fn new(mut writer: W, version: &str, header: &common::OdmHeader) -> io::Result<Self> {
+        write_kvn_line(&mut writer, "CCSDS_OEM_VERS", version)?;
+        write_kvn_comments(&mut writer, &header.comment_list)?;
+        for classification in &header.classification_list {
+            write_kvn_line(&mut writer, "CLASSIFICATION", classification)?;
+        }
+        write_kvn_line(&mut writer, "CREATION_DATE", &header.creation_date.0)?;
+        write_kvn_line(&mut writer, "ORIGINATOR", &header.originator)?;
+        if let Some(message_id) = &header.message_id {
+            write_kvn_line(&mut writer, "MESSAGE_ID", message_id)?;
+        }
+        Ok(Self { writer })
+    }
+
+    /// Writes a segment consisting of the `metadata` block and the ephemeris
+    /// data lines for `state_vectors`.
+    pub fn write_segment(
+        &mut self,
+        metadata: &OemMetadata,
+        state_vectors: &[OemStateVector],
+    ) -> io::Result<()> {
+        let w = &mut self.writer;
+        writeln!(w)?;
+        writeln!(w, "META_START")?;
+        write_kvn_comments(w, &metadata.comment_list)?;
+        write_kvn_line(w, "OBJECT_NAME", &metadata.object_name)?;
+        write_kvn_line(w, "OBJECT_ID", &metadata.object_id)?;
+        write_kvn_line(w, "CENTER_NAME", &metadata.center_name)?;
+        write_kvn_line(w, "REF_FRAME", &metadata.ref_frame)?;
+        if let Some(epoch) = &metadata.ref_frame_epoch {
+            write_kvn_line(w, "REF_FRAME_EPOCH", &epoch.0)?;
+        }
+        write_kvn_line(w, "TIME_SYSTEM", &metadata.time_system)?;
+        write_kvn_line(w, "START_TIME", &metadata.start_time.0)?;
+        if let Some(epoch) = &metadata.useable_start_time {
+            write_kvn_line(w, "USEABLE_START_TIME", &epoch.0)?;
+        }
+        if let Some(epoch) = &metadata.useable_stop_time {
+            write_kvn_line(w, "USEABLE_STOP_TIME", &epoch.0)?;
+        }
+        write_kvn_line(w, "STOP_TIME", &metadata.stop_time.0)?;
+        if let Some(interpolation) = &metadata.interpolation {
+            write_kvn_line(w, "INTERPOLATION", interpolation)?;
+        }
+        if let Some(degree) = metadata.interpolation_degree {
+            write_kvn_line(w, "INTERPOLATION_DEGREE", &degree.to_string())?;
+        }
+        writeln!(w, "META_STOP")?;
+        writeln!(w)?;
+
+        for state_vector in state_vectors {
+            write!(w, "{}", state_vector.epoch.0)?;
+            let values = state_vector
+                .position
+                .iter()
+                .chain(&state_vector.velocity)
+                .chain(state_vector.acceleration.iter().flatten());
+            for value in values {
+                write!(w, " {}", format_kvn_float(*value))?;
+            }
+            writeln!(w)?;
+        }
+        Ok(())
+    }
+
+    /// Consumes the writer and returns the underlying [Write] implementation.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+fn write_kvn_line<W: Write>(writer: &mut W, keyword: &str, value: &str) -> io::Result<()> {
+    writeln!(writer, "{keyword:<20} = {value}")
+}
+
+fn write_kvn_comments<W: Write>(writer: &mut W, comments: &[String]) -> io::Result<()> {
+    for comment in comments {
+        writeln!(writer, "COMMENT {comment}")?;
+    }
+    Ok(())
+}
+
+/// Formats `value` in scientific notation with 17 significant digits and a
+/// signed two-digit exponent, e.g. ` 2.7896190000000000E+03`. Positive values
+/// are padded with a space such that all columns have the same width.
+fn format_kvn_float(value: f64) -> String {
+    let formatted = format!("{value:.16E}");
+    // Unwrap is okay because the `E` format always contains an exponent
+    let (mantissa, exponent) = formatted.split_once('E').unwrap();
+    let exponent: i32 = exponent.parse().unwrap();
+    let sign = if value.is_sign_negative() { "" } else { " " };
+    format!("{sign}{mantissa}E{exponent:+03}")
+}
+
 #[cfg(test)]
 mod test {
+    use rstest::rstest;
+
     use crate::ndm::xml::FromXmlStr;
 
     use super::*;
@@ -1770,4 +2240,303 @@ COVARIANCE_STOP"#;
             })
         );
     }
+
+    #[test]
+    fn test_parse_kvn_oem_state_vectors() {
+        let kvn = r#"CCSDS_OEM_VERS = 3.0
+CREATION_DATE = 1996-11-04T17:22:31
+ORIGINATOR = NASA/JPL
+
+META_START
+OBJECT_NAME         = MARS GLOBAL SURVEYOR
+OBJECT_ID           = 1996-062A
+CENTER_NAME         = MARS BARYCENTER
+REF_FRAME           = J2000
+TIME_SYSTEM         = TAI
+START_TIME          = 1996-12-18T12:00:00.331
+STOP_TIME           = 1996-12-28T21:28:00.331
+META_STOP
+
+COMMENT This file was produced by M.R. Somebody, MSOO NAV/JPL, 1996NOV 04. It is
+1996-12-18T12:00:00.331 2789.619 -280.045 -1746.755 4.73372 -2.49586 -1.04195
+1996-12-18T12:01:00.331  2.783419e+03 -3.08143E2 -1877.071 5.18604 -2.42124 -1.99608
+
+META_START
+OBJECT_NAME         = MARS GLOBAL SURVEYOR
+OBJECT_ID           = 1996-062A
+CENTER_NAME         = MARS BARYCENTER
+REF_FRAME           = J2000
+TIME_SYSTEM         = TAI
+START_TIME          = 1996-12-28T21:29:07.267
+STOP_TIME           = 1996-12-30T01:28:02.267
+META_STOP
+
+1996-12-28T21:29:07.267 -2432.166 -063.042 1742.754 7.33702 -3.495867 -1.041945 1.234 -2.345 3.455
+
+COVARIANCE_START
+EPOCH = 1996-12-28T21:29:07.267
+COV_REF_FRAME = EME2000
+3.3313494e-04
+4.6189273e-04 6.7824216e-04
+-3.0700078e-04 -4.2212341e-04 3.2319319e-04
+-3.3493650e-07 -4.6860842e-07 2.4849495e-07 4.2960228e-10
+-2.2118325e-07 -2.8641868e-07 1.7980986e-07 2.6088992e-10 1.7675147e-10
+-3.0413460e-07 -4.9894969e-07 3.5403109e-07 1.8692631e-10 1.0088625e-10 6.2244443e-10
+COVARIANCE_STOP"#;
+
+        assert_eq!(
+            parse_kvn_oem_state_vectors(kvn),
+            Ok(vec![
+                vec![
+                    OemStateVector {
+                        epoch: common::EpochType("1996-12-18T12:00:00.331".to_string()),
+                        position: [2789.619, -280.045, -1746.755],
+                        velocity: [4.73372, -2.49586, -1.04195],
+                        acceleration: None,
+                    },
+                    OemStateVector {
+                        epoch: common::EpochType("1996-12-18T12:01:00.331".to_string()),
+                        position: [2783.419, -308.143, -1877.071],
+                        velocity: [5.18604, -2.42124, -1.99608],
+                        acceleration: None,
+                    },
+                ],
+                vec![OemStateVector {
+                    epoch: common::EpochType("1996-12-28T21:29:07.267".to_string()),
+                    position: [-2432.166, -63.042, 1742.754],
+                    velocity: [7.33702, -3.495867, -1.041945],
+                    acceleration: Some([1.234, -2.345, 3.455]),
+                }],
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_kvn_oem_state_vectors_invalid_item_count() {
+        let kvn = r#"CCSDS_OEM_VERS = 3.0
+CREATION_DATE = 1996-11-04T17:22:31
+ORIGINATOR = NASA/JPL
+META_START
+OBJECT_NAME         = MARS GLOBAL SURVEYOR
+OBJECT_ID           = 1996-062A
+CENTER_NAME         = MARS BARYCENTER
+REF_FRAME           = J2000
+TIME_SYSTEM         = TAI
+START_TIME          = 1996-12-18T12:00:00.331
+STOP_TIME           = 1996-12-28T21:28:00.331
+META_STOP
+1996-12-18T12:00:00.331 2789.619 -280.045 -1746.755 4.73372 -2.49586 -1.04195
+1996-12-18T12:01:00.331 2783.419 -308.143 -1877.071 5.18604 -2.42124"#;
+
+        assert_eq!(
+            parse_kvn_oem_state_vectors(kvn),
+            Err(KvnDeserializerErr::InvalidEphemerisLine {
+                line_number: 14,
+                input: "1996-12-18T12:01:00.331 2783.419 -308.143 -1877.071 5.18604 -2.42124"
+                    .to_string(),
+            })
+        );
+
+        // The derived deserializer must not silently end the ephemeris data
+        // block at a malformed line
+        assert_eq!(
+            crate::ndm::kvn::KvnDeserializer::from_kvn_str(kvn),
+            Err::<OemType, _>(KvnDeserializerErr::InvalidStateVectorFormat {
+                input: "1996-12-18T12:01:00.331 2783.419 -308.143 -1877.071 5.18604 -2.42124"
+                    .to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_oem_data_state_vectors() {
+        let data = OemData {
+            comment_list: vec![],
+            state_vector_list: vec![
+                parse_kvn_state_vector(
+                    "1996-12-28T21:29:07.267 -2432.166 -063.042 1742.754 7.33702 -3.495867 -1.041945",
+                )
+                .unwrap()
+                .into(),
+            ],
+            covariance_matrix_list: vec![],
+        };
+
+        assert_eq!(
+            data.state_vectors(),
+            vec![OemStateVector {
+                epoch: common::EpochType("1996-12-28T21:29:07.267".to_string()),
+                position: [-2432.166, -63.042, 1742.754],
+                velocity: [7.33702, -3.495867, -1.041945],
+                acceleration: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_oem_kvn_reader() {
+        let kvn = r#"CCSDS_OEM_VERS = 3.0
+CREATION_DATE = 1996-11-04T17:22:31
+ORIGINATOR = NASA/JPL
+
+META_START
+OBJECT_NAME         = MARS GLOBAL SURVEYOR
+OBJECT_ID           = 1996-062A
+CENTER_NAME         = MARS BARYCENTER
+REF_FRAME           = J2000
+TIME_SYSTEM         = TAI
+START_TIME          = 1996-12-18T12:00:00.331
+STOP_TIME           = 1996-12-28T21:28:00.331
+META_STOP
+
+COMMENT This file was produced by M.R. Somebody, MSOO NAV/JPL, 1996NOV 04. It is
+1996-12-18T12:00:00.331 2789.619 -280.045 -1746.755 4.73372 -2.49586 -1.04195
+1996-12-18T12:01:00.331 2783.419 -308.143 -1877.071 5.18604 -2.42124
+1996-12-18T12:02:00.331 2776.033 -336.859 -2008.682 5.63678 -2.33951 -1.94687
+
+META_START
+OBJECT_NAME         = MARS GLOBAL SURVEYOR
+OBJECT_ID           = 1996-062A
+CENTER_NAME         = MARS BARYCENTER
+REF_FRAME           = EME2000
+TIME_SYSTEM         = TAI
+START_TIME          = 1996-12-28T21:29:07.267
+STOP_TIME           = 1996-12-30T01:28:02.267
+META_STOP
+
+1996-12-28T21:29:07.267 -2432.166 -063.042 1742.754 7.33702 -3.495867 -1.041945 1.234 -2.345 3.455
+
+COVARIANCE_START
+EPOCH = 1996-12-28T21:29:07.267
+COV_REF_FRAME = EME2000
+3.3313494e-04
+COVARIANCE_STOP"#;
+
+        let mut reader = OemKvnReader::new(kvn.as_bytes()).unwrap();
+        assert_eq!(reader.version(), "3.0");
+        assert_eq!(reader.header().originator, "NASA/JPL");
+        assert_eq!(reader.segment_index(), Some(0));
+        assert_eq!(reader.metadata().unwrap().ref_frame, "J2000");
+
+        let first = reader.next().unwrap().unwrap();
+        assert_eq!(first.position, [2789.619, -280.045, -1746.755]);
+        assert!(reader.is_segment_start());
+
+        // A malformed line does not end the iteration
+        assert_eq!(
+            reader.next(),
+            Some(Err(KvnDeserializerErr::InvalidEphemerisLine {
+                line_number: 17,
+                input: "1996-12-18T12:01:00.331 2783.419 -308.143 -1877.071 5.18604 -2.42124"
+                    .to_string(),
+            }))
+        );
+        assert!(!reader.is_segment_start());
+
+        let third = reader.next().unwrap().unwrap();
+        assert_eq!(third.velocity, [5.63678, -2.33951, -1.94687]);
+        assert!(!reader.is_segment_start());
+        assert_eq!(reader.segment_index(), Some(0));
+
+        let fourth = reader.next().unwrap().unwrap();
+        assert_eq!(fourth.acceleration, Some([1.234, -2.345, 3.455]));
+        assert!(reader.is_segment_start());
+        assert_eq!(reader.segment_index(), Some(1));
+        assert_eq!(reader.metadata().unwrap().ref_frame, "EME2000");
+
+        assert_eq!(reader.next(), None);
+    }
+
+    #[test]
+    fn test_oem_kvn_reader_invalid_header() {
+        let kvn = r#"CCSDS_OPM_VERS = 3.0
+CREATION_DATE = 1996-11-04T17:22:31
+ORIGINATOR = NASA/JPL
+META_START"#;
+
+        assert!(matches!(
+            OemKvnReader::new(kvn.as_bytes()),
+            Err(KvnDeserializerErr::UnexpectedKeyword { .. })
+        ));
+    }
+
+    #[rstest]
+    #[case(2789.619, " 2.7896190000000001E+03")]
+    #[case(-0.000660415582, "-6.6041558199999998E-04")]
+    #[case(0.0, " 0.0000000000000000E+00")]
+    #[case(1.5e-120, " 1.5000000000000001E-120")]
+    fn test_format_kvn_float(#[case] value: f64, #[case] expected: &str) {
+        assert_eq!(format_kvn_float(value), expected);
+        assert_eq!(expected.trim().parse::<f64>().unwrap(), value);
+    }
+
+    #[test]
+    fn test_oem_kvn_writer() {
+        let header = common::OdmHeader {
+            comment_list: vec!["Generated by LOX".to_string()],
+            creation_date: common::EpochType("1996-11-04T17:22:31".to_string()),
+            originator: "NASA/JPL".to_string(),
+            ..Default::default()
+        };
+        let metadata = OemMetadata {
+            object_name: "MARS GLOBAL SURVEYOR".to_string(),
+            object_id: "1996-062A".to_string(),
+            center_name: "MARS BARYCENTER".to_string(),
+            ref_frame: "J2000".to_string(),
+            time_system: "TAI".to_string(),
+            start_time: common::EpochType("1996-12-18T12:00:00.331".to_string()),
+            stop_time: common::EpochType("1996-12-18T12:01:00.331".to_string()),
+            interpolation: Some("HERMITE".to_string()),
+            interpolation_degree: Some(7),
+            ..Default::default()
+        };
+        let state_vectors = vec![
+            OemStateVector {
+                epoch: common::EpochType("1996-12-18T12:00:00.331".to_string()),
+                position: [2789.619, -280.045, -1746.755],
+                velocity: [4.73372, -2.49586, -1.04195],
+                acceleration: None,
+            },
+            OemStateVector {
+                epoch: common::EpochType("1996-12-18T12:01:00.331".to_string()),
+                position: [2783.419, -308.143, -1877.071],
+                velocity: [5.18604, -2.42124, -1.99608],
+                acceleration: Some([0.001, -0.002, 0.003]),
+            },
+        ];
+
+        let mut writer = OemKvnWriter::new(Vec::new(), "3.0", &header).unwrap();
+        writer.write_segment(&metadata, &state_vectors).unwrap();
+        writer
+            .write_segment(&metadata, &state_vectors[..1])
+            .unwrap();
+        let kvn = String::from_utf8(writer.into_inner()).unwrap();
+
+        let mut lines = kvn.lines();
+        assert_eq!(lines.next(), Some("CCSDS_OEM_VERS       = 3.0"));
+        assert_eq!(lines.next(), Some("COMMENT Generated by LOX"));
+        assert_eq!(lines.nth(11), Some("INTERPOLATION        = HERMITE"));
+        assert_eq!(
+            kvn.lines().nth(17),
+            Some(
+                "1996-12-18T12:00:00.331  2.7896190000000001E+03 -2.8004500000000002E+02 \
+                 -1.7467550000000001E+03  4.7337199999999999E+00 -2.4958600000000000E+00 \
+                 -1.0419499999999999E+00"
+            )
+        );
+
+        let mut reader = OemKvnReader::new(kvn.as_bytes()).unwrap();
+        assert_eq!(reader.version(), "3.0");
+        assert_eq!(reader.header(), &header);
+        assert_eq!(reader.metadata(), Some(&metadata));
+        let read: Vec<_> = reader.by_ref().map(Result::unwrap).collect();
+        assert_eq!(reader.segment_index(), Some(1));
+        assert_eq!(reader.metadata(), Some(&metadata));
+        assert_eq!(read, [&state_vectors[..], &state_vectors[..1]].concat());
+
+        assert_eq!(
+            parse_kvn_oem_state_vectors(&kvn).unwrap(),
+            vec![state_vectors.clone(), state_vectors[..1].to_vec()]
+        );
+    }
 }
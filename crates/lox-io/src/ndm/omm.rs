@@ -414,6 +414,7 @@ pub struct TleParametersType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(value_unit_struct)]
 pub struct BStarType {
     #[serde(rename = "$text")]
     pub base: f64,
@@ -431,6 +432,7 @@ pub struct BStarType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(value_unit_struct)]
 pub struct BTermType {
     #[serde(rename = "$text")]
     pub base: f64,
@@ -448,6 +450,7 @@ pub struct BTermType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(value_unit_struct)]
 pub struct AgomType {
     #[serde(rename = "$text")]
     pub base: f64,
@@ -465,6 +468,7 @@ pub struct AgomType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(value_unit_struct)]
 pub struct RevType {
     #[serde(rename = "$text")]
     pub base: f64,
@@ -482,6 +486,7 @@ pub struct RevType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(value_unit_struct)]
 pub struct DRevType {
     #[serde(rename = "$text")]
     pub base: f64,
@@ -499,6 +504,7 @@ pub struct DRevType {
     lox_derive::KvnDeserialize,
 )]
 #[serde(default)]
+#[kvn(value_unit_struct)]
 pub struct DdRevType {
     #[serde(rename = "$text")]
     pub base: f64,
@@ -508,6 +514,7 @@ pub struct DdRevType {
 
 #[cfg(test)]
 mod test {
+    use crate::ndm::kvn::KvnDeserializer;
     use crate::ndm::xml::FromXmlStr;
 
     use super::*;
@@ -1552,7 +1559,6 @@ mod test {
 
     #[test]
     fn test_parse_omm_message_kvn() {
-        //@TOOD add back user defined stuff
         let kvn = r#"CCSDS_OMM_VERS = 3.0
  COMMENT this is a comment
  COMMENT here is another one
@@ -1602,7 +1608,10 @@ mod test {
  CZ_DOT_Z = 3.540310904497689e-07
  CZ_DOT_X_DOT = 1.869263192954590e-10
  CZ_DOT_Y_DOT = 1.008862586240695e-10
- CZ_DOT_Z_DOT = 6.224444338635500e-10"#;
+ CZ_DOT_Z_DOT = 6.224444338635500e-10
+ USER_DEFINED_FOO = foo enters
+ USER_DEFINED_BAR = a bar
+ USER_DEFINED_FOO = foo exits"#;
 
         assert_eq!(
             crate::ndm::kvn::KvnDeserializer::from_kvn_str(kvn),
@@ -1768,7 +1777,23 @@ mod test {
                                     units: None,
                                 },
                             },),
-                            user_defined_parameters: None,
+                            user_defined_parameters: Some(common::UserDefinedType {
+                                comment_list: vec![],
+                                user_defined_list: vec![
+                                    common::UserDefinedParameterType {
+                                        base: "a bar".to_string(),
+                                        parameter: "BAR".to_string(),
+                                    },
+                                    common::UserDefinedParameterType {
+                                        base: "foo enters".to_string(),
+                                        parameter: "FOO".to_string(),
+                                    },
+                                    common::UserDefinedParameterType {
+                                        base: "foo exits".to_string(),
+                                        parameter: "FOO".to_string(),
+                                    },
+                                ],
+                            }),
                         },
                     },
                 },
@@ -1777,4 +1802,68 @@ mod test {
             },)
         );
     }
+
+    #[test]
+    fn test_parse_omm_message_kvn_tle_parameters() {
+        let kvn = r#"CCSDS_OMM_VERS = 2.0
+CREATION_DATE = 2007-065T16:00:00
+ORIGINATOR = NOAA/USA
+OBJECT_NAME = GOES 9
+OBJECT_ID = 1995-025A
+CENTER_NAME = EARTH
+REF_FRAME = TEME
+TIME_SYSTEM = UTC
+MEAN_ELEMENT_THEORY = SGP/SGP4
+EPOCH = 2007-064T10:34:41.4264
+MEAN_MOTION = 1.00273272 [rev/day]
+ECCENTRICITY = 0.0005013
+INCLINATION = 3.0539
+RA_OF_ASC_NODE = 81.7939
+ARG_OF_PERICENTER = 249.2363
+MEAN_ANOMALY = 150.1602
+GM = 398600.8
+EPHEMERIS_TYPE = 0
+CLASSIFICATION_TYPE = U
+NORAD_CAT_ID = 23581
+ELEMENT_SET_NO = 0925
+REV_AT_EPOCH = 4316
+BSTAR = 0.0001 [1/ER]
+MEAN_MOTION_DOT = -0.00000113
+MEAN_MOTION_DDOT = 0.0"#;
+
+        let message = OmmType::from_kvn_str(kvn).unwrap();
+        let data = message.body.segment.data;
+        assert_eq!(
+            data.mean_elements.mean_motion,
+            Some(RevType {
+                base: 1.00273272,
+                units: Some(RevUnits("rev/day".to_string())),
+            })
+        );
+        assert_eq!(
+            data.tle_parameters,
+            Some(TleParametersType {
+                comment_list: vec![],
+                ephemeris_type: Some(0),
+                classification_type: Some("U".to_string()),
+                norad_cat_id: Some(23581),
+                element_set_no: Some(ElementSetNoType("0925".to_string())),
+                rev_at_epoch: Some(4316),
+                bstar: Some(BStarType {
+                    base: 0.0001,
+                    units: Some(BStarUnits("1/ER".to_string())),
+                }),
+                bterm: None,
+                mean_motion_dot: DRevType {
+                    base: -0.00000113,
+                    units: None,
+                },
+                mean_motion_ddot: Some(DRevType {
+                    base: 0.0,
+                    units: None,
+                }),
+                agom: None,
+            })
+        );
+    }
 }
@@ -180,6 +180,7 @@
 use serde;
 
 use super::common;
+use super::kvn::{UnitError, si_conversion_factor};
 
 #[derive(
     Clone,
@@ -351,8 +352,51 @@ pub struct ManeuverParametersType {
     pub man_dv_3: common::VelocityType,
 }
 
+/// Converts `value` given in `unit` to the `default` unit of its keyword.
+/// Values without a unit are already in the default unit.
+fn to_default_unit(value: f64, unit: Option<&str>, default: &str) -> Result<f64, UnitError> {
+    match unit {
+        None => Ok(value),
+        Some(unit) => Ok(value * si_conversion_factor(unit)? / si_conversion_factor(default)?),
+    }
+}
+
+impl ManeuverParametersType {
+    /// Returns the duration of the burn in seconds, zero for impulsive maneuvers
+    pub fn duration(&self) -> Result<f64, UnitError> {
+        to_default_unit(
+            self.man_duration.base.0,
+            self.man_duration.units.as_ref().map(|u| u.0.as_str()),
+            "s",
+        )
+    }
+
+    /// Returns the change of the spacecraft mass in kilograms
+    pub fn delta_mass(&self) -> Result<f64, UnitError> {
+        to_default_unit(
+            self.man_delta_mass.base.0,
+            self.man_delta_mass.units.as_ref().map(|u| u.0.as_str()),
+            "kg",
+        )
+    }
+
+    /// Returns the velocity increment in km/s in the frame given by `MAN_REF_FRAME`
+    pub fn delta_v(&self) -> Result<[f64; 3], UnitError> {
+        let dv = |dv: &common::VelocityType| {
+            to_default_unit(dv.base, dv.units.as_ref().map(|u| u.0.as_str()), "km/s")
+        };
+        Ok([
+            dv(&self.man_dv_1)?,
+            dv(&self.man_dv_2)?,
+            dv(&self.man_dv_3)?,
+        ])
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use float_eq::assert_float_eq;
+
     use crate::ndm::xml::FromXmlStr;
 
     use super::*;
@@ -981,4 +1025,163 @@ MAN_DV_3 = 0.00000000 [km/s]"#;
             },)
         );
     }
+
+    #[test]
+    fn test_parse_opm_message_kvn_missing_keyword() {
+        let kvn = r#"CCSDS_OPM_VERS = 3.0
+CREATION_DATE = 2021-06-03T05:33:00.123
+ORIGINATOR = GSOC
+OBJECT_NAME = EUTELSAT W4
+OBJECT_ID = 2021-028A
+CENTER_NAME = EARTH
+REF_FRAME = TOD
+TIME_SYSTEM = UTC
+COMMENT State Vector
+X = 6655.9942 [km]
+Y = -40218.5751 [km]
+Z = -82.9177 [km]
+X_DOT = 3.11548208 [km/s]
+Y_DOT = 0.47042605 [km/s]
+Z_DOT = -0.00101495 [km/s]"#;
+
+        assert_eq!(
+            crate::ndm::kvn::KvnDeserializer::from_kvn_str(kvn),
+            Err::<OpmType, _>(crate::ndm::kvn::KvnDeserializerErr::KeywordNotFound {
+                expected: "EPOCH".to_string(),
+            })
+        );
+
+        let kvn = r#"CCSDS_OPM_VERS = 3.0
+CREATION_DATE = 2021-06-03T05:33:00.123
+OBJECT_NAME = EUTELSAT W4"#;
+
+        assert_eq!(
+            crate::ndm::kvn::KvnDeserializer::from_kvn_str(kvn),
+            Err::<OpmType, _>(crate::ndm::kvn::KvnDeserializerErr::KeywordNotFound {
+                expected: "ORIGINATOR".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_opm_message_kvn_empty_keyword_value() {
+        let kvn = r#"CCSDS_OPM_VERS = 3.0
+CREATION_DATE = 2021-06-03T05:33:00.123
+ORIGINATOR = GSOC
+OBJECT_NAME = EUTELSAT W4
+OBJECT_ID = 2021-028A
+CENTER_NAME = EARTH
+REF_FRAME = TOD
+TIME_SYSTEM = UTC
+EPOCH =
+X = 6655.9942 [km]
+Y = -40218.5751 [km]
+Z = -82.9177 [km]
+X_DOT = 3.11548208 [km/s]
+Y_DOT = 0.47042605 [km/s]
+Z_DOT = -0.00101495 [km/s]"#;
+
+        assert_eq!(
+            crate::ndm::kvn::KvnDeserializer::from_kvn_str(kvn),
+            Err::<OpmType, _>(crate::ndm::kvn::KvnDeserializerErr::EmptyValue {
+                input: "EPOCH =".to_string(),
+            })
+        );
+    }
+
+    const OPM_STATE: &str = r#"CCSDS_OPM_VERS = 3.0
+CREATION_DATE = 2021-06-03T05:33:00.123
+ORIGINATOR = GSOC
+OBJECT_NAME = EUTELSAT W4
+OBJECT_ID = 2021-028A
+CENTER_NAME = EARTH
+REF_FRAME = TOD
+TIME_SYSTEM = UTC
+EPOCH = 2021-06-03T00:00:00.000
+X = 6655.9942 [km]
+Y = -40218.5751 [km]
+Z = -82.9177 [km]
+X_DOT = 3.11548208 [km/s]
+Y_DOT = 0.47042605 [km/s]
+Z_DOT = -0.00101495 [km/s]
+"#;
+
+    #[test]
+    fn test_opm_maneuvers() {
+        let kvn = format!(
+            "{}{}",
+            OPM_STATE,
+            r#"MAN_EPOCH_IGNITION = 2021-06-03T09:00:34.1
+MAN_DURATION = 132.60 [s]
+MAN_DELTA_MASS = -18.418 [kg]
+MAN_REF_FRAME = EME2000
+MAN_DV_1 = -23.257 [m/s]
+MAN_DV_2 = 16.8316 [m/s]
+MAN_DV_3 = -8.93444 [m/s]
+MAN_EPOCH_IGNITION = 2021-06-05T18:59:21.0
+MAN_DURATION = 0.00
+MAN_DELTA_MASS = -1.469
+MAN_REF_FRAME = RTN
+MAN_DV_1 = 0.00101500
+MAN_DV_2 = -0.00187300
+MAN_DV_3 = 0.00000000"#
+        );
+
+        let message: OpmType = crate::ndm::kvn::KvnDeserializer::from_kvn_str(&kvn).unwrap();
+        let maneuvers = &message.body.segment.data.maneuver_parameters_list;
+
+        assert_eq!(maneuvers.len(), 2);
+
+        let first = &maneuvers[0];
+        assert_eq!(first.man_epoch_ignition.0, "2021-06-03T09:00:34.1");
+        assert_eq!(first.duration(), Ok(132.6));
+        assert_eq!(first.delta_mass(), Ok(-18.418));
+        assert_eq!(first.man_ref_frame, "EME2000");
+        assert_float_eq!(
+            first.delta_v().unwrap(),
+            [-0.023257, 0.0168316, -0.00893444],
+            rel_all <= 1e-12
+        );
+
+        let second = &maneuvers[1];
+        assert_eq!(second.man_epoch_ignition.0, "2021-06-05T18:59:21.0");
+        assert_eq!(second.duration(), Ok(0.0));
+        assert_eq!(second.delta_mass(), Ok(-1.469));
+        assert_eq!(second.man_ref_frame, "RTN");
+        assert_eq!(second.delta_v(), Ok([0.001015, -0.001873, 0.0]));
+    }
+
+    #[test]
+    fn test_opm_without_maneuvers() {
+        let message: OpmType = crate::ndm::kvn::KvnDeserializer::from_kvn_str(OPM_STATE).unwrap();
+        assert!(
+            message
+                .body
+                .segment
+                .data
+                .maneuver_parameters_list
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_opm_maneuvers_unknown_unit() {
+        let kvn = format!(
+            "{}{}",
+            OPM_STATE,
+            r#"MAN_EPOCH_IGNITION = 2021-06-03T09:00:34.1
+MAN_DURATION = 132.60 [s]
+MAN_DELTA_MASS = -18.418 [kg]
+MAN_REF_FRAME = EME2000
+MAN_DV_1 = -0.02325700 [furlong/s]
+MAN_DV_2 = 0.01683160 [km/s]
+MAN_DV_3 = -0.00893444 [km/s]"#
+        );
+
+        let message: OpmType = crate::ndm::kvn::KvnDeserializer::from_kvn_str(&kvn).unwrap();
+        assert_eq!(
+            message.body.segment.data.maneuver_parameters_list[0].delta_v(),
+            Err(UnitError::UnknownUnit("furlong/s".to_string()))
+        );
+    }
 }
@@ -0,0 +1,389 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Deserializer for KVN CCSDS Tracking Data Message
+//!
+//! The message structure follows
+//! [CCSDS 503.0-B-2](https://public.ccsds.org/Pubs/503x0b2c1.pdf). Only the
+//! KVN encoding is supported. The metadata keywords have to appear in the
+//! order of table 3-3 of the standard.
+//!
+//! ```
+//! # let kvn = r#"CCSDS_TDM_VERS = 2.0
+//! # CREATION_DATE = 2005-160T20:15:00
+//! # ORIGINATOR = NASA
+//! # META_START
+//! # TIME_SYSTEM = UTC
+//! # PARTICIPANT_1 = DSS-25
+//! # PARTICIPANT_2 = YYYY-NNNA
+//! # MODE = SEQUENTIAL
+//! # PATH = 1,2,1
+//! # META_STOP
+//! # DATA_START
+//! # RANGE = 2005-159T17:41:00 +4.00000000000000e+04
+//! # DATA_STOP"#;
+//! # use lox_io::ndm::tdm::TdmType;
+//! use lox_io::ndm::kvn::KvnDeserializer;
+//!
+//! let message: TdmType = KvnDeserializer::from_kvn_str(&kvn).unwrap();
+//! ```
+
+use super::common;
+use super::kvn::parser::{
+    KvnTrackingDataValue, get_next_nonempty_line, kvn_line_matches_key,
+    parse_kvn_tracking_data_line,
+};
+use super::kvn::{KvnContext, KvnDeserializer, KvnDeserializerErr};
+
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+pub struct TdmType {
+    pub version: String,
+    pub header: TdmHeader,
+    pub body: TdmBody,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+pub struct TdmHeader {
+    pub comment_list: Vec<String>,
+    pub creation_date: common::EpochType,
+    pub originator: String,
+    pub message_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+pub struct TdmBody {
+    pub segment_list: Vec<TdmSegment>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+pub struct TdmSegment {
+    pub metadata: TdmMetadata,
+    pub data: TdmData,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, lox_derive::KvnDeserialize)]
+#[kvn(prefix_and_postfix_keyword = "META")]
+pub struct TdmMetadata {
+    pub comment_list: Vec<String>,
+    pub track_id: Option<String>,
+    pub data_types: Option<String>,
+    pub time_system: String,
+    pub start_time: Option<common::EpochType>,
+    pub stop_time: Option<common::EpochType>,
+    pub participant_1: String,
+    pub participant_2: Option<String>,
+    pub participant_3: Option<String>,
+    pub participant_4: Option<String>,
+    pub participant_5: Option<String>,
+    pub mode: Option<String>,
+    pub path: Option<String>,
+    pub path_1: Option<String>,
+    pub path_2: Option<String>,
+    pub ephemeris_name_1: Option<String>,
+    pub ephemeris_name_2: Option<String>,
+    pub ephemeris_name_3: Option<String>,
+    pub ephemeris_name_4: Option<String>,
+    pub ephemeris_name_5: Option<String>,
+    pub transmit_band: Option<String>,
+    pub receive_band: Option<String>,
+    pub turnaround_numerator: Option<i32>,
+    pub turnaround_denominator: Option<i32>,
+    pub timetag_ref: Option<String>,
+    pub integration_interval: Option<f64>,
+    pub integration_ref: Option<String>,
+    pub freq_offset: Option<f64>,
+    pub range_mode: Option<String>,
+    pub range_modulus: Option<f64>,
+    pub range_units: Option<String>,
+    pub angle_type: Option<String>,
+    pub reference_frame: Option<String>,
+    pub interpolation: Option<String>,
+    pub interpolation_degree: Option<u64>,
+    pub doppler_count_bias: Option<f64>,
+    pub doppler_count_scale: Option<u64>,
+    pub doppler_count_rollover: Option<String>,
+    pub transmit_delay_1: Option<f64>,
+    pub transmit_delay_2: Option<f64>,
+    pub transmit_delay_3: Option<f64>,
+    pub transmit_delay_4: Option<f64>,
+    pub transmit_delay_5: Option<f64>,
+    pub receive_delay_1: Option<f64>,
+    pub receive_delay_2: Option<f64>,
+    pub receive_delay_3: Option<f64>,
+    pub receive_delay_4: Option<f64>,
+    pub receive_delay_5: Option<f64>,
+    pub data_quality: Option<String>,
+    pub correction_angle_1: Option<f64>,
+    pub correction_angle_2: Option<f64>,
+    pub correction_doppler: Option<f64>,
+    pub correction_mag: Option<f64>,
+    pub correction_range: Option<f64>,
+    pub correction_rcs: Option<f64>,
+    pub correction_receive: Option<f64>,
+    pub correction_transmit: Option<f64>,
+    pub correction_aberration_yearly: Option<f64>,
+    pub correction_aberration_diurnal: Option<f64>,
+    pub corrections_applied: Option<String>,
+}
+
+impl TdmMetadata {
+    /// Parses the value of the `TIME_SYSTEM` keyword.
+    pub fn parse_time_system(&self) -> Result<common::TimeSystem, common::UnknownTimeSystemError> {
+        self.time_system.parse()
+    }
+}
+
+/// The data block of a TDM segment enclosed by `DATA_START` and `DATA_STOP`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TdmData {
+    pub comment_list: Vec<String>,
+    pub observation_list: Vec<TdmObservation>,
+}
+
+/// A single tracking data line, e.g. `RANGE = 2005-159T17:41:00 4.0e+04`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TdmObservation {
+    /// The data type keyword, e.g. `RANGE` or `RECEIVE_FREQ_2`
+    pub keyword: String,
+    pub epoch: common::EpochType,
+    pub value: f64,
+}
+
+impl From<KvnTrackingDataValue> for TdmObservation {
+    fn from(value: KvnTrackingDataValue) -> Self {
+        Self {
+            keyword: value.keyword,
+            epoch: common::EpochType(value.epoch.full_value),
+            value: value.value,
+        }
+    }
+}
+
+impl KvnDeserializer for TdmData {
+    fn deserialize_with_context<'a>(
+        lines: &mut std::iter::Peekable<impl Iterator<Item = &'a str>>,
+        context: &KvnContext,
+    ) -> Result<Self, KvnDeserializerErr<String>> {
+        let next_line =
+            get_next_nonempty_line(lines).ok_or(KvnDeserializerErr::UnexpectedEndOfInput {
+                keyword: "DATA_START".to_string(),
+            })?;
+
+        if !kvn_line_matches_key("DATA_START", next_line)? {
+            return Err(KvnDeserializerErr::UnexpectedKeyword {
+                found: next_line.to_string(),
+                expected: "DATA_START".to_string(),
+            });
+        }
+        lines.next();
+
+        let mut data = TdmData::default();
+
+        loop {
+            let next_line =
+                get_next_nonempty_line(lines).ok_or(KvnDeserializerErr::UnexpectedEndOfInput {
+                    keyword: "DATA_STOP".to_string(),
+                })?;
+            // Unwrap is okay because we just peeked the line
+            lines.next().unwrap();
+
+            let trimmed = next_line.trim();
+
+            if trimmed == "DATA_STOP" {
+                break;
+            }
+
+            if trimmed == "COMMENT" || trimmed.starts_with("COMMENT ") {
+                data.comment_list.push(
+                    trimmed
+                        .trim_start_matches("COMMENT")
+                        .trim_start()
+                        .to_string(),
+                );
+                continue;
+            }
+
+            match parse_kvn_tracking_data_line(next_line) {
+                Ok(observation) => data.observation_list.push(observation.into()),
+                Err(e) => context.recover(e.into())?,
+            }
+        }
+
+        Ok(data)
+    }
+
+    fn should_check_key_match() -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_tdm_message_kvn() {
+        let kvn = r#"CCSDS_TDM_VERS = 2.0
+COMMENT TDM example created by yyyyy-nnnA Nav Team (NASA/JPL)
+CREATION_DATE = 2005-160T20:15:00
+ORIGINATOR = NASA
+
+META_START
+COMMENT This is a meta-data comment
+TIME_SYSTEM = UTC
+START_TIME = 2005-159T17:41:00
+STOP_TIME = 2005-159T17:41:40
+PARTICIPANT_1 = DSS-25
+PARTICIPANT_2 = yyyy-nnnA
+MODE = SEQUENTIAL
+PATH = 2,1
+INTEGRATION_INTERVAL = 1.0
+INTEGRATION_REF = MIDDLE
+RANGE_MODE = CONSTANT
+RANGE_MODULUS = 2.0e+26
+RANGE_UNITS = RU
+TRANSMIT_DELAY_1 = 7.7e-5
+RECEIVE_DELAY_1 = 7.7e-5
+META_STOP
+
+DATA_START
+COMMENT This is a data comment
+TRANSMIT_FREQ_2 = 2005-159T17:41:00 7180064367.3536
+RANGE = 2005-159T17:41:00 +3.9e+04
+RANGE = 2005-159T17:41:01 +3.8e+04
+DATA_STOP
+
+META_START
+TIME_SYSTEM = TAI
+PARTICIPANT_1 = DSS-25
+PARTICIPANT_2 = yyyy-nnnA
+MODE = SEQUENTIAL
+PATH = 1,2,1
+META_STOP
+DATA_START
+RECEIVE_FREQ_1 = 2005-159T17:42:00 8415000000.0
+DATA_STOP"#;
+
+        let message: TdmType = KvnDeserializer::from_kvn_str(kvn).unwrap();
+
+        assert_eq!(message.version, "2.0");
+        assert_eq!(message.header.originator, "NASA");
+        assert_eq!(message.body.segment_list.len(), 2);
+
+        let first = &message.body.segment_list[0];
+        assert_eq!(
+            first.metadata.comment_list,
+            vec!["This is a meta-data comment".to_string()]
+        );
+        assert_eq!(
+            first.metadata.stop_time,
+            Some(common::EpochType("2005-159T17:41:40".to_string()))
+        );
+        assert_eq!(first.metadata.path, Some("2,1".to_string()));
+        assert_eq!(first.metadata.range_modulus, Some(2.0e26));
+        assert_eq!(first.metadata.receive_delay_1, Some(7.7e-5));
+        assert_eq!(
+            first.metadata.parse_time_system(),
+            Ok(common::TimeSystem::Utc)
+        );
+        assert_eq!(
+            first.data,
+            TdmData {
+                comment_list: vec!["This is a data comment".to_string()],
+                observation_list: vec![
+                    TdmObservation {
+                        keyword: "TRANSMIT_FREQ_2".to_string(),
+                        epoch: common::EpochType("2005-159T17:41:00".to_string()),
+                        value: 7180064367.3536,
+                    },
+                    TdmObservation {
+                        keyword: "RANGE".to_string(),
+                        epoch: common::EpochType("2005-159T17:41:00".to_string()),
+                        value: 3.9e4,
+                    },
+                    TdmObservation {
+                        keyword: "RANGE".to_string(),
+                        epoch: common::EpochType("2005-159T17:41:01".to_string()),
+                        value: 3.8e4,
+                    },
+                ],
+            }
+        );
+
+        let second = &message.body.segment_list[1];
+        assert_eq!(second.metadata.path, Some("1,2,1".to_string()));
+        assert_eq!(
+            second.metadata.parse_time_system(),
+            Ok(common::TimeSystem::Tai)
+        );
+        assert_eq!(
+            second.data.observation_list,
+            vec![TdmObservation {
+                keyword: "RECEIVE_FREQ_1".to_string(),
+                epoch: common::EpochType("2005-159T17:42:00".to_string()),
+                value: 8415000000.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_tdm_message_kvn_invalid_observation() {
+        let kvn = r#"CCSDS_TDM_VERS = 2.0
+CREATION_DATE = 2005-160T20:15:00
+ORIGINATOR = NASA
+META_START
+TIME_SYSTEM = UTC
+PARTICIPANT_1 = DSS-25
+META_STOP
+DATA_START
+RANGE = 2005-159T17:41:00 abc
+DATA_STOP"#;
+
+        assert_eq!(
+            KvnDeserializer::from_kvn_str(kvn),
+            Err::<TdmType, _>(KvnDeserializerErr::InvalidNumberFormat {
+                input: "RANGE = 2005-159T17:41:00 abc".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_tdm_message_kvn_collecting() {
+        let kvn = r#"CCSDS_TDM_VERS = 2.0
+CREATION_DATE = 2005-160T20:15:00
+ORIGINATOR = NASA
+META_START
+TIME_SYSTEM = UTC
+PARTICIPANT_1 = DSS-25
+META_STOP
+DATA_START
+RANGE = 2005-159T17:41:00 abc
+COMMENT the next observation is valid
+RANGE = 2005-159T17:42:00 7.0
+RANGE = 2005-159T17:43:00 x
+DATA_STOP"#;
+
+        assert_eq!(
+            TdmType::deserialize_collecting(kvn),
+            Err(vec![
+                (
+                    9,
+                    KvnDeserializerErr::InvalidNumberFormat {
+                        input: "RANGE = 2005-159T17:41:00 abc".to_string(),
+                    }
+                ),
+                (
+                    12,
+                    KvnDeserializerErr::InvalidNumberFormat {
+                        input: "RANGE = 2005-159T17:43:00 x".to_string(),
+                    }
+                ),
+            ])
+        );
+    }
+}
@@ -8,4 +8,5 @@
 
 //! Module `f64` exposes f64 constants shared between Lox crates.
 
+pub mod physics;
 pub mod time;
@@ -0,0 +1,19 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module `physics` exposes physical constants shared between Lox crates.
+
+/// The speed of light in vacuum in km/s.
+pub const SPEED_OF_LIGHT: f64 = 299792.458;
+
+/// The astronomical unit in km as defined by IAU 2012 Resolution B2.
+pub const ASTRONOMICAL_UNIT: f64 = 149597870.7;
+
+/// The total solar irradiance at a distance of one astronomical unit in W/m² as recommended by
+/// IAU 2015 Resolution B3.
+pub const SOLAR_FLUX: f64 = 1361.0;
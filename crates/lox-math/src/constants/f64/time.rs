@@ -12,6 +12,11 @@
    Days
 */
 
+pub const DAYS_PER_JULIAN_YEAR: f64 = 365.25;
+
+/// The length of the tropical year at B1900 in days, which defines the Besselian epoch.
+pub const DAYS_PER_BESSELIAN_YEAR: f64 = 365.242198781;
+
 pub const DAYS_PER_JULIAN_CENTURY: f64 = 36525.0;
 
 /*
@@ -32,3 +37,8 @@ pub const SECONDS_PER_JULIAN_CENTURY: f64 = SECONDS_PER_JULIAN_YEAR * 100.0;
 */
 
 pub const MJD_J2000: f64 = 51544.5;
+
+pub const JD_J2000: f64 = 2451545.0;
+
+/// The Julian date of the Besselian epoch B1900.0.
+pub const JD_B1900: f64 = 2415020.31352;
@@ -11,3 +11,62 @@ impl Azimuth for DVec3 {
         self.y.atan2(self.x)
     }
 }
+
+/// Returns the angle between the vectors `a` and `b` in the range [0, π].
+///
+/// Unlike the arccosine of the normalized dot product, this formulation retains full precision for
+/// nearly parallel and antiparallel vectors.
+pub fn angular_separation(a: DVec3, b: DVec3) -> Radians {
+    a.cross(b).length().atan2(a.dot(b))
+}
+
+/// Returns the phase angle, i.e. the Sun-target-observer angle, in radians for the given
+/// positions of the `observer`, the `target`, and the `sun` in a common frame.
+pub fn phase_angle(observer: DVec3, target: DVec3, sun: DVec3) -> Radians {
+    angular_separation(observer - target, sun - target)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use float_eq::assert_float_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::identical(DVec3::X, DVec3::X, 0.0)]
+    #[case::orthogonal(DVec3::X, DVec3::new(0.0, 2.0, 0.0), FRAC_PI_2)]
+    #[case::antiparallel(DVec3::new(1.0, 2.0, 3.0), DVec3::new(-2.0, -4.0, -6.0), PI)]
+    #[case::tiny(DVec3::X, DVec3::new(1.0, 1e-8, 0.0), 1e-8)]
+    #[case::tiny_scaled(DVec3::new(4e7, 0.0, 0.0), DVec3::new(3e5, 0.0, 3e-3), 1e-8)]
+    #[case::nearly_antiparallel(DVec3::X, DVec3::new(-1.0, 1e-8, 0.0), PI - 1e-8)]
+    fn test_angular_separation(#[case] a: DVec3, #[case] b: DVec3, #[case] expected: Radians) {
+        assert_float_eq!(angular_separation(a, b), expected, rel <= 1e-14);
+        assert_float_eq!(angular_separation(b, a), expected, rel <= 1e-14);
+    }
+
+    #[test]
+    fn test_angular_separation_exactly_pi() {
+        assert_eq!(angular_separation(DVec3::Z, -DVec3::Z), PI);
+    }
+
+    #[test]
+    fn test_phase_angle() {
+        let sun = DVec3::new(1.5e8, 0.0, 0.0);
+        let distance = 384400.0;
+        // Full Moon, new Moon, and quarter Moon as seen from the Earth
+        let full = DVec3::new(-distance, 0.0, 0.0);
+        assert_eq!(phase_angle(DVec3::ZERO, full, sun), 0.0);
+        let new = DVec3::new(distance, 0.0, 0.0);
+        assert_eq!(phase_angle(DVec3::ZERO, new, sun), PI);
+        let quarter = DVec3::new(0.0, distance, 0.0);
+        let expected = FRAC_PI_2 - (distance / sun.x).atan();
+        assert_float_eq!(
+            phase_angle(DVec3::ZERO, quarter, sun),
+            expected,
+            rel <= 1e-14
+        );
+    }
+}
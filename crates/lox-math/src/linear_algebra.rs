@@ -5,4 +5,36 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
+
+//! Module linear_algebra provides the vector and matrix types used throughout Lox.
+//!
+//! [Vector3] and [Matrix3] are aliases of the double-precision types of the [glam] crate, which
+//! provide all common operations:
+//!
+//! - [Vector3::dot] and [Vector3::cross] for the dot and cross products,
+//! - [Vector3::length] for the Euclidean norm and [Vector3::normalize] for the unit vector,
+//! - `Matrix3 * Vector3` and `Matrix3 * Matrix3` for matrix multiplication.
+//!
+//! ```
+//! use lox_math::linear_algebra::{Matrix3, Vector3};
+//!
+//! let x = Vector3::new(1.0, 0.0, 0.0);
+//! let y = Vector3::new(0.0, 1.0, 0.0);
+//! let z = x.cross(y);
+//! assert_eq!(z, Vector3::Z);
+//! assert_eq!(x.dot(z), 0.0);
+//! assert_eq!((2.0 * z).length(), 2.0);
+//! assert_eq!((2.0 * z).normalize(), z);
+//!
+//! // A rotation by 90 degrees about the z-axis maps x onto y
+//! let m = Matrix3::from_rotation_z(std::f64::consts::FRAC_PI_2);
+//! assert!((m * x).abs_diff_eq(y, 1e-15));
+//! ```
+
 pub mod tridiagonal;
+
+/// A three-dimensional vector of `f64` components.
+pub type Vector3 = glam::DVec3;
+
+/// A 3x3 column-major matrix of `f64` elements.
+pub type Matrix3 = glam::DMat3;
@@ -151,17 +151,42 @@ impl<T: AsRef<[f64]>, U: AsRef<[f64]>> Series<T, U> {
     }
 
     pub fn interpolate(&self, xp: f64) -> f64 {
+        self.evaluate(self.find_index(xp), xp)
+    }
+
+    /// Interpolates the series at `xp` like [Series::interpolate] but starts the search for the
+    /// enclosing interval at the index `cursor`, which is updated to the interval that was found.
+    ///
+    /// For monotonic sequences of `xp` the enclosing interval is usually at or right after
+    /// `cursor`, which avoids the binary search. Other inputs fall back to the binary search and
+    /// yield the same results.
+    pub fn interpolate_from(&self, xp: f64, cursor: &mut usize) -> f64 {
+        let x = self.x.as_ref();
+        let last = x.len() - 2;
+        let start = (*cursor).min(last);
+        let idx = (start..=(start + 1).min(last))
+            .find(|&idx| (idx == 0 || xp > x[idx]) && (idx == last || xp <= x[idx + 1]))
+            .unwrap_or_else(|| self.find_index(xp));
+        *cursor = idx;
+        self.evaluate(idx, xp)
+    }
+
+    fn find_index(&self, xp: f64) -> usize {
         let x = self.x.as_ref();
-        let y = self.y.as_ref();
         let x0 = *x.first().unwrap();
         let xn = *x.last().unwrap();
-        let idx = if xp <= x0 {
+        if xp <= x0 {
             0
         } else if xp >= xn {
             x.len() - 2
         } else {
             x.partition_point(|&val| xp > val) - 1
-        };
+        }
+    }
+
+    fn evaluate(&self, idx: usize, xp: f64) -> f64 {
+        let x = self.x.as_ref();
+        let y = self.y.as_ref();
         match &self.interpolation {
             Interpolation::Linear => {
                 let x0 = x[idx];
@@ -293,6 +318,29 @@ mod tests {
         assert_float_eq!(actual, expected, rel <= 1e-12);
     }
 
+    #[test]
+    fn test_series_interpolate_from() {
+        let x = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let y = vec![
+            0.08138419591321655,
+            1.6543878900257172,
+            -0.7644606583671828,
+            -0.6587179995856219,
+            -0.7254418066056914,
+        ];
+        let s = Series::with_cubic_spline(x, y).unwrap();
+
+        let forward: Vec<f64> = (0..=60).map(|i| i as f64 / 10.0).collect();
+        let backward: Vec<f64> = forward.iter().rev().copied().collect();
+        let shuffled = [3.3, 0.5, 5.9, 2.0, 2.0, 4.4, 1.0, 5.0];
+        for xps in [&forward[..], &backward[..], &shuffled[..]] {
+            let mut cursor = 0;
+            for &xp in xps {
+                assert_eq!(s.interpolate_from(xp, &mut cursor), s.interpolate(xp));
+            }
+        }
+    }
+
     #[rstest]
     #[case(Series::new(vec![1.0], vec![1.0]), Err(SeriesError::InsufficientPoints(1, 2)))]
     #[case(Series::with_cubic_spline(vec![1.0], vec![1.0]), Err(SeriesError::InsufficientPoints(1, 4)))]
@@ -8,6 +8,9 @@
 
 //! Mod `units` exposes shared type aliases representing a variety of physical units.
 
+use std::f64::consts::{PI, TAU};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
 pub type Arcseconds = f64;
 
 pub type Microarcseconds = f64;
@@ -19,3 +22,202 @@ pub type Seconds = f64;
 pub type JulianCenturies = f64;
 
 pub type Days = f64;
+
+/// An angle which is stored in radians, such that the unit is encoded in the type.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Angle(Radians);
+
+impl Angle {
+    pub const ZERO: Self = Self(0.0);
+
+    pub const fn from_radians(radians: Radians) -> Self {
+        Self(radians)
+    }
+
+    pub fn from_degrees(degrees: f64) -> Self {
+        Self(degrees.to_radians())
+    }
+
+    pub const fn to_radians(self) -> Radians {
+        self.0
+    }
+
+    pub fn to_degrees(self) -> f64 {
+        self.0.to_degrees()
+    }
+
+    /// Wraps the angle into the range [0, 2π).
+    ///
+    /// Multiples of 2π wrap to exactly zero and tiny negative angles, whose remainder would round
+    /// up to 2π, also wrap to zero.
+    pub fn wrap_to_2pi(self) -> Self {
+        let wrapped = self.0.rem_euclid(TAU);
+        if wrapped >= TAU {
+            Self(0.0)
+        } else {
+            Self(wrapped)
+        }
+    }
+
+    /// Wraps the angle into the range [-π, π).
+    ///
+    /// Angles within the range are returned unchanged and odd multiples of π wrap to exactly -π.
+    pub fn wrap_to_pi(self) -> Self {
+        if (-PI..PI).contains(&self.0) {
+            return self;
+        }
+        let wrapped = self.wrap_to_2pi().0;
+        if wrapped >= PI {
+            Self(wrapped - TAU)
+        } else {
+            Self(wrapped)
+        }
+    }
+}
+
+impl Add for Angle {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Angle {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Angle {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Angle {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for Angle {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl Mul<f64> for Angle {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl Mul<Angle> for f64 {
+    type Output = Angle;
+
+    fn mul(self, rhs: Angle) -> Self::Output {
+        Angle(self * rhs.0)
+    }
+}
+
+impl Div<f64> for Angle {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
+/// An angular rate which is stored in radians per second, such that the unit is encoded in the
+/// type.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct AngularRate(f64);
+
+impl AngularRate {
+    pub const fn from_radians_per_second(radians_per_second: f64) -> Self {
+        Self(radians_per_second)
+    }
+
+    pub fn from_degrees_per_second(degrees_per_second: f64) -> Self {
+        Self(degrees_per_second.to_radians())
+    }
+
+    pub const fn to_radians_per_second(self) -> f64 {
+        self.0
+    }
+
+    pub fn to_degrees_per_second(self) -> f64 {
+        self.0.to_degrees()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_angle_conversions() {
+        assert_eq!(Angle::from_degrees(180.0).to_radians(), PI);
+        assert_eq!(Angle::from_radians(PI).to_degrees(), 180.0);
+    }
+
+    #[rstest]
+    #[case::zero(0.0, 0.0)]
+    #[case::two_pi(TAU, 0.0)]
+    #[case::minus_two_pi(-TAU, 0.0)]
+    #[case::four_pi(2.0 * TAU, 0.0)]
+    #[case::pi(PI, PI)]
+    #[case::minus_pi(-PI, PI)]
+    #[case::tiny_negative(-1e-17, 0.0)]
+    #[case::three_halves_pi(-PI / 2.0, 3.0 * PI / 2.0)]
+    fn test_angle_wrap_to_2pi(#[case] angle: f64, #[case] expected: f64) {
+        assert_eq!(
+            Angle::from_radians(angle).wrap_to_2pi().to_radians(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::zero(0.0, 0.0)]
+    #[case::two_pi(TAU, 0.0)]
+    #[case::minus_two_pi(-TAU, 0.0)]
+    #[case::pi(PI, -PI)]
+    #[case::minus_pi(-PI, -PI)]
+    #[case::three_pi(3.0 * PI, -PI)]
+    #[case::tiny_positive(1e-20, 1e-20)]
+    #[case::tiny_negative(-1e-20, -1e-20)]
+    #[case::three_halves_pi(3.0 * PI / 2.0, -PI / 2.0)]
+    fn test_angle_wrap_to_pi(#[case] angle: f64, #[case] expected: f64) {
+        assert_eq!(
+            Angle::from_radians(angle).wrap_to_pi().to_radians(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_angle_arithmetic() {
+        let a = Angle::from_degrees(90.0);
+        let b = Angle::from_degrees(45.0);
+        assert_float_eq!((a + b).to_degrees(), 135.0, rel <= 1e-15);
+        assert_float_eq!((a - b).to_degrees(), 45.0, rel <= 1e-15);
+        assert_float_eq!((-a).to_degrees(), -90.0, rel <= 1e-15);
+        assert_float_eq!((a * 2.0).to_degrees(), 180.0, rel <= 1e-15);
+        assert_float_eq!((2.0 * a).to_degrees(), 180.0, rel <= 1e-15);
+        assert_float_eq!((a / 2.0).to_degrees(), 45.0, rel <= 1e-15);
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+}
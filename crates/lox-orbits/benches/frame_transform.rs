@@ -0,0 +1,81 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Compares transforming a cloud of states from the ICRF to the ITRF with a cached
+//! [FrameTransform] against recomputing the rotation for every state.
+//!
+//! Run with `cargo bench -p lox-orbits --bench frame_transform`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use glam::DVec3;
+use lox_bodies::DynOrigin;
+use lox_orbits::frames::DynFrame;
+use lox_orbits::frames::transform::FrameTransform;
+use lox_orbits::states::DynState;
+use lox_time::ut1::DeltaUt1Tai;
+use lox_time::utc::Utc;
+
+const STATES: usize = 10_000;
+
+fn bench(name: &str, f: impl Fn() -> DVec3) -> Duration {
+    // Warm-up
+    black_box(f());
+    let start = Instant::now();
+    black_box(f());
+    let elapsed = start.elapsed();
+    println!(
+        "{name:<10} {:>12.3?} total {:>10.1?} per state",
+        elapsed,
+        elapsed / STATES as u32
+    );
+    elapsed
+}
+
+fn main() {
+    let time = Utc::from_iso("2024-07-05T09:09:18.173")
+        .unwrap()
+        .to_dyn_time();
+    let states: Vec<DynState> = (0..STATES)
+        .map(|i| {
+            let angle = i as f64 * 1e-3;
+            DynState::new(
+                time,
+                DVec3::new(7000.0 * angle.cos(), 7000.0 * angle.sin(), 100.0),
+                DVec3::new(-7.5 * angle.sin(), 7.5 * angle.cos(), 0.1),
+                DynOrigin::Earth,
+                DynFrame::Icrf,
+            )
+        })
+        .collect();
+
+    let uncached = bench("uncached", || {
+        states
+            .iter()
+            .map(|s| {
+                s.try_to_frame(DynFrame::Itrf, None::<&DeltaUt1Tai>)
+                    .unwrap()
+                    .position()
+            })
+            .sum()
+    });
+    let cached = bench("cached", || {
+        let transform =
+            FrameTransform::try_new(DynFrame::Icrf, DynFrame::Itrf, time, None::<&DeltaUt1Tai>)
+                .unwrap();
+        states
+            .iter()
+            .map(|s| transform.apply(s).unwrap().position())
+            .sum()
+    });
+    println!(
+        "speed-up   {:>12.1}x",
+        uncached.as_secs_f64() / cached.as_secs_f64()
+    );
+}
@@ -0,0 +1,97 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Compares propagating the OneWeb constellation with [propagate_many] against a sequential loop
+//! over the propagators.
+//!
+//! [propagate_many] runs in rayon thread pools of 1, 2, 4, … threads up to the number of
+//! available cores, and the speed-up over the sequential loop is printed for each thread count.
+//!
+//! Run with `cargo bench -p lox-orbits --bench propagate_many`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use lox_orbits::propagators::sgp4::Sgp4;
+use lox_orbits::propagators::{Propagator, propagate_many};
+use lox_orbits::tle;
+use lox_time::Time;
+use lox_time::deltas::TimeDelta;
+use lox_time::time_scales::Tai;
+
+const STEPS: i64 = 144;
+
+fn bench(name: &str, objects: usize, f: impl Fn() -> usize) -> Duration {
+    // Warm-up
+    black_box(f());
+    let start = Instant::now();
+    black_box(f());
+    let elapsed = start.elapsed();
+    println!(
+        "{name:<10} {:>12.3?} total {:>10.1?} per object",
+        elapsed,
+        elapsed / objects as u32
+    );
+    elapsed
+}
+
+fn main() {
+    let tles = include_str!("../../../data/oneweb_tle.txt");
+    let lines: Vec<&str> = tles.lines().collect();
+    let propagators: Vec<Sgp4> = lines
+        .chunks_exact(3)
+        .map(|chunk| Sgp4::from_tle(&tle::parse(chunk[1], chunk[2]).unwrap()).unwrap())
+        .collect();
+    // One day with a step size of ten minutes
+    let t0 = propagators[0].time();
+    let times: Vec<Time<Tai>> = (0..STEPS)
+        .map(|i| t0 + TimeDelta::from_seconds(i * 600))
+        .collect();
+
+    let cores = std::thread::available_parallelism().map_or(1, |n| n.get());
+    println!(
+        "{} objects, {} epochs, {} cores",
+        propagators.len(),
+        times.len(),
+        cores
+    );
+    let sequential = bench("sequential", propagators.len(), || {
+        propagators
+            .iter()
+            .map(|sgp4| {
+                sgp4.propagate_all(times.iter().cloned())
+                    .unwrap()
+                    .states()
+                    .len()
+            })
+            .sum()
+    });
+    let mut threads: Vec<usize> = std::iter::successors(Some(1), |n| Some(n * 2))
+        .take_while(|&n| n < cores)
+        .collect();
+    threads.push(cores);
+    for threads in threads {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        let name = format!("{threads} threads");
+        let parallel = pool.install(|| {
+            bench(&name, propagators.len(), || {
+                propagate_many(&propagators, &times)
+                    .into_iter()
+                    .map(|trajectory| trajectory.unwrap().states().len())
+                    .sum()
+            })
+        });
+        println!(
+            "speed-up   {:>12.2}x",
+            sequential.as_secs_f64() / parallel.as_secs_f64()
+        );
+    }
+}
@@ -9,12 +9,15 @@
 use glam::DVec3;
 use itertools::Itertools;
 use lox_bodies::{
-    DynOrigin, Origin, RotationalElements, Spheroid, TryMeanRadius, TrySpheroid,
+    DynOrigin, Origin, RotationalElements, Spheroid, Sun, TryMeanRadius, TrySpheroid,
     UndefinedOriginPropertyError,
 };
 use lox_ephem::{Ephemeris, path_from_ids};
+use lox_math::constants::f64::physics::SPEED_OF_LIGHT;
+use lox_math::glam::angular_separation;
 use lox_math::roots::Brent;
 use lox_math::series::{Series, SeriesError};
+use lox_math::types::julian_dates::Epoch;
 use lox_math::types::units::Radians;
 use lox_time::deltas::TimeDelta;
 use lox_time::julian_dates::JulianDate;
@@ -22,16 +25,26 @@ use lox_time::time_scales::{DynTimeScale, TryToScale};
 use lox_time::time_scales::{Tdb, TimeScale};
 use lox_time::ut1::DeltaUt1TaiProvider;
 use lox_time::{DynTime, Time};
+#[cfg(feature = "rayon")]
 use rayon::prelude::*;
+use std::cell::RefCell;
 use std::f64::consts::PI;
 use thiserror::Error;
 
-use crate::events::{Window, find_windows, intersect_windows};
+use crate::events::{
+    FindEventError, Window, ZeroCrossing, find_events, find_windows, intersect_windows,
+};
 use crate::frames::{DynFrame, Iau, Icrf, TryRotateTo};
 use crate::ground::{DynGroundLocation, DynGroundPropagator, GroundLocation};
+use crate::propagators::Propagator;
 use crate::states::State;
 use crate::trajectories::{DynTrajectory, Trajectory};
 
+const MAX_LIGHT_TIME_ITER: usize = 10;
+
+// One nanosecond
+const LIGHT_TIME_TOLERANCE: f64 = 1e-9;
+
 // Salvatore Alfano, David Negron, Jr., and Jennifer L. Moore
 // Rapid Determination of Satellite Visibility Periods
 // The Journal of the Astronautical Sciences. Vol. 40, No. 2, April-June 1992, pp. 281-296
@@ -219,8 +232,11 @@ pub fn visibility_combined<
     provider: Option<&P>,
 ) -> Vec<Window<DynTimeScale>> {
     let w1 = visibility_dyn(times, gs, mask, sc, provider);
-    let wb: Vec<Vec<Window<DynTimeScale>>> = bodies
-        .par_iter()
+    #[cfg(feature = "rayon")]
+    let bodies_iter = bodies.par_iter();
+    #[cfg(not(feature = "rayon"))]
+    let bodies_iter = bodies.iter();
+    let wb: Vec<Vec<Window<DynTimeScale>>> = bodies_iter
         .map(|&body| visibility_los(times, gs, body, sc, ephem, provider))
         .collect();
     let mut w = w1;
@@ -286,20 +302,420 @@ pub fn visibility<
     )
 }
 
+/// The illumination of a spacecraft by the Sun with respect to the shadow of an occulting body.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ShadowState {
+    Sunlit,
+    Penumbra,
+    Umbra,
+}
+
+/// Returns the apparent angular radii of the Sun and the occulting body as well as the angular
+/// separation of their centres as seen from the spacecraft.
+fn shadow_angles(sat_position: DVec3, sun_position: DVec3, body_radius: f64) -> (f64, f64, f64) {
+    let to_sun = sun_position - sat_position;
+    let to_body = -sat_position;
+    let sun_radius = Sun.equatorial_radius();
+    let a = (sun_radius / to_sun.length()).asin();
+    let b = (body_radius / to_body.length()).min(1.0).asin();
+    let c = angular_separation(to_sun, to_body);
+    (a, b, c)
+}
+
+/// Determines whether the spacecraft at `sat_position` is sunlit or in the penumbra or umbra of
+/// an occulting body with radius `body_radius` based on a conical shadow model.
+///
+/// Both `sat_position` and `sun_position` are relative to the centre of the occulting body. An
+/// annular eclipse, i.e. a transit of the occulting body across the solar disc, is reported as
+/// penumbra.
+pub fn shadow(sat_position: DVec3, sun_position: DVec3, body_radius: f64) -> ShadowState {
+    let (a, b, c) = shadow_angles(sat_position, sun_position, body_radius);
+    if c >= a + b {
+        ShadowState::Sunlit
+    } else if c <= b - a {
+        ShadowState::Umbra
+    } else {
+        ShadowState::Penumbra
+    }
+}
+
+/// Returns the fraction of the solar disc which is visible from the spacecraft at `sat_position`,
+/// i.e. one if sunlit, zero in the umbra, and the unobscured fraction of the solar disc in the
+/// penumbra.
+///
+/// The arguments are the same as for [shadow]. The apparent discs are treated as planar, see
+/// Montenbruck & Gill (2000), section 3.4.2.
+pub fn illumination(sat_position: DVec3, sun_position: DVec3, body_radius: f64) -> f64 {
+    let (a, b, c) = shadow_angles(sat_position, sun_position, body_radius);
+    if c >= a + b {
+        1.0
+    } else if c <= b - a {
+        0.0
+    } else if c <= a - b {
+        1.0 - (b / a).powi(2)
+    } else {
+        let x = (c * c + a * a - b * b) / (2.0 * c);
+        let y = (a * a - x * x).max(0.0).sqrt();
+        let occulted = a * a * (x / a).clamp(-1.0, 1.0).acos()
+            + b * b * ((c - x) / b).clamp(-1.0, 1.0).acos()
+            - c * y;
+        1.0 - occulted / (PI * a * a)
+    }
+}
+
+/// An eclipse of the Sun by the central body as seen from a spacecraft.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Eclipse<T: TimeScale> {
+    penumbra: Window<T>,
+    umbra: Option<Window<T>>,
+}
+
+impl<T: TimeScale + Clone> Eclipse<T> {
+    /// Returns the interval during which the spacecraft is not fully sunlit, i.e. from penumbra
+    /// entry to penumbra exit.
+    pub fn penumbra(&self) -> Window<T> {
+        self.penumbra.clone()
+    }
+
+    /// Returns the interval during which the spacecraft is in the umbra, if any.
+    pub fn umbra(&self) -> Option<Window<T>> {
+        self.umbra.clone()
+    }
+}
+
+/// Finds the eclipses of the Sun by the central body along the spacecraft trajectory `sc` based on
+/// a conical shadow model.
+///
+/// The Sun position is obtained from `ephem` and the shadow entry and exit epochs are refined with
+/// a root-finder between the sampling `times`.
+pub fn eclipses<
+    T: TimeScale + TryToScale<Tdb, P> + Copy,
+    O: Origin + Spheroid + Clone,
+    P,
+    E: Ephemeris,
+>(
+    times: &[Time<T>],
+    sc: &Trajectory<T, O, Icrf>,
+    ephem: &E,
+    provider: Option<&P>,
+) -> Vec<Eclipse<T>> {
+    if times.len() < 2 {
+        return vec![];
+    }
+    let start = *times.first().unwrap();
+    let end = *times.last().unwrap();
+    let steps: Vec<f64> = times
+        .iter()
+        .map(|&t| (t - start).to_decimal_seconds())
+        .collect();
+    let origin = sc.origin();
+    let body_radius = origin.equatorial_radius();
+    let angles = |t: f64| {
+        let time = start + TimeDelta::from_decimal_seconds(t);
+        let epoch = time
+            .try_to_scale(Tdb, provider)
+            .unwrap()
+            .seconds_since_j2000();
+        let r_sun = body_position(ephem, epoch, origin.id().0, Sun.id().0).unwrap();
+        shadow_angles(sc.interpolate_at(time).position(), r_sun, body_radius)
+    };
+    let penumbra = find_windows(
+        |t| {
+            let (a, b, c) = angles(t);
+            a + b - c
+        },
+        start,
+        end,
+        &steps,
+        Brent::default(),
+    );
+    let umbra = find_windows(
+        |t| {
+            let (a, b, c) = angles(t);
+            b - a - c
+        },
+        start,
+        end,
+        &steps,
+        Brent::default(),
+    );
+    penumbra
+        .into_iter()
+        .map(|penumbra| Eclipse {
+            penumbra,
+            umbra: umbra
+                .iter()
+                .find(|umbra| {
+                    (umbra.start() - penumbra.start()).to_decimal_seconds() >= 0.0
+                        && (penumbra.end() - umbra.end()).to_decimal_seconds() >= 0.0
+                })
+                .copied(),
+        })
+        .collect()
+}
+
+/// Returns the position of `target` with respect to `origin` from `ephem` at `epoch` in TDB
+/// seconds since J2000.
+pub(crate) fn body_position<E: Ephemeris>(
+    ephem: &E,
+    epoch: Epoch,
+    origin: i32,
+    target: i32,
+) -> Result<DVec3, E::Error> {
+    Ok(ephem.position_wrt(epoch, origin, target)?.into())
+}
+
+/// Computes the light-time corrected position of `target` relative to the `observer`, i.e. the
+/// position of `target` at the time of emission of the light that reaches the observer at the
+/// epoch of the observer state.
+///
+/// The one-way light time is iterated until convergence, which typically requires two to three
+/// iterations.
+///
+/// # Errors
+///
+/// Returns an error if the position of `target` could not be obtained from `ephem`.
+pub fn light_time_corrected<
+    T: TimeScale + TryToScale<Tdb, P> + Clone,
+    O: Origin + Clone,
+    P,
+    E: Ephemeris,
+>(
+    observer: &State<T, O, Icrf>,
+    target: impl Origin,
+    ephem: &E,
+    provider: Option<&P>,
+) -> Result<DVec3, E::Error> {
+    let epoch = observer
+        .time()
+        .try_to_scale(Tdb, provider)
+        .unwrap()
+        .seconds_since_j2000();
+    let origin = observer.origin().id().0;
+    let target = target.id().0;
+    let mut light_time = 0.0;
+    let mut position = DVec3::ZERO;
+    for _ in 0..MAX_LIGHT_TIME_ITER {
+        position = body_position(ephem, epoch - light_time, origin, target)? - observer.position();
+        let previous = light_time;
+        light_time = position.length() / SPEED_OF_LIGHT;
+        if (light_time - previous).abs() <= LIGHT_TIME_TOLERANCE {
+            break;
+        }
+    }
+    Ok(position)
+}
+
+/// A pass of a spacecraft over a ground station, i.e. an interval during which the spacecraft is
+/// above the minimum elevation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pass<T: TimeScale> {
+    window: Window<T>,
+    max_elevation_time: Time<T>,
+    max_elevation: Radians,
+    partial: bool,
+}
+
+impl<T: TimeScale + Clone> Pass<T> {
+    /// Returns the acquisition of signal (AOS) epoch.
+    pub fn aos(&self) -> Time<T> {
+        self.window.start()
+    }
+
+    /// Returns the loss of signal (LOS) epoch.
+    pub fn los(&self) -> Time<T> {
+        self.window.end()
+    }
+
+    /// Returns the epoch of the maximum elevation during the pass.
+    pub fn max_elevation_time(&self) -> Time<T> {
+        self.max_elevation_time.clone()
+    }
+
+    /// Returns the maximum elevation during the pass in radians.
+    pub fn max_elevation(&self) -> Radians {
+        self.max_elevation
+    }
+
+    /// Returns the interval between AOS and LOS.
+    pub fn window(&self) -> Window<T> {
+        self.window.clone()
+    }
+
+    /// Returns `true` if the pass was already in progress at the start or was still in progress at
+    /// the end of the search window. In this case, AOS or LOS coincide with the window boundaries.
+    pub fn is_partial(&self) -> bool {
+        self.partial
+    }
+}
+
+/// Predicts the passes of the spacecraft propagated by `propagator` over the ground station `gs`
+/// between `start` and `stop` above `min_elevation`.
+///
+/// The elevation is sampled with the given `step` and the horizon crossings are refined with a
+/// root-finder, i.e. the accuracy of AOS and LOS does not depend on the step size. The step size
+/// must however be small enough to not miss short passes.
+///
+/// # Errors
+///
+/// Returns the first error returned by `propagator`.
+///
+/// # Panics
+///
+/// Panics if `step` is not positive.
+pub fn passes<T, O, P, R>(
+    propagator: &R,
+    gs: &GroundLocation<O>,
+    start: Time<T>,
+    stop: Time<T>,
+    step: TimeDelta,
+    min_elevation: Radians,
+    provider: Option<&P>,
+) -> Result<Vec<Pass<T>>, R::Error>
+where
+    T: TimeScale + TryToScale<Tdb, P> + Copy,
+    O: Origin + Spheroid + RotationalElements + Clone,
+    R: Propagator<T, O, Icrf>,
+{
+    let step = step.to_decimal_seconds();
+    assert!(step > 0.0, "step must be positive");
+    let duration = (stop - start).to_decimal_seconds();
+    if duration <= 0.0 {
+        return Ok(vec![]);
+    }
+    let mut steps: Vec<f64> = (0..)
+        .map(|i| i as f64 * step)
+        .take_while(|&t| t < duration)
+        .collect();
+    steps.push(duration);
+
+    // The root-finder requires an infallible function, so the first propagation error is
+    // stashed and returned after the search
+    let error: RefCell<Option<R::Error>> = RefCell::new(None);
+    let elevation = |t: f64| -> Radians {
+        match propagator.propagate(start + TimeDelta::from_decimal_seconds(t)) {
+            Ok(sc) => {
+                let sc = sc.try_to_frame(Iau(gs.origin()), provider).unwrap();
+                gs.observables(sc).elevation()
+            }
+            Err(err) => {
+                error.borrow_mut().get_or_insert(err);
+                min_elevation
+            }
+        }
+    };
+    let check = || match error.borrow_mut().take() {
+        Some(err) => Err(err),
+        None => Ok(()),
+    };
+
+    let events = find_events(
+        |t| elevation(t) - min_elevation,
+        start,
+        &steps,
+        Brent::default(),
+    );
+    check()?;
+    let (events, mut aos) = match events {
+        Ok(events) => {
+            let in_progress = events
+                .first()
+                .is_some_and(|event| event.crossing() == ZeroCrossing::Down);
+            (events, in_progress.then_some(0.0))
+        }
+        Err(FindEventError::AlwaysPositive) => (vec![], Some(0.0)),
+        Err(FindEventError::AlwaysNegative) => (vec![], None),
+    };
+
+    let mut intervals = vec![];
+    for event in events {
+        let t = (event.time() - start).to_decimal_seconds();
+        match event.crossing() {
+            ZeroCrossing::Up => aos = Some(t),
+            ZeroCrossing::Down => {
+                if let Some(t0) = aos.take() {
+                    intervals.push((t0, t, t0 == 0.0));
+                }
+            }
+        }
+    }
+    if let Some(t0) = aos {
+        intervals.push((t0, duration, true));
+    }
+
+    let mut passes = vec![];
+    for (t0, t1, partial) in intervals {
+        // Bracket the maximum with the sampled elevations and refine it
+        let (t_max, _) = steps
+            .iter()
+            .copied()
+            .filter(|&t| t > t0 && t < t1)
+            .chain([t0, t1])
+            .map(|t| (t, elevation(t)))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .unwrap();
+        let t_max = golden_section_max(elevation, (t_max - step).max(t0), (t_max + step).min(t1));
+        let max_elevation = elevation(t_max);
+        check()?;
+        let time = |t: f64| match t {
+            0.0 => start,
+            t if t == duration => stop,
+            t => start + TimeDelta::from_decimal_seconds(t),
+        };
+        passes.push(Pass {
+            window: Window::new(time(t0), time(t1)),
+            max_elevation_time: time(t_max),
+            max_elevation,
+            partial,
+        });
+    }
+    Ok(passes)
+}
+
+/// Locates the maximum of the unimodal function `f` within `[a, b]` to within a millisecond.
+fn golden_section_max(f: impl Fn(f64) -> f64, mut a: f64, mut b: f64) -> f64 {
+    let ratio = (5f64.sqrt() - 1.0) / 2.0;
+    let mut c = b - ratio * (b - a);
+    let mut d = a + ratio * (b - a);
+    let (mut fc, mut fd) = (f(c), f(d));
+    while b - a > 1e-3 {
+        if fc > fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - ratio * (b - a);
+            fc = f(c);
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + ratio * (b - a);
+            fd = f(d);
+        }
+    }
+    (a + b) / 2.0
+}
+
 #[cfg(test)]
 mod tests {
-    use lox_bodies::Earth;
+    use lox_bodies::{Earth, Mars};
     use lox_ephem::spk::parser::{Spk, parse_daf_spk};
     use lox_math::assert_close;
     use lox_math::is_close::IsClose;
+    use lox_math::types::units::Angle;
     use lox_time::Time;
+    use lox_time::time;
     use lox_time::time_scales::Tai;
     use lox_time::ut1::DeltaUt1Tai;
     use lox_time::utc::Utc;
+    use rstest::rstest;
     use std::iter::zip;
     use std::path::PathBuf;
     use std::sync::OnceLock;
 
+    use crate::elements::Keplerian;
+    use crate::propagators::semi_analytical::Vallado;
+
     use super::*;
 
     #[test]
@@ -393,7 +809,7 @@ mod tests {
             &times,
             &gs,
             &mask,
-            &vec![DynOrigin::Moon],
+            &[DynOrigin::Moon],
             &sc,
             ephemeris(),
             None::<&DeltaUt1Tai>,
@@ -405,6 +821,359 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case::sunlit(DVec3::new(42164.0, 0.0, 0.0), ShadowState::Sunlit)]
+    #[case::umbra(DVec3::new(-42164.0, 0.0, 0.0), ShadowState::Umbra)]
+    #[case::penumbra(DVec3::new(-42164.0, 6420.0, 0.0), ShadowState::Penumbra)]
+    #[case::above_shadow(DVec3::new(-42164.0, 0.0, 7000.0), ShadowState::Sunlit)]
+    #[case::inside_shadow(DVec3::new(-42164.0, 0.0, 6000.0), ShadowState::Umbra)]
+    fn test_shadow(#[case] sat_position: DVec3, #[case] expected: ShadowState) {
+        let sun_position = DVec3::new(1.496e8, 0.0, 0.0);
+        let actual = shadow(sat_position, sun_position, Earth.equatorial_radius());
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case::sunlit(DVec3::new(42164.0, 0.0, 0.0), 1.0)]
+    #[case::umbra(DVec3::new(-42164.0, 0.0, 0.0), 0.0)]
+    #[case::above_shadow(DVec3::new(-42164.0, 0.0, 7000.0), 1.0)]
+    fn test_illumination(#[case] sat_position: DVec3, #[case] expected: f64) {
+        let sun_position = DVec3::new(1.496e8, 0.0, 0.0);
+        let actual = illumination(sat_position, sun_position, Earth.equatorial_radius());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_illumination_penumbra() {
+        // The illumination decreases continuously from one to zero across the penumbra
+        let sun_position = DVec3::new(1.496e8, 0.0, 0.0);
+        let radius = Earth.equatorial_radius();
+        let fractions: Vec<f64> = (0..=100)
+            .map(|i| {
+                let sat_position = DVec3::new(-42164.0, 6000.0 + 10.0 * i as f64, 0.0);
+                let fraction = illumination(sat_position, sun_position, radius);
+                let state = shadow(sat_position, sun_position, radius);
+                match state {
+                    ShadowState::Sunlit => assert_eq!(fraction, 1.0),
+                    ShadowState::Umbra => assert_eq!(fraction, 0.0),
+                    ShadowState::Penumbra => assert!(fraction > 0.0 && fraction < 1.0),
+                }
+                fraction
+            })
+            .collect();
+        assert_eq!(fractions[0], 0.0);
+        assert_eq!(fractions[100], 1.0);
+        assert!(fractions.windows(2).all(|w| w[1] >= w[0]));
+        assert!(fractions.windows(2).all(|w| w[1] - w[0] < 0.1));
+
+        // A small body transiting the solar disc obscures the ratio of the areas of the discs
+        let sat_position = DVec3::new(-1e6, 0.0, 0.0);
+        let (a, b, _) = shadow_angles(sat_position, sun_position, 1.0);
+        assert_close!(
+            illumination(sat_position, sun_position, 1.0),
+            1.0 - (b / a).powi(2),
+            1e-15,
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_shadow_annular() {
+        // A small body transiting the solar disc does not cause an umbra
+        let sun_position = DVec3::new(1.496e8, 0.0, 0.0);
+        let actual = shadow(DVec3::new(-1e6, 0.0, 0.0), sun_position, 1.0);
+        assert_eq!(actual, ShadowState::Penumbra);
+    }
+
+    #[test]
+    fn test_eclipses_geo_equinox() {
+        // Around the March equinox, a geostationary satellite passes through the centre of the
+        // shadow of the Earth once per day. The umbral and penumbral phases last about 67 and 72
+        // minutes respectively.
+        let start = time!(Tai, 2025, 3, 19).unwrap();
+        let sc = geo_trajectory(start);
+        let times: Vec<Time<Tai>> = sc.states().iter().map(|s| s.time()).collect();
+        let actual = eclipses(&times, &sc, ephemeris(), None::<&()>);
+        assert_eq!(actual.len(), 2);
+        for eclipse in actual {
+            let penumbra = eclipse.penumbra().duration().to_decimal_seconds() / 60.0;
+            let umbra = eclipse.umbra().unwrap();
+            assert_close!(penumbra, 71.5, 1.0, 0.0);
+            assert_close!(umbra.duration().to_decimal_seconds() / 60.0, 67.3, 1.0, 0.0);
+
+            // The entry and exit epochs are refined beyond the sampling step
+            let (r_sun, r_sat) = sun_and_spacecraft(&sc, umbra.start());
+            let (a, b, c) = shadow_angles(r_sat, r_sun, Earth.equatorial_radius());
+            assert_close!(c, b - a, 1e-7, 0.0);
+            for (t, expected) in [
+                (
+                    eclipse.penumbra().start() - TimeDelta::from_seconds(1),
+                    ShadowState::Sunlit,
+                ),
+                (
+                    eclipse.penumbra().start() + TimeDelta::from_seconds(1),
+                    ShadowState::Penumbra,
+                ),
+                (
+                    umbra.start() + TimeDelta::from_seconds(1),
+                    ShadowState::Umbra,
+                ),
+                (
+                    umbra.end() + TimeDelta::from_seconds(1),
+                    ShadowState::Penumbra,
+                ),
+                (
+                    eclipse.penumbra().end() + TimeDelta::from_seconds(1),
+                    ShadowState::Sunlit,
+                ),
+            ] {
+                let (r_sun, r_sat) = sun_and_spacecraft(&sc, t);
+                assert_eq!(shadow(r_sat, r_sun, Earth.equatorial_radius()), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_eclipses_geo_solstice() {
+        // Outside of the eclipse seasons, a geostationary satellite passes north or south of the
+        // shadow of the Earth
+        let start = time!(Tai, 2025, 6, 20).unwrap();
+        let sc = geo_trajectory(start);
+        let times: Vec<Time<Tai>> = sc.states().iter().map(|s| s.time()).collect();
+        assert!(eclipses(&times, &sc, ephemeris(), None::<&()>).is_empty());
+    }
+
+    fn geo_trajectory(start: Time<Tai>) -> Trajectory<Tai, Earth, Icrf> {
+        let propagator = Vallado::new(
+            Keplerian::new(
+                start,
+                Earth,
+                42164.0,
+                0.0,
+                Angle::ZERO,
+                Angle::ZERO,
+                Angle::ZERO,
+                Angle::ZERO,
+            )
+            .to_cartesian(),
+        );
+        let times = (0..=2 * 24 * 60).map(|i| start + TimeDelta::from_seconds(60 * i));
+        propagator.propagate_all(times).unwrap()
+    }
+
+    fn sun_and_spacecraft(sc: &Trajectory<Tai, Earth, Icrf>, time: Time<Tai>) -> (DVec3, DVec3) {
+        let epoch = time.to_scale(Tdb).seconds_since_j2000();
+        let (r_ssb, r_earth) = (
+            ephemeris().position(epoch, 0, 10).unwrap(),
+            ephemeris().position(epoch, 0, 3).unwrap(),
+        );
+        let r_emb_earth: DVec3 = ephemeris().position(epoch, 3, 399).unwrap().into();
+        let r_sun = DVec3::from(r_ssb) - DVec3::from(r_earth) - r_emb_earth;
+        (r_sun, sc.interpolate_at(time).position())
+    }
+
+    #[test]
+    fn test_light_time_corrected() {
+        // The target moves at 30 km/s perpendicular to the line of sight at a distance of 1 AU,
+        // i.e. the apparent position lags behind by about 15000 km
+        let distance = 1.495978707e8;
+        let velocity = DVec3::new(0.0, 30.0, 0.0);
+        let ephem = LinearEphemeris {
+            id: 499,
+            position: DVec3::new(distance, 0.0, 0.0),
+            velocity,
+        };
+        let time = time!(Tdb, 2000, 1, 1, 12, 0, 0.0).unwrap();
+        let observer = State::new(time, DVec3::ZERO, DVec3::ZERO, Earth, Icrf);
+        let actual = light_time_corrected(&observer, Mars, &ephem, None::<&()>).unwrap();
+        let light_time = actual.length() / SPEED_OF_LIGHT;
+        assert_close!(actual, ephem.position - velocity * light_time, 1e-6, 0.0);
+        assert_close!(actual.y, -30.0 * distance / SPEED_OF_LIGHT, 1e-3, 0.0);
+
+        // The observer position is subtracted at the epoch of observation
+        let offset = DVec3::new(1e4, 2e4, 3e4);
+        let observer = State::new(time, offset, DVec3::ZERO, Earth, Icrf);
+        let actual = light_time_corrected(&observer, Mars, &ephem, None::<&()>).unwrap();
+        let light_time = actual.length() / SPEED_OF_LIGHT;
+        assert_close!(
+            actual,
+            ephem.position - velocity * light_time - offset,
+            1e-6,
+            0.0
+        );
+    }
+
+    /// Ephemeris of a single body moving uniformly with respect to all other bodies, which are at
+    /// rest at the solar system barycentre.
+    struct LinearEphemeris {
+        id: i32,
+        position: DVec3,
+        velocity: DVec3,
+    }
+
+    impl LinearEphemeris {
+        fn barycentric_position(&self, epoch: Epoch, id: i32) -> DVec3 {
+            if id == self.id {
+                self.position + self.velocity * epoch
+            } else {
+                DVec3::ZERO
+            }
+        }
+    }
+
+    impl Ephemeris for LinearEphemeris {
+        type Error = std::convert::Infallible;
+
+        fn position(
+            &self,
+            epoch: Epoch,
+            origin: i32,
+            target: i32,
+        ) -> Result<(f64, f64, f64), Self::Error> {
+            let r =
+                self.barycentric_position(epoch, target) - self.barycentric_position(epoch, origin);
+            Ok(r.into())
+        }
+
+        fn velocity(
+            &self,
+            _epoch: Epoch,
+            origin: i32,
+            target: i32,
+        ) -> Result<(f64, f64, f64), Self::Error> {
+            let v = |id: i32| {
+                if id == self.id {
+                    self.velocity
+                } else {
+                    DVec3::ZERO
+                }
+            };
+            Ok((v(target) - v(origin)).into())
+        }
+
+        fn state(
+            &self,
+            epoch: Epoch,
+            origin: i32,
+            target: i32,
+        ) -> Result<((f64, f64, f64), (f64, f64, f64)), Self::Error> {
+            Ok((
+                self.position(epoch, origin, target)?,
+                self.velocity(epoch, origin, target)?,
+            ))
+        }
+    }
+
+    #[test]
+    fn test_passes() {
+        let propagator = leo_propagator();
+        let gs = location();
+        let start = time!(Tai, 2025, 1, 1).unwrap();
+        let stop = start + TimeDelta::from_days(1.0).unwrap();
+        let min_elevation = 5f64.to_radians();
+        let coarse = passes(
+            &propagator,
+            &gs,
+            start,
+            stop,
+            TimeDelta::from_seconds(60),
+            min_elevation,
+            None::<&()>,
+        )
+        .unwrap();
+        assert!(!coarse.is_empty());
+
+        // Compare against the horizon crossings sampled with a one-second step
+        let elevation = |t: Time<Tai>| {
+            let sc = propagator.propagate(t).unwrap();
+            let sc = sc.try_to_frame(Iau(Earth), None::<&()>).unwrap();
+            gs.observables(sc).elevation()
+        };
+        let mut expected = vec![];
+        let mut t = start;
+        let mut visible = elevation(t) > min_elevation;
+        while t < stop {
+            let t1 = t + TimeDelta::from_seconds(1);
+            let visible1 = elevation(t1) > min_elevation;
+            if visible1 != visible {
+                expected.push(t1);
+            }
+            (t, visible) = (t1, visible1);
+        }
+        assert_eq!(2 * coarse.len(), expected.len());
+        for (pass, crossings) in zip(&coarse, expected.chunks(2)) {
+            assert!(!pass.is_partial());
+            assert!((pass.aos() - crossings[0]).to_decimal_seconds().abs() <= 1.0);
+            assert!((pass.los() - crossings[1]).to_decimal_seconds().abs() <= 1.0);
+            assert_close!(elevation(pass.aos()), min_elevation, 1e-6, 0.0);
+            assert_close!(elevation(pass.los()), min_elevation, 1e-6, 0.0);
+            assert!(pass.aos() < pass.max_elevation_time());
+            assert!(pass.max_elevation_time() < pass.los());
+            for dt in [-1, 1] {
+                let t = pass.max_elevation_time() + TimeDelta::from_seconds(dt);
+                assert!(elevation(t) < pass.max_elevation());
+            }
+        }
+    }
+
+    #[test]
+    fn test_passes_partial() {
+        let propagator = leo_propagator();
+        let gs = location();
+        let start = time!(Tai, 2025, 1, 1).unwrap();
+        let stop = start + TimeDelta::from_days(1.0).unwrap();
+        let step = TimeDelta::from_seconds(60);
+        let all = passes(&propagator, &gs, start, stop, step, 0.0, None::<&()>).unwrap();
+        let first = all.first().unwrap();
+        let last = all.last().unwrap();
+
+        // Start and stop the search halfway through the first and the last pass
+        let start = first.max_elevation_time();
+        let stop = last.max_elevation_time();
+        let actual = passes(&propagator, &gs, start, stop, step, 0.0, None::<&()>).unwrap();
+        assert_eq!(actual.len(), all.len());
+        let (head, tail) = (actual.first().unwrap(), actual.last().unwrap());
+        assert!(head.is_partial());
+        assert_eq!(head.aos(), start);
+        assert_close!(head.los(), first.los(), 1e-3, 0.0);
+        assert!(tail.is_partial());
+        assert_eq!(tail.los(), stop);
+        assert_close!(tail.aos(), last.aos(), 1e-3, 0.0);
+        assert!(actual[1..actual.len() - 1].iter().all(|p| !p.is_partial()));
+
+        // The spacecraft is visible during the whole window
+        let actual = passes(
+            &propagator,
+            &gs,
+            first.aos() + TimeDelta::from_seconds(10),
+            first.los() - TimeDelta::from_seconds(10),
+            step,
+            0.0,
+            None::<&()>,
+        )
+        .unwrap();
+        assert_eq!(actual.len(), 1);
+        assert!(actual[0].is_partial());
+    }
+
+    fn leo_propagator() -> Vallado<Tai, Earth, Icrf> {
+        let time = time!(Tai, 2025, 1, 1).unwrap();
+        let semi_major_axis = Earth.equatorial_radius() + 500.0;
+        Vallado::new(
+            Keplerian::new(
+                time,
+                Earth,
+                semi_major_axis,
+                0.0,
+                Angle::from_degrees(51.6),
+                Angle::ZERO,
+                Angle::ZERO,
+                Angle::ZERO,
+            )
+            .to_cartesian(),
+        )
+    }
+
     fn ground_station_trajectory() -> Trajectory<Tai, Earth, Icrf> {
         Trajectory::from_csv(
             include_str!("../../../data/trajectory_cebr.csv"),
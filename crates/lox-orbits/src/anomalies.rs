@@ -6,14 +6,210 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+//! Module anomalies provides conversions between the true, eccentric, and mean anomaly of
+//! elliptic, parabolic, and hyperbolic orbits.
+//!
+//! For hyperbolic orbits, the eccentric anomaly is the hyperbolic anomaly `H` and the mean anomaly
+//! is defined by `M = e sinh(H) - H`. For parabolic orbits, i.e. an eccentricity of exactly one,
+//! the eccentric anomaly is the parabolic anomaly `D = tan(ν/2)` and the mean anomaly is defined
+//! by Barker's equation `M = D + D³/3`.
+//!
+//! The conversions to the true anomaly and from the true anomaly to the eccentric anomaly of
+//! elliptic orbits return values in `[-π, π)`. The conversions between the eccentric and the mean
+//! anomaly preserve the revolution. For hyperbolic orbits, the true anomaly is bounded by the
+//! asymptotes, i.e. `|ν| < acos(-1/e)`, and inputs beyond this limit result in `NaN`.
+
+use std::f64::consts::PI;
+
+use lox_math::math::normalize_two_pi;
+
+/// Upper bound for the number of refinement steps of the Kepler equation
+/// solvers. The starting values are accurate enough that the bound is never
+/// reached in practice.
+const MAX_ITERATIONS: usize = 50;
+
+/// Converts the hyperbolic anomaly of a hyperbolic orbit, i.e. `eccentricity > 1`, to the true
+/// anomaly.
 pub fn hyperbolic_to_true(hyperbolic_anomaly: f64, eccentricity: f64) -> f64 {
     2.0 * (((1.0 + eccentricity) / (eccentricity - 1.0)).sqrt() * (hyperbolic_anomaly / 2.0).tanh())
         .atan()
 }
 
+/// Converts the eccentric anomaly of an elliptic orbit, the parabolic anomaly of a parabolic orbit,
+/// or the hyperbolic anomaly of a hyperbolic orbit to the true anomaly.
 pub fn eccentric_to_true(eccentric_anomaly: f64, eccentricity: f64) -> f64 {
-    2.0 * (((1.0 + eccentricity) / (1.0 - eccentricity)).sqrt() * (eccentric_anomaly / 2.0).tan())
-        .atan()
+    let e = eccentricity;
+    if is_parabolic(e) {
+        return 2.0 * eccentric_anomaly.atan();
+    }
+    if e > 1.0 {
+        return hyperbolic_to_true(eccentric_anomaly, e);
+    }
+    let (sin_half, cos_half) = (eccentric_anomaly / 2.0).sin_cos();
+    let anomaly = 2.0 * ((1.0 + e).sqrt() * sin_half).atan2((1.0 - e).sqrt() * cos_half);
+    normalize_two_pi(anomaly, 0.0)
+}
+
+/// Converts the true anomaly to the eccentric anomaly of an elliptic orbit, the parabolic anomaly
+/// of a parabolic orbit, or the hyperbolic anomaly of a hyperbolic orbit.
+pub fn true_to_eccentric(true_anomaly: f64, eccentricity: f64) -> f64 {
+    let e = eccentricity;
+    if is_parabolic(e) {
+        return (true_anomaly / 2.0).tan();
+    }
+    if e > 1.0 {
+        return 2.0 * (((e - 1.0) / (e + 1.0)).sqrt() * (true_anomaly / 2.0).tan()).atanh();
+    }
+    let (sin_half, cos_half) = (true_anomaly / 2.0).sin_cos();
+    let anomaly = 2.0 * ((1.0 - e).sqrt() * sin_half).atan2((1.0 + e).sqrt() * cos_half);
+    normalize_two_pi(anomaly, 0.0)
+}
+
+/// Converts the eccentric anomaly of an elliptic orbit or the hyperbolic anomaly of a hyperbolic
+/// orbit to the mean anomaly using Kepler's equation, or the parabolic anomaly of a parabolic orbit
+/// using Barker's equation.
+pub fn eccentric_to_mean(eccentric_anomaly: f64, eccentricity: f64) -> f64 {
+    let e = eccentricity;
+    if is_parabolic(e) {
+        eccentric_anomaly + eccentric_anomaly.powi(3) / 3.0
+    } else if e > 1.0 {
+        e * eccentric_anomaly.sinh() - eccentric_anomaly
+    } else {
+        eccentric_anomaly - e * eccentric_anomaly.sin()
+    }
+}
+
+/// Converts the mean anomaly to the eccentric anomaly of an elliptic orbit or the hyperbolic
+/// anomaly of a hyperbolic orbit by solving Kepler's equation, or to the parabolic anomaly of a
+/// parabolic orbit by solving Barker's equation.
+pub fn mean_to_eccentric(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    if is_parabolic(eccentricity) {
+        mean_to_parabolic_anomaly(mean_anomaly)
+    } else if eccentricity > 1.0 {
+        mean_to_hyperbolic_anomaly(mean_anomaly, eccentricity)
+    } else {
+        mean_to_eccentric_anomaly(mean_anomaly, eccentricity)
+    }
+}
+
+/// Solves Kepler's equation `M = E - e sin(E)` for the eccentric anomaly.
+///
+/// The starting value is computed with Markley's method and refined by a
+/// fifth-order correction, followed by safeguarded Newton steps. The solver
+/// converges for all eccentricities in `[0, 1)` and the result lies in the
+/// same revolution as `mean_anomaly`.
+pub fn mean_to_eccentric_anomaly(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    debug_assert!((0.0..1.0).contains(&eccentricity));
+    let e = eccentricity;
+    let m_reduced = normalize_two_pi(mean_anomaly, 0.0);
+    let offset = mean_anomaly - m_reduced;
+    let sign = m_reduced.signum();
+    let m = m_reduced.abs();
+
+    let alpha = (3.0 * PI.powi(2) + 1.6 * PI * (PI - m) / (1.0 + e)) / (PI.powi(2) - 6.0);
+    let d = 3.0 * (1.0 - e) + alpha * e;
+    let q = 2.0 * alpha * d * (1.0 - e) - m.powi(2);
+    let r = 3.0 * alpha * d * (d - 1.0 + e) * m + m.powi(3);
+    let w = (r.abs() + (q.powi(3) + r.powi(2)).sqrt()).powf(2.0 / 3.0);
+    let e1 = if w == 0.0 {
+        0.0
+    } else {
+        (2.0 * r * w / (w.powi(2) + w * q + q.powi(2)) + m) / d
+    };
+
+    let (sin_e1, cos_e1) = e1.sin_cos();
+    let f0 = e1 - e * sin_e1 - m;
+    let f1 = 1.0 - e * cos_e1;
+    let f2 = e * sin_e1;
+    let f3 = 1.0 - f1;
+    let f4 = -f2;
+    let d3 = -f0 / (f1 - 0.5 * f0 * f2 / f1);
+    let d4 = -f0 / (f1 + 0.5 * d3 * f2 + d3.powi(2) * f3 / 6.0);
+    let d5 = -f0 / (f1 + 0.5 * d4 * f2 + d4.powi(2) * f3 / 6.0 + d4.powi(3) * f4 / 24.0);
+    let start = (e1 + d5).clamp(0.0, PI);
+
+    let anomaly = safeguarded_newton(|x| (x - e * x.sin() - m, 1.0 - e * x.cos()), start, 0.0, PI);
+    sign * anomaly + offset
+}
+
+/// Solves the hyperbolic Kepler equation `M = e sinh(H) - H` for the
+/// hyperbolic anomaly.
+///
+/// The solution is bracketed by `|H| <= asinh(|M| / (e - 1))` which
+/// guarantees convergence for all eccentricities greater than one.
+pub fn mean_to_hyperbolic_anomaly(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    debug_assert!(eccentricity > 1.0);
+    let e = eccentricity;
+    let sign = mean_anomaly.signum();
+    let m = mean_anomaly.abs();
+    let upper = (m / (e - 1.0)).asinh();
+    // Danby's starting value
+    let start = (2.0 * m / e + 1.8).ln().clamp(0.0, upper);
+    let anomaly = safeguarded_newton(
+        |x| (e * x.sinh() - x - m, e * x.cosh() - 1.0),
+        start,
+        0.0,
+        upper,
+    );
+    sign * anomaly
+}
+
+/// Solves Barker's equation `M = D + D³/3` for the parabolic anomaly.
+///
+/// The cubic has a single real root, which is computed in closed form via the identity
+/// `sinh(3x) = 3 sinh(x) + 4 sinh³(x)`. Unlike Cardano's formula, this form does not suffer from
+/// cancellation for small mean anomalies.
+pub fn mean_to_parabolic_anomaly(mean_anomaly: f64) -> f64 {
+    2.0 * ((1.5 * mean_anomaly).asinh() / 3.0).sinh()
+}
+
+/// Converts the mean anomaly of an elliptic, parabolic, or hyperbolic orbit
+/// to the true anomaly.
+pub fn mean_to_true(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    eccentric_to_true(mean_to_eccentric(mean_anomaly, eccentricity), eccentricity)
+}
+
+/// Converts the true anomaly of an elliptic, parabolic, or hyperbolic orbit
+/// to the mean anomaly.
+pub fn true_to_mean(true_anomaly: f64, eccentricity: f64) -> f64 {
+    eccentric_to_mean(true_to_eccentric(true_anomaly, eccentricity), eccentricity)
+}
+
+/// Parabolic orbits are treated separately, since the elliptic and hyperbolic relations are
+/// singular for an eccentricity of exactly one.
+fn is_parabolic(eccentricity: f64) -> bool {
+    eccentricity == 1.0
+}
+
+/// Newton's method for monotonically increasing functions which falls back
+/// to bisection whenever a step would leave the bracket `[lower, upper]`.
+fn safeguarded_newton(
+    f: impl Fn(f64) -> (f64, f64),
+    start: f64,
+    mut lower: f64,
+    mut upper: f64,
+) -> f64 {
+    let mut x = start;
+    for _ in 0..MAX_ITERATIONS {
+        let (value, derivative) = f(x);
+        if value == 0.0 {
+            return x;
+        }
+        if value < 0.0 {
+            lower = x;
+        } else {
+            upper = x;
+        }
+        let mut next = x - value / derivative;
+        if !(lower..=upper).contains(&next) || !next.is_finite() {
+            next = 0.5 * (lower + upper);
+        }
+        if (next - x).abs() <= f64::EPSILON * x.abs().max(1.0) {
+            return next;
+        }
+        x = next;
+    }
+    x
 }
 
 #[cfg(test)]
@@ -21,6 +217,7 @@ mod tests {
     use std::f64::consts::PI;
 
     use float_eq::assert_float_eq;
+    use rstest::rstest;
 
     use super::*;
 
@@ -41,4 +238,181 @@ mod tests {
             rel <= 1e-8
         );
     }
+
+    fn mean_anomalies() -> impl Iterator<Item = f64> {
+        (0..=360).map(|i| (i as f64 - 180.0).to_radians())
+    }
+
+    #[rstest]
+    #[case(0.0)]
+    #[case(0.1)]
+    #[case(0.5)]
+    #[case(0.9)]
+    #[case(0.99)]
+    #[case(0.999)]
+    #[case(0.999999)]
+    fn test_mean_to_eccentric_anomaly(#[case] eccentricity: f64) {
+        for mean_anomaly in mean_anomalies() {
+            let anomaly = mean_to_eccentric_anomaly(mean_anomaly, eccentricity);
+            let residual = anomaly - eccentricity * anomaly.sin() - mean_anomaly;
+            assert!(
+                residual.abs() < 1e-12,
+                "M = {mean_anomaly}, e = {eccentricity}, residual = {residual}"
+            );
+        }
+    }
+
+    #[rstest]
+    #[case(1.000001)]
+    #[case(1.001)]
+    #[case(1.1)]
+    #[case(1.5)]
+    #[case(2.0)]
+    fn test_mean_to_hyperbolic_anomaly(#[case] eccentricity: f64) {
+        for mean_anomaly in mean_anomalies() {
+            let anomaly = mean_to_hyperbolic_anomaly(mean_anomaly, eccentricity);
+            let residual = eccentricity * anomaly.sinh() - anomaly - mean_anomaly;
+            assert!(
+                residual.abs() < 1e-12 * anomaly.cosh(),
+                "M = {mean_anomaly}, e = {eccentricity}, residual = {residual}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_mean_to_eccentric_anomaly_multiple_revolutions() {
+        let anomaly = mean_to_eccentric_anomaly(4.0 * PI + 1.0, 0.3);
+        assert_float_eq!(
+            anomaly,
+            4.0 * PI + mean_to_eccentric_anomaly(1.0, 0.3),
+            rel <= 1e-15
+        );
+    }
+
+    #[test]
+    fn test_mean_to_true() {
+        assert_float_eq!(
+            mean_to_true(PI / 2.0 - 0.2, 0.2),
+            1.7721542475852272,
+            rel <= 1e-12
+        );
+    }
+
+    #[rstest]
+    #[case(0.0)]
+    #[case(0.3)]
+    #[case(0.95)]
+    #[case(1.0)]
+    #[case(1.5)]
+    fn test_true_to_mean_roundtrip(#[case] eccentricity: f64) {
+        for true_anomaly in [-2.0, -0.5, 0.0, 0.5, 1.0, 2.0] {
+            let mean_anomaly = true_to_mean(true_anomaly, eccentricity);
+            assert_float_eq!(
+                mean_to_true(mean_anomaly, eccentricity),
+                true_anomaly,
+                abs <= 1e-12
+            );
+        }
+    }
+
+    const ELLIPTIC: [f64; 6] = [0.0, 0.1, 0.5, 0.9, 0.99, 0.999];
+    const HYPERBOLIC: [f64; 5] = [1.001, 1.1, 1.5, 2.0, 5.0];
+
+    /// Anomalies in `[-π, π)` with a step of 5°.
+    fn anomalies() -> impl Iterator<Item = f64> {
+        (0..72).map(|i| (5.0 * i as f64 - 180.0).to_radians())
+    }
+
+    #[test]
+    fn test_true_eccentric_roundtrip() {
+        for e in ELLIPTIC {
+            for true_anomaly in anomalies() {
+                let eccentric = true_to_eccentric(true_anomaly, e);
+                assert!((-PI..PI).contains(&eccentric));
+                assert_float_eq!(eccentric_to_true(eccentric, e), true_anomaly, abs <= 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_eccentric_mean_roundtrip() {
+        for e in ELLIPTIC.into_iter().chain(HYPERBOLIC) {
+            for eccentric in anomalies() {
+                let mean = eccentric_to_mean(eccentric, e);
+                assert_float_eq!(mean_to_eccentric(mean, e), eccentric, abs <= 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mean_true_roundtrip() {
+        for e in ELLIPTIC {
+            for mean_anomaly in anomalies() {
+                let true_anomaly = mean_to_true(mean_anomaly, e);
+                assert!((-PI..PI).contains(&true_anomaly));
+                // Near periapsis of highly eccentric orbits, the mean anomaly is insensitive to
+                // the true anomaly
+                assert_float_eq!(true_to_mean(true_anomaly, e), mean_anomaly, abs <= 1e-11);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hyperbolic_roundtrip() {
+        for e in HYPERBOLIC {
+            let limit = (-1.0 / e).acos();
+            for true_anomaly in anomalies().filter(|nu| nu.abs() < 0.99 * limit) {
+                let hyperbolic = true_to_eccentric(true_anomaly, e);
+                assert_float_eq!(eccentric_to_true(hyperbolic, e), true_anomaly, abs <= 1e-12);
+                let mean = true_to_mean(true_anomaly, e);
+                assert_float_eq!(mean_to_true(mean, e), true_anomaly, abs <= 1e-12);
+            }
+            assert!(true_to_eccentric(1.01 * limit, e).is_nan());
+        }
+    }
+
+    #[test]
+    fn test_parabolic_roundtrip() {
+        let e = 1.0;
+        for true_anomaly in anomalies().filter(|nu| nu.abs() < 0.99 * PI) {
+            let parabolic = true_to_eccentric(true_anomaly, e);
+            assert_float_eq!(parabolic, (true_anomaly / 2.0).tan(), rel <= 1e-15);
+            assert_float_eq!(eccentric_to_true(parabolic, e), true_anomaly, abs <= 1e-12);
+            let mean = true_to_mean(true_anomaly, e);
+            assert_float_eq!(mean, parabolic + parabolic.powi(3) / 3.0, rel <= 1e-15);
+            assert_float_eq!(mean_to_true(mean, e), true_anomaly, abs <= 1e-12);
+        }
+    }
+
+    #[rstest]
+    #[case(0.0, 0.0)]
+    #[case(4.0 / 3.0, 1.0)]
+    #[case(-4.0 / 3.0, -1.0)]
+    #[case(14.0 / 3.0, 2.0)]
+    #[case(1e6, 144.2180234)]
+    #[case(-1e-12, -1e-12)]
+    fn test_mean_to_parabolic_anomaly(#[case] mean_anomaly: f64, #[case] expected: f64) {
+        let anomaly = mean_to_eccentric(mean_anomaly, 1.0);
+        assert_float_eq!(anomaly, expected, rel <= 1e-9);
+        let residual = anomaly + anomaly.powi(3) / 3.0 - mean_anomaly;
+        assert!(residual.abs() <= 1e-12 * mean_anomaly.abs().max(1.0));
+    }
+
+    #[test]
+    fn test_anomaly_quadrants() {
+        // Apoapsis and periapsis are fixed points of all conversions
+        for e in ELLIPTIC {
+            assert_eq!(true_to_eccentric(0.0, e), 0.0);
+            assert_eq!(eccentric_to_mean(0.0, e), 0.0);
+            assert_float_eq!(true_to_eccentric(-PI, e), -PI, abs <= 1e-14);
+            assert_float_eq!(mean_to_true(-PI, e), -PI, abs <= 1e-14);
+        }
+        // The conversions preserve the sign of the anomaly
+        let e = 0.7;
+        for anomaly in [-3.0, -1.5, -0.1, 0.1, 1.5, 3.0] {
+            assert_eq!(true_to_eccentric(anomaly, e).signum(), anomaly.signum());
+            assert_eq!(eccentric_to_mean(anomaly, e).signum(), anomaly.signum());
+            assert_eq!(mean_to_true(anomaly, e).signum(), anomaly.signum());
+        }
+    }
 }
@@ -0,0 +1,233 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module conjunction provides the assessment of close approaches between two space objects.
+
+use std::f64::consts::TAU;
+
+use glam::{DMat3, DVec3};
+use thiserror::Error;
+
+/// The number of radial integration intervals, which must be even for Simpson's rule.
+const RADIAL_STEPS: usize = 200;
+
+/// The number of angular integration steps.
+const ANGULAR_STEPS: usize = 180;
+
+#[derive(Debug, Clone, Error, PartialEq)]
+pub enum CollisionProbabilityError {
+    #[error("the combined hard-body radius must be positive but was {0}")]
+    InvalidRadius(f64),
+    #[error("the relative velocity is zero, such that the encounter plane is undefined")]
+    ZeroRelativeVelocity,
+    #[error("the combined covariance projected into the encounter plane is singular")]
+    SingularCovariance,
+}
+
+/// Computes the probability of collision between two objects at the time of closest approach
+/// with the two-dimensional method of Foster and Estes (1992).
+///
+/// The inputs are the position and velocity of the secondary relative to the primary in km and
+/// km/s, the combined 3x3 position covariance of both objects in km², and the combined hard-body
+/// radius in km. All vectors and the covariance must be expressed in the same frame.
+///
+/// The combined covariance is projected into the encounter plane perpendicular to the relative
+/// velocity and the resulting bivariate normal distribution is integrated numerically over the
+/// disc of the hard-body radius centred on the miss vector.
+///
+/// # Errors
+///
+/// - [CollisionProbabilityError::InvalidRadius] if `hard_body_radius` is not positive.
+/// - [CollisionProbabilityError::ZeroRelativeVelocity] if `relative_velocity` is zero.
+/// - [CollisionProbabilityError::SingularCovariance] if the projected covariance is not positive
+///   definite.
+pub fn collision_probability(
+    relative_position: DVec3,
+    relative_velocity: DVec3,
+    covariance: DMat3,
+    hard_body_radius: f64,
+) -> Result<f64, CollisionProbabilityError> {
+    if hard_body_radius.is_nan() || hard_body_radius <= 0.0 {
+        return Err(CollisionProbabilityError::InvalidRadius(hard_body_radius));
+    }
+    let normal = relative_velocity
+        .try_normalize()
+        .ok_or(CollisionProbabilityError::ZeroRelativeVelocity)?;
+
+    // The encounter plane is spanned by the unit vector along the miss vector and a second unit
+    // vector perpendicular to it and to the relative velocity
+    let miss = relative_position - relative_position.dot(normal) * normal;
+    let x = miss
+        .try_normalize()
+        .unwrap_or_else(|| normal.any_orthonormal_vector());
+    let z = normal.cross(x);
+    let sxx = x.dot(covariance * x);
+    let sxz = x.dot(covariance * z);
+    let szz = z.dot(covariance * z);
+    let det = sxx * szz - sxz * sxz;
+    if !det.is_finite() || sxx <= 0.0 || szz <= 0.0 || det <= f64::EPSILON * sxx * szz {
+        return Err(CollisionProbabilityError::SingularCovariance);
+    }
+
+    // Elements of the inverse of the projected covariance
+    let ixx = szz / det;
+    let ixz = -sxz / det;
+    let izz = sxx / det;
+    let d = miss.length();
+    let density = |u: f64, w: f64| {
+        (-0.5 * (ixx * u * u + 2.0 * ixz * u * w + izz * w * w)).exp() / (TAU * det.sqrt())
+    };
+
+    // Simpson's rule in the radial and the trapezoidal rule in the periodic angular direction
+    // of polar coordinates centred on the miss vector
+    let h = hard_body_radius / RADIAL_STEPS as f64;
+    let dtheta = TAU / ANGULAR_STEPS as f64;
+    let angles: Vec<(f64, f64)> = (0..ANGULAR_STEPS)
+        .map(|k| (k as f64 * dtheta).sin_cos())
+        .collect();
+    let probability: f64 = (1..=RADIAL_STEPS)
+        .map(|i| {
+            let rho = i as f64 * h;
+            let weight = if i == RADIAL_STEPS {
+                1.0
+            } else if i % 2 == 1 {
+                4.0
+            } else {
+                2.0
+            };
+            let ring: f64 = angles
+                .iter()
+                .map(|&(sin, cos)| density(d + rho * cos, rho * sin))
+                .sum();
+            weight * rho * ring
+        })
+        .sum();
+    Ok((probability * h / 3.0 * dtheta).clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    const VELOCITY: DVec3 = DVec3::new(0.0, 10.0, 0.0);
+
+    /// Chan's series for the collision probability with an isotropic covariance in the encounter
+    /// plane, which is exact in this case.
+    ///
+    /// Chan, 2008, Spacecraft Collision Probability, Eq. 5.26
+    fn chan(sigma: f64, miss: f64, radius: f64) -> f64 {
+        let u = (radius / sigma).powi(2) / 2.0;
+        let v = (miss / sigma).powi(2) / 2.0;
+        let mut pc = 0.0;
+        let mut v_term = (-v).exp();
+        let mut u_sum = 0.0;
+        let mut u_term = (-u).exp();
+        for m in 0..200 {
+            u_sum += u_term;
+            pc += v_term * (1.0 - u_sum);
+            v_term *= v / (m + 1) as f64;
+            u_term *= u / (m + 1) as f64;
+        }
+        pc
+    }
+
+    #[rstest]
+    #[case(0.1, 0.0, 0.02)]
+    #[case(0.1, 0.1, 0.02)]
+    #[case(0.05, 0.2, 0.01)]
+    #[case(1.0, 0.5, 0.005)]
+    fn test_collision_probability_isotropic(
+        #[case] sigma: f64,
+        #[case] miss: f64,
+        #[case] radius: f64,
+    ) {
+        // The variance along the relative velocity does not affect the result
+        let covariance = DMat3::from_diagonal(DVec3::new(sigma.powi(2), 100.0, sigma.powi(2)));
+        let position = DVec3::new(miss * 0.6, 0.0, miss * 0.8);
+        let pc = collision_probability(position, VELOCITY, covariance, radius).unwrap();
+        assert_float_eq!(pc, chan(sigma, miss, radius), r2nd <= 1e-3);
+    }
+
+    #[test]
+    fn test_collision_probability_zero_miss_distance() {
+        let sigma: f64 = 0.1;
+        let radius: f64 = 0.02;
+        let covariance = DMat3::from_diagonal(DVec3::splat(sigma.powi(2)));
+        let pc = collision_probability(DVec3::ZERO, VELOCITY, covariance, radius).unwrap();
+        let expected = 1.0 - (-(radius / sigma).powi(2) / 2.0).exp();
+        assert_float_eq!(pc, expected, r2nd <= 1e-3);
+    }
+
+    #[test]
+    fn test_collision_probability_anisotropic() {
+        // Swapping the axes of the miss vector and the covariance leaves the probability unchanged
+        let covariance = DMat3::from_diagonal(DVec3::new(0.04, 1.0, 0.0025));
+        let swapped = DMat3::from_diagonal(DVec3::new(0.0025, 1.0, 0.04));
+        let pc =
+            collision_probability(DVec3::new(0.1, 0.0, 0.02), VELOCITY, covariance, 0.01).unwrap();
+        let pc_swapped =
+            collision_probability(DVec3::new(0.02, 0.0, 0.1), VELOCITY, swapped, 0.01).unwrap();
+        assert_float_eq!(pc, pc_swapped, r2nd <= 1e-9);
+        assert!(pc > 0.0 && pc < 1.0);
+    }
+
+    #[test]
+    fn test_collision_probability_cara_reference() {
+        // Reference case of the `Pc2D_Foster` unit test of the NASA CARA Analysis Tools with
+        // states in km and km/s, covariances in km², and a hard-body radius of 20 m
+        let r1 = DVec3::new(378.39559, 4305.721887, 5752.767554);
+        let v1 = DVec3::new(2.360800244, 5.580331936, -4.322349039);
+        let r2 = DVec3::new(374.5180598, 4307.560983, 5751.130418);
+        let v2 = DVec3::new(-5.388125081, -3.946827739, 3.322820358);
+        let cov1 = DMat3::from_cols_array(&[
+            44.5757544811362,
+            81.6751751052616,
+            -67.8687662707124,
+            81.6751751052616,
+            158.453402956163,
+            -128.616921644857,
+            -67.8687662707124,
+            -128.616921644857,
+            105.490542562701,
+        ]);
+        let cov2 = DMat3::from_cols_array(&[
+            2.31067077720423,
+            1.69905293875632,
+            -1.4170164577661,
+            1.69905293875632,
+            1.24957388457206,
+            -1.04174164279599,
+            -1.4170164577661,
+            -1.04174164279599,
+            0.869260558223714,
+        ]);
+        let pc = collision_probability(r2 - r1, v2 - v1, cov1 + cov2, 0.02).unwrap();
+        assert_float_eq!(pc, 2.7060234e-5, r2nd <= 1e-6);
+    }
+
+    #[test]
+    fn test_collision_probability_errors() {
+        let covariance = DMat3::IDENTITY;
+        assert_eq!(
+            collision_probability(DVec3::X, VELOCITY, covariance, 0.0),
+            Err(CollisionProbabilityError::InvalidRadius(0.0))
+        );
+        assert_eq!(
+            collision_probability(DVec3::X, DVec3::ZERO, covariance, 0.01),
+            Err(CollisionProbabilityError::ZeroRelativeVelocity)
+        );
+        let singular = DMat3::from_diagonal(DVec3::new(1.0, 1.0, 0.0));
+        assert_eq!(
+            collision_probability(DVec3::X, VELOCITY, singular, 0.01),
+            Err(CollisionProbabilityError::SingularCovariance)
+        );
+    }
+}
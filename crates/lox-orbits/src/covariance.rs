@@ -0,0 +1,179 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module covariance provides the transformation of 6x6 position-velocity covariance matrices
+//! between reference frames.
+
+use glam::DMat3;
+
+use crate::rotations::Rotation;
+
+/// A row-major 6x6 matrix.
+pub type Matrix6 = [[f64; 6]; 6];
+
+/// A symmetric 6x6 covariance matrix of a Cartesian state ordered as x, y, z, vx, vy, vz, in
+/// km², km²/s, and km²/s².
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Covariance(Matrix6);
+
+impl Covariance {
+    pub fn new(matrix: Matrix6) -> Self {
+        Self(matrix)
+    }
+
+    pub fn matrix(&self) -> Matrix6 {
+        self.0
+    }
+
+    /// Returns the 3x3 position block of the covariance.
+    pub fn position(&self) -> DMat3 {
+        DMat3::from_cols_array_2d(&std::array::from_fn(|j| {
+            std::array::from_fn(|i| self.0[i][j])
+        }))
+    }
+
+    /// Transforms the covariance with the 6x6 `jacobian` of the state transformation, i.e.
+    /// P' = J P Jᵀ.
+    ///
+    /// The result is re-symmetrized to remove the asymmetry introduced by round-off.
+    pub fn transform(&self, jacobian: &Matrix6) -> Self {
+        let jacobian_t = std::array::from_fn(|i| std::array::from_fn(|j| jacobian[j][i]));
+        let p = mul(&mul(jacobian, &self.0), &jacobian_t);
+        Self(std::array::from_fn(|i| {
+            std::array::from_fn(|j| 0.5 * (p[i][j] + p[j][i]))
+        }))
+    }
+
+    /// Rotates the covariance into the frame defined by `rotation`, accounting for the angular
+    /// velocity of the frame through the derivative of the rotation.
+    pub fn rotate(&self, rotation: &Rotation) -> Self {
+        self.transform(&rotation.jacobian())
+    }
+}
+
+impl From<Matrix6> for Covariance {
+    fn from(matrix: Matrix6) -> Self {
+        Self(matrix)
+    }
+}
+
+/// Transforms the covariance `cov` with the 6x6 `jacobian` of the state transformation, i.e.
+/// P' = J P Jᵀ.
+pub fn transform_covariance(cov: &Covariance, jacobian: &Matrix6) -> Covariance {
+    cov.transform(jacobian)
+}
+
+impl Rotation {
+    /// Returns the 6x6 Jacobian of the state transformation performed by [Rotation::rotate_state],
+    /// i.e. the block matrix [[M, 0], [Ṁ, M]].
+    pub fn jacobian(&self) -> Matrix6 {
+        let m = self.position_matrix();
+        let dm = self.velocity_matrix();
+        let mut jacobian = [[0.0; 6]; 6];
+        set_block(&mut jacobian, 0, 0, m);
+        set_block(&mut jacobian, 3, 0, dm);
+        set_block(&mut jacobian, 3, 3, m);
+        jacobian
+    }
+}
+
+fn set_block(matrix: &mut Matrix6, row: usize, col: usize, block: DMat3) {
+    for j in 0..3 {
+        let column = block.col(j);
+        for i in 0..3 {
+            matrix[row + i][col + j] = column[i];
+        }
+    }
+}
+
+pub(crate) fn mul(a: &Matrix6, b: &Matrix6) -> Matrix6 {
+    let mut out = [[0.0; 6]; 6];
+    for i in 0..6 {
+        for j in 0..6 {
+            out[i][j] = (0..6).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::DVec3;
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+
+    use crate::states::LocalOrbitalFrame;
+
+    use super::*;
+
+    fn covariance() -> Covariance {
+        let mut matrix = [[0.0; 6]; 6];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = if i == j {
+                    10.0_f64.powi(-(i as i32))
+                } else {
+                    1e-4 / (1.0 + (i + j) as f64)
+                };
+            }
+        }
+        Covariance::new(matrix)
+    }
+
+    fn identity() -> Matrix6 {
+        std::array::from_fn(|i| std::array::from_fn(|j| if i == j { 1.0 } else { 0.0 }))
+    }
+
+    fn assert_matrix_close(act: &Matrix6, exp: &Matrix6, tol: f64) {
+        for i in 0..6 {
+            for j in 0..6 {
+                assert_close!(act[i][j], exp[i][j], tol, 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_transform_covariance_identity() {
+        let cov = covariance();
+        assert_eq!(transform_covariance(&cov, &identity()), cov);
+        assert_eq!(cov.rotate(&Rotation::IDENTITY), cov);
+        assert_eq!(cov.position().col(1)[2], cov.matrix()[2][1]);
+    }
+
+    #[test]
+    fn test_covariance_rtn_round_trip() {
+        let position = DVec3::new(6068.27927, -1692.84394, -2516.61918);
+        let velocity = DVec3::new(-0.660415582, 5.495938726, -5.303093233);
+        let to_rtn = LocalOrbitalFrame::Rtn.rotation(position, velocity);
+        let cov = covariance();
+        let inertial = cov.rotate(&to_rtn.transpose());
+        let matrix = inertial.matrix();
+        for (i, row) in matrix.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                assert_eq!(*value, matrix[j][i]);
+            }
+        }
+        let act = inertial.rotate(&to_rtn);
+        assert_matrix_close(&act.matrix(), &cov.matrix(), 1e-15);
+    }
+
+    #[test]
+    fn test_rotation_jacobian() {
+        let position = DVec3::new(6068.27927, -1692.84394, -2516.61918);
+        let velocity = DVec3::new(-0.660415582, 5.495938726, -5.303093233);
+        let rotation = LocalOrbitalFrame::Rtn.rotation(position, velocity);
+        let r = DVec3::new(1.0, 2.0, 3.0);
+        let v = DVec3::new(-0.1, 0.2, 0.3);
+        let x = [r.x, r.y, r.z, v.x, v.y, v.z];
+        let jacobian = rotation.jacobian();
+        let act: [f64; 6] = std::array::from_fn(|i| (0..6).map(|k| jacobian[i][k] * x[k]).sum());
+        let (r_exp, v_exp) = rotation.rotate_state(r, v);
+        assert_close!(DVec3::new(act[0], act[1], act[2]), r_exp);
+        assert_close!(DVec3::new(act[3], act[4], act[5]), v_exp);
+    }
+}
@@ -12,12 +12,13 @@ use float_eq::float_eq;
 use glam::{DMat3, DVec3};
 
 use lox_bodies::{DynOrigin, PointMass, TryPointMass, UndefinedOriginPropertyError};
+use lox_math::types::units::Angle;
 use lox_time::deltas::TimeDelta;
 use lox_time::time_scales::{DynTimeScale, TimeScale};
 use lox_time::{DynTime, Time};
 
 use crate::frames::{DynFrame, Icrf, ReferenceFrame};
-use crate::states::State;
+use crate::states::{State, rv_to_keplerian};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct KeplerianElements {
@@ -30,6 +31,22 @@ pub struct KeplerianElements {
 }
 
 impl KeplerianElements {
+    /// Computes the osculating elements from a Cartesian state vector.
+    ///
+    /// Undefined angles are resolved with the usual conventions:
+    ///
+    /// - Circular orbits have an argument of periapsis of zero and the true
+    ///   anomaly is measured from the ascending node (argument of latitude).
+    /// - Equatorial orbits have a longitude of the ascending node of zero and
+    ///   the argument of periapsis is measured from the x-axis (longitude of
+    ///   periapsis).
+    /// - Circular equatorial orbits use the true longitude as true anomaly.
+    ///
+    /// Hyperbolic orbits have a negative semi-major axis.
+    pub fn from_cartesian(pos: DVec3, vel: DVec3, grav_param: f64) -> Self {
+        rv_to_keplerian(pos, vel, grav_param)
+    }
+
     pub fn semi_parameter(&self) -> f64 {
         if is_circular(self.eccentricity) {
             self.semi_major_axis
@@ -38,6 +55,41 @@ impl KeplerianElements {
         }
     }
 
+    /// Returns the orbital period or `None` for parabolic and hyperbolic orbits.
+    pub fn period(&self, grav_param: f64) -> Option<TimeDelta> {
+        if self.eccentricity >= 1.0 {
+            return None;
+        }
+        TimeDelta::try_from_decimal_seconds(TAU / self.mean_motion(grav_param)).ok()
+    }
+
+    /// Returns the mean motion in rad/s.
+    ///
+    /// For hyperbolic orbits, this is the rate of the hyperbolic mean anomaly.
+    pub fn mean_motion(&self, grav_param: f64) -> f64 {
+        (grav_param / self.semi_major_axis.abs().powi(3)).sqrt()
+    }
+
+    pub fn periapsis_radius(&self) -> f64 {
+        self.semi_major_axis * (1.0 - self.eccentricity)
+    }
+
+    /// Returns the apoapsis radius or `None` for parabolic and hyperbolic orbits.
+    pub fn apoapsis_radius(&self) -> Option<f64> {
+        (self.eccentricity < 1.0).then_some(self.semi_major_axis * (1.0 + self.eccentricity))
+    }
+
+    /// Returns the velocity at periapsis from the vis-viva equation.
+    pub fn periapsis_velocity(&self, grav_param: f64) -> f64 {
+        vis_viva(grav_param, self.periapsis_radius(), self.semi_major_axis)
+    }
+
+    /// Returns the velocity at apoapsis or `None` for parabolic and hyperbolic orbits.
+    pub fn apoapsis_velocity(&self, grav_param: f64) -> Option<f64> {
+        self.apoapsis_radius()
+            .map(|r| vis_viva(grav_param, r, self.semi_major_axis))
+    }
+
     pub fn to_perifocal(&self, grav_param: f64) -> (DVec3, DVec3) {
         let semiparameter = self.semi_parameter();
         let (sin_nu, cos_nu) = self.true_anomaly.sin_cos();
@@ -85,10 +137,10 @@ where
         origin: O,
         semi_major_axis: f64,
         eccentricity: f64,
-        inclination: f64,
-        longitude_of_ascending_node: f64,
-        argument_of_periapsis: f64,
-        true_anomaly: f64,
+        inclination: Angle,
+        longitude_of_ascending_node: Angle,
+        argument_of_periapsis: Angle,
+        true_anomaly: Angle,
     ) -> Self {
         Self {
             time,
@@ -96,10 +148,10 @@ where
             frame: Icrf,
             semi_major_axis,
             eccentricity,
-            inclination,
-            longitude_of_ascending_node,
-            argument_of_periapsis,
-            true_anomaly,
+            inclination: inclination.to_radians(),
+            longitude_of_ascending_node: longitude_of_ascending_node.to_radians(),
+            argument_of_periapsis: argument_of_periapsis.to_radians(),
+            true_anomaly: true_anomaly.to_radians(),
         }
     }
 }
@@ -111,10 +163,10 @@ impl DynKeplerian {
         origin: DynOrigin,
         semi_major_axis: f64,
         eccentricity: f64,
-        inclination: f64,
-        longitude_of_ascending_node: f64,
-        argument_of_periapsis: f64,
-        true_anomaly: f64,
+        inclination: Angle,
+        longitude_of_ascending_node: Angle,
+        argument_of_periapsis: Angle,
+        true_anomaly: Angle,
     ) -> Result<Self, UndefinedOriginPropertyError> {
         let _ = origin.try_gravitational_parameter()?;
         Ok(Self {
@@ -123,10 +175,10 @@ impl DynKeplerian {
             frame: DynFrame::Icrf,
             semi_major_axis,
             eccentricity,
-            inclination,
-            longitude_of_ascending_node,
-            argument_of_periapsis,
-            true_anomaly,
+            inclination: inclination.to_radians(),
+            longitude_of_ascending_node: longitude_of_ascending_node.to_radians(),
+            argument_of_periapsis: argument_of_periapsis.to_radians(),
+            true_anomaly: true_anomaly.to_radians(),
         })
     }
 }
@@ -172,20 +224,20 @@ where
         self.eccentricity
     }
 
-    pub fn inclination(&self) -> f64 {
-        self.inclination
+    pub fn inclination(&self) -> Angle {
+        Angle::from_radians(self.inclination)
     }
 
-    pub fn longitude_of_ascending_node(&self) -> f64 {
-        self.longitude_of_ascending_node
+    pub fn longitude_of_ascending_node(&self) -> Angle {
+        Angle::from_radians(self.longitude_of_ascending_node)
     }
 
-    pub fn argument_of_periapsis(&self) -> f64 {
-        self.argument_of_periapsis
+    pub fn argument_of_periapsis(&self) -> Angle {
+        Angle::from_radians(self.argument_of_periapsis)
     }
 
-    pub fn true_anomaly(&self) -> f64 {
-        self.true_anomaly
+    pub fn true_anomaly(&self) -> Angle {
+        Angle::from_radians(self.true_anomaly)
     }
 
     pub fn semiparameter(&self) -> f64 {
@@ -209,6 +261,39 @@ where
         (pos, vel)
     }
 
+    pub fn mean_motion(&self) -> f64 {
+        self.elements().mean_motion(self.gravitational_parameter())
+    }
+
+    pub fn periapsis_radius(&self) -> f64 {
+        self.elements().periapsis_radius()
+    }
+
+    pub fn apoapsis_radius(&self) -> Option<f64> {
+        self.elements().apoapsis_radius()
+    }
+
+    pub fn periapsis_velocity(&self) -> f64 {
+        self.elements()
+            .periapsis_velocity(self.gravitational_parameter())
+    }
+
+    pub fn apoapsis_velocity(&self) -> Option<f64> {
+        self.elements()
+            .apoapsis_velocity(self.gravitational_parameter())
+    }
+
+    fn elements(&self) -> KeplerianElements {
+        KeplerianElements {
+            semi_major_axis: self.semi_major_axis,
+            eccentricity: self.eccentricity,
+            inclination: self.inclination,
+            longitude_of_ascending_node: self.longitude_of_ascending_node,
+            argument_of_periapsis: self.argument_of_periapsis,
+            true_anomaly: self.true_anomaly,
+        }
+    }
+
     pub fn orbital_period(&self) -> TimeDelta {
         let mu = self.gravitational_parameter();
         let a = self.semi_major_axis();
@@ -237,6 +322,10 @@ where
     }
 }
 
+fn vis_viva(grav_param: f64, radius: f64, semi_major_axis: f64) -> f64 {
+    (grav_param * (2.0 / radius - 1.0 / semi_major_axis)).sqrt()
+}
+
 pub fn is_equatorial(inclination: f64) -> bool {
     float_eq!(inclination.abs(), 0.0, abs <= 1e-8)
 }
@@ -249,10 +338,13 @@ pub fn is_circular(eccentricity: f64) -> bool {
 mod tests {
     use super::*;
 
+    use std::f64::consts::PI;
+
     use float_eq::assert_float_eq;
     use lox_bodies::Earth;
     use lox_time::time_scales::Tdb;
     use lox_time::{Time, time};
+    use rstest::rstest;
 
     #[test]
     fn test_keplerian() {
@@ -269,10 +361,10 @@ mod tests {
             Earth,
             semi_major,
             eccentricity,
-            inclination,
-            ascending_node,
-            periapsis_arg,
-            true_anomaly,
+            Angle::from_radians(inclination),
+            Angle::from_radians(ascending_node),
+            Angle::from_radians(periapsis_arg),
+            Angle::from_radians(true_anomaly),
         );
         let keplerian1 = keplerian.to_cartesian().to_keplerian();
 
@@ -291,24 +383,197 @@ mod tests {
             abs <= 1e-6
         );
         assert_float_eq!(
-            keplerian.inclination(),
-            keplerian1.inclination(),
+            keplerian.inclination().to_radians(),
+            keplerian1.inclination().to_radians(),
             rel <= 1e-6
         );
         assert_float_eq!(
-            keplerian.longitude_of_ascending_node(),
-            keplerian1.longitude_of_ascending_node(),
+            keplerian.longitude_of_ascending_node().to_radians(),
+            keplerian1.longitude_of_ascending_node().to_radians(),
             rel <= 1e-6
         );
         assert_float_eq!(
-            keplerian.argument_of_periapsis(),
-            keplerian1.argument_of_periapsis(),
+            keplerian.argument_of_periapsis().to_radians(),
+            keplerian1.argument_of_periapsis().to_radians(),
             rel <= 1e-6
         );
         assert_float_eq!(
-            keplerian.true_anomaly(),
-            keplerian1.true_anomaly(),
+            keplerian.true_anomaly().to_radians(),
+            keplerian1.true_anomaly().to_radians(),
             rel <= 1e-6
         );
     }
+
+    #[test]
+    fn test_keplerian_angles() {
+        let time = time!(Tdb, 2023, 3, 25, 21, 8, 0.0).expect("time should be valid");
+        let keplerian = Keplerian::new(
+            time,
+            Earth,
+            24464.560,
+            0.7311,
+            Angle::from_radians(0.122138),
+            Angle::from_radians(1.00681),
+            Angle::from_radians(3.10686),
+            Angle::from_radians(-0.5),
+        );
+
+        assert_eq!(keplerian.inclination(), Angle::from_radians(0.122138));
+        assert_eq!(
+            keplerian.longitude_of_ascending_node(),
+            Angle::from_radians(1.00681)
+        );
+        assert_eq!(
+            keplerian.argument_of_periapsis(),
+            Angle::from_radians(3.10686)
+        );
+        assert_eq!(keplerian.true_anomaly(), Angle::from_radians(-0.5));
+        assert_float_eq!(
+            keplerian.true_anomaly().wrap_to_2pi().to_radians(),
+            TAU - 0.5,
+            rel <= 1e-15
+        );
+    }
+
+    const MU_EARTH: f64 = 398600.4418;
+
+    fn assert_elements_close(act: &KeplerianElements, exp: &KeplerianElements) {
+        assert_float_eq!(act.semi_major_axis, exp.semi_major_axis, rel <= 1e-9);
+        assert_float_eq!(act.eccentricity, exp.eccentricity, abs <= 1e-9);
+        assert_float_eq!(act.inclination, exp.inclination, abs <= 1e-9);
+        assert_float_eq!(
+            act.longitude_of_ascending_node,
+            exp.longitude_of_ascending_node,
+            abs <= 1e-9
+        );
+        assert_float_eq!(
+            act.argument_of_periapsis,
+            exp.argument_of_periapsis,
+            abs <= 1e-9
+        );
+        assert_float_eq!(act.true_anomaly, exp.true_anomaly, abs <= 1e-9);
+    }
+
+    #[rstest]
+    #[case::leo(6778.0, 0.001, 0.9, 1.2, 0.5, 2.1)]
+    #[case::molniya(26600.0, 0.74, 1.1, 4.5, 4.7, 0.3)]
+    #[case::hyperbolic(-12000.0, 1.5, 0.4, 0.3, 1.0, 0.7)]
+    #[case::retrograde(7000.0, 0.01, 2.5, 0.2, 5.5, -0.3)]
+    #[case::circular(7000.0, 0.0, 0.9, 1.2, 0.0, 2.1)]
+    #[case::equatorial(7000.0, 0.1, 0.0, 0.0, 1.2, 2.1)]
+    #[case::retrograde_equatorial(7000.0, 0.1, PI, 0.0, 1.2, 2.1)]
+    #[case::circular_equatorial(7000.0, 0.0, 0.0, 0.0, 0.0, 2.1)]
+    fn test_keplerian_elements_roundtrip(
+        #[case] semi_major_axis: f64,
+        #[case] eccentricity: f64,
+        #[case] inclination: f64,
+        #[case] longitude_of_ascending_node: f64,
+        #[case] argument_of_periapsis: f64,
+        #[case] true_anomaly: f64,
+    ) {
+        let elements = KeplerianElements {
+            semi_major_axis,
+            eccentricity,
+            inclination,
+            longitude_of_ascending_node,
+            argument_of_periapsis,
+            true_anomaly,
+        };
+        let (pos, vel) = elements.to_cartesian(MU_EARTH);
+        let elements1 = KeplerianElements::from_cartesian(pos, vel, MU_EARTH);
+        assert_elements_close(&elements1, &elements);
+    }
+
+    fn elements(semi_major_axis: f64, eccentricity: f64) -> KeplerianElements {
+        KeplerianElements {
+            semi_major_axis,
+            eccentricity,
+            inclination: 0.9,
+            longitude_of_ascending_node: 1.2,
+            argument_of_periapsis: 0.5,
+            true_anomaly: 2.1,
+        }
+    }
+
+    #[test]
+    fn test_keplerian_elements_circular() {
+        let elements = elements(7000.0, 0.0);
+        let v = (MU_EARTH / 7000.0).sqrt();
+        assert_eq!(elements.periapsis_radius(), 7000.0);
+        assert_eq!(elements.apoapsis_radius(), Some(7000.0));
+        assert_float_eq!(elements.periapsis_velocity(MU_EARTH), v, rel <= 1e-15);
+        assert_float_eq!(
+            elements.apoapsis_velocity(MU_EARTH).unwrap(),
+            v,
+            rel <= 1e-15
+        );
+        let period = elements.period(MU_EARTH).unwrap().to_decimal_seconds();
+        assert_float_eq!(period, 5828.516637686015, rel <= 1e-12);
+        assert_float_eq!(elements.mean_motion(MU_EARTH) * period, TAU, rel <= 1e-15);
+    }
+
+    #[test]
+    fn test_keplerian_elements_elliptical() {
+        // Molniya orbit
+        let elements = elements(26600.0, 0.74);
+        assert_float_eq!(elements.periapsis_radius(), 6916.0, rel <= 1e-15);
+        assert_float_eq!(elements.apoapsis_radius().unwrap(), 46284.0, rel <= 1e-15);
+        let v_p = elements.periapsis_velocity(MU_EARTH);
+        let v_a = elements.apoapsis_velocity(MU_EARTH).unwrap();
+        assert_float_eq!(v_p, 10.014194442460433, rel <= 1e-12);
+        // Conservation of angular momentum
+        assert_float_eq!(v_p * 6916.0, v_a * 46284.0, rel <= 1e-12);
+        let period = elements.period(MU_EARTH).unwrap().to_decimal_seconds();
+        assert_float_eq!(period, 43175.10828214549, rel <= 1e-12);
+    }
+
+    #[test]
+    fn test_keplerian_elements_hyperbolic() {
+        let elements = elements(-12000.0, 1.5);
+        assert_float_eq!(elements.periapsis_radius(), 6000.0, rel <= 1e-15);
+        assert_eq!(elements.apoapsis_radius(), None);
+        assert_eq!(elements.apoapsis_velocity(MU_EARTH), None);
+        assert_eq!(elements.period(MU_EARTH), None);
+        let v_inf = (MU_EARTH / 12000.0).sqrt();
+        let v_esc = (2.0 * MU_EARTH / 6000.0).sqrt();
+        assert_float_eq!(
+            elements.periapsis_velocity(MU_EARTH),
+            (v_inf.powi(2) + v_esc.powi(2)).sqrt(),
+            rel <= 1e-15
+        );
+        assert_float_eq!(
+            elements.mean_motion(MU_EARTH),
+            (MU_EARTH / 12000.0_f64.powi(3)).sqrt(),
+            rel <= 1e-15
+        );
+    }
+
+    #[test]
+    fn test_keplerian_apsides() {
+        let time = time!(Tdb, 2023, 3, 25, 21, 8, 0.0).unwrap();
+        let keplerian = Keplerian::new(
+            time,
+            Earth,
+            26600.0,
+            0.74,
+            Angle::from_radians(1.1),
+            Angle::from_radians(4.5),
+            Angle::from_radians(4.7),
+            Angle::from_radians(0.3),
+        );
+        let elements = elements(26600.0, 0.74);
+        let mu = Earth.gravitational_parameter();
+        assert_eq!(keplerian.periapsis_radius(), elements.periapsis_radius());
+        assert_eq!(keplerian.apoapsis_radius(), elements.apoapsis_radius());
+        assert_eq!(keplerian.mean_motion(), elements.mean_motion(mu));
+        assert_eq!(
+            keplerian.periapsis_velocity(),
+            elements.periapsis_velocity(mu)
+        );
+        assert_eq!(
+            keplerian.apoapsis_velocity(),
+            elements.apoapsis_velocity(mu)
+        );
+        assert_eq!(Some(keplerian.orbital_period()), elements.period(mu));
+    }
 }
@@ -0,0 +1,331 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module ephemeris provides polynomial interpolation of tabulated ephemerides such as the data
+//! lines of an OEM segment.
+
+use glam::DVec3;
+use lox_bodies::Origin;
+use lox_time::Time;
+use lox_time::time_scales::TimeScale;
+use thiserror::Error;
+
+use crate::frames::ReferenceFrame;
+use crate::states::State;
+
+/// The interpolation schemes supported by [EphemerisTable], which correspond to the values of the
+/// `INTERPOLATION` keyword of an OEM.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InterpolationMethod {
+    /// Lagrange interpolation of the positions. The velocity is obtained from the derivative of the
+    /// interpolating polynomial.
+    #[default]
+    Lagrange,
+    /// Hermite interpolation of the positions which also matches the tabulated velocities.
+    Hermite,
+}
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+pub enum EphemerisTableError {
+    #[error(
+        "interpolation of degree {degree} requires at least {required} states but {actual} were provided"
+    )]
+    InsufficientStates {
+        degree: usize,
+        required: usize,
+        actual: usize,
+    },
+    #[error("the epochs of the ephemeris must be strictly increasing")]
+    UnorderedEpochs,
+    #[error("epoch {epoch} is outside of the span of the ephemeris from {start} to {end}")]
+    OutOfRange {
+        epoch: String,
+        start: String,
+        end: String,
+    },
+}
+
+/// A table of states at strictly increasing epochs which can be interpolated with a polynomial of
+/// configurable degree.
+#[derive(Clone, Debug)]
+pub struct EphemerisTable<T: TimeScale, O: Origin, R: ReferenceFrame> {
+    states: Vec<State<T, O, R>>,
+    method: InterpolationMethod,
+    degree: usize,
+}
+
+impl<T, O, R> EphemerisTable<T, O, R>
+where
+    T: TimeScale + Clone,
+    O: Origin + Clone,
+    R: ReferenceFrame + Clone,
+{
+    /// Creates a new table from `states` which are interpolated using `method` and a polynomial of
+    /// `degree`, i.e. the value of the `INTERPOLATION_DEGREE` keyword of an OEM.
+    ///
+    /// Lagrange interpolation uses a window of `degree + 1` states. Hermite interpolation matches
+    /// both positions and velocities and therefore only requires `(degree + 1) / 2` states,
+    /// rounded up.
+    pub fn new(
+        states: Vec<State<T, O, R>>,
+        method: InterpolationMethod,
+        degree: usize,
+    ) -> Result<Self, EphemerisTableError> {
+        let required = window_size(method, degree);
+        if states.len() < required {
+            return Err(EphemerisTableError::InsufficientStates {
+                degree,
+                required,
+                actual: states.len(),
+            });
+        }
+        if states
+            .windows(2)
+            .any(|w| (w[1].time() - w[0].time()).to_decimal_seconds() <= 0.0)
+        {
+            return Err(EphemerisTableError::UnorderedEpochs);
+        }
+        Ok(Self {
+            states,
+            method,
+            degree,
+        })
+    }
+
+    pub fn states(&self) -> &[State<T, O, R>] {
+        &self.states
+    }
+
+    pub fn method(&self) -> InterpolationMethod {
+        self.method
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    pub fn start_time(&self) -> Time<T> {
+        self.states[0].time()
+    }
+
+    pub fn end_time(&self) -> Time<T> {
+        self.states.last().unwrap().time()
+    }
+
+    /// Interpolates the state at `epoch` from the window of states centered on it.
+    ///
+    /// The window is shifted inwards close to the boundaries of the table. Epochs outside of the
+    /// span of the table are rejected since the interpolating polynomials diverge quickly when
+    /// used for extrapolation.
+    pub fn interpolate(&self, epoch: Time<T>) -> Result<State<T, O, R>, EphemerisTableError> {
+        // Offsets in seconds relative to the requested epoch
+        let t: Vec<f64> = self
+            .states
+            .iter()
+            .map(|s| (s.time() - epoch.clone()).to_decimal_seconds())
+            .collect();
+        if t[0] > 0.0 || *t.last().unwrap() < 0.0 {
+            return Err(EphemerisTableError::OutOfRange {
+                epoch: epoch.to_string(),
+                start: self.start_time().to_string(),
+                end: self.end_time().to_string(),
+            });
+        }
+
+        let n = window_size(self.method, self.degree);
+        // Index of the first state after the requested epoch
+        let next = t.partition_point(|&ti| ti <= 0.0);
+        let start = next.saturating_sub(n / 2).min(self.states.len() - n);
+        let window = &self.states[start..start + n];
+        let t = &t[start..start + n];
+
+        let (nodes, values) = match self.method {
+            InterpolationMethod::Lagrange => {
+                let values: Vec<DVec3> = window.iter().map(|s| s.position()).collect();
+                (t.to_vec(), lagrange_coefficients(t, values))
+            }
+            InterpolationMethod::Hermite => {
+                let nodes: Vec<f64> = t.iter().flat_map(|&ti| [ti, ti]).collect();
+                let values = hermite_coefficients(&nodes, window);
+                (nodes, values)
+            }
+        };
+        let (position, velocity) = evaluate_newton(&nodes, &values, 0.0);
+        Ok(State::new(
+            epoch,
+            position,
+            velocity,
+            window[0].origin(),
+            window[0].reference_frame(),
+        ))
+    }
+}
+
+fn window_size(method: InterpolationMethod, degree: usize) -> usize {
+    match method {
+        InterpolationMethod::Lagrange => degree + 1,
+        InterpolationMethod::Hermite => (degree + 1).div_ceil(2),
+    }
+}
+
+/// Returns the coefficients of the Newton form of the polynomial through `values` at `nodes`.
+fn lagrange_coefficients(nodes: &[f64], mut values: Vec<DVec3>) -> Vec<DVec3> {
+    for j in 1..nodes.len() {
+        for i in (j..nodes.len()).rev() {
+            values[i] = (values[i] - values[i - 1]) / (nodes[i] - nodes[i - j]);
+        }
+    }
+    values
+}
+
+/// Returns the coefficients of the Newton form of the Hermite polynomial through the positions and
+/// velocities of `states` at the doubled `nodes`.
+fn hermite_coefficients<T, O, R>(nodes: &[f64], states: &[State<T, O, R>]) -> Vec<DVec3>
+where
+    T: TimeScale,
+    O: Origin,
+    R: ReferenceFrame,
+{
+    let mut values: Vec<DVec3> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, _)| states[i / 2].position())
+        .collect();
+    for j in 1..nodes.len() {
+        for i in (j..nodes.len()).rev() {
+            values[i] = if j == 1 && i % 2 == 1 {
+                // The first divided difference at a repeated node is the derivative
+                states[i / 2].velocity()
+            } else {
+                (values[i] - values[i - 1]) / (nodes[i] - nodes[i - j])
+            };
+        }
+    }
+    values
+}
+
+/// Evaluates the Newton polynomial with the given `nodes` and `coefficients` and its derivative
+/// at `t`.
+fn evaluate_newton(nodes: &[f64], coefficients: &[DVec3], t: f64) -> (DVec3, DVec3) {
+    let mut value = *coefficients.last().unwrap();
+    let mut derivative = DVec3::ZERO;
+    for k in (0..coefficients.len() - 1).rev() {
+        derivative = derivative * (t - nodes[k]) + value;
+        value = value * (t - nodes[k]) + coefficients[k];
+    }
+    (value, derivative)
+}
+
+#[cfg(test)]
+mod tests {
+    use lox_bodies::{Earth, PointMass};
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+    use lox_time::deltas::TimeDelta;
+    use lox_time::time;
+    use lox_time::time_scales::Tdb;
+    use rstest::rstest;
+
+    use crate::frames::Icrf;
+    use crate::propagators::semi_analytical::TwoBody;
+
+    use super::*;
+
+    fn initial_state() -> State<Tdb, Earth, Icrf> {
+        State::new(
+            time!(Tdb, 2023, 3, 25, 21, 8, 0.0).unwrap(),
+            DVec3::new(6068.27927, -1692.84394, -2516.61918),
+            DVec3::new(-0.660415582, 5.495938726, -5.303093233),
+            Earth,
+            Icrf,
+        )
+    }
+
+    fn propagate(dt: f64) -> State<Tdb, Earth, Icrf> {
+        TwoBody::propagate(
+            &initial_state(),
+            TimeDelta::from_decimal_seconds(dt),
+            Earth.gravitational_parameter(),
+        )
+        .unwrap()
+    }
+
+    /// Samples a two-body arc every 60 seconds over half an hour.
+    fn two_body_arc(
+        method: InterpolationMethod,
+        degree: usize,
+    ) -> EphemerisTable<Tdb, Earth, Icrf> {
+        let states = (0..=30).map(|i| propagate(60.0 * i as f64)).collect();
+        EphemerisTable::new(states, method, degree).unwrap()
+    }
+
+    #[rstest]
+    #[case(InterpolationMethod::Hermite, 7, 1e-8, 1e-11)]
+    #[case(InterpolationMethod::Lagrange, 7, 1e-6, 1e-8)]
+    fn test_ephemeris_table_two_body(
+        #[case] method: InterpolationMethod,
+        #[case] degree: usize,
+        #[case] position_tol: f64,
+        #[case] velocity_tol: f64,
+    ) {
+        let table = two_body_arc(method, degree);
+        for dt in [5.0, 451.5, 929.0, 1795.0] {
+            let expected = propagate(dt);
+            let actual = table.interpolate(expected.time()).unwrap();
+            assert_eq!(actual.time(), expected.time());
+            assert_close!(actual.position(), expected.position(), position_tol);
+            assert_close!(actual.velocity(), expected.velocity(), velocity_tol);
+        }
+    }
+
+    #[test]
+    fn test_ephemeris_table_nodes() {
+        let table = two_body_arc(InterpolationMethod::Hermite, 5);
+        for state in [&table.states()[0], &table.states()[13], &table.states()[30]] {
+            let actual = table.interpolate(state.time()).unwrap();
+            assert_close!(actual.position(), state.position(), 1e-9);
+            assert_close!(actual.velocity(), state.velocity(), 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_ephemeris_table_out_of_range() {
+        let table = two_body_arc(InterpolationMethod::Hermite, 7);
+        let before = table.start_time() - TimeDelta::from_decimal_seconds(1.0);
+        let after = table.end_time() + TimeDelta::from_decimal_seconds(1.0);
+        for epoch in [before, after] {
+            assert_eq!(
+                table.interpolate(epoch),
+                Err(EphemerisTableError::OutOfRange {
+                    epoch: epoch.to_string(),
+                    start: table.start_time().to_string(),
+                    end: table.end_time().to_string(),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_ephemeris_table_errors() {
+        let states: Vec<_> = (0..4).map(|i| propagate(60.0 * i as f64)).collect();
+        assert_eq!(
+            EphemerisTable::new(states.clone(), InterpolationMethod::Lagrange, 7).unwrap_err(),
+            EphemerisTableError::InsufficientStates {
+                degree: 7,
+                required: 8,
+                actual: 4,
+            }
+        );
+        assert!(EphemerisTable::new(states.clone(), InterpolationMethod::Hermite, 7).is_ok());
+        let reversed = states.into_iter().rev().collect();
+        assert_eq!(
+            EphemerisTable::new(reversed, InterpolationMethod::Hermite, 7).unwrap_err(),
+            EphemerisTableError::UnorderedEpochs
+        );
+    }
+}
@@ -0,0 +1,707 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module estimation provides [BatchLeastSquares], which determines the orbit of a spacecraft
+//! from range, azimuth-elevation, and position observations.
+
+use std::f64::consts::PI;
+
+use glam::{DMat3, DVec3};
+use lox_bodies::{Origin, ReferenceEllipsoid};
+use lox_earth::geodetic::geodetic_to_cartesian;
+use lox_math::types::units::Radians;
+use lox_time::Time;
+use lox_time::time_scales::TimeScale;
+use thiserror::Error;
+
+use crate::covariance::{Covariance, Matrix6, mul};
+use crate::frames::Icrf;
+use crate::ground::rotation_to_topocentric;
+use crate::propagators::numerical::{Cowell, CowellError};
+use crate::rotations::Rotation;
+use crate::states::State;
+
+/// The default maximum number of Gauss-Newton iterations.
+const MAX_ITERATIONS: usize = 20;
+
+/// The default relative change of the weighted RMS of the residuals below which the estimate is
+/// considered converged.
+const CONVERGENCE_TOLERANCE: f64 = 1e-6;
+
+/// The factor by which the weighted RMS of the residuals may exceed the smallest RMS of the
+/// previous iterations before the estimate is considered diverged.
+const DIVERGENCE_FACTOR: f64 = 10.0;
+
+#[derive(Debug, Clone, Error, PartialEq)]
+pub enum BatchLeastSquaresError {
+    #[error("{actual} measurement components are insufficient to estimate the 6 state components")]
+    InsufficientObservations { actual: usize },
+    #[error("the normal matrix is singular, the state is unobservable from the observations")]
+    SingularNormalMatrix,
+    #[error("diverged in iteration {iteration}, the weighted RMS increased to {rms} from {best}")]
+    Diverged {
+        iteration: usize,
+        rms: f64,
+        best: f64,
+    },
+    #[error("the estimate did not converge within {iterations} iterations")]
+    MaxIterationsExceeded { iterations: usize, rms: f64 },
+    #[error(transparent)]
+    Propagation(#[from] CowellError),
+}
+
+/// The location of a ground station at the epoch of an observation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Station {
+    position: DVec3,
+    rotation: DMat3,
+}
+
+impl Station {
+    /// Creates a station at the ICRF `position` in km, where `rotation` rotates ICRF vectors into
+    /// the topocentric south-east-zenith frame of the station.
+    pub fn new(position: DVec3, rotation: DMat3) -> Self {
+        Self { position, rotation }
+    }
+
+    /// Creates a station at the given geodetic latitude, longitude, and altitude above the WGS-84
+    /// ellipsoid, where `icrf_to_itrf` is the rotation from the ICRF to the ITRF at the epoch of
+    /// the observation.
+    pub fn from_geodetic(
+        latitude: Radians,
+        longitude: Radians,
+        altitude: f64,
+        icrf_to_itrf: &Rotation,
+    ) -> Self {
+        let m = icrf_to_itrf.position_matrix();
+        let position =
+            geodetic_to_cartesian(latitude, longitude, altitude, &ReferenceEllipsoid::WGS84);
+        Self {
+            position: m.transpose() * position,
+            rotation: rotation_to_topocentric(longitude, latitude) * m,
+        }
+    }
+
+    /// Returns the ICRF position of the station in km.
+    pub fn position(&self) -> DVec3 {
+        self.position
+    }
+
+    /// Returns the rotation from the ICRF to the topocentric south-east-zenith frame.
+    pub fn rotation(&self) -> DMat3 {
+        self.rotation
+    }
+}
+
+/// A measurement of the position of a spacecraft.
+///
+/// Light time and the motion of the station during the propagation of the signal are neglected.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Measurement {
+    /// The distance between the station and the spacecraft in km.
+    Range { station: Station, range: f64 },
+    /// The azimuth, measured clockwise from north, and the elevation of the spacecraft in
+    /// radians.
+    AzimuthElevation {
+        station: Station,
+        azimuth: Radians,
+        elevation: Radians,
+    },
+    /// The ICRF position of the spacecraft in km, e.g. from a GNSS receiver.
+    Position(DVec3),
+}
+
+impl Measurement {
+    /// Returns the number of scalar components of the measurement.
+    pub fn dimension(&self) -> usize {
+        match self {
+            Measurement::Range { .. } => 1,
+            Measurement::AzimuthElevation { .. } => 2,
+            Measurement::Position(_) => 3,
+        }
+    }
+
+    /// Returns the residuals, i.e. the measured minus the computed values, for the spacecraft at
+    /// position `r` and their partial derivatives with respect to `r`.
+    fn residuals(&self, r: DVec3) -> Vec<(f64, DVec3)> {
+        match *self {
+            Measurement::Range { station, range } => {
+                let rho = r - station.position;
+                vec![(range - rho.length(), rho.normalize())]
+            }
+            Measurement::AzimuthElevation {
+                station,
+                azimuth,
+                elevation,
+            } => {
+                let m = station.rotation;
+                let p = m * (r - station.position);
+                let horizontal = p.x.hypot(p.y);
+                let range = p.length();
+                let computed_azimuth = p.y.atan2(-p.x);
+                let computed_elevation = (p.z / range).asin();
+                let d_azimuth = DVec3::new(p.y, -p.x, 0.0) / horizontal.powi(2);
+                let d_elevation =
+                    (range.powi(2) * DVec3::Z - p.z * p) / (range.powi(2) * horizontal);
+                vec![
+                    (
+                        wrap_angle(azimuth - computed_azimuth),
+                        m.transpose() * d_azimuth,
+                    ),
+                    (elevation - computed_elevation, m.transpose() * d_elevation),
+                ]
+            }
+            Measurement::Position(position) => (0..3)
+                .map(|i| (position[i] - r[i], DVec3::AXES[i]))
+                .collect(),
+        }
+    }
+}
+
+/// Wraps `angle` into the range [-π, π).
+fn wrap_angle(angle: f64) -> f64 {
+    (angle + PI).rem_euclid(2.0 * PI) - PI
+}
+
+/// An observation of a spacecraft, i.e. a [Measurement] at an epoch with the standard deviation
+/// of the noise of its components.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Observation<T: TimeScale> {
+    time: Time<T>,
+    measurement: Measurement,
+    sigma: f64,
+}
+
+impl<T: TimeScale> Observation<T> {
+    /// Creates an observation with the standard deviation `sigma` in km for range and position
+    /// measurements and in radians for azimuth-elevation measurements.
+    pub fn new(time: Time<T>, measurement: Measurement, sigma: f64) -> Self {
+        Self {
+            time,
+            measurement,
+            sigma,
+        }
+    }
+
+    pub fn time(&self) -> Time<T>
+    where
+        T: Clone,
+    {
+        self.time.clone()
+    }
+
+    pub fn measurement(&self) -> &Measurement {
+        &self.measurement
+    }
+
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+}
+
+/// The converged result of [BatchLeastSquares::estimate].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrbitEstimate<T: TimeScale, O: Origin> {
+    state: State<T, O, Icrf>,
+    covariance: Covariance,
+    iterations: usize,
+    rms: f64,
+}
+
+impl<T, O> OrbitEstimate<T, O>
+where
+    T: TimeScale + Clone,
+    O: Origin + Clone,
+{
+    /// Returns the estimated state at the epoch of the initial guess.
+    pub fn state(&self) -> State<T, O, Icrf> {
+        self.state.clone()
+    }
+
+    /// Returns the formal covariance of the estimated state.
+    pub fn covariance(&self) -> Covariance {
+        self.covariance
+    }
+
+    /// Returns the number of Gauss-Newton iterations.
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Returns the RMS of the residuals weighted with the standard deviations of the
+    /// observations, which is close to one if the noise is consistent with them.
+    pub fn rms(&self) -> f64 {
+        self.rms
+    }
+}
+
+/// Batch least-squares orbit determination, which iteratively corrects an initial guess of the
+/// state with the Gauss-Newton method.
+///
+/// The observations are modelled with the force models of the [Cowell] propagator, whose initial
+/// state serves as the initial guess, and the partial derivatives of the observations with
+/// respect to the initial state are obtained from its state transition matrix.
+pub struct BatchLeastSquares<T: TimeScale, O: Origin> {
+    propagator: Cowell<T, O>,
+    max_iterations: usize,
+    tolerance: f64,
+}
+
+impl<T, O> BatchLeastSquares<T, O>
+where
+    T: TimeScale + Clone,
+    O: Origin + Clone,
+{
+    pub fn new(propagator: Cowell<T, O>) -> Self {
+        Self {
+            propagator,
+            max_iterations: MAX_ITERATIONS,
+            tolerance: CONVERGENCE_TOLERANCE,
+        }
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Sets the relative change of the weighted RMS of the residuals between two iterations below
+    /// which the estimate is considered converged.
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Estimates the state at the epoch of the initial guess from `observations`.
+    ///
+    /// # Errors
+    ///
+    /// - [BatchLeastSquaresError::InsufficientObservations] if the observations have fewer than
+    ///   six components.
+    /// - [BatchLeastSquaresError::SingularNormalMatrix] if the state can't be determined from the
+    ///   observations.
+    /// - [BatchLeastSquaresError::Diverged] if the residuals grow instead of decreasing.
+    /// - [BatchLeastSquaresError::MaxIterationsExceeded] if the estimate does not converge within
+    ///   the maximum number of iterations.
+    /// - [BatchLeastSquaresError::Propagation] if the propagation fails.
+    pub fn estimate(
+        &self,
+        observations: &[Observation<T>],
+    ) -> Result<OrbitEstimate<T, O>, BatchLeastSquaresError> {
+        let dimension: usize = observations
+            .iter()
+            .map(|observation| observation.measurement.dimension())
+            .sum();
+        if dimension < 6 {
+            return Err(BatchLeastSquaresError::InsufficientObservations { actual: dimension });
+        }
+        let mut observations: Vec<&Observation<T>> = observations.iter().collect();
+        observations.sort_by(|a, b| {
+            (a.time.clone() - b.time.clone())
+                .to_decimal_seconds()
+                .total_cmp(&0.0)
+        });
+
+        let mut state = self.propagator.initial_state().clone();
+        let mut previous: Option<f64> = None;
+        let mut best = f64::INFINITY;
+        for iteration in 1..=self.max_iterations {
+            let (normal, rhs, rms) = self.accumulate(&state, &observations)?;
+            if !rms.is_finite() || rms > DIVERGENCE_FACTOR * best {
+                return Err(BatchLeastSquaresError::Diverged {
+                    iteration,
+                    rms,
+                    best,
+                });
+            }
+            best = best.min(rms);
+            let covariance =
+                invert_symmetric(&normal).ok_or(BatchLeastSquaresError::SingularNormalMatrix)?;
+            if let Some(previous) = previous
+                && (previous - rms).abs() <= self.tolerance * rms
+            {
+                return Ok(OrbitEstimate {
+                    state,
+                    covariance: Covariance::new(covariance),
+                    iterations: iteration,
+                    rms,
+                });
+            }
+            previous = Some(rms);
+            let dx: [f64; 6] =
+                std::array::from_fn(|i| (0..6).map(|j| covariance[i][j] * rhs[j]).sum());
+            state = State::new(
+                state.time(),
+                state.position() + DVec3::new(dx[0], dx[1], dx[2]),
+                state.velocity() + DVec3::new(dx[3], dx[4], dx[5]),
+                state.origin(),
+                Icrf,
+            );
+        }
+        let (_, _, rms) = self.accumulate(&state, &observations)?;
+        Err(BatchLeastSquaresError::MaxIterationsExceeded {
+            iterations: self.max_iterations,
+            rms,
+        })
+    }
+
+    /// Propagates `state` through the epochs of the sorted `observations` and returns the normal
+    /// matrix, the right-hand side of the normal equations, and the weighted RMS of the residuals.
+    fn accumulate(
+        &self,
+        state: &State<T, O, Icrf>,
+        observations: &[&Observation<T>],
+    ) -> Result<(Matrix6, [f64; 6], f64), BatchLeastSquaresError> {
+        let mut normal = [[0.0; 6]; 6];
+        let mut rhs = [0.0; 6];
+        let mut sum = 0.0;
+        let mut count = 0;
+        let mut current = state.clone();
+        let mut stm: Matrix6 =
+            std::array::from_fn(|i| std::array::from_fn(|j| if i == j { 1.0 } else { 0.0 }));
+        for observation in observations {
+            let (next, step) = self
+                .propagator
+                .integrate_with_stm(&current, observation.time.clone())?;
+            stm = mul(&step, &stm);
+            current = next;
+            let weight = observation.sigma.powi(-2);
+            for (residual, partials) in observation.measurement.residuals(current.position()) {
+                // The measurements depend on the position only, such that the partials with
+                // respect to the initial state are the first three rows of the STM
+                let h: [f64; 6] =
+                    std::array::from_fn(|j| (0..3).map(|k| partials[k] * stm[k][j]).sum());
+                for i in 0..6 {
+                    rhs[i] += weight * h[i] * residual;
+                    for j in 0..6 {
+                        normal[i][j] += weight * h[i] * h[j];
+                    }
+                }
+                sum += weight * residual.powi(2);
+                count += 1;
+            }
+        }
+        Ok((normal, rhs, (sum / count as f64).sqrt()))
+    }
+}
+
+/// Inverts the symmetric positive-definite `matrix` via its Cholesky decomposition.
+///
+/// The matrix is equilibrated by its diagonal beforehand, since the position and velocity
+/// components of the normal matrix differ by many orders of magnitude.
+fn invert_symmetric(matrix: &Matrix6) -> Option<Matrix6> {
+    let scale: [f64; 6] = std::array::from_fn(|i| matrix[i][i].sqrt());
+    if scale.iter().any(|s| !s.is_normal()) {
+        return None;
+    }
+    let a: Matrix6 =
+        std::array::from_fn(|i| std::array::from_fn(|j| matrix[i][j] / (scale[i] * scale[j])));
+    let mut l = [[0.0; 6]; 6];
+    for i in 0..6 {
+        for j in 0..=i {
+            let sum = a[i][j] - (0..j).map(|k| l[i][k] * l[j][k]).sum::<f64>();
+            if i == j {
+                if sum <= f64::EPSILON * 6.0 {
+                    return None;
+                }
+                l[i][i] = sum.sqrt();
+            } else {
+                l[i][j] = sum / l[j][j];
+            }
+        }
+    }
+    // Invert the lower triangular factor by forward substitution and form L⁻ᵀ L⁻¹
+    let mut l_inv = [[0.0; 6]; 6];
+    for j in 0..6 {
+        l_inv[j][j] = 1.0 / l[j][j];
+        for i in j + 1..6 {
+            let sum: f64 = (j..i).map(|k| l[i][k] * l_inv[k][j]).sum();
+            l_inv[i][j] = -sum / l[i][i];
+        }
+    }
+    Some(std::array::from_fn(|i| {
+        std::array::from_fn(|j| {
+            (i.max(j)..6)
+                .map(|k| l_inv[k][i] * l_inv[k][j])
+                .sum::<f64>()
+                / (scale[i] * scale[j])
+        })
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use lox_bodies::{Earth, Spheroid};
+    use lox_earth::rotation_angle::RotationAngle;
+    use lox_math::types::units::Angle;
+    use lox_time::deltas::TimeDelta;
+    use lox_time::julian_dates::JulianDate;
+    use lox_time::time;
+    use lox_time::time_scales::Tdb;
+
+    use super::*;
+    use crate::elements::Keplerian;
+    use crate::propagators::Propagator;
+
+    /// A deterministic source of normally distributed noise based on a linear congruential
+    /// generator and the Box-Muller transform.
+    struct Noise(u64);
+
+    impl Noise {
+        fn uniform(&mut self) -> f64 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((self.0 >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+        }
+
+        fn normal(&mut self, sigma: f64) -> f64 {
+            let (u1, u2) = (self.uniform(), self.uniform());
+            sigma * (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+        }
+    }
+
+    fn truth() -> State<Tdb, Earth, Icrf> {
+        Keplerian::new(
+            time!(Tdb, 2025, 1, 1).unwrap(),
+            Earth,
+            Earth.equatorial_radius() + 800.0,
+            0.01,
+            Angle::from_degrees(60.0),
+            Angle::from_radians(0.5),
+            Angle::from_radians(1.0),
+            Angle::ZERO,
+        )
+        .to_cartesian()
+    }
+
+    fn initial_guess(error: f64) -> State<Tdb, Earth, Icrf> {
+        let s = truth();
+        State::new(
+            s.time(),
+            s.position() + error * DVec3::new(1.0, -2.0, 0.5),
+            s.velocity() + error * 1e-3 * DVec3::new(-0.5, 1.0, 2.0),
+            Earth,
+            Icrf,
+        )
+    }
+
+    /// The station at the geodetic `latitude` and `longitude` in degrees, where the Earth is
+    /// assumed to rotate uniformly about the z-axis of the ICRF.
+    fn station(latitude: f64, longitude: f64, time: Time<Tdb>) -> Station {
+        let days = time.days_since_j2000();
+        let rotation = Rotation::new(DMat3::from_rotation_z(-Earth::rotation_angle_00(days)));
+        Station::from_geodetic(
+            latitude.to_radians(),
+            longitude.to_radians(),
+            0.3,
+            &rotation,
+        )
+    }
+
+    /// Simulates range and azimuth-elevation observations of the true orbit from three stations
+    /// every minute whenever the spacecraft is above the horizon.
+    fn simulate(sigma_range: f64, sigma_angles: f64, noise: &mut Noise) -> Vec<Observation<Tdb>> {
+        let propagator = Cowell::two_body(truth());
+        let mut observations = vec![];
+        for minute in 0..(6 * 60) {
+            let time = truth().time() + TimeDelta::from_decimal_seconds(60.0 * minute as f64);
+            let r = propagator.propagate(time).unwrap().position();
+            for (latitude, longitude) in [(52.0, 13.0), (-33.0, 151.0), (40.0, -105.0)] {
+                let station = station(latitude, longitude, time);
+                let p = station.rotation() * (r - station.position());
+                let elevation = (p.z / p.length()).asin();
+                if elevation < 10f64.to_radians() {
+                    continue;
+                }
+                let range = p.length();
+                let azimuth = p.y.atan2(-p.x);
+                observations.push(Observation::new(
+                    time,
+                    Measurement::Range {
+                        station,
+                        range: range + noise.normal(sigma_range),
+                    },
+                    sigma_range,
+                ));
+                observations.push(Observation::new(
+                    time,
+                    Measurement::AzimuthElevation {
+                        station,
+                        azimuth: azimuth + noise.normal(sigma_angles),
+                        elevation: elevation + noise.normal(sigma_angles),
+                    },
+                    sigma_angles,
+                ));
+            }
+        }
+        observations
+    }
+
+    #[test]
+    fn test_measurement_partials() {
+        let time = truth().time();
+        let station = station(52.0, 13.0, time);
+        let r = DVec3::new(2000.0, 3000.0, 6000.0);
+        for measurement in [
+            Measurement::Range {
+                station,
+                range: 0.0,
+            },
+            Measurement::AzimuthElevation {
+                station,
+                azimuth: 0.0,
+                elevation: 0.0,
+            },
+            Measurement::Position(DVec3::ZERO),
+        ] {
+            let residuals = measurement.residuals(r);
+            assert_eq!(residuals.len(), measurement.dimension());
+            for (k, (_, partials)) in residuals.iter().enumerate() {
+                let h = 1e-3;
+                for i in 0..3 {
+                    let e = h * DVec3::AXES[i];
+                    // The residuals are measured minus computed values
+                    let expected = -(measurement.residuals(r + e)[k].0
+                        - measurement.residuals(r - e)[k].0)
+                        / (2.0 * h);
+                    assert_float_eq!(partials[i], expected, abs <= 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_wrap_angle() {
+        assert_float_eq!(wrap_angle(0.1), 0.1, abs <= 1e-15);
+        assert_float_eq!(wrap_angle(2.0 * PI - 0.1), -0.1, abs <= 1e-15);
+        assert_float_eq!(wrap_angle(-2.0 * PI + 0.1), 0.1, abs <= 1e-15);
+    }
+
+    #[test]
+    fn test_invert_symmetric() {
+        let matrix: Matrix6 = std::array::from_fn(|i| {
+            std::array::from_fn(|j| {
+                let scale = 10f64.powi(i as i32 + j as i32);
+                scale
+                    * if i == j {
+                        4.0
+                    } else {
+                        1.0 / (1.0 + (i + j) as f64)
+                    }
+            })
+        });
+        let inverse = invert_symmetric(&matrix).unwrap();
+        let identity = mul(&matrix, &inverse);
+        for (i, row) in identity.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                assert_float_eq!(*value, if i == j { 1.0 } else { 0.0 }, abs <= 1e-12);
+            }
+        }
+        let mut singular = matrix;
+        singular[5] = singular[4];
+        singular[5][5] = singular[4][4];
+        for row in singular.iter_mut() {
+            row[5] = row[4];
+        }
+        assert_eq!(invert_symmetric(&singular), None);
+    }
+
+    #[test]
+    fn test_batch_least_squares_stations() {
+        // Range noise of 10 m and angle noise of 0.01°
+        let (sigma_range, sigma_angles) = (0.01, 0.01f64.to_radians());
+        let observations = simulate(sigma_range, sigma_angles, &mut Noise(42));
+        assert!(observations.len() > 50);
+
+        let estimator = BatchLeastSquares::new(Cowell::two_body(initial_guess(10.0)));
+        let estimate = estimator.estimate(&observations).unwrap();
+        assert!(estimate.iterations() > 2);
+        assert!(estimate.rms() > 0.8 && estimate.rms() < 1.2);
+
+        // The errors of the estimate are consistent with its formal covariance
+        let covariance = estimate.covariance().matrix();
+        let error = estimate.state().position() - truth().position();
+        for i in 0..3 {
+            assert!(error[i].abs() < 4.0 * covariance[i][i].sqrt());
+        }
+        let error = estimate.state().velocity() - truth().velocity();
+        for i in 0..3 {
+            assert!(error[i].abs() < 4.0 * covariance[i + 3][i + 3].sqrt());
+        }
+        // The position is recovered well within the range noise
+        assert!((estimate.state().position() - truth().position()).length() < sigma_range);
+    }
+
+    #[test]
+    fn test_batch_least_squares_positions() {
+        let sigma = 0.005;
+        let mut noise = Noise(7);
+        let propagator = Cowell::two_body(truth());
+        let observations: Vec<Observation<Tdb>> = (0..30)
+            .map(|i| {
+                let time = truth().time() + TimeDelta::from_decimal_seconds(120.0 * i as f64);
+                let r = propagator.propagate(time).unwrap().position();
+                let noise = DVec3::new(
+                    noise.normal(sigma),
+                    noise.normal(sigma),
+                    noise.normal(sigma),
+                );
+                Observation::new(time, Measurement::Position(r + noise), sigma)
+            })
+            .collect();
+        let estimate = BatchLeastSquares::new(Cowell::two_body(initial_guess(50.0)))
+            .estimate(&observations)
+            .unwrap();
+        assert!(estimate.rms() > 0.8 && estimate.rms() < 1.2);
+        let error = (estimate.state().position() - truth().position()).length();
+        assert!(error < sigma, "position error {error} km");
+        let error = (estimate.state().velocity() - truth().velocity()).length();
+        assert!(error < 1e-5, "velocity error {error} km/s");
+    }
+
+    #[test]
+    fn test_batch_least_squares_errors() {
+        let observations = simulate(0.01, 0.01f64.to_radians(), &mut Noise(1));
+
+        assert_eq!(
+            BatchLeastSquares::new(Cowell::two_body(truth()))
+                .estimate(&observations[..2])
+                .unwrap_err(),
+            BatchLeastSquaresError::InsufficientObservations { actual: 3 }
+        );
+
+        // Repeated range measurements at a single epoch only constrain one direction
+        let range = observations
+            .iter()
+            .find(|o| matches!(o.measurement(), Measurement::Range { .. }))
+            .unwrap();
+        assert_eq!(
+            BatchLeastSquares::new(Cowell::two_body(truth()))
+                .estimate(&vec![range.clone(); 6])
+                .unwrap_err(),
+            BatchLeastSquaresError::SingularNormalMatrix
+        );
+
+        assert!(matches!(
+            BatchLeastSquares::new(Cowell::two_body(initial_guess(10.0)))
+                .with_max_iterations(1)
+                .estimate(&observations),
+            Err(BatchLeastSquaresError::MaxIterationsExceeded { iterations: 1, .. })
+        ));
+
+        // A guess far from the true orbit makes the linearisation invalid
+        let guess = initial_guess(2000.0);
+        assert!(matches!(
+            BatchLeastSquares::new(Cowell::two_body(guess)).estimate(&observations),
+            Err(BatchLeastSquaresError::Diverged { .. })
+        ));
+    }
+}
@@ -4,6 +4,7 @@ use lox_time::time_scales::TimeScale;
 use std::collections::VecDeque;
 use std::fmt::Display;
 use std::iter::zip;
+use std::str::FromStr;
 use thiserror::Error;
 
 use lox_math::roots::FindBracketedRoot;
@@ -36,6 +37,45 @@ impl Display for ZeroCrossing {
     }
 }
 
+/// The direction of the zero crossings to be detected by [find_events_in_direction].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CrossingDirection {
+    /// Only detect crossings from negative to positive values.
+    Up,
+    /// Only detect crossings from positive to negative values.
+    Down,
+    /// Detect crossings in both directions.
+    #[default]
+    Both,
+}
+
+impl CrossingDirection {
+    pub fn matches(&self, crossing: ZeroCrossing) -> bool {
+        match self {
+            CrossingDirection::Up => crossing == ZeroCrossing::Up,
+            CrossingDirection::Down => crossing == ZeroCrossing::Down,
+            CrossingDirection::Both => true,
+        }
+    }
+}
+
+impl FromStr for CrossingDirection {
+    type Err = UnknownCrossingDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "up" | "Up" | "UP" => Ok(CrossingDirection::Up),
+            "down" | "Down" | "DOWN" => Ok(CrossingDirection::Down),
+            "both" | "Both" | "BOTH" => Ok(CrossingDirection::Both),
+            _ => Err(UnknownCrossingDirectionError(s.to_owned())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+#[error("unknown crossing direction `{0}`, expected one of `up`, `down`, or `both`")]
+pub struct UnknownCrossingDirectionError(String);
+
 #[derive(Debug, Error, Clone, Eq, PartialEq)]
 pub enum FindEventError {
     #[error("function is always negative")]
@@ -69,6 +109,28 @@ pub fn find_events<F: Fn(f64) -> f64 + Copy, T: TimeScale + Clone, R: FindBracke
     steps: &[f64],
     root_finder: R,
 ) -> Result<Vec<Event<T>>, FindEventError> {
+    find_events_in_direction(func, start, steps, root_finder, CrossingDirection::Both)
+}
+
+/// Finds the zero crossings of `func` in the given `direction` by bracketing sign changes between
+/// the `steps`, in seconds since `start`, and refining them with `root_finder`.
+///
+/// # Errors
+///
+/// - [FindEventError::AlwaysNegative] if `func` is negative at all steps.
+/// - [FindEventError::AlwaysPositive] if `func` is positive at all steps.
+pub fn find_events_in_direction<F, T, R>(
+    func: F,
+    start: Time<T>,
+    steps: &[f64],
+    root_finder: R,
+    direction: CrossingDirection,
+) -> Result<Vec<Event<T>>, FindEventError>
+where
+    F: Fn(f64) -> f64 + Copy,
+    T: TimeScale + Clone,
+    R: FindBracketedRoot<F>,
+{
     // Determine the sign of `func` at each time step
     let signs: Vec<f64> = steps.iter().map(|&t| func(t).signum()).collect();
 
@@ -86,16 +148,17 @@ pub fn find_events<F: Fn(f64) -> f64 + Copy, T: TimeScale + Clone, R: FindBracke
 
     // Loop over all time step pairs and determine if the sign of the function changes inbetween
     for ((&t0, s0), (&t1, s1)) in zip(steps, signs).tuple_windows() {
-        if let Some(crossing) = ZeroCrossing::new(s0, s1) {
-            // If the sign changes, run the root finder to determine the exact point in time when
-            // the event occurred
-            let t = root_finder
-                .find_in_bracket(func, (t0, t1))
-                .expect("sign changed but root finder failed");
-            let time = start.clone() + TimeDelta::try_from_decimal_seconds(t).unwrap();
-
-            events.push(Event { crossing, time });
-        }
+        let Some(crossing) = ZeroCrossing::new(s0, s1).filter(|&c| direction.matches(c)) else {
+            continue;
+        };
+        // If the sign changes, run the root finder to determine the exact point in time when
+        // the event occurred
+        let t = root_finder
+            .find_in_bracket(func, (t0, t1))
+            .expect("sign changed but root finder failed");
+        let time = start.clone() + TimeDelta::try_from_decimal_seconds(t).unwrap();
+
+        events.push(Event { crossing, time });
     }
 
     Ok(events)
@@ -239,11 +302,20 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::elements::Keplerian;
+    use crate::frames::Icrf;
+    use crate::propagators::Propagator;
+    use crate::propagators::semi_analytical::Vallado;
+    use crate::states::State;
+    use float_eq::assert_float_eq;
+    use lox_bodies::Earth;
     use lox_math::assert_close;
     use lox_math::is_close::IsClose;
     use lox_math::roots::Brent;
+    use lox_math::types::units::Angle;
     use lox_time::time_scales::Tai;
     use lox_time::{Time, time};
+    use rstest::rstest;
     use std::f64::consts::{PI, TAU};
 
     #[test]
@@ -271,6 +343,79 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::up(CrossingDirection::Up, vec![(ZeroCrossing::Up, TAU)])]
+    #[case::down(CrossingDirection::Down, vec![(ZeroCrossing::Down, PI)])]
+    #[case::both(CrossingDirection::Both, vec![(ZeroCrossing::Down, PI), (ZeroCrossing::Up, TAU)])]
+    fn test_events_in_direction(
+        #[case] direction: CrossingDirection,
+        #[case] expected: Vec<(ZeroCrossing, f64)>,
+    ) {
+        let func = |t: f64| t.sin();
+        let start = time!(Tai, 2000, 1, 1, 12).unwrap();
+        let steps = vec![0.5, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+
+        let events =
+            find_events_in_direction(func, start, &steps, Brent::default(), direction).unwrap();
+
+        assert_eq!(events.len(), expected.len());
+        for (event, (crossing, t)) in zip(events, expected) {
+            assert_eq!(event.crossing, crossing);
+            assert_close!(
+                event.time,
+                start + TimeDelta::try_from_decimal_seconds(t).unwrap(),
+                1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn test_trajectory_events_in_direction() {
+        let time = time!(Tai, 2024, 1, 1).unwrap();
+        let orbit = Keplerian::new(
+            time,
+            Earth,
+            24464.56,
+            0.7311,
+            Angle::from_radians(0.122138),
+            Angle::from_radians(1.00681),
+            Angle::from_radians(3.10686),
+            Angle::ZERO,
+        );
+        let period = orbit.orbital_period().to_decimal_seconds();
+        let propagator = Vallado::new(orbit.to_cartesian());
+        let times = (0..=100)
+            .map(|i| time + TimeDelta::from_decimal_seconds(i as f64 * period / 100.0 + 1.0));
+        let trajectory = propagator.propagate_all(times).unwrap();
+        let range_rate = |s: State<Tai, Earth, Icrf>| s.position().dot(s.velocity());
+
+        let apoapsis = trajectory.find_events_in_direction(range_rate, CrossingDirection::Down);
+        assert_eq!(apoapsis.len(), 1);
+        let true_anomaly = trajectory
+            .interpolate_at(apoapsis[0].time())
+            .to_keplerian()
+            .true_anomaly()
+            .to_radians();
+        assert_float_eq!(true_anomaly.abs(), PI, rel <= 1e-6);
+
+        let periapsis = trajectory.find_events_in_direction(range_rate, CrossingDirection::Up);
+        assert_eq!(periapsis.len(), 1);
+        assert_eq!(periapsis[0].crossing(), ZeroCrossing::Up);
+        assert_eq!(trajectory.find_events(range_rate).len(), 2);
+    }
+
+    #[rstest]
+    #[case("up", Ok(CrossingDirection::Up))]
+    #[case("Down", Ok(CrossingDirection::Down))]
+    #[case("both", Ok(CrossingDirection::Both))]
+    #[case("sideways", Err(UnknownCrossingDirectionError("sideways".to_string())))]
+    fn test_crossing_direction_from_str(
+        #[case] s: &str,
+        #[case] expected: Result<CrossingDirection, UnknownCrossingDirectionError>,
+    ) {
+        assert_eq!(s.parse(), expected);
+    }
+
     #[test]
     fn test_windows() {
         let func = |t: f64| t.sin();
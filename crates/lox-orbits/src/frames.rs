@@ -20,11 +20,14 @@ use thiserror::Error;
 use crate::{
     frames::iau::{IauFrameTransformationError, icrf_to_iau},
     frames::iers::{cirf_to_tirf, icrf_to_cirf, tirf_to_itrf},
+    frames::teme::teme_to_tirf,
     rotations::Rotation,
 };
 
 pub mod iau;
 pub mod iers;
+pub mod teme;
+pub mod transform;
 
 pub trait ReferenceFrame {
     fn name(&self) -> String;
@@ -136,6 +139,26 @@ impl ReferenceFrame for Itrf {
 
 impl BodyFixed for Itrf {}
 
+/// The True Equator, Mean Equinox frame used by the SGP4 propagator
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Ord, PartialOrd)]
+pub struct Teme;
+
+impl ReferenceFrame for Teme {
+    fn name(&self) -> String {
+        "True Equator, Mean Equinox".to_string()
+    }
+
+    fn abbreviation(&self) -> String {
+        "TEME".to_string()
+    }
+
+    fn is_rotating(&self) -> bool {
+        false
+    }
+}
+
+impl QuasiInertial for Teme {}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Ord, PartialOrd)]
 pub struct Iau<T: RotationalElements>(pub T);
 
@@ -170,6 +193,7 @@ pub enum DynFrame {
     Cirf,
     Tirf,
     Itrf,
+    Teme,
     Iau(DynOrigin),
 }
 
@@ -180,6 +204,7 @@ impl ReferenceFrame for DynFrame {
             DynFrame::Cirf => Cirf.name(),
             DynFrame::Tirf => Tirf.name(),
             DynFrame::Itrf => Itrf.name(),
+            DynFrame::Teme => Teme.name(),
             DynFrame::Iau(dyn_origin) => {
                 let body = dyn_origin.name();
                 match body {
@@ -196,6 +221,7 @@ impl ReferenceFrame for DynFrame {
             DynFrame::Cirf => Cirf.abbreviation(),
             DynFrame::Tirf => Tirf.abbreviation(),
             DynFrame::Itrf => Itrf.abbreviation(),
+            DynFrame::Teme => Teme.abbreviation(),
             DynFrame::Iau(dyn_origin) => {
                 let body = dyn_origin.name().replace([' ', '-'], "_").to_uppercase();
                 format!("IAU_{}", body)
@@ -205,7 +231,7 @@ impl ReferenceFrame for DynFrame {
 
     fn is_rotating(&self) -> bool {
         match self {
-            DynFrame::Icrf | DynFrame::Cirf => false,
+            DynFrame::Icrf | DynFrame::Cirf | DynFrame::Teme => false,
             DynFrame::Tirf | DynFrame::Itrf | DynFrame::Iau(_) => true,
         }
     }
@@ -214,7 +240,7 @@ impl ReferenceFrame for DynFrame {
 impl TryQuasiInertial for DynFrame {
     fn try_quasi_inertial(&self) -> Result<(), NonQuasiInertialFrameError> {
         match self {
-            DynFrame::Icrf => Ok(()),
+            DynFrame::Icrf | DynFrame::Teme => Ok(()),
             _ => Err(NonQuasiInertialFrameError(self.abbreviation())),
         }
     }
@@ -252,6 +278,7 @@ impl FromStr for DynFrame {
             "cirf" | "CIRF" => Ok(DynFrame::Cirf),
             "tirf" | "TIRF" => Ok(DynFrame::Tirf),
             "itrf" | "ITRF" => Ok(DynFrame::Itrf),
+            "teme" | "TEME" => Ok(DynFrame::Teme),
             _ => {
                 if let Some(frame) = parse_iau_frame(s) {
                     Ok(frame)
@@ -300,19 +327,26 @@ where
                 DynFrame::Itrf => Ok(icrf_to_cirf(centuries_j2000)
                     .compose(&cirf_to_tirf(seconds_j2000))
                     .compose(&tirf_to_itrf(centuries_j2000))),
+                DynFrame::Teme => Ok(icrf_to_cirf(centuries_j2000)
+                    .compose(&cirf_to_tirf(seconds_j2000))
+                    .compose(&teme_to_tirf(centuries_j2000).transpose())),
                 DynFrame::Iau(target) => icrf_to_iau(time, target, provider),
             },
-            DynFrame::Cirf => match frame {
-                DynFrame::Icrf => Ok(icrf_to_cirf(centuries_j2000).transpose()),
-                DynFrame::Cirf => Ok(Rotation::IDENTITY),
-                DynFrame::Tirf => Ok(cirf_to_tirf(seconds_j2000)),
-                DynFrame::Itrf => {
-                    Ok(cirf_to_tirf(seconds_j2000).compose(&tirf_to_itrf(centuries_j2000)))
+            DynFrame::Cirf => {
+                match frame {
+                    DynFrame::Icrf => Ok(icrf_to_cirf(centuries_j2000).transpose()),
+                    DynFrame::Cirf => Ok(Rotation::IDENTITY),
+                    DynFrame::Tirf => Ok(cirf_to_tirf(seconds_j2000)),
+                    DynFrame::Itrf => {
+                        Ok(cirf_to_tirf(seconds_j2000).compose(&tirf_to_itrf(centuries_j2000)))
+                    }
+                    DynFrame::Teme => Ok(cirf_to_tirf(seconds_j2000)
+                        .compose(&teme_to_tirf(centuries_j2000).transpose())),
+                    DynFrame::Iau(_) => Ok(self
+                        .try_rotation(DynFrame::Icrf, time, provider)?
+                        .compose(&DynFrame::Icrf.try_rotation(frame, time, provider)?)),
                 }
-                DynFrame::Iau(_) => Ok(self
-                    .try_rotation(DynFrame::Icrf, time, provider)?
-                    .compose(&DynFrame::Icrf.try_rotation(frame, time, provider)?)),
-            },
+            }
             DynFrame::Tirf => match frame {
                 DynFrame::Icrf => Ok(cirf_to_tirf(seconds_j2000)
                     .transpose()
@@ -320,6 +354,7 @@ where
                 DynFrame::Cirf => Ok(cirf_to_tirf(seconds_j2000).transpose()),
                 DynFrame::Tirf => Ok(Rotation::IDENTITY),
                 DynFrame::Itrf => Ok(tirf_to_itrf(centuries_j2000)),
+                DynFrame::Teme => Ok(teme_to_tirf(centuries_j2000).transpose()),
                 DynFrame::Iau(_) => Ok(self
                     .try_rotation(DynFrame::Icrf, time, provider)?
                     .compose(&DynFrame::Icrf.try_rotation(frame, time, provider)?)),
@@ -334,10 +369,30 @@ where
                     .compose(&cirf_to_tirf(seconds_j2000).transpose())),
                 DynFrame::Tirf => Ok(tirf_to_itrf(centuries_j2000).transpose()),
                 DynFrame::Itrf => Ok(Rotation::IDENTITY),
+                DynFrame::Teme => Ok(tirf_to_itrf(centuries_j2000)
+                    .transpose()
+                    .compose(&teme_to_tirf(centuries_j2000).transpose())),
                 DynFrame::Iau(_) => Ok(self
                     .try_rotation(DynFrame::Icrf, time, provider)?
                     .compose(&DynFrame::Icrf.try_rotation(frame, time, provider)?)),
             },
+            DynFrame::Teme => {
+                match frame {
+                    DynFrame::Icrf => Ok(teme_to_tirf(centuries_j2000)
+                        .compose(&cirf_to_tirf(seconds_j2000).transpose())
+                        .compose(&icrf_to_cirf(centuries_j2000).transpose())),
+                    DynFrame::Cirf => Ok(teme_to_tirf(centuries_j2000)
+                        .compose(&cirf_to_tirf(seconds_j2000).transpose())),
+                    DynFrame::Tirf => Ok(teme_to_tirf(centuries_j2000)),
+                    DynFrame::Itrf => {
+                        Ok(teme_to_tirf(centuries_j2000).compose(&tirf_to_itrf(centuries_j2000)))
+                    }
+                    DynFrame::Teme => Ok(Rotation::IDENTITY),
+                    DynFrame::Iau(_) => Ok(self
+                        .try_rotation(DynFrame::Icrf, time, provider)?
+                        .compose(&DynFrame::Icrf.try_rotation(frame, time, provider)?)),
+                }
+            }
             DynFrame::Iau(origin) => match frame {
                 DynFrame::Icrf => Ok(icrf_to_iau(time, *origin, provider)?.transpose()),
                 DynFrame::Cirf => Ok(self
@@ -349,6 +404,9 @@ where
                 DynFrame::Itrf => Ok(self
                     .try_rotation(DynFrame::Icrf, time, provider)?
                     .compose(&DynFrame::Icrf.try_rotation(frame, time, provider)?)),
+                DynFrame::Teme => Ok(self
+                    .try_rotation(DynFrame::Icrf, time, provider)?
+                    .compose(&DynFrame::Icrf.try_rotation(frame, time, provider)?)),
                 DynFrame::Iau(target) => {
                     if *origin == target {
                         Ok(Rotation::IDENTITY)
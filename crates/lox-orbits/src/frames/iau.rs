@@ -6,10 +6,11 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::rotations::Rotation;
+use crate::rotations::{Rotation, skew};
 use glam::{DMat3, DVec3};
-use lox_bodies::RotationalElements;
+use lox_bodies::{Elements, Moon, RotationalElements};
 use lox_bodies::{TryRotationalElements, UndefinedOriginPropertyError};
+use lox_math::math::RADIANS_IN_ARCSECOND;
 use lox_time::Time;
 use lox_time::time_scales::Tdb;
 use lox_time::time_scales::TryToScale;
@@ -42,16 +43,103 @@ where
         .try_to_scale(Tdb, provider)
         .map_err(|err| IauFrameTransformationError::Tdb(err.to_string()))?
         .seconds_since_j2000();
-    let (right_ascension, declination, rotation_angle) = body.try_rotational_elements(seconds)?;
-    let (right_ascension_rate, declination_rate, rotation_rate) =
-        body.try_rotational_element_rates(seconds)?;
+    let elements = body.try_rotational_elements(seconds)?;
+    let rates = body.try_rotational_element_rates(seconds)?;
+    let (rotation, _) = iau_rotation(elements, rates);
+    Ok(rotation)
+}
+
+impl<O: RotationalElements> Iau<O> {
+    /// Returns the rotation from the ICRF to this frame at `time` together with the angular
+    /// velocity of this frame with respect to the ICRF in rad/s, expressed in the ICRF.
+    ///
+    /// The angular velocity is derived analytically from the rates of the right ascension and
+    /// declination of the pole and of the prime meridian.
+    pub fn rotation_with_angular_velocity<T, P>(
+        &self,
+        time: Time<T>,
+        provider: Option<&P>,
+    ) -> Result<(Rotation, DVec3), IauFrameTransformationError>
+    where
+        T: TimeScale + TryToScale<Tdb, P>,
+    {
+        let seconds = time
+            .try_to_scale(Tdb, provider)
+            .map_err(|err| IauFrameTransformationError::Tdb(err.to_string()))?
+            .seconds_since_j2000();
+        Ok(iau_rotation(
+            self.0.rotational_elements(seconds),
+            self.0.rotational_element_rates(seconds),
+        ))
+    }
+}
+
+/// The body-fixed frames of the Moon.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LunarFrame {
+    /// The mean Earth/polar axis frame defined by the IAU WGCCRE rotational elements of the Moon,
+    /// including the lunar libration terms.
+    #[default]
+    MeanEarth,
+    /// The principal axis frame of the DE421 lunar ephemeris.
+    PrincipalAxes,
+}
+
+/// The angles of the rotation from the DE421 principal axis frame to the mean Earth/polar axis
+/// frame of the Moon about the x, y, and z axes in arcseconds.
+///
+/// Williams et al., 2008, DE421 Lunar Orbit, Physical Librations, and Surface Coordinates
+const DE421_PA_TO_ME: [f64; 3] = [0.2785, 78.6944, 67.8526];
+
+impl Iau<Moon> {
+    /// Returns the rotation from the ICRF to the lunar body-fixed `frame` at `time`.
+    ///
+    /// The principal axis frame is derived from the mean Earth/polar axis frame by the constant
+    /// rotation between both frames for DE421.
+    pub fn lunar_rotation<T, P>(
+        &self,
+        frame: LunarFrame,
+        time: Time<T>,
+        provider: Option<&P>,
+    ) -> Result<Rotation, IauFrameTransformationError>
+    where
+        T: TimeScale + TryToScale<Tdb, P>,
+    {
+        let (mean_earth, _) = self.rotation_with_angular_velocity(time, provider)?;
+        match frame {
+            LunarFrame::MeanEarth => Ok(mean_earth),
+            LunarFrame::PrincipalAxes => {
+                let [x, y, z] = DE421_PA_TO_ME.map(|angle| angle * RADIANS_IN_ARCSECOND);
+                // The passive rotations R(-θ) of Williams et al. are the active rotations R(θ)
+                let pa_to_me = DMat3::from_rotation_x(x)
+                    * DMat3::from_rotation_y(y)
+                    * DMat3::from_rotation_z(z);
+                Ok(mean_earth.compose(&Rotation::new(pa_to_me.transpose())))
+            }
+        }
+    }
+}
+
+/// Computes the rotation from the ICRF to an IAU body-fixed frame and the angular velocity of the
+/// body-fixed frame expressed in the ICRF from the rotational elements and their rates.
+fn iau_rotation(elements: Elements, rates: Elements) -> (Rotation, DVec3) {
+    let (right_ascension, declination, rotation_angle) = elements;
+    let (right_ascension_rate, declination_rate, rotation_rate) = rates;
 
     let m1 = DMat3::from_rotation_z(-(right_ascension + FRAC_PI_2));
     let m2 = DMat3::from_rotation_x(-(FRAC_PI_2 - declination));
     let m3 = DMat3::from_rotation_z(-(rotation_angle % TAU));
     let m = m3 * m2 * m1;
-    let v = DVec3::new(right_ascension_rate, -declination_rate, rotation_rate);
-    Ok(Rotation::new(m).with_angular_velocity(v))
+
+    // The three rotations are about the ICRF pole, the ascending node of the body's equator, and
+    // the body's pole, respectively
+    let (sin_ra, cos_ra) = right_ascension.sin_cos();
+    let (sin_dec, cos_dec) = declination.sin_cos();
+    let node = DVec3::new(-sin_ra, cos_ra, 0.0);
+    let pole = DVec3::new(cos_dec * cos_ra, cos_dec * sin_ra, sin_dec);
+    let w = right_ascension_rate * DVec3::Z - declination_rate * node + rotation_rate * pole;
+
+    (Rotation::new(m).with_derivative(-m * skew(w)), w)
 }
 
 impl<T, O, P> TryRotateTo<T, Iau<O>, P> for Icrf
@@ -87,3 +175,156 @@ where
         Ok(icrf_to_iau(time, self.0.clone(), provider)?.transpose())
     }
 }
+
+impl<T, O1, O2, P> TryRotateTo<T, Iau<O2>, P> for Iau<O1>
+where
+    T: TimeScale + TryToScale<Tdb, P> + Clone,
+    O1: RotationalElements + Clone,
+    O2: RotationalElements,
+{
+    type Error = IauFrameTransformationError;
+
+    /// Chains the rotations between both body-fixed frames and the ICRF.
+    fn try_rotation(
+        &self,
+        frame: Iau<O2>,
+        time: Time<T>,
+        provider: Option<&P>,
+    ) -> Result<Rotation, Self::Error> {
+        let to_icrf = icrf_to_iau(time.clone(), self.0.clone(), provider)?.transpose();
+        let from_icrf = icrf_to_iau(time, frame.0, provider)?;
+        Ok(to_icrf.compose(&from_icrf))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use lox_bodies::{Earth, Jupiter, Moon};
+    use lox_time::deltas::TimeDelta;
+    use lox_time::time;
+    use rstest::rstest;
+
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+
+    use super::*;
+    use crate::states::State;
+
+    #[rstest]
+    #[case::earth(Earth)]
+    #[case::moon(Moon)]
+    #[case::jupiter(Jupiter)]
+    fn test_iau_angular_velocity<O: RotationalElements + Copy>(#[case] body: O) {
+        // Close to J2000, such that the rotation angle of the prime meridian is not too large for an
+        // accurate finite difference
+        let time = time!(Tdb, 2000, 1, 1, 18).unwrap();
+        let frame = Iau(body);
+        let (rotation, w) = frame
+            .rotation_with_angular_velocity(time, None::<&()>)
+            .unwrap();
+
+        let h = 0.1;
+        let matrix = |dt: f64| {
+            let t = time + TimeDelta::from_decimal_seconds(dt);
+            frame
+                .rotation_with_angular_velocity(t, None::<&()>)
+                .unwrap()
+                .0
+                .position_matrix()
+        };
+        let dm = (matrix(h) - matrix(-h)) / (2.0 * h);
+        let scale = w.length();
+        for (act, exp) in rotation
+            .velocity_matrix()
+            .to_cols_array()
+            .iter()
+            .zip(dm.to_cols_array())
+        {
+            assert_float_eq!(*act / scale, exp / scale, abs <= 1e-9);
+        }
+
+        // The angular velocity rotates positions fixed in the body-fixed frame
+        let r = DVec3::new(1.0, 2.0, 3.0);
+        let r_icrf = rotation.position_matrix().transpose() * r;
+        let v_icrf = dm.transpose() * r;
+        let expected = w.cross(r_icrf);
+        assert_float_eq!(v_icrf.x, expected.x, abs <= 1e-9 * scale);
+        assert_float_eq!(v_icrf.y, expected.y, abs <= 1e-9 * scale);
+        assert_float_eq!(v_icrf.z, expected.z, abs <= 1e-9 * scale);
+    }
+
+    #[test]
+    fn test_lunar_rotation() {
+        let time = time!(Tdb, 2024, 3, 1, 12).unwrap();
+        let frame = Iau(Moon);
+        let me = frame
+            .lunar_rotation(LunarFrame::MeanEarth, time, None::<&()>)
+            .unwrap();
+        let expected = Icrf.try_rotation(frame, time, None::<&()>).unwrap();
+        assert_eq!(me.position_matrix(), expected.position_matrix());
+
+        // The principal axis frame is rotated by approximately 104 arcseconds with respect to the
+        // mean Earth/polar axis frame, of which 78.7 arcseconds are a tilt of the pole
+        let pa = frame
+            .lunar_rotation(LunarFrame::PrincipalAxes, time, None::<&()>)
+            .unwrap();
+        let relative = pa.position_matrix() * me.position_matrix().transpose();
+        let angle =
+            ((relative.x_axis.x + relative.y_axis.y + relative.z_axis.z - 1.0) / 2.0).acos();
+        let tilt = relative.z_axis.z.acos();
+        assert_float_eq!(angle / RADIANS_IN_ARCSECOND, 103.908, abs <= 1e-3);
+        assert_float_eq!(tilt / RADIANS_IN_ARCSECOND, 78.695, abs <= 1e-3);
+        assert!(
+            pa.velocity_matrix()
+                .abs_diff_eq(relative * me.velocity_matrix(), 1e-20)
+        );
+
+        // The x-axis of the principal axis frame points 67.85 arcseconds east of and 78.69
+        // arcseconds below the prime meridian of the mean Earth/polar axis frame
+        let x_axis = relative.transpose() * DVec3::X;
+        assert_float_eq!(x_axis.y / RADIANS_IN_ARCSECOND, 67.8526, abs <= 1e-2);
+        assert_float_eq!(x_axis.z / RADIANS_IN_ARCSECOND, -78.6944, abs <= 1e-2);
+    }
+
+    #[rstest]
+    #[case::earth(Earth)]
+    #[case::moon(Moon)]
+    #[case::jupiter(Jupiter)]
+    fn test_iau_state_round_trip<O: RotationalElements + Copy>(#[case] body: O) {
+        let time = time!(Tdb, 2024, 3, 1, 12).unwrap();
+        let r0 = DVec3::new(6068.27927, -1692.84394, -2516.61918);
+        let v0 = DVec3::new(-0.660415582, 5.495938726, -5.303093233);
+        let s0 = State::new(time, r0, v0, body, Icrf);
+        let s1 = s0.try_to_frame(Iau(body), None::<&()>).unwrap();
+        let s2 = s1.try_to_frame(Icrf, None::<&()>).unwrap();
+        assert_close!(s2.position(), r0, 1e-9);
+        assert_close!(s2.velocity(), v0, 1e-12);
+
+        // The body-fixed velocity excludes the rotation of the frame, i.e. the ω×r term
+        let (rotation, w) = Iau(body)
+            .rotation_with_angular_velocity(time, None::<&()>)
+            .unwrap();
+        let expected = rotation.position_matrix() * (v0 - w.cross(r0));
+        assert_close!(s1.velocity(), expected, 1e-12);
+    }
+
+    #[test]
+    fn test_iau_to_iau() {
+        let time = time!(Tdb, 2024, 3, 1, 12).unwrap();
+        let r0 = DVec3::new(6068.27927, -1692.84394, -2516.61918);
+        let v0 = DVec3::new(-0.660415582, 5.495938726, -5.303093233);
+        let s0 = State::new(time, r0, v0, Earth, Iau(Earth));
+        let direct = s0.try_to_frame(Iau(Moon), None::<&()>).unwrap();
+        let via_icrf = s0
+            .try_to_frame(Icrf, None::<&()>)
+            .unwrap()
+            .try_to_frame(Iau(Moon), None::<&()>)
+            .unwrap();
+        assert_close!(direct.position(), via_icrf.position(), 1e-9);
+        assert_close!(direct.velocity(), via_icrf.velocity(), 1e-12);
+        let back = direct.try_to_frame(Iau(Earth), None::<&()>).unwrap();
+        assert_close!(back.position(), r0, 1e-9);
+        assert_close!(back.velocity(), v0, 1e-12);
+    }
+}
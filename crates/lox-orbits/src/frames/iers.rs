@@ -13,13 +13,51 @@ use lox_earth::cip::xy06::xy;
 use lox_earth::coordinate_transformations::{
     celestial_to_intermediate_frame_of_date_matrix, polar_motion_matrix,
 };
+use lox_earth::eop::EopProvider;
 use lox_earth::rotation_angle::RotationAngle;
 use lox_earth::tio::sp_00;
 use lox_math::constants::f64::time::SECONDS_PER_DAY;
+use lox_math::math::arcsec_to_rad;
+use lox_time::Time;
+use lox_time::deltas::ToDelta;
+use lox_time::julian_dates::JulianDate;
+use lox_time::time_scales::{Tai, TimeScale, ToScale, Tt};
+
+/// Offset of the ICRS right ascension origin from the J2000.0 mean equinox dα₀ in arcseconds.
+const FRAME_BIAS_RIGHT_ASCENSION: f64 = -0.0146;
+
+/// Celestial pole offset at J2000.0 in longitude δψ₀ in arcseconds, such that ξ₀ = δψ₀ sin ε₀.
+const FRAME_BIAS_LONGITUDE: f64 = -0.041775;
+
+/// Celestial pole offset at J2000.0 in obliquity η₀ in arcseconds.
+const FRAME_BIAS_OBLIQUITY: f64 = -0.0068192;
+
+/// IAU 2000 obliquity of the ecliptic at J2000.0 ε₀ in arcseconds, which defines ξ₀.
+const FRAME_BIAS_OBLIQUITY_J2000: f64 = 84381.448;
+
+/// Returns the constant frame bias rotation from the GCRF to the dynamical mean equator and
+/// equinox of J2000.0.
+///
+/// The bias is defined by the offsets dα₀, ξ₀, and η₀ of IERS Conventions (2010), Section 5.6.2,
+/// such that the classical precession-nutation chain from the GCRF is B, followed by precession
+/// and nutation.
+pub fn frame_bias_matrix() -> Rotation {
+    let xi0 = FRAME_BIAS_LONGITUDE * arcsec_to_rad(FRAME_BIAS_OBLIQUITY_J2000).sin();
+    // The signs of all angles are reversed relative to the IERS Conventions, which use passive
+    // rotations
+    let m = DMat3::from_rotation_x(arcsec_to_rad(FRAME_BIAS_OBLIQUITY))
+        * DMat3::from_rotation_y(-arcsec_to_rad(xi0))
+        * DMat3::from_rotation_z(-arcsec_to_rad(FRAME_BIAS_RIGHT_ASCENSION));
+    Rotation::new(m)
+}
 
 pub fn icrf_to_cirf(centuries: f64) -> Rotation {
     // TODO: Add IERS corrections
-    let cip_coords = xy(centuries);
+    icrf_to_cirf_with_offsets(centuries, DVec2::ZERO)
+}
+
+fn icrf_to_cirf_with_offsets(centuries: f64, offsets: DVec2) -> Rotation {
+    let cip_coords = xy(centuries) + offsets;
     let cio_locator = s(centuries, cip_coords);
     let m = celestial_to_intermediate_frame_of_date_matrix(cip_coords, cio_locator);
     Rotation::new(m)
@@ -27,7 +65,7 @@ pub fn icrf_to_cirf(centuries: f64) -> Rotation {
 
 pub fn cirf_to_tirf(seconds: f64) -> Rotation {
     let era = Earth::rotation_angle_00(seconds / SECONDS_PER_DAY);
-    let rate = Earth.rotation_rate(seconds);
+    let rate = Earth.rotation_rate(seconds).to_radians_per_second();
     let m = DMat3::from_rotation_z(-era);
     let v = DVec3::new(0.0, 0.0, rate);
     Rotation::new(m).with_angular_velocity(v)
@@ -35,8 +73,165 @@ pub fn cirf_to_tirf(seconds: f64) -> Rotation {
 
 pub fn tirf_to_itrf(centuries: f64) -> Rotation {
     // TODO: Add IERS corrections
-    let pole_coords: DVec2 = (0.0, 0.0).into();
+    tirf_to_itrf_with_pole_coords(centuries, DVec2::ZERO)
+}
+
+fn tirf_to_itrf_with_pole_coords(centuries: f64, pole_coords: DVec2) -> Rotation {
     let tio_locator = sp_00(centuries);
     let m = polar_motion_matrix(pole_coords, tio_locator);
     Rotation::new(m)
 }
+
+/// Computes the full IAU 2006/2000A CIO-based rotation from the GCRF to the ITRF.
+///
+/// The celestial pole offsets, UT1, and polar motion are taken from `eop`.
+pub fn gcrf_to_itrf<T, P>(time: Time<T>, eop: &P) -> Result<Rotation, P::Error>
+where
+    T: TimeScale + ToScale<Tai> + Copy,
+    P: EopProvider,
+{
+    let tai = time.to_scale(Tai);
+    let centuries = tai.to_scale(Tt).centuries_since_j2000();
+    let delta = tai.to_delta();
+    let (dx, dy) = eop.celestial_pole_offsets(delta)?;
+    let (xp, yp) = eop.polar_motion(delta)?;
+    let ut1 = tai.seconds_since_j2000() + eop.delta_ut1_tai(delta)?.to_decimal_seconds();
+    let offsets = DVec2::new(arcsec_to_rad(dx), arcsec_to_rad(dy));
+    let pole_coords = DVec2::new(arcsec_to_rad(xp), arcsec_to_rad(yp));
+    Ok(icrf_to_cirf_with_offsets(centuries, offsets)
+        .compose(&cirf_to_tirf(ut1))
+        .compose(&tirf_to_itrf_with_pole_coords(centuries, pole_coords)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use lox_earth::eop::IersEop;
+    use lox_earth::nutation::{Model, nutation};
+    use lox_earth::sidereal_time::equation_of_origins;
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+    use lox_math::types::units::Arcseconds;
+    use lox_time::deltas::TimeDelta;
+    use lox_time::time;
+    use lox_time::time_scales::Tdb;
+    use lox_time::ut1::DeltaUt1TaiProvider;
+    use lox_time::utc::leap_seconds::BuiltinLeapSeconds;
+
+    use super::*;
+
+    // One microarcsecond in radians
+    const MICROARCSECOND: f64 = 4.84813681109536e-12;
+
+    /// Constant Earth Orientation Parameters for 2007-04-05 12:00 UTC from the example in
+    /// "SOFA Tools for Earth Attitude"
+    struct SofaExample;
+
+    impl DeltaUt1TaiProvider for SofaExample {
+        type Error = Infallible;
+
+        fn delta_ut1_tai(&self, _tai: TimeDelta) -> Result<TimeDelta, Self::Error> {
+            // UT1-UTC = -0.072073685 s and TAI-UTC = 33 s
+            Ok(TimeDelta::from_decimal_seconds(-33.072073685))
+        }
+
+        fn delta_tai_ut1(&self, _ut1: TimeDelta) -> Result<TimeDelta, Self::Error> {
+            Ok(TimeDelta::from_decimal_seconds(33.072073685))
+        }
+    }
+
+    impl EopProvider for SofaExample {
+        fn polar_motion(&self, _tai: TimeDelta) -> Result<(Arcseconds, Arcseconds), Self::Error> {
+            Ok((0.0349282, 0.4833163))
+        }
+
+        fn celestial_pole_offsets(
+            &self,
+            _tai: TimeDelta,
+        ) -> Result<(Arcseconds, Arcseconds), Self::Error> {
+            Ok((0.1750e-3, -0.2259e-3))
+        }
+    }
+
+    #[test]
+    fn test_frame_bias_matrix() {
+        // Reference values from the SOFA/ERFA test suite
+        let expected = DMat3::from_cols_array(&[
+            0.9999999999999942,
+            7.078279477857338e-8,
+            -8.056217380986972e-8,
+            -7.078279744199197e-8,
+            0.999999999999997,
+            -3.3060408839805523e-8,
+            8.056217146976134e-8,
+            3.3060414542221364e-8,
+            0.9999999999999962,
+        ]);
+        let m = frame_bias_matrix().position_matrix();
+        for i in 0..3 {
+            assert_close!(m.col(i), expected.col(i), 1e-15, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_frame_bias_classical_and_cio_based() {
+        // At J2000.0 precession vanishes, such that bias and nutation followed by the rotation by
+        // the equation of origins about the CIP must agree with the CIO-based transformation
+        let nutation = nutation(Model::IAU2006A, Time::j2000(Tdb));
+        // IAU 2006 obliquity of the ecliptic at J2000.0
+        let eps = arcsec_to_rad(84381.406);
+        let n = DMat3::from_rotation_x(eps + nutation.obliquity)
+            * DMat3::from_rotation_z(nutation.longitude)
+            * DMat3::from_rotation_x(-eps);
+        let classical = Rotation::new(n).compose(&Rotation::new(DMat3::from_rotation_z(
+            equation_of_origins(0.0),
+        )));
+        let actual = frame_bias_matrix().compose(&classical).position_matrix();
+        let expected = icrf_to_cirf(0.0).position_matrix();
+        for i in 0..3 {
+            assert_close!(actual.col(i), expected.col(i), MICROARCSECOND, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_gcrf_to_itrf() {
+        let time = time!(Tai, 2007, 4, 5, 12, 0, 33.0).unwrap();
+        let expected = DMat3::from_cols_array(&[
+            0.973104317697535,
+            -0.230363800456037,
+            0.000711560162668,
+            0.230363826239128,
+            0.973104570632801,
+            0.000046626403995,
+            -0.000703163482198,
+            0.000118545366625,
+            0.999999745754024,
+        ]);
+        let actual = gcrf_to_itrf(time, &SofaExample).unwrap();
+        let m = actual.position_matrix();
+        for i in 0..3 {
+            assert_close!(m.col(i), expected.col(i), MICROARCSECOND, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_gcrf_to_itrf_iers_eop() {
+        let eop = IersEop::new(
+            format!(
+                "{}/../../data/finals2000A.all.csv",
+                env!("CARGO_MANIFEST_DIR")
+            ),
+            &BuiltinLeapSeconds,
+        )
+        .unwrap();
+        let time = time!(Tai, 2007, 4, 5, 12, 0, 33.0).unwrap();
+        let expected = gcrf_to_itrf(time, &SofaExample).unwrap().position_matrix();
+        let actual = gcrf_to_itrf(time, &eop).unwrap().position_matrix();
+        // The current IERS series differs from the values used in the SOFA example by less than
+        // one milliarcsecond
+        for i in 0..3 {
+            assert_close!(actual.col(i), expected.col(i), 1e3 * MICROARCSECOND, 0.0);
+        }
+    }
+}
@@ -0,0 +1,104 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::convert::Infallible;
+use std::f64::consts::TAU;
+
+use glam::{DMat3, DVec3};
+use lox_math::constants::f64::time::{SECONDS_PER_DAY, SECONDS_PER_JULIAN_CENTURY};
+use lox_time::Time;
+use lox_time::julian_dates::JulianDate;
+use lox_time::time_scales::TimeScale;
+
+use super::iers::{cirf_to_tirf, icrf_to_cirf};
+use super::{Icrf, Teme, TryRotateTo};
+use crate::rotations::Rotation;
+
+const SECONDS_PER_HOUR: f64 = 3600.0;
+
+/// Greenwich mean sidereal time (IAU 1982) and its rate in rad and rad/s
+fn gmst_82(centuries: f64) -> (f64, f64) {
+    let t = centuries;
+    // The polynomial is given in seconds of time
+    let gmst =
+        67310.54841 + (876600.0 * SECONDS_PER_HOUR + 8640184.812866) * t + 0.093104 * t.powi(2)
+            - 6.2e-6 * t.powi(3);
+    let rate = 876600.0 * SECONDS_PER_HOUR + 8640184.812866 + 2.0 * 0.093104 * t
+        - 3.0 * 6.2e-6 * t.powi(2);
+    (
+        (gmst * TAU / SECONDS_PER_DAY).rem_euclid(TAU),
+        rate * TAU / SECONDS_PER_DAY / SECONDS_PER_JULIAN_CENTURY,
+    )
+}
+
+/// Rotation from the TEME frame to the pseudo Earth-fixed frame of the SGP4
+/// theory, which is used as an approximation of TIRF.
+pub fn teme_to_tirf(centuries: f64) -> Rotation {
+    let (gmst, rate) = gmst_82(centuries);
+    let m = DMat3::from_rotation_z(-gmst);
+    let v = DVec3::new(0.0, 0.0, rate);
+    Rotation::new(m).with_angular_velocity(v)
+}
+
+impl<T: TimeScale, P> TryRotateTo<T, Icrf, P> for Teme {
+    type Error = Infallible;
+
+    fn try_rotation(
+        &self,
+        _frame: Icrf,
+        time: Time<T>,
+        _provider: Option<&P>,
+    ) -> Result<Rotation, Self::Error> {
+        let centuries = time.centuries_since_j2000();
+        Ok(teme_to_tirf(centuries)
+            .compose(&cirf_to_tirf(time.seconds_since_j2000()).transpose())
+            .compose(&icrf_to_cirf(centuries).transpose()))
+    }
+}
+
+impl<T: TimeScale, P> TryRotateTo<T, Teme, P> for Icrf {
+    type Error = Infallible;
+
+    fn try_rotation(
+        &self,
+        _frame: Teme,
+        time: Time<T>,
+        provider: Option<&P>,
+    ) -> Result<Rotation, Self::Error> {
+        Ok(Teme.try_rotation(Icrf, time, provider)?.transpose())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+
+    use super::*;
+
+    // Vallado, Fundamentals of Astrodynamics and Applications, 4th ed., Example 3-15
+    const CENTURIES: f64 = 1556.827406783 / 36525.0;
+
+    #[test]
+    fn test_gmst_82() {
+        let (gmst, _) = gmst_82(CENTURIES);
+        assert_close!(gmst, 312.8098943f64.to_radians(), 1e-7);
+    }
+
+    #[test]
+    fn test_teme_to_tirf() {
+        let r = DVec3::new(5094.18016210, 6127.64465950, 6380.34453270);
+        let v = DVec3::new(-4.746131487, 0.785818041, 5.531931288);
+        let r_exp = DVec3::new(-1033.4750261, 7901.3055863, 6380.3445327);
+        let (r_act, v_act) = teme_to_tirf(CENTURIES).rotate_state(r, v);
+        assert_close!(r_act, r_exp, 1e-3);
+        let omega = DVec3::new(0.0, 0.0, 7.292115855e-5);
+        let v_exp = teme_to_tirf(CENTURIES).rotate_position(v) - omega.cross(r_act);
+        assert_close!(v_act, v_exp, 1e-9);
+    }
+}
@@ -0,0 +1,274 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module transform provides [FrameTransform] which caches the rotation between two reference
+//! frames at a fixed epoch.
+
+use glam::DVec3;
+use lox_bodies::Origin;
+use lox_time::Time;
+use lox_time::time_scales::{DynTimeScale, TimeScale};
+use thiserror::Error;
+
+use crate::covariance::Covariance;
+use crate::frames::{DynFrame, ReferenceFrame, TryRotateTo};
+use crate::rotations::Rotation;
+use crate::states::State;
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+pub enum FrameTransformError {
+    #[error("the transform is defined at {expected} but the state is at {actual}")]
+    EpochMismatch { expected: String, actual: String },
+    #[error("the transform is defined for frame {expected} but the state is in frame {actual}")]
+    FrameMismatch { expected: String, actual: String },
+}
+
+/// The rotation from one reference frame to another at a fixed epoch.
+///
+/// Computing the rotation between two frames, e.g. the ICRF and the ITRF, requires the evaluation
+/// of lengthy precession-nutation series. A `FrameTransform` evaluates them once and can then be
+/// applied cheaply to any number of states or covariances at the same epoch.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrameTransform<T: TimeScale, R1: ReferenceFrame, R2: ReferenceFrame> {
+    time: Time<T>,
+    from: R1,
+    to: R2,
+    rotation: Rotation,
+}
+
+pub type DynFrameTransform = FrameTransform<DynTimeScale, DynFrame, DynFrame>;
+
+impl<T, R1, R2> FrameTransform<T, R1, R2>
+where
+    T: TimeScale + Clone,
+    R1: ReferenceFrame + Clone + PartialEq,
+    R2: ReferenceFrame + Clone,
+{
+    /// Computes the rotation including its time derivative from `from` to `to` at `time`.
+    pub fn try_new<P>(
+        from: R1,
+        to: R2,
+        time: Time<T>,
+        provider: Option<&P>,
+    ) -> Result<Self, R1::Error>
+    where
+        R1: TryRotateTo<T, R2, P>,
+    {
+        let rotation = from.try_rotation(to.clone(), time.clone(), provider)?;
+        Ok(Self {
+            time,
+            from,
+            to,
+            rotation,
+        })
+    }
+
+    pub fn time(&self) -> Time<T> {
+        self.time.clone()
+    }
+
+    pub fn from_frame(&self) -> R1 {
+        self.from.clone()
+    }
+
+    pub fn to_frame(&self) -> R2 {
+        self.to.clone()
+    }
+
+    pub fn rotation(&self) -> &Rotation {
+        &self.rotation
+    }
+
+    pub fn rotate_position(&self, position: DVec3) -> DVec3 {
+        self.rotation.rotate_position(position)
+    }
+
+    /// Rotates `position` and `velocity`, accounting for the angular velocity between the frames.
+    pub fn rotate_state(&self, position: DVec3, velocity: DVec3) -> (DVec3, DVec3) {
+        self.rotation.rotate_state(position, velocity)
+    }
+
+    pub fn rotate_covariance(&self, covariance: &Covariance) -> Covariance {
+        covariance.rotate(&self.rotation)
+    }
+
+    /// Transforms `state` into the target frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns [FrameTransformError] if the epoch or the frame of `state` differ from those of the
+    /// transform.
+    pub fn apply<O>(&self, state: &State<T, O, R1>) -> Result<State<T, O, R2>, FrameTransformError>
+    where
+        T: PartialEq,
+        O: Origin + Clone,
+    {
+        if state.time() != self.time {
+            return Err(FrameTransformError::EpochMismatch {
+                expected: self.time.to_string(),
+                actual: state.time().to_string(),
+            });
+        }
+        if state.reference_frame() != self.from {
+            return Err(FrameTransformError::FrameMismatch {
+                expected: self.from.abbreviation(),
+                actual: state.reference_frame().abbreviation(),
+            });
+        }
+        let (position, velocity) = self.rotate_state(state.position(), state.velocity());
+        Ok(State::new(
+            state.time(),
+            position,
+            velocity,
+            state.origin(),
+            self.to.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::DMat3;
+    use lox_bodies::DynOrigin;
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+    use lox_time::DynTime;
+    use lox_time::deltas::TimeDelta;
+    use lox_time::time_scales::Tai;
+    use lox_time::ut1::DeltaUt1Tai;
+    use lox_time::utc::Utc;
+
+    use super::*;
+    use crate::covariance::Matrix6;
+
+    fn time() -> DynTime {
+        Utc::from_iso("2024-07-05T09:09:18.173")
+            .unwrap()
+            .to_dyn_time()
+    }
+
+    fn state<T: TimeScale>(time: Time<T>, frame: DynFrame) -> State<T, DynOrigin, DynFrame> {
+        State::new(
+            time,
+            DVec3::new(-5530.01774359, -3487.0895338, -1850.03476185),
+            DVec3::new(1.29534407, -5.02456882, 5.6391936),
+            DynOrigin::Earth,
+            frame,
+        )
+    }
+
+    fn transform() -> DynFrameTransform {
+        FrameTransform::try_new(DynFrame::Icrf, DynFrame::Itrf, time(), None::<&DeltaUt1Tai>)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_frame_transform() {
+        let transform = transform();
+        assert_eq!(transform.time(), time());
+        assert_eq!(transform.from_frame(), DynFrame::Icrf);
+        assert_eq!(transform.to_frame(), DynFrame::Itrf);
+
+        let s0 = state(time(), DynFrame::Icrf);
+        let expected = s0
+            .try_to_frame(DynFrame::Itrf, None::<&DeltaUt1Tai>)
+            .unwrap();
+        let actual = transform.apply(&s0).unwrap();
+        assert_eq!(actual.reference_frame(), DynFrame::Itrf);
+        assert_eq!(actual.position(), expected.position());
+        assert_eq!(actual.velocity(), expected.velocity());
+        assert_eq!(
+            transform.rotate_position(s0.position()),
+            expected.position()
+        );
+    }
+
+    #[test]
+    fn test_frame_transform_roundtrip() {
+        let time = Utc::from_iso("2024-07-05T09:09:18.173").unwrap().to_time();
+        let to_itrf =
+            FrameTransform::try_new(DynFrame::Icrf, DynFrame::Itrf, time, None::<&()>).unwrap();
+        let to_icrf =
+            FrameTransform::try_new(DynFrame::Itrf, DynFrame::Icrf, time, None::<&()>).unwrap();
+        let s0: State<Tai, _, _> = state(time, DynFrame::Icrf);
+        let s1 = to_itrf.apply(&s0).unwrap();
+        let s2 = to_icrf.apply(&s1).unwrap();
+        assert_close!(s2.position(), s0.position(), 1e-8);
+        assert_close!(s2.velocity(), s0.velocity(), 1e-11);
+    }
+
+    #[test]
+    fn test_frame_transform_mismatch() {
+        let transform = transform();
+        let later = time() + TimeDelta::from_decimal_seconds(1.0);
+        assert_eq!(
+            transform.apply(&state(later, DynFrame::Icrf)),
+            Err(FrameTransformError::EpochMismatch {
+                expected: time().to_string(),
+                actual: later.to_string(),
+            })
+        );
+        assert_eq!(
+            transform.apply(&state(time(), DynFrame::Teme)),
+            Err(FrameTransformError::FrameMismatch {
+                expected: "ICRF".to_string(),
+                actual: "TEME".to_string(),
+            })
+        );
+    }
+
+    fn block(matrix: &Matrix6, row: usize, col: usize) -> DMat3 {
+        DMat3::from_cols_array_2d(&std::array::from_fn(|j| {
+            std::array::from_fn(|i| matrix[row + i][col + j])
+        }))
+    }
+
+    #[test]
+    fn test_frame_transform_covariance() {
+        let transform = transform();
+        // A correlated covariance P = L Lᵀ
+        let mut l = [[0.0; 6]; 6];
+        for (i, row) in l.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate().take(i + 1) {
+                *value = if i == j {
+                    1.0 + i as f64
+                } else {
+                    0.1 * (i + 2 * j) as f64
+                };
+            }
+        }
+        let mut matrix = [[0.0; 6]; 6];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..6).map(|k| l[i][k] * l[j][k]).sum();
+            }
+        }
+        let actual = transform
+            .rotate_covariance(&Covariance::new(matrix))
+            .matrix();
+
+        // The blocks of R P Rᵀ with R = [[M, 0], [Ṁ, M]]
+        let m = transform.rotation().position_matrix();
+        let dm = transform.rotation().velocity_matrix();
+        assert!(!m.abs_diff_eq(DMat3::IDENTITY, 1e-3));
+        assert!(!dm.abs_diff_eq(DMat3::ZERO, 1e-6));
+        let a = block(&matrix, 0, 0);
+        let b = block(&matrix, 0, 3);
+        let c = block(&matrix, 3, 3);
+        let rr = m * a * m.transpose();
+        let rv = m * a * dm.transpose() + m * b * m.transpose();
+        let vv = dm * a * dm.transpose()
+            + dm * b * m.transpose()
+            + m * b.transpose() * dm.transpose()
+            + m * c * m.transpose();
+        assert!(block(&actual, 0, 0).abs_diff_eq(rr, 1e-12));
+        assert!(block(&actual, 0, 3).abs_diff_eq(rv, 1e-12));
+        assert!(block(&actual, 3, 0).abs_diff_eq(rv.transpose(), 1e-12));
+        assert!(block(&actual, 3, 3).abs_diff_eq(vv, 1e-12));
+    }
+}
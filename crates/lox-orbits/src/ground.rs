@@ -6,16 +6,20 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::f64::consts::FRAC_PI_2;
+use std::f64::consts::{FRAC_PI_2, TAU};
 
-use crate::frames::{DynFrame, Iau, Icrf, TryRotateTo};
+use crate::frames::iers::gcrf_to_itrf;
+use crate::frames::{DynFrame, Iau, Icrf, Itrf, TryRotateTo};
 use crate::propagators::Propagator;
 use crate::states::{DynState, State};
 use crate::trajectories::{DynTrajectory, Trajectory, TrajectoryError};
 use glam::{DMat3, DVec3};
-use lox_bodies::{DynOrigin, RotationalElements, Spheroid, TrySpheroid};
+use lox_bodies::{DynOrigin, Earth, ReferenceEllipsoid, RotationalElements, Spheroid, TrySpheroid};
+use lox_earth::eop::EopProvider;
+use lox_earth::geodetic::{cartesian_to_geodetic, geodetic_to_cartesian};
+use lox_math::math::normalize_two_pi;
 use lox_math::types::units::Radians;
-use lox_time::time_scales::TryToScale;
+use lox_time::time_scales::{Tai, ToScale, TryToScale};
 use lox_time::time_scales::{Tdb, TimeScale};
 use lox_time::ut1::DeltaUt1TaiProvider;
 use lox_time::{DynTime, Time};
@@ -142,9 +146,7 @@ impl<B: TrySpheroid> GroundLocation<B> {
     }
 
     pub fn rotation_to_topocentric(&self) -> DMat3 {
-        let rot1 = DMat3::from_rotation_z(self.longitude()).transpose();
-        let rot2 = DMat3::from_rotation_y(FRAC_PI_2 - self.latitude()).transpose();
-        rot2 * rot1
+        rotation_to_topocentric(self.longitude, self.latitude)
     }
 
     pub fn observables<T: TimeScale + Clone>(&self, state: State<T, B, Iau<B>>) -> Observables
@@ -183,6 +185,79 @@ impl<B: TrySpheroid> GroundLocation<B> {
     }
 }
 
+pub(crate) fn rotation_to_topocentric(longitude: Radians, latitude: Radians) -> DMat3 {
+    let rot1 = DMat3::from_rotation_z(longitude).transpose();
+    let rot2 = DMat3::from_rotation_y(FRAC_PI_2 - latitude).transpose();
+    rot2 * rot1
+}
+
+/// Computes the azimuth, elevation, range, and range rate of `state` as observed from a station
+/// at the given geodetic latitude, longitude, and altitude above the WGS-84 ellipsoid.
+///
+/// The azimuth is measured clockwise from north in the range [0, 2π) and the elevation is
+/// negative for targets below the local horizon.
+pub fn topocentric<T: TimeScale>(
+    latitude: Radians,
+    longitude: Radians,
+    altitude: f64,
+    state: &State<T, Earth, Itrf>,
+) -> Observables {
+    let station = geodetic_to_cartesian(latitude, longitude, altitude, &ReferenceEllipsoid::WGS84);
+    // South-east-zenith
+    let rot = rotation_to_topocentric(longitude, latitude);
+    let position = rot * (state.position() - station);
+    let velocity = rot * state.velocity();
+    let range = position.length();
+    let range_rate = position.dot(velocity) / range;
+    let elevation = (position.z / range).asin();
+    let azimuth = position.y.atan2(-position.x).rem_euclid(TAU);
+    Observables {
+        azimuth,
+        elevation,
+        range,
+        range_rate,
+    }
+}
+
+/// The geodetic latitude and longitude in radians of a subsatellite point and its time.
+pub type GroundTrackPoint<T> = (Radians, Radians, Time<T>);
+
+/// Computes the ground track of `trajectory`, i.e. the geodetic latitude and longitude of the
+/// subsatellite point on the WGS-84 ellipsoid at each of its states.
+///
+/// The states are rotated to the ITRF with the Earth Orientation Parameters from `eop`. The
+/// longitude is unwrapped, such that consecutive points never differ by more than π and the
+/// track does not jump at the antimeridian. It may therefore leave the range [-π, π].
+///
+/// # Errors
+///
+/// - `P::Error` if no Earth Orientation Parameters are available for one of the states.
+pub fn ground_track<T, P>(
+    trajectory: &Trajectory<T, Earth, Icrf>,
+    eop: &P,
+) -> Result<Vec<GroundTrackPoint<T>>, P::Error>
+where
+    T: TimeScale + ToScale<Tai> + Copy,
+    P: EopProvider,
+{
+    let states = trajectory.states();
+    let mut track: Vec<GroundTrackPoint<T>> = Vec::with_capacity(states.len());
+    for state in states {
+        let time = state.time();
+        let rotation = gcrf_to_itrf(time, eop)?;
+        let (latitude, longitude, _) = cartesian_to_geodetic(
+            rotation.rotate_position(state.position()),
+            &ReferenceEllipsoid::WGS84,
+        );
+        let longitude = match track.last() {
+            Some(&(_, previous, _)) => normalize_two_pi(longitude, previous),
+            None => longitude,
+        };
+        track.push((latitude, longitude, time));
+    }
+    Ok(track)
+}
+
 #[derive(Debug, Error)]
 pub enum GroundPropagatorError {
     #[error("frame transformation error: {0}")]
@@ -268,15 +343,26 @@ where
 
 #[cfg(test)]
 mod tests {
+    use std::f64::consts::SQRT_2;
+
     use float_eq::assert_float_eq;
+    use rstest::rstest;
 
-    use lox_bodies::Earth;
     use lox_math::assert_close;
     use lox_math::is_close::IsClose;
+    use lox_math::types::units::Angle;
     use lox_time::utc::Utc;
     use lox_time::{Time, time, utc};
 
+    use itertools::Itertools;
+    use lox_earth::eop::IersEop;
+    use lox_time::deltas::TimeDelta;
+    use lox_time::utc::leap_seconds::BuiltinLeapSeconds;
+    use std::f64::consts::PI;
+
     use super::*;
+    use crate::elements::Keplerian;
+    use crate::propagators::semi_analytical::Vallado;
 
     #[test]
     fn test_ground_location_to_body_fixed() {
@@ -307,6 +393,57 @@ mod tests {
         assert_close!(rot.z_axis, expected.z_axis);
     }
 
+    #[rstest]
+    #[case::zenith(0.0, 0.0, 500.0, 90.0)]
+    #[case::north(0.1, 0.0, 0.0, 0.0)]
+    #[case::east(0.0, 0.1, 0.0, 90.0)]
+    #[case::south(-0.1, 0.0, 0.0, 180.0)]
+    #[case::west(0.0, -0.1, 0.0, 270.0)]
+    // Degrees of longitude are shorter than degrees of latitude by cos(45°)
+    #[case::north_west(0.1, -0.1 * SQRT_2, 0.0, 315.0)]
+    fn test_topocentric(
+        #[case] dlat: f64,
+        #[case] dlon: f64,
+        #[case] altitude: f64,
+        #[case] expected: f64,
+    ) {
+        let latitude = 45f64.to_radians();
+        let longitude = 10f64.to_radians();
+        let time = time!(Tdb, 2025, 1, 1).unwrap();
+        let position = geodetic_to_cartesian(
+            latitude + dlat.to_radians(),
+            longitude + dlon.to_radians(),
+            altitude,
+            &ReferenceEllipsoid::WGS84,
+        );
+        let state = State::new(time, position, DVec3::ZERO, Earth, Itrf);
+        let observables = topocentric(latitude, longitude, 0.0, &state);
+        if altitude > 0.0 {
+            assert_float_eq!(observables.elevation(), expected.to_radians(), abs <= 1e-12);
+            assert_float_eq!(observables.range(), altitude, rel <= 1e-12);
+        } else {
+            assert_float_eq!(observables.azimuth(), expected.to_radians(), abs <= 1e-2);
+            // The target lies on the ellipsoid and therefore slightly below the horizon
+            assert!(observables.elevation() < 0.0);
+        }
+    }
+
+    #[test]
+    fn test_topocentric_range_rate() {
+        let latitude = -30f64.to_radians();
+        let longitude = 120f64.to_radians();
+        let time = time!(Tdb, 2025, 1, 1).unwrap();
+        let position =
+            geodetic_to_cartesian(latitude, longitude, 1000.0, &ReferenceEllipsoid::WGS84);
+        let (lat, lon, _) = cartesian_to_geodetic(position, &ReferenceEllipsoid::WGS84);
+        // Unit vector along the local vertical
+        let zenith = DVec3::new(lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin());
+        let state = State::new(time, position, -7.0 * zenith, Earth, Itrf);
+        let observables = topocentric(latitude, longitude, 0.0, &state);
+        assert_float_eq!(observables.range_rate(), -7.0, rel <= 1e-12);
+        assert_float_eq!(observables.elevation(), FRAC_PI_2, abs <= 1e-12);
+    }
+
     #[test]
     fn test_ground_location_observables() {
         let longitude = -4f64.to_radians();
@@ -338,4 +475,46 @@ mod tests {
         let state = propagator.propagate(time).unwrap();
         assert_close!(state.position(), expected);
     }
+
+    #[test]
+    fn test_ground_track_near_polar_orbit() {
+        let eop = IersEop::new(
+            format!(
+                "{}/../../data/finals2000A.all.csv",
+                env!("CARGO_MANIFEST_DIR")
+            ),
+            &BuiltinLeapSeconds,
+        )
+        .unwrap();
+        let time = time!(Tai, 2024, 6, 1).unwrap();
+        let inclination = 97f64.to_radians();
+        let orbit = Keplerian::new(
+            time,
+            Earth,
+            Earth.equatorial_radius() + 700.0,
+            0.0,
+            Angle::from_radians(inclination),
+            Angle::from_radians(0.3),
+            Angle::ZERO,
+            Angle::ZERO,
+        );
+        let propagator = Vallado::new(orbit.to_cartesian());
+        let times = (0..=1440).map(|i| time + TimeDelta::from_seconds(60 * i));
+        let trajectory = propagator.propagate_all(times).unwrap();
+
+        let track = ground_track(&trajectory, &eop).unwrap();
+        assert_eq!(track.len(), 1441);
+        assert_eq!(track[0].2, time);
+
+        // The maximum latitude of a retrograde orbit is the supplement of its inclination
+        let max_latitude = track.iter().map(|p| p.0.abs()).fold(0.0, f64::max);
+        assert_float_eq!(max_latitude, PI - inclination, abs <= 0.5f64.to_radians());
+
+        // The unwrapped longitude drifts continuously over many revolutions of the Earth
+        for (p0, p1) in track.iter().tuple_windows() {
+            assert!((p1.1 - p0.1).abs() < 30f64.to_radians());
+        }
+        let (first, last) = (track.first().unwrap().1, track.last().unwrap().1);
+        assert!((last - first).abs() > TAU);
+    }
 }
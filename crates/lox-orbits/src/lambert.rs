@@ -0,0 +1,426 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Solver for Lambert's problem based on Izzo, D. (2015), "Revisiting
+//! Lambert's problem", Celestial Mechanics and Dynamical Astronomy, 121(1).
+
+use std::f64::consts::PI;
+
+use glam::DVec3;
+use thiserror::Error;
+
+const MAX_ITERATIONS: usize = 35;
+const ABS_TOL: f64 = 1e-12;
+const REL_TOL: f64 = 1e-12;
+
+/// Upper bound for the number of terms of the hypergeometric series. Within the range of Battin's
+/// method, the argument of the series is bounded by about 0.4 in magnitude and it converges to
+/// machine precision within a few dozen terms.
+const MAX_SERIES_TERMS: usize = 10_000;
+
+/// Minimum norm of the cross product of the unit position vectors below
+/// which the transfer plane is considered to be undefined.
+const COLLINEARITY_TOL: f64 = 1e-12;
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum LambertError {
+    #[error("the time of flight must be positive but was {0}")]
+    InvalidTimeOfFlight(f64),
+    #[error("the position vectors are collinear and do not define a transfer plane")]
+    CollinearPositions,
+    #[error(
+        "no solution with {revolutions} revolutions exists, the maximum for this time of flight is {max_revolutions}"
+    )]
+    TooManyRevolutions {
+        revolutions: u32,
+        max_revolutions: u32,
+    },
+    #[error("the solver did not converge")]
+    NotConverged,
+}
+
+/// Selects one of the two solutions of the multi-revolution Lambert problem
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LambertPath {
+    #[default]
+    Low,
+    High,
+}
+
+/// Computes the velocities at `r1` and `r2` of the conic that connects both
+/// positions in the time of flight `tof` around a central body with the
+/// gravitational parameter `mu`.
+///
+/// For `revolutions > 0` two solutions exist which are selected by `path`.
+/// For single-revolution transfers `path` is ignored. Transfers between
+/// collinear position vectors, e.g. 180° transfers, are rejected because
+/// the transfer plane is undefined.
+pub fn lambert(
+    r1: DVec3,
+    r2: DVec3,
+    tof: f64,
+    mu: f64,
+    prograde: bool,
+    revolutions: u32,
+    path: LambertPath,
+) -> Result<(DVec3, DVec3), LambertError> {
+    if tof <= 0.0 || !tof.is_finite() {
+        return Err(LambertError::InvalidTimeOfFlight(tof));
+    }
+
+    let c = r2 - r1;
+    let c_norm = c.length();
+    let r1_norm = r1.length();
+    let r2_norm = r2.length();
+    let s = (r1_norm + r2_norm + c_norm) / 2.0;
+
+    let i_r1 = r1 / r1_norm;
+    let i_r2 = r2 / r2_norm;
+    let i_h = i_r1.cross(i_r2);
+    if i_h.length() < COLLINEARITY_TOL {
+        return Err(LambertError::CollinearPositions);
+    }
+    let i_h = i_h.normalize();
+
+    let mut lambda = (1.0 - (c_norm / s).min(1.0)).sqrt();
+    let (mut i_t1, mut i_t2) = if i_h.z < 0.0 {
+        lambda = -lambda;
+        (i_r1.cross(i_h), i_r2.cross(i_h))
+    } else {
+        (i_h.cross(i_r1), i_h.cross(i_r2))
+    };
+    if !prograde {
+        lambda = -lambda;
+        i_t1 = -i_t1;
+        i_t2 = -i_t2;
+    }
+
+    let t = (2.0 * mu / s.powi(3)).sqrt() * tof;
+    let (x, y) = find_xy(lambda, t, revolutions, path)?;
+
+    let gamma = (mu * s / 2.0).sqrt();
+    let rho = (r1_norm - r2_norm) / c_norm;
+    let sigma = (1.0 - rho.powi(2)).sqrt();
+
+    let v_r1 = gamma * ((lambda * y - x) - rho * (lambda * y + x)) / r1_norm;
+    let v_r2 = -gamma * ((lambda * y - x) + rho * (lambda * y + x)) / r2_norm;
+    let v_t1 = gamma * sigma * (y + lambda * x) / r1_norm;
+    let v_t2 = gamma * sigma * (y + lambda * x) / r2_norm;
+
+    let v1 = v_r1 * i_r1 + v_t1 * i_t1;
+    let v2 = v_r2 * i_r2 + v_t2 * i_t2;
+    Ok((v1, v2))
+}
+
+fn find_xy(
+    lambda: f64,
+    t: f64,
+    revolutions: u32,
+    path: LambertPath,
+) -> Result<(f64, f64), LambertError> {
+    let mut max_revolutions = (t / PI).floor();
+    let t_00 = lambda.acos() + lambda * (1.0 - lambda.powi(2)).sqrt();
+    if max_revolutions > 0.0 && t < t_00 + max_revolutions * PI {
+        let t_min = compute_t_min(lambda, max_revolutions)?;
+        if t < t_min {
+            max_revolutions -= 1.0;
+        }
+    }
+    let m = revolutions as f64;
+    if m > max_revolutions {
+        return Err(LambertError::TooManyRevolutions {
+            revolutions,
+            max_revolutions: max_revolutions as u32,
+        });
+    }
+
+    let x0 = initial_guess(t, lambda, m, path);
+    let x = householder(x0, t, lambda, m)?;
+    Ok((x, compute_y(x, lambda)))
+}
+
+fn compute_y(x: f64, lambda: f64) -> f64 {
+    (1.0 - lambda.powi(2) * (1.0 - x.powi(2))).sqrt()
+}
+
+fn compute_psi(x: f64, y: f64, lambda: f64) -> f64 {
+    if (-1.0..1.0).contains(&x) {
+        (x * y + lambda * (1.0 - x.powi(2))).acos()
+    } else if x > 1.0 {
+        ((y - x * lambda) * (x.powi(2) - 1.0).sqrt()).asinh()
+    } else {
+        0.0
+    }
+}
+
+/// Gauss hypergeometric function 2F1(3, 1, 5/2, x) for `x < 1`
+///
+/// Returns [LambertError::NotConverged] if `x` is NaN or the series does not converge within
+/// [MAX_SERIES_TERMS] terms.
+fn hyp2f1b(x: f64) -> Result<f64, LambertError> {
+    if x >= 1.0 {
+        return Ok(f64::INFINITY);
+    }
+    let mut result = 1.0;
+    let mut term = 1.0;
+    for i in 0..MAX_SERIES_TERMS {
+        let i = i as f64;
+        term *= (3.0 + i) * (1.0 + i) / (2.5 + i) * x / (i + 1.0);
+        let previous = result;
+        result += term;
+        if !result.is_finite() {
+            break;
+        }
+        if previous == result {
+            return Ok(result);
+        }
+    }
+    Err(LambertError::NotConverged)
+}
+
+fn tof_equation(x: f64, t0: f64, lambda: f64, m: f64) -> Result<f64, LambertError> {
+    tof_equation_y(x, compute_y(x, lambda), t0, lambda, m)
+}
+
+fn tof_equation_y(x: f64, y: f64, t0: f64, lambda: f64, m: f64) -> Result<f64, LambertError> {
+    let t = if m == 0.0 && 0.6f64.sqrt() < x && x < 1.4f64.sqrt() {
+        // Battin's series avoids the loss of precision close to parabolic orbits
+        let eta = y - lambda * x;
+        let s1 = (1.0 - lambda - x * eta) * 0.5;
+        let q = 4.0 / 3.0 * hyp2f1b(s1)?;
+        (eta.powi(3) * q + 4.0 * lambda * eta) * 0.5
+    } else {
+        let psi = compute_psi(x, y, lambda);
+        ((psi + m * PI) / (1.0 - x.powi(2)).abs().sqrt() - x + lambda * y) / (1.0 - x.powi(2))
+    };
+    Ok(t - t0)
+}
+
+fn tof_derivatives(x: f64, y: f64, t: f64, lambda: f64) -> (f64, f64, f64) {
+    let d1 = (3.0 * t * x - 2.0 + 2.0 * lambda.powi(3) * x / y) / (1.0 - x.powi(2));
+    let d2 = (3.0 * t + 5.0 * x * d1 + 2.0 * (1.0 - lambda.powi(2)) * lambda.powi(3) / y.powi(3))
+        / (1.0 - x.powi(2));
+    let d3 = (7.0 * x * d2 + 8.0 * d1
+        - 6.0 * (1.0 - lambda.powi(2)) * lambda.powi(5) * x / y.powi(5))
+        / (1.0 - x.powi(2));
+    (d1, d2, d3)
+}
+
+fn compute_t_min(lambda: f64, m: f64) -> Result<f64, LambertError> {
+    if lambda == 1.0 {
+        return tof_equation(0.0, 0.0, lambda, m);
+    }
+    // Halley iterations for the minimum of the time of flight curve
+    let mut x0 = 0.1;
+    let mut t0 = tof_equation(x0, 0.0, lambda, m)?;
+    for _ in 0..MAX_ITERATIONS {
+        let y = compute_y(x0, lambda);
+        let (d1, d2, d3) = tof_derivatives(x0, y, t0, lambda);
+        if d2 == 0.0 {
+            return Err(LambertError::NotConverged);
+        }
+        let x = x0 - 2.0 * d1 * d2 / (2.0 * d2.powi(2) - d1 * d3);
+        if (x - x0).abs() < REL_TOL * x0.abs() + ABS_TOL {
+            return tof_equation(x, 0.0, lambda, m);
+        }
+        x0 = x;
+        t0 = tof_equation(x0, 0.0, lambda, m)?;
+    }
+    Err(LambertError::NotConverged)
+}
+
+fn initial_guess(t: f64, lambda: f64, m: f64, path: LambertPath) -> f64 {
+    if m == 0.0 {
+        let t_0 = lambda.acos() + lambda * (1.0 - lambda.powi(2)).sqrt();
+        let t_1 = 2.0 * (1.0 - lambda.powi(3)) / 3.0;
+        if t >= t_0 {
+            (t_0 / t).powf(2.0 / 3.0) - 1.0
+        } else if t < t_1 {
+            2.5 * t_1 / t * (t_1 - t) / (1.0 - lambda.powi(5)) + 1.0
+        } else {
+            (2f64.ln() * (t / t_0).ln() / (t_1 / t_0).ln()).exp() - 1.0
+        }
+    } else {
+        let a = ((m * PI + PI) / (8.0 * t)).powf(2.0 / 3.0);
+        let x0_left = (a - 1.0) / (a + 1.0);
+        let b = ((8.0 * t) / (m * PI)).powf(2.0 / 3.0);
+        let x0_right = (b - 1.0) / (b + 1.0);
+        match path {
+            LambertPath::Low => x0_left.max(x0_right),
+            LambertPath::High => x0_left.min(x0_right),
+        }
+    }
+}
+
+fn householder(mut x0: f64, t0: f64, lambda: f64, m: f64) -> Result<f64, LambertError> {
+    for _ in 0..MAX_ITERATIONS {
+        let y = compute_y(x0, lambda);
+        let f = tof_equation_y(x0, y, t0, lambda, m)?;
+        let t = f + t0;
+        let (d1, d2, d3) = tof_derivatives(x0, y, t, lambda);
+        let x = x0
+            - f * ((d1.powi(2) - f * d2 / 2.0)
+                / (d1 * (d1.powi(2) - f * d2) + d3 * f.powi(2) / 6.0));
+        if !x.is_finite() {
+            return Err(LambertError::NotConverged);
+        }
+        if (x - x0).abs() < REL_TOL * x0.abs() + ABS_TOL {
+            return Ok(x);
+        }
+        x0 = x;
+    }
+    Err(LambertError::NotConverged)
+}
+
+#[cfg(test)]
+mod tests {
+    use lox_bodies::{PointMass, Sun};
+    use lox_ephem::Ephemeris;
+    use lox_ephem::spk::parser::{Spk, parse_daf_spk};
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+    use lox_time::julian_dates::JulianDate;
+    use lox_time::time_scales::Tdb;
+    use lox_time::{Time, time};
+
+    use crate::anomalies::mean_to_true;
+    use crate::elements::KeplerianElements;
+    use crate::frames::Icrf;
+    use crate::propagators::Propagator;
+    use crate::propagators::semi_analytical::Vallado;
+    use crate::states::State;
+
+    use super::*;
+
+    const MU_EARTH: f64 = 398600.4418;
+
+    // Vallado, Fundamentals of Astrodynamics and Applications, 4th ed., Example 7-5
+    #[test]
+    fn test_lambert_vallado() {
+        let r1 = DVec3::new(15945.34, 0.0, 0.0);
+        let r2 = DVec3::new(12214.83899, 10249.46731, 0.0);
+        let tof = 76.0 * 60.0;
+        let (v1, v2) = lambert(r1, r2, tof, MU_EARTH, true, 0, LambertPath::Low).unwrap();
+        assert_close!(v1, DVec3::new(2.058913, 2.915965, 0.0), 1e-6);
+        assert_close!(v2, DVec3::new(-3.451565, 0.910315, 0.0), 1e-6);
+    }
+
+    fn heliocentric_position(spk: &Spk, epoch: f64, target: &[i32]) -> DVec3 {
+        let sun: DVec3 = spk.position(epoch, 0, 10).unwrap().into();
+        let position: DVec3 = target
+            .windows(2)
+            .map(|ids| DVec3::from(spk.position(epoch, ids[0], ids[1]).unwrap()))
+            .sum();
+        position - sun
+    }
+
+    /// Propagates an elliptic orbit analytically by advancing the mean anomaly
+    fn kepler_position(r: DVec3, v: DVec3, mu: f64, dt: f64) -> DVec3 {
+        let mut elements = KeplerianElements::from_cartesian(r, v, mu);
+        let e = elements.eccentricity;
+        let eccentric_anomaly =
+            2.0 * (((1.0 - e) / (1.0 + e)).sqrt() * (elements.true_anomaly / 2.0).tan()).atan();
+        let mean_anomaly = eccentric_anomaly - e * eccentric_anomaly.sin()
+            + (mu / elements.semi_major_axis.powi(3)).sqrt() * dt;
+        elements.true_anomaly = mean_to_true(mean_anomaly, e);
+        elements.to_cartesian(mu).0
+    }
+
+    // Mars Reconnaissance Orbiter, launched 2005-08-12 and arrived 2006-03-10
+    #[test]
+    fn test_lambert_earth_to_mars() {
+        let contents = std::fs::read(format!(
+            "{}/../../data/de440s.bsp",
+            env!("CARGO_MANIFEST_DIR")
+        ))
+        .unwrap();
+        let spk = parse_daf_spk(&contents).unwrap();
+        let t0: Time<Tdb> = time!(Tdb, 2005, 8, 12).unwrap();
+        let t1: Time<Tdb> = time!(Tdb, 2006, 3, 10).unwrap();
+        let tof = (t1 - t0).to_decimal_seconds();
+        let r_earth = heliocentric_position(&spk, t0.seconds_since_j2000(), &[0, 3, 399]);
+        let r_mars = heliocentric_position(&spk, t1.seconds_since_j2000(), &[0, 4]);
+
+        let mu = Sun.gravitational_parameter();
+        let (v1, v2) = lambert(r_earth, r_mars, tof, mu, true, 0, LambertPath::Low).unwrap();
+        // The heliocentric velocities of the transfer orbit are close to the
+        // orbital velocities of Earth and Mars
+        assert!((28.0..35.0).contains(&v1.length()));
+        assert!((19.0..27.0).contains(&v2.length()));
+
+        let state = State::new(t0, r_earth, v1, Sun, Icrf);
+        let propagated = Vallado::new(state).propagate(t1).unwrap();
+        assert_close!(propagated.position(), r_mars, 1e-2);
+
+        // The reverse transfer recovers the same conic
+        let (v1_rev, v2_rev) =
+            lambert(r_mars, r_earth, tof, mu, false, 0, LambertPath::Low).unwrap();
+        assert_close!(v1_rev, -v2, 1e-8);
+        assert_close!(v2_rev, -v1, 1e-8);
+    }
+
+    #[test]
+    fn test_lambert_multi_revolution() {
+        let r1 = DVec3::new(7000.0, 0.0, 0.0);
+        let r2 = DVec3::new(0.0, 8000.0, 1000.0);
+        let tof = 5.0 * 3600.0;
+        let (low, _) = lambert(r1, r2, tof, MU_EARTH, true, 1, LambertPath::Low).unwrap();
+        let (high, _) = lambert(r1, r2, tof, MU_EARTH, true, 1, LambertPath::High).unwrap();
+        assert!(!low.is_close_abs(&high, 1e-3));
+        for v1 in [low, high] {
+            assert_close!(kepler_position(r1, v1, MU_EARTH, tof), r2, 1e-6);
+        }
+
+        let (v1, _) = lambert(r1, r2, tof, MU_EARTH, true, 3, LambertPath::High).unwrap();
+        assert_close!(kepler_position(r1, v1, MU_EARTH, tof), r2, 1e-6);
+
+        assert_eq!(
+            lambert(r1, r2, tof, MU_EARTH, true, 10, LambertPath::Low),
+            Err(LambertError::TooManyRevolutions {
+                revolutions: 10,
+                max_revolutions: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_hyp2f1b() {
+        assert_eq!(hyp2f1b(0.0), Ok(1.0));
+        // 2F1(3, 1, 5/2, x) = 1 + 6/5 x + ... for small x
+        assert_close!(hyp2f1b(1e-8).unwrap(), 1.0 + 1.2e-8, 1e-15);
+        assert!(hyp2f1b(0.9).unwrap().is_finite());
+        assert_eq!(hyp2f1b(1.0), Ok(f64::INFINITY));
+        assert_eq!(hyp2f1b(f64::NAN), Err(LambertError::NotConverged));
+        assert_eq!(hyp2f1b(1.0 - f64::EPSILON), Err(LambertError::NotConverged));
+    }
+
+    #[test]
+    fn test_lambert_errors() {
+        let r1 = DVec3::new(7000.0, 0.0, 0.0);
+        assert_eq!(
+            lambert(r1, -r1, 3600.0, MU_EARTH, true, 0, LambertPath::Low),
+            Err(LambertError::CollinearPositions)
+        );
+        assert_eq!(
+            lambert(r1, 2.0 * r1, 3600.0, MU_EARTH, true, 0, LambertPath::Low),
+            Err(LambertError::CollinearPositions)
+        );
+        assert_eq!(
+            lambert(
+                r1,
+                DVec3::Y * 7000.0,
+                0.0,
+                MU_EARTH,
+                true,
+                0,
+                LambertPath::Low
+            ),
+            Err(LambertError::InvalidTimeOfFlight(0.0))
+        );
+    }
+}
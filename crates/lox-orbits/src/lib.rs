@@ -7,16 +7,23 @@
  */
 
 pub use glam::DVec3;
+pub use lox_math::linear_algebra::{Matrix3, Vector3};
 
 pub mod analysis;
 pub mod anomalies;
+pub mod conjunction;
+pub mod covariance;
 pub mod elements;
+pub mod ephemeris;
+pub mod estimation;
 pub mod events;
 pub mod frames;
 pub mod ground;
+pub mod lambert;
 pub mod propagators;
 #[cfg(feature = "python")]
 pub mod python;
 pub mod rotations;
 pub mod states;
+pub mod tle;
 pub mod trajectories;
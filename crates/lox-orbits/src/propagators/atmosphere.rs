@@ -0,0 +1,323 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module atmosphere provides models of the density of the Earth's atmosphere for the computation
+//! of atmospheric drag.
+
+use glam::DVec3;
+use lox_bodies::ellipsoid::ReferenceEllipsoid;
+use lox_earth::geodetic::cartesian_to_geodetic;
+use lox_math::constants::f64::time::SECONDS_PER_JULIAN_CENTURY;
+
+/// Implementers of `AtmosphericDensity` provide the density of the Earth's atmosphere.
+pub trait AtmosphericDensity: Send + Sync {
+    /// Returns the density in kg/m³ at `t` seconds since J2000 for the position `r` in km with
+    /// respect to the Earth.
+    fn atmospheric_density(&self, t: f64, r: DVec3) -> f64;
+}
+
+/// Returns the height in km of `r` above the WGS-84 ellipsoid.
+///
+/// The height does not depend on the longitude, such that the difference between the equator of
+/// the inertial frame and the true equator of date can be neglected.
+fn geodetic_height(r: DVec3) -> f64 {
+    cartesian_to_geodetic(r, &ReferenceEllipsoid::WGS84).2
+}
+
+/// The base altitude in km, nominal density in kg/m³, and scale height in km of the exponential
+/// atmosphere model from Vallado (2013), table 8-4.
+const EXPONENTIAL_ATMOSPHERE: [(f64, f64, f64); 28] = [
+    (0.0, 1.225, 7.249),
+    (25.0, 3.899e-2, 6.349),
+    (30.0, 1.774e-2, 6.682),
+    (40.0, 3.972e-3, 7.554),
+    (50.0, 1.057e-3, 8.382),
+    (60.0, 3.206e-4, 7.714),
+    (70.0, 8.770e-5, 6.549),
+    (80.0, 1.905e-5, 5.799),
+    (90.0, 3.396e-6, 5.382),
+    (100.0, 5.297e-7, 5.877),
+    (110.0, 9.661e-8, 7.263),
+    (120.0, 2.438e-8, 9.473),
+    (130.0, 8.484e-9, 12.636),
+    (140.0, 3.845e-9, 16.149),
+    (150.0, 2.070e-9, 22.523),
+    (180.0, 5.464e-10, 29.740),
+    (200.0, 2.789e-10, 37.105),
+    (250.0, 7.248e-11, 45.546),
+    (300.0, 2.418e-11, 53.628),
+    (350.0, 9.518e-12, 53.298),
+    (400.0, 3.725e-12, 58.515),
+    (450.0, 1.585e-12, 60.828),
+    (500.0, 6.967e-13, 63.822),
+    (600.0, 1.454e-13, 71.835),
+    (700.0, 3.614e-14, 88.667),
+    (800.0, 1.170e-14, 124.64),
+    (900.0, 5.245e-15, 181.05),
+    (1000.0, 3.019e-15, 268.00),
+];
+
+/// The exponential atmosphere model, which assumes a static and spherically symmetric atmosphere
+/// whose density decays exponentially within each band of the table of scale heights.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ExponentialAtmosphere;
+
+impl ExponentialAtmosphere {
+    /// Returns the density in kg/m³ at `altitude` in km.
+    ///
+    /// Altitudes above 1000 km use the scale height of the highest band and the density at
+    /// negative altitudes is the density at sea level.
+    pub fn density(&self, altitude: f64) -> f64 {
+        let altitude = altitude.max(0.0);
+        let i = EXPONENTIAL_ATMOSPHERE.partition_point(|&(h0, _, _)| h0 <= altitude) - 1;
+        let (h0, rho0, scale_height) = EXPONENTIAL_ATMOSPHERE[i];
+        rho0 * (-(altitude - h0) / scale_height).exp()
+    }
+}
+
+impl AtmosphericDensity for ExponentialAtmosphere {
+    fn atmospheric_density(&self, _t: f64, r: DVec3) -> f64 {
+        self.density(geodetic_height(r))
+    }
+}
+
+/// The lower and upper limits in km of the Harris-Priester model.
+const HARRIS_PRIESTER_LIMITS: (f64, f64) = (100.0, 1000.0);
+
+/// The altitude in km and the minimum and maximum density in kg/m³ of the Harris-Priester model
+/// for mean solar activity from Montenbruck & Gill (2000), table 3.8.
+const HARRIS_PRIESTER: [(f64, f64, f64); 50] = [
+    (100.0, 4.974e-7, 4.974e-7),
+    (120.0, 2.490e-8, 2.490e-8),
+    (130.0, 8.377e-9, 8.710e-9),
+    (140.0, 3.899e-9, 4.059e-9),
+    (150.0, 2.122e-9, 2.215e-9),
+    (160.0, 1.263e-9, 1.344e-9),
+    (170.0, 8.008e-10, 8.758e-10),
+    (180.0, 5.283e-10, 6.010e-10),
+    (190.0, 3.617e-10, 4.297e-10),
+    (200.0, 2.557e-10, 3.162e-10),
+    (210.0, 1.839e-10, 2.396e-10),
+    (220.0, 1.341e-10, 1.853e-10),
+    (230.0, 9.949e-11, 1.455e-10),
+    (240.0, 7.488e-11, 1.157e-10),
+    (250.0, 5.709e-11, 9.308e-11),
+    (260.0, 4.403e-11, 7.555e-11),
+    (270.0, 3.430e-11, 6.182e-11),
+    (280.0, 2.697e-11, 5.095e-11),
+    (290.0, 2.139e-11, 4.226e-11),
+    (300.0, 1.708e-11, 3.526e-11),
+    (320.0, 1.099e-11, 2.511e-11),
+    (340.0, 7.214e-12, 1.819e-11),
+    (360.0, 4.824e-12, 1.337e-11),
+    (380.0, 3.274e-12, 9.955e-12),
+    (400.0, 2.249e-12, 7.492e-12),
+    (420.0, 1.558e-12, 5.684e-12),
+    (440.0, 1.091e-12, 4.355e-12),
+    (460.0, 7.701e-13, 3.362e-12),
+    (480.0, 5.474e-13, 2.612e-12),
+    (500.0, 3.916e-13, 2.042e-12),
+    (520.0, 2.819e-13, 1.605e-12),
+    (540.0, 2.042e-13, 1.267e-12),
+    (560.0, 1.488e-13, 1.005e-12),
+    (580.0, 1.092e-13, 7.997e-13),
+    (600.0, 8.070e-14, 6.390e-13),
+    (620.0, 6.012e-14, 5.123e-13),
+    (640.0, 4.519e-14, 4.121e-13),
+    (660.0, 3.430e-14, 3.325e-13),
+    (680.0, 2.632e-14, 2.691e-13),
+    (700.0, 2.043e-14, 2.185e-13),
+    (720.0, 1.607e-14, 1.779e-13),
+    (740.0, 1.281e-14, 1.452e-13),
+    (760.0, 1.036e-14, 1.190e-13),
+    (780.0, 8.496e-15, 9.776e-14),
+    (800.0, 7.069e-15, 8.059e-14),
+    (840.0, 5.070e-15, 5.741e-14),
+    (880.0, 3.790e-15, 4.210e-14),
+    (920.0, 2.923e-15, 3.209e-14),
+    (960.0, 2.299e-15, 2.545e-14),
+    (1000.0, 1.826e-15, 2.096e-14),
+];
+
+/// The lag of the apex of the diurnal bulge behind the Sun in right ascension, i.e. 30°.
+const HARRIS_PRIESTER_LAG: f64 = std::f64::consts::PI / 6.0;
+
+/// The Harris-Priester atmosphere model, which accounts for the diurnal density bulge caused by
+/// solar heating.
+///
+/// The density is interpolated between the minimum and maximum density at each altitude depending
+/// on the angle between the position and the apex of the bulge, which lags behind the Sun by 30°
+/// in right ascension. It is zero outside of the range from 100 km to 1000 km.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HarrisPriester {
+    exponent: f64,
+}
+
+impl Default for HarrisPriester {
+    fn default() -> Self {
+        Self { exponent: 2.0 }
+    }
+}
+
+impl HarrisPriester {
+    /// Creates the model with the default cosine exponent of 2 for low-inclination orbits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the exponent of the cosine of the angle to the bulge, which ranges from 2 for
+    /// low-inclination orbits to 6 for polar orbits.
+    pub fn with_exponent(mut self, exponent: f64) -> Self {
+        self.exponent = exponent;
+        self
+    }
+
+    pub fn exponent(&self) -> f64 {
+        self.exponent
+    }
+
+    /// Returns the density in kg/m³ for the position `r` in km with respect to the Earth and the
+    /// direction of the Sun `sun`, both given in an Earth-centred equatorial frame.
+    pub fn density(&self, r: DVec3, sun: DVec3) -> f64 {
+        let altitude = geodetic_height(r);
+        let (lower, upper) = HARRIS_PRIESTER_LIMITS;
+        if !(lower..upper).contains(&altitude) {
+            return 0.0;
+        }
+        let i = HARRIS_PRIESTER.partition_point(|&(h, _, _)| h <= altitude) - 1;
+        let (h0, min0, max0) = HARRIS_PRIESTER[i];
+        let (h1, min1, max1) = HARRIS_PRIESTER[i + 1];
+        let min = min0 * (min1 / min0).powf((altitude - h0) / (h1 - h0));
+        let max = max0 * (max1 / max0).powf((altitude - h0) / (h1 - h0));
+
+        let right_ascension = sun.y.atan2(sun.x) + HARRIS_PRIESTER_LAG;
+        let declination = (sun.z / sun.length()).asin();
+        let apex = DVec3::new(
+            declination.cos() * right_ascension.cos(),
+            declination.cos() * right_ascension.sin(),
+            declination.sin(),
+        );
+        // The cosine of half the angle between the position and the apex squared
+        let cos_half_angle_squared = 0.5 + 0.5 * r.dot(apex) / r.length();
+        min + (max - min) * cos_half_angle_squared.powf(self.exponent / 2.0)
+    }
+}
+
+impl AtmosphericDensity for HarrisPriester {
+    fn atmospheric_density(&self, t: f64, r: DVec3) -> f64 {
+        self.density(r, sun_direction(t))
+    }
+}
+
+/// Returns the unit vector from the Earth to the Sun in the equatorial frame at `t` seconds since
+/// J2000 from the low-precision solar coordinates of Montenbruck & Gill (2000), section 3.3.2.
+fn sun_direction(t: f64) -> DVec3 {
+    let centuries = t / SECONDS_PER_JULIAN_CENTURY;
+    let mean_anomaly = (357.5256 + 35999.049 * centuries).to_radians();
+    let longitude = (282.9400
+        + mean_anomaly.to_degrees()
+        + (6892.0 * mean_anomaly.sin() + 72.0 * (2.0 * mean_anomaly).sin()) / 3600.0)
+        .to_radians();
+    let obliquity = 23.43929111_f64.to_radians();
+    DVec3::new(
+        longitude.cos(),
+        longitude.sin() * obliquity.cos(),
+        longitude.sin() * obliquity.sin(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    // Geodetic heights above the poles are exact which keeps the tabulated values recoverable
+    const POLAR_RADIUS: f64 = 6356.752314245179;
+
+    #[test]
+    fn test_exponential_atmosphere_reference_heights() {
+        for (h0, rho0, _) in EXPONENTIAL_ATMOSPHERE {
+            assert_float_eq!(ExponentialAtmosphere.density(h0), rho0, rel <= 1e-15);
+            let r = DVec3::new(0.0, 0.0, POLAR_RADIUS + h0);
+            assert_float_eq!(
+                ExponentialAtmosphere.atmospheric_density(0.0, r),
+                rho0,
+                rel <= 1e-9
+            );
+        }
+    }
+
+    #[rstest]
+    #[case(ExponentialAtmosphere.density(-10.0), 1.225)]
+    #[case(ExponentialAtmosphere.density(425.0), 3.725e-12 * (-25.0 / 58.515f64).exp())]
+    #[case(ExponentialAtmosphere.density(1100.0), 3.019e-15 * (-100.0 / 268.0f64).exp())]
+    fn test_exponential_atmosphere(#[case] actual: f64, #[case] expected: f64) {
+        assert_float_eq!(actual, expected, rel <= 1e-14);
+    }
+
+    #[test]
+    fn test_exponential_atmosphere_monotonic() {
+        let densities: Vec<f64> = (0..=1500)
+            .map(|h| ExponentialAtmosphere.density(h as f64))
+            .collect();
+        assert!(densities.windows(2).all(|w| w[1] < w[0]));
+    }
+
+    #[test]
+    fn test_harris_priester_reference_heights() {
+        let model = HarrisPriester::new();
+        // With the Sun above the north pole, the apex of the bulge is above the north pole as well
+        let sun = DVec3::Z;
+        for (h, min, max) in HARRIS_PRIESTER.iter().take(HARRIS_PRIESTER.len() - 1) {
+            let r = (POLAR_RADIUS + h) * DVec3::Z;
+            assert_float_eq!(model.density(r, sun), *max, rel <= 1e-9);
+            assert_float_eq!(model.density(-r, sun), *min, rel <= 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_harris_priester() {
+        let model = HarrisPriester::new();
+        let sun = DVec3::new(1.0, 1.0, 0.5);
+        assert_eq!(model.density((POLAR_RADIUS + 99.0) * DVec3::Z, sun), 0.0);
+        assert_eq!(model.density((POLAR_RADIUS + 1000.0) * DVec3::Z, sun), 0.0);
+
+        let direction = DVec3::new(-0.3, 0.8, 0.2).normalize();
+        let densities: Vec<f64> = (130..990)
+            .map(|h| model.density((POLAR_RADIUS + h as f64) * direction, sun))
+            .collect();
+        assert!(densities.windows(2).all(|w| w[1] < w[0]));
+
+        // The density between the reference heights is interpolated exponentially
+        let r = (POLAR_RADIUS + 410.0) * DVec3::NEG_Z;
+        assert_float_eq!(
+            model.density(r, DVec3::Z),
+            (2.249e-12f64 * 1.558e-12).sqrt(),
+            rel <= 1e-9
+        );
+
+        // A higher exponent narrows the bulge
+        let r = (POLAR_RADIUS + 400.0) * DVec3::Y;
+        let polar = HarrisPriester::new().with_exponent(6.0);
+        assert_eq!(polar.exponent(), 6.0);
+        assert!(polar.density(r, sun) < model.density(r, sun));
+    }
+
+    #[test]
+    fn test_sun_direction() {
+        // The Sun is close to the vernal equinox on 2000-03-20T07:35 TT
+        let t = (79.0 * 24.0 - 12.0 + 7.0 + 35.0 / 60.0) * 3600.0;
+        let sun = sun_direction(t);
+        assert_float_eq!(sun.length(), 1.0, abs <= 1e-15);
+        assert_float_eq!(sun.x, 1.0, abs <= 1e-6);
+        assert_float_eq!(sun.y, 0.0, abs <= 1e-3);
+        assert_float_eq!(sun.z, 0.0, abs <= 1e-3);
+    }
+}
@@ -0,0 +1,212 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use glam::DVec3;
+use lox_bodies::PointMass;
+use lox_time::deltas::TimeDelta;
+use lox_time::time_scales::TimeScale;
+
+use crate::frames::Icrf;
+use crate::states::{LocalOrbitalFrame, State};
+
+/// For each LVLH axis, the index of the corresponding RTN axis and the sign relating the two,
+/// i.e. x = T, y = -N, z = -R.
+const LVLH_FROM_RTN: [(usize, f64); 3] = [(1, 1.0), (2, -1.0), (0, -1.0)];
+
+/// Closed-form propagator for the motion of a deputy spacecraft relative to a chief on a circular
+/// orbit, based on the Clohessy-Wiltshire equations.
+///
+/// Relative states are expressed in the rotating LVLH frame of the chief as defined by
+/// [LocalOrbitalFrame::Lvlh], i.e. x is along-track, y is opposite to the orbital angular
+/// momentum, and z points to the nadir.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClohessyWiltshire {
+    mean_motion: f64,
+    position: DVec3,
+    velocity: DVec3,
+}
+
+impl ClohessyWiltshire {
+    /// Creates a new propagator for a chief with `mean_motion` in rad/s and the initial relative
+    /// `position` in km and `velocity` in km/s of the deputy in the chief's LVLH frame.
+    pub fn new(mean_motion: f64, position: DVec3, velocity: DVec3) -> Self {
+        Self {
+            mean_motion,
+            position,
+            velocity,
+        }
+    }
+
+    /// Creates a new propagator from the inertial states of the `chief` and the `deputy`.
+    ///
+    /// The mean motion is derived from the current radius of the chief, which is assumed to be on
+    /// a circular orbit.
+    pub fn from_states<T, O>(chief: &State<T, O, Icrf>, deputy: &State<T, O, Icrf>) -> Self
+    where
+        T: TimeScale,
+        O: PointMass + Clone,
+    {
+        let mu = chief.origin().gravitational_parameter();
+        let mean_motion = (mu / chief.position().length().powi(3)).sqrt();
+        let (position, velocity) = chief.relative_local(deputy, LocalOrbitalFrame::Lvlh);
+        Self::new(mean_motion, position, velocity)
+    }
+
+    pub fn mean_motion(&self) -> f64 {
+        self.mean_motion
+    }
+
+    pub fn position(&self) -> DVec3 {
+        self.position
+    }
+
+    pub fn velocity(&self) -> DVec3 {
+        self.velocity
+    }
+
+    /// Returns the 6x6 state transition matrix which maps the relative LVLH state
+    /// (x, y, z, vx, vy, vz) at the initial epoch to the relative state after `dt`.
+    ///
+    /// The matrix is row-major, i.e. `stm[i][j]` is the partial derivative of the i-th component
+    /// of the final state with respect to the j-th component of the initial state.
+    pub fn stm(&self, dt: TimeDelta) -> [[f64; 6]; 6] {
+        let rtn = rtn_stm(self.mean_motion, dt.to_decimal_seconds());
+        let mut stm = [[0.0; 6]; 6];
+        for i in 0..6 {
+            let (k_i, s_i) = LVLH_FROM_RTN[i % 3];
+            for j in 0..6 {
+                let (k_j, s_j) = LVLH_FROM_RTN[j % 3];
+                stm[i][j] = s_i * s_j * rtn[k_i + 3 * (i / 3)][k_j + 3 * (j / 3)];
+            }
+        }
+        stm
+    }
+
+    /// Returns the relative LVLH position and velocity of the deputy after `dt`.
+    pub fn propagate(&self, dt: TimeDelta) -> (DVec3, DVec3) {
+        let stm = self.stm(dt);
+        let x0 = [
+            self.position.x,
+            self.position.y,
+            self.position.z,
+            self.velocity.x,
+            self.velocity.y,
+            self.velocity.z,
+        ];
+        let x: [f64; 6] =
+            std::array::from_fn(|i| stm[i].iter().zip(x0).map(|(a, b)| a * b).sum::<f64>());
+        (DVec3::new(x[0], x[1], x[2]), DVec3::new(x[3], x[4], x[5]))
+    }
+}
+
+/// Returns the Clohessy-Wiltshire state transition matrix in the RTN frame.
+///
+/// Vallado, 2013, Fundamentals of Astrodynamics and Applications, Eq. 6-66
+fn rtn_stm(n: f64, t: f64) -> [[f64; 6]; 6] {
+    let nt = n * t;
+    let (s, c) = nt.sin_cos();
+    [
+        [4.0 - 3.0 * c, 0.0, 0.0, s / n, 2.0 * (1.0 - c) / n, 0.0],
+        [
+            6.0 * (s - nt),
+            1.0,
+            0.0,
+            -2.0 * (1.0 - c) / n,
+            (4.0 * s - 3.0 * nt) / n,
+            0.0,
+        ],
+        [0.0, 0.0, c, 0.0, 0.0, s / n],
+        [3.0 * n * s, 0.0, 0.0, c, 2.0 * s, 0.0],
+        [-6.0 * n * (1.0 - c), 0.0, 0.0, -2.0 * s, 4.0 * c - 3.0, 0.0],
+        [0.0, 0.0, -n * s, 0.0, 0.0, c],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::TAU;
+
+    use lox_bodies::Earth;
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+    use lox_time::time_scales::Tdb;
+    use lox_time::{Time, time};
+
+    use super::*;
+
+    const MEAN_MOTION: f64 = 0.0011;
+
+    fn period() -> TimeDelta {
+        TimeDelta::from_decimal_seconds(TAU / MEAN_MOTION)
+    }
+
+    #[test]
+    fn test_clohessy_wiltshire_closed_orbit() {
+        // An in-plane relative ellipse is closed if the along-track drift vanishes,
+        // i.e. vx = 2 n z in LVLH coordinates
+        let position = DVec3::new(0.2, 0.05, 0.1);
+        let velocity = DVec3::new(2.0 * MEAN_MOTION * 0.1, 1e-5, 3e-5);
+        let cw = ClohessyWiltshire::new(MEAN_MOTION, position, velocity);
+        let (r1, v1) = cw.propagate(period());
+        assert_close!(r1, position, 1e-12, 0.0);
+        assert_close!(v1, velocity, 1e-15, 0.0);
+    }
+
+    #[test]
+    fn test_clohessy_wiltshire_along_track_drift() {
+        // A deputy below the chief with zero relative velocity drifts along-track by 12π z0
+        // per orbit
+        let z0 = 0.1;
+        let cw = ClohessyWiltshire::new(MEAN_MOTION, DVec3::new(0.0, 0.0, z0), DVec3::ZERO);
+        let (r1, _) = cw.propagate(period());
+        assert_close!(r1, DVec3::new(6.0 * TAU * z0, 0.0, z0), 1e-12, 0.0);
+    }
+
+    #[test]
+    fn test_clohessy_wiltshire_stm_composition() {
+        let cw = ClohessyWiltshire::new(MEAN_MOTION, DVec3::X, DVec3::ZERO);
+        let dt = TimeDelta::from_decimal_seconds(600.0);
+        let stm = cw.stm(dt);
+        let stm2 = cw.stm(dt + dt);
+        for i in 0..6 {
+            for j in 0..6 {
+                let act: f64 = (0..6).map(|k| stm[i][k] * stm[k][j]).sum();
+                assert_close!(act, stm2[i][j], 1e-9, 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_clohessy_wiltshire_from_states() {
+        let time = time!(Tdb, 2023, 1, 1).unwrap();
+        let r: f64 = 7000.0;
+        let n = (Earth.gravitational_parameter() / r.powi(3)).sqrt();
+        let chief = State::new(
+            time,
+            DVec3::new(r, 0.0, 0.0),
+            DVec3::new(0.0, n * r, 0.0),
+            Earth,
+            Icrf,
+        );
+        // A deputy 1 km lower on a circular orbit
+        let r_deputy = r - 1.0;
+        let n_deputy = (Earth.gravitational_parameter() / r_deputy.powi(3)).sqrt();
+        let deputy = State::new(
+            time,
+            DVec3::new(r_deputy, 0.0, 0.0),
+            DVec3::new(0.0, n_deputy * r_deputy, 0.0),
+            Earth,
+            Icrf,
+        );
+        let cw = ClohessyWiltshire::from_states(&chief, &deputy);
+        assert_close!(cw.mean_motion(), n);
+        assert_close!(cw.position(), DVec3::new(0.0, 0.0, 1.0), 1e-12, 0.0);
+        // The lower deputy moves ahead at approximately 1.5 n z
+        assert_close!(cw.velocity().x, 1.5 * n, 1e-6, 0.0);
+    }
+}
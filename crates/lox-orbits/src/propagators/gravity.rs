@@ -0,0 +1,537 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module gravity provides [GravityField], a spherical harmonic model of the gravity field of the
+//! Earth loaded from EGM coefficient files, and the corresponding [GravityForce].
+
+use std::fs::read_to_string;
+use std::num::{ParseFloatError, ParseIntError};
+use std::path::Path;
+
+use glam::{DMat3, DVec3};
+use lox_bodies::Earth;
+use lox_earth::rotation_angle::RotationAngle;
+use lox_math::constants::f64::time::SECONDS_PER_DAY;
+use thiserror::Error;
+
+use crate::propagators::numerical::{ForceModel, ForceModelError};
+
+/// The gravitational parameter of the Earth in km³/s² of EGM2008, which is assumed for coefficient
+/// files without a header.
+const EGM2008_GRAVITATIONAL_PARAMETER: f64 = 398600.4415;
+
+/// The reference radius of the Earth in km of EGM2008, which is assumed for coefficient files
+/// without a header.
+const EGM2008_RADIUS: f64 = 6378.1363;
+
+#[derive(Debug, Error)]
+pub enum GravityFieldError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    ParseInt(#[from] ParseIntError),
+    #[error(transparent)]
+    ParseFloat(#[from] ParseFloatError),
+    #[error("invalid coefficient record in line {0}")]
+    InvalidRecord(usize),
+    #[error("unsupported normalisation `{0}`, only fully normalised coefficients are supported")]
+    UnsupportedNormalization(String),
+    #[error("maximum order {max_order} exceeds maximum degree {max_degree}")]
+    InvalidOrder { max_degree: usize, max_order: usize },
+    #[error("maximum degree {requested} exceeds the maximum degree {available} of the model")]
+    InsufficientDegree { requested: usize, available: usize },
+}
+
+/// A spherical harmonic model of the gravity field of the Earth with fully normalised
+/// coefficients C̄ₙₘ and S̄ₙₘ.
+///
+/// Only the coefficients of degree two and higher are retained, such that the model provides the
+/// perturbation of the central gravity field, i.e. [crate::propagators::numerical::PointMassGravity].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GravityField {
+    gravitational_parameter: f64,
+    radius: f64,
+    max_degree: usize,
+    max_order: usize,
+    c: Vec<Vec<f64>>,
+    s: Vec<Vec<f64>>,
+}
+
+impl GravityField {
+    /// Loads the coefficients up to `max_degree` and `max_order` from the EGM coefficient file at
+    /// `path`.
+    ///
+    /// See [GravityField::from_egm_str] for the supported formats.
+    ///
+    /// # Errors
+    ///
+    /// - [GravityFieldError::Io] if the file at `path` can't be read.
+    /// - All errors of [GravityField::from_egm_str].
+    pub fn from_egm(
+        path: impl AsRef<Path>,
+        max_degree: usize,
+        max_order: usize,
+    ) -> Result<Self, GravityFieldError> {
+        let egm = read_to_string(path)?;
+        Self::from_egm_str(&egm, max_degree, max_order)
+    }
+
+    /// Parses the coefficients up to `max_degree` and `max_order` from the contents of an EGM
+    /// coefficient file.
+    ///
+    /// Both the ICGEM format, i.e. a header terminated by `end_of_head` followed by `gfc` records,
+    /// and the plain format of the NGA distribution of EGM2008 with records of the form
+    /// `n m C̄ₙₘ S̄ₙₘ σC σS` and Fortran exponents are supported. The gravitational parameter and
+    /// the reference radius are read from the ICGEM header and default to the values of EGM2008
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// - [GravityFieldError::InvalidOrder] if `max_order` exceeds `max_degree`.
+    /// - [GravityFieldError::UnsupportedNormalization] if the coefficients are not fully
+    ///   normalised.
+    /// - [GravityFieldError::InvalidRecord], [GravityFieldError::ParseInt], or
+    ///   [GravityFieldError::ParseFloat] if a coefficient record is malformed.
+    /// - [GravityFieldError::InsufficientDegree] if the file does not contain coefficients up to
+    ///   `max_degree`.
+    pub fn from_egm_str(
+        egm: &str,
+        max_degree: usize,
+        max_order: usize,
+    ) -> Result<Self, GravityFieldError> {
+        if max_order > max_degree {
+            return Err(GravityFieldError::InvalidOrder {
+                max_degree,
+                max_order,
+            });
+        }
+        let mut gravitational_parameter = EGM2008_GRAVITATIONAL_PARAMETER;
+        let mut radius = EGM2008_RADIUS;
+        let mut c: Vec<Vec<f64>> = (0..=max_degree)
+            .map(|n| vec![0.0; n.min(max_order) + 1])
+            .collect();
+        let mut s = c.clone();
+        let mut available = 0;
+
+        let has_header = egm.lines().any(|line| line.starts_with("end_of_head"));
+        let mut in_header = has_header;
+        for (i, line) in egm.lines().enumerate() {
+            let mut fields = line.split_whitespace();
+            if in_header {
+                match fields.next() {
+                    Some("earth_gravity_constant") => {
+                        gravitational_parameter = parse_float(fields.next(), i + 1)? * 1e-9;
+                    }
+                    Some("radius") => radius = parse_float(fields.next(), i + 1)? * 1e-3,
+                    Some("norm") => {
+                        let norm = fields.next().unwrap_or_default();
+                        if norm != "fully_normalized" {
+                            return Err(GravityFieldError::UnsupportedNormalization(
+                                norm.to_string(),
+                            ));
+                        }
+                    }
+                    Some("end_of_head") => in_header = false,
+                    _ => {}
+                }
+                continue;
+            }
+            if has_header {
+                match fields.next() {
+                    Some("gfc") => {}
+                    Some(_) => return Err(GravityFieldError::InvalidRecord(i + 1)),
+                    None => continue,
+                }
+            } else if line.trim().is_empty() {
+                continue;
+            }
+            let n: usize = fields
+                .next()
+                .ok_or(GravityFieldError::InvalidRecord(i + 1))?
+                .parse()?;
+            let m: usize = fields
+                .next()
+                .ok_or(GravityFieldError::InvalidRecord(i + 1))?
+                .parse()?;
+            if m > n {
+                return Err(GravityFieldError::InvalidRecord(i + 1));
+            }
+            let cnm = parse_float(fields.next(), i + 1)?;
+            let snm = parse_float(fields.next(), i + 1)?;
+            available = available.max(n);
+            if n < 2 || n > max_degree || m > max_order {
+                continue;
+            }
+            c[n][m] = cnm;
+            s[n][m] = snm;
+        }
+
+        if available < max_degree {
+            return Err(GravityFieldError::InsufficientDegree {
+                requested: max_degree,
+                available,
+            });
+        }
+        Ok(Self {
+            gravitational_parameter,
+            radius,
+            max_degree,
+            max_order,
+            c,
+            s,
+        })
+    }
+
+    /// Returns the gravitational parameter in km³/s².
+    pub fn gravitational_parameter(&self) -> f64 {
+        self.gravitational_parameter
+    }
+
+    /// Returns the reference radius in km.
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    pub fn max_degree(&self) -> usize {
+        self.max_degree
+    }
+
+    pub fn max_order(&self) -> usize {
+        self.max_order
+    }
+
+    /// Returns the normalised coefficients C̄ₙₘ and S̄ₙₘ or `None` if they are not part of the
+    /// model.
+    pub fn coefficients(&self, n: usize, m: usize) -> Option<(f64, f64)> {
+        if n < 2 {
+            return None;
+        }
+        let c = self.c.get(n)?.get(m)?;
+        let s = self.s[n][m];
+        Some((*c, s))
+    }
+
+    /// Returns the potential in km²/s² for the body-fixed position `r` in km, such that the
+    /// acceleration is its gradient.
+    pub fn potential(&self, r: DVec3) -> f64 {
+        let (v, w) = self.harmonics(r, self.max_degree, self.max_order);
+        let mut potential = 0.0;
+        for n in 2..=self.max_degree {
+            for m in 0..=n.min(self.max_order) {
+                potential += self.c[n][m] * v[n][m] + self.s[n][m] * w[n][m];
+            }
+        }
+        self.gravitational_parameter / self.radius * potential
+    }
+
+    /// Returns the acceleration in km/s² for the body-fixed position `r` in km.
+    pub fn acceleration(&self, r: DVec3) -> DVec3 {
+        // Montenbruck & Gill (2000), section 3.2.5, with the recursions and the partial
+        // derivatives expressed in terms of fully normalised quantities to avoid the overflow of
+        // the normalisation factors at high degrees
+        let (v, w) = self.harmonics(r, self.max_degree + 1, self.max_order + 1);
+        let mut acceleration = DVec3::ZERO;
+        for n in 2..=self.max_degree {
+            let nf = n as f64;
+            for m in 0..=n.min(self.max_order) {
+                let mf = m as f64;
+                let c = self.c[n][m];
+                let s = self.s[n][m];
+                let k = (2.0 * nf + 1.0) / (2.0 * nf + 3.0);
+                if m == 0 {
+                    let f1 = (k * (nf + 1.0) * (nf + 2.0) / 2.0).sqrt();
+                    acceleration.x -= f1 * c * v[n + 1][1];
+                    acceleration.y -= f1 * c * w[n + 1][1];
+                } else {
+                    let f1 = (k * (nf + mf + 1.0) * (nf + mf + 2.0)).sqrt();
+                    let delta = if m == 1 { 2.0 } else { 1.0 };
+                    let f2 = (delta * k * (nf - mf + 1.0) * (nf - mf + 2.0)).sqrt();
+                    acceleration.x += 0.5
+                        * (f1 * (-c * v[n + 1][m + 1] - s * w[n + 1][m + 1])
+                            + f2 * (c * v[n + 1][m - 1] + s * w[n + 1][m - 1]));
+                    acceleration.y += 0.5
+                        * (f1 * (-c * w[n + 1][m + 1] + s * v[n + 1][m + 1])
+                            + f2 * (-c * w[n + 1][m - 1] + s * v[n + 1][m - 1]));
+                }
+                let f3 = (k * (nf + mf + 1.0) * (nf - mf + 1.0)).sqrt();
+                acceleration.z += f3 * (-c * v[n + 1][m] - s * w[n + 1][m]);
+            }
+        }
+        self.gravitational_parameter / self.radius.powi(2) * acceleration
+    }
+
+    /// Evaluates the fully normalised harmonics V̄ₙₘ and W̄ₙₘ up to `max_degree` and `max_order`
+    /// with the recursions of Cunningham (1970).
+    fn harmonics(
+        &self,
+        r: DVec3,
+        max_degree: usize,
+        max_order: usize,
+    ) -> (Vec<Vec<f64>>, Vec<Vec<f64>>) {
+        let r2 = r.length_squared();
+        let rho = self.radius.powi(2) / r2;
+        let p = self.radius * r / r2;
+
+        let mut v: Vec<Vec<f64>> = (0..=max_degree)
+            .map(|n| vec![0.0; n.min(max_order) + 1])
+            .collect();
+        let mut w = v.clone();
+        v[0][0] = self.radius / r2.sqrt();
+
+        for m in 0..=max_order {
+            let mf = m as f64;
+            if m > 0 {
+                let delta = if m == 1 { 2.0 } else { 1.0 };
+                let f = (delta * (2.0 * mf + 1.0) / (2.0 * mf)).sqrt();
+                let (vp, wp) = (v[m - 1][m - 1], w[m - 1][m - 1]);
+                v[m][m] = f * (p.x * vp - p.y * wp);
+                w[m][m] = f * (p.x * wp + p.y * vp);
+            }
+            for n in m + 1..=max_degree {
+                let nf = n as f64;
+                let a = ((2.0 * nf + 1.0) * (2.0 * nf - 1.0) / ((nf - mf) * (nf + mf))).sqrt();
+                v[n][m] = a * p.z * v[n - 1][m];
+                w[n][m] = a * p.z * w[n - 1][m];
+                if n >= m + 2 {
+                    let b = ((2.0 * nf + 1.0) * (nf + mf - 1.0) * (nf - mf - 1.0)
+                        / ((2.0 * nf - 3.0) * (nf + mf) * (nf - mf)))
+                        .sqrt();
+                    v[n][m] -= b * rho * v[n - 2][m];
+                    w[n][m] -= b * rho * w[n - 2][m];
+                }
+            }
+        }
+        (v, w)
+    }
+}
+
+fn parse_float(field: Option<&str>, line: usize) -> Result<f64, GravityFieldError> {
+    let field = field.ok_or(GravityFieldError::InvalidRecord(line))?;
+    Ok(field.replace(['D', 'd'], "E").parse()?)
+}
+
+/// The perturbing acceleration caused by the non-spherical gravity field of the Earth.
+///
+/// The body-fixed frame of the [GravityField] is approximated by a rotation of the inertial frame
+/// about its z-axis by the Earth rotation angle, i.e. precession, nutation, and polar motion are
+/// neglected and the time scale of the propagation is assumed to approximate UT1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GravityForce {
+    field: GravityField,
+}
+
+impl GravityForce {
+    pub fn new(field: GravityField) -> Self {
+        Self { field }
+    }
+
+    pub fn field(&self) -> &GravityField {
+        &self.field
+    }
+}
+
+impl ForceModel for GravityForce {
+    fn acceleration(&self, t: f64, r: DVec3, _v: DVec3) -> Result<DVec3, ForceModelError> {
+        let rotation = DMat3::from_rotation_z(Earth::rotation_angle_00(t / SECONDS_PER_DAY));
+        let acceleration = self.field.acceleration(rotation.transpose() * r);
+        Ok(rotation * acceleration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use float_eq::assert_float_eq;
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+
+    use super::*;
+
+    fn egm2008(max_degree: usize, max_order: usize) -> GravityField {
+        let path = PathBuf::from(format!(
+            "{}/../../data/egm2008_4.gfc",
+            env!("CARGO_MANIFEST_DIR")
+        ));
+        GravityField::from_egm(path, max_degree, max_order).unwrap()
+    }
+
+    /// A synthetic gravity field of high degree with coefficients of the magnitude predicted by
+    /// Kaula's rule.
+    fn synthetic(max_degree: usize) -> GravityField {
+        let mut egm = String::new();
+        for n in 2..=max_degree {
+            for m in 0..=n {
+                let kaula = 1e-5 / (n * n) as f64;
+                let c = kaula * ((n * 7 + m * 3) as f64).sin();
+                let s = if m == 0 {
+                    0.0
+                } else {
+                    kaula * ((n * 5 + m * 11) as f64).cos()
+                };
+                egm.push_str(&format!("{n} {m} {c:.15E} {s:.15E} 0.0 0.0\n"));
+            }
+        }
+        GravityField::from_egm_str(&egm, max_degree, max_degree).unwrap()
+    }
+
+    fn gradient(field: &GravityField, r: DVec3) -> DVec3 {
+        let h = 1e-3;
+        let d = |e: DVec3| (field.potential(r + h * e) - field.potential(r - h * e)) / (2.0 * h);
+        DVec3::new(d(DVec3::X), d(DVec3::Y), d(DVec3::Z))
+    }
+
+    #[test]
+    fn test_gravity_field_from_egm() {
+        let field = egm2008(4, 4);
+        assert_eq!(field.gravitational_parameter(), 398600.4415);
+        assert_float_eq!(field.radius(), 6378.1363, rel <= 1e-15);
+        assert_eq!(field.max_degree(), 4);
+        assert_eq!(field.max_order(), 4);
+        assert_eq!(field.coefficients(0, 0), None);
+        assert_eq!(field.coefficients(2, 0), Some((-0.484165143790815e-3, 0.0)));
+        assert_eq!(
+            field.coefficients(4, 4),
+            Some((-0.188519633023033e-6, 0.308803882149194e-6))
+        );
+        assert_eq!(field.coefficients(5, 0), None);
+
+        let field = egm2008(3, 1);
+        assert_eq!(
+            field.coefficients(3, 1),
+            Some((0.203046201047864e-5, 0.248200415856872e-6))
+        );
+        assert_eq!(field.coefficients(3, 2), None);
+    }
+
+    #[test]
+    fn test_gravity_field_from_egm_plain() {
+        let egm = "\
+    2    0 -0.484165143790815D-03  0.000000000000000D+00  0.7481239490D-11  0.0000000000D+00
+    2    1 -0.206615509074176D-09  0.138441389137979D-08  0.7063781502D-11  0.7348347201D-11
+    2    2  0.243938357328313D-05 -0.140027370385934D-05  0.7230231722D-11  0.7425816951D-11
+";
+        let field = GravityField::from_egm_str(egm, 2, 2).unwrap();
+        assert_eq!(field, egm2008(2, 2));
+    }
+
+    #[test]
+    fn test_gravity_field_from_egm_errors() {
+        assert!(matches!(
+            GravityField::from_egm_str("", 2, 3),
+            Err(GravityFieldError::InvalidOrder {
+                max_degree: 2,
+                max_order: 3
+            })
+        ));
+        assert!(matches!(
+            GravityField::from_egm_str("2 0 -0.484165143790815E-03 0.0 0.0 0.0", 4, 4),
+            Err(GravityFieldError::InsufficientDegree {
+                requested: 4,
+                available: 2
+            })
+        ));
+        assert!(matches!(
+            GravityField::from_egm_str("2 0 -0.484165143790815E-03", 2, 0),
+            Err(GravityFieldError::InvalidRecord(1))
+        ));
+        assert!(matches!(
+            GravityField::from_egm_str("2 3 0.0 0.0", 2, 2),
+            Err(GravityFieldError::InvalidRecord(1))
+        ));
+        assert!(matches!(
+            GravityField::from_egm_str("2 0 abc 0.0", 2, 2),
+            Err(GravityFieldError::ParseFloat(_))
+        ));
+        assert!(matches!(
+            GravityField::from_egm_str("norm unnormalized\nend_of_head\n", 2, 2),
+            Err(GravityFieldError::UnsupportedNormalization(norm)) if norm == "unnormalized"
+        ));
+        assert!(matches!(
+            GravityField::from_egm("not_a_file.gfc", 2, 2),
+            Err(GravityFieldError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_gravity_field_j2() {
+        let field = egm2008(2, 0);
+        let mu = field.gravitational_parameter();
+        let re = field.radius();
+        let j2 = -5f64.sqrt() * field.coefficients(2, 0).unwrap().0;
+        for r in [
+            DVec3::new(6678.0, 0.0, 0.0),
+            DVec3::new(-1234.5, 5678.9, 3456.7),
+            DVec3::new(100.0, -200.0, 7000.0),
+        ] {
+            let rn = r.length();
+            let zr2 = (r.z / rn).powi(2);
+            let factor = -1.5 * j2 * mu * re.powi(2) / rn.powi(5);
+            let expected = factor
+                * DVec3::new(
+                    r.x * (1.0 - 5.0 * zr2),
+                    r.y * (1.0 - 5.0 * zr2),
+                    r.z * (3.0 - 5.0 * zr2),
+                );
+            assert_close!(field.acceleration(r), expected, 1e-18);
+        }
+    }
+
+    #[test]
+    fn test_gravity_field_gradient() {
+        let r = DVec3::new(-4321.0, 3210.9, 4567.8);
+        let field = egm2008(4, 4);
+        assert_close!(field.acceleration(r), gradient(&field, r), 1e-12);
+        let field = synthetic(30);
+        assert_close!(field.acceleration(r), gradient(&field, r), 1e-12);
+    }
+
+    #[test]
+    fn test_gravity_field_high_degree() {
+        // The normalised recursions remain stable at degrees where the unnormalised coefficients
+        // would under- or overflow
+        let field = synthetic(360);
+        for r in [
+            DVec3::new(6578.0, 0.0, 0.0),
+            DVec3::new(0.0, 0.0, 6578.0),
+            DVec3::new(3000.0, -4000.0, 4500.0),
+        ] {
+            let acceleration = field.acceleration(r);
+            assert!(acceleration.is_finite());
+            assert!(acceleration.length() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_gravity_force() {
+        let field = egm2008(4, 4);
+        let force = GravityForce::new(field.clone());
+        assert_eq!(force.field(), &field);
+
+        // The zonal harmonics do not depend on the rotation of the Earth
+        let zonal = GravityForce::new(egm2008(4, 0));
+        let r = DVec3::new(-1234.5, 5678.9, 3456.7);
+        assert_close!(
+            zonal.acceleration(0.0, r, DVec3::ZERO).unwrap(),
+            zonal.field().acceleration(r),
+            1e-18
+        );
+
+        // The tesseral harmonics rotate with the Earth
+        let t = 12345.0;
+        let angle = Earth::rotation_angle_00(t / SECONDS_PER_DAY);
+        let r_body = DVec3::new(6678.0, 0.0, 0.0);
+        let r = DMat3::from_rotation_z(angle) * r_body;
+        let expected = DMat3::from_rotation_z(angle) * field.acceleration(r_body);
+        assert_close!(
+            force.acceleration(t, r, DVec3::ZERO).unwrap(),
+            expected,
+            1e-18
+        );
+    }
+}
@@ -0,0 +1,174 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use lox_bodies::{J2, PointMass, Spheroid};
+use lox_math::types::units::Angle;
+use lox_time::Time;
+use lox_time::deltas::TimeDelta;
+use lox_time::time_scales::TimeScale;
+
+use crate::anomalies::{mean_to_true, true_to_mean};
+use crate::elements::Keplerian;
+use crate::frames::Icrf;
+use crate::propagators::Propagator;
+use crate::states::State;
+use crate::trajectories::TrajectoryError;
+
+/// Mean-element propagator for elliptic orbits which applies the secular
+/// rates caused by the J2 zonal harmonic of the central body
+#[derive(Debug, Clone, PartialEq)]
+pub struct J2Propagator<T: TimeScale, O: PointMass> {
+    initial_state: Keplerian<T, O, Icrf>,
+}
+
+impl<T, O> J2Propagator<T, O>
+where
+    T: TimeScale + Clone,
+    O: PointMass + Spheroid + J2 + Clone,
+{
+    pub fn new(initial_state: Keplerian<T, O, Icrf>) -> Self {
+        Self { initial_state }
+    }
+
+    /// Returns the secular rates of the longitude of the ascending node, the
+    /// argument of periapsis, and the mean anomaly in rad/s.
+    pub fn secular_rates(&self) -> (f64, f64, f64) {
+        let k = &self.initial_state;
+        let origin = k.origin();
+        let a = k.semi_major_axis();
+        let e = k.eccentricity();
+        let mean_motion = (origin.gravitational_parameter() / a.powi(3)).sqrt();
+        let p = a * (1.0 - e.powi(2));
+        let factor = 1.5 * origin.j2() * (origin.equatorial_radius() / p).powi(2) * mean_motion;
+        let (sin_i, cos_i) = k.inclination().to_radians().sin_cos();
+
+        let node_rate = -factor * cos_i;
+        let periapsis_rate = factor * (2.0 - 2.5 * sin_i.powi(2));
+        let mean_anomaly_rate =
+            mean_motion + factor * (1.0 - e.powi(2)).sqrt() * (1.0 - 1.5 * sin_i.powi(2));
+        (node_rate, periapsis_rate, mean_anomaly_rate)
+    }
+
+    /// Returns the mean elements after the propagation duration `dt`.
+    pub fn elements_after(&self, dt: TimeDelta) -> Keplerian<T, O, Icrf> {
+        let k = &self.initial_state;
+        let dt_seconds = dt.to_decimal_seconds();
+        let (node_rate, periapsis_rate, mean_anomaly_rate) = self.secular_rates();
+        let e = k.eccentricity();
+        let mean_anomaly =
+            true_to_mean(k.true_anomaly().to_radians(), e) + mean_anomaly_rate * dt_seconds;
+        Keplerian::new(
+            k.time() + dt,
+            k.origin(),
+            k.semi_major_axis(),
+            e,
+            k.inclination(),
+            (k.longitude_of_ascending_node() + Angle::from_radians(node_rate * dt_seconds))
+                .wrap_to_2pi(),
+            (k.argument_of_periapsis() + Angle::from_radians(periapsis_rate * dt_seconds))
+                .wrap_to_2pi(),
+            Angle::from_radians(mean_to_true(mean_anomaly, e)),
+        )
+    }
+}
+
+impl<T, O> Propagator<T, O, Icrf> for J2Propagator<T, O>
+where
+    T: TimeScale + Clone,
+    O: PointMass + Spheroid + J2 + Clone,
+{
+    type Error = TrajectoryError;
+
+    fn propagate(&self, time: Time<T>) -> Result<State<T, O, Icrf>, Self::Error> {
+        let dt = time - self.initial_state.time();
+        Ok(self.elements_after(dt).to_cartesian())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use lox_bodies::Earth;
+    use lox_math::constants::f64::time::SECONDS_PER_DAY;
+    use lox_math::math::mod_two_pi;
+    use lox_time::time;
+    use lox_time::time_scales::Tdb;
+
+    use super::*;
+
+    fn propagator(inclination_deg: f64) -> J2Propagator<Tdb, Earth> {
+        let time = time!(Tdb, 2025, 1, 1).unwrap();
+        let semi_major_axis = Earth.equatorial_radius() + 700.0;
+        J2Propagator::new(Keplerian::new(
+            time,
+            Earth,
+            semi_major_axis,
+            0.001,
+            Angle::from_degrees(inclination_deg),
+            Angle::from_radians(0.5),
+            Angle::from_radians(1.0),
+            Angle::ZERO,
+        ))
+    }
+
+    #[test]
+    fn test_j2_sun_synchronous() {
+        let (node_rate, _, _) = propagator(98.19).secular_rates();
+        let node_rate_deg_per_day = node_rate.to_degrees() * SECONDS_PER_DAY;
+        assert_float_eq!(node_rate_deg_per_day, 0.9856, abs <= 5e-3);
+    }
+
+    #[test]
+    fn test_j2_rate_signs() {
+        // The node regresses for prograde orbits and advances for retrograde
+        // orbits. The line of apsides advances outside of the critical
+        // inclinations at 63.4° and 116.6° and regresses between them.
+        let (node_rate, periapsis_rate, _) = propagator(51.6).secular_rates();
+        assert!(node_rate < 0.0);
+        assert!(periapsis_rate > 0.0);
+        let (node_rate, periapsis_rate, _) = propagator(80.0).secular_rates();
+        assert!(node_rate < 0.0);
+        assert!(periapsis_rate < 0.0);
+        let (node_rate, periapsis_rate, _) = propagator(100.0).secular_rates();
+        assert!(node_rate > 0.0);
+        assert!(periapsis_rate < 0.0);
+        let (node_rate, periapsis_rate, _) = propagator(130.0).secular_rates();
+        assert!(node_rate > 0.0);
+        assert!(periapsis_rate > 0.0);
+
+        let critical = 0.8f64.sqrt().asin().to_degrees();
+        for inclination in [critical, 180.0 - critical] {
+            let (_, periapsis_rate, _) = propagator(inclination).secular_rates();
+            assert_float_eq!(periapsis_rate, 0.0, abs <= 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_j2_elements_after() {
+        let propagator = propagator(98.19);
+        let k0 = &propagator.initial_state;
+        let dt = TimeDelta::from_days(1.0).unwrap();
+        let k1 = propagator.elements_after(dt);
+        let (node_rate, periapsis_rate, _) = propagator.secular_rates();
+        assert_eq!(k1.time(), k0.time() + dt);
+        assert_eq!(k1.semi_major_axis(), k0.semi_major_axis());
+        assert_float_eq!(
+            k1.longitude_of_ascending_node().to_radians(),
+            k0.longitude_of_ascending_node().to_radians() + node_rate * SECONDS_PER_DAY,
+            abs <= 1e-12
+        );
+        assert_float_eq!(
+            k1.argument_of_periapsis().to_radians(),
+            mod_two_pi(k0.argument_of_periapsis().to_radians() + periapsis_rate * SECONDS_PER_DAY),
+            abs <= 1e-12
+        );
+
+        let state = propagator.propagate(k0.time() + dt).unwrap();
+        assert_eq!(state.time(), k1.time());
+    }
+}
@@ -0,0 +1,2039 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Module numerical provides [Cowell], a numerical propagator which integrates the equations of
+//! motion subject to a composable set of [ForceModel]s with an adaptive Dormand-Prince 8(7)
+//! integrator.
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use glam::{DMat3, DVec3};
+use lox_bodies::{Earth, Moon, NaifId, Origin, PointMass, RotationalElements, Spheroid, Sun};
+use lox_ephem::Ephemeris;
+use lox_io::ndm::common::{EpochType, TimeSystem, UnknownTimeSystemError};
+use lox_io::ndm::kvn::UnitError;
+use lox_io::ndm::opm::OpmType;
+use lox_math::constants::f64::physics::{ASTRONOMICAL_UNIT, SOLAR_FLUX, SPEED_OF_LIGHT};
+use lox_time::calendar_dates::CalendarDate;
+use lox_time::deltas::TimeDelta;
+use lox_time::julian_dates::JulianDate;
+use lox_time::time_of_day::CivilTime;
+use lox_time::time_scales::{DynTimeScale, TimeScale, UnknownTimeScaleError};
+use lox_time::utc::leap_seconds::BuiltinLeapSeconds;
+use lox_time::utc::{Utc, UtcError};
+use lox_time::{DynTime, Time, TimeError};
+use thiserror::Error;
+
+use crate::analysis::{body_position, illumination};
+use crate::covariance::{Matrix6, mul};
+use crate::frames::Icrf;
+use crate::propagators::Propagator;
+use crate::propagators::atmosphere::AtmosphericDensity;
+use crate::states::{LocalOrbitalFrame, State};
+use crate::trajectories::{Trajectory, TrajectoryError};
+
+/// The default relative tolerance of the step size control.
+const RELATIVE_TOLERANCE: f64 = 1e-10;
+
+/// The default absolute tolerance of the step size control in km and km/s.
+const ABSOLUTE_TOLERANCE: f64 = 1e-12;
+
+/// The default maximum number of integration steps per call to [Propagator::propagate].
+const MAX_STEPS: usize = 100_000;
+
+/// The maximum number of iterations for reverting a maneuver in a local orbital frame.
+const MANEUVER_ITERATIONS: usize = 10;
+
+/// The relative step size of the central differences in [ForceModel::partials].
+const PARTIALS_STEP: f64 = 1e-6;
+
+/// The zonal harmonic coefficients J2 to J6 of the Earth from the EGM2008 gravity model.
+pub const EARTH_ZONAL_HARMONICS: [f64; 5] = [
+    1.0826261738522227e-3,
+    -2.5324105185677225e-6,
+    -1.6198975999169731e-6,
+    -2.2775359073083618e-7,
+    5.406665762838132e-7,
+];
+
+/// The error returned by a [ForceModel] whose acceleration could not be evaluated, e.g. because
+/// the epoch is outside of the time span of an ephemeris.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("force model evaluation failed: {0}")]
+pub struct ForceModelError(String);
+
+impl ForceModelError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self(message.into())
+    }
+}
+
+/// Implementers of `ForceModel` provide a perturbing or central acceleration for the numerical
+/// integration of the equations of motion.
+///
+/// Force models are boxed by [Cowell], such that arbitrary perturbations can be composed.
+pub trait ForceModel: Send + Sync {
+    /// Returns the acceleration in km/s² at `t` seconds since J2000 for the position `r` in km
+    /// and the velocity `v` in km/s with respect to the central body.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ForceModelError] if the acceleration could not be evaluated.
+    fn acceleration(&self, t: f64, r: DVec3, v: DVec3) -> Result<DVec3, ForceModelError>;
+
+    /// Returns the partial derivatives of the acceleration with respect to the position and the
+    /// velocity, which are required for the integration of the variational equations.
+    ///
+    /// The default implementation approximates the partials with central differences.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ForceModelError] if the acceleration could not be evaluated.
+    fn partials(&self, t: f64, r: DVec3, v: DVec3) -> Result<(DMat3, DMat3), ForceModelError> {
+        let hr = PARTIALS_STEP * r.length().max(1.0);
+        let hv = PARTIALS_STEP * v.length().max(1e-3);
+        let mut dr = [[0.0; 3]; 3];
+        let mut dv = [[0.0; 3]; 3];
+        for j in 0..3 {
+            let e = hr * DVec3::AXES[j];
+            let da = self.acceleration(t, r + e, v)? - self.acceleration(t, r - e, v)?;
+            dr[j] = (da / (2.0 * hr)).to_array();
+            let e = hv * DVec3::AXES[j];
+            let da = self.acceleration(t, r, v + e)? - self.acceleration(t, r, v - e)?;
+            dv[j] = (da / (2.0 * hv)).to_array();
+        }
+        Ok((
+            DMat3::from_cols_array_2d(&dr),
+            DMat3::from_cols_array_2d(&dv),
+        ))
+    }
+}
+
+/// The gravitational acceleration of a point mass.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointMassGravity {
+    gravitational_parameter: f64,
+}
+
+impl PointMassGravity {
+    pub fn new(body: &impl PointMass) -> Self {
+        Self {
+            gravitational_parameter: body.gravitational_parameter(),
+        }
+    }
+}
+
+impl ForceModel for PointMassGravity {
+    fn acceleration(&self, _t: f64, r: DVec3, _v: DVec3) -> Result<DVec3, ForceModelError> {
+        Ok(-self.gravitational_parameter * r / r.length().powi(3))
+    }
+
+    fn partials(&self, _t: f64, r: DVec3, _v: DVec3) -> Result<(DMat3, DMat3), ForceModelError> {
+        let rn = r.length();
+        let u = r / rn;
+        let dr = self.gravitational_parameter / rn.powi(3)
+            * (3.0 * DMat3::from_cols(u.x * u, u.y * u, u.z * u) - DMat3::IDENTITY);
+        Ok((dr, DMat3::ZERO))
+    }
+}
+
+/// The perturbing acceleration caused by the zonal harmonics of the gravity field of a body.
+///
+/// The body's rotation axis is assumed to coincide with the z-axis of the reference frame.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZonalHarmonics {
+    gravitational_parameter: f64,
+    equatorial_radius: f64,
+    coefficients: Vec<f64>,
+}
+
+impl ZonalHarmonics {
+    /// Creates the zonal harmonics perturbation of `body` from the unnormalised coefficients
+    /// `coefficients`, starting with J2.
+    pub fn new(body: &(impl PointMass + Spheroid), coefficients: &[f64]) -> Self {
+        Self {
+            gravitational_parameter: body.gravitational_parameter(),
+            equatorial_radius: body.equatorial_radius(),
+            coefficients: coefficients.to_vec(),
+        }
+    }
+
+    /// Returns the potential of the zonal harmonics in km²/s², such that the acceleration is its
+    /// gradient.
+    pub fn potential(&self, r: DVec3) -> f64 {
+        let rn = r.length();
+        let u = r.z / rn;
+        let mut potential = 0.0;
+        for (n, (p, _)) in self.legendre(u) {
+            let jn = self.coefficients[n - 2];
+            potential -= self.gravitational_parameter
+                * jn
+                * (self.equatorial_radius / rn).powi(n as i32)
+                * p
+                / rn;
+        }
+        potential
+    }
+
+    /// Returns the Legendre polynomials P_n(u) and their derivatives for each degree of the
+    /// coefficients.
+    fn legendre(&self, u: f64) -> impl Iterator<Item = (usize, (f64, f64))> {
+        let mut p = (1.0, u);
+        let mut dp = (0.0, 1.0);
+        (2..self.coefficients.len() + 2).map(move |n| {
+            let nf = n as f64;
+            let pn = ((2.0 * nf - 1.0) * u * p.1 - (nf - 1.0) * p.0) / nf;
+            let dpn = dp.0 + (2.0 * nf - 1.0) * p.1;
+            p = (p.1, pn);
+            dp = (dp.1, dpn);
+            (n, (pn, dpn))
+        })
+    }
+}
+
+impl ForceModel for ZonalHarmonics {
+    fn acceleration(&self, _t: f64, r: DVec3, _v: DVec3) -> Result<DVec3, ForceModelError> {
+        let rn = r.length();
+        let u = r.z / rn;
+        let mut acceleration = DVec3::ZERO;
+        for (n, (p, dp)) in self.legendre(u) {
+            let jn = self.coefficients[n - 2];
+            let nf = n as f64;
+            let factor =
+                self.gravitational_parameter * jn * (self.equatorial_radius / rn).powi(n as i32)
+                    / rn.powi(2);
+            acceleration += factor * (((nf + 1.0) * p + u * dp) * r / rn - dp * DVec3::Z);
+        }
+        Ok(acceleration)
+    }
+}
+
+/// The acceleration caused by atmospheric drag on a spacecraft orbiting the Earth.
+///
+/// The atmosphere is assumed to co-rotate with the Earth about the z-axis of the reference frame
+/// at the rotation rate of the IAU rotational elements of [Earth].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragForce<D: AtmosphericDensity> {
+    drag_coefficient: f64,
+    area: f64,
+    mass: f64,
+    density_model: D,
+}
+
+impl<D: AtmosphericDensity> DragForce<D> {
+    /// Creates the drag force for a spacecraft with the dimensionless `drag_coefficient`, the
+    /// cross-sectional `area` in m², and the `mass` in kg.
+    pub fn new(drag_coefficient: f64, area: f64, mass: f64, density_model: D) -> Self {
+        Self {
+            drag_coefficient,
+            area,
+            mass,
+            density_model,
+        }
+    }
+
+    pub fn drag_coefficient(&self) -> f64 {
+        self.drag_coefficient
+    }
+
+    pub fn area(&self) -> f64 {
+        self.area
+    }
+
+    pub fn mass(&self) -> f64 {
+        self.mass
+    }
+
+    pub fn density_model(&self) -> &D {
+        &self.density_model
+    }
+}
+
+impl<D: AtmosphericDensity> ForceModel for DragForce<D> {
+    fn acceleration(&self, t: f64, r: DVec3, v: DVec3) -> Result<DVec3, ForceModelError> {
+        let density = self.density_model.atmospheric_density(t, r);
+        if density == 0.0 {
+            return Ok(DVec3::ZERO);
+        }
+        // Velocity relative to the co-rotating atmosphere in km/s
+        let rate = Earth.rotation_rate(t).to_radians_per_second();
+        let v_rel = v - rate * DVec3::Z.cross(r);
+        // The density is given in kg/m³ and the area in m², such that the factor of 1e3 converts
+        // the acceleration from m/s² to km/s² and the velocities from km/s to m/s
+        Ok(-0.5 * self.drag_coefficient * self.area / self.mass
+            * density
+            * v_rel.length()
+            * v_rel
+            * 1e3)
+    }
+}
+
+/// The perturbing acceleration caused by the gravitational attraction of third bodies, e.g. the
+/// Sun and the Moon, whose positions are obtained from an ephemeris.
+///
+/// The time of the propagation is assumed to approximate TDB.
+#[derive(Debug)]
+pub struct ThirdBodyForce<E: Ephemeris> {
+    central_body: NaifId,
+    perturbers: Vec<(NaifId, f64)>,
+    ephemeris: Arc<E>,
+}
+
+impl<E: Ephemeris> Clone for ThirdBodyForce<E> {
+    fn clone(&self) -> Self {
+        Self {
+            central_body: self.central_body,
+            perturbers: self.perturbers.clone(),
+            ephemeris: self.ephemeris.clone(),
+        }
+    }
+}
+
+impl<E: Ephemeris> ThirdBodyForce<E> {
+    /// Creates the third-body perturbation for a spacecraft orbiting `central_body` without any
+    /// perturbers.
+    pub fn new(central_body: impl Origin, ephemeris: Arc<E>) -> Self {
+        Self {
+            central_body: central_body.id(),
+            perturbers: vec![],
+            ephemeris,
+        }
+    }
+
+    /// Creates the third-body perturbation of the Sun and the Moon for a spacecraft orbiting
+    /// `central_body`.
+    pub fn sun_and_moon(central_body: impl Origin, ephemeris: Arc<E>) -> Self {
+        Self::new(central_body, ephemeris)
+            .with_perturber(Sun, Sun.gravitational_parameter())
+            .with_perturber(Moon, Moon.gravitational_parameter())
+    }
+
+    /// Adds `body` with the gravitational parameter `gravitational_parameter` in km³/s² to the
+    /// perturbers.
+    pub fn with_perturber(mut self, body: impl Origin, gravitational_parameter: f64) -> Self {
+        self.perturbers.push((body.id(), gravitational_parameter));
+        self
+    }
+
+    pub fn central_body(&self) -> NaifId {
+        self.central_body
+    }
+
+    /// Returns the NAIF IDs and gravitational parameters of the perturbers.
+    pub fn perturbers(&self) -> &[(NaifId, f64)] {
+        &self.perturbers
+    }
+
+    /// Returns the acceleration in km/s² at `t` seconds since J2000 for the position `r` in km
+    /// with respect to the central body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the position of a perturber could not be obtained from the ephemeris.
+    pub fn try_acceleration(&self, t: f64, r: DVec3) -> Result<DVec3, E::Error> {
+        let mut acceleration = DVec3::ZERO;
+        for (body, gravitational_parameter) in &self.perturbers {
+            let s = body_position(self.ephemeris.as_ref(), t, self.central_body.0, body.0)?;
+            acceleration += third_body_acceleration(*gravitational_parameter, r, s);
+        }
+        Ok(acceleration)
+    }
+}
+
+impl<E: Ephemeris + Send + Sync> ForceModel for ThirdBodyForce<E> {
+    fn acceleration(&self, t: f64, r: DVec3, _v: DVec3) -> Result<DVec3, ForceModelError> {
+        self.try_acceleration(t, r)
+            .map_err(|err| ForceModelError::new(err.to_string()))
+    }
+}
+
+/// The acceleration caused by solar radiation pressure on a spacecraft based on a cannonball
+/// model.
+///
+/// The solar flux scales with the inverse square of the distance from the Sun and is attenuated
+/// by the [illumination] of the spacecraft in the shadow of the central body. The time of the
+/// propagation is assumed to approximate TDB.
+#[derive(Debug)]
+pub struct SrpForce<E: Ephemeris> {
+    reflectivity_coefficient: f64,
+    area_to_mass: f64,
+    central_body: NaifId,
+    body_radius: f64,
+    shadow: bool,
+    ephemeris: Arc<E>,
+}
+
+impl<E: Ephemeris> Clone for SrpForce<E> {
+    fn clone(&self) -> Self {
+        Self {
+            reflectivity_coefficient: self.reflectivity_coefficient,
+            area_to_mass: self.area_to_mass,
+            central_body: self.central_body,
+            body_radius: self.body_radius,
+            shadow: self.shadow,
+            ephemeris: self.ephemeris.clone(),
+        }
+    }
+}
+
+impl<E: Ephemeris> SrpForce<E> {
+    /// Creates the solar radiation pressure for a spacecraft orbiting `central_body` with the
+    /// dimensionless `reflectivity_coefficient` and the area-to-mass ratio `area_to_mass` in
+    /// m²/kg.
+    pub fn new(
+        reflectivity_coefficient: f64,
+        area_to_mass: f64,
+        central_body: impl Origin + Spheroid,
+        ephemeris: Arc<E>,
+    ) -> Self {
+        Self {
+            reflectivity_coefficient,
+            area_to_mass,
+            central_body: central_body.id(),
+            body_radius: central_body.equatorial_radius(),
+            shadow: true,
+            ephemeris,
+        }
+    }
+
+    /// Enables or disables the attenuation of the solar flux in the shadow of the central body.
+    pub fn with_shadow(mut self, shadow: bool) -> Self {
+        self.shadow = shadow;
+        self
+    }
+
+    pub fn reflectivity_coefficient(&self) -> f64 {
+        self.reflectivity_coefficient
+    }
+
+    pub fn area_to_mass(&self) -> f64 {
+        self.area_to_mass
+    }
+
+    pub fn shadow(&self) -> bool {
+        self.shadow
+    }
+
+    /// Returns the acceleration in km/s² at `t` seconds since J2000 for the position `r` in km
+    /// with respect to the central body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the position of the Sun could not be obtained from the ephemeris.
+    pub fn try_acceleration(&self, t: f64, r: DVec3) -> Result<DVec3, E::Error> {
+        let sun = body_position(self.ephemeris.as_ref(), t, self.central_body.0, Sun.id().0)?;
+        let fraction = if self.shadow {
+            illumination(r, sun, self.body_radius)
+        } else {
+            1.0
+        };
+        Ok(fraction * self.sunlit_acceleration(r - sun))
+    }
+
+    /// Returns the acceleration in km/s² of the fully illuminated spacecraft at the position `d`
+    /// in km with respect to the Sun.
+    fn sunlit_acceleration(&self, d: DVec3) -> DVec3 {
+        let distance = d.length();
+        // The radiation pressure at 1 AU in N/m², which yields the acceleration in m/s² for an
+        // area-to-mass ratio in m²/kg and is converted to km/s²
+        let pressure = SOLAR_FLUX / (SPEED_OF_LIGHT * 1e3);
+        pressure
+            * (ASTRONOMICAL_UNIT / distance).powi(2)
+            * self.reflectivity_coefficient
+            * self.area_to_mass
+            * d
+            / distance
+            * 1e-3
+    }
+}
+
+impl<E: Ephemeris + Send + Sync> ForceModel for SrpForce<E> {
+    fn acceleration(&self, t: f64, r: DVec3, _v: DVec3) -> Result<DVec3, ForceModelError> {
+        self.try_acceleration(t, r)
+            .map_err(|err| ForceModelError::new(err.to_string()))
+    }
+}
+
+/// Returns the differential acceleration exerted by a body with the gravitational parameter
+/// `gravitational_parameter` at the position `s` on a spacecraft at the position `r`.
+///
+/// The difference of the direct and the indirect term is evaluated with Battin's F(q) function to
+/// avoid the loss of precision for distant perturbers, see Battin (1999), section 8.4.
+fn third_body_acceleration(gravitational_parameter: f64, r: DVec3, s: DVec3) -> DVec3 {
+    let d = r - s;
+    let q = r.dot(r - 2.0 * s) / s.length_squared();
+    let f = q * (3.0 + 3.0 * q + q * q) / (1.0 + (1.0 + q).powf(1.5));
+    -gravitational_parameter / d.length().powi(3) * (r + f * s)
+}
+
+#[derive(Debug, Clone, Error, PartialEq)]
+pub enum CowellError {
+    #[error("step size of {0} s is too small")]
+    StepSizeTooSmall(f64),
+    #[error("maximum number of {0} steps exceeded")]
+    MaxStepsExceeded(usize),
+    #[error(transparent)]
+    TrajectoryError(#[from] TrajectoryError),
+    #[error(transparent)]
+    ForceModelError(#[from] ForceModelError),
+}
+
+/// The frame in which the velocity increment of an [ImpulsiveManeuver] is given.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ManeuverFrame {
+    /// The integration frame, i.e. the ICRF.
+    Inertial,
+    /// A local orbital frame of the spacecraft at the epoch of the maneuver.
+    Local(LocalOrbitalFrame),
+}
+
+/// An instantaneous change of the velocity of the spacecraft.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImpulsiveManeuver<T: TimeScale> {
+    time: Time<T>,
+    delta_v: DVec3,
+    frame: ManeuverFrame,
+}
+
+impl<T: TimeScale + Clone> ImpulsiveManeuver<T> {
+    /// Creates a new maneuver at `time` with the velocity increment `delta_v` in km/s given in
+    /// `frame`.
+    pub fn new(time: Time<T>, delta_v: DVec3, frame: ManeuverFrame) -> Self {
+        Self {
+            time,
+            delta_v,
+            frame,
+        }
+    }
+
+    pub fn time(&self) -> Time<T> {
+        self.time.clone()
+    }
+
+    pub fn delta_v(&self) -> DVec3 {
+        self.delta_v
+    }
+
+    pub fn frame(&self) -> ManeuverFrame {
+        self.frame
+    }
+
+    /// Returns the velocity increment in the ICRF for a spacecraft at `position` with
+    /// `velocity`.
+    pub fn inertial_delta_v(&self, position: DVec3, velocity: DVec3) -> DVec3 {
+        match self.frame {
+            ManeuverFrame::Inertial => self.delta_v,
+            ManeuverFrame::Local(frame) => frame
+                .rotation(position, velocity)
+                .position_matrix()
+                .transpose()
+                .mul_vec3(self.delta_v),
+        }
+    }
+
+    /// Applies the maneuver to the velocity of the spacecraft at `position`, or reverts it if
+    /// `forward` is false.
+    fn apply(&self, position: DVec3, velocity: DVec3, forward: bool) -> DVec3 {
+        if forward {
+            return velocity + self.inertial_delta_v(position, velocity);
+        }
+        // The orientation of a local orbital frame depends on the velocity before the maneuver,
+        // which is found by fixed-point iteration.
+        let mut before = velocity - self.inertial_delta_v(position, velocity);
+        if let ManeuverFrame::Local(_) = self.frame {
+            for _ in 0..MANEUVER_ITERATIONS {
+                let next = velocity - self.inertial_delta_v(position, before);
+                let converged = (next - before).length() <= f64::EPSILON * velocity.length();
+                before = next;
+                if converged {
+                    break;
+                }
+            }
+        }
+        before
+    }
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[error("unknown maneuver frame `{0}`")]
+pub struct UnknownManeuverFrameError(String);
+
+impl FromStr for ManeuverFrame {
+    type Err = UnknownManeuverFrameError;
+
+    /// Parses the value of the `MAN_REF_FRAME` keyword of a CCSDS orbit parameter message.
+    ///
+    /// `EME2000` is treated as the ICRF, i.e. the frame bias of less than 0.1" between both
+    /// frames is neglected.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_uppercase().as_str() {
+            "ICRF" | "GCRF" | "EME2000" => Ok(ManeuverFrame::Inertial),
+            "RTN" | "RSW" | "RIC" => Ok(ManeuverFrame::Local(LocalOrbitalFrame::Rtn)),
+            "LVLH" => Ok(ManeuverFrame::Local(LocalOrbitalFrame::Lvlh)),
+            _ => Err(UnknownManeuverFrameError(s.to_owned())),
+        }
+    }
+}
+
+/// The error returned when the maneuvers of an OPM cannot be converted to [OpmManeuver]s.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum OpmManeuverError {
+    #[error(transparent)]
+    UnknownTimeSystem(#[from] UnknownTimeSystemError),
+    #[error(transparent)]
+    UnknownTimeScale(#[from] UnknownTimeScaleError),
+    #[error(transparent)]
+    TimeError(#[from] TimeError),
+    #[error(transparent)]
+    UtcError(#[from] UtcError),
+    #[error(transparent)]
+    UnitError(#[from] UnitError),
+    #[error(transparent)]
+    UnknownManeuverFrame(#[from] UnknownManeuverFrameError),
+}
+
+/// A maneuver of a CCSDS orbit parameter message (OPM).
+#[derive(Clone, Debug, PartialEq)]
+pub struct OpmManeuver {
+    /// The epoch of ignition in the `TIME_SYSTEM` of the message, or in TAI for UTC.
+    pub epoch_ignition: DynTime,
+    /// The duration of the burn in seconds, zero for impulsive maneuvers.
+    pub duration: f64,
+    /// The change of the spacecraft mass in kilograms.
+    pub delta_mass: f64,
+    /// The frame of [OpmManeuver::delta_v].
+    pub frame: ManeuverFrame,
+    /// The velocity increment in km/s.
+    pub delta_v: [f64; 3],
+}
+
+impl OpmManeuver {
+    /// Returns the maneuvers of `opm` in the order in which they appear.
+    ///
+    /// # Errors
+    ///
+    /// - [OpmManeuverError::UnknownTimeSystem] or [OpmManeuverError::UnknownTimeScale] if the
+    ///   `TIME_SYSTEM` of the message is not supported.
+    /// - [OpmManeuverError::TimeError] or [OpmManeuverError::UtcError] if an epoch of ignition
+    ///   is invalid.
+    /// - [OpmManeuverError::UnitError] if a value is given in an unknown unit.
+    /// - [OpmManeuverError::UnknownManeuverFrame] if a `MAN_REF_FRAME` is not supported.
+    pub fn from_opm(opm: &OpmType) -> Result<Vec<Self>, OpmManeuverError> {
+        let segment = &opm.body.segment;
+        let time_system: TimeSystem = segment.metadata.time_system.parse()?;
+        segment
+            .data
+            .maneuver_parameters_list
+            .iter()
+            .map(|maneuver| {
+                Ok(OpmManeuver {
+                    epoch_ignition: ndm_epoch(time_system, &maneuver.man_epoch_ignition)?,
+                    duration: maneuver.duration()?,
+                    delta_mass: maneuver.delta_mass()?,
+                    frame: maneuver.man_ref_frame.parse()?,
+                    delta_v: maneuver.delta_v()?,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the impulsive approximation of the maneuver, which applies the full velocity
+    /// increment at the middle of the burn.
+    pub fn to_impulsive(&self) -> ImpulsiveManeuver<DynTimeScale> {
+        ImpulsiveManeuver::new(
+            self.epoch_ignition + TimeDelta::from_decimal_seconds(self.duration / 2.0),
+            DVec3::from_array(self.delta_v),
+            self.frame,
+        )
+    }
+}
+
+/// Parses an epoch of a navigation data message given in `time_system`.
+fn ndm_epoch(time_system: TimeSystem, epoch: &EpochType) -> Result<DynTime, OpmManeuverError> {
+    if time_system != TimeSystem::Utc {
+        return Ok(Time::from_ndm_epoch(
+            DynTimeScale::try_from(time_system)?,
+            epoch,
+        )?);
+    }
+    // The epoch is parsed into its calendar date and time of day before it is interpreted as UTC
+    let civil = Time::from_ndm_epoch(DynTimeScale::Tai, epoch)?;
+    Ok(Utc::new(civil.date(), civil.time(), &BuiltinLeapSeconds)?.to_dyn_time())
+}
+
+/// Numerical propagator which integrates the equations of motion in Cartesian coordinates,
+/// i.e. Cowell's method, with the sum of the accelerations of its [ForceModel]s.
+///
+/// The integrator is the embedded Runge-Kutta method RK8(7)13M of Prince & Dormand (1981) with
+/// adaptive step size control.
+///
+/// [ImpulsiveManeuver]s are applied instantaneously when the integration passes their epochs.
+/// The integration stops exactly at the epoch of each maneuver and the state at that epoch is the
+/// state before the maneuver.
+pub struct Cowell<T: TimeScale, O: Origin> {
+    initial_state: State<T, O, Icrf>,
+    force_models: Vec<Box<dyn ForceModel>>,
+    maneuvers: Vec<ImpulsiveManeuver<T>>,
+    relative_tolerance: f64,
+    absolute_tolerance: f64,
+    max_steps: usize,
+}
+
+impl<T, O> Cowell<T, O>
+where
+    T: TimeScale + Clone,
+    O: Origin + Clone,
+{
+    /// Creates a new propagator without any force models.
+    pub fn new(initial_state: State<T, O, Icrf>) -> Self {
+        Self {
+            initial_state,
+            force_models: vec![],
+            maneuvers: vec![],
+            relative_tolerance: RELATIVE_TOLERANCE,
+            absolute_tolerance: ABSOLUTE_TOLERANCE,
+            max_steps: MAX_STEPS,
+        }
+    }
+
+    /// Creates a new propagator with point-mass gravity of the central body.
+    pub fn two_body(initial_state: State<T, O, Icrf>) -> Self
+    where
+        O: PointMass,
+    {
+        let gravity = PointMassGravity::new(&initial_state.origin());
+        Self::new(initial_state).with_force_model(gravity)
+    }
+
+    pub fn with_force_model(mut self, force_model: impl ForceModel + 'static) -> Self {
+        self.force_models.push(Box::new(force_model));
+        self
+    }
+
+    /// Adds `maneuver` to the maneuver schedule. Maneuvers at the same epoch are applied in the
+    /// order in which they were added.
+    pub fn with_maneuver(mut self, maneuver: ImpulsiveManeuver<T>) -> Self {
+        let index = self
+            .maneuvers
+            .partition_point(|other| !(maneuver.time() - other.time()).is_negative());
+        self.maneuvers.insert(index, maneuver);
+        self
+    }
+
+    pub fn maneuvers(&self) -> &[ImpulsiveManeuver<T>] {
+        &self.maneuvers
+    }
+
+    pub fn with_tolerances(mut self, relative: f64, absolute: f64) -> Self {
+        self.relative_tolerance = relative;
+        self.absolute_tolerance = absolute;
+        self
+    }
+
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    pub fn initial_state(&self) -> &State<T, O, Icrf> {
+        &self.initial_state
+    }
+
+    /// Returns the sum of the accelerations of all force models.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ForceModelError] if the acceleration of a force model could not be evaluated.
+    pub fn acceleration(&self, t: f64, r: DVec3, v: DVec3) -> Result<DVec3, ForceModelError> {
+        self.force_models
+            .iter()
+            .map(|force_model| force_model.acceleration(t, r, v))
+            .sum()
+    }
+
+    /// Returns the sums of the partial derivatives of the accelerations of all force models with
+    /// respect to the position and the velocity.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ForceModelError] if the partials of a force model could not be evaluated.
+    pub fn partials(&self, t: f64, r: DVec3, v: DVec3) -> Result<(DMat3, DMat3), ForceModelError> {
+        self.force_models
+            .iter()
+            .try_fold((DMat3::ZERO, DMat3::ZERO), |(dr, dv), force_model| {
+                let (dri, dvi) = force_model.partials(t, r, v)?;
+                Ok((dr + dri, dv + dvi))
+            })
+    }
+
+    /// Propagates the initial state to `time` and integrates the variational equations alongside
+    /// the equations of motion.
+    ///
+    /// Returns the state at `time` and the 6x6 state transition matrix, i.e. the partial
+    /// derivatives of the state at `time` with respect to the initial state, both ordered as
+    /// x, y, z, vx, vy, vz. The step size is controlled by the error of the state only.
+    ///
+    /// The partial derivatives of the velocity increments of maneuvers in local orbital frames
+    /// with respect to the state are neglected.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [CowellError] if the integration fails.
+    pub fn propagate_with_stm(
+        &self,
+        time: Time<T>,
+    ) -> Result<(State<T, O, Icrf>, Matrix6), CowellError> {
+        self.integrate_with_stm(&self.initial_state, time)
+    }
+
+    /// Returns the maneuvers which are passed when integrating from `start` to `end`, in the order
+    /// in which they are passed, and whether the integration is forward in time.
+    ///
+    /// Since the state at the epoch of a maneuver is the state before the maneuver, maneuvers at
+    /// `start` are passed in forward direction and maneuvers at `end` in backward direction.
+    fn maneuvers_between(
+        &self,
+        start: &Time<T>,
+        end: &Time<T>,
+    ) -> (Vec<&ImpulsiveManeuver<T>>, bool) {
+        let forward = !(end.clone() - start.clone()).is_negative();
+        let maneuvers = self.maneuvers.iter().filter(|maneuver| {
+            let after_start = !(maneuver.time() - start.clone()).is_negative();
+            let before_end = (maneuver.time() - end.clone()).is_negative();
+            if forward {
+                after_start && before_end
+            } else {
+                !after_start && !before_end
+            }
+        });
+        if forward {
+            (maneuvers.collect(), true)
+        } else {
+            (maneuvers.rev().collect(), false)
+        }
+    }
+
+    /// Integrates `state` and the variational equations to `time`, applying all maneuvers in
+    /// between.
+    pub(crate) fn integrate_with_stm(
+        &self,
+        state: &State<T, O, Icrf>,
+        time: Time<T>,
+    ) -> Result<(State<T, O, Icrf>, Matrix6), CowellError> {
+        let (maneuvers, forward) = self.maneuvers_between(&state.time(), &time);
+        let mut state = state.clone();
+        let mut stm = std::array::from_fn(|i| std::array::from_fn(|j| f64::from(i == j)));
+        for maneuver in maneuvers {
+            let (segment, segment_stm) =
+                self.integrate_segment_with_stm(&state, maneuver.time())?;
+            let velocity = maneuver.apply(segment.position(), segment.velocity(), forward);
+            state = State::new(
+                segment.time(),
+                segment.position(),
+                velocity,
+                segment.origin(),
+                segment.reference_frame(),
+            );
+            stm = mul(&segment_stm, &stm);
+        }
+        let (state, segment_stm) = self.integrate_segment_with_stm(&state, time)?;
+        Ok((state, mul(&segment_stm, &stm)))
+    }
+
+    /// Integrates `state` and the variational equations to `time` without maneuvers.
+    fn integrate_segment_with_stm(
+        &self,
+        state: &State<T, O, Icrf>,
+        time: Time<T>,
+    ) -> Result<(State<T, O, Icrf>, Matrix6), CowellError> {
+        // The columns of the state transition matrix are appended to the state, split into their
+        // position and velocity parts
+        let mut y0 = [DVec3::ZERO; 14];
+        y0[0] = state.position();
+        y0[1] = state.velocity();
+        for j in 0..6 {
+            y0[2 + 2 * j + j / 3][j % 3] = 1.0;
+        }
+        let f = |t: f64, y: &[DVec3; 14]| {
+            let (dr, dv) = self.partials(t, y[0], y[1])?;
+            let mut dy = [DVec3::ZERO; 14];
+            dy[0] = y[1];
+            dy[1] = self.acceleration(t, y[0], y[1])?;
+            for j in 0..6 {
+                dy[2 + 2 * j] = y[3 + 2 * j];
+                dy[3 + 2 * j] = dr * y[2 + 2 * j] + dv * y[3 + 2 * j];
+            }
+            Ok(dy)
+        };
+        let y = self.solve(state, &time, y0, f)?;
+        let stm = std::array::from_fn(|i| std::array::from_fn(|j| y[2 + 2 * j + i / 3][i % 3]));
+        let state = State::new(time, y[0], y[1], state.origin(), state.reference_frame());
+        Ok((state, stm))
+    }
+
+    /// Integrates `state` to `time`, such that the resulting state is exactly at `time`, applying
+    /// all maneuvers in between.
+    fn integrate(
+        &self,
+        state: &State<T, O, Icrf>,
+        time: Time<T>,
+    ) -> Result<State<T, O, Icrf>, CowellError> {
+        let (maneuvers, forward) = self.maneuvers_between(&state.time(), &time);
+        let mut state = state.clone();
+        for maneuver in maneuvers {
+            let segment = self.integrate_segment(&state, maneuver.time())?;
+            let velocity = maneuver.apply(segment.position(), segment.velocity(), forward);
+            state = State::new(
+                segment.time(),
+                segment.position(),
+                velocity,
+                segment.origin(),
+                segment.reference_frame(),
+            );
+        }
+        self.integrate_segment(&state, time)
+    }
+
+    /// Integrates `state` to `time` without maneuvers.
+    fn integrate_segment(
+        &self,
+        state: &State<T, O, Icrf>,
+        time: Time<T>,
+    ) -> Result<State<T, O, Icrf>, CowellError> {
+        let f = |t: f64, y: &[DVec3; 2]| Ok([y[1], self.acceleration(t, y[0], y[1])?]);
+        let y = self.solve(state, &time, [state.position(), state.velocity()], f)?;
+        Ok(State::new(
+            time,
+            y[0],
+            y[1],
+            state.origin(),
+            state.reference_frame(),
+        ))
+    }
+
+    /// Solves the initial value problem `f` with the initial values `y`, whose first two elements
+    /// are the position and the velocity of `state`, from the epoch of `state` to `time`.
+    ///
+    /// `f` is evaluated at seconds since J2000 and its errors abort the integration.
+    fn solve<const N: usize, F>(
+        &self,
+        state: &State<T, O, Icrf>,
+        time: &Time<T>,
+        mut y: [DVec3; N],
+        f: F,
+    ) -> Result<[DVec3; N], CowellError>
+    where
+        F: Fn(f64, &[DVec3; N]) -> Result<[DVec3; N], ForceModelError>,
+    {
+        let t0 = state.time().seconds_since_j2000();
+        let duration = (time.clone() - state.time()).to_decimal_seconds();
+        if duration == 0.0 {
+            return Ok(y);
+        }
+        let f = |t: f64, y: &[DVec3; N]| f(t0 + t, y);
+        let direction = duration.signum();
+        let mut t = 0.0;
+        let mut h = direction * (0.01 * y[0].length() / y[1].length()).min(duration.abs());
+        let mut steps = 0;
+        while t != duration {
+            if steps == self.max_steps {
+                return Err(CowellError::MaxStepsExceeded(self.max_steps));
+            }
+            steps += 1;
+            let last = (duration - t).abs() <= h.abs();
+            if last {
+                h = duration - t;
+            }
+            let (y_new, error) = dormand_prince_step(&f, t, &y, h)?;
+            let error = self.error_norm(&y[..2], &y_new[..2], &error[..2]);
+            if error <= 1.0 {
+                t = if last { duration } else { t + h };
+                y = y_new;
+            }
+            let factor = if error == 0.0 {
+                MAX_FACTOR
+            } else {
+                (SAFETY * error.powf(-1.0 / 8.0)).clamp(MIN_FACTOR, MAX_FACTOR)
+            };
+            h *= factor;
+            if h.abs() <= 16.0 * f64::EPSILON * t0.abs().max(t.abs()).max(1.0) {
+                return Err(CowellError::StepSizeTooSmall(h.abs()));
+            }
+        }
+        Ok(y)
+    }
+
+    /// Returns the root mean square of the local error scaled by the tolerances.
+    fn error_norm(&self, y: &[DVec3], y_new: &[DVec3], error: &[DVec3]) -> f64 {
+        let mut sum = 0.0;
+        for i in 0..y.len() {
+            let scale = DVec3::splat(self.absolute_tolerance)
+                + self.relative_tolerance * y[i].abs().max(y_new[i].abs());
+            sum += (error[i] / scale).length_squared();
+        }
+        (sum / (3 * y.len()) as f64).sqrt()
+    }
+}
+
+impl<T, O> Propagator<T, O, Icrf> for Cowell<T, O>
+where
+    T: TimeScale + Clone,
+    O: Origin + Clone,
+{
+    type Error = CowellError;
+
+    fn propagate(&self, time: Time<T>) -> Result<State<T, O, Icrf>, Self::Error> {
+        self.integrate(&self.initial_state, time)
+    }
+
+    /// Propagates to each of `times` in turn, continuing the integration from the previous
+    /// state, which is efficient for monotonic sequences of times.
+    fn propagate_all(
+        &self,
+        times: impl IntoIterator<Item = Time<T>>,
+    ) -> Result<Trajectory<T, O, Icrf>, Self::Error> {
+        let mut states: Vec<State<T, O, Icrf>> = vec![];
+        for time in times {
+            let previous = states.last().unwrap_or(&self.initial_state);
+            let state = self.integrate(previous, time)?;
+            states.push(state);
+        }
+        Ok(Trajectory::new(&states)?)
+    }
+}
+
+/// The safety factor of the step size control.
+const SAFETY: f64 = 0.9;
+
+/// The minimum factor by which the step size is changed.
+const MIN_FACTOR: f64 = 0.2;
+
+/// The maximum factor by which the step size is changed.
+const MAX_FACTOR: f64 = 5.0;
+
+/// The number of stages of the RK8(7)13M method.
+const STAGES: usize = 13;
+
+/// Performs a single RK8(7)13M step of size `h` and returns the eighth-order solution and the
+/// difference to the embedded seventh-order solution.
+fn dormand_prince_step<const N: usize, F>(
+    f: &F,
+    t: f64,
+    y: &[DVec3; N],
+    h: f64,
+) -> Result<([DVec3; N], [DVec3; N]), ForceModelError>
+where
+    F: Fn(f64, &[DVec3; N]) -> Result<[DVec3; N], ForceModelError>,
+{
+    let mut k = [[DVec3::ZERO; N]; STAGES];
+    for i in 0..STAGES {
+        let mut yi = *y;
+        for (j, kj) in k.iter().enumerate().take(i) {
+            let a = A[i][j];
+            if a != 0.0 {
+                for (yi, kj) in yi.iter_mut().zip(kj) {
+                    *yi += h * a * *kj;
+                }
+            }
+        }
+        k[i] = f(t + C[i] * h, &yi)?;
+    }
+    let mut y_new = *y;
+    let mut error = [DVec3::ZERO; N];
+    for (i, ki) in k.iter().enumerate() {
+        for (n, kin) in ki.iter().enumerate() {
+            y_new[n] += h * B[i] * *kin;
+            error[n] += h * (B[i] - B_HAT[i]) * *kin;
+        }
+    }
+    Ok((y_new, error))
+}
+
+// The coefficients of RK8(7)13M from Prince & Dormand (1981)
+const C: [f64; STAGES] = [
+    0.0,
+    1.0 / 18.0,
+    1.0 / 12.0,
+    1.0 / 8.0,
+    5.0 / 16.0,
+    3.0 / 8.0,
+    59.0 / 400.0,
+    93.0 / 200.0,
+    5490023248.0 / 9719169821.0,
+    13.0 / 20.0,
+    1201146811.0 / 1299019798.0,
+    1.0,
+    1.0,
+];
+
+const A: [[f64; STAGES - 1]; STAGES] = [
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [
+        1.0 / 18.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+    ],
+    [
+        1.0 / 48.0,
+        1.0 / 16.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+    ],
+    [
+        1.0 / 32.0,
+        0.0,
+        3.0 / 32.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+    ],
+    [
+        5.0 / 16.0,
+        0.0,
+        -75.0 / 64.0,
+        75.0 / 64.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+    ],
+    [
+        3.0 / 80.0,
+        0.0,
+        0.0,
+        3.0 / 16.0,
+        3.0 / 20.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+    ],
+    [
+        29443841.0 / 614563906.0,
+        0.0,
+        0.0,
+        77736538.0 / 692538347.0,
+        -28693883.0 / 1125000000.0,
+        23124283.0 / 1800000000.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+    ],
+    [
+        16016141.0 / 946692911.0,
+        0.0,
+        0.0,
+        61564180.0 / 158732637.0,
+        22789713.0 / 633445777.0,
+        545815736.0 / 2771057229.0,
+        -180193667.0 / 1043307555.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+    ],
+    [
+        39632708.0 / 573591083.0,
+        0.0,
+        0.0,
+        -433636366.0 / 683701615.0,
+        -421739975.0 / 2616292301.0,
+        100302831.0 / 723423059.0,
+        790204164.0 / 839813087.0,
+        800635310.0 / 3783071287.0,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+    ],
+    [
+        246121993.0 / 1340847787.0,
+        0.0,
+        0.0,
+        -37695042795.0 / 15268766246.0,
+        -309121744.0 / 1061227803.0,
+        -12992083.0 / 490766935.0,
+        6005943493.0 / 2108947869.0,
+        393006217.0 / 1396673457.0,
+        123872331.0 / 1001029789.0,
+        0.0,
+        0.0,
+        0.0,
+    ],
+    [
+        -1028468189.0 / 846180014.0,
+        0.0,
+        0.0,
+        8478235783.0 / 508512852.0,
+        1311729495.0 / 1432422823.0,
+        -10304129995.0 / 1701304382.0,
+        -48777925059.0 / 3047939560.0,
+        15336726248.0 / 1032824649.0,
+        -45442868181.0 / 3398467696.0,
+        3065993473.0 / 597172653.0,
+        0.0,
+        0.0,
+    ],
+    [
+        185892177.0 / 718116043.0,
+        0.0,
+        0.0,
+        -3185094517.0 / 667107341.0,
+        -477755414.0 / 1098053517.0,
+        -703635378.0 / 230739211.0,
+        5731566787.0 / 1027545527.0,
+        5232866602.0 / 850066563.0,
+        -4093664535.0 / 808688257.0,
+        3962137247.0 / 1805957418.0,
+        65686358.0 / 487910083.0,
+        0.0,
+    ],
+    [
+        403863854.0 / 491063109.0,
+        0.0,
+        0.0,
+        -5068492393.0 / 434740067.0,
+        -411421997.0 / 543043805.0,
+        652783627.0 / 914296604.0,
+        11173962825.0 / 925320556.0,
+        -13158990841.0 / 6184727034.0,
+        3936647629.0 / 1978049680.0,
+        -160528059.0 / 685178525.0,
+        248638103.0 / 1413531060.0,
+        0.0,
+    ],
+];
+
+const B: [f64; STAGES] = [
+    14005451.0 / 335480064.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    -59238493.0 / 1068277825.0,
+    181606767.0 / 758867731.0,
+    561292985.0 / 797845732.0,
+    -1041891430.0 / 1371343529.0,
+    760417239.0 / 1151165299.0,
+    118820643.0 / 751138087.0,
+    -528747749.0 / 2220607170.0,
+    1.0 / 4.0,
+];
+
+const B_HAT: [f64; STAGES] = [
+    13451932.0 / 455176623.0,
+    0.0,
+    0.0,
+    0.0,
+    0.0,
+    -808719846.0 / 976000145.0,
+    1757004468.0 / 5645159321.0,
+    656045339.0 / 265891186.0,
+    -3867574721.0 / 1518517206.0,
+    465885868.0 / 322736535.0,
+    53011238.0 / 667516719.0,
+    2.0 / 45.0,
+    0.0,
+];
+
+#[cfg(test)]
+mod tests {
+    use std::sync::OnceLock;
+
+    use float_eq::assert_float_eq;
+    use lox_bodies::ellipsoid::ReferenceEllipsoid;
+    use lox_ephem::spk::parser::{Spk, parse_daf_spk};
+    use lox_io::ndm::kvn::KvnDeserializer;
+    use lox_math::assert_close;
+    use lox_math::constants::f64::time::SECONDS_PER_DAY;
+    use lox_math::is_close::IsClose;
+    use lox_math::types::units::Angle;
+    use lox_time::time;
+    use lox_time::time_scales::Tdb;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::elements::Keplerian;
+    use crate::propagators::atmosphere::{ExponentialAtmosphere, HarrisPriester};
+    use crate::propagators::semi_analytical::Vallado;
+
+    fn initial_state() -> State<Tdb, Earth, Icrf> {
+        let time = time!(Tdb, 2025, 1, 1).unwrap();
+        Keplerian::new(
+            time,
+            Earth,
+            Earth.equatorial_radius() + 2000.0,
+            0.1,
+            Angle::from_degrees(51.6),
+            Angle::from_radians(0.5),
+            Angle::from_radians(1.0),
+            Angle::ZERO,
+        )
+        .to_cartesian()
+    }
+
+    fn energy(state: &State<Tdb, Earth, Icrf>, zonals: Option<&ZonalHarmonics>) -> f64 {
+        let r = state.position();
+        let kinetic = state.velocity().length_squared() / 2.0;
+        let potential = Earth.gravitational_parameter() / r.length()
+            + zonals.map(|zonals| zonals.potential(r)).unwrap_or_default();
+        kinetic - potential
+    }
+
+    #[test]
+    fn test_cowell_two_body_energy_conservation() {
+        let s0 = initial_state();
+        let period = s0.to_keplerian().orbital_period().to_decimal_seconds();
+        let propagator = Cowell::two_body(s0).with_tolerances(1e-12, 1e-14);
+        let time = s0.time() + TimeDelta::from_decimal_seconds(100.0 * period);
+        let s1 = propagator.propagate(time).unwrap();
+        let e0 = energy(&s0, None);
+        let e1 = energy(&s1, None);
+        assert_float_eq!(e1, e0, r2nd <= 1e-10);
+    }
+
+    #[test]
+    fn test_cowell_two_body_against_vallado() {
+        let s0 = initial_state();
+        let time = s0.time() + TimeDelta::from_days(1.0).unwrap();
+        let expected = Vallado::new(s0).propagate(time).unwrap();
+        let actual = Cowell::two_body(s0).propagate(time).unwrap();
+        assert_eq!(actual.time(), time);
+        // One metre and one millimetre per second
+        assert!((actual.position() - expected.position()).length() < 1e-3);
+        assert!((actual.velocity() - expected.velocity()).length() < 1e-6);
+    }
+
+    #[test]
+    fn test_cowell_zonal_harmonics_energy_conservation() {
+        let s0 = initial_state();
+        let zonals = ZonalHarmonics::new(&Earth, &EARTH_ZONAL_HARMONICS);
+        let propagator = Cowell::two_body(s0)
+            .with_force_model(zonals.clone())
+            .with_tolerances(1e-12, 1e-14);
+        let time = s0.time() + TimeDelta::from_days(2.0).unwrap();
+        let s1 = propagator.propagate(time).unwrap();
+        let e0 = energy(&s0, Some(&zonals));
+        let e1 = energy(&s1, Some(&zonals));
+        assert_float_eq!(e1, e0, r2nd <= 1e-10);
+        // Without the potential of the zonal harmonics, the energy is not conserved
+        assert!((energy(&s1, None) - energy(&s0, None)).abs() > 1e-6);
+    }
+
+    #[test]
+    fn test_cowell_impulsive_maneuver_raises_apoapsis() {
+        let time = time!(Tdb, 2025, 1, 1).unwrap();
+        let mu = Earth.gravitational_parameter();
+        let r0 = 7000.0;
+        let s0 = Keplerian::new(
+            time,
+            Earth,
+            r0,
+            0.0,
+            Angle::from_radians(0.5),
+            Angle::ZERO,
+            Angle::ZERO,
+            Angle::ZERO,
+        )
+        .to_cartesian();
+        let v0 = (mu / r0).sqrt();
+        let dv = 0.1;
+        let epoch = time + TimeDelta::from_decimal_seconds(600.0);
+        let maneuver = ImpulsiveManeuver::new(
+            epoch,
+            DVec3::new(0.0, dv, 0.0),
+            ManeuverFrame::Local(LocalOrbitalFrame::Rtn),
+        );
+        let propagator = Cowell::two_body(s0)
+            .with_tolerances(1e-12, 1e-14)
+            .with_maneuver(maneuver.clone());
+
+        // The state at the epoch of the maneuver is the state before the maneuver
+        let before = propagator.propagate(epoch).unwrap();
+        assert_float_eq!(before.velocity().length(), v0, rel <= 1e-10);
+
+        // The maneuver point becomes the periapsis and the apoapsis follows from the vis-viva
+        // equation
+        let sma = 1.0 / (2.0 / r0 - (v0 + dv).powi(2) / mu);
+        let apoapsis = 2.0 * sma - r0;
+        let period = std::f64::consts::TAU * (sma.powi(3) / mu).sqrt();
+        let times: Vec<Time<Tdb>> = (0..5)
+            .map(|i| epoch + TimeDelta::from_decimal_seconds(i as f64 * period / 4.0))
+            .collect();
+        let trajectory = propagator.propagate_all(times.clone()).unwrap();
+        let states = trajectory.states();
+        assert_float_eq!(states[2].position().length(), apoapsis, rel <= 1e-9);
+        assert_float_eq!(states[4].position().length(), r0, rel <= 1e-9);
+        let after = propagator.propagate(times[2]).unwrap();
+        assert!((after.position() - states[2].position()).length() < 1e-6);
+
+        let (with_stm, _) = propagator.propagate_with_stm(times[2]).unwrap();
+        assert!((with_stm.position() - after.position()).length() < 1e-6);
+
+        // Propagating backwards reverts the maneuver
+        let reverse = Cowell::two_body(after)
+            .with_tolerances(1e-12, 1e-14)
+            .with_maneuver(maneuver);
+        let s1 = reverse.propagate(time).unwrap();
+        assert!((s1.position() - s0.position()).length() < 1e-6);
+        assert!((s1.velocity() - s0.velocity()).length() < 1e-9);
+    }
+
+    #[test]
+    fn test_cowell_maneuver_order() {
+        let s0 = initial_state();
+        let epoch = s0.time() + TimeDelta::from_decimal_seconds(60.0);
+        let later = epoch + TimeDelta::from_decimal_seconds(60.0);
+        let propagator = Cowell::two_body(s0)
+            .with_maneuver(ImpulsiveManeuver::new(
+                later,
+                DVec3::X,
+                ManeuverFrame::Inertial,
+            ))
+            .with_maneuver(ImpulsiveManeuver::new(
+                epoch,
+                DVec3::Y,
+                ManeuverFrame::Inertial,
+            ));
+        let times: Vec<Time<Tdb>> = propagator.maneuvers().iter().map(|m| m.time()).collect();
+        assert_eq!(times, vec![epoch, later]);
+        // A maneuver at the initial epoch is applied
+        let propagator = Cowell::two_body(s0).with_maneuver(ImpulsiveManeuver::new(
+            s0.time(),
+            DVec3::Z,
+            ManeuverFrame::Inertial,
+        ));
+        let s1 = propagator.propagate(epoch).unwrap();
+        let expected = Cowell::two_body(State::new(
+            s0.time(),
+            s0.position(),
+            s0.velocity() + DVec3::Z,
+            Earth,
+            Icrf,
+        ))
+        .propagate(epoch)
+        .unwrap();
+        assert!((s1.position() - expected.position()).length() < 1e-9);
+    }
+
+    #[rstest]
+    #[case("EME2000", ManeuverFrame::Inertial)]
+    #[case("ICRF", ManeuverFrame::Inertial)]
+    #[case("rtn", ManeuverFrame::Local(LocalOrbitalFrame::Rtn))]
+    #[case("RSW", ManeuverFrame::Local(LocalOrbitalFrame::Rtn))]
+    #[case("LVLH", ManeuverFrame::Local(LocalOrbitalFrame::Lvlh))]
+    fn test_maneuver_frame_from_str(#[case] frame: &str, #[case] expected: ManeuverFrame) {
+        assert_eq!(frame.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn test_maneuver_frame_from_str_unknown() {
+        assert_eq!(
+            "TOD".parse::<ManeuverFrame>(),
+            Err(UnknownManeuverFrameError("TOD".to_string()))
+        );
+    }
+
+    const OPM: &str = r#"CCSDS_OPM_VERS = 3.0
+CREATION_DATE = 2021-06-03T05:33:00.123
+ORIGINATOR = GSOC
+OBJECT_NAME = EUTELSAT W4
+OBJECT_ID = 2021-028A
+CENTER_NAME = EARTH
+REF_FRAME = TOD
+TIME_SYSTEM = UTC
+EPOCH = 2021-06-03T00:00:00.000
+X = 6655.9942 [km]
+Y = -40218.5751 [km]
+Z = -82.9177 [km]
+X_DOT = 3.11548208 [km/s]
+Y_DOT = 0.47042605 [km/s]
+Z_DOT = -0.00101495 [km/s]
+MAN_EPOCH_IGNITION = 2021-06-03T09:00:34.1
+MAN_DURATION = 132.60 [s]
+MAN_DELTA_MASS = -18.418 [kg]
+MAN_REF_FRAME = EME2000
+MAN_DV_1 = -23.257 [m/s]
+MAN_DV_2 = 16.8316 [m/s]
+MAN_DV_3 = -8.93444 [m/s]
+MAN_EPOCH_IGNITION = 2021-157T18:59:21.0
+MAN_DURATION = 0.00
+MAN_DELTA_MASS = -1.469
+MAN_REF_FRAME = RTN
+MAN_DV_1 = 0.00101500
+MAN_DV_2 = -0.00187300
+MAN_DV_3 = 0.00000000
+"#;
+
+    #[test]
+    fn test_opm_maneuvers() {
+        let opm = OpmType::from_kvn_str(OPM).unwrap();
+        let maneuvers = OpmManeuver::from_opm(&opm).unwrap();
+        assert_eq!(maneuvers.len(), 2);
+
+        let first = &maneuvers[0];
+        let ignition = Utc::from_iso("2021-06-03T09:00:34.1")
+            .unwrap()
+            .to_dyn_time();
+        assert_eq!(first.epoch_ignition, ignition);
+        assert_eq!(first.duration, 132.6);
+        assert_eq!(first.delta_mass, -18.418);
+        assert_eq!(first.frame, ManeuverFrame::Inertial);
+        assert_float_eq!(
+            first.delta_v,
+            [-0.023257, 0.0168316, -0.00893444],
+            rel_all <= 1e-12
+        );
+        let impulsive = first.to_impulsive();
+        assert_eq!(
+            impulsive.time(),
+            ignition + TimeDelta::from_decimal_seconds(66.3)
+        );
+        assert_eq!(impulsive.frame(), ManeuverFrame::Inertial);
+
+        // The epoch of the second maneuver is given as day of year
+        let second = &maneuvers[1];
+        let ignition = Utc::from_iso("2021-06-06T18:59:21.0")
+            .unwrap()
+            .to_dyn_time();
+        assert_eq!(second.epoch_ignition, ignition);
+        assert_eq!(second.frame, ManeuverFrame::Local(LocalOrbitalFrame::Rtn));
+        assert_eq!(second.to_impulsive().time(), ignition);
+        assert_eq!(second.delta_v, [0.001015, -0.001873, 0.0]);
+    }
+
+    #[test]
+    fn test_opm_maneuvers_time_scale() {
+        let opm =
+            OpmType::from_kvn_str(&OPM.replace("TIME_SYSTEM = UTC", "TIME_SYSTEM = TDB")).unwrap();
+        let maneuvers = OpmManeuver::from_opm(&opm).unwrap();
+        let expected = Time::from_iso(DynTimeScale::Tdb, "2021-06-03T09:00:34.1").unwrap();
+        assert_eq!(maneuvers[0].epoch_ignition, expected);
+    }
+
+    #[test]
+    fn test_opm_maneuvers_unknown_frame() {
+        let opm = OpmType::from_kvn_str(&OPM.replace("= RTN", "= TOD")).unwrap();
+        assert_eq!(
+            OpmManeuver::from_opm(&opm),
+            Err(OpmManeuverError::UnknownManeuverFrame(
+                UnknownManeuverFrameError("TOD".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn test_zonal_harmonics_j2() {
+        let zonals = ZonalHarmonics::new(&Earth, &EARTH_ZONAL_HARMONICS[..1]);
+        let r = DVec3::new(5000.0, 3000.0, 4000.0);
+        let mu = Earth.gravitational_parameter();
+        let j2 = EARTH_ZONAL_HARMONICS[0];
+        let re = Earth.equatorial_radius();
+        let rn = r.length();
+        let z2 = (r.z / rn).powi(2);
+        let factor = 1.5 * j2 * mu * re.powi(2) / rn.powi(5);
+        let expected = DVec3::new(
+            factor * r.x * (5.0 * z2 - 1.0),
+            factor * r.y * (5.0 * z2 - 1.0),
+            factor * r.z * (5.0 * z2 - 3.0),
+        );
+        let actual = zonals.acceleration(0.0, r, DVec3::ZERO).unwrap();
+        assert_float_eq!(actual.x, expected.x, rel <= 1e-12);
+        assert_float_eq!(actual.y, expected.y, rel <= 1e-12);
+        assert_float_eq!(actual.z, expected.z, rel <= 1e-12);
+    }
+
+    #[test]
+    fn test_drag_force() {
+        let drag = DragForce::new(2.2, 10.0, 1000.0, ExponentialAtmosphere);
+        assert_eq!(drag.drag_coefficient(), 2.2);
+        assert_eq!(drag.area(), 10.0);
+        assert_eq!(drag.mass(), 1000.0);
+        assert_eq!(drag.density_model(), &ExponentialAtmosphere);
+
+        // Over the pole the atmosphere does not move with respect to the inertial frame
+        let r = DVec3::new(
+            0.0,
+            0.0,
+            ReferenceEllipsoid::WGS84.semi_minor_axis() + 400.0,
+        );
+        let v = DVec3::new(7.67, 0.0, 0.0);
+        let density = ExponentialAtmosphere.density(400.0);
+        let expected = -0.5 * 2.2 * 10.0 / 1000.0 * density * (7670.0 * 7670.0) / 1000.0;
+        let actual = drag.acceleration(0.0, r, v).unwrap();
+        assert_float_eq!(actual.x, expected, rel <= 1e-9);
+        assert_eq!(actual.y, 0.0);
+        assert_eq!(actual.z, 0.0);
+
+        // Above the equator the acceleration opposes the velocity relative to the atmosphere
+        let r = DVec3::new(Earth.equatorial_radius() + 400.0, 0.0, 0.0);
+        let v = DVec3::new(0.0, 7.67, 0.0);
+        let actual = drag.acceleration(0.0, r, v).unwrap();
+        let v_rel = 7.67 - Earth.rotation_rate(0.0).to_radians_per_second() * r.x;
+        assert_float_eq!(actual.y, expected * (v_rel / 7.67).powi(2), rel <= 1e-4);
+
+        // No drag outside of the Harris-Priester model
+        let drag = DragForce::new(2.2, 10.0, 1000.0, HarrisPriester::new());
+        let r = DVec3::new(Earth.equatorial_radius() + 1500.0, 0.0, 0.0);
+        assert_eq!(drag.acceleration(0.0, r, v).unwrap(), DVec3::ZERO);
+    }
+
+    #[test]
+    fn test_cowell_drag_decay() {
+        let time = time!(Tdb, 2025, 1, 1).unwrap();
+        let s0 = Keplerian::new(
+            time,
+            Earth,
+            Earth.equatorial_radius() + 300.0,
+            0.001,
+            Angle::from_degrees(51.6),
+            Angle::from_radians(0.5),
+            Angle::from_radians(1.0),
+            Angle::ZERO,
+        )
+        .to_cartesian();
+        let t1 = time + TimeDelta::from_days(1.0).unwrap();
+        let a0 = s0.to_keplerian().semi_major_axis();
+        let two_body = Cowell::two_body(s0).propagate(t1).unwrap();
+        assert_float_eq!(two_body.to_keplerian().semi_major_axis(), a0, abs <= 1e-4);
+        let semi_major_axis = |propagator: Cowell<Tdb, Earth>| {
+            propagator
+                .propagate(t1)
+                .unwrap()
+                .to_keplerian()
+                .semi_major_axis()
+        };
+        let drag = DragForce::new(2.2, 10.0, 1000.0, ExponentialAtmosphere);
+        let a1 = semi_major_axis(Cowell::two_body(s0).with_force_model(drag));
+        assert!(a1 < a0 - 0.1 && a1 > a0 - 10.0);
+        let drag = DragForce::new(2.2, 10.0, 1000.0, HarrisPriester::new());
+        let a1 = semi_major_axis(Cowell::two_body(s0).with_force_model(drag));
+        assert!(a1 < a0 - 0.1 && a1 > a0 - 10.0);
+    }
+
+    #[test]
+    fn test_cowell_round_trip() {
+        let s0 = initial_state();
+        let propagator = Cowell::two_body(s0).with_tolerances(1e-12, 1e-14);
+        let t1 = s0.time() + TimeDelta::from_decimal_seconds(0.3 * SECONDS_PER_DAY);
+        let s1 = propagator.propagate(t1).unwrap();
+        let s2 = Cowell::two_body(s1)
+            .with_tolerances(1e-12, 1e-14)
+            .propagate(s0.time())
+            .unwrap();
+        assert_eq!(s2.time(), s0.time());
+        assert!((s2.position() - s0.position()).length() < 1e-6);
+        assert!((s2.velocity() - s0.velocity()).length() < 1e-9);
+        assert_eq!(propagator.propagate(s0.time()).unwrap(), s0);
+    }
+
+    fn ephemeris() -> Arc<Spk> {
+        let contents = std::fs::read(format!(
+            "{}/../../data/de440s.bsp",
+            env!("CARGO_MANIFEST_DIR")
+        ))
+        .unwrap();
+        static EPHEMERIS: OnceLock<Arc<Spk>> = OnceLock::new();
+        EPHEMERIS
+            .get_or_init(|| Arc::new(parse_daf_spk(&contents).unwrap()))
+            .clone()
+    }
+
+    #[test]
+    fn test_third_body_acceleration() {
+        let mu = Sun.gravitational_parameter();
+        let r = DVec3::new(-1234.5, 5678.9, 3456.7);
+
+        // The formulation agrees with the difference of the direct and the indirect term
+        let s = DVec3::new(3e4, -4e4, 1e4);
+        let expected = mu * ((s - r) / (s - r).length().powi(3) - s / s.length().powi(3));
+        assert_close!(third_body_acceleration(mu, r, s), expected, 0.0, 1e-13);
+
+        // For distant perturbers it converges to the tidal acceleration without cancellation
+        let s = DVec3::new(1e12, 2e12, -1e12);
+        let u = s.normalize();
+        let expected = mu / s.length().powi(3) * (3.0 * u.dot(r) * u - r);
+        assert_close!(third_body_acceleration(mu, r, s), expected, 0.0, 1e-7);
+    }
+
+    #[test]
+    fn test_third_body_force() {
+        let ephemeris = ephemeris();
+        let force = ThirdBodyForce::sun_and_moon(Earth, ephemeris.clone());
+        assert_eq!(force.central_body(), Earth.id());
+        assert_eq!(
+            force.perturbers(),
+            [
+                (Sun.id(), Sun.gravitational_parameter()),
+                (Moon.id(), Moon.gravitational_parameter())
+            ]
+        );
+
+        let t = initial_state().time().seconds_since_j2000();
+        let r = initial_state().position();
+        let mut expected = DVec3::ZERO;
+        for (body, mu) in force.perturbers() {
+            let s = body_position(ephemeris.as_ref(), t, Earth.id().0, body.0).unwrap();
+            expected += *mu * ((s - r) / (s - r).length().powi(3) - s / s.length().powi(3));
+        }
+        assert_close!(
+            force.acceleration(t, r, DVec3::ZERO).unwrap(),
+            expected,
+            0.0,
+            1e-10
+        );
+
+        // The Moon dominates the third-body perturbation in Earth orbit
+        let moon = ThirdBodyForce::new(Earth, ephemeris.clone())
+            .with_perturber(Moon, Moon.gravitational_parameter());
+        let sun = ThirdBodyForce::new(Earth, ephemeris)
+            .with_perturber(Sun, Sun.gravitational_parameter());
+        let moon = moon.try_acceleration(t, r).unwrap().length();
+        let sun = sun.try_acceleration(t, r).unwrap().length();
+        assert!(moon > 1.5 * sun && moon < 3.0 * sun);
+
+        // The ephemeris covers 1849 to 2150
+        assert!(force.try_acceleration(-1e10, r).is_err());
+    }
+
+    #[test]
+    fn test_srp_force_magnitude() {
+        let srp = SrpForce::new(1.3, 0.02, Earth, ephemeris());
+        assert_eq!(srp.reflectivity_coefficient(), 1.3);
+        assert_eq!(srp.area_to_mass(), 0.02);
+        assert!(srp.shadow());
+
+        // The radiation pressure at 1 AU is about 4.54e-6 N/m²
+        let d = ASTRONOMICAL_UNIT * DVec3::new(0.6, -0.8, 0.0);
+        let actual = srp.sunlit_acceleration(d);
+        assert_float_eq!(actual.length(), 4.54e-6 * 1.3 * 0.02 * 1e-3, rel <= 1e-3);
+        assert_close!(actual.normalize(), d.normalize(), 1e-15);
+
+        // The flux decreases with the inverse square of the distance from the Sun
+        let actual = srp.sunlit_acceleration(2.0 * d);
+        assert_float_eq!(
+            actual.length(),
+            4.54e-6 * 1.3 * 0.02 * 1e-3 / 4.0,
+            rel <= 1e-3
+        );
+    }
+
+    #[test]
+    fn test_srp_force_shadow() {
+        let ephemeris = ephemeris();
+        let srp = SrpForce::new(1.3, 0.02, Earth, ephemeris.clone());
+        let t = initial_state().time().seconds_since_j2000();
+        let sun = body_position(ephemeris.as_ref(), t, Earth.id().0, Sun.id().0).unwrap();
+
+        // Sunlit
+        let r = 42164.0 * sun.normalize();
+        let expected = srp.sunlit_acceleration(r - sun);
+        assert_eq!(srp.acceleration(t, r, DVec3::ZERO).unwrap(), expected);
+
+        // Umbra
+        let r = -42164.0 * sun.normalize();
+        assert_eq!(srp.acceleration(t, r, DVec3::ZERO).unwrap(), DVec3::ZERO);
+        let srp = srp.with_shadow(false);
+        assert!(!srp.shadow());
+        assert_eq!(
+            srp.acceleration(t, r, DVec3::ZERO).unwrap(),
+            srp.sunlit_acceleration(r - sun)
+        );
+
+        // The acceleration is attenuated continuously across the penumbra
+        let srp = srp.with_shadow(true);
+        let normal = sun.cross(DVec3::Z).normalize();
+        let fractions: Vec<f64> = (0..=200)
+            .map(|i| {
+                let r = -42164.0 * sun.normalize() + (6000.0 + 5.0 * i as f64) * normal;
+                srp.try_acceleration(t, r).unwrap().length()
+                    / srp.sunlit_acceleration(r - sun).length()
+            })
+            .collect();
+        assert_eq!(fractions[0], 0.0);
+        assert_float_eq!(fractions[200], 1.0, abs <= 1e-15);
+        assert!(fractions.windows(2).all(|w| w[1] >= w[0]));
+        assert!(fractions.windows(2).all(|w| w[1] - w[0] < 0.05));
+        assert!(fractions.iter().any(|f| *f > 0.1 && *f < 0.9));
+
+        assert!(srp.try_acceleration(-1e10, r).is_err());
+        assert!(srp.acceleration(-1e10, r, DVec3::ZERO).is_err());
+    }
+
+    #[test]
+    fn test_cowell_third_body_geo_inclination() {
+        // The luni-solar perturbation rotates the orbital plane of an initially equatorial
+        // geostationary orbit, such that its inclination grows by about 0.75° to 0.95° per year
+        // depending on the orientation of the lunar orbit. The lunar orbit is close to its
+        // maximum inclination with respect to the equator in 2025, such that the inclination
+        // reaches the upper end of this range, i.e. between 0.9° and 1.0° after one year
+        let time = time!(Tdb, 2025, 1, 1).unwrap();
+        let s0 = Keplerian::new(
+            time,
+            Earth,
+            42164.0,
+            0.0,
+            Angle::ZERO,
+            Angle::ZERO,
+            Angle::ZERO,
+            Angle::ZERO,
+        )
+        .to_cartesian();
+        let t1 = time + TimeDelta::from_days(365.25).unwrap();
+        let propagator = Cowell::two_body(s0)
+            .with_force_model(ThirdBodyForce::sun_and_moon(Earth, ephemeris()))
+            .with_tolerances(1e-9, 1e-9);
+        let inclination = propagator
+            .propagate(t1)
+            .unwrap()
+            .to_keplerian()
+            .inclination()
+            .to_degrees();
+        assert!(
+            inclination > 0.9 && inclination < 1.0,
+            "inclination {inclination}°"
+        );
+        let two_body = Cowell::two_body(s0)
+            .with_tolerances(1e-9, 1e-9)
+            .propagate(t1)
+            .unwrap();
+        assert!(two_body.to_keplerian().inclination().to_degrees() < 1e-6);
+    }
+
+    #[test]
+    fn test_cowell_third_body_ephemeris_error() {
+        // The ephemeris covers 1849 to 2150
+        let time = time!(Tdb, 2149, 12, 1).unwrap();
+        let s0 = Keplerian::new(
+            time,
+            Earth,
+            42164.0,
+            0.0,
+            Angle::ZERO,
+            Angle::ZERO,
+            Angle::ZERO,
+            Angle::ZERO,
+        )
+        .to_cartesian();
+        let t1 = time + TimeDelta::from_days(365.25).unwrap();
+        let propagator =
+            Cowell::two_body(s0).with_force_model(ThirdBodyForce::sun_and_moon(Earth, ephemeris()));
+        assert!(matches!(
+            propagator.propagate(t1),
+            Err(CowellError::ForceModelError(_))
+        ));
+        assert!(matches!(
+            propagator.propagate_with_stm(t1),
+            Err(CowellError::ForceModelError(_))
+        ));
+        let t = t1.seconds_since_j2000();
+        assert!(
+            propagator
+                .acceleration(t, s0.position(), s0.velocity())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_cowell_srp_ephemeris_error() {
+        // The ephemeris covers 1849 to 2150
+        let time = time!(Tdb, 2149, 12, 1).unwrap();
+        let s0 = Keplerian::new(
+            time,
+            Earth,
+            42164.0,
+            0.0,
+            Angle::ZERO,
+            Angle::ZERO,
+            Angle::ZERO,
+            Angle::ZERO,
+        )
+        .to_cartesian();
+        let t1 = time + TimeDelta::from_days(365.25).unwrap();
+        let propagator =
+            Cowell::two_body(s0).with_force_model(SrpForce::new(1.3, 0.02, Earth, ephemeris()));
+        assert!(matches!(
+            propagator.propagate(t1),
+            Err(CowellError::ForceModelError(_))
+        ));
+    }
+
+    /// Approximates the state transition matrix of `propagate` from `s0` with central
+    /// differences.
+    fn finite_difference_stm(
+        s0: State<Tdb, Earth, Icrf>,
+        propagate: impl Fn(State<Tdb, Earth, Icrf>) -> State<Tdb, Earth, Icrf>,
+    ) -> Matrix6 {
+        let mut stm = [[0.0; 6]; 6];
+        for j in 0..6 {
+            let h = if j < 3 { 1e-3 } else { 1e-6 };
+            let perturbed = |sign: f64| {
+                let mut r = s0.position();
+                let mut v = s0.velocity();
+                if j < 3 {
+                    r[j] += sign * h;
+                } else {
+                    v[j - 3] += sign * h;
+                }
+                let s1 = propagate(State::new(s0.time(), r, v, Earth, Icrf));
+                [s1.position(), s1.velocity()]
+            };
+            let (plus, minus) = (perturbed(1.0), perturbed(-1.0));
+            for i in 0..6 {
+                stm[i][j] = (plus[i / 3][i % 3] - minus[i / 3][i % 3]) / (2.0 * h);
+            }
+        }
+        stm
+    }
+
+    fn assert_stm_close(actual: &Matrix6, expected: &Matrix6, rel: f64) {
+        let scale = expected
+            .iter()
+            .flatten()
+            .fold(0.0f64, |m, x| m.max(x.abs()));
+        for (actual, expected) in actual.iter().flatten().zip(expected.iter().flatten()) {
+            assert_float_eq!(*actual, *expected, abs <= rel * scale);
+        }
+    }
+
+    #[test]
+    fn test_point_mass_gravity_partials() {
+        struct FiniteDifferences(PointMassGravity);
+
+        impl ForceModel for FiniteDifferences {
+            fn acceleration(&self, t: f64, r: DVec3, v: DVec3) -> Result<DVec3, ForceModelError> {
+                self.0.acceleration(t, r, v)
+            }
+        }
+
+        let gravity = PointMassGravity::new(&Earth);
+        let s0 = initial_state();
+        let (dr, dv) = gravity.partials(0.0, s0.position(), s0.velocity()).unwrap();
+        let (dr_fd, dv_fd) = FiniteDifferences(gravity)
+            .partials(0.0, s0.position(), s0.velocity())
+            .unwrap();
+        assert!(dr.abs_diff_eq(dr_fd, 1e-15));
+        assert_eq!(dv, DMat3::ZERO);
+        assert_eq!(dv_fd, DMat3::ZERO);
+        // The gravity gradient is symmetric and trace-free
+        assert!(dr.abs_diff_eq(dr.transpose(), 1e-20));
+        assert_float_eq!(dr.x_axis.x + dr.y_axis.y + dr.z_axis.z, 0.0, abs <= 1e-20);
+    }
+
+    #[test]
+    fn test_cowell_stm_two_body() {
+        let s0 = initial_state();
+        let t1 = s0.time() + TimeDelta::from_decimal_seconds(5000.0);
+        let propagator = Cowell::two_body(s0);
+        let (s1, stm) = propagator.propagate_with_stm(t1).unwrap();
+        assert_eq!(s1, propagator.propagate(t1).unwrap());
+
+        // The state transition matrix matches the one of the analytical Keplerian propagator
+        let expected = finite_difference_stm(s0, |s| Vallado::new(s).propagate(t1).unwrap());
+        assert_stm_close(&stm, &expected, 1e-7);
+
+        // The flow of the two-body problem is symplectic, i.e. Φᵀ J Φ = J, where the residual is
+        // small compared to the squared magnitude of the entries of Φ of up to about 1e3
+        let j: Matrix6 = std::array::from_fn(|i| {
+            std::array::from_fn(|k| match (i, k) {
+                (i, k) if k == i + 3 => 1.0,
+                (i, k) if i == k + 3 => -1.0,
+                _ => 0.0,
+            })
+        });
+        let mul = |a: &Matrix6, b: &Matrix6| -> Matrix6 {
+            std::array::from_fn(|i| {
+                std::array::from_fn(|k| (0..6).map(|l| a[i][l] * b[l][k]).sum())
+            })
+        };
+        let stm_t: Matrix6 = std::array::from_fn(|i| std::array::from_fn(|k| stm[k][i]));
+        assert_stm_close(&mul(&mul(&stm_t, &j), &stm), &j, 1e-5);
+
+        // At the initial epoch the state transition matrix is the identity
+        let (_, stm) = propagator.propagate_with_stm(s0.time()).unwrap();
+        let identity: Matrix6 =
+            std::array::from_fn(|i| std::array::from_fn(|k| if i == k { 1.0 } else { 0.0 }));
+        assert_eq!(stm, identity);
+    }
+
+    #[test]
+    fn test_cowell_stm_perturbed() {
+        // The default partials of the perturbations yield the sensitivities of the propagation
+        let s0 = initial_state();
+        let t1 = s0.time() + TimeDelta::from_decimal_seconds(5000.0);
+        let propagator = |s| {
+            Cowell::two_body(s)
+                .with_force_model(ZonalHarmonics::new(&Earth, &EARTH_ZONAL_HARMONICS))
+                .with_force_model(DragForce::new(2.2, 10.0, 100.0, ExponentialAtmosphere))
+                .with_tolerances(1e-13, 1e-13)
+        };
+        let (_, stm) = propagator(s0).propagate_with_stm(t1).unwrap();
+        let expected = finite_difference_stm(s0, |s| propagator(s).propagate(t1).unwrap());
+        assert_stm_close(&stm, &expected, 1e-6);
+    }
+
+    #[test]
+    fn test_cowell_propagate_all() {
+        let s0 = initial_state();
+        let propagator = Cowell::two_body(s0);
+        let times: Vec<_> = (0..10)
+            .map(|i| s0.time() + TimeDelta::from_decimal_seconds(600.0 * i as f64))
+            .collect();
+        let trajectory = propagator.propagate_all(times.clone()).unwrap();
+        let last = *trajectory.states().last().unwrap();
+        let expected = propagator.propagate(times[9]).unwrap();
+        assert_eq!(last.time(), times[9]);
+        assert!((last.position() - expected.position()).length() < 1e-6);
+    }
+
+    #[test]
+    fn test_cowell_max_steps_exceeded() {
+        let s0 = initial_state();
+        let propagator = Cowell::two_body(s0).with_max_steps(3);
+        let time = s0.time() + TimeDelta::from_days(1.0).unwrap();
+        assert_eq!(
+            propagator.propagate(time),
+            Err(CowellError::MaxStepsExceeded(3))
+        );
+    }
+}
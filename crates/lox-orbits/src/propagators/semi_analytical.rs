@@ -6,7 +6,9 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use glam::DVec3;
 use lox_time::Time;
+use lox_time::deltas::TimeDelta;
 use lox_time::time_scales::{DynTimeScale, TimeScale};
 use thiserror::Error;
 
@@ -17,6 +19,8 @@ use crate::propagators::{Propagator, stumpff};
 use crate::states::{DynState, State};
 use crate::trajectories::TrajectoryError;
 
+const MAX_ITER: i32 = 300;
+
 #[derive(Debug, Error, Eq, PartialEq)]
 pub enum ValladoError {
     #[error("did not converge")]
@@ -74,7 +78,7 @@ where
     pub fn new(initial_state: State<T, O, Icrf>) -> Self {
         Self {
             initial_state,
-            max_iter: 300,
+            max_iter: MAX_ITER,
         }
     }
 }
@@ -92,7 +96,7 @@ impl DynVallado {
         }
         Ok(Self {
             initial_state,
-            max_iter: 300,
+            max_iter: MAX_ITER,
         })
     }
 }
@@ -111,59 +115,108 @@ where
         let mu = self.gravitational_parameter();
         let t0 = self.initial_state.time();
         let dt = (time.clone() - t0).to_decimal_seconds();
-        let sqrt_mu = mu.sqrt();
-        let p0 = self.initial_state.position();
-        let v0 = self.initial_state.velocity();
-        let dot_p0v0 = p0.dot(v0);
-        let norm_p0 = p0.length();
-        let alpha = -v0.dot(v0) / mu + 2.0 / norm_p0;
-
-        let mut xi_new = if alpha > 0.0 {
-            sqrt_mu * dt * alpha
-        } else if alpha < 0.0 {
-            dt.signum()
-                * (-1.0 / alpha).powf(0.5)
-                * (-2.0 * mu * alpha * dt
-                    / (dot_p0v0 + dt.signum() * (-mu / alpha).sqrt() * (1.0 - norm_p0 * alpha)))
-                    .ln()
+        let (p, v) = universal_variables(
+            self.initial_state.position(),
+            self.initial_state.velocity(),
+            dt,
+            mu,
+            self.max_iter,
+        )?;
+        Ok(State::new(time, p, v, origin, frame))
+    }
+}
+
+/// Analytical two-body propagation for elliptic, parabolic, and hyperbolic
+/// orbits based on universal variables
+pub struct TwoBody;
+
+impl TwoBody {
+    /// Advances `state` by `dt` along the Keplerian orbit defined by the
+    /// gravitational parameter `mu`.
+    pub fn propagate<T, O, R>(
+        state: &State<T, O, R>,
+        dt: TimeDelta,
+        mu: f64,
+    ) -> Result<State<T, O, R>, ValladoError>
+    where
+        T: TimeScale + Clone,
+        O: Origin + Clone,
+        R: ReferenceFrame + Clone,
+    {
+        let (p, v) = universal_variables(
+            state.position(),
+            state.velocity(),
+            dt.to_decimal_seconds(),
+            mu,
+            MAX_ITER,
+        )?;
+        Ok(State::new(
+            state.time() + dt,
+            p,
+            v,
+            state.origin(),
+            state.reference_frame(),
+        ))
+    }
+}
+
+fn universal_variables(
+    p0: DVec3,
+    v0: DVec3,
+    dt: f64,
+    mu: f64,
+    max_iter: i32,
+) -> Result<(DVec3, DVec3), ValladoError> {
+    let sqrt_mu = mu.sqrt();
+    let dot_p0v0 = p0.dot(v0);
+    let norm_p0 = p0.length();
+    let alpha = -v0.dot(v0) / mu + 2.0 / norm_p0;
+
+    let mut xi_new = if alpha > 0.0 {
+        sqrt_mu * dt * alpha
+    } else if alpha < 0.0 {
+        dt.signum()
+            * (-1.0 / alpha).powf(0.5)
+            * (-2.0 * mu * alpha * dt
+                / (dot_p0v0 + dt.signum() * (-mu / alpha).sqrt() * (1.0 - norm_p0 * alpha)))
+                .ln()
+    } else {
+        sqrt_mu * dt / norm_p0
+    };
+
+    let mut count = 0;
+    while count < max_iter {
+        let xi = xi_new;
+        let psi = xi * xi * alpha;
+        let c2_psi = stumpff::c2(psi);
+        let c3_psi = stumpff::c3(psi);
+        let norm_r = xi.powi(2) * c2_psi
+            + dot_p0v0 / sqrt_mu * xi * (1.0 - psi * c3_psi)
+            + norm_p0 * (1.0 - psi * c2_psi);
+        let delta_xi = (sqrt_mu * dt
+            - xi.powi(3) * c3_psi
+            - dot_p0v0 / sqrt_mu * xi.powi(2) * c2_psi
+            - norm_p0 * xi * (1.0 - psi * c3_psi))
+            / norm_r;
+        xi_new = xi + delta_xi;
+        if (xi_new - xi).abs() < 1e-7 {
+            let f = 1.0 - xi.powi(2) / norm_p0 * c2_psi;
+            let g = dt - xi.powi(3) / sqrt_mu * c3_psi;
+
+            let gdot = 1.0 - xi.powi(2) / norm_r * c2_psi;
+            let fdot = sqrt_mu / (norm_r * norm_p0) * xi * (psi * c3_psi - 1.0);
+
+            debug_assert!((f * gdot - fdot * g - 1.0).abs() < 1e-5);
+
+            let p = f * p0 + g * v0;
+            let v = fdot * p0 + gdot * v0;
+
+            return Ok((p, v));
         } else {
-            sqrt_mu * dt / norm_p0
-        };
-
-        let mut count = 0;
-        while count < self.max_iter {
-            let xi = xi_new;
-            let psi = xi * xi * alpha;
-            let c2_psi = stumpff::c2(psi);
-            let c3_psi = stumpff::c3(psi);
-            let norm_r = xi.powi(2) * c2_psi
-                + dot_p0v0 / sqrt_mu * xi * (1.0 - psi * c3_psi)
-                + norm_p0 * (1.0 - psi * c2_psi);
-            let delta_xi = (sqrt_mu * dt
-                - xi.powi(3) * c3_psi
-                - dot_p0v0 / sqrt_mu * xi.powi(2) * c2_psi
-                - norm_p0 * xi * (1.0 - psi * c3_psi))
-                / norm_r;
-            xi_new = xi + delta_xi;
-            if (xi_new - xi).abs() < 1e-7 {
-                let f = 1.0 - xi.powi(2) / norm_p0 * c2_psi;
-                let g = dt - xi.powi(3) / sqrt_mu * c3_psi;
-
-                let gdot = 1.0 - xi.powi(2) / norm_r * c2_psi;
-                let fdot = sqrt_mu / (norm_r * norm_p0) * xi * (psi * c3_psi - 1.0);
-
-                debug_assert!((f * gdot - fdot * g - 1.0).abs() < 1e-5);
-
-                let p = f * p0 + g * v0;
-                let v = fdot * p0 + gdot * v0;
-
-                return Ok(State::new(time, p, v, origin, frame));
-            } else {
-                count += 1
-            }
+            count += 1
         }
-        Err(ValladoError::NotConverged)
     }
+    Err(ValladoError::NotConverged)
 }
 
 #[cfg(test)]
@@ -173,15 +226,19 @@ mod tests {
     use lox_bodies::Earth;
     use lox_math::assert_close;
     use lox_math::is_close::IsClose;
-    use lox_time::deltas::TimeDelta;
+    use lox_math::types::units::Angle;
     use lox_time::time_scales::Tdb;
     use lox_time::utc;
     use lox_time::utc::Utc;
 
+    use rstest::rstest;
+
     use crate::elements::Keplerian;
 
     use super::*;
 
+    const MU_EARTH: f64 = 398600.4418;
+
     #[test]
     fn test_vallado_propagate() {
         let utc = utc!(2023, 3, 25, 21, 8, 0.0).unwrap();
@@ -198,10 +255,10 @@ mod tests {
             Earth,
             semi_major,
             eccentricity,
-            inclination,
-            ascending_node,
-            periapsis_arg,
-            true_anomaly,
+            Angle::from_radians(inclination),
+            Angle::from_radians(ascending_node),
+            Angle::from_radians(periapsis_arg),
+            Angle::from_radians(true_anomaly),
         );
         let s0 = k0.to_cartesian();
         let t1 = time + k0.orbital_period();
@@ -214,14 +271,18 @@ mod tests {
         let k1 = s1.to_keplerian();
         assert_float_eq!(k1.semi_major_axis(), semi_major, rel <= 1e-8);
         assert_float_eq!(k1.eccentricity(), eccentricity, rel <= 1e-8);
-        assert_float_eq!(k1.inclination(), inclination, rel <= 1e-8);
+        assert_float_eq!(k1.inclination().to_radians(), inclination, rel <= 1e-8);
         assert_float_eq!(
-            k1.longitude_of_ascending_node(),
+            k1.longitude_of_ascending_node().to_radians(),
             ascending_node,
             rel <= 1e-8
         );
-        assert_float_eq!(k1.argument_of_periapsis(), periapsis_arg, rel <= 1e-8);
-        assert_float_eq!(k1.true_anomaly(), true_anomaly, rel <= 1e-8);
+        assert_float_eq!(
+            k1.argument_of_periapsis().to_radians(),
+            periapsis_arg,
+            rel <= 1e-8
+        );
+        assert_float_eq!(k1.true_anomaly().to_radians(), true_anomaly, rel <= 1e-8);
         assert_close!(k1.time(), t1);
     }
 
@@ -241,10 +302,10 @@ mod tests {
             Earth,
             semi_major,
             eccentricity,
-            inclination,
-            ascending_node,
-            periapsis_arg,
-            true_anomaly,
+            Angle::from_radians(inclination),
+            Angle::from_radians(ascending_node),
+            Angle::from_radians(periapsis_arg),
+            Angle::from_radians(true_anomaly),
         );
         let s0 = k0.to_cartesian();
         let period = k0.orbital_period();
@@ -256,13 +317,57 @@ mod tests {
 
         assert_float_eq!(k1.semi_major_axis(), semi_major, rel <= 1e-8);
         assert_float_eq!(k1.eccentricity(), eccentricity, rel <= 1e-8);
-        assert_float_eq!(k1.inclination(), inclination, rel <= 1e-8);
+        assert_float_eq!(k1.inclination().to_radians(), inclination, rel <= 1e-8);
         assert_float_eq!(
-            k1.longitude_of_ascending_node(),
+            k1.longitude_of_ascending_node().to_radians(),
             ascending_node,
             rel <= 1e-8
         );
-        assert_float_eq!(k1.argument_of_periapsis(), periapsis_arg, rel <= 1e-8);
-        assert_float_eq!(k1.true_anomaly(), true_anomaly, rel <= 1e-8);
+        assert_float_eq!(
+            k1.argument_of_periapsis().to_radians(),
+            periapsis_arg,
+            rel <= 1e-8
+        );
+        assert_float_eq!(k1.true_anomaly().to_radians(), true_anomaly, rel <= 1e-8);
+    }
+
+    #[rstest]
+    #[case::elliptic(DVec3::new(0.0, 8.0, 1.0))]
+    #[case::parabolic(DVec3::new(0.0, (2.0 * MU_EARTH / 7000.0f64).sqrt(), 0.0))]
+    #[case::hyperbolic(DVec3::new(0.0, 11.0, 2.0))]
+    fn test_two_body_roundtrip(#[case] velocity: DVec3) {
+        let time = utc!(2023, 3, 25, 21, 8, 0.0).unwrap().to_time();
+        let s0 = State::new(time, DVec3::new(7000.0, 0.0, 0.0), velocity, Earth, Icrf);
+        let dt = TimeDelta::from_hours(3.0).unwrap();
+
+        let s1 = TwoBody::propagate(&s0, dt, MU_EARTH).unwrap();
+        assert_eq!(s1.time(), time + dt);
+        let s2 = TwoBody::propagate(&s1, -dt, MU_EARTH).unwrap();
+        assert_eq!(s2.time(), time);
+        assert_close!(s2.position(), s0.position(), 1e-6);
+        assert_close!(s2.velocity(), s0.velocity(), 1e-9);
+    }
+
+    #[test]
+    fn test_two_body_period() {
+        let time = utc!(2023, 3, 25, 21, 8, 0.0).unwrap().to_time();
+        let k0 = Keplerian::new(
+            time,
+            Earth,
+            24464.560,
+            0.7311,
+            Angle::from_radians(0.122138),
+            Angle::from_radians(1.00681),
+            Angle::from_radians(3.10686),
+            Angle::from_radians(0.4436956),
+        );
+        let s0 = k0.to_cartesian();
+        let s1 =
+            TwoBody::propagate(&s0, k0.orbital_period(), Earth.gravitational_parameter()).unwrap();
+        assert_close!(s1.position(), s0.position(), 1e-6);
+        assert_close!(s1.velocity(), s0.velocity(), 1e-9);
+
+        let s1 = Vallado::new(s0).propagate(s1.time()).unwrap();
+        assert_close!(s1.position(), s0.position(), 1e-6);
     }
 }
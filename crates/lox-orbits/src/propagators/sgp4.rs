@@ -6,21 +6,24 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::f64::consts::PI;
+
 use glam::DVec3;
 pub use sgp4::Elements;
-use sgp4::{Constants, ElementsError, MinutesSinceEpoch};
+use sgp4::{Constants, ElementsError, MinutesSinceEpoch, Orbit, WGS72, iau_epoch_to_sidereal_time};
 use thiserror::Error;
 
 use lox_bodies::Earth;
 use lox_math::constants::f64::time::SECONDS_PER_MINUTE;
 use lox_time::Time;
-use lox_time::deltas::TimeDelta;
+use lox_time::deltas::{TimeDelta, TimeDeltaError};
 use lox_time::time_scales::Tai;
 use lox_time::utc::Utc;
 
-use crate::frames::Icrf;
+use crate::frames::{Icrf, Teme};
 use crate::propagators::Propagator;
 use crate::states::State;
+use crate::tle::Tle;
 use crate::trajectories::TrajectoryError;
 
 #[derive(Debug, Clone, Error)]
@@ -29,41 +32,138 @@ pub enum Sgp4Error {
     TrajectoryError(#[from] TrajectoryError),
     #[error(transparent)]
     Sgp4(#[from] sgp4::Error),
+    #[error(transparent)]
+    Elements(#[from] ElementsError),
+    #[error(transparent)]
+    Tle(#[from] sgp4::TleError),
+    #[error(transparent)]
+    TimeDelta(#[from] TimeDeltaError),
 }
 
+/// SGP4/SDP4 propagator based on the WGS-72 constants
+///
+/// Near-Earth and deep-space (orbital period ≥ 225 min) element sets are
+/// supported. The native output of the theory in the [Teme] frame is available via
+/// [Sgp4::propagate_teme] and [Sgp4::propagate_minutes], while the [Propagator] implementation
+/// returns states rotated to the [Icrf].
+///
+/// Atmospheric drag is modelled through the BSTAR drag term of the element
+/// set. The derivatives of the mean motion are retained but, as in the
+/// reference implementation, do not affect the propagation.
 pub struct Sgp4 {
     constants: Constants,
     time: Time<Tai>,
+    drag_term: f64,
+    mean_motion_dot: f64,
+    mean_motion_ddot: f64,
 }
 
 impl Sgp4 {
     pub fn new(initial_state: Elements) -> Result<Self, ElementsError> {
         let epoch = initial_state.epoch();
         let time = Utc::from_delta(TimeDelta::from_julian_years(epoch).unwrap()).to_time();
-        let constants = Constants::from_elements(&initial_state)?;
-        Ok(Self { constants, time })
+        let constants = Constants::new(
+            WGS72,
+            iau_epoch_to_sidereal_time,
+            epoch,
+            initial_state.drag_term,
+            Orbit::from_kozai_elements(
+                &WGS72,
+                initial_state.inclination.to_radians(),
+                initial_state.right_ascension.to_radians(),
+                initial_state.eccentricity,
+                initial_state.argument_of_perigee.to_radians(),
+                initial_state.mean_anomaly.to_radians(),
+                // Revolutions per day to radians per minute
+                initial_state.mean_motion * PI / 720.0,
+            )?,
+        )?;
+        Ok(Self {
+            constants,
+            time,
+            drag_term: initial_state.drag_term,
+            mean_motion_dot: initial_state.mean_motion_dot,
+            mean_motion_ddot: initial_state.mean_motion_ddot,
+        })
+    }
+
+    pub fn from_tle(tle: &Tle) -> Result<Self, Sgp4Error> {
+        let (line1, line2) = tle.lines();
+        let elements = Elements::from_tle(None, line1.as_bytes(), line2.as_bytes())?;
+        Ok(Self::new(elements)?)
     }
 
     pub fn time(&self) -> Time<Tai> {
         self.time
     }
+
+    /// The BSTAR drag term in inverse Earth radii
+    pub fn drag_term(&self) -> f64 {
+        self.drag_term
+    }
+
+    /// The first time derivative of the mean motion divided by two in rev/day²,
+    /// which is not used by SGP4
+    pub fn mean_motion_dot(&self) -> f64 {
+        self.mean_motion_dot
+    }
+
+    /// The second time derivative of the mean motion divided by six in rev/day³,
+    /// which is not used by SGP4
+    pub fn mean_motion_ddot(&self) -> f64 {
+        self.mean_motion_ddot
+    }
+
+    /// Propagates the element set to `time` and returns the state in the [Teme] frame.
+    pub fn propagate_teme(&self, time: Time<Tai>) -> Result<State<Tai, Earth, Teme>, Sgp4Error> {
+        let dt = (time - self.time).to_decimal_seconds() / SECONDS_PER_MINUTE;
+        self.state(time, dt)
+    }
+
+    /// Propagates the element set by `minutes_since_epoch` minutes and returns the state in the
+    /// [Teme] frame.
+    pub fn propagate_minutes(
+        &self,
+        minutes_since_epoch: f64,
+    ) -> Result<State<Tai, Earth, Teme>, Sgp4Error> {
+        let time = self.time + TimeDelta::from_minutes(minutes_since_epoch)?;
+        self.state(time, minutes_since_epoch)
+    }
+
+    fn state(
+        &self,
+        time: Time<Tai>,
+        minutes_since_epoch: f64,
+    ) -> Result<State<Tai, Earth, Teme>, Sgp4Error> {
+        let prediction = self
+            .constants
+            .propagate(MinutesSinceEpoch(minutes_since_epoch))?;
+        let position = DVec3::from_array(prediction.position);
+        let velocity = DVec3::from_array(prediction.velocity);
+        Ok(State::new(time, position, velocity, Earth, Teme))
+    }
 }
 
 impl Propagator<Tai, Earth, Icrf> for Sgp4 {
     type Error = Sgp4Error;
 
     fn propagate(&self, time: Time<Tai>) -> Result<State<Tai, Earth, Icrf>, Self::Error> {
-        let dt = (time - self.time).to_decimal_seconds() / SECONDS_PER_MINUTE;
-        let prediction = self.constants.propagate(MinutesSinceEpoch(dt))?;
-        let position = DVec3::from_array(prediction.position);
-        let velocity = DVec3::from_array(prediction.velocity);
-        Ok(State::new(time, position, velocity, Earth, Icrf))
+        let Ok(state) = self.propagate_teme(time)?.try_to_frame(Icrf, None::<&()>);
+        Ok(state)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use float_eq::assert_float_eq;
+    use lox_io::ndm::omm::OmmType;
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+    use rstest::rstest;
+
+    use crate::propagators::propagate_many;
+    use crate::tle;
+    use crate::tle::WGS72_GM;
 
     use super::*;
 
@@ -86,4 +186,200 @@ mod tests {
             rel <= 1e-4
         );
     }
+
+    #[test]
+    fn test_sgp4_icrf_and_teme_output() {
+        let tle = Elements::from_tle(
+            None,
+            "1 25544U 98067A   24170.37528350  .00016566  00000+0  30244-3 0  9996".as_bytes(),
+            "2 25544  51.6410 309.3890 0010444 339.5369 107.8830 15.49495945458731".as_bytes(),
+        )
+        .unwrap();
+        let sgp4 = Sgp4::new(tle).unwrap();
+        let time = sgp4.time() + TimeDelta::from_minutes(30.0).unwrap();
+        let teme = sgp4.propagate_teme(time).unwrap();
+        let icrf = sgp4.propagate(time).unwrap();
+        assert_eq!(teme, sgp4.propagate_minutes(30.0).unwrap());
+        assert_eq!(icrf.reference_frame(), Icrf);
+        assert_close!(
+            icrf.position(),
+            teme.try_to_frame(Icrf, None::<&()>).unwrap().position()
+        );
+        // The equinox-based TEME frame differs noticeably from the ICRF in 2024 due to precession
+        assert!((icrf.position() - teme.position()).length() > 10.0);
+    }
+
+    #[test]
+    fn test_sgp4_propagate_many() {
+        let propagators: Vec<Sgp4> = [
+            (
+                "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753",
+                "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667",
+            ),
+            (
+                "1 09880U 77021A   06176.56157475  .00000421  00000-0  10000-3 0  9814",
+                "2 09880  64.5968 349.3786 7069051 270.0229  16.3320  2.00813614112380",
+            ),
+            (
+                "1 25544U 98067A   24170.37528350  .00016566  00000+0  30244-3 0  9996",
+                "2 25544  51.6410 309.3890 0010444 339.5369 107.8830 15.49495945458731",
+            ),
+        ]
+        .iter()
+        .map(|(line1, line2)| Sgp4::from_tle(&tle::parse(line1, line2).unwrap()).unwrap())
+        .collect();
+        let t0 = propagators[2].time();
+        let times: Vec<Time<Tai>> = (0..100)
+            .map(|i| t0 + TimeDelta::from_seconds(i * 60))
+            .collect();
+        let trajectories = propagate_many(&propagators, &times);
+        assert_eq!(trajectories.len(), propagators.len());
+        for (sgp4, trajectory) in propagators.iter().zip(trajectories) {
+            let expected = sgp4.propagate_all(times.iter().cloned()).unwrap();
+            for (act, exp) in trajectory.unwrap().states().iter().zip(expected.states()) {
+                assert_eq!(act.position(), exp.position());
+                assert_eq!(act.velocity(), exp.velocity());
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(
+        "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753",
+        "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667"
+    )]
+    #[case(
+        "1 09880U 77021A   06176.56157475  .00000421  00000-0  10000-3 0  9814",
+        "2 09880  64.5968 349.3786 7069051 270.0229  16.3320  2.00813614112380"
+    )]
+    #[case(
+        "1 25544U 98067A   24170.37528350  .00016566  00000+0  30244-3 0  9996",
+        "2 25544  51.6410 309.3890 0010444 339.5369 107.8830 15.49495945458731"
+    )]
+    fn test_sgp4_omm_roundtrip(#[case] line1: &str, #[case] line2: &str) {
+        let tle = tle::parse(line1, line2).unwrap();
+        let omm = OmmType::from(&tle);
+        let expected = Sgp4::from_tle(&tle).unwrap();
+        let actual = Sgp4::from_tle(&Tle::try_from(&omm).unwrap()).unwrap();
+        assert_eq!(actual.time(), expected.time());
+        for minutes in [0.0, 90.0, 720.0, 1440.0] {
+            let s1 = actual.propagate_minutes(minutes).unwrap();
+            let s0 = expected.propagate_minutes(minutes).unwrap();
+            assert_eq!(s1.position(), s0.position());
+            assert_eq!(s1.velocity(), s0.velocity());
+        }
+    }
+
+    const DECAYING_LINE1: &str =
+        "1 29141U 85108AA  06170.26783845  .99999999  00000-0  13519-0 0   718";
+    const DECAYING_LINE2: &str =
+        "2 29141  82.4288 273.4882 0015848 277.2124  83.9133 15.93343074  6828";
+
+    fn semi_major_axis(state: &State<Tai, Earth, Teme>) -> f64 {
+        1.0 / (2.0 / state.position().length() - state.velocity().length_squared() / WGS72_GM)
+    }
+
+    #[test]
+    fn test_sgp4_drag() {
+        let tle = tle::parse(DECAYING_LINE1, DECAYING_LINE2).unwrap();
+        let sgp4 = Sgp4::from_tle(&tle).unwrap();
+        assert_eq!(sgp4.drag_term(), tle.bstar());
+        assert_eq!(sgp4.mean_motion_dot(), tle.mean_motion_dot());
+        assert_eq!(sgp4.mean_motion_ddot(), tle.mean_motion_ddot());
+
+        let mut elements =
+            Elements::from_tle(None, DECAYING_LINE1.as_bytes(), DECAYING_LINE2.as_bytes()).unwrap();
+        elements.drag_term = 0.0;
+        let drag_free = Sgp4::new(elements).unwrap();
+
+        // Sample the osculating semi-major axis once per orbit to suppress the
+        // short-periodic perturbations
+        let orbital_period = 90.0;
+        let a0 = semi_major_axis(&sgp4.propagate_minutes(0.0).unwrap());
+        let mut a_prev = a0;
+        for i in 1..=4 {
+            let minutes = i as f64 * orbital_period;
+            let a = semi_major_axis(&sgp4.propagate_minutes(minutes).unwrap());
+            assert!(a < a_prev);
+            a_prev = a;
+            let a_drag_free = semi_major_axis(&drag_free.propagate_minutes(minutes).unwrap());
+            assert_float_eq!(a_drag_free, a0, abs <= 1.0);
+        }
+        assert!(a0 - a_prev > 150.0);
+
+        // The object has re-entered 440 minutes after epoch and the propagation
+        // eventually fails
+        let state = sgp4.propagate_minutes(440.0).unwrap();
+        assert!(state.position().length() < WGS72.ae);
+        assert!(matches!(
+            sgp4.propagate_minutes(1380.0),
+            Err(Sgp4Error::Sgp4(_))
+        ));
+    }
+
+    // Test cases from the SGP4 verification data set of Vallado et al. (2006),
+    // "Revisiting Spacetrack Report #3"
+    #[rstest]
+    #[case::near_earth(
+        "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753",
+        "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667",
+        0.0,
+        DVec3::new(7022.46529266, -1400.08296755, 0.03995155),
+        DVec3::new(1.893841015, 6.405893759, 4.534807250),
+    )]
+    #[case::near_earth(
+        "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753",
+        "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667",
+        1440.0,
+        DVec3::new(-938.55923943, -6268.18748831, -4294.02924751),
+        DVec3::new(7.536105209, -0.427127707, 0.989878080),
+    )]
+    #[case::deep_space(
+        "1 09880U 77021A   06176.56157475  .00000421  00000-0  10000-3 0  9814",
+        "2 09880  64.5968 349.3786 7069051 270.0229  16.3320  2.00813614112380",
+        0.0,
+        DVec3::new(13020.06750784, -2449.07193500, 1.15896030),
+        DVec3::new(4.247363935, 1.597178501, 4.956708611),
+    )]
+    #[case::deep_space(
+        "1 09880U 77021A   06176.56157475  .00000421  00000-0  10000-3 0  9814",
+        "2 09880  64.5968 349.3786 7069051 270.0229  16.3320  2.00813614112380",
+        240.0,
+        DVec3::new(11332.67806218, 16517.99124008, 38569.78482991),
+        DVec3::new(-1.400974747, 0.710947006, 0.923935636),
+    )]
+    #[case::decaying(
+        DECAYING_LINE1,
+        DECAYING_LINE2,
+        0.0,
+        DVec3::new(423.99295524, -6658.12256149, 136.13040356),
+        DVec3::new(1.006373613, 0.217309983, 7.662587892),
+    )]
+    #[case::decaying(
+        DECAYING_LINE1,
+        DECAYING_LINE2,
+        200.0,
+        DVec3::new(916.34911813, -884.08649248, 6491.09810362),
+        DVec3::new(-0.302163049, 7.669887109, 1.084336909),
+    )]
+    #[case::decaying(
+        DECAYING_LINE1,
+        DECAYING_LINE2,
+        420.0,
+        DVec3::new(-852.93910071, 192.65232023, -6322.47054784),
+        DVec3::new(0.396006194, -7.882964919, -0.289331517),
+    )]
+    fn test_sgp4_verification(
+        #[case] line1: &str,
+        #[case] line2: &str,
+        #[case] minutes: f64,
+        #[case] r_exp: DVec3,
+        #[case] v_exp: DVec3,
+    ) {
+        let tle = tle::parse(line1, line2).unwrap();
+        let sgp4 = Sgp4::from_tle(&tle).unwrap();
+        let state = sgp4.propagate_minutes(minutes).unwrap();
+        assert_close!(state.position(), r_exp, 1e-6);
+        assert_close!(state.velocity(), v_exp, 1e-9);
+    }
 }
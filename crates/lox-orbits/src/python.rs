@@ -25,6 +25,7 @@ use sgp4::Elements;
 
 use lox_bodies::*;
 use lox_math::roots::Brent;
+use lox_math::types::units::Angle;
 use lox_time::deltas::TimeDelta;
 use lox_time::python::deltas::PyTimeDelta;
 use lox_time::python::time::PyTime;
@@ -33,7 +34,9 @@ use lox_time::time_scales::{DynTimeScale, Tai};
 
 use crate::analysis::{ElevationMask, ElevationMaskError, visibility_combined};
 use crate::elements::{DynKeplerian, Keplerian};
-use crate::events::{Event, FindEventError, Window};
+use crate::events::{
+    CrossingDirection, Event, FindEventError, UnknownCrossingDirectionError, Window,
+};
 use crate::frames::iau::IauFrameTransformationError;
 use crate::frames::{DynFrame, ReferenceFrame, TryRotateTo, UnknownFrameError};
 use crate::ground::{DynGroundLocation, DynGroundPropagator, GroundPropagatorError, Observables};
@@ -61,6 +64,12 @@ impl From<FindEventError> for PyErr {
     }
 }
 
+impl From<UnknownCrossingDirectionError> for PyErr {
+    fn from(err: UnknownCrossingDirectionError) -> Self {
+        PyValueError::new_err(err.to_string())
+    }
+}
+
 impl From<IauFrameTransformationError> for PyErr {
     fn from(err: IauFrameTransformationError) -> Self {
         // FIXME: wrong error type
@@ -297,10 +306,10 @@ impl PyKeplerian {
                 origin,
                 semi_major_axis,
                 eccentricity,
-                inclination,
-                longitude_of_ascending_node,
-                argument_of_periapsis,
-                true_anomaly,
+                Angle::from_radians(inclination),
+                Angle::from_radians(longitude_of_ascending_node),
+                Angle::from_radians(argument_of_periapsis),
+                Angle::from_radians(true_anomaly),
             )
             .map_err(|err| PyValueError::new_err(err.to_string()))?,
         ))
@@ -323,19 +332,19 @@ impl PyKeplerian {
     }
 
     fn inclination(&self) -> f64 {
-        self.0.inclination()
+        self.0.inclination().to_radians()
     }
 
     fn longitude_of_ascending_node(&self) -> f64 {
-        self.0.longitude_of_ascending_node()
+        self.0.longitude_of_ascending_node().to_radians()
     }
 
     fn argument_of_periapsis(&self) -> f64 {
-        self.0.argument_of_periapsis()
+        self.0.argument_of_periapsis().to_radians()
     }
 
     fn true_anomaly(&self) -> f64 {
-        self.0.true_anomaly()
+        self.0.true_anomaly().to_radians()
     }
 
     fn to_cartesian(&self) -> PyResult<PyState> {
@@ -407,16 +416,26 @@ impl PyTrajectory {
         self.0.states().into_iter().map(PyState).collect()
     }
 
-    fn find_events(&self, py: Python<'_>, func: &Bound<'_, PyAny>) -> PyResult<Vec<PyEvent>> {
+    #[pyo3(signature = (func, direction="both"))]
+    fn find_events(
+        &self,
+        py: Python<'_>,
+        func: &Bound<'_, PyAny>,
+        direction: &str,
+    ) -> PyResult<Vec<PyEvent>> {
+        let direction: CrossingDirection = direction.parse()?;
         Ok(self
             .0
-            .find_events(|s| {
-                func.call((PyState(s),), None)
-                    // FIXME: Bad idea
-                    .unwrap_or(f64::NAN.into_bound_py_any(py).unwrap())
-                    .extract()
-                    .unwrap_or(f64::NAN)
-            })
+            .find_events_in_direction(
+                |s| {
+                    func.call((PyState(s),), None)
+                        // FIXME: Bad idea
+                        .unwrap_or(f64::NAN.into_bound_py_any(py).unwrap())
+                        .extract()
+                        .unwrap_or(f64::NAN)
+                },
+                direction,
+            )
             .into_iter()
             .map(PyEvent)
             .collect())
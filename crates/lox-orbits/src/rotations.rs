@@ -1,13 +1,38 @@
-use glam::{DMat3, DVec3};
+use glam::{DMat3, DQuat, DVec3};
+use lox_io::ndm::apm::{ApmEulerAngleState, ApmQuaternionState};
+use lox_io::ndm::common::{AdmError, AttitudeDirection, EulerRotationSequence};
+use lox_math::types::units::Angle;
 
+/// Returns the time derivative of the rotation matrix `m` of a frame which rotates with the
+/// angular velocity `v`, given in the coordinates of the rotated frame, i.e. `-skew(v) * m`.
 pub fn rotation_matrix_derivative(m: DMat3, v: DVec3) -> DMat3 {
-    let sx = DVec3::new(0.0, v.z, v.y);
-    let sy = DVec3::new(-v.z, 0.0, v.x);
-    let sz = DVec3::new(v.y, -v.x, 0.0);
-    let s = DMat3::from_cols(sx, sy, sz);
-    -s * m
+    -skew(v) * m
 }
 
+/// Returns the skew-symmetric cross-product matrix of `v`, such that `skew(v) * w == v.cross(w)`.
+pub(crate) fn skew(v: DVec3) -> DMat3 {
+    DMat3::from_cols(
+        DVec3::new(0.0, v.z, -v.y),
+        DVec3::new(-v.z, 0.0, v.x),
+        DVec3::new(v.y, -v.x, 0.0),
+    )
+}
+
+/// A time-dependent rotation between two reference frames consisting of the rotation matrix and
+/// its time derivative.
+///
+/// Positions and velocities are represented by [Vector3](crate::Vector3) and the matrices by
+/// [Matrix3](crate::Matrix3).
+///
+/// ```
+/// use lox_orbits::rotations::Rotation;
+/// use lox_orbits::{Matrix3, Vector3};
+///
+/// let rotation = Rotation::new(Matrix3::from_rotation_z(std::f64::consts::FRAC_PI_2));
+/// let position = rotation.rotate_position(Vector3::X);
+/// assert!(position.abs_diff_eq(Vector3::Y, 1e-15));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Rotation {
     m: DMat3,
     dm: DMat3,
@@ -41,6 +66,8 @@ impl Rotation {
         self.dm
     }
 
+    /// Returns the rotation which applies `self` first and then `other`. The derivatives are
+    /// combined according to the product rule.
     pub fn compose(&self, other: &Self) -> Self {
         Self {
             m: other.m * self.m,
@@ -54,6 +81,106 @@ impl Rotation {
         Self { m, dm }
     }
 
+    /// Creates a rotation from a quaternion in scalar-first order `[q0, q1, q2, q3]`, where `q0`
+    /// is the scalar part. The rotation has no time derivative.
+    ///
+    /// The quaternion is active, i.e. [Rotation::rotate_position] rotates a vector `v` to
+    /// `q v q*`. For a rotation from frame A to frame B, `q` is thus the conjugate of the
+    /// quaternion which rotates the axes of A onto the axes of B.
+    ///
+    /// The quaternion is normalized before the conversion and `q` and `-q` result in the same
+    /// rotation.
+    pub fn from_quaternion(q: [f64; 4]) -> Self {
+        let [w, x, y, z] = q;
+        Self::new(DMat3::from_quat(DQuat::from_xyzw(x, y, z, w).normalize()))
+    }
+
+    /// Returns the unit quaternion of the rotation in scalar-first order `[q0, q1, q2, q3]`,
+    /// where `q0` is the scalar part. The quaternion is active, see [Rotation::from_quaternion].
+    ///
+    /// Since `q` and `-q` represent the same rotation, the sign is chosen such that the scalar
+    /// part is non-negative.
+    pub fn to_quaternion(&self) -> [f64; 4] {
+        let q = DQuat::from_mat3(&self.m).normalize();
+        let q = if q.w < 0.0 { -q } else { q };
+        [q.w, q.x, q.y, q.z]
+    }
+
+    /// Creates the rotation from frame A to frame B from a quaternion of a CCSDS attitude data
+    /// message in scalar-first order `[qc, q1, q2, q3]`.
+    ///
+    /// ADM quaternions are passive, i.e. their direction cosine matrix transforms the coordinates
+    /// of vectors in the given `direction`. For [AttitudeDirection::AToB] it is therefore the
+    /// transpose of the matrix of [Rotation::from_quaternion] for the same quaternion.
+    pub fn from_adm_quaternion(q: [f64; 4], direction: AttitudeDirection) -> Self {
+        let rotation = Self::from_quaternion(q);
+        match direction {
+            AttitudeDirection::AToB => rotation.transpose(),
+            AttitudeDirection::BToA => rotation,
+        }
+    }
+
+    /// Creates the rotation from frame A to frame B from the Euler angles of a CCSDS attitude
+    /// data message.
+    ///
+    /// `angles` are the rotation angles about the X, Y, and Z axes, which are applied in the
+    /// order given by `sequence`. Each rotation transforms the coordinates of vectors into the
+    /// rotated frame.
+    pub fn from_adm_euler_angles(
+        sequence: EulerRotationSequence,
+        angles: [Angle; 3],
+        direction: AttitudeDirection,
+    ) -> Self {
+        let m = sequence.axes().iter().fold(DMat3::IDENTITY, |m, &axis| {
+            let angle = angles[axis as usize - 1].to_radians();
+            let rotation = match axis {
+                1 => DMat3::from_rotation_x(angle),
+                2 => DMat3::from_rotation_y(angle),
+                _ => DMat3::from_rotation_z(angle),
+            };
+            rotation.transpose() * m
+        });
+        match direction {
+            AttitudeDirection::AToB => Self::new(m),
+            AttitudeDirection::BToA => Self::new(m.transpose()),
+        }
+    }
+
+    /// Returns the inverse rotation, which is equal to the [transpose](Rotation::transpose) since
+    /// rotation matrices are orthogonal.
+    pub fn inverse(&self) -> Self {
+        self.transpose()
+    }
+
+    /// Returns the angular velocity of the rotation.
+    pub fn angular_velocity(&self) -> DVec3 {
+        // dm = -skew(v) * m and therefore skew(v) = -dm * m^T
+        let s = -self.dm * self.m.transpose();
+        DVec3::new(s.y_axis.z, s.z_axis.x, s.x_axis.y)
+    }
+
+    /// Spherically interpolates between the orientations `a` and `b` for `t` in `[0, 1]`.
+    ///
+    /// The orientation is interpolated along the shortest great arc with constant angular rate.
+    /// The angular velocity is interpolated linearly between those of `a` and `b`. The endpoints
+    /// are returned unchanged for `t = 0` and `t = 1`.
+    pub fn slerp(a: &Self, b: &Self, t: f64) -> Self {
+        if t == 0.0 {
+            return *a;
+        }
+        if t == 1.0 {
+            return *b;
+        }
+        let qa = DQuat::from_mat3(&a.m);
+        let qb = DQuat::from_mat3(&b.m);
+        let m = DMat3::from_quat(qa.slerp(qb, t));
+        let v = a.angular_velocity().lerp(b.angular_velocity(), t);
+        Self {
+            m,
+            dm: rotation_matrix_derivative(m, v),
+        }
+    }
+
     pub fn rotate_position(&self, pos: DVec3) -> DVec3 {
         self.m * pos
     }
@@ -66,3 +193,371 @@ impl Rotation {
         (self.rotate_position(pos), self.rotate_velocity(pos, vel))
     }
 }
+
+/// Returns the time derivative of the rotation matrix of the unit quaternion `q` in scalar-first
+/// order, given its time derivative `q_dot`.
+fn quaternion_matrix_derivative(q: [f64; 4], q_dot: [f64; 4]) -> DMat3 {
+    let [w, x, y, z] = q;
+    let [dw, dx, dy, dz] = q_dot;
+    DMat3::from_cols(
+        2.0 * DVec3::new(
+            w * dw + x * dx - y * dy - z * dz,
+            dx * y + x * dy + dw * z + w * dz,
+            dx * z + x * dz - dw * y - w * dy,
+        ),
+        2.0 * DVec3::new(
+            dx * y + x * dy - dw * z - w * dz,
+            w * dw - x * dx + y * dy - z * dz,
+            dy * z + y * dz + dw * x + w * dx,
+        ),
+        2.0 * DVec3::new(
+            dx * z + x * dz + dw * y + w * dy,
+            dy * z + y * dz - dw * x - w * dx,
+            w * dw - x * dx - y * dy + z * dz,
+        ),
+    )
+}
+
+impl TryFrom<&ApmQuaternionState> for Rotation {
+    type Error = AdmError;
+
+    /// Returns the rotation from `Q_FRAME_A` to `Q_FRAME_B` including its time derivative if the
+    /// quaternion rates are present.
+    fn try_from(state: &ApmQuaternionState) -> Result<Self, Self::Error> {
+        let direction = state.direction()?;
+        let q = state.quaternion();
+        let rotation = Self::from_adm_quaternion(q, direction);
+        let Some(q_dot) = state.quaternion_derivative() else {
+            return Ok(rotation);
+        };
+        let norm = q.iter().map(|q| q * q).sum::<f64>().sqrt();
+        let dm = quaternion_matrix_derivative(q.map(|q| q / norm), q_dot.map(|q| q / norm));
+        Ok(match direction {
+            AttitudeDirection::AToB => rotation.with_derivative(dm.transpose()),
+            AttitudeDirection::BToA => rotation.with_derivative(dm),
+        })
+    }
+}
+
+impl TryFrom<&ApmEulerAngleState> for Rotation {
+    type Error = AdmError;
+
+    /// Returns the rotation from `EULER_FRAME_A` to `EULER_FRAME_B`. The Euler angle rates are
+    /// not taken into account.
+    fn try_from(state: &ApmEulerAngleState) -> Result<Self, Self::Error> {
+        Ok(Self::from_adm_euler_angles(
+            state.rotation_sequence()?,
+            state.angles()?,
+            state.direction()?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use lox_io::ndm::apm::ApmType;
+    use lox_io::ndm::common::{AngleType, AngleUnits};
+    use lox_io::ndm::kvn::KvnDeserializer;
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+
+    use super::*;
+
+    fn rotation() -> Rotation {
+        let m = DMat3::from_euler(glam::EulerRot::ZXZ, 0.3, -1.2, 2.5);
+        Rotation::new(m).with_angular_velocity(DVec3::new(1e-4, -2e-5, 7.3e-5))
+    }
+
+    #[test]
+    fn test_rotation_inverse() {
+        let r = rotation();
+        let identity = r.compose(&r.inverse());
+        assert!(
+            identity
+                .position_matrix()
+                .abs_diff_eq(DMat3::IDENTITY, 1e-15)
+        );
+        assert!(identity.velocity_matrix().abs_diff_eq(DMat3::ZERO, 1e-18));
+        let identity = r.inverse().compose(&r);
+        assert!(
+            identity
+                .position_matrix()
+                .abs_diff_eq(DMat3::IDENTITY, 1e-15)
+        );
+        assert!(identity.velocity_matrix().abs_diff_eq(DMat3::ZERO, 1e-18));
+    }
+
+    #[test]
+    fn test_rotation_compose_derivative() {
+        // The derivative of the composition must match a finite difference approximation
+        let v1 = DVec3::new(1e-4, -2e-5, 7.3e-5);
+        let v2 = DVec3::new(-3e-5, 5e-5, 1e-5);
+        let r = |t: f64| {
+            let m1 = DMat3::from_axis_angle(v1.normalize(), v1.length() * t);
+            let m2 = DMat3::from_axis_angle(v2.normalize(), v2.length() * t)
+                * DMat3::from_rotation_x(0.4);
+            (m1, m2)
+        };
+        let t = 100.0;
+        let h = 1e-3;
+        let (m1, m2) = r(t);
+        let (m1_plus, m2_plus) = r(t + h);
+        let (m1_minus, m2_minus) = r(t - h);
+        let r1 = Rotation::new(m1).with_derivative((m1_plus - m1_minus) / (2.0 * h));
+        let r2 = Rotation::new(m2).with_derivative((m2_plus - m2_minus) / (2.0 * h));
+        let expected = (m2_plus * m1_plus - m2_minus * m1_minus) / (2.0 * h);
+        assert!(
+            r1.compose(&r2)
+                .velocity_matrix()
+                .abs_diff_eq(expected, 1e-12)
+        );
+    }
+
+    #[test]
+    fn test_skew() {
+        let v = DVec3::new(0.3, -1.2, 2.5);
+        let w = DVec3::new(-0.7, 0.4, 1.1);
+        assert_close!(skew(v) * w, v.cross(w), 1e-15);
+        assert_eq!(skew(v).transpose(), -skew(v));
+    }
+
+    #[test]
+    fn test_rotation_matrix_derivative() {
+        // The coordinates of a frame rotating with the angular velocity `v` about an axis which
+        // is not aligned with any of the coordinate axes
+        let v = DVec3::new(0.3, -1.2, 2.5) * 1e-3;
+        let m = |t: f64| DMat3::from_axis_angle(v.normalize(), v.length() * t).transpose();
+        let t = 100.0;
+        let h = 1e-3;
+        let expected = (m(t + h) - m(t - h)) / (2.0 * h);
+        assert!(rotation_matrix_derivative(m(t), v).abs_diff_eq(expected, 1e-12));
+        // A vector fixed in the inertial frame appears to rotate in the opposite sense
+        let r = DVec3::X;
+        let dr = rotation_matrix_derivative(m(t), v) * r;
+        assert_close!(dr, -v.cross(m(t) * r), 1e-15);
+    }
+
+    #[test]
+    fn test_rotation_angular_velocity() {
+        let v = DVec3::new(1e-4, -2e-5, 7.3e-5);
+        let r = Rotation::new(DMat3::from_rotation_y(0.7)).with_angular_velocity(DVec3::ZERO);
+        assert_eq!(r.angular_velocity(), DVec3::ZERO);
+        assert_close!(rotation().angular_velocity(), v, 1e-18);
+    }
+
+    #[test]
+    fn test_rotation_quaternion() {
+        let (s, c) = (FRAC_PI_2 / 2.0).sin_cos();
+        let r = Rotation::from_quaternion([c, 0.0, 0.0, s]);
+        assert!(
+            r.position_matrix()
+                .abs_diff_eq(DMat3::from_rotation_z(FRAC_PI_2), 1e-15)
+        );
+        assert!(r.rotate_position(DVec3::X).abs_diff_eq(DVec3::Y, 1e-15));
+
+        // Non-unit quaternions and the opposite sign result in the same rotation
+        let scaled = Rotation::from_quaternion([-2.0 * c, 0.0, 0.0, -2.0 * s]);
+        assert!(
+            scaled
+                .position_matrix()
+                .abs_diff_eq(r.position_matrix(), 1e-15)
+        );
+        let q = scaled.to_quaternion();
+        assert_close!(DVec3::new(q[1], q[2], q[3]), DVec3::new(0.0, 0.0, s), 1e-15);
+        assert_close!(q[0], c, 1e-15);
+    }
+
+    #[test]
+    fn test_rotation_quaternion_round_trip() {
+        for m in [
+            DMat3::IDENTITY,
+            DMat3::from_rotation_x(PI),
+            DMat3::from_rotation_y(-3.0),
+            rotation().position_matrix(),
+        ] {
+            let q = Rotation::new(m).to_quaternion();
+            assert!(q[0] >= 0.0);
+            assert_close!(q.iter().map(|q| q * q).sum::<f64>(), 1.0, 1e-15);
+            let actual = Rotation::from_quaternion(q).position_matrix();
+            assert!(actual.abs_diff_eq(m, 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_rotation_from_adm_quaternion() {
+        // Frame B is rotated by 90 degrees about the z-axis of frame A
+        let (s, c) = (FRAC_PI_2 / 2.0).sin_cos();
+        let q = [c, 0.0, 0.0, s];
+        let a2b = Rotation::from_adm_quaternion(q, AttitudeDirection::AToB);
+        assert!(a2b.rotate_position(DVec3::X).abs_diff_eq(-DVec3::Y, 1e-15));
+        assert!(a2b.rotate_position(DVec3::Y).abs_diff_eq(DVec3::X, 1e-15));
+        let b2a = Rotation::from_adm_quaternion(q, AttitudeDirection::BToA);
+        assert_eq!(b2a, a2b.inverse());
+    }
+
+    #[test]
+    fn test_rotation_from_apm() {
+        let kvn = r#"CCSDS_APM_VERS = 1.0
+CREATION_DATE = 2003-09-30T14:28:15.1172
+ORIGINATOR = GSFC
+OBJECT_NAME = TRMM
+OBJECT_ID = 1997-009A
+CENTER_NAME = EARTH
+TIME_SYSTEM = UTC
+EPOCH = 2003-09-30T14:28:15.1172
+Q_FRAME_A = SC_BODY_1
+Q_FRAME_B = ITRF-97
+Q_DIR = A2B
+Q1 = 0.25678
+Q2 = 0.00005
+Q3 = 0.87543
+QC = 0.40949"#;
+        let apm = ApmType::from_kvn_str(kvn).unwrap();
+        let state = &apm.body.segment.data.quaternion_state;
+        let rotation = Rotation::try_from(state).unwrap();
+
+        // The direction cosine matrix of CCSDS 504.0-B-1, eq. 3-1
+        let (q1, q2, q3, qc) = (0.25678, 0.00005, 0.87543, 0.40949);
+        let n2 = q1 * q1 + q2 * q2 + q3 * q3 + qc * qc;
+        let expected = DMat3::from_cols(
+            DVec3::new(
+                q1 * q1 - q2 * q2 - q3 * q3 + qc * qc,
+                2.0 * (q1 * q2 - q3 * qc),
+                2.0 * (q1 * q3 + q2 * qc),
+            ),
+            DVec3::new(
+                2.0 * (q1 * q2 + q3 * qc),
+                -q1 * q1 + q2 * q2 - q3 * q3 + qc * qc,
+                2.0 * (q2 * q3 - q1 * qc),
+            ),
+            DVec3::new(
+                2.0 * (q1 * q3 - q2 * qc),
+                2.0 * (q2 * q3 + q1 * qc),
+                -q1 * q1 - q2 * q2 + q3 * q3 + qc * qc,
+            ),
+        ) / n2;
+        assert!(rotation.position_matrix().abs_diff_eq(expected, 1e-12));
+        assert_eq!(rotation.velocity_matrix(), DMat3::ZERO);
+        assert_close!(rotation.to_quaternion()[0], qc / n2.sqrt(), 1e-12);
+
+        let mut state = state.clone();
+        state.q_dir = "A2C".to_string();
+        assert_eq!(
+            Rotation::try_from(&state),
+            Err(AdmError::InvalidAttitudeDirection("A2C".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_rotation_from_apm_quaternion_derivative() {
+        // A constant rotation about a fixed axis
+        let axis = DVec3::new(1.0, -2.0, 0.5).normalize();
+        let rate = 1e-2;
+        let quaternion = |t: f64| {
+            let (s, c) = (rate * t / 2.0).sin_cos();
+            [c, s * axis.x, s * axis.y, s * axis.z]
+        };
+        let t = 30.0;
+        let [qc, q1, q2, q3] = quaternion(t);
+        let (s, c) = (rate * t / 2.0).sin_cos();
+        let state = ApmQuaternionState {
+            q_dir: "A2B".to_string(),
+            q1,
+            q2,
+            q3,
+            qc,
+            q1_dot: Some(rate / 2.0 * c * axis.x),
+            q2_dot: Some(rate / 2.0 * c * axis.y),
+            q3_dot: Some(rate / 2.0 * c * axis.z),
+            qc_dot: Some(-rate / 2.0 * s),
+            ..Default::default()
+        };
+        let rotation = Rotation::try_from(&state).unwrap();
+
+        let h = 1e-3;
+        let m = |t: f64| Rotation::from_adm_quaternion(quaternion(t), AttitudeDirection::AToB);
+        let expected = (m(t + h).position_matrix() - m(t - h).position_matrix()) / (2.0 * h);
+        assert!(rotation.velocity_matrix().abs_diff_eq(expected, 1e-10));
+    }
+
+    #[test]
+    fn test_rotation_from_adm_euler_angles() {
+        let (x, y, z) = (0.3, -0.5, 1.2);
+        let elementary = |axis: DVec3, angle: f64| {
+            let (s, c) = (angle / 2.0).sin_cos();
+            Rotation::from_adm_quaternion(
+                [c, s * axis.x, s * axis.y, s * axis.z],
+                AttitudeDirection::AToB,
+            )
+        };
+        // The rotations are applied in the order of the sequence
+        let expected = elementary(DVec3::Z, z)
+            .compose(&elementary(DVec3::X, x))
+            .compose(&elementary(DVec3::Y, y));
+
+        let state = ApmEulerAngleState {
+            euler_dir: "A2B".to_string(),
+            euler_rot_seq: "312".to_string(),
+            x_angle: AngleType {
+                base: x,
+                units: Some(AngleUnits("rad".to_string())),
+            },
+            y_angle: AngleType {
+                base: y.to_degrees(),
+                units: None,
+            },
+            z_angle: AngleType {
+                base: z.to_degrees(),
+                units: Some(AngleUnits("deg".to_string())),
+            },
+            ..Default::default()
+        };
+        let actual = Rotation::try_from(&state).unwrap();
+        assert!(
+            actual
+                .position_matrix()
+                .abs_diff_eq(expected.position_matrix(), 1e-15)
+        );
+
+        let state = ApmEulerAngleState {
+            euler_dir: "B2A".to_string(),
+            euler_rot_seq: "ZXY".to_string(),
+            ..state
+        };
+        let actual = Rotation::try_from(&state).unwrap();
+        assert!(
+            actual
+                .position_matrix()
+                .abs_diff_eq(expected.inverse().position_matrix(), 1e-15)
+        );
+    }
+
+    #[test]
+    fn test_rotation_slerp() {
+        let a = Rotation::new(DMat3::IDENTITY).with_angular_velocity(DVec3::Z * 1e-3);
+        let b =
+            Rotation::new(DMat3::from_rotation_z(FRAC_PI_2)).with_angular_velocity(DVec3::Z * 3e-3);
+        assert_eq!(Rotation::slerp(&a, &b, 0.0), a);
+        assert_eq!(Rotation::slerp(&a, &b, 1.0), b);
+
+        let mid = Rotation::slerp(&a, &b, 0.5);
+        assert!(
+            mid.position_matrix()
+                .abs_diff_eq(DMat3::from_rotation_z(FRAC_PI_2 / 2.0), 1e-15)
+        );
+        assert_close!(mid.angular_velocity(), DVec3::Z * 2e-3, 1e-18);
+
+        let r = rotation();
+        let mid = Rotation::slerp(&r, &r, 0.3);
+        assert!(
+            mid.position_matrix()
+                .abs_diff_eq(r.position_matrix(), 1e-15)
+        );
+        assert!(
+            mid.velocity_matrix()
+                .abs_diff_eq(r.velocity_matrix(), 1e-18)
+        );
+    }
+}
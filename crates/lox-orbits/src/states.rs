@@ -6,7 +6,7 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::f64::consts::{PI, TAU};
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
 use std::ops::Sub;
 
 use glam::{DMat3, DVec3};
@@ -20,6 +20,7 @@ use lox_math::{
     glam::Azimuth,
     math::{mod_two_pi, normalize_two_pi},
     roots::{BracketError, FindRoot, Secant},
+    types::units::Angle,
 };
 use lox_time::{Time, julian_dates::JulianDate, time_scales::DynTimeScale, time_scales::TimeScale};
 use thiserror::Error;
@@ -28,6 +29,7 @@ use crate::anomalies::{eccentric_to_true, hyperbolic_to_true};
 use crate::elements::{DynKeplerian, Keplerian, KeplerianElements, is_circular, is_equatorial};
 use crate::frames::{DynFrame, Iau, Icrf, ReferenceFrame, TryRotateTo};
 use crate::ground::{DynGroundLocation, GroundLocation};
+use crate::rotations::{Rotation, skew};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct State<T: TimeScale, O: Origin, R: ReferenceFrame> {
@@ -56,6 +58,12 @@ where
         }
     }
 
+    /// Returns a [StateBuilder] for constructing a new [State] at `time` relative to `origin` in
+    /// `frame`.
+    pub fn builder(time: Time<T>, origin: O, frame: R) -> StateBuilder<T, O, R> {
+        StateBuilder::new(time, origin, frame)
+    }
+
     pub fn origin(&self) -> O
     where
         O: Clone,
@@ -104,15 +112,131 @@ where
     }
 }
 
+/// `StateBuilder` supports the construction of [State] instances piecewise using the builder
+/// pattern.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StateBuilder<T: TimeScale, O: Origin, R: ReferenceFrame> {
+    time: Time<T>,
+    origin: O,
+    frame: R,
+    position: DVec3,
+    velocity: DVec3,
+}
+
+impl<T, O, R> StateBuilder<T, O, R>
+where
+    T: TimeScale,
+    O: Origin,
+    R: ReferenceFrame,
+{
+    /// Returns a new [StateBuilder] at `time` relative to `origin` in `frame` with zero position
+    /// and velocity.
+    pub fn new(time: Time<T>, origin: O, frame: R) -> Self {
+        Self {
+            time,
+            origin,
+            frame,
+            position: DVec3::ZERO,
+            velocity: DVec3::ZERO,
+        }
+    }
+
+    /// Sets the epoch of the [State] under construction.
+    pub fn with_time<T1: TimeScale>(self, time: Time<T1>) -> StateBuilder<T1, O, R> {
+        StateBuilder {
+            time,
+            origin: self.origin,
+            frame: self.frame,
+            position: self.position,
+            velocity: self.velocity,
+        }
+    }
+
+    /// Sets the central body of the [State] under construction.
+    pub fn with_origin<O1: Origin>(self, origin: O1) -> StateBuilder<T, O1, R> {
+        StateBuilder {
+            time: self.time,
+            origin,
+            frame: self.frame,
+            position: self.position,
+            velocity: self.velocity,
+        }
+    }
+
+    /// Sets the reference frame of the [State] under construction.
+    pub fn with_frame<R1: ReferenceFrame>(self, frame: R1) -> StateBuilder<T, O, R1> {
+        StateBuilder {
+            time: self.time,
+            origin: self.origin,
+            frame,
+            position: self.position,
+            velocity: self.velocity,
+        }
+    }
+
+    /// Sets the `position` in km of the [State] under construction.
+    pub fn with_position(self, position: DVec3) -> Self {
+        Self { position, ..self }
+    }
+
+    /// Sets the `velocity` in km/s of the [State] under construction.
+    pub fn with_velocity(self, velocity: DVec3) -> Self {
+        Self { velocity, ..self }
+    }
+
+    /// Builds the [State] instance.
+    pub fn build(self) -> State<T, O, R> {
+        State::new(
+            self.time,
+            self.position,
+            self.velocity,
+            self.origin,
+            self.frame,
+        )
+    }
+}
+
 fn rotation_lvlh(position: DVec3, velocity: DVec3) -> DMat3 {
     let r = position.normalize();
-    let v = velocity.normalize();
     let z = -r;
-    let y = -r.cross(v);
+    let y = -r.cross(velocity).normalize();
     let x = y.cross(z);
     DMat3::from_cols(x, y, z)
 }
 
+/// The local orbital frames defined by the position and velocity of a chief spacecraft.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LocalOrbitalFrame {
+    /// The radial-transverse-normal frame, where R points along the position, N along the
+    /// instantaneous orbital angular momentum, and T = N × R completes the right-handed triad,
+    /// such that it is aligned with the velocity for circular orbits.
+    Rtn,
+    /// The local vertical, local horizontal frame, where z points to the nadir (-R), y against
+    /// the orbital angular momentum (-N), and x = y × z (T).
+    Lvlh,
+}
+
+impl LocalOrbitalFrame {
+    /// Returns the rotation from the inertial frame to this local orbital frame for a chief at
+    /// `position` with `velocity`.
+    ///
+    /// The derivative of the rotation accounts for the rotation of the frame with the orbital
+    /// angular velocity (r × v) / |r|².
+    pub fn rotation(&self, position: DVec3, velocity: DVec3) -> Rotation {
+        let m = match self {
+            LocalOrbitalFrame::Rtn => {
+                let r = position.normalize();
+                let n = position.cross(velocity).normalize();
+                let t = n.cross(r);
+                DMat3::from_cols(r, t, n).transpose()
+            }
+            LocalOrbitalFrame::Lvlh => rotation_lvlh(position, velocity).transpose(),
+        };
+        let w = position.cross(velocity) / position.length_squared();
+        Rotation::new(m).with_derivative(-m * skew(w))
+    }
+}
+
 impl<T, O> State<T, O, Icrf>
 where
     T: TimeScale,
@@ -121,6 +245,49 @@ where
     pub fn rotation_lvlh(&self) -> DMat3 {
         rotation_lvlh(self.position(), self.velocity())
     }
+
+    /// Returns the rotation from the ICRF to the local orbital `frame` of this state.
+    pub fn rotation_local(&self, frame: LocalOrbitalFrame) -> Rotation {
+        frame.rotation(self.position(), self.velocity())
+    }
+
+    /// Returns the position and velocity of `other` relative to this state, expressed in the
+    /// local orbital `frame` of this state.
+    ///
+    /// The relative velocity is observed from the rotating local orbital frame.
+    pub fn relative_local(&self, other: &Self, frame: LocalOrbitalFrame) -> (DVec3, DVec3) {
+        self.rotation_local(frame).rotate_state(
+            other.position() - self.position(),
+            other.velocity() - self.velocity(),
+        )
+    }
+
+    /// Returns the ICRF state of a deputy at `position` with `velocity` relative to this state,
+    /// expressed in the local orbital `frame` of this state.
+    ///
+    /// This is the inverse of [State::relative_local].
+    pub fn from_relative_local(
+        &self,
+        position: DVec3,
+        velocity: DVec3,
+        frame: LocalOrbitalFrame,
+    ) -> Self
+    where
+        T: Clone,
+        O: Clone,
+    {
+        let (dr, dv) = self
+            .rotation_local(frame)
+            .transpose()
+            .rotate_state(position, velocity);
+        State::new(
+            self.time(),
+            self.position() + dr,
+            self.velocity() + dv,
+            self.origin(),
+            Icrf,
+        )
+    }
 }
 
 impl DynState {
@@ -309,8 +476,12 @@ pub(crate) fn rv_to_keplerian(r: DVec3, v: DVec3, mu: f64) -> KeplerianElements
     let eccentricity = e.length();
     let inclination = h.angle_between(DVec3::Z);
 
-    let equatorial = is_equatorial(inclination);
+    // For retrograde equatorial orbits the angles are measured clockwise
+    // when viewed from the north pole
+    let retrograde = inclination > FRAC_PI_2;
+    let equatorial = is_equatorial(inclination) || is_equatorial(PI - inclination);
     let circular = is_circular(eccentricity);
+    let sign = if retrograde { -1.0 } else { 1.0 };
 
     let semi_major_axis = if circular {
         hm.powi(2) / mu
@@ -323,7 +494,7 @@ pub(crate) fn rv_to_keplerian(r: DVec3, v: DVec3, mu: f64) -> KeplerianElements
     let true_anomaly;
     if equatorial && !circular {
         longitude_of_ascending_node = 0.0;
-        argument_of_periapsis = e.azimuth();
+        argument_of_periapsis = sign * e.azimuth();
         true_anomaly = (h.dot(e.cross(r)) / hm).atan2(r.dot(e));
     } else if !equatorial && circular {
         longitude_of_ascending_node = node.azimuth();
@@ -332,7 +503,7 @@ pub(crate) fn rv_to_keplerian(r: DVec3, v: DVec3, mu: f64) -> KeplerianElements
     } else if equatorial && circular {
         longitude_of_ascending_node = 0.0;
         argument_of_periapsis = 0.0;
-        true_anomaly = r.azimuth();
+        true_anomaly = sign * r.azimuth();
     } else {
         if semi_major_axis > 0.0 {
             let e_se = r.dot(v) / (mu * semi_major_axis).sqrt();
@@ -376,10 +547,10 @@ where
             self.origin(),
             elements.semi_major_axis,
             elements.eccentricity,
-            elements.inclination,
-            elements.longitude_of_ascending_node,
-            elements.argument_of_periapsis,
-            elements.true_anomaly,
+            Angle::from_radians(elements.inclination),
+            Angle::from_radians(elements.longitude_of_ascending_node),
+            Angle::from_radians(elements.argument_of_periapsis),
+            Angle::from_radians(elements.true_anomaly),
         )
     }
 }
@@ -397,10 +568,10 @@ impl DynState {
             self.origin(),
             elements.semi_major_axis,
             elements.eccentricity,
-            elements.inclination,
-            elements.longitude_of_ascending_node,
-            elements.argument_of_periapsis,
-            elements.true_anomaly,
+            Angle::from_radians(elements.inclination),
+            Angle::from_radians(elements.longitude_of_ascending_node),
+            Angle::from_radians(elements.argument_of_periapsis),
+            Angle::from_radians(elements.true_anomaly),
         )
     }
 }
@@ -411,11 +582,11 @@ mod tests {
 
     use float_eq::assert_float_eq;
 
-    use lox_bodies::{Earth, Jupiter, Venus};
+    use lox_bodies::{Earth, Jupiter, Moon, Venus};
     use lox_ephem::spk::parser::{Spk, parse_daf_spk};
     use lox_math::assert_close;
     use lox_math::is_close::IsClose;
-    use lox_time::{Time, time, time_scales::Tdb, utc::Utc};
+    use lox_time::{Time, time, time_scales::Tai, time_scales::Tdb, utc::Utc};
 
     use super::*;
 
@@ -477,6 +648,46 @@ mod tests {
         assert_float_eq!(cartesian.velocity().z, cartesian1.velocity().z, rel <= 1e-6);
     }
 
+    #[test]
+    fn test_state_builder() {
+        let time = time!(Tdb, 2012, 7, 1).unwrap();
+        let position = DVec3::new(3359.927, -2398.072, 5153.0);
+        let velocity = DVec3::new(5.0657, 5.485, -0.744);
+        let state = State::builder(time, Earth, Icrf)
+            .with_position(position)
+            .with_velocity(velocity)
+            .build();
+        assert_eq!(state, State::new(time, position, velocity, Earth, Icrf));
+
+        let tai = time!(Tai, 2012, 7, 1).unwrap();
+        let state = StateBuilder::new(time, Earth, Icrf)
+            .with_time(tai)
+            .with_origin(Moon)
+            .with_frame(Iau(Moon))
+            .with_position(position)
+            .build();
+        assert_eq!(state.time(), tai);
+        assert_eq!(state.origin(), Moon);
+        assert_eq!(state.reference_frame(), Iau(Moon));
+        assert_eq!(state.velocity(), DVec3::ZERO);
+    }
+
+    #[test]
+    fn test_state_frame_transformation_updates_frame() {
+        let time = time!(Tdb, 2012, 7, 1).unwrap();
+        let state = State::builder(time, Earth, Icrf)
+            .with_position(DVec3::new(3359.927, -2398.072, 5153.0))
+            .with_velocity(DVec3::new(5.0657, 5.485, -0.744))
+            .build();
+        let body_fixed = state.try_to_frame(Iau(Earth), None::<&()>).unwrap();
+        assert_eq!(body_fixed.reference_frame(), Iau(Earth));
+        assert_eq!(body_fixed.time(), time);
+        assert_eq!(body_fixed.origin(), Earth);
+        assert_ne!(body_fixed.position(), state.position());
+        let inertial = body_fixed.try_to_frame(Icrf, None::<&()>).unwrap();
+        assert_eq!(inertial.reference_frame(), Icrf);
+    }
+
     #[test]
     fn test_state_to_ground_location() {
         let lat_exp = 51.484f64.to_radians();
@@ -493,6 +704,88 @@ mod tests {
         assert_float_eq!(ground.altitude(), alt_exp, rel <= 1e-4);
     }
 
+    #[test]
+    fn test_rtn_circular_orbit() {
+        let time = time!(Tdb, 2023, 1, 1).unwrap();
+        let r = 7000.0;
+        let v = (Earth.gravitational_parameter() / r).sqrt();
+        let position = DVec3::new(r, 0.0, 0.0);
+        let velocity = DVec3::new(0.0, v * 0.6, v * 0.8);
+        let chief = State::new(time, position, velocity, Earth, Icrf);
+        let rot = chief.rotation_local(LocalOrbitalFrame::Rtn);
+
+        let h = position.cross(velocity).normalize();
+        assert_close!(
+            rot.rotate_position(position),
+            DVec3::new(r, 0.0, 0.0),
+            1e-12,
+            0.0
+        );
+        assert_close!(rot.rotate_position(h), DVec3::Z, 1e-15, 0.0);
+        assert_close!(
+            rot.rotate_position(velocity.normalize()),
+            DVec3::Y,
+            1e-15,
+            0.0
+        );
+
+        // The chief is at rest at the origin of its own local orbital frame
+        let (_, v_rtn) = rot.rotate_state(position, velocity);
+        assert_close!(v_rtn, DVec3::ZERO, 1e-12, 0.0);
+
+        let lvlh = chief.rotation_local(LocalOrbitalFrame::Lvlh);
+        assert_close!(
+            lvlh.rotate_position(position),
+            DVec3::new(0.0, 0.0, -r),
+            1e-12,
+            0.0
+        );
+        assert_close!(lvlh.rotate_position(h), -DVec3::Y, 1e-15, 0.0);
+        assert!(
+            lvlh.position_matrix()
+                .abs_diff_eq(chief.rotation_lvlh().transpose(), 1e-15)
+        );
+    }
+
+    #[test]
+    fn test_rtn_relative_state() {
+        let time = time!(Tdb, 2023, 1, 1).unwrap();
+        let mu = Earth.gravitational_parameter();
+        let r: f64 = 7000.0;
+        let n = (mu / r.powi(3)).sqrt();
+        let chief = State::new(
+            time,
+            DVec3::new(r, 0.0, 0.0),
+            DVec3::new(0.0, n * r, 0.0),
+            Earth,
+            Icrf,
+        );
+
+        // A deputy trailing on the same circular orbit does not move in the chief's frame
+        let phi: f64 = 1e-3;
+        let deputy = State::new(
+            time,
+            r * DVec3::new(phi.cos(), -phi.sin(), 0.0),
+            n * r * DVec3::new(phi.sin(), phi.cos(), 0.0),
+            Earth,
+            Icrf,
+        );
+        let (dr, dv) = chief.relative_local(&deputy, LocalOrbitalFrame::Rtn);
+        assert_float_eq!(dr.y, -r * phi.sin(), abs <= 1e-9);
+        assert_float_eq!(dr.z, 0.0, abs <= 1e-12);
+        assert_close!(dv, DVec3::ZERO, 1e-12, 0.0);
+
+        let act = chief.from_relative_local(dr, dv, LocalOrbitalFrame::Rtn);
+        assert_close!(act.position(), deputy.position(), 1e-9, 0.0);
+        assert_close!(act.velocity(), deputy.velocity(), 1e-12, 0.0);
+    }
+
+    #[test]
+    fn test_rotation_lvlh_eccentric_orbit_is_orthonormal() {
+        let m = rotation_lvlh(DVec3::new(7000.0, 0.0, 0.0), DVec3::new(1.0, 9.0, 2.0));
+        assert!((m.transpose() * m).abs_diff_eq(DMat3::IDENTITY, 1e-15));
+    }
+
     pub fn data_dir() -> PathBuf {
         PathBuf::from(format!("{}/../../data", env!("CARGO_MANIFEST_DIR")))
     }
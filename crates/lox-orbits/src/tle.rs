@@ -0,0 +1,974 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Parsing of NORAD two-line element sets (TLE)
+//!
+//! # Example
+//!
+//! ```
+//! use lox_orbits::tle;
+//!
+//! let tle = tle::parse(
+//!     "1 25544U 98067A   24170.37528350  .00016566  00000+0  30244-3 0  9996",
+//!     "2 25544  51.6410 309.3890 0010444 339.5369 107.8830 15.49495945458731",
+//! )
+//! .unwrap();
+//! assert_eq!(tle.catalog_number(), 25544);
+//! ```
+
+use std::f64::consts::TAU;
+
+use thiserror::Error;
+
+use lox_io::ndm::common::{
+    AngleType, EpochType, GmType, InclinationType, NonNegativeDouble, OdmHeader, PositiveDouble,
+};
+use lox_io::ndm::omm::{
+    BStarType, DRevType, ElementSetNoType, MeanElementsType, OmmBody, OmmData, OmmMetadata,
+    OmmSegment, OmmType, RevType, TleParametersType,
+};
+use lox_math::constants::f64::time::SECONDS_PER_DAY;
+use lox_time::calendar_dates::Date;
+use lox_time::utc::Utc;
+
+const LINE_LENGTH: usize = 69;
+
+/// Two-digit epoch years below this value belong to the 21st century.
+const EPOCH_YEAR_ROLLOVER: i64 = 57;
+
+/// The resolution of the fractional day of the epoch, i.e. 1e-8 days, in nanoseconds
+const EPOCH_RESOLUTION_NANOS: u64 = 864_000;
+
+/// The gravitational parameter of the WGS-72 model used by SGP4 in km³/s²
+pub(crate) const WGS72_GM: f64 = 398600.8;
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum TleError {
+    #[error("line {line} must be {LINE_LENGTH} characters long but was {length}")]
+    InvalidLineLength { line: u8, length: usize },
+    #[error("line {line} must start with `{line}` but started with `{found}`")]
+    InvalidLineNumber { line: u8, found: String },
+    #[error("checksum mismatch on line {line}: expected {expected} but computed {actual}")]
+    ChecksumMismatch { line: u8, expected: u8, actual: u8 },
+    #[error("malformed {field} on line {line}: `{value}`")]
+    MalformedField {
+        line: u8,
+        field: &'static str,
+        value: String,
+    },
+    #[error("catalog numbers do not match: `{line1}` on line 1 and `{line2}` on line 2")]
+    CatalogNumberMismatch { line1: u32, line2: u32 },
+    #[error("the OMM does not contain the {0} required for a TLE")]
+    MissingOmmField(&'static str),
+    #[error("{field} `{value}` cannot be represented in a TLE")]
+    UnrepresentableField { field: &'static str, value: String },
+}
+
+/// A parsed two-line element set
+///
+/// Angles are given in radians and the mean motion in radians per second.
+/// The derivatives of the mean motion and the BSTAR drag term are kept in
+/// the units of the TLE format.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tle {
+    catalog_number: u32,
+    classification: char,
+    international_designator: String,
+    epoch: Utc,
+    mean_motion_dot: f64,
+    mean_motion_ddot: f64,
+    bstar: f64,
+    ephemeris_type: u8,
+    element_set_number: u32,
+    inclination: f64,
+    longitude_of_ascending_node: f64,
+    eccentricity: f64,
+    argument_of_periapsis: f64,
+    mean_anomaly: f64,
+    mean_motion: f64,
+    revolution_number: u32,
+    lines: [String; 2],
+}
+
+impl Tle {
+    /// The NORAD catalog number of the satellite
+    pub fn catalog_number(&self) -> u32 {
+        self.catalog_number
+    }
+
+    /// The classification, i.e. `U` (unclassified), `C` (classified), or `S` (secret)
+    pub fn classification(&self) -> char {
+        self.classification
+    }
+
+    /// The international designator, e.g. `98067A`
+    pub fn international_designator(&self) -> &str {
+        &self.international_designator
+    }
+
+    pub fn epoch(&self) -> Utc {
+        self.epoch
+    }
+
+    /// The first time derivative of the mean motion divided by two in rev/day²
+    pub fn mean_motion_dot(&self) -> f64 {
+        self.mean_motion_dot
+    }
+
+    /// The second time derivative of the mean motion divided by six in rev/day³
+    pub fn mean_motion_ddot(&self) -> f64 {
+        self.mean_motion_ddot
+    }
+
+    /// The BSTAR drag term in inverse Earth radii
+    pub fn bstar(&self) -> f64 {
+        self.bstar
+    }
+
+    /// The ephemeris type, which is always `0` for element sets distributed to the public
+    pub fn ephemeris_type(&self) -> u8 {
+        self.ephemeris_type
+    }
+
+    pub fn element_set_number(&self) -> u32 {
+        self.element_set_number
+    }
+
+    pub fn inclination(&self) -> f64 {
+        self.inclination
+    }
+
+    pub fn longitude_of_ascending_node(&self) -> f64 {
+        self.longitude_of_ascending_node
+    }
+
+    pub fn eccentricity(&self) -> f64 {
+        self.eccentricity
+    }
+
+    pub fn argument_of_periapsis(&self) -> f64 {
+        self.argument_of_periapsis
+    }
+
+    pub fn mean_anomaly(&self) -> f64 {
+        self.mean_anomaly
+    }
+
+    /// The mean motion in rad/s
+    pub fn mean_motion(&self) -> f64 {
+        self.mean_motion
+    }
+
+    /// The mean motion in revolutions per day as given in the TLE
+    pub fn revolutions_per_day(&self) -> f64 {
+        self.mean_motion * SECONDS_PER_DAY / TAU
+    }
+
+    /// The revolution number at epoch
+    pub fn revolution_number(&self) -> u32 {
+        self.revolution_number
+    }
+
+    /// The two lines the element set was parsed from
+    pub fn lines(&self) -> (&str, &str) {
+        (&self.lines[0], &self.lines[1])
+    }
+}
+
+/// Parses a two-line element set and validates the checksums of both lines.
+///
+/// Two-digit epoch years from 57 to 99 refer to the 20th century and all
+/// others to the 21st century. Trailing whitespace, e.g. a carriage return,
+/// is ignored.
+pub fn parse(line1: &str, line2: &str) -> Result<Tle, TleError> {
+    let line1 = validate_line(line1, 1)?;
+    let line2 = validate_line(line2, 2)?;
+
+    let catalog_number = parse_field(line1, 1, 2..7, "catalog number")?;
+    let classification = line1[7..8].chars().next().unwrap_or(' ');
+    let international_designator = line1[9..17].trim().to_string();
+    let epoch = parse_epoch(line1)?;
+    let mean_motion_dot = parse_field(line1, 1, 33..43, "first derivative of mean motion")?;
+    let mean_motion_ddot =
+        parse_implied_decimal(line1, 1, 44..52, "second derivative of mean motion")?;
+    let bstar = parse_implied_decimal(line1, 1, 53..61, "BSTAR")?;
+    let ephemeris_type = match line1[62..63].trim() {
+        "" => 0,
+        _ => parse_field(line1, 1, 62..63, "ephemeris type")?,
+    };
+    let element_set_number = parse_field(line1, 1, 64..68, "element set number")?;
+
+    let catalog_number2 = parse_field(line2, 2, 2..7, "catalog number")?;
+    if catalog_number != catalog_number2 {
+        return Err(TleError::CatalogNumberMismatch {
+            line1: catalog_number,
+            line2: catalog_number2,
+        });
+    }
+    let inclination: f64 = parse_field(line2, 2, 8..16, "inclination")?;
+    let longitude_of_ascending_node: f64 =
+        parse_field(line2, 2, 17..25, "right ascension of the ascending node")?;
+    let eccentricity = parse_eccentricity(line2)?;
+    let argument_of_periapsis: f64 = parse_field(line2, 2, 34..42, "argument of perigee")?;
+    let mean_anomaly: f64 = parse_field(line2, 2, 43..51, "mean anomaly")?;
+    let revolutions_per_day: f64 = parse_field(line2, 2, 52..63, "mean motion")?;
+    let revolution_number = parse_field(line2, 2, 63..68, "revolution number")?;
+
+    Ok(Tle {
+        catalog_number,
+        classification,
+        international_designator,
+        epoch,
+        mean_motion_dot,
+        mean_motion_ddot,
+        bstar,
+        ephemeris_type,
+        element_set_number,
+        inclination: inclination.to_radians(),
+        longitude_of_ascending_node: longitude_of_ascending_node.to_radians(),
+        eccentricity,
+        argument_of_periapsis: argument_of_periapsis.to_radians(),
+        mean_anomaly: mean_anomaly.to_radians(),
+        mean_motion: revolutions_per_day * TAU / SECONDS_PER_DAY,
+        revolution_number,
+        lines: [line1.to_string(), line2.to_string()],
+    })
+}
+
+/// Computes the modulo 10 checksum of a TLE line. Digits count with their
+/// value, minus signs count as one, and all other characters are ignored.
+fn checksum(line: &str) -> u8 {
+    let sum: u32 = line
+        .chars()
+        .map(|c| match c {
+            '-' => 1,
+            c => c.to_digit(10).unwrap_or(0),
+        })
+        .sum();
+    (sum % 10) as u8
+}
+
+fn validate_line(line: &str, line_number: u8) -> Result<&str, TleError> {
+    let line = line.trim_end();
+    if !line.is_ascii() || line.len() != LINE_LENGTH {
+        return Err(TleError::InvalidLineLength {
+            line: line_number,
+            length: line.chars().count(),
+        });
+    }
+    let found = &line[0..1];
+    if found != line_number.to_string() {
+        return Err(TleError::InvalidLineNumber {
+            line: line_number,
+            found: found.to_string(),
+        });
+    }
+    let expected = line[68..69].parse().map_err(|_| TleError::MalformedField {
+        line: line_number,
+        field: "checksum",
+        value: line[68..69].to_string(),
+    })?;
+    let actual = checksum(&line[..68]);
+    if expected != actual {
+        return Err(TleError::ChecksumMismatch {
+            line: line_number,
+            expected,
+            actual,
+        });
+    }
+    Ok(line)
+}
+
+fn malformed(
+    line: &str,
+    line_number: u8,
+    range: std::ops::Range<usize>,
+    field: &'static str,
+) -> TleError {
+    TleError::MalformedField {
+        line: line_number,
+        field,
+        value: line[range].trim().to_string(),
+    }
+}
+
+fn parse_field<T: std::str::FromStr>(
+    line: &str,
+    line_number: u8,
+    range: std::ops::Range<usize>,
+    field: &'static str,
+) -> Result<T, TleError> {
+    line[range.clone()]
+        .trim()
+        .parse()
+        .map_err(|_| malformed(line, line_number, range, field))
+}
+
+/// Parses fields in the compact exponential notation of the TLE format,
+/// e.g. `-11606-4` which represents -0.11606e-4.
+fn parse_implied_decimal(
+    line: &str,
+    line_number: u8,
+    range: std::ops::Range<usize>,
+    field: &'static str,
+) -> Result<f64, TleError> {
+    let err = || malformed(line, line_number, range.clone(), field);
+    let value = line[range.clone()].trim();
+    if value.len() < 3 {
+        return Err(err());
+    }
+    let (mantissa, exponent) = value.split_at(value.len() - 2);
+    let (sign, digits) = match mantissa.strip_prefix('-') {
+        Some(digits) => (-1.0, digits),
+        None => (1.0, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(err());
+    }
+    let exponent: i32 = exponent.parse().map_err(|_| err())?;
+    // Let the float parser round the decimal value instead of scaling the mantissa
+    let value: f64 = format!("0.{}e{}", digits, exponent)
+        .parse()
+        .map_err(|_| err())?;
+    Ok(sign * value)
+}
+
+fn parse_eccentricity(line: &str) -> Result<f64, TleError> {
+    let range = 26..33;
+    let digits = &line[range.clone()];
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(malformed(line, 2, range, "eccentricity"));
+    }
+    format!("0.{}", digits)
+        .parse()
+        .map_err(|_| malformed(line, 2, range, "eccentricity"))
+}
+
+/// Expands a two-digit TLE epoch or launch year to the full year.
+fn full_year(year: i64) -> i64 {
+    if year < EPOCH_YEAR_ROLLOVER {
+        2000 + year
+    } else {
+        1900 + year
+    }
+}
+
+fn parse_epoch(line: &str) -> Result<Utc, TleError> {
+    let err = || malformed(line, 1, 18..32, "epoch");
+    let year = full_year(parse_field(line, 1, 18..20, "epoch")?);
+    let day: f64 = parse_field(line, 1, 20..32, "epoch")?;
+    if !(1.0..367.0).contains(&day) {
+        return Err(err());
+    }
+    let day_of_year = day.trunc() as u16;
+    let date = Date::from_day_of_year(year, day_of_year).map_err(|_| err())?;
+    let seconds = day.fract() * SECONDS_PER_DAY;
+    let hour = (seconds / 3600.0).trunc();
+    let minute = ((seconds - hour * 3600.0) / 60.0).trunc();
+    let second = seconds - hour * 3600.0 - minute * 60.0;
+    Utc::builder()
+        .with_ymd(date.year(), date.month(), date.day())
+        .with_hms(hour as u8, minute as u8, second)
+        .build()
+        .map_err(|_| err())
+}
+
+impl From<&Tle> for OmmType {
+    /// Converts the element set into an OMM with SGP4 mean elements in the TEME frame.
+    ///
+    /// The values are taken verbatim from the lines of the element set, such that [tle_lines]
+    /// reproduces them. The object name is set to `UNKNOWN` and the creation date to the epoch
+    /// since a TLE carries neither.
+    fn from(tle: &Tle) -> Self {
+        // The lines have already been validated by `parse`
+        let decimal = |line: usize, range: std::ops::Range<usize>| -> f64 {
+            tle.lines[line][range].trim().parse().unwrap()
+        };
+        let epoch = EpochType(omm_epoch(&tle.lines[0]));
+        OmmType {
+            id: Some("CCSDS_OMM_VERS".to_string()),
+            version: "3.0".to_string(),
+            header: OdmHeader {
+                creation_date: epoch.clone(),
+                originator: "LOX".to_string(),
+                ..Default::default()
+            },
+            body: OmmBody {
+                segment: OmmSegment {
+                    metadata: OmmMetadata {
+                        object_name: "UNKNOWN".to_string(),
+                        object_id: object_id(&tle.international_designator),
+                        center_name: "EARTH".to_string(),
+                        ref_frame: "TEME".to_string(),
+                        time_system: "UTC".to_string(),
+                        mean_element_theory: "SGP4".to_string(),
+                        ..Default::default()
+                    },
+                    data: OmmData {
+                        mean_elements: MeanElementsType {
+                            epoch,
+                            mean_motion: Some(RevType {
+                                base: decimal(1, 52..63),
+                                units: None,
+                            }),
+                            eccentricity: NonNegativeDouble(tle.eccentricity),
+                            inclination: InclinationType {
+                                base: decimal(1, 8..16),
+                                units: None,
+                            },
+                            ra_of_asc_node: AngleType {
+                                base: decimal(1, 17..25),
+                                units: None,
+                            },
+                            arg_of_pericenter: AngleType {
+                                base: decimal(1, 34..42),
+                                units: None,
+                            },
+                            mean_anomaly: AngleType {
+                                base: decimal(1, 43..51),
+                                units: None,
+                            },
+                            gm: Some(GmType {
+                                base: PositiveDouble(WGS72_GM),
+                                units: None,
+                            }),
+                            ..Default::default()
+                        },
+                        tle_parameters: Some(TleParametersType {
+                            ephemeris_type: Some(tle.ephemeris_type as i32),
+                            classification_type: Some(tle.classification.to_string()),
+                            norad_cat_id: Some(tle.catalog_number as i32),
+                            element_set_no: Some(ElementSetNoType(
+                                tle.element_set_number.to_string(),
+                            )),
+                            rev_at_epoch: Some(tle.revolution_number as u64),
+                            bstar: Some(BStarType {
+                                base: tle.bstar,
+                                units: None,
+                            }),
+                            mean_motion_dot: DRevType {
+                                base: tle.mean_motion_dot,
+                                units: None,
+                            },
+                            mean_motion_ddot: Some(DRevType {
+                                base: tle.mean_motion_ddot,
+                                units: None,
+                            }),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                },
+            },
+        }
+    }
+}
+
+impl TryFrom<&OmmType> for Tle {
+    type Error = TleError;
+
+    /// Converts the SGP4 mean elements of an OMM into a TLE. See [tle_lines].
+    fn try_from(omm: &OmmType) -> Result<Self, Self::Error> {
+        let (line1, line2) = tle_lines(omm)?;
+        parse(&line1, &line2)
+    }
+}
+
+/// Formats the SGP4 mean elements of an OMM as the two lines of a TLE including checksums.
+///
+/// The mean motion and the TLE parameters `NORAD_CAT_ID` and `MEAN_MOTION_DOT` are required. The
+/// derivatives of the mean motion are expected in the units of the TLE format, i.e. divided by two
+/// and six respectively, which is the convention of OMMs distributed by CelesTrak and Space-Track.
+/// The classification defaults to `U`, the ephemeris type to `0`, and the element set number to
+/// `999`.
+pub fn tle_lines(omm: &OmmType) -> Result<(String, String), TleError> {
+    let metadata = &omm.body.segment.metadata;
+    let elements = &omm.body.segment.data.mean_elements;
+    let parameters = omm
+        .body
+        .segment
+        .data
+        .tle_parameters
+        .as_ref()
+        .ok_or(TleError::MissingOmmField("TLE parameters"))?;
+    let mean_motion = elements
+        .mean_motion
+        .as_ref()
+        .ok_or(TleError::MissingOmmField("MEAN_MOTION"))?
+        .base;
+    let catalog_number = parameters
+        .norad_cat_id
+        .ok_or(TleError::MissingOmmField("NORAD_CAT_ID"))?;
+
+    let unrepresentable =
+        |field: &'static str, value: String| TleError::UnrepresentableField { field, value };
+    if !(0..100_000).contains(&catalog_number) {
+        return Err(unrepresentable("NORAD_CAT_ID", catalog_number.to_string()));
+    }
+    let classification = match parameters.classification_type.as_deref().map(str::trim) {
+        None | Some("") => 'U',
+        Some(c) if c.len() == 1 && c.is_ascii() => c.chars().next().unwrap(),
+        Some(c) => return Err(unrepresentable("CLASSIFICATION_TYPE", c.to_string())),
+    };
+    let designator = international_designator(&metadata.object_id)
+        .ok_or_else(|| unrepresentable("OBJECT_ID", metadata.object_id.clone()))?;
+    let epoch = tle_epoch(&elements.epoch.0)
+        .ok_or_else(|| unrepresentable("EPOCH", elements.epoch.0.clone()))?;
+    let mean_motion_dot =
+        format_mean_motion_dot(parameters.mean_motion_dot.base).ok_or_else(|| {
+            unrepresentable(
+                "MEAN_MOTION_DOT",
+                parameters.mean_motion_dot.base.to_string(),
+            )
+        })?;
+    let mean_motion_ddot = parameters.mean_motion_ddot.as_ref().map_or(0.0, |d| d.base);
+    let mean_motion_ddot = format_implied_decimal(mean_motion_ddot)
+        .ok_or_else(|| unrepresentable("MEAN_MOTION_DDOT", mean_motion_ddot.to_string()))?;
+    let bstar = parameters.bstar.as_ref().map_or(0.0, |b| b.base);
+    let bstar =
+        format_implied_decimal(bstar).ok_or_else(|| unrepresentable("BSTAR", bstar.to_string()))?;
+    let ephemeris_type = parameters.ephemeris_type.unwrap_or(0);
+    if !(0..10).contains(&ephemeris_type) {
+        return Err(unrepresentable(
+            "EPHEMERIS_TYPE",
+            ephemeris_type.to_string(),
+        ));
+    }
+    let element_set_number = match &parameters.element_set_no {
+        Some(ElementSetNoType(n)) => n
+            .trim()
+            .parse::<u32>()
+            .ok()
+            .filter(|&n| n < 10_000)
+            .ok_or_else(|| unrepresentable("ELEMENT_SET_NO", n.clone()))?,
+        None => 999,
+    };
+    let eccentricity = (elements.eccentricity.0 * 1e7).round();
+    if !(0.0..1e7).contains(&eccentricity) {
+        return Err(unrepresentable(
+            "ECCENTRICITY",
+            elements.eccentricity.0.to_string(),
+        ));
+    }
+    // The revolution number wraps around after 99999 revolutions
+    let revolution_number = parameters.rev_at_epoch.unwrap_or(0) % 100_000;
+
+    let line1 = format!(
+        "1 {:05}{} {:<8} {} {} {} {} {} {:>4}",
+        catalog_number,
+        classification,
+        designator,
+        epoch,
+        mean_motion_dot,
+        mean_motion_ddot,
+        bstar,
+        ephemeris_type,
+        element_set_number,
+    );
+    let line2 = format!(
+        "2 {:05} {:8.4} {:8.4} {:07} {:8.4} {:8.4} {:11.8}{:>5}",
+        catalog_number,
+        elements.inclination.base,
+        elements.ra_of_asc_node.base,
+        eccentricity as u32,
+        elements.arg_of_pericenter.base,
+        elements.mean_anomaly.base,
+        mean_motion,
+        revolution_number,
+    );
+    let line1 = format!("{}{}", line1, checksum(&line1));
+    let line2 = format!("{}{}", line2, checksum(&line2));
+    Ok((line1, line2))
+}
+
+/// Converts an international designator, e.g. `98067A`, into an OMM object ID, e.g. `1998-067A`.
+fn object_id(designator: &str) -> String {
+    match designator
+        .get(..2)
+        .and_then(|year| year.parse::<i64>().ok())
+    {
+        Some(year) => format!("{}-{}", full_year(year), &designator[2..]),
+        None => designator.to_string(),
+    }
+}
+
+/// Converts an OMM object ID, e.g. `1998-067A`, into an international designator, e.g. `98067A`.
+fn international_designator(object_id: &str) -> Option<String> {
+    let designator = match object_id.trim().split_once('-') {
+        Some((year, rest)) if year.len() == 4 => format!("{}{}", &year[2..], rest),
+        _ => object_id.trim().to_string(),
+    };
+    (designator.is_ascii() && designator.len() <= 8).then_some(designator)
+}
+
+/// Formats the epoch on line 1 of a TLE as an OMM epoch with microsecond precision, which
+/// represents the fractional day of the TLE exactly.
+fn omm_epoch(line1: &str) -> String {
+    let year = full_year(line1[18..20].parse().unwrap());
+    let (day, fraction) = line1[20..32]
+        .trim()
+        .split_once('.')
+        .unwrap_or((line1[20..32].trim(), ""));
+    let date = Date::from_day_of_year(year, day.parse().unwrap()).unwrap();
+    let units: u64 = format!("{:0<8.8}", fraction).parse().unwrap();
+    let micros = units * EPOCH_RESOLUTION_NANOS / 1000;
+    let seconds = micros / 1_000_000;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:06}",
+        date.year(),
+        date.month(),
+        date.day(),
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60,
+        micros % 1_000_000,
+    )
+}
+
+/// Formats an OMM epoch in calendar or day-of-year format as the epoch field of a TLE, rounded to
+/// the nearest 1e-8 days.
+fn tle_epoch(epoch: &str) -> Option<String> {
+    let (date, time) = epoch.trim().split_once('T')?;
+    let time = time.strip_suffix('Z').unwrap_or(time);
+    let date = match date.split_once('-') {
+        Some((year, day)) if day.len() == 3 => {
+            Date::from_day_of_year(year.parse().ok()?, day.parse().ok()?).ok()?
+        }
+        _ => date.parse().ok()?,
+    };
+    let (hms, fraction) = time.split_once('.').unwrap_or((time, ""));
+    let mut hms = hms.split(':').map(|v| v.parse::<u64>().ok());
+    let (Some(Some(hour)), Some(Some(minute)), Some(Some(second)), None) =
+        (hms.next(), hms.next(), hms.next(), hms.next())
+    else {
+        return None;
+    };
+    if !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let nanos: u64 = format!("{:0<9.9}", fraction).parse().ok()?;
+    let nanos = ((hour * 60 + minute) * 60 + second) * 1_000_000_000 + nanos;
+    let mut units = (nanos + EPOCH_RESOLUTION_NANOS / 2) / EPOCH_RESOLUTION_NANOS;
+    // Rounding may carry over into the next day
+    let date = if units >= 100_000_000 {
+        units -= 100_000_000;
+        Date::from_days_since_j2000(date.j2000_day_number() + 1)
+    } else {
+        date
+    };
+    let year = date.year();
+    if !(1900 + EPOCH_YEAR_ROLLOVER..2000 + EPOCH_YEAR_ROLLOVER).contains(&year) {
+        return None;
+    }
+    let day = date.j2000_day_number() - Date::new(year, 1, 1).ok()?.j2000_day_number() + 1;
+    Some(format!("{:02}{:03}.{:08}", year % 100, day, units))
+}
+
+/// Formats the first derivative of the mean motion as e.g. ` .00016566`.
+fn format_mean_motion_dot(value: f64) -> Option<String> {
+    let digits = format!("{:.8}", value.abs());
+    let digits = digits.strip_prefix('0')?;
+    let sign = if value < 0.0 { '-' } else { ' ' };
+    Some(format!("{}{}", sign, digits))
+}
+
+/// Formats a value in the compact exponential notation of the TLE format, e.g. `-11606-4` for
+/// -0.11606e-4.
+fn format_implied_decimal(value: f64) -> Option<String> {
+    if value == 0.0 {
+        return Some(" 00000+0".to_string());
+    }
+    let mut exponent = value.abs().log10().floor() as i32 + 1;
+    let mut mantissa = (value.abs() * 10f64.powi(5 - exponent)).round();
+    if mantissa >= 1e5 {
+        mantissa = (mantissa / 10.0).round();
+        exponent += 1;
+    }
+    if !(-9..=9).contains(&exponent) {
+        return None;
+    }
+    let sign = if value < 0.0 { '-' } else { ' ' };
+    Some(format!("{}{:05}{:+}", sign, mantissa as u32, exponent))
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+    use lox_io::ndm::kvn::KvnDeserializer;
+    use lox_time::calendar_dates::CalendarDate;
+    use lox_time::time_of_day::CivilTime;
+    use rstest::rstest;
+
+    use super::*;
+
+    const ISS_LINE1: &str = "1 25544U 98067A   24170.37528350  .00016566  00000+0  30244-3 0  9996";
+    const ISS_LINE2: &str = "2 25544  51.6410 309.3890 0010444 339.5369 107.8830 15.49495945458731";
+
+    #[test]
+    fn test_parse_tle() {
+        let tle = parse(ISS_LINE1, ISS_LINE2).unwrap();
+        assert_eq!(tle.catalog_number(), 25544);
+        assert_eq!(tle.classification(), 'U');
+        assert_eq!(tle.international_designator(), "98067A");
+        assert_float_eq!(tle.mean_motion_dot(), 0.00016566, rel <= 1e-15);
+        assert_eq!(tle.mean_motion_ddot(), 0.0);
+        assert_float_eq!(tle.bstar(), 0.30244e-3, rel <= 1e-15);
+        assert_eq!(tle.element_set_number(), 999);
+        assert_float_eq!(tle.inclination(), 51.6410f64.to_radians(), rel <= 1e-15);
+        assert_float_eq!(
+            tle.longitude_of_ascending_node(),
+            309.3890f64.to_radians(),
+            rel <= 1e-15
+        );
+        assert_float_eq!(tle.eccentricity(), 0.0010444, rel <= 1e-15);
+        assert_float_eq!(
+            tle.argument_of_periapsis(),
+            339.5369f64.to_radians(),
+            rel <= 1e-15
+        );
+        assert_float_eq!(tle.mean_anomaly(), 107.8830f64.to_radians(), rel <= 1e-15);
+        assert_float_eq!(tle.revolutions_per_day(), 15.49495945, rel <= 1e-14);
+        assert_eq!(tle.revolution_number(), 45873);
+
+        let epoch = tle.epoch();
+        assert_eq!(epoch.year(), 2024);
+        assert_eq!(epoch.month(), 6);
+        assert_eq!(epoch.day(), 18);
+        assert_eq!(epoch.hour(), 9);
+        assert_eq!(epoch.minute(), 0);
+        assert_eq!(epoch.second(), 24);
+    }
+
+    #[test]
+    fn test_parse_tle_epoch_rollover() {
+        let tle = parse(
+            "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753",
+            "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667",
+        )
+        .unwrap();
+        assert_eq!(tle.epoch().year(), 2000);
+        assert_float_eq!(tle.bstar(), 0.28098e-4, rel <= 1e-15);
+
+        let tle = parse(
+            "1 00005U 58002B   56179.78495062  .00000023  00000-0 -28098-4 0  4755",
+            "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667",
+        )
+        .unwrap();
+        assert_eq!(tle.epoch().year(), 2056);
+        assert_float_eq!(tle.bstar(), -0.28098e-4, rel <= 1e-15);
+
+        let tle = parse(
+            "1 00005U 58002B   99179.78495062  .00000023  00000-0 -28098-4 0  4752",
+            "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667",
+        )
+        .unwrap();
+        assert_eq!(tle.epoch().year(), 1999);
+    }
+
+    #[test]
+    fn test_parse_oneweb_tles() {
+        let tles = include_str!("../../../data/oneweb_tle.txt");
+        let lines: Vec<&str> = tles.lines().collect();
+        for chunk in lines.chunks_exact(3) {
+            parse(chunk[1], chunk[2]).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_tle_to_omm() {
+        let tle = parse(ISS_LINE1, ISS_LINE2).unwrap();
+        let omm = OmmType::from(&tle);
+        let metadata = &omm.body.segment.metadata;
+        assert_eq!(metadata.object_id, "1998-067A");
+        assert_eq!(metadata.ref_frame, "TEME");
+        assert_eq!(metadata.mean_element_theory, "SGP4");
+        let elements = &omm.body.segment.data.mean_elements;
+        assert_eq!(elements.epoch.0, "2024-06-18T09:00:24.494400");
+        assert_eq!(elements.mean_motion.as_ref().unwrap().base, 15.49495945);
+        assert_eq!(elements.eccentricity.0, 0.0010444);
+        assert_eq!(elements.inclination.base, 51.641);
+        assert_eq!(elements.ra_of_asc_node.base, 309.389);
+        assert_eq!(elements.arg_of_pericenter.base, 339.5369);
+        assert_eq!(elements.mean_anomaly.base, 107.883);
+        let parameters = omm.body.segment.data.tle_parameters.as_ref().unwrap();
+        assert_eq!(parameters.ephemeris_type, Some(0));
+        assert_eq!(parameters.classification_type.as_deref(), Some("U"));
+        assert_eq!(parameters.norad_cat_id, Some(25544));
+        assert_eq!(
+            parameters.element_set_no,
+            Some(ElementSetNoType("999".to_string()))
+        );
+        assert_eq!(parameters.rev_at_epoch, Some(45873));
+        assert_eq!(parameters.bstar.as_ref().unwrap().base, 0.30244e-3);
+        assert_eq!(parameters.mean_motion_dot.base, 0.00016566);
+        assert_eq!(parameters.mean_motion_ddot.as_ref().unwrap().base, 0.0);
+    }
+
+    #[test]
+    fn test_tle_omm_roundtrip() {
+        let tles = include_str!("../../../data/oneweb_tle.txt");
+        let lines: Vec<&str> = tles.lines().collect();
+        let iss = [[ISS_LINE1, ISS_LINE2]];
+        for [line1, line2] in lines
+            .chunks_exact(3)
+            .map(|chunk| [chunk[1], chunk[2]])
+            .chain(iss)
+        {
+            let tle = parse(line1, line2).unwrap();
+            let omm = OmmType::from(&tle);
+            assert_eq!(
+                tle_lines(&omm).unwrap(),
+                (line1.to_string(), line2.to_string())
+            );
+            assert_eq!(Tle::try_from(&omm).unwrap(), tle);
+        }
+    }
+
+    #[test]
+    fn test_omm_to_tle() {
+        let kvn = r#"CCSDS_OMM_VERS = 2.0
+CREATION_DATE = 2007-065T16:00:00
+ORIGINATOR = NOAA/USA
+OBJECT_NAME = GOES 9
+OBJECT_ID = 1995-025A
+CENTER_NAME = EARTH
+REF_FRAME = TEME
+TIME_SYSTEM = UTC
+MEAN_ELEMENT_THEORY = SGP/SGP4
+EPOCH = 2007-064T10:34:41.4264
+MEAN_MOTION = 1.00273272
+ECCENTRICITY = 0.0005013
+INCLINATION = 3.0539
+RA_OF_ASC_NODE = 81.7939
+ARG_OF_PERICENTER = 249.2363
+MEAN_ANOMALY = 150.1602
+GM = 398600.8
+EPHEMERIS_TYPE = 0
+CLASSIFICATION_TYPE = U
+NORAD_CAT_ID = 23581
+ELEMENT_SET_NO = 0925
+REV_AT_EPOCH = 4316
+BSTAR = 0.0001
+MEAN_MOTION_DOT = -0.00000113
+MEAN_MOTION_DDOT = 0.0"#;
+        let omm = OmmType::from_kvn_str(kvn).unwrap();
+        let (line1, line2) = tle_lines(&omm).unwrap();
+        assert_eq!(
+            line1,
+            "1 23581U 95025A   07064.44075725 -.00000113  00000+0  10000-3 0  9259"
+        );
+        assert_eq!(
+            line2,
+            "2 23581   3.0539  81.7939 0005013 249.2363 150.1602  1.00273272 43169"
+        );
+        let tle = Tle::try_from(&omm).unwrap();
+        assert_eq!(tle.international_designator(), "95025A");
+        assert_eq!(tle.element_set_number(), 925);
+    }
+
+    #[test]
+    fn test_omm_to_tle_errors() {
+        let omm = OmmType::from(&parse(ISS_LINE1, ISS_LINE2).unwrap());
+
+        let mut invalid = omm.clone();
+        invalid.body.segment.data.tle_parameters = None;
+        assert_eq!(
+            tle_lines(&invalid),
+            Err(TleError::MissingOmmField("TLE parameters"))
+        );
+
+        let mut invalid = omm.clone();
+        invalid.body.segment.data.mean_elements.mean_motion = None;
+        assert_eq!(
+            tle_lines(&invalid),
+            Err(TleError::MissingOmmField("MEAN_MOTION"))
+        );
+
+        let mut invalid = omm.clone();
+        let parameters = invalid.body.segment.data.tle_parameters.as_mut().unwrap();
+        parameters.norad_cat_id = Some(100000);
+        assert_eq!(
+            tle_lines(&invalid),
+            Err(TleError::UnrepresentableField {
+                field: "NORAD_CAT_ID",
+                value: "100000".to_string()
+            })
+        );
+
+        let mut invalid = omm.clone();
+        invalid.body.segment.data.mean_elements.epoch =
+            EpochType("2060-01-01T00:00:00".to_string());
+        assert_eq!(
+            tle_lines(&invalid),
+            Err(TleError::UnrepresentableField {
+                field: "EPOCH",
+                value: "2060-01-01T00:00:00".to_string()
+            })
+        );
+    }
+
+    #[rstest]
+    #[case(0.0, Some(" 00000+0"))]
+    #[case(0.30244e-3, Some(" 30244-3"))]
+    #[case(-0.11606e-4, Some("-11606-4"))]
+    #[case(0.0001, Some(" 10000-3"))]
+    #[case(0.999995e-5, Some(" 10000-4"))]
+    #[case(1e-12, None)]
+    #[case(1e10, None)]
+    fn test_format_implied_decimal(#[case] value: f64, #[case] expected: Option<&str>) {
+        assert_eq!(format_implied_decimal(value).as_deref(), expected);
+    }
+
+    #[rstest]
+    #[case("2024-06-18T09:00:24.494400", "24170.37528350")]
+    #[case("2024-170T09:00:24.4944Z", "24170.37528350")]
+    #[case("2023-12-31T23:59:59.9999999", "24001.00000000")]
+    #[case("1957-10-04T19:26:24", "57277.81000000")]
+    fn test_tle_epoch(#[case] epoch: &str, #[case] expected: &str) {
+        assert_eq!(tle_epoch(epoch).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_parse_tle_errors() {
+        let line1 = ISS_LINE1.replace("9996", "9995");
+        assert_eq!(
+            parse(&line1, ISS_LINE2),
+            Err(TleError::ChecksumMismatch {
+                line: 1,
+                expected: 5,
+                actual: 6
+            })
+        );
+        assert_eq!(
+            parse(ISS_LINE1, &ISS_LINE2[..60]),
+            Err(TleError::InvalidLineLength {
+                line: 2,
+                length: 60
+            })
+        );
+        assert_eq!(
+            parse(ISS_LINE2, ISS_LINE2),
+            Err(TleError::InvalidLineNumber {
+                line: 1,
+                found: "2".to_string()
+            })
+        );
+        // `51.6410` -> `51.6X10` changes the checksum by -4
+        let line2 = ISS_LINE2
+            .replace(" 51.6410", " 51.6X10")
+            .replace("58731", "58737");
+        assert_eq!(
+            parse(ISS_LINE1, &line2),
+            Err(TleError::MalformedField {
+                line: 2,
+                field: "inclination",
+                value: "51.6X10".to_string()
+            })
+        );
+    }
+}
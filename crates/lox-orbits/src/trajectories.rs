@@ -8,13 +8,15 @@ use lox_time::time_scales::{DynTimeScale, TimeScale};
 use thiserror::Error;
 
 use lox_bodies::{DynOrigin, Origin};
+use lox_io::ndm::common::EpochType;
+use lox_io::ndm::oem::{OemMetadata, OemStateVector};
 use lox_math::roots::Brent;
 use lox_math::series::{Series, SeriesError};
 use lox_time::time_scales::Tai;
 use lox_time::utc::Utc;
 use lox_time::{Time, deltas::TimeDelta};
 
-use crate::events::{Event, Window, find_events, find_windows};
+use crate::events::{CrossingDirection, Event, Window, find_events_in_direction, find_windows};
 use crate::frames::ReferenceFrame;
 use crate::frames::{DynFrame, Icrf};
 use crate::states::State;
@@ -160,8 +162,21 @@ where
     }
 
     pub fn find_events<F: Fn(State<T, O, R>) -> f64>(&self, func: F) -> Vec<Event<T>> {
+        self.find_events_in_direction(func, CrossingDirection::Both)
+    }
+
+    /// Finds the epochs at which `func` crosses zero in the given `direction`, e.g. the apoapsis
+    /// passages where the range rate changes sign from positive to negative.
+    ///
+    /// Sign changes are bracketed between the states of the trajectory and refined with Brent's
+    /// method.
+    pub fn find_events_in_direction<F: Fn(State<T, O, R>) -> f64>(
+        &self,
+        func: F,
+        direction: CrossingDirection,
+    ) -> Vec<Event<T>> {
         let root_finder = Brent::default();
-        find_events(
+        find_events_in_direction(
             |t| {
                 func(State::new(
                     self.start_time() + TimeDelta::try_from_decimal_seconds(t).unwrap(),
@@ -174,6 +189,7 @@ where
             self.start_time(),
             self.t.as_ref(),
             root_finder,
+            direction,
         )
         .unwrap_or_default()
     }
@@ -198,6 +214,49 @@ where
     }
 }
 
+impl<T, O, R> Trajectory<T, O, R>
+where
+    T: TimeScale + Clone,
+    O: Origin + Clone,
+    R: ReferenceFrame + Clone,
+{
+    /// Returns the metadata and the ephemeris data lines of an OEM segment for the trajectory,
+    /// which can be written with [lox_io::ndm::oem::OemKvnWriter].
+    ///
+    /// The epochs are formatted with nanosecond resolution in the time scale of the trajectory.
+    pub fn to_oem_segment(
+        &self,
+        object_name: &str,
+        object_id: &str,
+    ) -> (OemMetadata, Vec<OemStateVector>) {
+        let metadata = OemMetadata {
+            object_name: object_name.to_string(),
+            object_id: object_id.to_string(),
+            center_name: self.origin().name().to_uppercase(),
+            ref_frame: self.reference_frame().abbreviation(),
+            time_system: self.start_time().scale().abbreviation().to_string(),
+            start_time: oem_epoch(&self.start_time()),
+            stop_time: oem_epoch(&self.end_time()),
+            ..Default::default()
+        };
+        let state_vectors = self
+            .states
+            .iter()
+            .map(|s| OemStateVector {
+                epoch: oem_epoch(&s.time()),
+                position: s.position().to_array(),
+                velocity: s.velocity().to_array(),
+                acceleration: None,
+            })
+            .collect();
+        (metadata, state_vectors)
+    }
+}
+
+fn oem_epoch<T: TimeScale>(time: &Time<T>) -> EpochType {
+    EpochType(time.format("%Y-%m-%dT%H:%M:%S.%9f"))
+}
+
 impl<T, O> Trajectory<T, O, Icrf>
 where
     T: TimeScale + Clone,
@@ -368,3 +427,66 @@ pub enum TrajectoryTransformationError {
     #[error("state transformation failed: {0}")]
     StateTransformationError(String),
 }
+
+#[cfg(test)]
+mod tests {
+    use lox_bodies::Earth;
+    use lox_io::ndm::common::OdmHeader;
+    use lox_io::ndm::oem::{OemKvnReader, OemKvnWriter};
+    use lox_time::time;
+    use lox_time::time_scales::Tdb;
+
+    use crate::propagators::Propagator;
+    use crate::propagators::semi_analytical::Vallado;
+
+    use super::*;
+
+    #[test]
+    fn test_trajectory_oem_round_trip() {
+        let s0 = State::new(
+            time!(Tdb, 2023, 3, 25, 21, 8, 0.0).unwrap(),
+            DVec3::new(6068.27927, -1692.84394, -2516.61918),
+            DVec3::new(-0.660415582, 5.495938726, -5.303093233),
+            Earth,
+            Icrf,
+        );
+        let times = (0..=10).map(|i| s0.time() + TimeDelta::from_decimal_seconds(60.5 * i as f64));
+        let trajectory = Vallado::new(s0).propagate_all(times).unwrap();
+
+        let (metadata, state_vectors) = trajectory.to_oem_segment("ISS", "1998-067A");
+        assert_eq!(metadata.center_name, "EARTH");
+        assert_eq!(metadata.ref_frame, "ICRF");
+        assert_eq!(metadata.time_system, "TDB");
+        assert_eq!(metadata.start_time.0, "2023-03-25T21:08:00.000000000");
+        assert_eq!(metadata.stop_time.0, "2023-03-25T21:18:05.000000000");
+
+        let header = OdmHeader {
+            creation_date: EpochType("2023-03-25T21:08:00".to_string()),
+            originator: "LOX".to_string(),
+            ..Default::default()
+        };
+        let mut writer = OemKvnWriter::new(Vec::new(), "3.0", &header).unwrap();
+        writer.write_segment(&metadata, &state_vectors).unwrap();
+        let kvn = writer.into_inner();
+
+        let mut reader = OemKvnReader::new(kvn.as_slice()).unwrap();
+        let states: Vec<State<Tdb, Earth, Icrf>> = reader
+            .by_ref()
+            .map(|state_vector| {
+                let state_vector = state_vector.unwrap();
+                State::new(
+                    Time::from_iso(Tdb, &state_vector.epoch.0).unwrap(),
+                    DVec3::from_array(state_vector.position),
+                    DVec3::from_array(state_vector.velocity),
+                    Earth,
+                    Icrf,
+                )
+            })
+            .collect();
+        assert_eq!(reader.metadata(), Some(&metadata));
+
+        let parsed = Trajectory::new(&states).unwrap();
+        assert_eq!(parsed.states(), trajectory.states());
+        assert_eq!(parsed.to_vec(), trajectory.to_vec());
+    }
+}
@@ -2,7 +2,7 @@ from collections.abc import Callable
 from typing import Literal, Self, overload
 import numpy as np
 
-type Scale = Literal["TAI", "TCB", "TCG", "TDB", "TT", "UT1"]
+type Scale = Literal["GPS", "TAI", "TCB", "TCG", "TDB", "TT", "UT1"]
 type Epoch = Literal["jd", "mjd", "j1950", "j2000"]
 type Unit = Literal["seconds", "days", "centuries"]
 type Vec3 = tuple[float, float, float]
@@ -127,7 +127,11 @@ class Trajectory:
     def reference_frame(self) -> Frame: ...
     def to_numpy(self) -> np.ndarray: ...
     def states(self) -> list[State]: ...
-    def find_events(self, func: Callable[[State], float]) -> list[Event]: ...
+    def find_events(
+        self,
+        func: Callable[[State], float],
+        direction: Literal["up", "down", "both"] = "both",
+    ) -> list[Event]: ...
     def find_windows(self, func: Callable[[State], float]) -> list[Window]: ...
     def interpolate(self, time: Time) -> State: ...
     def to_frame(self, frame: Frame) -> Self: ...
@@ -216,6 +220,8 @@ class Time:
         cls, scale: Scale | TimeScale, jd: float, epoch: str = "jd"
     ) -> Self: ...
     @classmethod
+    def from_modified_julian_date(cls, scale: Scale | TimeScale, mjd: float) -> Self: ...
+    @classmethod
     def from_two_part_julian_date(
         cls, scale: Scale | TimeScale, jd1: float, jd2: float
     ) -> Self: ...
@@ -239,6 +245,7 @@ class Time:
     def subsecond(self) -> float: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
+    def format(self, pattern: str) -> str: ...
     def __add__(self, other: TimeDelta) -> Self: ...
     @overload
     def __sub__(self, other: TimeDelta) -> Self: ...
@@ -247,6 +254,7 @@ class Time:
     def __eq__(self, other: object) -> bool: ...
     def __lt__(self, other: object) -> bool: ...
     def __le__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
     def isclose(
         self, other: Time, rel_tol: float = 1e-8, abs_tol: float = 1e-14
     ) -> bool: ...
@@ -255,6 +263,7 @@ class Time:
         epoch: Epoch = "jd",
         unit: Unit = "days",
     ) -> float: ...
+    def modified_julian_date(self) -> float: ...
     def two_part_julian_date(self) -> tuple[float, float]: ...
     def scale(self) -> TimeScale: ...
     def year(self) -> int: ...
@@ -270,6 +279,7 @@ class Time:
     def picosecond(self) -> int: ...
     def femtosecond(self) -> int: ...
     def decimal_seconds(self) -> float: ...
+    def total_nanoseconds(self) -> int: ...
     def to_scale(
         self, scale: Scale | TimeScale, provider: UT1Provider | None = None
     ) -> Self: ...
@@ -313,12 +323,26 @@ class UTC:
     ): ...
     @classmethod
     def from_iso(cls, iso: str) -> Self: ...
+    @classmethod
+    def from_unix(cls, seconds: float) -> Self: ...
+    def unix_seconds(self) -> float: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
+    def format(self, pattern: str) -> str: ...
     def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: object) -> bool: ...
+    def __le__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __add__(self, other: TimeDelta) -> Self: ...
+    @overload
+    def __sub__(self, other: TimeDelta) -> Self: ...
+    @overload
+    def __sub__(self, other: UTC) -> TimeDelta: ...
     def year(self) -> int: ...
     def month(self) -> int: ...
     def day(self) -> int: ...
+    def weekday(self) -> int: ...
+    def iso_week(self) -> tuple[int, int]: ...
     def hour(self) -> int: ...
     def minute(self) -> int: ...
     def second(self) -> int: ...
@@ -326,13 +350,25 @@ class UTC:
     def microsecond(self) -> int: ...
     def nanosecond(self) -> int: ...
     def picosecond(self) -> int: ...
+    def femtosecond(self) -> int: ...
     def decimal_seconds(self) -> float: ...
     def to_scale(
         self, scale: Scale | TimeScale, provider: UT1Provider | None = None
     ) -> Time: ...
+    @classmethod
+    def to_scale_batch(
+        cls,
+        utcs: list[UTC],
+        scale: Scale | TimeScale,
+        provider: UT1Provider | None = None,
+    ) -> list[Time]: ...
 
 class UT1Provider:
     def __new__(cls, path: str): ...
+    @classmethod
+    def from_cache(
+        cls, max_age_days: float = 7.0, fetch: Callable[[], bytes] | None = None
+    ) -> Self: ...
 
 class Series:
     def __new__(
@@ -26,3 +26,21 @@ def test_visibility_benchmark(estrack, ensemble, oneweb, provider, times, epheme
     assert len(passes) == len(oneweb)
     for sc_passes in passes.values():
         assert len(sc_passes) == len(estrack)
+
+
+@pytest.fixture(scope="session")
+def utcs():
+    t0 = lox.UTC(2020, 1, 1)
+    return [t0 + t for t in lox.TimeDelta.range(0, 86400 * 30, 60)]
+
+
+@pytest.mark.benchmark()
+def test_utc_to_scale_benchmark(utcs, provider):
+    times = [utc.to_scale("UT1", provider) for utc in utcs]
+    assert len(times) == len(utcs)
+
+
+@pytest.mark.benchmark()
+def test_utc_to_scale_batch_benchmark(utcs, provider):
+    times = lox.UTC.to_scale_batch(utcs, "UT1", provider)
+    assert len(times) == len(utcs)
@@ -18,6 +18,7 @@ import pytest
         lox.ElevationMask.fixed(0.0),
         lox.TimeScale("TAI"),
         lox.Time("TAI", 2000, 1, 1),
+        lox.UTC(2000, 1, 1, 12, 13, 14.5),
     ],
 )
 def test_pickle(obj):
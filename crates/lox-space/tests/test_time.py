@@ -8,6 +8,8 @@ def test_time(provider):
     assert tai_exp == tai_act
     tai_act = tai_exp.to_scale("TAI")
     assert tai_exp == tai_act
+    tai_act = tai_exp.to_scale("GPS").to_scale("TAI")
+    assert tai_exp == tai_act
     tai_act = tai_exp.to_scale("TCB").to_scale("TAI")
     assert tai_exp.isclose(tai_act)
     tai_act = tai_exp.to_scale("TCG").to_scale("TAI")
@@ -30,18 +32,47 @@ def test_time(provider):
     assert tai_exp + dt == tai1
     assert tai1 - dt == tai_exp
     assert tai1 - tai_exp == dt
+    assert sorted([tai1, tai_exp]) == [tai_exp, tai1]
+    assert len({tai_exp, lox.Time("TAI", 2000, 1, 1), tai1}) == 2
+    assert tai1.total_nanoseconds() - tai_exp.total_nanoseconds() == 500_000_000
+
+
+def test_julian_dates():
+    j2000 = lox.Time.from_julian_date("TT", 2451545.0)
+    assert j2000 == lox.Time("TT", 2000, 1, 1, 12)
+    assert j2000 == lox.Time.from_modified_julian_date("TT", 51544.5)
+    assert j2000.julian_date() == 2451545.0
+    assert j2000.modified_julian_date() == 51544.5
+    assert j2000.two_part_julian_date() == (2451545.0, 0.0)
+    assert lox.Time.from_two_part_julian_date("TT", 2451545.0, 0.0) == j2000
+    assert j2000.format("%Y-%m-%dT%H:%M:%S %Z") == "2000-01-01T12:00:00 TT"
 
 
 def test_utc(provider):
     utc_exp = lox.UTC(2000, 1, 1)
     utc_act = lox.UTC.from_iso("2000-01-01T00:00:00.000")
     assert utc_exp == utc_act
+    utc_act = lox.UTC.from_iso("2000-01-01T02:00:00.000+02:00")
+    assert utc_exp == utc_act
+    assert lox.UTC.from_unix(946684800.0) == utc_exp
+    utcs = [lox.UTC(2000, 1, 2), lox.UTC(1999, 12, 31), utc_exp]
+    assert sorted(utcs) == [lox.UTC(1999, 12, 31), utc_exp, lox.UTC(2000, 1, 2)]
+    assert len({utc_exp, lox.UTC(2000, 1, 1), lox.UTC(2000, 1, 2)}) == 2
+    assert utc_exp.unix_seconds() == 946684800.0
+    before = lox.UTC(2016, 12, 31, 23, 59, 59.0)
+    after = lox.UTC(2017, 1, 1)
+    assert before + lox.TimeDelta(2.0) == after
+    assert after - lox.TimeDelta(2.0) == before
+    assert after - before == lox.TimeDelta(2.0)
+    assert after.format("%Y-%j %H:%M:%S.%3f %Z") == "2017-001 00:00:00.000 UTC"
     utc_act = lox.UTC.from_iso("2000-01-01T00:00:00.000Z")
     assert utc_exp == utc_act
     utc_act = lox.UTC.from_iso("2000-01-01T00:00:00.000 UTC")
     assert utc_exp == utc_act
     utc_act = utc_exp.to_scale("TAI").to_utc()
     assert utc_exp == utc_act
+    utc_act = utc_exp.to_scale("GPS").to_utc()
+    assert utc_exp == utc_act
     utc_act = utc_exp.to_scale("TCB").to_utc()
     assert utc_exp == utc_act
     utc_act = utc_exp.to_scale("TCG").to_utc()
@@ -52,6 +83,9 @@ def test_utc(provider):
     assert utc_exp == utc_act
     utc_act = utc_exp.to_scale("UT1", provider).to_utc(provider)
     assert utc_exp == utc_act
+    utcs = [before, after, utc_exp]
+    times = lox.UTC.to_scale_batch(utcs, "UT1", provider)
+    assert times == [utc.to_scale("UT1", provider) for utc in utcs]
 
 
 def test_time_delta():
@@ -88,6 +88,15 @@ def test_events(trajectory):
     k2 = trajectory.interpolate(events[1].time()).to_keplerian()
     assert k2.true_anomaly() == pytest.approx(0.0, abs=1e-8)
 
+    apoapsis = trajectory.find_events(apsis_pass, direction="down")
+    assert len(apoapsis) == 1
+    assert apoapsis[0].time() == events[0].time()
+    periapsis = trajectory.find_events(apsis_pass, direction="up")
+    assert len(periapsis) == 1
+    assert periapsis[0].time() == events[1].time()
+    with pytest.raises(ValueError):
+        trajectory.find_events(apsis_pass, direction="sideways")
+
 
 def test_windows(trajectory):
     def above_equator(s):
@@ -0,0 +1,61 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Compares the cost of the [TdbTtModel]s for TDB − TT over two centuries of epochs.
+//!
+//! Run with `cargo bench -p lox-time --bench tdb_tt`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use lox_time::deltas::TimeDelta;
+use lox_time::time_scales::offsets::TdbTtModel;
+
+const EPOCHS: usize = 100_000;
+
+fn bench(name: &str, epochs: &[TimeDelta], model: TdbTtModel) -> Duration {
+    let f = || {
+        epochs
+            .iter()
+            .map(|&tt| model.tdb_minus_tt(tt).to_decimal_seconds())
+            .sum::<f64>()
+    };
+    // Warm-up
+    black_box(f());
+    let start = Instant::now();
+    black_box(f());
+    let elapsed = start.elapsed();
+    println!(
+        "{name:<12} {:>12.3?} total {:>10.1?} per epoch",
+        elapsed,
+        elapsed / EPOCHS as u32
+    );
+    elapsed
+}
+
+fn main() {
+    // Evenly spaced epochs between 1900 and 2100
+    let century = 36525.0 * 86400.0;
+    let epochs: Vec<TimeDelta> = (0..EPOCHS)
+        .map(|i| {
+            TimeDelta::from_decimal_seconds(-century + 2.0 * century * i as f64 / EPOCHS as f64)
+        })
+        .collect();
+
+    let approximate = bench("approximate", &epochs, TdbTtModel::Approximate);
+    for (name, model) in [
+        ("truncated", TdbTtModel::TruncatedFairheadBretagnon),
+        ("de440", TdbTtModel::De440),
+    ] {
+        let elapsed = bench(name, &epochs, model);
+        println!(
+            "slow-down    {:>12.1}x",
+            elapsed.as_secs_f64() / approximate.as_secs_f64()
+        );
+    }
+}
@@ -54,6 +54,35 @@ pub enum Calendar {
     Gregorian,
 }
 
+/// The days of the week.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Thursday,
+    Weekday::Friday,
+    Weekday::Saturday,
+    Weekday::Sunday,
+];
+
+impl Weekday {
+    /// Returns the ISO 8601 number of the day, from 1 for Monday to 7 for Sunday.
+    pub fn iso_number(&self) -> u8 {
+        *self as u8 + 1
+    }
+}
+
 /// A calendar date.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Date {
@@ -246,6 +275,32 @@ impl Date {
     pub fn j2000_day_number(&self) -> i64 {
         j2000_day_number(self.calendar, self.year, self.month, self.day)
     }
+
+    /// Returns the date `months` calendar months after `self`, or before for negative `months`.
+    ///
+    /// If the day of month of `self` does not exist in the resulting month, it is clamped to the
+    /// last day of that month, e.g. January 31 plus one month is February 28 or 29.
+    pub fn add_months(&self, months: i32) -> Self {
+        self.shift_months(months as i64)
+    }
+
+    /// Returns the date `years` calendar years after `self`, or before for negative `years`.
+    ///
+    /// February 29 is clamped to February 28 if the resulting year is not a leap year.
+    pub fn add_years(&self, years: i32) -> Self {
+        self.shift_months(years as i64 * 12)
+    }
+
+    fn shift_months(&self, months: i64) -> Self {
+        let months = self.year * 12 + (self.month - 1) as i64 + months;
+        let year = months.div_euclid(12);
+        let month = (months.rem_euclid(12) + 1) as u8;
+        // Days are also missing within October 1582 due to the Gregorian calendar reform
+        (1..=self.day)
+            .rev()
+            .find_map(|day| Date::new(year, month, day).ok())
+            .unwrap_or_else(|| unreachable!("the first day of the month should be valid"))
+    }
 }
 
 impl JulianDate for Date {
@@ -382,6 +437,32 @@ pub trait CalendarDate {
         let leap = is_leap_year(date.calendar(), date.year());
         find_day_in_year(date.month(), date.day(), leap)
     }
+
+    fn weekday(&self) -> Weekday {
+        // 2000-01-01 was a Saturday
+        let day = self.date().j2000_day_number() + Weekday::Saturday as i64;
+        WEEKDAYS[day.rem_euclid(7) as usize]
+    }
+
+    /// Returns the ISO 8601 week-numbering year and the week number within that year.
+    ///
+    /// ISO weeks start on Monday and the first week of a year is the week containing its first
+    /// Thursday. Hence, days in early January may belong to the last week of the previous year
+    /// and days in late December to the first week of the next year.
+    fn iso_week(&self) -> (i64, u8) {
+        let date = self.date();
+        // The week belongs to the year of its Thursday
+        let thursday = date.j2000_day_number() + 3 - self.weekday() as i64;
+        let thursday = Date::from_days_since_j2000(thursday);
+        let week = (thursday.day_of_year() - 1) / 7 + 1;
+        (thursday.year(), week as u8)
+    }
+}
+
+impl CalendarDate for Date {
+    fn date(&self) -> Date {
+        *self
+    }
 }
 
 #[cfg(test)]
@@ -414,6 +495,64 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[rstest]
+    #[case::leap_year(Date::new(2024, 1, 31).unwrap(), 1, Date::new(2024, 2, 29).unwrap())]
+    #[case::non_leap_year(Date::new(2023, 1, 31).unwrap(), 1, Date::new(2023, 2, 28).unwrap())]
+    #[case::thirty_days(Date::new(2023, 3, 31).unwrap(), 1, Date::new(2023, 4, 30).unwrap())]
+    #[case::no_clamping(Date::new(2023, 1, 15).unwrap(), 2, Date::new(2023, 3, 15).unwrap())]
+    #[case::zero(Date::new(2023, 1, 31).unwrap(), 0, Date::new(2023, 1, 31).unwrap())]
+    #[case::year_end(Date::new(2023, 12, 31).unwrap(), 1, Date::new(2024, 1, 31).unwrap())]
+    #[case::multiple_years(Date::new(2023, 11, 30).unwrap(), 27, Date::new(2026, 2, 28).unwrap())]
+    #[case::negative(Date::new(2024, 3, 31).unwrap(), -1, Date::new(2024, 2, 29).unwrap())]
+    #[case::negative_year(Date::new(2024, 1, 31).unwrap(), -13, Date::new(2022, 12, 31).unwrap())]
+    #[case::before_year_zero(Date::new(1, 2, 1).unwrap(), -14, Date::new(-1, 12, 1).unwrap())]
+    #[case::gregorian_reform(Date::new(1582, 9, 10).unwrap(), 1, Date::new(1582, 10, 4).unwrap())]
+    fn test_date_add_months(#[case] date: Date, #[case] months: i32, #[case] expected: Date) {
+        assert_eq!(date.add_months(months), expected);
+    }
+
+    #[rstest]
+    #[case::leap_day(Date::new(2024, 2, 29).unwrap(), 1, Date::new(2025, 2, 28).unwrap())]
+    #[case::leap_day_to_leap_year(Date::new(2024, 2, 29).unwrap(), 4, Date::new(2028, 2, 29).unwrap())]
+    #[case::century(Date::new(2096, 2, 29).unwrap(), 4, Date::new(2100, 2, 28).unwrap())]
+    #[case::negative(Date::new(2024, 2, 29).unwrap(), -1, Date::new(2023, 2, 28).unwrap())]
+    #[case::no_clamping(Date::new(2024, 7, 14).unwrap(), -10, Date::new(2014, 7, 14).unwrap())]
+    fn test_date_add_years(#[case] date: Date, #[case] years: i32, #[case] expected: Date) {
+        assert_eq!(date.add_years(years), expected);
+    }
+
+    #[rstest]
+    #[case::j2000(Date::new(2000, 1, 1).unwrap(), Weekday::Saturday)]
+    #[case::monday(Date::new(2024, 7, 15).unwrap(), Weekday::Monday)]
+    #[case::sunday(Date::new(2024, 7, 14).unwrap(), Weekday::Sunday)]
+    #[case::leap_day(Date::new(2024, 2, 29).unwrap(), Weekday::Thursday)]
+    #[case::gregorian_reform(Date::new(1582, 10, 15).unwrap(), Weekday::Friday)]
+    #[case::julian(Date::new(1582, 10, 4).unwrap(), Weekday::Thursday)]
+    #[case::before_year_zero(Date::new(-1, 1, 1).unwrap(), Weekday::Wednesday)]
+    fn test_date_weekday(#[case] date: Date, #[case] expected: Weekday) {
+        assert_eq!(date.weekday(), expected);
+    }
+
+    #[test]
+    fn test_weekday_iso_number() {
+        assert_eq!(Weekday::Monday.iso_number(), 1);
+        assert_eq!(Weekday::Sunday.iso_number(), 7);
+    }
+
+    #[rstest]
+    #[case::previous_year(Date::new(2021, 1, 1).unwrap(), (2020, 53))]
+    #[case::previous_year_sunday(Date::new(2021, 1, 3).unwrap(), (2020, 53))]
+    #[case::first_week(Date::new(2021, 1, 4).unwrap(), (2021, 1))]
+    #[case::next_year(Date::new(2024, 12, 30).unwrap(), (2025, 1))]
+    #[case::last_week(Date::new(2024, 12, 29).unwrap(), (2024, 52))]
+    #[case::week_53(Date::new(2026, 12, 31).unwrap(), (2026, 53))]
+    #[case::thursday_first(Date::new(2015, 1, 1).unwrap(), (2015, 1))]
+    #[case::mid_year(Date::new(2024, 7, 15).unwrap(), (2024, 29))]
+    #[case::j2000(Date::new(2000, 1, 1).unwrap(), (1999, 52))]
+    fn test_date_iso_week(#[case] date: Date, #[case] expected: (i64, u8)) {
+        assert_eq!(date.iso_week(), expected);
+    }
+
     #[test]
     fn test_date_from_day_of_year() {
         let date = Date::from_day_of_year(2000, 366).unwrap();
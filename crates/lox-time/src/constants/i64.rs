@@ -10,12 +10,16 @@
 
 pub const SECONDS_PER_MINUTE: i64 = 60;
 
+pub const NANOSECONDS_PER_SECOND: i64 = 1_000_000_000;
+
 pub const SECONDS_PER_HOUR: i64 = 60 * SECONDS_PER_MINUTE;
 
 pub const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
 
 pub const SECONDS_PER_HALF_DAY: i64 = SECONDS_PER_DAY / 2;
 
+pub const SECONDS_PER_WEEK: i64 = 7 * SECONDS_PER_DAY;
+
 pub const SECONDS_PER_JULIAN_YEAR: i64 = 31_557_600;
 
 pub const SECONDS_PER_JULIAN_CENTURY: i64 = SECONDS_PER_JULIAN_YEAR * 100;
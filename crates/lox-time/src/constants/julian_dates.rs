@@ -22,6 +22,12 @@ pub const SECONDS_BETWEEN_J1950_AND_J2000: i64 = 1577880000;
 
 pub const SECONDS_BETWEEN_J1977_AND_J2000: i64 = 725803200;
 
+pub const SECONDS_BETWEEN_GPS_EPOCH_AND_J2000: i64 = 630763200;
+
+/// The number of seconds between the Unix epoch, 1970-01-01T00:00:00 UTC, and J2000 according to
+/// the POSIX convention, i.e. ignoring leap seconds.
+pub const SECONDS_BETWEEN_UNIX_EPOCH_AND_J2000: i64 = 946728000;
+
 /// 4713 BC January 1 12:00
 pub const J0: TimeDelta = TimeDelta {
     seconds: -SECONDS_BETWEEN_JD_AND_J2000,
@@ -0,0 +1,158 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/*!
+    Module `formatting` implements `strftime`-style formatting of dates and times.
+
+    The following specifiers are supported:
+
+    | Specifier | Meaning                                                     | Example  |
+    |-----------|-------------------------------------------------------------|----------|
+    | `%Y`      | Year, zero-padded to four digits                            | `2000`   |
+    | `%m`      | Month, zero-padded                                          | `01`     |
+    | `%d`      | Day of the month, zero-padded                               | `01`     |
+    | `%j`      | Day of the year, zero-padded                                | `001`    |
+    | `%H`      | Hour, zero-padded                                           | `12`     |
+    | `%M`      | Minute, zero-padded                                         | `00`     |
+    | `%S`      | Second, zero-padded                                         | `00`     |
+    | `%f`      | Fraction of the second with `n` digits in `%nf` (default 6) | `000000` |
+    | `%Z`      | Time scale abbreviation                                     | `TAI`    |
+    | `%%`      | A literal `%`                                               | `%`      |
+
+    Unknown specifiers are copied to the output unchanged.
+*/
+
+use std::fmt::Write;
+
+use crate::calendar_dates::CalendarDate;
+use crate::time_of_day::CivilTime;
+
+/// The default number of digits of the fraction of the second formatted by `%f`.
+const DEFAULT_FRACTION_DIGITS: usize = 6;
+
+/// The maximum number of digits of the fraction of the second, i.e. femtosecond resolution.
+const MAX_FRACTION_DIGITS: usize = 15;
+
+/// Formats `time` in the time scale `scale` according to the `strftime`-style `pattern`.
+pub(crate) fn format(time: &(impl CalendarDate + CivilTime), scale: &str, pattern: &str) -> String {
+    let mut out = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        let mut width = String::new();
+        while let Some(&d) = chars.peek().filter(|d| d.is_ascii_digit()) {
+            width.push(d);
+            chars.next();
+        }
+        let Some(specifier) = chars.next() else {
+            out.push('%');
+            out.push_str(&width);
+            break;
+        };
+        // Writing to a `String` cannot fail
+        let _ = match (specifier, width.is_empty()) {
+            ('Y', true) => write!(out, "{:04}", time.year()),
+            ('m', true) => write!(out, "{:02}", time.month()),
+            ('d', true) => write!(out, "{:02}", time.day()),
+            ('j', true) => write!(out, "{:03}", time.day_of_year()),
+            ('H', true) => write!(out, "{:02}", time.hour()),
+            ('M', true) => write!(out, "{:02}", time.minute()),
+            ('S', true) => write!(out, "{:02}", time.second()),
+            ('Z', true) => write!(out, "{}", scale),
+            ('%', true) => write!(out, "%"),
+            ('f', _) => {
+                let digits = width
+                    .parse()
+                    .unwrap_or(DEFAULT_FRACTION_DIGITS)
+                    .min(MAX_FRACTION_DIGITS);
+                write!(out, "{}", &fraction(time)[..digits])
+            }
+            _ => write!(out, "%{}{}", width, specifier),
+        };
+    }
+    out
+}
+
+/// Returns the fraction of the second with femtosecond resolution as a string of digits.
+///
+/// The subsecond is rounded to the nearest femtosecond, but the digits beyond the requested
+/// precision are truncated rather than rounded, such that the formatted time never rolls over
+/// into the next second.
+fn fraction(time: &impl CivilTime) -> String {
+    format!(
+        "{:03}{:03}{:03}{:03}{:03}",
+        time.millisecond(),
+        time.microsecond(),
+        time.nanosecond(),
+        time.picosecond(),
+        time.femtosecond()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::calendar_dates::Date;
+    use crate::subsecond::Subsecond;
+    use crate::time_of_day::TimeOfDay;
+
+    use super::*;
+
+    struct DateTime(Date, TimeOfDay);
+
+    impl CalendarDate for DateTime {
+        fn date(&self) -> Date {
+            self.0
+        }
+    }
+
+    impl CivilTime for DateTime {
+        fn time(&self) -> TimeOfDay {
+            self.1
+        }
+    }
+
+    #[rstest]
+    #[case("%Y-%m-%dT%H:%M:%S", "2024-03-07T08:09:05")]
+    #[case("%Y-%j", "2024-067")]
+    #[case("%H:%M:%S.%f", "08:09:05.123456")]
+    #[case("%S.%3f", "05.123")]
+    #[case("%S.%9f", "05.123456789")]
+    #[case("%S.%15f", "05.123456789012346")]
+    #[case("%S.%20f", "05.123456789012346")]
+    #[case("%S.%0f", "05.")]
+    #[case("%Y %Z", "2024 TAI")]
+    #[case("100%%", "100%")]
+    #[case("%q %3Y", "%q %3Y")]
+    #[case("trailing %", "trailing %")]
+    #[case("trailing %12", "trailing %12")]
+    #[case("", "")]
+    fn test_format(#[case] pattern: &str, #[case] expected: &str) {
+        let date = Date::new(2024, 3, 7).unwrap();
+        let time = TimeOfDay::new(8, 9, 5)
+            .unwrap()
+            .with_subsecond(Subsecond::new(0.1234567890123456).unwrap());
+        assert_eq!(format(&DateTime(date, time), "TAI", pattern), expected);
+    }
+
+    #[test]
+    fn test_format_fraction_truncates() {
+        let date = Date::new(2024, 3, 7).unwrap();
+        let time = TimeOfDay::new(23, 59, 59)
+            .unwrap()
+            .with_subsecond(Subsecond::new(0.9999999).unwrap());
+        assert_eq!(
+            format(&DateTime(date, time), "UTC", "%H:%M:%S.%3f"),
+            "23:59:59.999"
+        );
+    }
+}
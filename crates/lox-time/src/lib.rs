@@ -36,11 +36,12 @@ use std::cmp::Ordering;
 use std::fmt;
 use std::fmt::Display;
 use std::fmt::Formatter;
-use std::ops::Add;
-use std::ops::Sub;
+use std::ops::{Add, AddAssign};
+use std::ops::{Sub, SubAssign};
 use std::str::FromStr;
 
 use itertools::Itertools;
+use lox_io::ndm::common::EpochType;
 use lox_math::constants::f64::time;
 use lox_math::is_close::IsClose;
 use lox_math::types::units::Days;
@@ -50,9 +51,12 @@ use thiserror::Error;
 use crate::calendar_dates::CalendarDate;
 use crate::calendar_dates::Date;
 use crate::calendar_dates::DateError;
+use crate::constants::i64::{NANOSECONDS_PER_SECOND, SECONDS_PER_DAY, SECONDS_PER_WEEK};
 use crate::constants::julian_dates::SECONDS_BETWEEN_J1950_AND_J2000;
 use crate::constants::julian_dates::SECONDS_BETWEEN_JD_AND_J2000;
-use crate::constants::julian_dates::SECONDS_BETWEEN_MJD_AND_J2000;
+use crate::constants::julian_dates::{
+    SECONDS_BETWEEN_GPS_EPOCH_AND_J2000, SECONDS_BETWEEN_MJD_AND_J2000,
+};
 use crate::deltas::TimeDelta;
 use crate::deltas::ToDelta;
 use crate::julian_dates::Epoch;
@@ -64,6 +68,7 @@ use crate::time_of_day::TimeOfDay;
 use crate::time_of_day::TimeOfDayError;
 use crate::time_scales::DynTimeScale;
 use crate::time_scales::FromScale;
+use crate::time_scales::Gps;
 use crate::time_scales::Tai;
 use crate::time_scales::Tcb;
 use crate::time_scales::Tcg;
@@ -74,10 +79,12 @@ use crate::time_scales::TryFromScale;
 use crate::time_scales::TryToScale;
 use crate::time_scales::Tt;
 use crate::time_scales::Ut1;
+use crate::time_scales::offsets::TdbTtModel;
 
 pub mod calendar_dates;
 pub mod constants;
 pub mod deltas;
+mod formatting;
 pub mod julian_dates;
 #[cfg(feature = "python")]
 pub mod python;
@@ -204,6 +211,34 @@ impl<T: TimeScale> Time<T> {
         Self::from_date_and_time(scale, date, time)
     }
 
+    /// Instantiates a [Time] in the given [TimeScale] from the epoch of a CCSDS navigation data
+    /// message, e.g. `2021-06-03T05:33:00.123456789012` or `2021-154T05:33:00.123Z`.
+    ///
+    /// All digits of the fractional second are preserved up to the femtosecond precision of
+    /// [Time].
+    ///
+    /// # Errors
+    ///
+    /// * Returns `TimeError::InvalidIsoString` if `epoch` is not a valid CCSDS epoch.
+    pub fn from_ndm_epoch(scale: T, epoch: &EpochType) -> Result<Self, TimeError> {
+        let value = epoch.0.trim();
+        let Some((date, time)) = value.split_once('T') else {
+            return Err(TimeError::InvalidIsoString(value.to_owned()));
+        };
+        let date = match date.split('-').collect_tuple() {
+            // The day of year is always given with three digits
+            Some((year, day_of_year)) if day_of_year.len() == 3 => {
+                let (Ok(year), Ok(day_of_year)) = (year.parse(), day_of_year.parse()) else {
+                    return Err(TimeError::InvalidIsoString(value.to_owned()));
+                };
+                Date::from_day_of_year(year, day_of_year)?
+            }
+            _ => date.parse()?,
+        };
+        let time: TimeOfDay = time.strip_suffix('Z').unwrap_or(time).parse()?;
+        Self::from_date_and_time(scale, date, time)
+    }
+
     /// Instantiates a [Time] in the given [TimeScale] and a [TimeDelta] relative to J2000.
     pub fn from_delta(scale: T, delta: TimeDelta) -> Self {
         Self {
@@ -255,8 +290,10 @@ impl<T: TimeScale> Time<T> {
                 seconds,
             )));
         }
-        let subsecond = Subsecond::new(seconds.fract()).unwrap();
-        let seconds = seconds.to_i64().unwrap_or_else(|| {
+        // Split off the subsecond towards negative infinity, since it must be positive
+        let whole_seconds = seconds.floor();
+        let subsecond = Subsecond::new(seconds - whole_seconds).unwrap();
+        let seconds = whole_seconds.to_i64().unwrap_or_else(|| {
             unreachable!(
                 "seconds since J2000 for Julian date {} are not representable as i64: {}",
                 julian_date, seconds
@@ -271,6 +308,25 @@ impl<T: TimeScale> Time<T> {
         Ok(Self::new(scale, seconds, subsecond))
     }
 
+    /// Given a Modified Julian Date, instantiates a [Time] in the specified [TimeScale].
+    ///
+    /// # Errors
+    ///
+    /// * Returns `TimeError::JulianDateOutOfRange` if `mjd` is NaN or ±infinity.
+    pub fn from_modified_julian_date(scale: T, mjd: Days) -> Result<Self, TimeError> {
+        Self::from_julian_date(scale, mjd, Epoch::ModifiedJulianDate)
+    }
+
+    /// Given a two-part Julian date, instantiates a [Time] in the specified [TimeScale].
+    ///
+    /// Following the SOFA convention, the Julian date is the sum `jd1 + jd2` and the parts may be
+    /// split arbitrarily. The full precision is retained when `jd1` holds the whole days and `jd2`
+    /// the fraction of the day, as returned by [JulianDate::two_part_julian_date].
+    ///
+    /// # Errors
+    ///
+    /// * Returns `TimeError::JulianDateOutOfRange` if the sum of `jd1` and `jd2` is NaN or
+    ///   ±infinity.
     pub fn from_two_part_julian_date(scale: T, jd1: Days, jd2: Days) -> Result<Self, TimeError> {
         let seconds1 = jd1 * time::SECONDS_PER_DAY;
         let seconds2 = jd2 * time::SECONDS_PER_DAY;
@@ -349,6 +405,19 @@ impl<T: TimeScale> Time<T> {
         Time::from_delta(scale, dt + scale.offset_from(time.scale, dt))
     }
 
+    /// Converts `self` to `scale`, using `provider` for transformations to and from UT1.
+    ///
+    /// All pairs of [Tai], [Tt], [Tdb], [Tcb], [Tcg], [Gps], [Ut1], and [DynTimeScale] are
+    /// supported. Transformations without a direct relation compose through intermediary scales:
+    /// TT for TDB and TCG, TDB for TCB, TAI for GPS and UT1. For example, UT1 → TDB chains
+    /// UT1 → TAI → TT → TDB, evaluating each step at the epoch in the preceding scale.
+    ///
+    /// Use [TryToUtc](crate::utc::transformations::TryToUtc) to convert to UTC.
+    ///
+    /// # Errors
+    ///
+    /// Returns [Ut1Error](crate::time_scales::offsets::Ut1Error) if the transformation involves
+    /// UT1 and `provider` is `None` or fails.
     pub fn try_to_scale<S, P>(
         &self,
         scale: S,
@@ -362,6 +431,9 @@ impl<T: TimeScale> Time<T> {
         Ok(self.with_scale_and_delta(scale, offset))
     }
 
+    /// Converts `self` to `scale`, which must not require a UT1-TAI provider.
+    ///
+    /// See [Time::try_to_scale] for the supported transformations.
     pub fn to_scale<S>(&self, scale: S) -> Time<S>
     where
         S: TimeScale + Copy,
@@ -389,6 +461,29 @@ impl<T: TimeScale> Time<T> {
         Self::from_epoch(scale, Epoch::ModifiedJulianDate)
     }
 
+    /// Instantiates a [Time] in the specified [TimeScale] from a Julian epoch, e.g. 2000.0 for
+    /// J2000.0.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `TimeError::JulianDateOutOfRange` if `julian_epoch` is NaN or ±infinity.
+    pub fn from_julian_epoch(scale: T, julian_epoch: f64) -> Result<Self, TimeError> {
+        let days = (julian_epoch - 2000.0) * time::DAYS_PER_JULIAN_YEAR;
+        Self::from_julian_date(scale, days, Epoch::J2000)
+    }
+
+    /// Instantiates a [Time] in the specified [TimeScale] from a Besselian epoch, e.g. 1950.0 for
+    /// B1950.0.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `TimeError::JulianDateOutOfRange` if `besselian_epoch` is NaN or ±infinity.
+    pub fn from_besselian_epoch(scale: T, besselian_epoch: f64) -> Result<Self, TimeError> {
+        let days = time::JD_B1900 - time::JD_J2000
+            + (besselian_epoch - 1900.0) * time::DAYS_PER_BESSELIAN_YEAR;
+        Self::from_julian_date(scale, days, Epoch::J2000)
+    }
+
     /// Returns the J1950 epoch as a [Time] in the given [TimeScale].
     pub fn j1950(scale: T) -> Self {
         Self::from_epoch(scale, Epoch::J1950)
@@ -408,6 +503,64 @@ impl<T: TimeScale> Time<T> {
     pub fn subsecond(&self) -> f64 {
         self.subsecond.into()
     }
+
+    /// Returns the total number of nanoseconds since J2000 as an integer, with the subsecond
+    /// rounded to the nearest nanosecond.
+    ///
+    /// Unlike the floating-point accessors, the result is exact and is suitable for integer
+    /// arithmetic and hashing.
+    pub fn total_nanoseconds(&self) -> i128 {
+        let nanoseconds = (self.subsecond() * NANOSECONDS_PER_SECOND as f64).round() as i128;
+        self.seconds as i128 * NANOSECONDS_PER_SECOND as i128 + nanoseconds
+    }
+
+    /// Formats `self` according to the `strftime`-style `pattern`.
+    ///
+    /// The supported specifiers are `%Y`, `%m`, `%d`, `%j`, `%H`, `%M`, `%S`, `%Z` for the time
+    /// scale abbreviation, and `%f` for the fraction of the second. `%f` formats six digits by
+    /// default, which can be changed to between 0 and 15 digits by a width, e.g. `%3f`. Unknown
+    /// specifiers are copied to the output unchanged.
+    pub fn format(&self, pattern: &str) -> String {
+        formatting::format(self, self.scale.abbreviation(), pattern)
+    }
+
+    /// Returns the Julian epoch of `self`, e.g. 2000.0 for J2000.0.
+    pub fn julian_epoch(&self) -> f64 {
+        2000.0 + self.days_since_j2000() / time::DAYS_PER_JULIAN_YEAR
+    }
+
+    /// Returns the Besselian epoch of `self`, e.g. 1950.0 for B1950.0.
+    pub fn besselian_epoch(&self) -> f64 {
+        1900.0
+            + (self.days_since_j2000() + time::JD_J2000 - time::JD_B1900)
+                / time::DAYS_PER_BESSELIAN_YEAR
+    }
+}
+
+impl Time<Gps> {
+    /// Instantiates a [Time] in the [Gps] scale from the GPS week number and the seconds elapsed
+    /// since the start of the week.
+    ///
+    /// The week number is counted continuously from the GPS epoch, 1980-01-06T00:00:00 UTC,
+    /// i.e. without the rollover after 1024 weeks used in the GPS navigation message.
+    pub fn from_gps_week(week: i64, seconds_of_week: f64) -> Self {
+        let seconds = week * SECONDS_PER_WEEK - SECONDS_BETWEEN_GPS_EPOCH_AND_J2000;
+        Self::from_delta(
+            Gps,
+            TimeDelta::from_seconds(seconds) + TimeDelta::from_decimal_seconds(seconds_of_week),
+        )
+    }
+
+    /// Returns the number of whole weeks since the GPS epoch, 1980-01-06T00:00:00 UTC.
+    pub fn gps_week(&self) -> i64 {
+        (self.seconds + SECONDS_BETWEEN_GPS_EPOCH_AND_J2000).div_euclid(SECONDS_PER_WEEK)
+    }
+
+    /// Returns the number of seconds elapsed since the start of the current GPS week.
+    pub fn gps_seconds_of_week(&self) -> f64 {
+        (self.seconds + SECONDS_BETWEEN_GPS_EPOCH_AND_J2000).rem_euclid(SECONDS_PER_WEEK) as f64
+            + self.subsecond.0
+    }
 }
 
 impl<T: TimeScale> IsClose for Time<T> {
@@ -431,6 +584,24 @@ impl<T: TimeScale> ToDelta for Time<T> {
     }
 }
 
+impl Time<Tt> {
+    /// Converts `self` to TDB using the given [TdbTtModel].
+    ///
+    /// [Time::to_scale] is equivalent to using [TdbTtModel::Approximate].
+    pub fn to_tdb_with(&self, model: TdbTtModel) -> Time<Tdb> {
+        self.with_scale_and_delta(Tdb, model.tdb_minus_tt(self.to_delta()))
+    }
+}
+
+impl Time<Tdb> {
+    /// Converts `self` to TT using the given [TdbTtModel].
+    ///
+    /// [Time::to_scale] is equivalent to using [TdbTtModel::Approximate].
+    pub fn to_tt_with(&self, model: TdbTtModel) -> Time<Tt> {
+        self.with_scale_and_delta(Tt, model.tt_minus_tdb(self.to_delta()))
+    }
+}
+
 impl<T: TimeScale> JulianDate for Time<T> {
     fn julian_date(&self, epoch: Epoch, unit: Unit) -> f64 {
         let mut decimal_seconds = (match epoch {
@@ -448,6 +619,18 @@ impl<T: TimeScale> JulianDate for Time<T> {
             Unit::Centuries => decimal_seconds / time::SECONDS_PER_JULIAN_CENTURY,
         }
     }
+
+    /// Splits `self` into whole Julian days and the fraction of the day without an intermediate
+    /// `f64` representation of the full date, such that no precision is lost in the integer part.
+    fn two_part_julian_date(&self) -> (f64, f64) {
+        let seconds = self.seconds + SECONDS_BETWEEN_JD_AND_J2000;
+        let days = seconds.div_euclid(SECONDS_PER_DAY);
+        let seconds_of_day = seconds.rem_euclid(SECONDS_PER_DAY);
+        (
+            days as f64,
+            (seconds_of_day as f64 + self.subsecond.0) / time::SECONDS_PER_DAY,
+        )
+    }
 }
 
 impl<T: TimeScale> Display for Time<T> {
@@ -472,6 +655,14 @@ impl FromStr for Time<Tai> {
     }
 }
 
+impl FromStr for Time<Gps> {
+    type Err = TimeError;
+
+    fn from_str(iso: &str) -> Result<Self, Self::Err> {
+        Self::from_iso(Gps, iso)
+    }
+}
+
 impl FromStr for Time<Tcb> {
     type Err = TimeError;
 
@@ -548,6 +739,18 @@ impl<T: TimeScale> Sub<TimeDelta> for Time<T> {
     }
 }
 
+impl<T: TimeScale + Copy> AddAssign<TimeDelta> for Time<T> {
+    fn add_assign(&mut self, rhs: TimeDelta) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: TimeScale + Copy> SubAssign<TimeDelta> for Time<T> {
+    fn sub_assign(&mut self, rhs: TimeDelta) {
+        *self = *self - rhs;
+    }
+}
+
 impl<T: TimeScale> Sub<Time<T>> for Time<T> {
     type Output = TimeDelta;
 
@@ -685,6 +888,8 @@ mod tests {
     use crate::Time;
     use crate::constants::i64::{SECONDS_PER_DAY, SECONDS_PER_HALF_DAY};
     use crate::time_scales::{Tai, Tdb, Tt};
+    use crate::utc;
+    use crate::utc::Utc;
 
     use super::*;
 
@@ -719,6 +924,31 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn test_time_from_ndm_epoch() {
+        let epoch = EpochType("2021-06-03T05:33:00.123456789012".to_string());
+        let time = Time::from_ndm_epoch(Tai, &epoch).unwrap();
+        assert_eq!(time.hour(), 5);
+        assert_eq!(time.minute(), 33);
+        assert_eq!(time.second(), 0);
+        assert_eq!(time.millisecond(), 123);
+        assert_eq!(time.microsecond(), 456);
+        assert_eq!(time.nanosecond(), 789);
+        assert_eq!(time.picosecond(), 12);
+
+        let epoch = EpochType("2021-154T05:33:00.123456789012Z".to_string());
+        assert_eq!(Time::from_ndm_epoch(Tai, &epoch), Ok(time));
+    }
+
+    #[rstest]
+    #[case("2021-06-03")]
+    #[case("2021-06T05:33:00")]
+    #[case("2021-1x4T05:33:00")]
+    fn test_time_from_ndm_epoch_invalid(#[case] epoch: &str) {
+        let epoch = EpochType(epoch.to_string());
+        assert!(Time::from_ndm_epoch(Tai, &epoch).is_err());
+    }
+
     #[rstest]
     #[case(Epoch::JulianDate, -SECONDS_BETWEEN_JD_AND_J2000)]
     #[case(Epoch::ModifiedJulianDate, -SECONDS_BETWEEN_MJD_AND_J2000)]
@@ -729,6 +959,14 @@ mod tests {
         assert_eq!(time.seconds(), seconds);
     }
 
+    #[test]
+    fn test_time_from_julian_date_negative_subsecond() {
+        let time =
+            Time::from_julian_date(Tai, -0.25 / time::SECONDS_PER_DAY, Epoch::J2000).unwrap();
+        assert_eq!(time.seconds(), -1);
+        assert_float_eq!(time.subsecond(), 0.75, abs <= 1e-15);
+    }
+
     #[test]
     fn test_time_from_julian_date_subsecond() {
         let time = Time::from_julian_date(Tai, 0.3 / time::SECONDS_PER_DAY, Epoch::J2000).unwrap();
@@ -743,6 +981,21 @@ mod tests {
         assert_close!(t0, t1);
     }
 
+    #[test]
+    fn test_time_two_part_julian_date_round_trip_precision() {
+        let t0 = Time::new(Tai, 789_000_000, Subsecond::new(0.123456789).unwrap());
+
+        let jd = t0.days_since_julian_epoch();
+        let t1 = Time::from_julian_date(Tai, jd, Epoch::JulianDate).unwrap();
+        let single_error = (t1 - t0).to_decimal_seconds().abs();
+        assert!(single_error > 1e-6);
+
+        let (jd1, jd2) = t0.two_part_julian_date();
+        let t2 = Time::from_two_part_julian_date(Tai, jd1, jd2).unwrap();
+        assert_eq!(t2.seconds(), t0.seconds());
+        assert_float_eq!(t2.subsecond(), t0.subsecond(), abs <= 1e-10);
+    }
+
     #[rstest]
     #[case(i64::MAX as f64, 1.0)]
     #[case(i64::MIN as f64, -1.0)]
@@ -817,6 +1070,28 @@ mod tests {
         assert_eq!(actual, expected)
     }
 
+    #[rstest]
+    #[case("2000-01-01T00:00:00", Ok(time!(Gps, 2000, 1, 1).unwrap()))]
+    #[case("2000-01-01T00:00:00 GPS", Ok(time!(Gps, 2000, 1, 1).unwrap()))]
+    #[case("2000-01-01T00:00:00 TAI", Err(TimeError::InvalidIsoString("2000-01-01T00:00:00 TAI".to_string())))]
+    fn test_time_from_str_gps(#[case] iso: &str, #[case] expected: Result<Time<Gps>, TimeError>) {
+        let actual: Result<Time<Gps>, TimeError> = iso.parse();
+        assert_eq!(actual, expected)
+    }
+
+    #[rstest]
+    #[case::gps_epoch(time!(Gps, 1980, 1, 6).unwrap(), 0, 0.0)]
+    #[case::first_rollover(time!(Gps, 1999, 8, 22).unwrap(), 1024, 0.0)]
+    #[case::j2000(Time::j2000(Gps), 1042, 561600.0)]
+    #[case::week_1930(time!(Gps, 2017, 1, 1, 0, 0, 18.0).unwrap(), 1930, 18.0)]
+    #[case::subsecond(time!(Gps, 2024, 3, 9, 23, 59, 59.5).unwrap(), 2304, 604799.5)]
+    #[case::before_epoch(time!(Gps, 1980, 1, 5, 23, 59, 59.0).unwrap(), -1, 604799.0)]
+    fn test_time_gps_week(#[case] time: Time<Gps>, #[case] week: i64, #[case] seconds: f64) {
+        assert_eq!(time.gps_week(), week);
+        assert_eq!(time.gps_seconds_of_week(), seconds);
+        assert_eq!(Time::from_gps_week(week, seconds), time);
+    }
+
     #[rstest]
     #[case("2000-01-01T00:00:00", Ok(time!(Tcb, 2000, 1, 1).unwrap()))]
     #[case("2000-01-01T00:00:00 TCB", Ok(time!(Tcb, 2000, 1, 1).unwrap()))]
@@ -921,6 +1196,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_time_total_nanoseconds() {
+        let time = Time::new(Tai, -2, Subsecond(0.123456789));
+        assert_eq!(time.total_nanoseconds(), -1_876_543_211);
+
+        // The integer nanoseconds are exact across the leap second at the end of 2016
+        let before = utc!(2016, 12, 31, 23, 59, 59.999999999).unwrap().to_time();
+        let leap = utc!(2016, 12, 31, 23, 59, 60.5).unwrap().to_time();
+        let after = utc!(2017, 1, 1, 0, 0, 0.000000001).unwrap().to_time();
+        assert_eq!(
+            leap.total_nanoseconds() - before.total_nanoseconds(),
+            500_000_001
+        );
+        assert_eq!(
+            after.total_nanoseconds() - before.total_nanoseconds(),
+            1_000_000_002
+        );
+    }
+
     #[test]
     fn test_julian_date() {
         let time = Time::jd0(Tdb);
@@ -977,6 +1271,75 @@ mod tests {
         assert_eq!(jd2, 0.5);
     }
 
+    #[test]
+    fn test_two_part_julian_date_precision() {
+        let time = Time::new(Tai, -SECONDS_BETWEEN_JD_AND_J2000 - 1, Subsecond(0.25));
+        let (jd1, jd2) = time.two_part_julian_date();
+        assert_eq!(jd1, -1.0);
+        assert_eq!(jd2, 86399.25 / time::SECONDS_PER_DAY);
+        let time = Time::new(Tai, 123, Subsecond(0.000001));
+        let (jd1, jd2) = time.two_part_julian_date();
+        assert_eq!(jd1, 2451545.0);
+        assert_eq!(jd2, (123.0 + 0.000001) / time::SECONDS_PER_DAY);
+    }
+
+    #[test]
+    fn test_julian_date_j2000_round_trip() {
+        let j2000 = Time::j2000(Tt);
+        let time = Time::from_julian_date(Tt, 2451545.0, Epoch::JulianDate).unwrap();
+        assert_eq!(time, j2000);
+        assert_eq!(time.days_since_julian_epoch(), 2451545.0);
+        let time = Time::from_modified_julian_date(Tt, 51544.5).unwrap();
+        assert_eq!(time, j2000);
+        assert_eq!(time.days_since_modified_julian_epoch(), 51544.5);
+        let (jd1, jd2) = j2000.two_part_julian_date();
+        assert_eq!((jd1, jd2), (2451545.0, 0.0));
+        let time = Time::from_two_part_julian_date(Tt, jd1, jd2).unwrap();
+        assert_eq!(time, j2000);
+    }
+
+    #[rstest]
+    #[case::j2000(2000.0, 2451545.0)]
+    #[case::j1950(1950.0, 2433282.5)]
+    #[case::j2100(2100.0, 2488070.0)]
+    fn test_julian_epoch(#[case] julian_epoch: f64, #[case] jd: f64) {
+        let time = Time::from_julian_epoch(Tt, julian_epoch).unwrap();
+        assert_eq!(time.days_since_julian_epoch(), jd);
+        assert_eq!(time.julian_epoch(), julian_epoch);
+    }
+
+    #[rstest]
+    #[case::b1900(1900.0, 2415020.31352)]
+    #[case::b1950(1950.0, 2433282.42345905)]
+    fn test_besselian_epoch(#[case] besselian_epoch: f64, #[case] jd: f64) {
+        let time = Time::from_besselian_epoch(Tt, besselian_epoch).unwrap();
+        assert_float_eq!(time.days_since_julian_epoch(), jd, abs <= 1e-9);
+        assert_float_eq!(time.besselian_epoch(), besselian_epoch, abs <= 1e-12);
+    }
+
+    #[test]
+    fn test_besselian_epoch_j2000() {
+        let time = Time::j2000(Tt);
+        assert_float_eq!(time.besselian_epoch(), 2000.0012775136652, abs <= 1e-12);
+        assert_float_eq!(
+            Time::from_julian_date(Tt, 2433282.4235, Epoch::JulianDate)
+                .unwrap()
+                .besselian_epoch(),
+            1950.0,
+            abs <= 1e-6
+        );
+    }
+
+    #[test]
+    fn test_time_format() {
+        let time = time!(Tdb, 2024, 2, 29, 13, 4, 5.25).unwrap();
+        assert_eq!(
+            time.format("%Y-%j %H:%M:%S.%3f %Z"),
+            "2024-060 13:04:05.250 TDB"
+        );
+        assert_eq!(time.format("%d/%m/%Y"), "29/02/2024");
+    }
+
     #[test]
     fn test_time_macro() {
         let time = time!(Tai, 2000, 1, 1).unwrap();
@@ -1184,6 +1547,16 @@ mod tests {
         assert_eq!(actual, time2);
     }
 
+    #[test]
+    fn test_time_add_sub_assign() {
+        let mut time = Time::new(Tai, 1, Subsecond(0.6));
+        time += TimeDelta::from_decimal_seconds(1.7);
+        assert_eq!(time, Time::new(Tai, 3, Subsecond(0.3)));
+        assert_eq!(time.scale(), Tai);
+        time -= TimeDelta::from_decimal_seconds(1.7);
+        assert_close!(time, Time::new(Tai, 1, Subsecond(0.6)));
+    }
+
     #[rstest]
     #[case::at_the_epoch(Time::default(), 0.0)]
     #[case::exactly_one_day_after_the_epoch(
@@ -1296,4 +1669,18 @@ mod tests {
         let expected = TimeDelta::from_seconds(0);
         assert_eq!(actual, expected);
     }
+
+    #[rstest]
+    #[case::approximate(TdbTtModel::Approximate)]
+    #[case::truncated_fairhead_bretagnon(TdbTtModel::TruncatedFairheadBretagnon)]
+    #[case::de440(TdbTtModel::De440)]
+    fn test_time_tdb_tt_model(#[case] model: TdbTtModel) {
+        let tt = time!(Tt, 2024, 6, 1, 12, 0, 0.0).unwrap();
+        let tdb = tt.to_tdb_with(model);
+        assert!((tdb.to_delta() - tt.to_delta()).to_decimal_seconds().abs() < 2e-3);
+        assert_eq!(tdb.to_tt_with(model), tt);
+        if model == TdbTtModel::Approximate {
+            assert_eq!(tdb, tt.to_scale(Tdb));
+        }
+    }
 }
@@ -6,13 +6,14 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::ops::{Add, Sub};
 use std::str::FromStr;
 
 use pyo3::basic::CompareOp;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::types::{PyAnyMethods, PyType};
-use pyo3::{Bound, IntoPyObjectExt, PyAny, PyErr, PyObject, PyResult, Python, pyclass, pymethods};
+use pyo3::{Bound, IntoPyObjectExt, PyAny, PyErr, PyResult, Python, pyclass, pymethods};
 
 use lox_math::is_close::IsClose;
 
@@ -122,6 +123,16 @@ impl PyTime {
         Ok(Self(Time::from_julian_date(scale, jd, epoch)?))
     }
 
+    #[classmethod]
+    pub fn from_modified_julian_date(
+        _cls: &Bound<'_, PyType>,
+        scale: &Bound<'_, PyAny>,
+        mjd: f64,
+    ) -> PyResult<Self> {
+        let scale: DynTimeScale = scale.try_into()?;
+        Ok(Self(Time::from_modified_julian_date(scale, mjd)?))
+    }
+
     #[classmethod]
     pub fn from_two_part_julian_date(
         _cls: &Bound<'_, PyType>,
@@ -186,8 +197,18 @@ impl PyTime {
         self.0.subsecond()
     }
 
-    #[classattr]
-    const __hash__: Option<PyObject> = None;
+    pub fn total_nanoseconds(&self) -> i128 {
+        self.0.total_nanoseconds()
+    }
+
+    /// Only the time scale and the whole seconds are hashed, since subseconds within one
+    /// femtosecond of each other compare equal.
+    pub fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.0.scale().hash(&mut hasher);
+        self.0.seconds().hash(&mut hasher);
+        hasher.finish()
+    }
 
     pub fn __str__(&self) -> String {
         self.0.to_string()
@@ -206,6 +227,10 @@ impl PyTime {
         )
     }
 
+    pub fn format(&self, pattern: &str) -> String {
+        self.0.format(pattern)
+    }
+
     pub fn __add__(&self, delta: PyTimeDelta) -> Self {
         PyTime(self.0 + delta.0)
     }
@@ -252,6 +277,10 @@ impl PyTime {
         Ok(self.0.julian_date(epoch, unit))
     }
 
+    pub fn modified_julian_date(&self) -> f64 {
+        self.0.days_since_modified_julian_epoch()
+    }
+
     pub fn two_part_julian_date(&self) -> (f64, f64) {
         self.0.two_part_julian_date()
     }
@@ -421,6 +450,10 @@ mod tests {
             "Time(\"TAI\", 2000, 1, 1, 0, 0, 12.123456789123)"
         );
         assert_eq!(time.__str__(), "2000-01-01T00:00:12.123 TAI");
+        assert_eq!(
+            time.format("%Y-%j %H:%M:%S.%6f %Z"),
+            "2000-001 00:00:12.123456 TAI"
+        );
         assert_eq!(time.scale().abbreviation(), "TAI".to_string());
         assert_eq!(time.year(), 2000);
         assert_eq!(time.month(), 1);
@@ -432,7 +465,8 @@ mod tests {
         assert_eq!(time.microsecond(), 456);
         assert_eq!(time.nanosecond(), 789);
         assert_eq!(time.picosecond(), 123);
-        assert_eq!(time.femtosecond(), 0);
+        // The nearest double to the decimal seconds exceeds them by 0.73 fs
+        assert_eq!(time.femtosecond(), 1);
         assert_float_eq!(time.decimal_seconds(), 12.123456789123, rel <= 1e-15);
     }
 
@@ -516,6 +550,17 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_pytime_hash() {
+        Python::with_gil(|py| {
+            let t0 = PyTime::new(&scale_to_any(py, "TAI"), 2000, 1, 1, 0, 0, 0.5).unwrap();
+            let t1 = PyTime::new(&scale_to_any(py, "TAI"), 2000, 1, 1, 0, 0, 0.5).unwrap();
+            let t2 = PyTime::new(&scale_to_any(py, "TT"), 2000, 1, 1, 0, 0, 0.5).unwrap();
+            assert_eq!(t0.__hash__(), t1.__hash__());
+            assert_ne!(t0.__hash__(), t2.__hash__());
+        })
+    }
+
     #[test]
     fn test_pytime_is_close() {
         Python::with_gil(|py| {
@@ -624,6 +669,20 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_pytime_modified_julian_date() {
+        Python::with_gil(|py| {
+            let cls = PyType::new::<PyTime>(py);
+            let time =
+                PyTime::from_modified_julian_date(&cls, &scale_to_any(py, "TT"), 51544.5).unwrap();
+            assert_eq!(time.seconds(), 0);
+            assert_eq!(time.subsecond(), 0.0);
+            assert_eq!(time.modified_julian_date(), 51544.5);
+            assert_eq!(time.julian_date("jd", "days").unwrap(), 2451545.0);
+            assert_eq!(time.two_part_julian_date(), (2451545.0, 0.0));
+        })
+    }
+
     #[test]
     #[should_panic(expected = "unknown epoch: unknown")]
     fn test_pytime_invalid_epoch() {
@@ -668,6 +727,11 @@ mod tests {
     }
 
     #[rstest]
+    #[case("GPS", "GPS")]
+    #[case("GPS", "TAI")]
+    #[case("GPS", "TDB")]
+    #[case("GPS", "UT1")]
+    #[case("TAI", "GPS")]
     #[case("TAI", "TAI")]
     #[case("TAI", "TCB")]
     #[case("TAI", "TCG")]
@@ -692,12 +756,14 @@ mod tests {
     #[case("TDB", "TDB")]
     #[case("TDB", "TT")]
     #[case("TDB", "UT1")]
+    #[case("TT", "GPS")]
     #[case("TT", "TAI")]
     #[case("TT", "TCB")]
     #[case("TT", "TCG")]
     #[case("TT", "TDB")]
     #[case("TT", "TT")]
     #[case("TT", "UT1")]
+    #[case("UT1", "GPS")]
     #[case("UT1", "TAI")]
     #[case("UT1", "TCB")]
     #[case("UT1", "TCG")]
@@ -6,11 +6,16 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::io::Cursor;
+use std::time::Duration;
+
 use crate::deltas::TimeDelta;
+use crate::ut1::cache::{FINALS_CSV, cache_dir};
 use crate::ut1::{DeltaUt1Tai, DeltaUt1TaiError, DeltaUt1TaiProvider, ExtrapolatedDeltaUt1Tai};
 use crate::utc::leap_seconds::BuiltinLeapSeconds;
 use pyo3::exceptions::PyValueError;
-use pyo3::{PyErr, PyResult, pyclass, pymethods};
+use pyo3::types::{PyAnyMethods, PyType};
+use pyo3::{Bound, PyAny, PyErr, PyResult, pyclass, pymethods};
 
 impl From<ExtrapolatedDeltaUt1Tai> for PyErr {
     fn from(value: ExtrapolatedDeltaUt1Tai) -> Self {
@@ -35,6 +40,46 @@ impl PyUt1Provider {
         let provider = DeltaUt1Tai::new(path, &BuiltinLeapSeconds)?;
         Ok(PyUt1Provider(provider))
     }
+
+    #[classmethod]
+    #[pyo3(signature = (max_age_days = 7.0, fetch = None))]
+    pub fn from_cache(
+        _cls: &Bound<'_, PyType>,
+        max_age_days: f64,
+        fetch: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyUt1Provider> {
+        let max_age = Duration::try_from_secs_f64(max_age_days * 86400.0)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        let Some(fetch) = fetch else {
+            return Ok(PyUt1Provider(from_cache_without_fetch(max_age)?));
+        };
+        let path = cache_dir()?.join(FINALS_CSV);
+        let provider = DeltaUt1Tai::from_cache_with(path, max_age, &BuiltinLeapSeconds, || {
+            fetch
+                .call0()
+                .and_then(|data| data.extract::<Vec<u8>>())
+                .map(Cursor::new)
+                .map_err(|err| err.to_string())
+        })?;
+        Ok(PyUt1Provider(provider))
+    }
+}
+
+#[cfg(feature = "iers-download")]
+fn from_cache_without_fetch(max_age: Duration) -> Result<DeltaUt1Tai, DeltaUt1TaiError> {
+    DeltaUt1Tai::from_cache(max_age)
+}
+
+/// Uses the cached data if available, since downloading requires the `iers-download` feature.
+#[cfg(not(feature = "iers-download"))]
+fn from_cache_without_fetch(max_age: Duration) -> Result<DeltaUt1Tai, DeltaUt1TaiError> {
+    let path = cache_dir()?.join(FINALS_CSV);
+    DeltaUt1Tai::from_cache_with(path, max_age, &BuiltinLeapSeconds, || {
+        Err::<&[u8], _>(
+            "downloading IERS data requires the `iers-download` feature, pass `fetch` instead"
+                .to_string(),
+        )
+    })
 }
 
 impl DeltaUt1TaiProvider for PyUt1Provider {
@@ -6,15 +6,20 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::hash::{DefaultHasher, Hash, Hasher};
+
 use crate::calendar_dates::CalendarDate;
+use crate::python::deltas::PyTimeDelta;
 use crate::python::time::PyTime;
 use crate::python::ut1::PyUt1Provider;
 use crate::time_of_day::CivilTime;
 use crate::time_scales::DynTimeScale;
+use crate::utc::transformations::ToUtc;
 use crate::utc::{Utc, UtcError};
-use pyo3::exceptions::PyValueError;
-use pyo3::types::PyType;
-use pyo3::{Bound, PyAny, PyErr, PyResult, pyclass, pymethods};
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::types::{PyAnyMethods, PyType};
+use pyo3::{Bound, PyAny, PyErr, PyResult, Python, pyclass, pymethods};
 
 impl From<UtcError> for PyErr {
     fn from(value: UtcError) -> Self {
@@ -50,6 +55,15 @@ impl PyUtc {
         Ok(PyUtc(iso.parse()?))
     }
 
+    #[classmethod]
+    pub fn from_unix(_cls: &Bound<'_, PyType>, seconds: f64) -> PyResult<PyUtc> {
+        Ok(PyUtc(Utc::from_unix_seconds(seconds)?))
+    }
+
+    pub fn unix_seconds(&self) -> f64 {
+        self.0.unix_seconds()
+    }
+
     pub fn __str__(&self) -> String {
         self.0.to_string()
     }
@@ -66,8 +80,59 @@ impl PyUtc {
         )
     }
 
-    pub fn __eq__(&self, other: PyUtc) -> bool {
-        self.0 == other.0
+    pub fn format(&self, pattern: &str) -> String {
+        self.0.format(pattern)
+    }
+
+    fn __getnewargs__(&self) -> (i64, u8, u8, u8, u8, f64) {
+        (
+            self.0.year(),
+            self.0.month(),
+            self.0.day(),
+            self.0.hour(),
+            self.0.minute(),
+            self.0.decimal_seconds(),
+        )
+    }
+
+    fn __richcmp__(&self, other: PyUtc, op: CompareOp) -> bool {
+        op.matches(self.0.cmp(&other.0))
+    }
+
+    /// Only the date and the whole seconds are hashed, since subseconds within one femtosecond of
+    /// each other compare equal.
+    pub fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        (
+            self.0.year(),
+            self.0.month(),
+            self.0.day(),
+            self.0.hour(),
+            self.0.minute(),
+            self.0.second(),
+        )
+            .hash(&mut hasher);
+        hasher.finish()
+    }
+
+    pub fn __add__(&self, delta: PyTimeDelta) -> PyResult<Self> {
+        Ok(PyUtc((self.0.to_time() + delta.0).to_utc()?))
+    }
+
+    pub fn __sub__<'py>(
+        &self,
+        py: Python<'py>,
+        rhs: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if let Ok(delta) = rhs.extract::<PyTimeDelta>() {
+            Ok(Bound::new(py, PyUtc((self.0.to_time() - delta.0).to_utc()?))?.into_any())
+        } else if let Ok(rhs) = rhs.extract::<PyUtc>() {
+            Ok(Bound::new(py, PyTimeDelta(self.0 - rhs.0))?.into_any())
+        } else {
+            Err(PyTypeError::new_err(
+                "`rhs` must be either a `UTC` or a `TimeDelta` object",
+            ))
+        }
     }
 
     pub fn year(&self) -> i64 {
@@ -82,6 +147,16 @@ impl PyUtc {
         self.0.day()
     }
 
+    /// Returns the day of the week as an integer, where Monday is 0 and Sunday is 6.
+    pub fn weekday(&self) -> u8 {
+        self.0.weekday() as u8
+    }
+
+    /// Returns the ISO 8601 week-numbering year and week number.
+    pub fn iso_week(&self) -> (i64, u8) {
+        self.0.iso_week()
+    }
+
     pub fn hour(&self) -> u8 {
         self.0.hour()
     }
@@ -110,6 +185,10 @@ impl PyUtc {
         self.0.picosecond()
     }
 
+    pub fn femtosecond(&self) -> i64 {
+        self.0.femtosecond()
+    }
+
     pub fn decimal_seconds(&self) -> f64 {
         self.0.decimal_seconds()
     }
@@ -129,11 +208,32 @@ impl PyUtc {
                 .map_err(|err| PyValueError::new_err(err.to_string()))?,
         ))
     }
+
+    #[classmethod]
+    #[pyo3(signature = (utcs, scale, provider=None))]
+    pub fn to_scale_batch(
+        _cls: &Bound<'_, PyType>,
+        utcs: Vec<PyUtc>,
+        scale: &Bound<'_, PyAny>,
+        provider: Option<&Bound<'_, PyUt1Provider>>,
+    ) -> PyResult<Vec<PyTime>> {
+        let scale: DynTimeScale = scale.try_into()?;
+        let provider = provider.map(|p| &p.get().0);
+        let utcs: Vec<Utc> = utcs.into_iter().map(|utc| utc.0).collect();
+        Ok(Utc::to_scale_batch(&utcs, scale, provider)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?
+            .into_iter()
+            .map(PyTime)
+            .collect())
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use pyo3::types::PyDict;
     use pyo3::{Bound, IntoPyObjectExt, Python};
+
+    use crate::deltas::TimeDelta;
     use rstest::rstest;
 
     use crate::test_helpers::data_dir;
@@ -146,6 +246,8 @@ mod tests {
         assert_eq!(utc.year(), 2000);
         assert_eq!(utc.month(), 1);
         assert_eq!(utc.day(), 1);
+        assert_eq!(utc.weekday(), 5);
+        assert_eq!(utc.iso_week(), (1999, 52));
         assert_eq!(utc.hour(), 12);
         assert_eq!(utc.minute(), 13);
         assert_eq!(utc.second(), 14);
@@ -153,10 +255,79 @@ mod tests {
         assert_eq!(utc.microsecond(), 456);
         assert_eq!(utc.nanosecond(), 789);
         assert_eq!(utc.picosecond(), 123);
+        // The nearest double to the decimal seconds exceeds them by 0.73 fs
+        assert_eq!(utc.femtosecond(), 1);
         assert_eq!(utc.decimal_seconds(), 14.123456789123);
         assert_eq!(utc.__str__(), "2000-01-01T12:13:14.123 UTC");
         assert_eq!(utc.__repr__(), "UTC(2000, 1, 1, 12, 13, 14.123456789123)");
-        assert!(utc.__eq__(utc.clone()));
+        assert!(utc.__richcmp__(utc.clone(), CompareOp::Eq));
+        assert_eq!(
+            utc.format("%Y-%j %H:%M:%S.%9f"),
+            "2000-001 12:13:14.123456789"
+        );
+    }
+
+    #[test]
+    fn test_pyutc_richcmp() {
+        let utc0 = PyUtc::new(2000, 1, 1, 0, 0, 0.0).unwrap();
+        let utc1 = PyUtc::new(2000, 1, 1, 0, 0, 1.0).unwrap();
+        assert!(utc0.__richcmp__(utc1.clone(), CompareOp::Lt));
+        assert!(utc0.__richcmp__(utc1.clone(), CompareOp::Le));
+        assert!(utc0.__richcmp__(utc1.clone(), CompareOp::Ne));
+        assert!(utc1.__richcmp__(utc0.clone(), CompareOp::Gt));
+        assert!(utc1.__richcmp__(utc0.clone(), CompareOp::Ge));
+        assert!(!utc0.__richcmp__(utc1.clone(), CompareOp::Eq));
+    }
+
+    #[test]
+    fn test_pyutc_hash() {
+        let utc0 = PyUtc::new(2000, 1, 1, 0, 0, 0.5).unwrap();
+        let utc1 = PyUtc::new(2000, 1, 1, 0, 0, 0.5).unwrap();
+        let utc2 = PyUtc::new(2000, 1, 1, 0, 0, 1.5).unwrap();
+        assert_eq!(utc0.__hash__(), utc1.__hash__());
+        assert_ne!(utc0.__hash__(), utc2.__hash__());
+    }
+
+    #[test]
+    fn test_pyutc_getnewargs() {
+        let utc = PyUtc::new(2016, 12, 31, 23, 59, 60.25).unwrap();
+        let (year, month, day, hour, minute, seconds) = utc.__getnewargs__();
+        assert_eq!(
+            PyUtc::new(year, month, day, hour, minute, seconds).unwrap(),
+            utc
+        );
+    }
+
+    #[test]
+    fn test_pyutc_ops() {
+        Python::with_gil(|py| {
+            let utc0 = PyUtc::new(2016, 12, 31, 23, 59, 59.0).unwrap();
+            let utc1 = PyUtc::new(2017, 1, 1, 0, 0, 0.0).unwrap();
+            let delta = PyTimeDelta::new(2.0).unwrap();
+            assert_eq!(utc0.__add__(delta.clone()).unwrap(), utc1);
+            let actual: PyUtc = utc1
+                .__sub__(py, &delta.into_bound_py_any(py).unwrap())
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(actual, utc0);
+            let actual: PyTimeDelta = utc1
+                .__sub__(py, &utc0.into_bound_py_any(py).unwrap())
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(actual.0, TimeDelta::from_seconds(2));
+        })
+    }
+
+    #[test]
+    #[should_panic(expected = "`rhs` must be either a `UTC` or a `TimeDelta` object")]
+    fn test_pyutc_ops_invalid_rhs() {
+        Python::with_gil(|py| {
+            let utc = PyUtc::new(2000, 1, 1, 0, 0, 0.0).unwrap();
+            let invalid = PyDict::new(py);
+            utc.__sub__(py, &invalid).unwrap();
+        })
     }
 
     #[test]
@@ -176,6 +347,32 @@ mod tests {
             assert_eq!(actual, expected);
             let actual = PyUtc::from_iso(&cls, "2000-01-01T00:00:00").unwrap();
             assert_eq!(actual, expected);
+            let actual = PyUtc::from_iso(&cls, "2000-01-01T02:00:00+02:00").unwrap();
+            assert_eq!(actual, expected);
+            let actual = PyUtc::from_iso(&cls, "1999-12-31T18:30:00-05:30").unwrap();
+            assert_eq!(actual, expected);
+        })
+    }
+
+    #[test]
+    fn test_pyutc_unix() {
+        Python::with_gil(|py| {
+            let cls = PyType::new::<PyUtc>(py);
+            let expected = PyUtc::new(1970, 1, 1, 0, 0, 0.0).unwrap();
+            let actual = PyUtc::from_unix(&cls, 0.0).unwrap();
+            assert_eq!(actual, expected);
+            assert_eq!(actual.unix_seconds(), 0.0);
+            let actual = PyUtc::from_unix(&cls, 1622698380.25).unwrap();
+            assert_eq!(actual, PyUtc::new(2021, 6, 3, 5, 33, 0.25).unwrap());
+        })
+    }
+
+    #[test]
+    #[should_panic(expected = "not representable as UTC")]
+    fn test_pyutc_unix_invalid() {
+        Python::with_gil(|py| {
+            let cls = PyType::new::<PyUtc>(py);
+            PyUtc::from_unix(&cls, f64::INFINITY).unwrap();
         })
     }
 
@@ -189,6 +386,7 @@ mod tests {
     }
 
     #[rstest]
+    #[case("GPS")]
     #[case("TAI")]
     #[case("TCB")]
     #[case("TCG")]
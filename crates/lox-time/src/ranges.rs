@@ -8,7 +8,10 @@
 
 use std::ops::RangeInclusive;
 
+use crate::Time;
 use crate::deltas::TimeDelta;
+use crate::subsecond::Subsecond;
+use crate::time_scales::TimeScale;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TimeDeltaRange {
@@ -86,8 +89,108 @@ impl Iterator for TimeDeltaRange {
     }
 }
 
+/// A range of [Time]s from `start` to `stop` with a fixed `step`, which may be negative.
+///
+/// Iterating over a [TimeRange] yields `start + n * step` for `n = 0, 1, 2, ...` as long as the
+/// result lies before `stop` in the direction of `step`, followed by `stop` itself. Each epoch is
+/// computed from `start` directly, so no rounding errors accumulate over many steps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeRange<T: TimeScale> {
+    start: Time<T>,
+    stop: Time<T>,
+    step: TimeDelta,
+}
+
+impl<T: TimeScale + Copy> TimeRange<T> {
+    /// Creates a new [TimeRange] from `start` to `stop` inclusive with the given `step`.
+    ///
+    /// If `stop` lies before `start` in the direction of `step`, the range yields `start` only.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    pub fn new(start: Time<T>, stop: Time<T>, step: TimeDelta) -> Self {
+        assert!(!step.is_zero(), "step must not be zero");
+        Self { start, stop, step }
+    }
+
+    pub fn start(&self) -> Time<T> {
+        self.start
+    }
+
+    pub fn stop(&self) -> Time<T> {
+        self.stop
+    }
+
+    pub fn step(&self) -> TimeDelta {
+        self.step
+    }
+}
+
+impl<T: TimeScale + Copy> IntoIterator for TimeRange<T> {
+    type Item = Time<T>;
+    type IntoIter = TimeRangeIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        TimeRangeIter {
+            range: self,
+            n: 0,
+            done: false,
+        }
+    }
+}
+
+/// Iterator over the epochs of a [TimeRange].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeRangeIter<T: TimeScale> {
+    range: TimeRange<T>,
+    n: i64,
+    done: bool,
+}
+
+impl<T: TimeScale + Copy> Iterator for TimeRangeIter<T> {
+    type Item = Time<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let TimeRange { start, stop, step } = self.range;
+        let time = start + multiply(step, self.n);
+        let remaining = if step.is_positive() {
+            stop - time
+        } else {
+            time - stop
+        };
+        if self.n == 0 || remaining.is_positive() {
+            self.done = remaining.is_negative() || remaining.is_zero();
+            self.n += 1;
+            Some(time)
+        } else {
+            self.done = true;
+            Some(stop)
+        }
+    }
+}
+
+/// Returns `delta * n` without the rounding errors of repeated addition.
+fn multiply(delta: TimeDelta, n: i64) -> TimeDelta {
+    let subsecond = delta.subsecond.0 * n as f64;
+    let carry = subsecond.floor();
+    TimeDelta::new(
+        delta.seconds * n + carry as i64,
+        Subsecond(subsecond - carry),
+    )
+}
+
 #[cfg(test)]
 mod tests {
+    use lox_math::assert_close;
+    use lox_math::is_close::IsClose;
+
+    use crate::time;
+    use crate::time_scales::Tai;
+
     use super::*;
 
     #[test]
@@ -99,4 +202,77 @@ mod tests {
         assert_eq!(values[1], TimeDelta::from_seconds(1));
         assert_eq!(values[10], TimeDelta::from_seconds(10));
     }
+
+    #[test]
+    fn test_time_range() {
+        let start = time!(Tai, 2000, 1, 1).unwrap();
+        let stop = time!(Tai, 2000, 1, 1, 0, 1, 0.0).unwrap();
+        let times: Vec<Time<Tai>> = TimeRange::new(start, stop, TimeDelta::from_seconds(10))
+            .into_iter()
+            .collect();
+        assert_eq!(times.len(), 7);
+        assert_eq!(times[0], start);
+        assert_eq!(times[1], start + TimeDelta::from_seconds(10));
+        assert_eq!(times[6], stop);
+    }
+
+    #[test]
+    fn test_time_range_partial_step() {
+        let start = time!(Tai, 2000, 1, 1).unwrap();
+        let stop = start + TimeDelta::from_seconds(25);
+        let times: Vec<Time<Tai>> = TimeRange::new(start, stop, TimeDelta::from_seconds(10))
+            .into_iter()
+            .collect();
+        let expected: Vec<Time<Tai>> = [0, 10, 20, 25]
+            .into_iter()
+            .map(|s| start + TimeDelta::from_seconds(s))
+            .collect();
+        assert_eq!(times, expected);
+    }
+
+    #[test]
+    fn test_time_range_negative_step() {
+        let start = time!(Tai, 2000, 1, 1).unwrap();
+        let stop = start - TimeDelta::from_seconds(25);
+        let times: Vec<Time<Tai>> = TimeRange::new(start, stop, TimeDelta::from_seconds(-10))
+            .into_iter()
+            .collect();
+        let expected: Vec<Time<Tai>> = [0, -10, -20, -25]
+            .into_iter()
+            .map(|s| start + TimeDelta::from_seconds(s))
+            .collect();
+        assert_eq!(times, expected);
+    }
+
+    #[test]
+    fn test_time_range_wrong_direction() {
+        let start = time!(Tai, 2000, 1, 1).unwrap();
+        let stop = start - TimeDelta::from_seconds(25);
+        let times: Vec<Time<Tai>> = TimeRange::new(start, stop, TimeDelta::from_seconds(10))
+            .into_iter()
+            .collect();
+        assert_eq!(times, vec![start]);
+    }
+
+    #[test]
+    fn test_time_range_no_drift() {
+        let start = time!(Tai, 2000, 1, 1).unwrap();
+        let step = TimeDelta::from_decimal_seconds(0.1);
+        let stop = start + TimeDelta::from_seconds(10000);
+        let range = TimeRange::new(start, stop, step);
+        let times: Vec<Time<Tai>> = range.into_iter().collect();
+        assert_eq!(times.len(), 100001);
+        for (n, time) in times.iter().enumerate().step_by(997) {
+            let expected = start + TimeDelta::from_decimal_seconds(n as f64 / 10.0);
+            assert_close!(*time, expected, 1e-9, 0.0);
+        }
+        assert_eq!(*times.last().unwrap(), stop);
+    }
+
+    #[test]
+    #[should_panic(expected = "step must not be zero")]
+    fn test_time_range_zero_step() {
+        let start = time!(Tai, 2000, 1, 1).unwrap();
+        TimeRange::new(start, start, TimeDelta::default());
+    }
 }
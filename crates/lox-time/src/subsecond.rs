@@ -16,6 +16,8 @@ use num::ToPrimitive;
 
 use thiserror::Error;
 
+const FEMTOSECONDS_PER_SECOND: f64 = 1e15;
+
 /// Error type returned when attempting to construct a [Subsecond] from an invalid `f64`.
 #[derive(Debug, Copy, Clone, Error)]
 #[error("subsecond must be in the range [0.0, 1.0), but was `{0}`")]
@@ -83,27 +85,41 @@ impl Subsecond {
 
     /// The number of milliseconds in the subsecond.
     pub fn millisecond(&self) -> i64 {
-        (self.0 * 1e3).trunc().to_i64().unwrap()
+        self.femtoseconds() / 1_000_000_000_000
     }
 
     /// The number of microseconds since the last millisecond.
     pub fn microsecond(&self) -> i64 {
-        (self.0 * 1e6).trunc().to_i64().unwrap() % 1_000
+        self.femtoseconds() / 1_000_000_000 % 1_000
     }
 
     /// The number of nanoseconds since the last microsecond.
     pub fn nanosecond(&self) -> i64 {
-        (self.0 * 1e9).trunc().to_i64().unwrap() % 1_000
+        self.femtoseconds() / 1_000_000 % 1_000
     }
 
     /// The number of picoseconds since the last nanosecond.
     pub fn picosecond(&self) -> i64 {
-        (self.0 * 1e12).trunc().to_i64().unwrap() % 1_000
+        self.femtoseconds() / 1_000 % 1_000
     }
 
     /// The number of femtoseconds since the last picosecond.
     pub fn femtosecond(&self) -> i64 {
-        (self.0 * 1e15).trunc().to_i64().unwrap() % 1_000
+        self.femtoseconds() % 1_000
+    }
+
+    /// The subsecond rounded to the nearest integer number of femtoseconds.
+    ///
+    /// All components are derived from this value, such that they are consistent with each other
+    /// even if scaling the subsecond by different powers of ten rounds in different directions.
+    /// Rounding rather than truncating ensures that decimal subseconds, which are not exactly
+    /// representable as binary floating-point numbers, yield their decimal digits.
+    fn femtoseconds(&self) -> i64 {
+        (self.0 * 1e15)
+            .round()
+            .min(FEMTOSECONDS_PER_SECOND - 1.0)
+            .to_i64()
+            .unwrap()
     }
 }
 
@@ -167,6 +183,24 @@ mod tests {
         assert_eq!(543, subsecond.femtosecond());
     }
 
+    #[rstest]
+    // 0.00013 * 1e15 is slightly below 1.3e11 and rounded to the nearest femtosecond
+    #[case::inexact(0.000_13, [0, 130, 0, 0, 0])]
+    #[case::exact(0.5, [500, 0, 0, 0, 0])]
+    #[case::all_components(0.123456789876543, [123, 456, 789, 876, 543])]
+    #[case::largest(0.999_999_999_999_999_9, [999, 999, 999, 999, 999])]
+    fn test_subsecond_components(#[case] raw: f64, #[case] expected: [i64; 5]) {
+        let subsecond = Subsecond(raw);
+        let actual = [
+            subsecond.millisecond(),
+            subsecond.microsecond(),
+            subsecond.nanosecond(),
+            subsecond.picosecond(),
+            subsecond.femtosecond(),
+        ];
+        assert_eq!(actual, expected);
+    }
+
     #[rstest]
     #[case::exactly_equal(Subsecond(0.0), Subsecond(0.0), true)]
     #[case::one_femtosecond_difference(Subsecond(0.0), Subsecond(1e-15), false)]
@@ -273,6 +273,12 @@ impl TimeOfDay {
     }
 }
 
+impl CivilTime for TimeOfDay {
+    fn time(&self) -> TimeOfDay {
+        *self
+    }
+}
+
 impl Display for TimeOfDay {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let precision = f.precision().unwrap_or(3);
@@ -297,10 +303,43 @@ impl FromStr for TimeOfDay {
 
 #[cfg(test)]
 mod tests {
+    use float_eq::assert_float_eq;
+    use proptest::prelude::*;
     use rstest::rstest;
 
     use super::*;
 
+    #[test]
+    fn test_time_of_day_components() {
+        let time = TimeOfDay::from_hms(12, 13, 14.123456789123).unwrap();
+        assert_eq!(time.millisecond(), 123);
+        assert_eq!(time.microsecond(), 456);
+        assert_eq!(time.nanosecond(), 789);
+        assert_eq!(time.picosecond(), 123);
+        assert_float_eq!(time.decimal_seconds(), 14.123456789123, abs <= 1e-15);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_time_of_day_components_roundtrip(seconds in 0.0..61.0) {
+            let time = TimeOfDay::from_hms(23, 59, seconds).unwrap();
+            let components = [
+                time.millisecond(),
+                time.microsecond(),
+                time.nanosecond(),
+                time.picosecond(),
+                time.femtosecond(),
+            ];
+            prop_assert!(components.iter().all(|c| (0..1000).contains(c)));
+            let subsecond = components
+                .iter()
+                .fold(0, |femtoseconds, c| femtoseconds * 1000 + c) as f64
+                * 1e-15;
+            let reassembled = time.second() as f64 + subsecond;
+            assert_float_eq!(reassembled, time.decimal_seconds(), abs <= 1e-14);
+        }
+    }
+
     #[rstest]
     #[case(43201, TimeOfDay::new(12, 0, 1))]
     #[case(86399, TimeOfDay::new(23, 59, 59))]
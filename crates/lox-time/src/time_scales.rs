@@ -19,6 +19,7 @@
 use std::fmt::Display;
 use std::str::FromStr;
 
+use lox_io::ndm::common::TimeSystem;
 use thiserror::Error;
 
 use crate::deltas::TimeDelta;
@@ -88,6 +89,28 @@ where
     }
 }
 
+/// Global Positioning System Time.
+///
+/// GPS time is offset from TAI by a constant 19 seconds and its epoch is
+/// 1980-01-06T00:00:00 UTC.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Gps;
+
+impl TimeScale for Gps {
+    fn abbreviation(&self) -> &'static str {
+        "GPS"
+    }
+    fn name(&self) -> &'static str {
+        "Global Positioning System Time"
+    }
+}
+
+impl Display for Gps {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
 /// International Atomic Time.
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Tai;
@@ -202,10 +225,11 @@ impl Display for Ut1 {
     }
 }
 
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub enum DynTimeScale {
     #[default]
     Tai,
+    Gps,
     Tcb,
     Tcg,
     Tdb,
@@ -217,6 +241,7 @@ impl TimeScale for DynTimeScale {
     fn abbreviation(&self) -> &'static str {
         match self {
             DynTimeScale::Tai => Tai.abbreviation(),
+            DynTimeScale::Gps => Gps.abbreviation(),
             DynTimeScale::Tcb => Tcb.abbreviation(),
             DynTimeScale::Tcg => Tcg.abbreviation(),
             DynTimeScale::Tdb => Tdb.abbreviation(),
@@ -228,6 +253,7 @@ impl TimeScale for DynTimeScale {
     fn name(&self) -> &'static str {
         match self {
             DynTimeScale::Tai => Tai.name(),
+            DynTimeScale::Gps => Gps.name(),
             DynTimeScale::Tcb => Tcb.name(),
             DynTimeScale::Tcg => Tcg.name(),
             DynTimeScale::Tdb => Tdb.name(),
@@ -247,6 +273,7 @@ impl FromStr for DynTimeScale {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "tai" | "TAI" => Ok(DynTimeScale::Tai),
+            "gps" | "GPS" => Ok(DynTimeScale::Gps),
             "tcb" | "TCB" => Ok(DynTimeScale::Tcb),
             "tcg" | "TCG" => Ok(DynTimeScale::Tcg),
             "tdb" | "TDB" => Ok(DynTimeScale::Tdb),
@@ -257,6 +284,26 @@ impl FromStr for DynTimeScale {
     }
 }
 
+impl TryFrom<TimeSystem> for DynTimeScale {
+    type Error = UnknownTimeScaleError;
+
+    /// Maps the `TIME_SYSTEM` of a CCSDS navigation data message onto a
+    /// continuous time scale. UTC and the mission-specific time systems are
+    /// not continuous time scales and are rejected.
+    fn try_from(time_system: TimeSystem) -> Result<Self, Self::Error> {
+        match time_system {
+            TimeSystem::Tai => Ok(DynTimeScale::Tai),
+            TimeSystem::Gps => Ok(DynTimeScale::Gps),
+            TimeSystem::Tcb => Ok(DynTimeScale::Tcb),
+            TimeSystem::Tcg => Ok(DynTimeScale::Tcg),
+            TimeSystem::Tdb => Ok(DynTimeScale::Tdb),
+            TimeSystem::Tt => Ok(DynTimeScale::Tt),
+            TimeSystem::Ut1 => Ok(DynTimeScale::Ut1),
+            _ => Err(UnknownTimeScaleError(time_system.abbreviation().to_owned())),
+        }
+    }
+}
+
 impl Display for DynTimeScale {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.abbreviation())
@@ -270,6 +317,7 @@ mod tests {
 
     #[rstest]
     #[case(Tai, "TAI", "International Atomic Time")]
+    #[case(Gps, "GPS", "Global Positioning System Time")]
     #[case(Tcb, "TCB", "Barycentric Coordinate Time")]
     #[case(Tcg, "TCG", "Geocentric Coordinate Time")]
     #[case(Tdb, "TDB", "Barycentric Dynamical Time")]
@@ -287,6 +335,7 @@ mod tests {
 
     #[rstest]
     #[case("TAI", "International Atomic Time")]
+    #[case("GPS", "Global Positioning System Time")]
     #[case("TCB", "Barycentric Coordinate Time")]
     #[case("TCG", "Geocentric Coordinate Time")]
     #[case("TDB", "Barycentric Dynamical Time")]
@@ -299,6 +348,19 @@ mod tests {
         assert_eq!(scale.name(), name);
     }
 
+    #[rstest]
+    #[case(TimeSystem::Tai, Ok(DynTimeScale::Tai))]
+    #[case(TimeSystem::Gps, Ok(DynTimeScale::Gps))]
+    #[case(TimeSystem::Tdb, Ok(DynTimeScale::Tdb))]
+    #[case(TimeSystem::Ut1, Ok(DynTimeScale::Ut1))]
+    #[case(TimeSystem::Utc, Err(UnknownTimeScaleError("UTC".to_owned())))]
+    fn test_dyn_time_scale_from_time_system(
+        #[case] time_system: TimeSystem,
+        #[case] expected: Result<DynTimeScale, UnknownTimeScaleError>,
+    ) {
+        assert_eq!(DynTimeScale::try_from(time_system), expected);
+    }
+
     #[test]
     fn test_dyn_time_scale_invalid() {
         let scale: Result<DynTimeScale, UnknownTimeScaleError> = "NTS".parse();
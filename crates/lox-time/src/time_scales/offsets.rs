@@ -1,10 +1,15 @@
-use super::{DynTimeScale, FromScale, Tai, Tcb, Tcg, Tdb, TimeScale, ToScale, TryToScale, Tt, Ut1};
+use super::{
+    DynTimeScale, FromScale, Gps, Tai, Tcb, Tcg, Tdb, TimeScale, ToScale, TryToScale, Tt, Ut1,
+};
 use crate::{
     constants::julian_dates::J77, deltas::TimeDelta, subsecond::Subsecond, ut1::DeltaUt1TaiProvider,
 };
+use generated::{DE440_POLYNOMIAL, DE440_T0, DE440_T1, DE440_T2};
 use std::convert::Infallible;
 use thiserror::Error;
 
+mod generated;
+
 macro_rules! impl_fallible {
     ($in:ident, $out:ident) => {
         impl<P> TryToScale<$out, P> for $in {
@@ -45,6 +50,7 @@ macro_rules! impl_noops {
     };
 }
 
+impl_noops!(Gps);
 impl_noops!(Tai);
 impl_noops!(Tcb);
 impl_noops!(Tcg);
@@ -76,6 +82,30 @@ impl ToScale<Tai> for Tt {
 
 impl_fallible!(Tt, Tai);
 
+// TAI <-> GPS
+
+/// The constant offset between TAI and GPS time.
+pub const D_TAI_GPS: TimeDelta = TimeDelta {
+    seconds: -19,
+    subsecond: Subsecond(0.0),
+};
+
+impl ToScale<Gps> for Tai {
+    fn offset(&self, _scale: Gps, _dt: TimeDelta) -> TimeDelta {
+        D_TAI_GPS
+    }
+}
+
+impl_fallible!(Tai, Gps);
+
+impl ToScale<Tai> for Gps {
+    fn offset(&self, _scale: Tai, _dt: TimeDelta) -> TimeDelta {
+        -D_TAI_GPS
+    }
+}
+
+impl_fallible!(Gps, Tai);
+
 // TT <-> TCG
 
 /// The difference between J2000 TT and 1977 January 1.0 TAI as TT.
@@ -146,11 +176,123 @@ const EB: f64 = 1.671e-2;
 const M_0: f64 = 6.239996;
 const M_1: f64 = 1.99096871e-7;
 
+/// Seconds per Julian millennium.
+const SECONDS_PER_JULIAN_MILLENNIUM: f64 = 365250.0 * 86400.0;
+
+/// The leading periodic terms of the Fairhead & Bretagnon (1990) series for TDB − TT, as
+/// amplitude in seconds, frequency in radians per Julian millennium, and phase in radians.
+///
+/// The terms are ordered by the power of time they are multiplied by, i.e. `FB_T0` are constant,
+/// `FB_T1` are multiplied by `t`, and `FB_T2` by `t²`.
+const FB_T0: [(f64, f64, f64); 20] = [
+    (1656.674564e-6, 6283.075849991, 6.240054195),
+    (22.417471e-6, 5753.384884897, 4.296977442),
+    (13.839792e-6, 12566.151699983, 6.196904410),
+    (4.770086e-6, 529.690965095, 0.444401603),
+    (4.676740e-6, 6069.776754553, 4.021195093),
+    (2.256707e-6, 213.299095438, 5.543113262),
+    (1.694205e-6, -3.523118349, 5.025132748),
+    (1.554905e-6, 77713.771467920, 5.198467090),
+    (1.276839e-6, 7860.419392439, 5.988822341),
+    (1.193379e-6, 5223.693919802, 3.649823730),
+    (1.115322e-6, 3930.209696220, 1.422745069),
+    (0.794185e-6, 11506.769769794, 2.322313077),
+    (0.447061e-6, 26.298319800, 3.615796498),
+    (0.435206e-6, -398.149003408, 4.349338347),
+    (0.600309e-6, 1577.343542448, 2.678271909),
+    (0.496817e-6, 6208.294251424, 5.696701824),
+    (0.486306e-6, 5884.926846583, 0.520007179),
+    (0.432392e-6, 74.781598567, 2.435898309),
+    (0.468597e-6, 6244.942814354, 5.866398759),
+    (0.375510e-6, 5507.553238667, 4.103476804),
+];
+
+const FB_T1: [(f64, f64, f64); 2] = [
+    (102.156724e-6, 6283.075849991, 4.249032005),
+    (1.706807e-6, 12566.151699983, 4.205904248),
+];
+
+const FB_T2: [(f64, f64, f64); 1] = [(4.322990e-6, 6283.075849991, 2.642893748)];
+
+/// Models of the difference TDB − TT, which trade accuracy for speed.
+///
+/// The conversions between [Tt] and [Tdb] provided by [ToScale] use
+/// [TdbTtModel::Approximate]. Use [Time::to_tdb_with](crate::Time::to_tdb_with) and
+/// [Time::to_tt_with](crate::Time::to_tt_with) to select a model at the call site.
+///
+/// The stated accuracies are with respect to the full Fairhead & Bretagnon (1990) series between
+/// 1900 and 2100. None of the models includes the topocentric terms, which depend on the location
+/// of the observer and reach about 2 μs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TdbTtModel {
+    /// The leading periodic term of the Fairhead & Bretagnon (1990) series with the mean anomaly
+    /// of the Earth corrected for the eccentricity of its orbit, accurate to about 30 μs.
+    #[default]
+    Approximate,
+    /// The 23 largest terms of the Fairhead & Bretagnon (1990) series, including the secular
+    /// terms linear and quadratic in time, accurate to a few μs.
+    ///
+    /// This is a truncation of the full 787-term series and does not reach the nanosecond-level
+    /// agreement of the full series with the TE405 and Irwin & Fukushima (1999) time ephemerides.
+    TruncatedFairheadBretagnon,
+    /// A series of the form of Fairhead & Bretagnon (1990) with 545 terms, which has been fitted
+    /// by `lox-gen` to TDB − TT at the geocentre computed from the JPL DE440 ephemeris, accurate to
+    /// about 1 ns between 1900 and 2100 and to about 3 ns between 1850 and 2150.
+    ///
+    /// Like the full Fairhead & Bretagnon series, which is fitted to an older ephemeris, the
+    /// series is not suitable for extrapolation beyond the span of the fit.
+    De440,
+}
+
+impl TdbTtModel {
+    /// Returns TDB − TT for `tt`, the delta between the epoch and J2000 in TT.
+    pub fn tdb_minus_tt(&self, tt: TimeDelta) -> TimeDelta {
+        TimeDelta::from_decimal_seconds(self.evaluate(tt.to_decimal_seconds()))
+    }
+
+    /// Returns TT − TDB for `tdb`, the delta between the epoch and J2000 in TDB.
+    pub fn tt_minus_tdb(&self, tdb: TimeDelta) -> TimeDelta {
+        let tdb = tdb.to_decimal_seconds();
+        let mut offset = 0.0;
+        for _ in 1..3 {
+            offset = -self.evaluate(tdb + offset);
+        }
+        TimeDelta::from_decimal_seconds(offset)
+    }
+
+    fn evaluate(&self, tt: f64) -> f64 {
+        match self {
+            TdbTtModel::Approximate => {
+                let g = M_0 + M_1 * tt;
+                K * (g + EB * g.sin()).sin()
+            }
+            TdbTtModel::TruncatedFairheadBretagnon => {
+                // The argument is strictly TDB, but the difference to TT is negligible here
+                let t = tt / SECONDS_PER_JULIAN_MILLENNIUM;
+                sum_terms(&FB_T0, t) + t * (sum_terms(&FB_T1, t) + t * sum_terms(&FB_T2, t))
+            }
+            TdbTtModel::De440 => {
+                let t = tt / SECONDS_PER_JULIAN_MILLENNIUM;
+                let polynomial = DE440_POLYNOMIAL.iter().rev().fold(0.0, |p, c| p * t + c);
+                polynomial
+                    + sum_terms(&DE440_T0, t)
+                    + t * (sum_terms(&DE440_T1, t) + t * sum_terms(&DE440_T2, t))
+            }
+        }
+    }
+}
+
+/// Sums the periodic terms given as amplitude, frequency, and phase for `t` in Julian millennia.
+fn sum_terms(terms: &[(f64, f64, f64)], t: f64) -> f64 {
+    terms
+        .iter()
+        .map(|(amplitude, frequency, phase)| amplitude * (frequency * t + phase).sin())
+        .sum()
+}
+
 impl ToScale<Tdb> for Tt {
     fn offset(&self, _scale: Tdb, dt: TimeDelta) -> TimeDelta {
-        let tt = dt.to_decimal_seconds();
-        let g = M_0 + M_1 * tt;
-        TimeDelta::from_decimal_seconds(K * (g + EB * g.sin()).sin())
+        TdbTtModel::Approximate.tdb_minus_tt(dt)
     }
 }
 
@@ -158,13 +300,7 @@ impl_fallible!(Tt, Tdb);
 
 impl ToScale<Tt> for Tdb {
     fn offset(&self, _scale: Tt, dt: TimeDelta) -> TimeDelta {
-        let tdb = dt.to_decimal_seconds();
-        let mut offset = 0.0;
-        for _ in 1..3 {
-            let g = M_0 + M_1 * (tdb + offset);
-            offset = -K * (g + EB * g.sin()).sin();
-        }
-        TimeDelta::from_decimal_seconds(offset)
+        TdbTtModel::Approximate.tt_minus_tdb(dt)
     }
 }
 
@@ -343,6 +479,33 @@ impl ToScale<Tcb> for Tcg {
 
 impl_fallible!(Tcg, Tcb);
 
+// GPS <-> TCB, TCG, TDB, TT
+
+macro_rules! impl_gps {
+    ($scale:ident) => {
+        impl ToScale<$scale> for Gps {
+            fn offset(&self, scale: $scale, dt: TimeDelta) -> TimeDelta {
+                multi_step_offset(*self, Tai, scale, dt)
+            }
+        }
+
+        impl_fallible!(Gps, $scale);
+
+        impl ToScale<Gps> for $scale {
+            fn offset(&self, scale: Gps, dt: TimeDelta) -> TimeDelta {
+                multi_step_offset(*self, Tai, scale, dt)
+            }
+        }
+
+        impl_fallible!($scale, Gps);
+    };
+}
+
+impl_gps!(Tcb);
+impl_gps!(Tcg);
+impl_gps!(Tdb);
+impl_gps!(Tt);
+
 // UT1
 
 macro_rules! impl_ut1 {
@@ -385,6 +548,7 @@ macro_rules! impl_ut1 {
     };
 }
 
+impl_ut1!(Gps);
 impl_ut1!(Tcb);
 impl_ut1!(Tcg);
 impl_ut1!(Tdb);
@@ -407,14 +571,25 @@ where
         match self {
             DynTimeScale::Tai => match scale {
                 DynTimeScale::Tai => Ok(TimeDelta::default()),
+                DynTimeScale::Gps => Ok(Tai.offset(Gps, dt)),
                 DynTimeScale::Tcb => Ok(Tai.offset(Tcb, dt)),
                 DynTimeScale::Tcg => Ok(Tai.offset(Tcg, dt)),
                 DynTimeScale::Tdb => Ok(Tai.offset(Tdb, dt)),
                 DynTimeScale::Tt => Ok(Tai.offset(Tt, dt)),
                 DynTimeScale::Ut1 => Tai.try_offset(Ut1, dt, provider),
             },
+            DynTimeScale::Gps => match scale {
+                DynTimeScale::Tai => Ok(Gps.offset(Tai, dt)),
+                DynTimeScale::Gps => Ok(TimeDelta::default()),
+                DynTimeScale::Tcb => Ok(Gps.offset(Tcb, dt)),
+                DynTimeScale::Tcg => Ok(Gps.offset(Tcg, dt)),
+                DynTimeScale::Tdb => Ok(Gps.offset(Tdb, dt)),
+                DynTimeScale::Tt => Ok(Gps.offset(Tt, dt)),
+                DynTimeScale::Ut1 => Gps.try_offset(Ut1, dt, provider),
+            },
             DynTimeScale::Tcb => match scale {
                 DynTimeScale::Tai => Ok(Tcb.offset(Tai, dt)),
+                DynTimeScale::Gps => Ok(Tcb.offset(Gps, dt)),
                 DynTimeScale::Tcb => Ok(TimeDelta::default()),
                 DynTimeScale::Tcg => Ok(Tcb.offset(Tcg, dt)),
                 DynTimeScale::Tdb => Ok(Tcb.offset(Tdb, dt)),
@@ -423,6 +598,7 @@ where
             },
             DynTimeScale::Tcg => match scale {
                 DynTimeScale::Tai => Ok(Tcg.offset(Tai, dt)),
+                DynTimeScale::Gps => Ok(Tcg.offset(Gps, dt)),
                 DynTimeScale::Tcb => Ok(Tcg.offset(Tcb, dt)),
                 DynTimeScale::Tcg => Ok(TimeDelta::default()),
                 DynTimeScale::Tdb => Ok(Tcg.offset(Tdb, dt)),
@@ -431,6 +607,7 @@ where
             },
             DynTimeScale::Tdb => match scale {
                 DynTimeScale::Tai => Ok(Tdb.offset(Tai, dt)),
+                DynTimeScale::Gps => Ok(Tdb.offset(Gps, dt)),
                 DynTimeScale::Tcb => Ok(Tdb.offset(Tcb, dt)),
                 DynTimeScale::Tcg => Ok(Tdb.offset(Tcg, dt)),
                 DynTimeScale::Tdb => Ok(TimeDelta::default()),
@@ -439,6 +616,7 @@ where
             },
             DynTimeScale::Tt => match scale {
                 DynTimeScale::Tai => Ok(Tt.offset(Tai, dt)),
+                DynTimeScale::Gps => Ok(Tt.offset(Gps, dt)),
                 DynTimeScale::Tcb => Ok(Tt.offset(Tcb, dt)),
                 DynTimeScale::Tcg => Ok(Tt.offset(Tcg, dt)),
                 DynTimeScale::Tdb => Ok(Tt.offset(Tdb, dt)),
@@ -447,6 +625,7 @@ where
             },
             DynTimeScale::Ut1 => match scale {
                 DynTimeScale::Tai => Ut1.try_offset(Tai, dt, provider),
+                DynTimeScale::Gps => Ut1.try_offset(Gps, dt, provider),
                 DynTimeScale::Tcb => Ut1.try_offset(Tcb, dt, provider),
                 DynTimeScale::Tcg => Ut1.try_offset(Tcg, dt, provider),
                 DynTimeScale::Tdb => Ut1.try_offset(Tdb, dt, provider),
@@ -489,6 +668,38 @@ where
     }
 }
 
+impl<P> TryToScale<Gps, P> for DynTimeScale
+where
+    P: DeltaUt1TaiProvider,
+{
+    type Error = Ut1Error;
+
+    fn try_offset(
+        &self,
+        _scale: Gps,
+        dt: TimeDelta,
+        provider: Option<&P>,
+    ) -> Result<TimeDelta, Self::Error> {
+        self.try_offset(DynTimeScale::Gps, dt, provider)
+    }
+}
+
+impl<P> TryToScale<DynTimeScale, P> for Gps
+where
+    P: DeltaUt1TaiProvider,
+{
+    type Error = Ut1Error;
+
+    fn try_offset(
+        &self,
+        scale: DynTimeScale,
+        dt: TimeDelta,
+        provider: Option<&P>,
+    ) -> Result<TimeDelta, Self::Error> {
+        DynTimeScale::Gps.try_offset(scale, dt, provider)
+    }
+}
+
 impl<P> TryToScale<Tcb, P> for DynTimeScale
 where
     P: DeltaUt1TaiProvider,
@@ -651,13 +862,14 @@ where
 
 #[cfg(test)]
 mod tests {
+    use float_eq::assert_float_eq;
     use rstest::rstest;
 
     use super::*;
     use crate::time_scales::{FromScale, ToScale};
     use crate::{
         DynTime, calendar_dates::Date, deltas::ToDelta, test_helpers::delta_ut1_tai,
-        time_of_day::TimeOfDay,
+        time_of_day::TimeOfDay, ut1::DeltaUt1Tai,
     };
     use lox_math::is_close::IsClose;
 
@@ -678,6 +890,13 @@ mod tests {
     //
     #[rstest]
     #[case::tai_tai("TAI", "TAI", 0.0, None)]
+    #[case::tai_gps("TAI", "GPS", -19.0, None)]
+    #[case::gps_gps("GPS", "GPS", 0.0, None)]
+    #[case::gps_tai("GPS", "TAI", 19.0, None)]
+    #[case::gps_tdb("GPS", "TDB", 51.183882324981056, None)]
+    #[case::gps_tt("GPS", "TT", 51.184, None)]
+    #[case::gps_ut1("GPS", "UT1", -17.949521832072996, Some(UT1_TOL))]
+    #[case::tt_gps("TT", "GPS", -51.184, None)]
     #[case::tai_tcb("TAI", "TCB", 55.66851419888016, Some(TCB_TOL))]
     #[case::tai_tcg("TAI", "TCG", 33.239589335894145, None)]
     #[case::tai_tdb("TAI", "TDB", 32.183882324981056, None)]
@@ -736,4 +955,151 @@ mod tests {
             .to_decimal_seconds();
         assert_close!(act, exp, 1e-7, tol.unwrap_or(DEFAULT_TOL));
     }
+
+    const DYN_TIME_SCALES: [DynTimeScale; 7] = [
+        DynTimeScale::Tai,
+        DynTimeScale::Gps,
+        DynTimeScale::Tcb,
+        DynTimeScale::Tcg,
+        DynTimeScale::Tdb,
+        DynTimeScale::Tt,
+        DynTimeScale::Ut1,
+    ];
+
+    #[test]
+    fn test_dyn_time_scale_round_trips() {
+        let provider = Some(delta_ut1_tai());
+        let date = Date::new(2024, 12, 30).unwrap();
+        let time = TimeOfDay::from_hms(10, 27, 13.145).unwrap();
+        for origin in DYN_TIME_SCALES {
+            let t0 = DynTime::from_date_and_time(origin, date, time).unwrap();
+            for target in DYN_TIME_SCALES {
+                let t1 = t0.try_to_scale(target, provider).unwrap();
+                let act = t1.try_to_scale(origin, provider).unwrap();
+                assert_float_eq!(
+                    (act - t0).to_decimal_seconds(),
+                    0.0,
+                    abs <= 1e-9,
+                    "{origin:?} -> {target:?} -> {origin:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ut1_chains() {
+        use crate::Time;
+
+        let provider = Some(delta_ut1_tai());
+        let ut1 = Time::from_date_and_time(
+            Ut1,
+            Date::new(2024, 12, 30).unwrap(),
+            TimeOfDay::from_hms(10, 27, 13.145).unwrap(),
+        )
+        .unwrap();
+        let tai = ut1.try_to_scale(Tai, provider).unwrap();
+        let tt = tai.to_scale(Tt);
+        let tdb = tt.to_scale(Tdb);
+        assert_eq!(ut1.try_to_scale(Tt, provider).unwrap(), tt);
+        assert_float_eq!(
+            (ut1.try_to_scale(Tdb, provider).unwrap() - tdb).to_decimal_seconds(),
+            0.0,
+            abs <= 1e-12
+        );
+        assert_float_eq!(
+            (ut1.try_to_scale(Tcb, provider).unwrap() - tdb.to_scale(Tcb)).to_decimal_seconds(),
+            0.0,
+            abs <= 1e-12
+        );
+        assert_eq!(
+            ut1.try_to_scale(Tai, None::<&DeltaUt1Tai>),
+            Err(Ut1Error::MissingProvider)
+        );
+    }
+
+    // TDB − TT at JD 2448939.623 TDB from the ERFA test suite, which includes the topocentric
+    // terms omitted by the models
+    const ERFA_TDB: f64 = (2448939.623 - 2451545.0) * 86400.0;
+    const ERFA_TDB_MINUS_TT: f64 = -0.1280368005936999e-2;
+
+    #[rstest]
+    #[case::approximate(TdbTtModel::Approximate, 3e-5)]
+    #[case::truncated_fairhead_bretagnon(TdbTtModel::TruncatedFairheadBretagnon, 1e-6)]
+    #[case::de440(TdbTtModel::De440, 1e-6)]
+    fn test_tdb_tt_model_accuracy(#[case] model: TdbTtModel, #[case] tol: f64) {
+        let tdb = TimeDelta::from_decimal_seconds(ERFA_TDB);
+        let act = -model.tt_minus_tdb(tdb).to_decimal_seconds();
+        assert_float_eq!(act, ERFA_TDB_MINUS_TT, abs <= tol);
+    }
+
+    #[rstest]
+    #[case::approximate(TdbTtModel::Approximate)]
+    #[case::truncated_fairhead_bretagnon(TdbTtModel::TruncatedFairheadBretagnon)]
+    #[case::de440(TdbTtModel::De440)]
+    fn test_tdb_tt_model_round_trip(#[case] model: TdbTtModel) {
+        for years in [-100, -25, 0, 25, 100] {
+            let tt = TimeDelta::from_decimal_seconds(years as f64 * 365.25 * 86400.0 + 1234.5);
+            let tdb = tt + model.tdb_minus_tt(tt);
+            let act = tdb + model.tt_minus_tdb(tdb);
+            assert_float_eq!(
+                act.to_decimal_seconds(),
+                tt.to_decimal_seconds(),
+                abs <= 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_tdb_tt_models_agree() {
+        for days in (-36525..=36525).step_by(97) {
+            let tt = TimeDelta::from_decimal_seconds(days as f64 * 86400.0);
+            let approximate = TdbTtModel::Approximate.tdb_minus_tt(tt);
+            let accurate = TdbTtModel::TruncatedFairheadBretagnon.tdb_minus_tt(tt);
+            assert_float_eq!(
+                approximate.to_decimal_seconds(),
+                accurate.to_decimal_seconds(),
+                abs <= 5e-5
+            );
+        }
+    }
+
+    // TDB − TT at 0h TDB computed by `lox-gen` from DE440 on days since J2000 which are not part
+    // of the fit, with the accuracy of the series within and beyond 1900 to 2100
+    #[rstest]
+    #[case(-54786, 2.4144354190454572e-5, 4e-9)]
+    #[case(-43828, -1.4018401524211192e-5, 4e-9)]
+    #[case(-32870, 3.691627685658727e-5, 1e-9)]
+    #[case(-21912, -3.5898943606155236e-6, 1e-9)]
+    #[case(-10954, 5.0322565486922706e-5, 1e-9)]
+    #[case(4, 1.6897758784935598e-5, 1e-9)]
+    #[case(10962, 5.8297850282159054e-5, 1e-9)]
+    #[case(21920, 3.773581997795372e-5, 1e-9)]
+    #[case(32878, 6.857836949141937e-5, 1e-9)]
+    #[case(43836, 6.0976792701168954e-5, 4e-9)]
+    fn test_tdb_tt_model_de440(#[case] days: i64, #[case] exp: f64, #[case] tol: f64) {
+        let tdb = TimeDelta::from_decimal_seconds(days as f64 * 86400.0);
+        let act = -TdbTtModel::De440.tt_minus_tdb(tdb).to_decimal_seconds();
+        assert_float_eq!(act, exp, abs <= tol);
+    }
+
+    #[test]
+    fn test_tdb_tt_model_de440_agrees_with_fairhead_bretagnon() {
+        for days in (-36525..=36525).step_by(97) {
+            let tt = TimeDelta::from_decimal_seconds(days as f64 * 86400.0);
+            let truncated = TdbTtModel::TruncatedFairheadBretagnon.tdb_minus_tt(tt);
+            let de440 = TdbTtModel::De440.tdb_minus_tt(tt);
+            assert_float_eq!(
+                truncated.to_decimal_seconds(),
+                de440.to_decimal_seconds(),
+                abs <= 3e-6
+            );
+        }
+    }
+
+    #[test]
+    fn test_tdb_tt_model_default() {
+        let tt = TimeDelta::from_decimal_seconds(ERFA_TDB);
+        assert_eq!(TdbTtModel::default().tdb_minus_tt(tt), Tt.offset(Tdb, tt));
+        assert_eq!(TdbTtModel::default().tt_minus_tdb(tt), Tdb.offset(Tt, tt));
+    }
 }
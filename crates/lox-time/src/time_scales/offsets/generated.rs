@@ -0,0 +1,2727 @@
+/*
+ * Copyright (c) 2024. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Auto-generated by `lox-gen`. Do not edit!
+
+pub const DE440_POLYNOMIAL: [f64; 4] = [
+    -0.0000016869231704529188f64,
+    -0.000009514500357632569f64,
+    0.000027610785741555274f64,
+    0.00042797394432952154f64,
+];
+pub const DE440_T0: [(f64, f64, f64); 400] = [
+    (
+        0.0016565918174345157f64,
+        6283.075849991f64,
+        6.2400415729522205f64,
+    ),
+    (
+        0.000022416003888358958f64,
+        5753.51680085326f64,
+        4.296275799002972f64,
+    ),
+    (
+        0.00001383855617763948f64,
+        12566.151699983f64,
+        6.196879105451986f64,
+    ),
+    (
+        0.000004760413964319095f64,
+        529.5447370947952f64,
+        0.4382346232156326f64,
+    ),
+    (
+        0.000004677455827918533f64,
+        6069.851874135749f64,
+        4.022821931306305f64,
+    ),
+    (
+        0.0000022037050379072207f64,
+        213.5957408099463f64,
+        5.551310414959904f64,
+    ),
+    (
+        0.0000015549065063608682f64,
+        77713.76933049229f64,
+        5.19854556129333f64,
+    ),
+    (
+        0.0000012768074278997859f64,
+        7860.396409029348f64,
+        5.988910640256881f64,
+    ),
+    (
+        0.000001188803921340552f64,
+        5223.797520796621f64,
+        3.655139390054032f64,
+    ),
+    (
+        0.0000011154043474289391f64,
+        3930.2361756815867f64,
+        1.4228017943709508f64,
+    ),
+    (
+        0.0000007940886733351521f64,
+        11506.794402325857f64,
+        2.321021983308932f64,
+    ),
+    (
+        0.0000005992624209051087f64,
+        1577.2493594792406f64,
+        2.6655152540965075f64,
+    ),
+    (
+        0.000000485279746402794f64,
+        5885.64942748901f64,
+        0.5188475298834664f64,
+    ),
+    (
+        0.0000004690055777585616f64,
+        6248.170294517227f64,
+        5.842647292765032f64,
+    ),
+    (
+        0.0000004344216620861586f64,
+        397.9701277988246f64,
+        5.073283225602516f64,
+    ),
+    (
+        0.0000004245696808482443f64,
+        72.55810766253441f64,
+        2.4238089256241384f64,
+    ),
+    (
+        0.00000036962487654228474f64,
+        5509.463044118354f64,
+        4.099089790132933f64,
+    ),
+    (
+        0.0000003484325054723892f64,
+        6215.6781683253175f64,
+        5.679581325881194f64,
+    ),
+    (
+        0.0000002420359288774826f64,
+        40.03924817970196f64,
+        4.192648039957868f64,
+    ),
+    (
+        0.00000024000117263415456f64,
+        773.317244742889f64,
+        5.784731158553594f64,
+    ),
+    (
+        0.0000002230706919538261f64,
+        5854.531971308308f64,
+        4.7665851419667735f64,
+    ),
+    (
+        0.00000020385237049765855f64,
+        12036.135160094009f64,
+        4.332823554008916f64,
+    ),
+    (
+        0.00000018578472825041723f64,
+        6193.7919775918435f64,
+        5.832028780129159f64,
+    ),
+    (
+        0.00000017341216205403334f64,
+        18849.076296968233f64,
+        6.153681929655128f64,
+    ),
+    (
+        0.00000015759278063060938f64,
+        10977.31427169179f64,
+        1.9092936914964627f64,
+    ),
+    (
+        0.0000001384381264810789f64,
+        11790.240641519471f64,
+        1.1366148586532465f64,
+    ),
+    (
+        0.00000011588671926389238f64,
+        1058.9815887691673f64,
+        0.8584201308870494f64,
+    ),
+    (
+        0.0000001147443002209354f64,
+        5486.169613919999f64,
+        1.9460146693340898f64,
+    ),
+    (
+        0.00000011316678186600484f64,
+        795.849659915031f64,
+        3.6084651368674643f64,
+    ),
+    (
+        0.00000010135076738433667f64,
+        5571.91432590851f64,
+        3.4426846294238094f64,
+    ),
+    (
+        0.00000009837336498998068f64,
+        2544.4487880884462f64,
+        0.09191161999243487f64,
+    ),
+    (
+        0.00000007503787294376698f64,
+        2942.2403578728736f64,
+        4.9810719627793905f64,
+    ),
+    (
+        0.00000007182188227344666f64,
+        4693.342763627921f64,
+        3.067444210434261f64,
+    ),
+    (
+        0.00000007153612857529348f64,
+        424.9799423135237f64,
+        4.597597986185296f64,
+    ),
+    (
+        0.00000005349879314984377f64,
+        17260.25274386326f64,
+        3.415201439247822f64,
+    ),
+    (
+        0.000000048034043546095074f64,
+        2146.1657959016284f64,
+        1.4934645052905375f64,
+    ),
+    (
+        0.00000004256963720649091f64,
+        161000.68376286607f64,
+        1.2709603387871207f64,
+    ),
+    (
+        0.00000004224320210119857f64,
+        634.6995312515733f64,
+        5.663589915959274f64,
+    ),
+    (
+        0.00000004075110579046622f64,
+        12352.51660390827f64,
+        3.9818542109239274f64,
+    ),
+    (
+        0.00000004047145950699716f64,
+        15720.879605420676f64,
+        2.5468722063588474f64,
+    ),
+    (
+        0.00000003694551358135639f64,
+        3154.980630832502f64,
+        5.07167467033282f64,
+    ),
+    (
+        0.000000036794059526864275f64,
+        158.45311080196254f64,
+        2.3265927849324672f64,
+    ),
+    (
+        0.00000003649923531562124f64,
+        5088.114604052036f64,
+        3.322005172654205f64,
+    ),
+    (
+        0.000000033835427943520706f64,
+        100.5464457056575f64,
+        1.7660161890958481f64,
+    ),
+    (
+        0.0000000335037424505127f64,
+        9438.0153101943f64,
+        2.40494761122063f64,
+    ),
+    (
+        0.00000003242041139392585f64,
+        8827.37444747331f64,
+        5.540768018150803f64,
+    ),
+    (
+        0.00000003212674339263886f64,
+        7084.35490766809f64,
+        3.4081410344344043f64,
+    ),
+    (
+        0.000000029970975104251035f64,
+        12139.409769383701f64,
+        1.7725621147384691f64,
+    ),
+    (
+        0.000000029246462307626605f64,
+        71430.76697678774f64,
+        5.241629487726135f64,
+    ),
+    (
+        0.0000000251985677289372f64,
+        1748.5124952826177f64,
+        2.899590124372599f64,
+    ),
+    (
+        0.000000025182892339138195f64,
+        6133.332437756037f64,
+        3.2496343833823036f64,
+    ),
+    (
+        0.00000002481097834211906f64,
+        1194.5562179221981f64,
+        2.0560729057500358f64,
+    ),
+    (
+        0.000000022414617625015814f64,
+        10447.480817075244f64,
+        1.4739658678784244f64,
+    ),
+    (
+        0.00000002168267490067812f64,
+        14143.305794273772f64,
+        5.952899377848832f64,
+    ),
+    (
+        0.000000020928425077478183f64,
+        8429.683884639773f64,
+        0.6510201690924178f64,
+    ),
+    (
+        0.00000001849305466791715f64,
+        5646.521288733669f64,
+        5.5530870708692435f64,
+    ),
+    (
+        0.000000017638138526707076f64,
+        6812.720255353657f64,
+        3.1734943407509646f64,
+    ),
+    (
+        0.00000001616726119232096f64,
+        10213.026905774695f64,
+        1.327966881193525f64,
+    ),
+    (
+        0.000000016010076007345612f64,
+        2352.6445172705185f64,
+        3.285849224036857f64,
+    ),
+    (
+        0.000000015641708384295766f64,
+        6316.354645661033f64,
+        6.127176806187401f64,
+    ),
+    (
+        0.000000015434089295820923f64,
+        952.3154766280593f64,
+        5.200606795196082f64,
+    ),
+    (
+        0.00000001506209575780747f64,
+        19651.035819305336f64,
+        3.9699507453652587f64,
+    ),
+    (
+        0.000000014735434474375707f64,
+        1350.3799266874958f64,
+        4.30795528501944f64,
+    ),
+    (
+        0.000000014290492998948259f64,
+        16730.480944993793f64,
+        3.019762645555499f64,
+    ),
+    (
+        0.000000014189559856777097f64,
+        17789.881222744498f64,
+        2.106808093536609f64,
+    ),
+    (
+        0.000000011922420972767389f64,
+        8031.335868035099f64,
+        2.0530678987178907f64,
+    ),
+    (
+        0.000000011601312538091604f64,
+        5120.456253665019f64,
+        4.857445423526537f64,
+    ),
+    (
+        0.000000011190377815100325f64,
+        284.52047955427145f64,
+        2.003507953222352f64,
+    ),
+    (
+        0.00000000936574604398839f64,
+        149854.4080171574f64,
+        0.6733511038298442f64,
+    ),
+    (
+        0.00000000862520438292212f64,
+        3340.952422606849f64,
+        3.663090820350079f64,
+    ),
+    (
+        0.000000008494794592145655f64,
+        6159.926048696998f64,
+        6.0849576960702425f64,
+    ),
+    (
+        0.000000008361178677843102f64,
+        559.9670866065469f64,
+        0.6810541884443528f64,
+    ),
+    (
+        0.000000008153189397414766f64,
+        183.69402758187076f64,
+        1.6456753792623457f64,
+    ),
+    (
+        0.000000008127719740085682f64,
+        13367.925867362903f64,
+        3.7925325746291385f64,
+    ),
+    (
+        0.000000007865880570582523f64,
+        7236.419007572211f64,
+        4.7880483037337855f64,
+    ),
+    (
+        0.000000007793286589925909f64,
+        12166.625807851988f64,
+        0.5252943731009692f64,
+    ),
+    (
+        0.00000000767568087331219f64,
+        11766.01613833394f64,
+        1.2206710122376818f64,
+    ),
+    (
+        0.000000007321480866656608f64,
+        6338.095074617584f64,
+        2.5953656921568045f64,
+    ),
+    (
+        0.000000007282106728501734f64,
+        318.09357065328504f64,
+        2.3949191869553945f64,
+    ),
+    (
+        0.0000000067862529267033455f64,
+        7632.765939104469f64,
+        3.458466273815503f64,
+    ),
+    (
+        0.000000006642281090910574f64,
+        6678.978400686554f64,
+        6.0194243371783855f64,
+    ),
+    (
+        0.000000006578009778004219f64,
+        23580.6052342309f64,
+        5.3945867750886976f64,
+    ),
+    (
+        0.000000006194807562019846f64,
+        4164.728376945397f64,
+        2.2866756423400174f64,
+    ),
+    (
+        0.000000006103319972202731f64,
+        11926.300335277607f64,
+        2.505582762771169f64,
+    ),
+    (
+        0.000000005863834350470109f64,
+        6437.048465504848f64,
+        1.7015513980897017f64,
+    ),
+    (
+        0.000000005833890002216484f64,
+        4722.217603113384f64,
+        0.8568944615235811f64,
+    ),
+    (
+        0.000000005664798162573257f64,
+        23013.56683689681f64,
+        4.558756376946717f64,
+    ),
+    (
+        0.000000005118698457243035f64,
+        5963.894067783111f64,
+        2.273255678074838f64,
+    ),
+    (
+        0.000000005086509803440572f64,
+        11372.566010075658f64,
+        2.5457168326188135f64,
+    ),
+    (
+        0.000000004735757188738287f64,
+        5334.366627665137f64,
+        0.2868358644593293f64,
+    ),
+    (
+        0.000000004502923110098577f64,
+        4292.435816716697f64,
+        6.130742099049382f64,
+    ),
+    (
+        0.000000004440899818796961f64,
+        5435.631325343933f64,
+        1.2789108453439337f64,
+    ),
+    (
+        0.000000004010192079316279f64,
+        7058.326151370216f64,
+        5.7130014493431105f64,
+    ),
+    (
+        0.000000003791514184686872f64,
+        12534.587460038301f64,
+        5.789547382220759f64,
+    ),
+    (
+        0.0000000034293389441305693f64,
+        739.9052805066292f64,
+        0.3683782827073773f64,
+    ),
+    (
+        0.000000003353659219443505f64,
+        90955.54544885883f64,
+        1.1987770749826823f64,
+    ),
+    (
+        0.000000003260546695375232f64,
+        5777.95435361761f64,
+        3.7268605920685633f64,
+    ),
+    (
+        0.0000000031909914594989546f64,
+        27511.406839456973f64,
+        0.5328963433896216f64,
+    ),
+    (
+        0.0000000031850990859637527f64,
+        6836.24569914315f64,
+        6.275846936330197f64,
+    ),
+    (
+        0.00000000309714057449456f64,
+        240.74809804906832f64,
+        5.7752615662010935f64,
+    ),
+    (
+        0.0000000030556278871841676f64,
+        83286.84222354672f64,
+        2.355653652149816f64,
+    ),
+    (
+        0.000000003051844130460725f64,
+        233141.31396468572f64,
+        3.0290326073802687f64,
+    ),
+    (
+        0.000000003047144139621198f64,
+        12479.505956245144f64,
+        5.74142061358755f64,
+    ),
+    (
+        0.0000000029234810604049778f64,
+        6399.522170448228f64,
+        6.070246517457718f64,
+    ),
+    (
+        0.000000002851043543507055f64,
+        17298.699551811693f64,
+        5.237441288399901f64,
+    ),
+    (
+        0.000000002748475197182735f64,
+        9917.184767249593f64,
+        1.0528688326551572f64,
+    ),
+    (
+        0.0000000027353500752632226f64,
+        18319.18404254922f64,
+        4.319428312378534f64,
+    ),
+    (
+        0.000000002577430768703738f64,
+        25131.849811414348f64,
+        6.108952424016839f64,
+    ),
+    (
+        0.0000000025665267851209577f64,
+        475.49429744932615f64,
+        1.5562148559274642f64,
+    ),
+    (
+        0.000000002401063989305967f64,
+        16200.92113311321f64,
+        2.6103746997886077f64,
+    ),
+    (
+        0.0000000023230452067726764f64,
+        18072.725938274718f64,
+        1.0766427013272397f64,
+    ),
+    (
+        0.0000000023048345734838797f64,
+        83996.76310134737f64,
+        2.0130679128109445f64,
+    ),
+    (
+        0.000000002239457589294725f64,
+        6368.881503459625f64,
+        0.0716750694366989f64,
+    ),
+    (
+        0.0000000021949437656001725f64,
+        6036.829336680761f64,
+        5.672391984564266f64,
+    ),
+    (
+        0.000000002170533962039915f64,
+        1162.0657615250448f64,
+        6.190090951944636f64,
+    ),
+    (
+        0.000000002122440157864901f64,
+        11015.70082191368f64,
+        4.839159477977095f64,
+    ),
+    (
+        0.0000000020799543149153703f64,
+        5721.144336756581f64,
+        1.515330758787614f64,
+    ),
+    (
+        0.0000000020490472069171056f64,
+        12501.392146978616f64,
+        5.612730879363721f64,
+    ),
+    (
+        0.000000002030609921151112f64,
+        822.9207715594366f64,
+        5.9993822897814315f64,
+    ),
+    (
+        0.000000002023303865252516f64,
+        14711.765217062128f64,
+        2.7529320637023393f64,
+    ),
+    (
+        0.000000001900026010565271f64,
+        22484.032738519752f64,
+        4.173495981956011f64,
+    ),
+    (
+        0.0000000018815531550670377f64,
+        3127.527032541739f64,
+        4.499404355602067f64,
+    ),
+    (
+        0.00000000183764727451332f64,
+        10873.908529492408f64,
+        2.9018868577419688f64,
+    ),
+    (
+        0.0000000018328370858719464f64,
+        3739.093269155134f64,
+        2.6082814029465773f64,
+    ),
+    (
+        0.0000000018281602008059842f64,
+        1609.8074378489127f64,
+        0.8346211257426018f64,
+    ),
+    (
+        0.0000000018096883347410045f64,
+        88860.06450481419f64,
+        2.6544600580878f64,
+    ),
+    (
+        0.0000000017427738375022213f64,
+        244287.60566744895f64,
+        3.6265741409365138f64,
+    ),
+    (
+        0.0000000017181951149460806f64,
+        503.5457568700315f64,
+        5.134383372727582f64,
+    ),
+    (
+        0.0000000016633218007543965f64,
+        4749.303389144401f64,
+        1.0172985531098049f64,
+    ),
+    (
+        0.0000000016545625835535787f64,
+        31441.749165727502f64,
+        1.9551664732145242f64,
+    ),
+    (
+        0.0000000016541799363480713f64,
+        5538.025908493659f64,
+        3.4404204137001213f64,
+    ),
+    (
+        0.0000000016188222086582327f64,
+        6498.224920044302f64,
+        4.999424956210042f64,
+    ),
+    (
+        0.0000000016005232869745136f64,
+        14314.117895883448f64,
+        4.194038760750059f64,
+    ),
+    (
+        0.0000000014766112851229434f64,
+        4592.598387885588f64,
+        4.124920213641683f64,
+    ),
+    (
+        0.0000000014189378067189153f64,
+        9225.142628931955f64,
+        4.992102856623902f64,
+    ),
+    (
+        0.0000000013805095639421965f64,
+        8634.091280123639f64,
+        2.5491576275866827f64,
+    ),
+    (
+        0.0000000013662969302970599f64,
+        23542.91829927982f64,
+        2.976684664130332f64,
+    ),
+    (
+        0.000000001365082477325278f64,
+        18209.195281801152f64,
+        2.626505736752016f64,
+    ),
+    (
+        0.0000000013475650854526531f64,
+        3887.552214399182f64,
+        1.2667960911804201f64,
+    ),
+    (
+        0.000000001295724385660606f64,
+        21228.455710572656f64,
+        0.3850444344091368f64,
+    ),
+    (
+        0.0000000012753230024237035f64,
+        10575.297775271236f64,
+        5.273737947919999f64,
+    ),
+    (
+        0.000000001266375527855277f64,
+        4805.399437105145f64,
+        5.398702671363255f64,
+    ),
+    (
+        0.0000000012229741527126022f64,
+        1990.8046035255265f64,
+        5.512573409445024f64,
+    ),
+    (
+        0.0000000010794937047085084f64,
+        376.18610270547777f64,
+        1.5285721801400198f64,
+    ),
+    (
+        0.0000000010700714985678607f64,
+        154717.6717501139f64,
+        1.3141197433820584f64,
+    ),
+    (
+        0.0000000010339218594774842f64,
+        849.1842004396035f64,
+        2.7075136695285993f64,
+    ),
+    (
+        0.0000000009007436987846392f64,
+        35371.83974004448f64,
+        3.377700818168361f64,
+    ),
+    (
+        0.0000000008963293145016193f64,
+        5799.197483302551f64,
+        0.3988851225710921f64,
+    ),
+    (
+        0.0000000008910978867876436f64,
+        13916.899325436862f64,
+        5.585216621356036f64,
+    ),
+    (
+        0.0000000008288991832293204f64,
+        11710.100817095446f64,
+        3.223950290792188f64,
+    ),
+    (
+        0.0000000008185877883488434f64,
+        15110.496672270483f64,
+        5.155499377230627f64,
+    ),
+    (
+        0.0000000008116369552491084f64,
+        13521.261915474017f64,
+        1.7458796808635633f64,
+    ),
+    (
+        0.0000000008023562755783571f64,
+        17654.24585614052f64,
+        4.63106434974858f64,
+    ),
+    (
+        0.0000000008018391664458086f64,
+        6476.937142107037f64,
+        5.603096216356238f64,
+    ),
+    (
+        0.000000000799918570918712f64,
+        5018.95985984172f64,
+        3.9234413890531408f64,
+    ),
+    (
+        0.0000000007936105028243287f64,
+        8664.310215353033f64,
+        5.985030140076198f64,
+    ),
+    (
+        0.0000000007751834298019624f64,
+        4136.49004864543f64,
+        3.132830833917929f64,
+    ),
+    (
+        0.0000000007615317714395285f64,
+        2379.0085342767384f64,
+        2.791282413541807f64,
+    ),
+    (
+        0.0000000007483840311245493f64,
+        5699.1640670153665f64,
+        4.570310520193699f64,
+    ),
+    (
+        0.0000000007126506015783835f64,
+        1545.1650658022872f64,
+        2.354546677251688f64,
+    ),
+    (
+        0.0000000007015700446704597f64,
+        28766.846213603418f64,
+        5.692371208504653f64,
+    ),
+    (
+        0.0000000007014927731788985f64,
+        341.4245754612999f64,
+        5.136236890279337f64,
+    ),
+    (
+        0.0000000006840483951924631f64,
+        3496.161823733883f64,
+        2.6663567797916694f64,
+    ),
+    (
+        0.0000000006786265966053493f64,
+        3637.473478413115f64,
+        1.9044166237277185f64,
+    ),
+    (
+        0.0000000006675945321252355f64,
+        14945.041404617044f64,
+        6.273056434847954f64,
+    ),
+    (
+        0.0000000006596738443653997f64,
+        25158.29708798694f64,
+        1.7874568059264857f64,
+    ),
+    (
+        0.0000000006436019223955986f64,
+        6701.551602570149f64,
+        6.246141587471157f64,
+    ),
+    (
+        0.0000000006431109604512139f64,
+        11856.361470976291f64,
+        3.3890014501665973f64,
+    ),
+    (
+        0.0000000006387985322797014f64,
+        83467.36523790909f64,
+        3.208714738134861f64,
+    ),
+    (
+        0.0000000006312515783366695f64,
+        11403.360786494804f64,
+        3.8207011695113207f64,
+    ),
+    (
+        0.000000000619973847443202f64,
+        22003.910135948787f64,
+        2.465017212400821f64,
+    ),
+    (
+        0.0000000006185646860190065f64,
+        11088.76937246984f64,
+        4.5973268324804595f64,
+    ),
+    (
+        0.0000000006118055173369777f64,
+        143571.40333059058f64,
+        0.7163720614720045f64,
+    ),
+    (
+        0.0000000006034722738267063f64,
+        65147.79622998688f64,
+        5.284456451957881f64,
+    ),
+    (
+        0.0000000005937921409526765f64,
+        10176.046666052405f64,
+        0.42373615630469746f64,
+    ),
+    (
+        0.0000000005825935592852675f64,
+        3908.873664979058f64,
+        1.217912536904015f64,
+    ),
+    (
+        0.0000000005757704263117932f64,
+        72140.59863344186f64,
+        1.7582918839525605f64,
+    ),
+    (
+        0.0000000005332966361775563f64,
+        5822.865533148721f64,
+        4.125631917885348f64,
+    ),
+    (
+        0.0000000005218736254184429f64,
+        18635.900403326526f64,
+        3.9291504116356206f64,
+    ),
+    (
+        0.0000000005218654117296073f64,
+        12417.397439979086f64,
+        4.763628573629724f64,
+    ),
+    (
+        0.0000000005125843196512587f64,
+        712.3546257850608f64,
+        6.271540156768067f64,
+    ),
+    (
+        0.0000000005098729851563559f64,
+        4911.2612005720985f64,
+        1.049321641429868f64,
+    ),
+    (
+        0.0000000005097245637504876f64,
+        39302.146564333045f64,
+        4.800150925438166f64,
+    ),
+    (
+        0.0000000005092973207257304f64,
+        10344.304369390045f64,
+        2.4617536376648927f64,
+    ),
+    (
+        0.0000000005010486621720731f64,
+        11191.319182355395f64,
+        5.753598506781493f64,
+    ),
+    (
+        0.0000000004827632775986662f64,
+        9623.70458383682f64,
+        3.0225457033262475f64,
+    ),
+    (
+        0.0000000004814008113685708f64,
+        18422.085988493716f64,
+        1.716997369480463f64,
+    ),
+    (
+        0.000000000452773243300766f64,
+        599.2308903088374f64,
+        5.532127094651775f64,
+    ),
+    (
+        0.00000000044993156992616315f64,
+        20425.414350966956f64,
+        6.015538268642323f64,
+    ),
+    (
+        0.00000000044986047020894356f64,
+        1261.5168598396074f64,
+        5.5217326939445766f64,
+    ),
+    (
+        0.0000000004413621184254077f64,
+        16859.085471241324f64,
+        1.1832511303546587f64,
+    ),
+    (
+        0.0000000004135015582930137f64,
+        7479.286661341033f64,
+        2.0737426456030374f64,
+    ),
+    (
+        0.00000000039392553526577434f64,
+        11607.815822003391f64,
+        4.219801706375715f64,
+    ),
+    (
+        0.00000000038910799008577925f64,
+        7344.673637494061f64,
+        3.789736974872176f64,
+    ),
+    (
+        0.0000000003637829276395695f64,
+        21954.441651631576f64,
+        3.7128403701686254f64,
+    ),
+    (
+        0.00000000036289666862993787f64,
+        4666.1358643954645f64,
+        0.018781015195797904f64,
+    ),
+    (
+        0.00000000036075941738499423f64,
+        29088.868054437575f64,
+        3.231616555717797f64,
+    ),
+    (
+        0.0000000003571908799805914f64,
+        4535.076765396992f64,
+        1.5142208792809657f64,
+    ),
+    (
+        0.000000000351611971248446f64,
+        20198.77420449677f64,
+        1.644603295763082f64,
+    ),
+    (
+        0.0000000003513362974678465f64,
+        5676.017895831246f64,
+        0.6787839977736224f64,
+    ),
+    (
+        0.0000000003461705229147292f64,
+        17994.38922596288f64,
+        3.377983805141867f64,
+    ),
+    (
+        0.00000000033105029016589904f64,
+        20596.803492723182f64,
+        0.2060423049105848f64,
+    ),
+    (
+        0.0000000003161306785849586f64,
+        11814.165757909323f64,
+        1.6685698526663648f64,
+    ),
+    (
+        0.00000000030874969167976824f64,
+        6014.008886991476f64,
+        0.9984709844903371f64,
+    ),
+    (
+        0.00000000029867197975588323f64,
+        316428.2487727698f64,
+        5.38446350731444f64,
+    ),
+    (
+        0.0000000002969174928464776f64,
+        1820.9310690440404f64,
+        6.064969387472207f64,
+    ),
+    (
+        0.00000000029626344265599504f64,
+        43232.31891859163f64,
+        6.221670543491904f64,
+    ),
+    (
+        0.0000000002944859670612539f64,
+        18048.275415562846f64,
+        0.6181021298256f64,
+    ),
+    (
+        0.0000000002885656128152062f64,
+        2116.82560061333f64,
+        1.6225488043358183f64,
+    ),
+    (
+        0.00000000028772141809452234f64,
+        28237.542591255893f64,
+        5.238139039170509f64,
+    ),
+    (
+        0.00000000028544053518852274f64,
+        13098.061812721071f64,
+        3.211008777912685f64,
+    ),
+    (
+        0.0000000002792992746785876f64,
+        15670.512565185432f64,
+        2.187084449210417f64,
+    ),
+    (
+        0.0000000002768850244430905f64,
+        9778.852287754584f64,
+        1.805764755113717f64,
+    ),
+    (
+        0.0000000002736153875117806f64,
+        18877.094538239024f64,
+        1.4580507697114629f64,
+    ),
+    (
+        0.0000000002683952303106782f64,
+        226858.29298594513f64,
+        3.0721410726786256f64,
+    ),
+    (
+        0.00000000026654631498089306f64,
+        6915.595676804283f64,
+        1.73897741478041f64,
+    ),
+    (
+        0.0000000002652493159149398f64,
+        167283.73228406702f64,
+        4.368908494415634f64,
+    ),
+    (
+        0.00000000026521009395236005f64,
+        66567.43951791672f64,
+        4.602585631734724f64,
+    ),
+    (
+        0.0000000002556392117543416f64,
+        24073.506788094608f64,
+        2.0867131610126455f64,
+    ),
+    (
+        0.0000000002427613711649828f64,
+        16495.48504515506f64,
+        0.8852702231304443f64,
+    ),
+    (
+        0.0000000002378169864368426f64,
+        10550.419568419797f64,
+        3.700994107604459f64,
+    ),
+    (
+        0.0000000002353119426717168f64,
+        4933.147391305571f64,
+        5.763960746832419f64,
+    ),
+    (
+        0.0000000002334505314517929f64,
+        3094.7073697321393f64,
+        4.018965233321131f64,
+    ),
+    (
+        0.00000000022773972947400502f64,
+        16460.919613335525f64,
+        2.5993634232123113f64,
+    ),
+    (
+        0.00000000022731124552038786f64,
+        5608.037397377739f64,
+        5.320093231343914f64,
+    ),
+    (
+        0.00000000022411485204354028f64,
+        1475.815802393912f64,
+        5.840907811022561f64,
+    ),
+    (
+        0.0000000002217699171268261f64,
+        665.3401982784346f64,
+        5.181463513510621f64,
+    ),
+    (
+        0.0000000002203526875001595f64,
+        9394.631464722133f64,
+        0.7919986311420394f64,
+    ),
+    (
+        0.00000000021701933545569256f64,
+        19801.0721439728f64,
+        3.062994393904071f64,
+    ),
+    (
+        0.0000000002129854868772725f64,
+        5936.631682650752f64,
+        5.075829057374069f64,
+    ),
+    (
+        0.000000000210271725194941f64,
+        12600.449515877212f64,
+        0.6389360833911162f64,
+    ),
+    (
+        0.0000000002096033605236414f64,
+        33019.10794588507f64,
+        4.656586639634489f64,
+    ),
+    (
+        0.00000000020148391541710273f64,
+        11478.885123701704f64,
+        5.920477998118393f64,
+    ),
+    (
+        0.0000000002002994869280361f64,
+        25933.651927249797f64,
+        3.8191651279892285f64,
+    ),
+    (
+        0.0000000001925657532090815f64,
+        13120.273125469055f64,
+        2.0093970332799302f64,
+    ),
+    (
+        0.0000000001811984072441605f64,
+        11534.022516520876f64,
+        1.9487945521599015f64,
+    ),
+    (
+        0.00000000017852421331412555f64,
+        4488.041429082399f64,
+        4.7180230343698515f64,
+    ),
+    (
+        0.00000000017815785270066606f64,
+        29296.69142929169f64,
+        4.15493071811602f64,
+    ),
+    (
+        0.00000000017676655206109034f64,
+        47162.42748638769f64,
+        1.3631556311982942f64,
+    ),
+    (
+        0.00000000017670845402546587f64,
+        163096.19707083615f64,
+        2.9564889793171414f64,
+    ),
+    (
+        0.0000000001750279642784491f64,
+        23141.93753919426f64,
+        3.4400434424303628f64,
+    ),
+    (
+        0.00000000017093516700889551f64,
+        9684.581796781027f64,
+        5.7110528167419075f64,
+    ),
+    (
+        0.00000000017059298304751628f64,
+        4061.3917530199897f64,
+        3.463734026684771f64,
+    ),
+    (
+        0.00000000016884405976819558f64,
+        7668.921232989633f64,
+        5.146479564825289f64,
+    ),
+    (
+        0.00000000016874826938556986f64,
+        20994.998028884926f64,
+        2.1616404428108695f64,
+    ),
+    (
+        0.0000000001674627324716051f64,
+        16627.602846751626f64,
+        3.9999696344510367f64,
+    ),
+    (
+        0.00000000015703488505441431f64,
+        1692.7546804254303f64,
+        0.32711397802975606f64,
+    ),
+    (
+        0.00000000015689225767284095f64,
+        12448.871747163024f64,
+        5.693910930788657f64,
+    ),
+    (
+        0.0000000001517337552093175f64,
+        18449.30717540287f64,
+        0.5432827030628712f64,
+    ),
+    (
+        0.0000000001488539640869113f64,
+        1523.2788750688146f64,
+        5.869399886568285f64,
+    ),
+    (
+        0.00000000014664485499309603f64,
+        5257.063014160964f64,
+        4.13313066939384f64,
+    ),
+    (
+        0.0000000001450014548182282f64,
+        10660.101935665456f64,
+        5.443169724857481f64,
+    ),
+    (
+        0.00000000014490760180812813f64,
+        10771.68635240938f64,
+        1.9056994725041578f64,
+    ),
+    (
+        0.00000000014331230083946562f64,
+        13615.480847278295f64,
+        3.9913409286203527f64,
+    ),
+    (
+        0.00000000014207362239941172f64,
+        83783.46462456894f64,
+        2.9483046908916823f64,
+    ),
+    (
+        0.00000000014104971845970363f64,
+        5187.027203852109f64,
+        1.2962653570788103f64,
+    ),
+    (
+        0.00000000013379240344106408f64,
+        22806.408635097163f64,
+        2.8973817314935744f64,
+    ),
+    (
+        0.00000000012963995384052401f64,
+        3952.6460464460033f64,
+        4.462984896451967f64,
+    ),
+    (
+        0.00000000012941145167736423f64,
+        10024.707621629641f64,
+        2.134777864921453f64,
+    ),
+    (
+        0.0000000001276829338493874f64,
+        64472.437555973964f64,
+        6.039559306484242f64,
+    ),
+    (
+        0.00000000012750261526831068f64,
+        6546.619444434749f64,
+        1.2992725392792959f64,
+    ),
+    (
+        0.0000000001272356508745785f64,
+        19402.49180711813f64,
+        4.486067275741663f64,
+    ),
+    (
+        0.00000000012598878106425581f64,
+        4641.727686832401f64,
+        2.978907579915022f64,
+    ),
+    (
+        0.00000000012552393130318054f64,
+        1968.3436653640033f64,
+        2.3270780486575258f64,
+    ),
+    (
+        0.0000000001248908202743399f64,
+        36949.545999213566f64,
+        6.07692178573319f64,
+    ),
+    (
+        0.00000000012323062680545353f64,
+        22743.86697537429f64,
+        4.858753852676684f64,
+    ),
+    (
+        0.0000000001218986620719803f64,
+        4213.823426530029f64,
+        3.227916024748426f64,
+    ),
+    (
+        0.00000000011949951687550293f64,
+        5394.325728153222f64,
+        1.879405034320829f64,
+    ),
+    (
+        0.00000000011582053374585257f64,
+        2702.3066068612607f64,
+        5.443191029696559f64,
+    ),
+    (
+        0.00000000011476811959584601f64,
+        7577.118783910724f64,
+        3.9878508154021093f64,
+    ),
+    (
+        0.00000000011311914344280145f64,
+        6859.132175851149f64,
+        5.682831648754375f64,
+    ),
+    (
+        0.00000000011282998900113593f64,
+        4267.924974734313f64,
+        4.60031669998355f64,
+    ),
+    (
+        0.00000000010899654122936163f64,
+        174242.4928862953f64,
+        3.55330884167535f64,
+    ),
+    (
+        0.00000000010857562317867678f64,
+        16060.086760241244f64,
+        4.026830683073076f64,
+    ),
+    (
+        0.00000000010791176881148546f64,
+        4784.321294317959f64,
+        0.4783570785762684f64,
+    ),
+    (
+        0.00000000010738750026746225f64,
+        51092.876511640134f64,
+        2.781535292118164f64,
+    ),
+    (
+        0.00000000010702697643264531f64,
+        6603.943758225234f64,
+        5.461975513033521f64,
+    ),
+    (
+        0.00000000010525513441148026f64,
+        26084.159267861865f64,
+        5.449893622022089f64,
+    ),
+    (
+        0.00000000010481127957352997f64,
+        10242.737263245997f64,
+        2.654108945425721f64,
+    ),
+    (
+        0.0000000001031630521027585f64,
+        1500.5882114985761f64,
+        2.702733526667837f64,
+    ),
+    (
+        0.00000000010044659532217f64,
+        34519.69785877724f64,
+        0.427282195466926f64,
+    ),
+    (
+        0.00000000009915015737785823f64,
+        895.4841031621511f64,
+        2.6992269686981687f64,
+    ),
+    (
+        0.00000000009888210637631041f64,
+        12721.89552293589f64,
+        2.7599024960116614f64,
+    ),
+    (
+        0.00000000009822483832411398f64,
+        12962.248435605372f64,
+        5.90099824975562f64,
+    ),
+    (
+        0.00000000009625271263499358f64,
+        23937.48981804429f64,
+        3.9700185129895247f64,
+    ),
+    (
+        0.00000000009244335452687836f64,
+        1379.4122068692814f64,
+        0.9597263828001212f64,
+    ),
+    (
+        0.00000000009235985206539581f64,
+        2785.782554573641f64,
+        2.6977944032456747f64,
+    ),
+    (
+        0.00000000009129015393673438f64,
+        9817.03459140717f64,
+        2.0489945850249645f64,
+    ),
+    (
+        0.00000000008827114124668449f64,
+        4619.193274059233f64,
+        5.913352456475357f64,
+    ),
+    (
+        0.00000000008709058514182287f64,
+        166573.82288225612f64,
+        4.716185767421004f64,
+    ),
+    (
+        0.0000000000856759442668702f64,
+        1641.4643049913077f64,
+        1.0035890605280178f64,
+    ),
+    (
+        0.00000000008427468885671502f64,
+        327574.53953801445f64,
+        5.981918211158882f64,
+    ),
+    (
+        0.00000000008284568800481045f64,
+        4827.43173518181f64,
+        1.733133591919957f64,
+    ),
+    (
+        0.00000000007943720250057061f64,
+        24279.27988786176f64,
+        3.5017905174182764f64,
+    ),
+    (
+        0.00000000007897058307258495f64,
+        221995.02908035123f64,
+        2.4292182701259866f64,
+    ),
+    (
+        0.00000000007655343710130372f64,
+        40879.32379851961f64,
+        1.2278150256253946f64,
+    ),
+    (
+        0.00000000007560683872701922f64,
+        90279.83797246002f64,
+        1.970780712543009f64,
+    ),
+    (
+        0.00000000007485805422158903f64,
+        7883.405902177663f64,
+        0.48293888656462f64,
+    ),
+    (
+        0.00000000007414070728981364f64,
+        2638.317068573664f64,
+        0.7043442956656175f64,
+    ),
+    (
+        0.00000000007348780634589426f64,
+        4994.428725359294f64,
+        2.2458379914877766f64,
+    ),
+    (
+        0.00000000007325694600502049f64,
+        24356.929787825f64,
+        1.4626771575213642f64,
+    ),
+    (
+        0.00000000007319145503980038f64,
+        26735.94872764661f64,
+        4.2884455623541955f64,
+    ),
+    (
+        0.00000000007223853795409724f64,
+        172146.97884258552f64,
+        5.011245323206979f64,
+    ),
+    (
+        0.00000000007167204530170748f64,
+        22344.909282837874f64,
+        0.055208947287268786f64,
+    ),
+    (
+        0.00000000007036313687426727f64,
+        29862.632461372432f64,
+        5.145803347406095f64,
+    ),
+    (
+        0.00000000007003999854385325f64,
+        156137.39546711935f64,
+        3.7729554265515532f64,
+    ),
+    (
+        0.00000000007002760692399805f64,
+        13339.086260840284f64,
+        5.685524507173785f64,
+    ),
+    (
+        0.00000000006995197419054132f64,
+        39609.37299467675f64,
+        5.329954825361211f64,
+    ),
+    (
+        0.00000000006950362540959166f64,
+        4188.9300320830625f64,
+        4.774119980820346f64,
+    ),
+    (
+        0.00000000006818231956284767f64,
+        25687.188187735515f64,
+        0.4221009388903912f64,
+    ),
+    (
+        0.0000000000678881120179871f64,
+        1784.7613579512536f64,
+        1.279442755739242f64,
+    ),
+    (
+        0.0000000000671145208341903f64,
+        16523.927145682857f64,
+        2.63278114675882f64,
+    ),
+    (
+        0.00000000006707187113405868f64,
+        27707.275563506286f64,
+        4.9324921485723054f64,
+    ),
+    (
+        0.00000000006701613278056676f64,
+        4884.99777173019f64,
+        1.6594328678164776f64,
+    ),
+    (
+        0.0000000000659300837971479f64,
+        55022.87955881004f64,
+        4.206903089408381f64,
+    ),
+    (
+        0.0000000000652855157359903f64,
+        5281.541392962898f64,
+        0.3081197657763777f64,
+    ),
+    (
+        0.00000000006524751401486356f64,
+        688.6768496346126f64,
+        3.049776514033726f64,
+    ),
+    (
+        0.00000000006510495712584105f64,
+        2298.0500269954828f64,
+        0.7428586134495705f64,
+    ),
+    (
+        0.00000000006431115993487464f64,
+        97238.698133279f64,
+        1.153243814976802f64,
+    ),
+    (
+        0.00000000006394906650622585f64,
+        12318.094484223098f64,
+        5.249933703836742f64,
+    ),
+    (
+        0.0000000000638414510908019f64,
+        3659.371090617472f64,
+        2.4195179353153597f64,
+    ),
+    (
+        0.00000000006256172738582655f64,
+        8979.601365825038f64,
+        4.690337450519196f64,
+    ),
+    (
+        0.00000000006237147319979225f64,
+        305281.9895115574f64,
+        4.7867008039238055f64,
+    ),
+    (
+        0.00000000006166209404669129f64,
+        4956.9261226616f64,
+        2.4391735761940283f64,
+    ),
+    (
+        0.00000000006136344616523593f64,
+        26481.33125654867f64,
+        3.8231127401665335f64,
+    ),
+    (
+        0.00000000005938194889435208f64,
+        7009.377787849846f64,
+        0.22474524948977906f64,
+    ),
+    (
+        0.00000000005823568660820894f64,
+        11643.353775108548f64,
+        4.897375007622146f64,
+    ),
+    (
+        0.00000000005811427351343985f64,
+        5161.891287699085f64,
+        4.511473036739515f64,
+    ),
+    (
+        0.000000000057489505185799796f64,
+        19003.39673702945f64,
+        5.937590616842993f64,
+    ),
+    (
+        0.00000000005737416457474877f64,
+        21424.999025598925f64,
+        3.2705012172163603f64,
+    ),
+    (
+        0.000000000055979350351986906f64,
+        26879.855890643154f64,
+        5.799281244086316f64,
+    ),
+    (
+        0.00000000005471263216162425f64,
+        238004.5968928715f64,
+        3.668146143254128f64,
+    ),
+    (
+        0.00000000005395428941903235f64,
+        17157.066004824985f64,
+        4.202842032289479f64,
+    ),
+    (
+        0.0000000000537564892781057f64,
+        6880.330702540927f64,
+        2.3465439314105327f64,
+    ),
+    (
+        0.00000000005229563412140043f64,
+        12246.989691597302f64,
+        1.3044704085012049f64,
+    ),
+    (
+        0.000000000051998690397047154f64,
+        17360.12648980951f64,
+        5.229809838307517f64,
+    ),
+    (
+        0.00000000005183742389853089f64,
+        11303.6862451415f64,
+        2.6186696545848527f64,
+    ),
+    (
+        0.00000000005113570979074455f64,
+        95143.06188785793f64,
+        2.6102769855426287f64,
+    ),
+    (
+        0.000000000050688038313078246f64,
+        18778.35164933853f64,
+        5.749712201440012f64,
+    ),
+    (
+        0.000000000049885620441740994f64,
+        12663.349958406312f64,
+        0.6844616316785157f64,
+    ),
+    (
+        0.00000000004970441362840042f64,
+        1017.0668571575304f64,
+        1.614241967044085f64,
+    ),
+    (
+        0.000000000048388363051860314f64,
+        44810.02214086957f64,
+        2.6375876262612254f64,
+    ),
+    (
+        0.00000000004815935488557021f64,
+        11258.891680356406f64,
+        2.376790360026731f64,
+    ),
+    (
+        0.00000000004768534692735477f64,
+        16094.979006687121f64,
+        3.6331218294474352f64,
+    ),
+    (
+        0.00000000004691043298406519f64,
+        29026.09529621668f64,
+        0.84192467659803f64,
+    ),
+    (
+        0.00000000004663248294054199f64,
+        16943.58756911529f64,
+        0.5918177041599355f64,
+    ),
+    (
+        0.000000000046166443095930716f64,
+        33990.70470112765f64,
+        0.1794286802387465f64,
+    ),
+    (
+        0.00000000004525296598487375f64,
+        7318.758120133285f64,
+        1.111925082569421f64,
+    ),
+    (
+        0.00000000004499933833488646f64,
+        16834.85791220617f64,
+        5.547826580231446f64,
+    ),
+    (
+        0.00000000004258734179298369f64,
+        24601.76960199331f64,
+        4.342288697519979f64,
+    ),
+    (
+        0.00000000004232626507163184f64,
+        30667.120581689484f64,
+        5.7356346060988495f64,
+    ),
+    (
+        0.00000000004220705249263106f64,
+        7829.652437472889f64,
+        2.2890447848595117f64,
+    ),
+    (
+        0.000000000042205998476578707f64,
+        58953.45316499327f64,
+        5.627325858640526f64,
+    ),
+    (
+        0.000000000042028609444054335f64,
+        1842.817259777513f64,
+        5.564723721704166f64,
+    ),
+    (
+        0.000000000041896268170979166f64,
+        8144.872576613268f64,
+        1.5358323367380604f64,
+    ),
+    (
+        0.00000000004169241184207397f64,
+        4377.721106240822f64,
+        0.02595214960381434f64,
+    ),
+    (
+        0.0000000000415978159110363f64,
+        9974.84937063542f64,
+        4.587447034114792f64,
+    ),
+    (
+        0.00000000004157700935331093f64,
+        8850.775532635404f64,
+        6.143983035393764f64,
+    ),
+    (
+        0.00000000004144670739438073f64,
+        25288.529438307924f64,
+        1.837597740705586f64,
+    ),
+    (
+        0.000000000041383198331779075f64,
+        18815.11113518552f64,
+        5.870215769042413f64,
+    ),
+    (
+        0.0000000000408126855667223f64,
+        28628.792830436494f64,
+        2.240904771582653f64,
+    ),
+    (
+        0.00000000004081143204101506f64,
+        11555.74112422784f64,
+        5.118048531021576f64,
+    ),
+    (
+        0.00000000004034053350120286f64,
+        12626.171091273198f64,
+        3.1872569312904977f64,
+    ),
+    (
+        0.0000000000401126294352249f64,
+        11949.860140456853f64,
+        5.9420108060141725f64,
+    ),
+    (
+        0.00000000003995684573727893f64,
+        15142.044523462184f64,
+        1.7433871694308503f64,
+    ),
+    (
+        0.000000000039895233385298474f64,
+        6791.214613290484f64,
+        1.1478227794125575f64,
+    ),
+    (
+        0.000000000039611906734652785f64,
+        31414.97440687212f64,
+        6.127935860452372f64,
+    ),
+    (
+        0.00000000003904834801769929f64,
+        82577.18376829478f64,
+        2.6881923888896044f64,
+    ),
+    (
+        0.00000000003849914296897645f64,
+        29823.27992125393f64,
+        1.9993940845825042f64,
+    ),
+    (
+        0.0000000000380210342605257f64,
+        2808.3274863114193f64,
+        5.128194448684955f64,
+    ),
+    (
+        0.0000000000377374299362216f64,
+        14921.415307996213f64,
+        2.6958116968505137f64,
+    ),
+    (
+        0.00000000003586852805195312f64,
+        71959.50706279866f64,
+        0.8817502874401966f64,
+    ),
+    (
+        0.00000000003574348784760321f64,
+        3609.073318536719f64,
+        2.371558733666912f64,
+    ),
+    (
+        0.000000000035726738550587836f64,
+        239424.25867883756f64,
+        6.127896315161163f64,
+    ),
+    (
+        0.000000000034717118961107145f64,
+        11280.142704012595f64,
+        1.0063246622464528f64,
+    ),
+    (
+        0.00000000003363871054204056f64,
+        15509.527675778494f64,
+        5.378007666948877f64,
+    ),
+    (
+        0.000000000032822031939503196f64,
+        18609.957984089015f64,
+        1.0655691124308448f64,
+    ),
+    (
+        0.00000000003277909385251561f64,
+        28284.896580606994f64,
+        3.114936511464424f64,
+    ),
+    (
+        0.00000000003249800768791995f64,
+        4858.513284209499f64,
+        4.645437082585262f64,
+    ),
+    (
+        0.00000000003183663586627902f64,
+        10003.044828810589f64,
+        6.140217535483954f64,
+    ),
+    (
+        0.00000000003151727737458025f64,
+        161710.6304882699f64,
+        4.0782185641254465f64,
+    ),
+    (
+        0.00000000003122596032414483f64,
+        9368.743898707702f64,
+        2.947389612395511f64,
+    ),
+    (
+        0.00000000003098973810242257f64,
+        82937.88749759868f64,
+        2.94954347323311f64,
+    ),
+    (
+        0.00000000003049249912907518f64,
+        48739.96032825941f64,
+        4.064525208819214f64,
+    ),
+    (
+        0.000000000030416176614253634f64,
+        2065.5360269026623f64,
+        6.08212387121637f64,
+    ),
+    (
+        0.000000000030251164444503085f64,
+        11065.658034862821f64,
+        3.7886925930471866f64,
+    ),
+    (
+        0.00000000003009586842466874f64,
+        1669.9984348636822f64,
+        4.895578529021788f64,
+    ),
+    (
+        0.00000000002963298170259804f64,
+        3518.478290955322f64,
+        2.61122271764247f64,
+    ),
+    (
+        0.00000000002929247205857886f64,
+        3208.5155615079384f64,
+        3.182071993355247f64,
+    ),
+    (
+        0.00000000002917966801739959f64,
+        8579.411366269724f64,
+        6.06624084755611f64,
+    ),
+    (
+        0.000000000028712420727101205f64,
+        11147.095347731492f64,
+        3.389911818904267f64,
+    ),
+    (
+        0.00000000002840528406487138f64,
+        310145.2325662581f64,
+        5.424629574882687f64,
+    ),
+    (
+        0.00000000002816276301759276f64,
+        77374.86842793593f64,
+        0.9805234131686456f64,
+    ),
+    (
+        0.000000000028139859486724144f64,
+        137288.36566387763f64,
+        0.7563323527433441f64,
+    ),
+    (
+        0.000000000027838556910237567f64,
+        35050.1836537454f64,
+        5.230758877502997f64,
+    ),
+    (
+        0.00000000002728989161586156f64,
+        16309.67262054267f64,
+        4.779146771636091f64,
+    ),
+    (
+        0.000000000026260459997447142f64,
+        65857.72212549557f64,
+        1.797308753617252f64,
+    ),
+    (
+        0.000000000025684438009007436f64,
+        1716.6131023167945f64,
+        3.7712879603041203f64,
+    ),
+    (
+        0.0000000000239571641059817f64,
+        1864.7034505109855f64,
+        3.871744411723313f64,
+    ),
+    (
+        0.000000000021152216585505766f64,
+        8290.489049820251f64,
+        4.571401438309049f64,
+    ),
+];
+pub const DE440_T1: [(f64, f64, f64); 126] = [
+    (
+        0.0001013653462226695f64,
+        6283.075849991f64,
+        4.2484332908572835f64,
+    ),
+    (
+        0.0000021858809295944823f64,
+        5753.51680085326f64,
+        2.718810688758481f64,
+    ),
+    (
+        0.0000016931142596407968f64,
+        12566.151699983f64,
+        4.204770478294009f64,
+    ),
+    (
+        0.000001661689313742154f64,
+        6193.7919775918435f64,
+        1.1702898543816256f64,
+    ),
+    (
+        0.0000007579910487561731f64,
+        6069.851874135749f64,
+        2.4558649449636016f64,
+    ),
+    (
+        0.0000007301500979600821f64,
+        72.55810766253441f64,
+        4.883041514909369f64,
+    ),
+    (
+        0.0000006230640393709468f64,
+        5509.463044118354f64,
+        2.4617610426357888f64,
+    ),
+    (
+        0.0000005120378244642702f64,
+        6215.6781683253175f64,
+        3.111533300456265f64,
+    ),
+    (
+        0.0000005088107385038862f64,
+        773.317244742889f64,
+        1.1741662517550653f64,
+    ),
+    (
+        0.000000420649125432329f64,
+        529.5447370947952f64,
+        2.2117147568434086f64,
+    ),
+    (
+        0.00000036945140267513287f64,
+        5854.531971308308f64,
+        0.05651766046532564f64,
+    ),
+    (
+        0.0000003645824601692996f64,
+        100.5464457056575f64,
+        0.8583204483873564f64,
+    ),
+    (
+        0.0000003420200651837166f64,
+        5885.64942748901f64,
+        5.218204748046187f64,
+    ),
+    (
+        0.0000003197153318003818f64,
+        213.5957408099463f64,
+        3.8953989052032196f64,
+    ),
+    (
+        0.00000016520159750955622f64,
+        795.849659915031f64,
+        1.7694546201182249f64,
+    ),
+    (
+        0.000000134138375335558f64,
+        5571.91432590851f64,
+        4.944605096920325f64,
+    ),
+    (
+        0.00000010805411050389848f64,
+        1577.2493594792406f64,
+        3.6968893747561506f64,
+    ),
+    (
+        0.0000000932362248023939f64,
+        424.9799423135237f64,
+        1.7322348596040602f64,
+    ),
+    (
+        0.00000008933167789470915f64,
+        634.6995312515733f64,
+        4.293234699959128f64,
+    ),
+    (
+        0.00000007474483680849082f64,
+        158.45311080196254f64,
+        5.609088953819714f64,
+    ),
+    (
+        0.0000000720720985956408f64,
+        4693.342763627921f64,
+        3.33950967763435f64,
+    ),
+    (
+        0.00000006938724167848703f64,
+        6368.881503459625f64,
+        3.8476508681159616f64,
+    ),
+    (
+        0.0000000649177974533184f64,
+        6133.332437756037f64,
+        4.801238550263067f64,
+    ),
+    (
+        0.00000006256711911511865f64,
+        12036.135160094009f64,
+        5.993884534270135f64,
+    ),
+    (
+        0.00000004827561801747608f64,
+        4722.217603113384f64,
+        4.817936123668106f64,
+    ),
+    (
+        0.000000045094111421172627f64,
+        11790.240641519471f64,
+        2.641490910957608f64,
+    ),
+    (
+        0.000000036024328545617045f64,
+        559.9670866065469f64,
+        4.823121548743598f64,
+    ),
+    (
+        0.00000003407344778209481f64,
+        11506.794402325857f64,
+        3.8434164900473444f64,
+    ),
+    (
+        0.000000031890311797082143f64,
+        5486.169613919999f64,
+        4.1403772417124785f64,
+    ),
+    (
+        0.00000003030794971864886f64,
+        1058.9815887691673f64,
+        2.600807777025249f64,
+    ),
+    (
+        0.00000002955978369984792f64,
+        7860.396409029348f64,
+        1.268173707676442f64,
+    ),
+    (
+        0.000000026411195371459503f64,
+        12479.505956245144f64,
+        1.0644360433910758f64,
+    ),
+    (
+        0.0000000261047490251935f64,
+        3930.2361756815867f64,
+        6.154570044395511f64,
+    ),
+    (
+        0.000000023748187970013914f64,
+        5088.114604052036f64,
+        4.834988105545845f64,
+    ),
+    (
+        0.00000002286805103399468f64,
+        11766.01613833394f64,
+        2.754206824755063f64,
+    ),
+    (
+        0.00000002049712030111198f64,
+        284.52047955427145f64,
+        0.243814848801205f64,
+    ),
+    (
+        0.000000017589597664656243f64,
+        4749.303389144401f64,
+        5.669460629881352f64,
+    ),
+    (
+        0.000000016808234489837435f64,
+        1609.8074378489127f64,
+        5.599634586868509f64,
+    ),
+    (
+        0.0000000167758882625538f64,
+        2942.2403578728736f64,
+        0.26603848395562524f64,
+    ),
+    (
+        0.0000000165735213600191f64,
+        952.3154766280593f64,
+        5.175290049567387f64,
+    ),
+    (
+        0.000000015873338880973314f64,
+        12501.392146978616f64,
+        1.3385320912573953f64,
+    ),
+    (
+        0.000000014672320469744236f64,
+        6437.048465504848f64,
+        1.972721388518291f64,
+    ),
+    (
+        0.000000014293361892184778f64,
+        10977.31427169179f64,
+        0.9077444848907229f64,
+    ),
+    (
+        0.00000001362169088437841f64,
+        18849.076296968233f64,
+        3.221789435946271f64,
+    ),
+    (
+        0.000000013408378097546375f64,
+        5963.894067783111f64,
+        3.848024267578336f64,
+    ),
+    (
+        0.00000001266398935267876f64,
+        3154.980630832502f64,
+        3.2641283801728065f64,
+    ),
+    (
+        0.00000001134345363162197f64,
+        5223.797520796621f64,
+        0.6164262453190342f64,
+    ),
+    (
+        0.000000011090708621297308f64,
+        6678.978400686554f64,
+        1.2265965074685683f64,
+    ),
+    (
+        0.000000010815732966019715f64,
+        12166.625807851988f64,
+        2.067332391371979f64,
+    ),
+    (
+        0.000000010281738929613708f64,
+        5646.521288733669f64,
+        4.014430810736066f64,
+    ),
+    (
+        0.000000009553655070161504f64,
+        475.49429744932615f64,
+        3.3102718067520187f64,
+    ),
+    (
+        0.000000008513781343851868f64,
+        1194.5562179221981f64,
+        0.5531541492868883f64,
+    ),
+    (
+        0.000000008300312535246562f64,
+        849.1842004396035f64,
+        0.8302536643310056f64,
+    ),
+    (
+        0.000000007943210714199043f64,
+        12352.51660390827f64,
+        5.774968186467385f64,
+    ),
+    (
+        0.000000007723764284418243f64,
+        2146.1657959016284f64,
+        3.060401743007238f64,
+    ),
+    (
+        0.000000007590966581813117f64,
+        8429.683884639773f64,
+        5.37371192211112f64,
+    ),
+    (
+        0.000000007373629535516568f64,
+        7084.35490766809f64,
+        4.786575682498436f64,
+    ),
+    (
+        0.0000000072976799113113854f64,
+        5334.366627665137f64,
+        3.7165106326795914f64,
+    ),
+    (
+        0.00000000649116063873612f64,
+        6498.224920044302f64,
+        0.6079885869988608f64,
+    ),
+    (
+        0.000000006441997327153914f64,
+        3887.552214399182f64,
+        2.843263635216605f64,
+    ),
+    (
+        0.000000006267816442682996f64,
+        1748.5124952826177f64,
+        1.3386514817128157f64,
+    ),
+    (
+        0.000000005568043388674042f64,
+        2544.4487880884462f64,
+        4.8929571440472674f64,
+    ),
+    (
+        0.000000005492407654597905f64,
+        9438.0153101943f64,
+        0.7298056631171902f64,
+    ),
+    (
+        0.000000004554456716622621f64,
+        376.18610270547777f64,
+        5.683389046730054f64,
+    ),
+    (
+        0.000000004434005438270442f64,
+        2352.6445172705185f64,
+        5.778353622365836f64,
+    ),
+    (
+        0.000000004343468400262517f64,
+        23580.6052342309f64,
+        0.6954894174312766f64,
+    ),
+    (
+        0.00000000420552986803308f64,
+        11372.566010075658f64,
+        1.0208304887865873f64,
+    ),
+    (
+        0.000000003784552074811142f64,
+        2379.0085342767384f64,
+        5.685934948752058f64,
+    ),
+    (
+        0.000000003621160476379759f64,
+        7236.419007572211f64,
+        5.977164510266017f64,
+    ),
+    (
+        0.000000003540245804775979f64,
+        5120.456253665019f64,
+        0.2949284058544715f64,
+    ),
+    (
+        0.0000000034997445838238875f64,
+        3340.952422606849f64,
+        2.0997195901881875f64,
+    ),
+    (
+        0.000000003285714919995113f64,
+        10213.026905774695f64,
+        3.120172332346202f64,
+    ),
+    (
+        0.000000003248534247507968f64,
+        5435.631325343933f64,
+        4.0189811110660045f64,
+    ),
+    (
+        0.0000000031286631582812802f64,
+        77713.76933049229f64,
+        0.48095878100441447f64,
+    ),
+    (
+        0.000000002919350674044164f64,
+        6701.551602570149f64,
+        4.811905435796492f64,
+    ),
+    (
+        0.0000000028802411412716505f64,
+        14143.305794273772f64,
+        1.3999350694933237f64,
+    ),
+    (
+        0.0000000028326199811772476f64,
+        17260.25274386326f64,
+        4.599896065330274f64,
+    ),
+    (
+        0.0000000026240336556409698f64,
+        11926.300335277607f64,
+        0.8560624738562213f64,
+    ),
+    (
+        0.000000002618084180905895f64,
+        7632.765939104469f64,
+        4.92317819971596f64,
+    ),
+    (
+        0.0000000025976491855199517f64,
+        10447.480817075244f64,
+        3.337861308046395f64,
+    ),
+    (
+        0.0000000024871675122504024f64,
+        1350.3799266874958f64,
+        2.5782348375257773f64,
+    ),
+    (
+        0.0000000024389337232346294f64,
+        4164.728376945397f64,
+        0.3075307812816675f64,
+    ),
+    (
+        0.0000000023036373843971487f64,
+        8634.091280123639f64,
+        4.084073006361282f64,
+    ),
+    (
+        0.0000000022270613346402284f64,
+        4911.2612005720985f64,
+        4.520588241135786f64,
+    ),
+    (
+        0.0000000021947418260406796f64,
+        9917.184767249593f64,
+        2.833873722983262f64,
+    ),
+    (
+        0.0000000021835442954011333f64,
+        4592.598387885588f64,
+        3.0632827821318798f64,
+    ),
+    (
+        0.0000000021371424657256327f64,
+        16730.480944993793f64,
+        4.652043650988632f64,
+    ),
+    (
+        0.0000000019030506801760357f64,
+        18072.725938274718f64,
+        2.959810737634869f64,
+    ),
+    (
+        0.0000000018982883469842736f64,
+        6014.008886991476f64,
+        4.2684255280148795f64,
+    ),
+    (
+        0.0000000018897716063774635f64,
+        11710.100817095446f64,
+        4.421563644274661f64,
+    ),
+    (
+        0.0000000018389828375859168f64,
+        6836.24569914315f64,
+        3.3198630340576667f64,
+    ),
+    (
+        0.0000000018049294677219392f64,
+        3127.527032541739f64,
+        0.41634804772237993f64,
+    ),
+    (
+        0.0000000016643515932322304f64,
+        3637.473478413115f64,
+        5.229171503391354f64,
+    ),
+    (
+        0.0000000016147569857100634f64,
+        1261.5168598396074f64,
+        0.7028643945371992f64,
+    ),
+    (
+        0.000000001578208187786428f64,
+        11478.885123701704f64,
+        1.5810183264877742f64,
+    ),
+    (
+        0.0000000014440670170554493f64,
+        8664.310215353033f64,
+        4.443603129901682f64,
+    ),
+    (
+        0.000000001398360865366783f64,
+        1475.815802393912f64,
+        0.8446791857808728f64,
+    ),
+    (
+        0.000000001395520998139576f64,
+        13521.261915474017f64,
+        5.153220547713337f64,
+    ),
+    (
+        0.000000001384969453719461f64,
+        15720.879605420676f64,
+        1.0085299503119447f64,
+    ),
+    (
+        0.000000001353656708142894f64,
+        10550.419568419797f64,
+        5.4569085332549445f64,
+    ),
+    (
+        0.0000000013524415414680537f64,
+        4292.435816716697f64,
+        2.223637986890602f64,
+    ),
+    (
+        0.0000000012146236181809098f64,
+        9394.631464722133f64,
+        5.935358499370783f64,
+    ),
+    (
+        0.000000001211629479458147f64,
+        11088.76937246984f64,
+        2.5925286201031743f64,
+    ),
+    (
+        0.0000000011868537236657277f64,
+        12417.397439979086f64,
+        3.9990435302120066f64,
+    ),
+    (
+        0.0000000011435058576295203f64,
+        8031.335868035099f64,
+        0.4958935980644208f64,
+    ),
+    (
+        0.000000001122946482836449f64,
+        14711.765217062128f64,
+        4.289637646505165f64,
+    ),
+    (
+        0.000000001107538522676043f64,
+        12139.409769383701f64,
+        0.8868223064368332f64,
+    ),
+    (
+        0.0000000011073525008303416f64,
+        17298.699551811693f64,
+        3.293070054256164f64,
+    ),
+    (
+        0.00000000095466656936301f64,
+        4994.428725359294f64,
+        4.669210372047612f64,
+    ),
+    (
+        0.0000000008893181619630005f64,
+        13367.925867362903f64,
+        5.6406373975660555f64,
+    ),
+    (
+        0.0000000008808122503433187f64,
+        7479.286661341033f64,
+        0.1752202307833024f64,
+    ),
+    (
+        0.0000000008623044196526694f64,
+        71430.76697678774f64,
+        2.6469697553790557f64,
+    ),
+    (
+        0.0000000008553819099373711f64,
+        10575.297775271236f64,
+        1.101402205584329f64,
+    ),
+    (
+        0.0000000008424534833917629f64,
+        18048.275415562846f64,
+        2.5390853260495416f64,
+    ),
+    (
+        0.0000000008212251808540497f64,
+        13916.899325436862f64,
+        4.231047091379667f64,
+    ),
+    (
+        0.0000000007965435192485596f64,
+        3739.093269155134f64,
+        1.0388621271097171f64,
+    ),
+    (
+        0.0000000007485336421520413f64,
+        895.4841031621511f64,
+        5.646207294283366f64,
+    ),
+    (
+        0.0000000007445974866363415f64,
+        18319.18404254922f64,
+        6.098992280105348f64,
+    ),
+    (
+        0.0000000006514677187081808f64,
+        13098.061812721071f64,
+        1.7424966850667596f64,
+    ),
+    (
+        0.0000000006138114825953944f64,
+        10873.908529492408f64,
+        4.5525303972539115f64,
+    ),
+    (
+        0.0000000005888778597118987f64,
+        11015.70082191368f64,
+        2.755797954872864f64,
+    ),
+    (
+        0.0000000005607069446056418f64,
+        11607.815822003391f64,
+        5.676237900674782f64,
+    ),
+    (
+        0.0000000004666173178646235f64,
+        17654.24585614052f64,
+        6.134894952932261f64,
+    ),
+    (
+        0.000000000352816561583614f64,
+        11856.361470976291f64,
+        1.3116151980966702f64,
+    ),
+    (
+        0.00000000032107541368993594f64,
+        2702.3066068612607f64,
+        4.307373246111597f64,
+    ),
+    (
+        0.00000000029207177590601294f64,
+        16859.085471241324f64,
+        4.806072399840539f64,
+    ),
+];
+pub const DE440_T2: [(f64, f64, f64); 15] = [
+    (
+        0.000006000749785577652f64,
+        6283.075849991f64,
+        5.689466531698971f64,
+    ),
+    (
+        0.00000040970442428341275f64,
+        1577.2493594792406f64,
+        4.090102568394676f64,
+    ),
+    (
+        0.0000003827537382159077f64,
+        6437.048465504848f64,
+        5.694969154823226f64,
+    ),
+    (
+        0.0000001855605855028909f64,
+        5223.797520796621f64,
+        2.7152854138194393f64,
+    ),
+    (
+        0.00000011061888295553133f64,
+        5435.631325343933f64,
+        1.9528576849747334f64,
+    ),
+    (
+        0.00000003998506893413276f64,
+        952.3154766280593f64,
+        1.068126786190401f64,
+    ),
+    (
+        0.000000035981251675423086f64,
+        1058.9815887691673f64,
+        2.389598774051878f64,
+    ),
+    (
+        0.00000003175303926578383f64,
+        10977.31427169179f64,
+        1.034583852469558f64,
+    ),
+    (
+        0.000000021396660356301574f64,
+        7084.35490766809f64,
+        0.6140690854970955f64,
+    ),
+    (
+        0.000000015652546263842586f64,
+        77713.76933049229f64,
+        3.6396698987367047f64,
+    ),
+    (
+        0.000000014209935282516435f64,
+        12036.135160094009f64,
+        0.8682298353946356f64,
+    ),
+    (
+        0.000000013748437629376902f64,
+        7236.419007572211f64,
+        1.749734385816237f64,
+    ),
+    (
+        0.000000011946663142228046f64,
+        10213.026905774695f64,
+        3.644697035045339f64,
+    ),
+    (
+        0.000000008115754547798087f64,
+        10447.480817075244f64,
+        0.3799892636684737f64,
+    ),
+    (
+        0.000000007294551656825072f64,
+        17260.25274386326f64,
+        3.2296143939763198f64,
+    ),
+];
@@ -14,6 +14,7 @@
     Earth Orientation Parameters from an IERS CSV file.
 */
 
+use std::cell::Cell;
 use std::iter::zip;
 use thiserror::Error;
 
@@ -46,6 +47,8 @@ pub trait DeltaUt1TaiProvider {
     fn delta_tai_ut1(&self, ut1: TimeDelta) -> Result<TimeDelta, Self::Error>;
 }
 
+pub mod cache;
+
 /// Error type returned when [DeltaUt1Tai] instantiation fails.
 #[derive(Clone, Debug, Error)]
 pub enum DeltaUt1TaiError {
@@ -53,6 +56,10 @@ pub enum DeltaUt1TaiError {
     Csv(#[from] ParseFinalsCsvError),
     #[error(transparent)]
     Series(#[from] SeriesError),
+    #[error("failed to access the IERS data cache: {0}")]
+    Io(String),
+    #[error("failed to download IERS data: {0}")]
+    Download(String),
 }
 
 /// Error type indicating that an input date to [DeltaUt1Tai] was outside the range of available
@@ -129,29 +136,44 @@ impl DeltaUt1Tai {
     }
 }
 
-impl DeltaUt1TaiProvider for DeltaUt1Tai {
-    type Error = ExtrapolatedDeltaUt1Tai;
+impl DeltaUt1Tai {
+    /// Returns a [DeltaUt1TaiProvider] which remembers the position of the last lookup in the
+    /// EOP table, such that monotonic sequences of lookups avoid repeated binary searches.
+    pub fn cursor(&self) -> DeltaUt1TaiCursor<'_> {
+        DeltaUt1TaiCursor {
+            provider: self,
+            cursor: Cell::new(0),
+        }
+    }
 
-    fn delta_ut1_tai(&self, tai: TimeDelta) -> Result<TimeDelta, Self::Error> {
+    fn delta_ut1_tai_with(
+        &self,
+        tai: TimeDelta,
+        interpolate: impl Fn(f64) -> f64,
+    ) -> Result<TimeDelta, ExtrapolatedDeltaUt1Tai> {
         let seconds = tai.seconds_since_j2000();
         let (t0, _) = self.0.first();
         let (tn, _) = self.0.last();
-        let val = self.0.interpolate(seconds);
+        let val = interpolate(seconds);
         if seconds < t0 || seconds > tn {
             return Err(ExtrapolatedDeltaUt1Tai::new(t0, tn, seconds, val));
         }
         Ok(TimeDelta::try_from_decimal_seconds(val).unwrap())
     }
 
-    fn delta_tai_ut1(&self, ut1: TimeDelta) -> Result<TimeDelta, Self::Error> {
+    fn delta_tai_ut1_with(
+        &self,
+        ut1: TimeDelta,
+        interpolate: impl Fn(f64) -> f64,
+    ) -> Result<TimeDelta, ExtrapolatedDeltaUt1Tai> {
         let seconds = ut1.seconds_since_j2000();
         let (t0, _) = self.0.first();
         let (tn, _) = self.0.last();
         // Use the UT1 offset as an initial guess even though the table is based on TAI
-        let mut val = self.0.interpolate(seconds);
+        let mut val = interpolate(seconds);
         // Interpolate again with the adjusted offsets
         for _ in 0..2 {
-            val = self.0.interpolate(seconds - val);
+            val = interpolate(seconds - val);
         }
         if seconds < t0 || seconds > tn {
             return Err(ExtrapolatedDeltaUt1Tai::new(t0, tn, seconds, -val));
@@ -160,6 +182,49 @@ impl DeltaUt1TaiProvider for DeltaUt1Tai {
     }
 }
 
+impl DeltaUt1TaiProvider for DeltaUt1Tai {
+    type Error = ExtrapolatedDeltaUt1Tai;
+
+    fn delta_ut1_tai(&self, tai: TimeDelta) -> Result<TimeDelta, Self::Error> {
+        self.delta_ut1_tai_with(tai, |seconds| self.0.interpolate(seconds))
+    }
+
+    fn delta_tai_ut1(&self, ut1: TimeDelta) -> Result<TimeDelta, Self::Error> {
+        self.delta_tai_ut1_with(ut1, |seconds| self.0.interpolate(seconds))
+    }
+}
+
+/// A [DeltaUt1TaiProvider] returned by [DeltaUt1Tai::cursor], which yields the same results as
+/// [DeltaUt1Tai] but is faster for monotonic sequences of lookups.
+#[derive(Clone, Debug)]
+pub struct DeltaUt1TaiCursor<'a> {
+    provider: &'a DeltaUt1Tai,
+    cursor: Cell<usize>,
+}
+
+impl DeltaUt1TaiCursor<'_> {
+    fn interpolate(&self, seconds: f64) -> f64 {
+        let mut cursor = self.cursor.get();
+        let val = self.provider.0.interpolate_from(seconds, &mut cursor);
+        self.cursor.set(cursor);
+        val
+    }
+}
+
+impl DeltaUt1TaiProvider for DeltaUt1TaiCursor<'_> {
+    type Error = ExtrapolatedDeltaUt1Tai;
+
+    fn delta_ut1_tai(&self, tai: TimeDelta) -> Result<TimeDelta, Self::Error> {
+        self.provider
+            .delta_ut1_tai_with(tai, |seconds| self.interpolate(seconds))
+    }
+
+    fn delta_tai_ut1(&self, ut1: TimeDelta) -> Result<TimeDelta, Self::Error> {
+        self.provider
+            .delta_tai_ut1_with(ut1, |seconds| self.interpolate(seconds))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::OnceLock;
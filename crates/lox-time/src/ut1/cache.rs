@@ -0,0 +1,399 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/*!
+    Caching of IERS Earth Orientation Parameters for [DeltaUt1Tai].
+
+    [DeltaUt1Tai::from_cache_with] refreshes a cached IERS finals CSV from a reader supplied by the
+    caller, e.g. the body of an HTTP response for [FINALS_CSV_URL], such that default builds do not
+    go online.
+
+    With the opt-in `iers-download` feature, [DeltaUt1Tai::from_cache] downloads the CSV from
+    [FINALS_CSV_URL] into the directory returned by [cache_dir]. The download runs the system's
+    `curl` executable, which must be on the `PATH`, such that this crate does not depend on an
+    HTTP or TLS stack.
+*/
+
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::utc::LeapSecondsProvider;
+
+use super::{DeltaUt1Tai, DeltaUt1TaiError};
+
+/// The file name of the cached IERS finals CSV.
+pub const FINALS_CSV: &str = "finals2000A.all.csv";
+
+/// The URL from which the IERS finals CSV is downloaded.
+pub const FINALS_CSV_URL: &str = "https://datacenter.iers.org/data/csv/finals2000A.all.csv";
+
+impl DeltaUt1Tai {
+    /// Instantiates a new [DeltaUt1Tai] provider from the IERS finals CSV cached under the
+    /// directory returned by [cache_dir], downloading it from [FINALS_CSV_URL] if it is missing or
+    /// older than `max_age`.
+    ///
+    /// See [DeltaUt1Tai::from_cache_with] for how failed downloads are handled.
+    ///
+    /// # Errors
+    ///
+    /// - [DeltaUt1TaiError::Io] if the cache directory cannot be determined or created.
+    /// - [DeltaUt1TaiError::Download] if the download fails and no cached data is available.
+    /// - [DeltaUt1TaiError::Csv] if no valid cached data is available.
+    #[cfg(feature = "iers-download")]
+    pub fn from_cache(max_age: Duration) -> Result<Self, DeltaUt1TaiError> {
+        let path = cache_dir()?.join(FINALS_CSV);
+        Self::from_cache_with(
+            path,
+            max_age,
+            &crate::utc::leap_seconds::BuiltinLeapSeconds,
+            || download(FINALS_CSV_URL),
+        )
+    }
+
+    /// Instantiates a new [DeltaUt1Tai] provider from the IERS finals CSV cached at `path`, which
+    /// is refreshed from the reader returned by `fetch` if it is missing or older than `max_age`.
+    ///
+    /// Fetched data is written to a temporary file next to `path` and only replaces the cache
+    /// after it has been read completely and parsed successfully, such that a corrupted or
+    /// partial download never overwrites a good cache. If refreshing fails, the stale cache is
+    /// used instead.
+    ///
+    /// # Errors
+    ///
+    /// - [DeltaUt1TaiError::Io] if the cache cannot be written.
+    /// - [DeltaUt1TaiError::Download] if `fetch` or reading from the returned reader fails and no
+    ///   cached data is available.
+    /// - [DeltaUt1TaiError::Csv] if neither the fetched nor the cached data can be parsed.
+    pub fn from_cache_with<P, R, F>(
+        path: P,
+        max_age: Duration,
+        ls: &impl LeapSecondsProvider,
+        fetch: F,
+    ) -> Result<Self, DeltaUt1TaiError>
+    where
+        P: AsRef<Path>,
+        R: Read,
+        F: FnOnce() -> Result<R, String>,
+    {
+        let path = path.as_ref();
+        if is_fresh(path, max_age) {
+            return Self::new(path, ls);
+        }
+        match refresh(path, ls, fetch) {
+            Ok(provider) => Ok(provider),
+            Err(err) if path.exists() => Self::new(path, ls).map_err(|_| err),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Returns the directory in which Lox caches downloaded data.
+///
+/// The directory is `$LOX_CACHE_DIR` if set, and otherwise `lox` within the platform's cache
+/// directory, i.e. `$XDG_CACHE_HOME` or `~/.cache` on Unix, `~/Library/Caches` on macOS, and
+/// `%LOCALAPPDATA%` on Windows.
+///
+/// # Errors
+///
+/// - [DeltaUt1TaiError::Io] if none of the relevant environment variables are set.
+pub fn cache_dir() -> Result<PathBuf, DeltaUt1TaiError> {
+    let var = |key: &str| {
+        std::env::var_os(key)
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+    };
+    if let Some(dir) = var("LOX_CACHE_DIR") {
+        return Ok(dir);
+    }
+    let base = if cfg!(windows) {
+        var("LOCALAPPDATA")
+    } else if cfg!(target_os = "macos") {
+        var("HOME").map(|home| home.join("Library").join("Caches"))
+    } else {
+        var("XDG_CACHE_HOME").or_else(|| var("HOME").map(|home| home.join(".cache")))
+    };
+    base.map(|dir| dir.join("lox"))
+        .ok_or_else(|| DeltaUt1TaiError::Io("could not determine the cache directory".to_string()))
+}
+
+/// Starts downloading `url` with the system's `curl` executable.
+#[cfg(feature = "iers-download")]
+fn download(url: &str) -> Result<Download, String> {
+    use std::process::{Command, Stdio};
+
+    Command::new("curl")
+        .args(["--fail", "--silent", "--show-error", "--location", url])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map(Download)
+        .map_err(|err| format!("could not run `curl`: {err}"))
+}
+
+/// The output of a running `curl` process.
+///
+/// A failed transfer is reported as a read error once the output is exhausted, such that it is
+/// never mistaken for a complete download.
+#[cfg(feature = "iers-download")]
+struct Download(std::process::Child);
+
+#[cfg(feature = "iers-download")]
+impl Read for Download {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let stdout = self.0.stdout.as_mut().expect("stdout should be piped");
+        let n = stdout.read(buf)?;
+        if n == 0 && !buf.is_empty() {
+            let status = self.0.wait()?;
+            if !status.success() {
+                let mut stderr = String::new();
+                if let Some(mut pipe) = self.0.stderr.take() {
+                    pipe.read_to_string(&mut stderr)?;
+                }
+                return Err(io::Error::other(format!(
+                    "`curl` failed with {status}: {}",
+                    stderr.trim()
+                )));
+            }
+        }
+        Ok(n)
+    }
+}
+
+#[cfg(feature = "iers-download")]
+impl Drop for Download {
+    fn drop(&mut self) {
+        // Abort unfinished downloads, e.g. after a failed write to the cache
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+fn is_fresh(path: &Path, max_age: Duration) -> bool {
+    fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age <= max_age)
+}
+
+fn refresh<R, F>(
+    path: &Path,
+    ls: &impl LeapSecondsProvider,
+    fetch: F,
+) -> Result<DeltaUt1Tai, DeltaUt1TaiError>
+where
+    R: Read,
+    F: FnOnce() -> Result<R, String>,
+{
+    let io_err = |err: io::Error| DeltaUt1TaiError::Io(err.to_string());
+    let mut reader = fetch().map_err(DeltaUt1TaiError::Download)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_err)?;
+    }
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(format!(".{}.tmp", std::process::id()));
+    let tmp = PathBuf::from(tmp);
+    let mut file = File::create(&tmp).map_err(io_err)?;
+    let result = copy(&mut reader, &mut file).and_then(|_| DeltaUt1Tai::new(&tmp, ls));
+    drop(file);
+    let provider = match result {
+        Ok(provider) => provider,
+        Err(err) => {
+            let _ = fs::remove_file(&tmp);
+            return Err(err);
+        }
+    };
+    // Renaming within the same directory atomically replaces the cache
+    fs::rename(&tmp, path).map_err(io_err)?;
+    Ok(provider)
+}
+
+/// Copies all data from `reader` to `file`, distinguishing failed reads, i.e. interrupted
+/// downloads, from failed writes.
+fn copy(reader: &mut impl Read, file: &mut File) -> Result<(), DeltaUt1TaiError> {
+    let mut buf = [0u8; 8192];
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => {
+                return file
+                    .sync_all()
+                    .map_err(|err| DeltaUt1TaiError::Io(err.to_string()));
+            }
+            Ok(n) => n,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(DeltaUt1TaiError::Download(err.to_string())),
+        };
+        file.write_all(&buf[..n])
+            .map_err(|err| DeltaUt1TaiError::Io(err.to_string()))?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::io::Cursor;
+
+    use crate::test_helpers::data_dir;
+    use crate::utc::leap_seconds::BuiltinLeapSeconds;
+
+    use super::*;
+
+    const DAY: Duration = Duration::from_secs(86400);
+
+    fn cache_path(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("lox-ut1-cache-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join(FINALS_CSV)
+    }
+
+    fn finals() -> Vec<u8> {
+        fs::read(data_dir().join(FINALS_CSV)).unwrap()
+    }
+
+    fn expected() -> DeltaUt1Tai {
+        DeltaUt1Tai::new(data_dir().join(FINALS_CSV), &BuiltinLeapSeconds).unwrap()
+    }
+
+    #[test]
+    fn test_from_cache_downloads_missing_cache() {
+        let path = cache_path("missing");
+        let provider = DeltaUt1Tai::from_cache_with(&path, DAY, &BuiltinLeapSeconds, || {
+            Ok(Cursor::new(finals()))
+        })
+        .unwrap();
+        assert_eq!(provider, expected());
+        assert_eq!(fs::read(&path).unwrap(), finals());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_from_cache_uses_fresh_cache() {
+        let path = cache_path("fresh");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, finals()).unwrap();
+        let fetched = Cell::new(false);
+        let provider = DeltaUt1Tai::from_cache_with(&path, DAY, &BuiltinLeapSeconds, || {
+            fetched.set(true);
+            Err::<&[u8], _>("offline".to_string())
+        })
+        .unwrap();
+        assert!(!fetched.get());
+        assert_eq!(provider, expected());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_from_cache_keeps_good_cache_on_corrupted_download() {
+        let path = cache_path("corrupted");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, finals()).unwrap();
+        let partial = finals()[..1000].to_vec();
+        let provider =
+            DeltaUt1Tai::from_cache_with(&path, Duration::ZERO, &BuiltinLeapSeconds, || {
+                Ok(partial.as_slice())
+            })
+            .unwrap();
+        assert_eq!(provider, expected());
+        assert_eq!(fs::read(&path).unwrap(), finals());
+        let entries = fs::read_dir(path.parent().unwrap()).unwrap().count();
+        assert_eq!(entries, 1);
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    struct Interrupted;
+
+    impl Read for Interrupted {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::new(
+                io::ErrorKind::ConnectionReset,
+                "connection reset",
+            ))
+        }
+    }
+
+    #[test]
+    fn test_from_cache_keeps_good_cache_on_interrupted_download() {
+        let path = cache_path("interrupted");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, finals()).unwrap();
+        let data = finals();
+        let provider =
+            DeltaUt1Tai::from_cache_with(&path, Duration::ZERO, &BuiltinLeapSeconds, || {
+                Ok(data.as_slice().chain(Interrupted))
+            })
+            .unwrap();
+        assert_eq!(provider, expected());
+        assert_eq!(fs::read(&path).unwrap(), finals());
+        let entries = fs::read_dir(path.parent().unwrap()).unwrap().count();
+        assert_eq!(entries, 1);
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_from_cache_interrupted_download_without_cache() {
+        let path = cache_path("interrupted-missing");
+        let actual =
+            DeltaUt1Tai::from_cache_with(&path, DAY, &BuiltinLeapSeconds, || Ok(Interrupted));
+        assert!(
+            matches!(actual, Err(DeltaUt1TaiError::Download(msg)) if msg == "connection reset")
+        );
+        assert!(!path.exists());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_from_cache_uses_stale_cache_on_failed_download() {
+        let path = cache_path("stale");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, finals()).unwrap();
+        let provider =
+            DeltaUt1Tai::from_cache_with(&path, Duration::ZERO, &BuiltinLeapSeconds, || {
+                Err::<&[u8], _>("offline".to_string())
+            })
+            .unwrap();
+        assert_eq!(provider, expected());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[cfg(feature = "iers-download")]
+    #[test]
+    fn test_download() {
+        let url = format!("file://{}", data_dir().join(FINALS_CSV).display());
+        let mut data = Vec::new();
+        download(&url).unwrap().read_to_end(&mut data).unwrap();
+        assert_eq!(data, finals());
+    }
+
+    #[cfg(feature = "iers-download")]
+    #[test]
+    fn test_from_cache_failed_curl_download_without_cache() {
+        let path = cache_path("curl");
+        let url = format!("file://{}", path.with_extension("missing").display());
+        let actual =
+            DeltaUt1Tai::from_cache_with(&path, DAY, &BuiltinLeapSeconds, || download(&url));
+        assert!(
+            matches!(actual, Err(DeltaUt1TaiError::Download(msg)) if msg.starts_with("`curl` failed"))
+        );
+        assert!(!path.exists());
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_from_cache_failed_download_without_cache() {
+        let path = cache_path("failed");
+        let actual = DeltaUt1Tai::from_cache_with(&path, DAY, &BuiltinLeapSeconds, || {
+            Err::<&[u8], _>("offline".to_string())
+        });
+        assert!(matches!(actual, Err(DeltaUt1TaiError::Download(msg)) if msg == "offline"));
+        assert!(!path.exists());
+    }
+}
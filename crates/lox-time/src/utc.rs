@@ -22,8 +22,12 @@ use thiserror::Error;
 
 use crate::Time;
 use crate::calendar_dates::{CalendarDate, Date, DateError};
+use crate::constants::i64::{SECONDS_PER_HOUR, SECONDS_PER_MINUTE};
+use crate::constants::julian_dates::SECONDS_BETWEEN_UNIX_EPOCH_AND_J2000;
 use crate::deltas::{TimeDelta, ToDelta};
+use crate::formatting;
 use crate::julian_dates::JulianDate;
+use crate::subsecond::Subsecond;
 use crate::time_of_day::{CivilTime, TimeOfDay, TimeOfDayError};
 use crate::time_scales::Tai;
 
@@ -32,6 +36,8 @@ use self::leap_seconds::BuiltinLeapSeconds;
 pub mod leap_seconds;
 pub mod transformations;
 
+const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+
 /// Implementers of `LeapSecondsProvider` provide the offset between TAI and UTC in leap seconds at
 /// an instant in either time scale.
 pub trait LeapSecondsProvider {
@@ -61,6 +67,8 @@ pub enum UtcError {
     UtcUndefined,
     #[error("invalid ISO string `{0}`")]
     InvalidIsoString(String),
+    #[error("Unix timestamp `{0}` is not representable as UTC")]
+    InvalidUnixTimestamp(String),
 }
 
 /// Coordinated Universal Time.
@@ -101,9 +109,13 @@ impl Utc {
     /// Constructs a new [Utc] instance from the given ISO 8601 string, with leap second validation
     /// provided by the [LeapSecondsProvider].
     ///
+    /// The string may end in `Z`, ` UTC`, or a UTC offset of the form `±hh:mm`, `±hhmm`, or `±hh`,
+    /// in which case the time is normalised to UTC.
+    ///
     /// # Errors
     ///
-    /// - [UtcError::InvalidIsoString] if the input string is not a valid ISO 8601 string.
+    /// - [UtcError::InvalidIsoString] if the input string is not a valid ISO 8601 string or
+    ///   contains an invalid UTC offset.
     /// - [UtcError::DateError] if the date component of the string is invalid.
     /// - [UtcError::TimeError] if the time component of the string is invalid.
     /// - [UtcError::UtcUndefined] if the date is before 1960-01-01.
@@ -113,9 +125,8 @@ impl Utc {
         iso: &str,
         provider: &T,
     ) -> Result<Self, UtcError> {
-        let _ = iso.strip_suffix('Z');
-
-        let Some((date, time_and_scale)) = iso.split_once('T') else {
+        let Some((date, time_and_scale)) = iso.strip_suffix('Z').unwrap_or(iso).split_once('T')
+        else {
             return Err(UtcError::InvalidIsoString(iso.to_owned()));
         };
 
@@ -128,10 +139,36 @@ impl Utc {
             return Err(UtcError::InvalidIsoString(iso.to_owned()));
         }
 
+        let (time, offset) = match time.find(['+', '-']) {
+            Some(i) => {
+                let offset = parse_utc_offset(&time[i..])
+                    .ok_or_else(|| UtcError::InvalidIsoString(iso.to_owned()))?;
+                (&time[..i], Some(offset))
+            }
+            None => (time, None),
+        };
+
         let date: Date = date.parse()?;
         let time: TimeOfDay = time.parse()?;
 
-        Utc::new(date, time, provider)
+        let Some(offset) = offset else {
+            return Utc::new(date, time, provider);
+        };
+
+        // Normalise to UTC based on the civil time, while preserving a leap second which is
+        // shifted along with the offset
+        let is_leap_second = time.second() == 60;
+        let time = if is_leap_second {
+            TimeOfDay::new(time.hour(), time.minute(), 59)?.with_subsecond(time.subsecond())
+        } else {
+            time
+        };
+        let mut utc = Utc::from_delta(Utc { date, time }.to_delta() - offset);
+        if is_leap_second {
+            utc.time =
+                TimeOfDay::new(utc.hour(), utc.minute(), 60)?.with_subsecond(utc.time.subsecond());
+        }
+        Utc::new(utc.date, utc.time, provider)
     }
 
     /// Constructs a new [Utc] instance from the given ISO 8601 string, with leap second validation
@@ -140,6 +177,73 @@ impl Utc {
         Self::from_iso_with_provider(iso, &BuiltinLeapSeconds)
     }
 
+    /// Constructs a new [Utc] instance from the number of seconds since the Unix epoch,
+    /// 1970-01-01T00:00:00 UTC.
+    ///
+    /// Following the POSIX convention, every day is assumed to be exactly 86400 seconds long, i.e.
+    /// leap seconds are ignored and the resulting [Utc] never falls within a leap second.
+    ///
+    /// # Errors
+    ///
+    /// - [UtcError::InvalidUnixTimestamp] if `seconds` is NaN or ±infinity.
+    /// - [UtcError::UtcUndefined] if the result is before 1960-01-01.
+    pub fn from_unix_seconds(seconds: f64) -> Result<Self, UtcError> {
+        let delta = TimeDelta::try_from_decimal_seconds(seconds)
+            .map_err(|_| UtcError::InvalidUnixTimestamp(seconds.to_string()))?;
+        Self::from_unix_delta(delta)
+    }
+
+    /// Constructs a new [Utc] instance from the number of nanoseconds since the Unix epoch,
+    /// 1970-01-01T00:00:00 UTC, following the same POSIX convention as [Utc::from_unix_seconds].
+    ///
+    /// # Errors
+    ///
+    /// - [UtcError::InvalidUnixTimestamp] if the number of seconds overflows an `i64`.
+    /// - [UtcError::UtcUndefined] if the result is before 1960-01-01.
+    pub fn from_unix_nanos(nanos: i128) -> Result<Self, UtcError> {
+        let seconds = i64::try_from(nanos.div_euclid(NANOSECONDS_PER_SECOND))
+            .map_err(|_| UtcError::InvalidUnixTimestamp(format!("{nanos} ns")))?;
+        let subsecond = nanos.rem_euclid(NANOSECONDS_PER_SECOND) as f64 / 1e9;
+        Self::from_unix_delta(TimeDelta::new(seconds, Subsecond(subsecond)))
+    }
+
+    fn from_unix_delta(delta: TimeDelta) -> Result<Self, UtcError> {
+        let delta = delta - TimeDelta::from_seconds(SECONDS_BETWEEN_UNIX_EPOCH_AND_J2000);
+        let utc = Self::from_delta(delta);
+        if utc.date.year() < 1960 {
+            return Err(UtcError::UtcUndefined);
+        }
+        Ok(utc)
+    }
+
+    /// Returns the number of seconds since the Unix epoch, 1970-01-01T00:00:00 UTC.
+    ///
+    /// Following the POSIX convention, leap seconds are ignored, such that a leap second maps to
+    /// the same timestamp as the first second of the following day.
+    pub fn unix_seconds(&self) -> f64 {
+        let delta = self.unix_delta();
+        delta.seconds as f64 + delta.subsecond.0
+    }
+
+    /// Returns the number of nanoseconds since the Unix epoch, 1970-01-01T00:00:00 UTC, following
+    /// the same POSIX convention as [Utc::unix_seconds].
+    pub fn unix_nanos(&self) -> i128 {
+        let delta = self.unix_delta();
+        delta.seconds as i128 * NANOSECONDS_PER_SECOND + (delta.subsecond.0 * 1e9).round() as i128
+    }
+
+    fn unix_delta(&self) -> TimeDelta {
+        self.to_delta() + TimeDelta::from_seconds(SECONDS_BETWEEN_UNIX_EPOCH_AND_J2000)
+    }
+
+    /// Formats `self` according to the `strftime`-style `pattern`.
+    ///
+    /// See [Time::format] for the supported specifiers. `%Z` is formatted as `UTC` and leap
+    /// seconds are formatted as second 60.
+    pub fn format(&self, pattern: &str) -> String {
+        formatting::format(self, "UTC", pattern)
+    }
+
     /// Constructs a new [Utc] instance from a [TimeDelta] relative to J2000.
     ///
     /// Note that this constructor is not leap-second aware.
@@ -151,6 +255,32 @@ impl Utc {
     }
 }
 
+/// Parses an ISO 8601 UTC offset of the form `±hh:mm`, `±hhmm`, or `±hh`.
+fn parse_utc_offset(offset: &str) -> Option<TimeDelta> {
+    let (sign, digits) = match offset.split_at_checked(1)? {
+        ("+", digits) => (1, digits),
+        ("-", digits) => (-1, digits),
+        _ => return None,
+    };
+    let digits = match digits.split_once(':') {
+        Some((hours, minutes)) if minutes.len() == 2 => format!("{hours}{minutes}"),
+        Some(_) => return None,
+        None if digits.len() == 2 => format!("{digits}00"),
+        None => digits.to_owned(),
+    };
+    if digits.len() != 4 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let hours: i64 = digits[..2].parse().ok()?;
+    let minutes: i64 = digits[2..].parse().ok()?;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some(TimeDelta::from_seconds(
+        sign * (hours * SECONDS_PER_HOUR + minutes * SECONDS_PER_MINUTE),
+    ))
+}
+
 impl ToDelta for Utc {
     fn to_delta(&self) -> TimeDelta {
         let seconds = self.date.seconds_since_j2000().to_i64().unwrap_or_else(|| {
@@ -296,6 +426,58 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn test_utc_unix_epoch() {
+        let epoch = utc!(1970, 1, 1).unwrap();
+        assert_eq!(epoch.unix_seconds(), 0.0);
+        assert_eq!(epoch.unix_nanos(), 0);
+        assert_eq!(Utc::from_unix_seconds(0.0), Ok(epoch));
+        assert_eq!(Utc::from_unix_nanos(0), Ok(epoch));
+    }
+
+    #[rstest]
+    #[case::j2000(utc!(2000, 1, 1, 12).unwrap(), 946728000.0)]
+    #[case::before_epoch(utc!(1969, 12, 31, 23, 59, 59.5).unwrap(), -0.5)]
+    #[case::billennium(utc!(2001, 9, 9, 1, 46, 40.0).unwrap(), 1e9)]
+    #[case::after_leap_second(utc!(2017, 1, 1).unwrap(), 1483228800.0)]
+    #[case::fraction(utc!(2021, 6, 3, 5, 33, 0.25).unwrap(), 1622698380.25)]
+    fn test_utc_unix_seconds(#[case] utc: Utc, #[case] seconds: f64) {
+        assert_eq!(utc.unix_seconds(), seconds);
+        assert_eq!(Utc::from_unix_seconds(seconds), Ok(utc));
+    }
+
+    #[test]
+    fn test_utc_unix_leap_second() {
+        let leap = utc!(2016, 12, 31, 23, 59, 60.0).unwrap();
+        let after = utc!(2017, 1, 1).unwrap();
+        assert_eq!(leap.unix_seconds(), after.unix_seconds());
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1)]
+    #[case(-1)]
+    #[case(1_622_698_380_123_456_789)]
+    #[case(-123_456_789)]
+    fn test_utc_unix_nanos_round_trip(#[case] nanos: i128) {
+        assert_eq!(Utc::from_unix_nanos(nanos).unwrap().unix_nanos(), nanos);
+    }
+
+    #[rstest]
+    #[case::undefined(Utc::from_unix_seconds(-315619201.0), UtcError::UtcUndefined)]
+    #[case::nan(Utc::from_unix_seconds(f64::NAN), UtcError::InvalidUnixTimestamp("NaN".to_string()))]
+    #[case::overflow(Utc::from_unix_nanos(i128::MAX), UtcError::InvalidUnixTimestamp(format!("{} ns", i128::MAX)))]
+    fn test_utc_unix_invalid(#[case] actual: Result<Utc, UtcError>, #[case] expected: UtcError) {
+        assert_eq!(actual, Err(expected));
+    }
+
+    #[test]
+    fn test_utc_format() {
+        let utc = utc!(2016, 12, 31, 23, 59, 60.5).unwrap();
+        assert_eq!(utc.format("%Y-%m-%dT%H:%M:%S.%1f"), "2016-12-31T23:59:60.5");
+        assert_eq!(utc.format("%Y-%j %Z"), "2016-366 UTC");
+    }
+
     #[rstest]
     #[case(utc!(2000, 1, 1), Utc::builder().with_ymd(2000, 1, 1).build())]
     #[case(utc!(2000, 1, 1, 12), Utc::builder().with_ymd(2000, 1, 1).with_hms(12, 0, 0.0).build())]
@@ -343,6 +525,18 @@ mod tests {
     #[case("2000-01-01T0:00:00", Err(UtcError::TimeError(TimeOfDayError::InvalidIsoString("0:00:00".to_string()))))]
     #[case("2000-01-01-00:00:00", Err(UtcError::InvalidIsoString("2000-01-01-00:00:00".to_string())))]
     #[case("2000-01-01T00:00:00 TAI", Err(UtcError::InvalidIsoString("2000-01-01T00:00:00 TAI".to_string())))]
+    #[case("2021-06-03T07:33:00+02:00", Ok(utc!(2021, 6, 3, 5, 33, 0.0).unwrap()))]
+    #[case("2021-06-03T07:33:00.25-05:30", Ok(utc!(2021, 6, 3, 13, 3, 0.25).unwrap()))]
+    #[case("2021-06-03T07:33:00+0200", Ok(utc!(2021, 6, 3, 5, 33, 0.0).unwrap()))]
+    #[case("2021-06-03T07:33:00+02", Ok(utc!(2021, 6, 3, 5, 33, 0.0).unwrap()))]
+    #[case("2021-06-03T07:33:00+00:00", Ok(utc!(2021, 6, 3, 7, 33, 0.0).unwrap()))]
+    #[case("2021-06-03T20:00:00-05:30 UTC", Ok(utc!(2021, 6, 4, 1, 30, 0.0).unwrap()))]
+    #[case("2021-01-01T01:00:00+02:00", Ok(utc!(2020, 12, 31, 23, 0, 0.0).unwrap()))]
+    #[case("2017-01-01T01:59:60.5+02:00", Ok(utc!(2016, 12, 31, 23, 59, 60.5).unwrap()))]
+    #[case("2021-06-03T07:33:00+24:00", Err(UtcError::InvalidIsoString("2021-06-03T07:33:00+24:00".to_string())))]
+    #[case("2021-06-03T07:33:00+02:0", Err(UtcError::InvalidIsoString("2021-06-03T07:33:00+02:0".to_string())))]
+    #[case("2021-06-03T07:33:00+", Err(UtcError::InvalidIsoString("2021-06-03T07:33:00+".to_string())))]
+    #[case("2021-06-03T01:59:60+02:00", Err(UtcError::NonLeapSecondDate(Date::new(2021, 6, 2).unwrap())))]
     fn test_utc_from_str(#[case] iso: &str, #[case] expected: Result<Utc, UtcError>) {
         let actual: Result<Utc, UtcError> = iso.parse();
         assert_eq!(actual, expected)
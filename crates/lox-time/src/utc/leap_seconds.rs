@@ -12,7 +12,9 @@
     applications.
 
     `leap_seconds` additionally exposes the lower-level [LeapSecondsKernel] for working directly
-    with [NAIF Leap Seconds Kernel][LSK] data.
+    with [NAIF Leap Seconds Kernel][LSK] data, and [LeapSecondTable] for IERS and USNO leap second
+    files. A [LeapSecondTable] can be installed to supersede the built-in data without waiting for
+    a new release of `lox-time`.
 
     [LSK]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/time.html#The%20Leapseconds%20Kernel%20LSK
 */
@@ -27,6 +29,8 @@ use lox_io::spice::{Kernel, KernelError};
 use std::fs::read_to_string;
 use std::num::ParseIntError;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{PoisonError, RwLock};
 use thiserror::Error;
 
 use crate::utc::Utc;
@@ -58,26 +62,31 @@ const LEAP_SECONDS: [i64; 28] = [
 ///
 /// `BuiltinLeapSeconds` relies on a hard-coded table of leap second data. As new leap seconds are
 /// announced, `lox-time` will be updated to include the new data, reflected by a minor version
-/// change. If this is unsuitable for your use case, we recommend implementing [LeapSecondsProvider]
-/// manually.
+/// change. If this is unsuitable for your use case, install an up-to-date [LeapSecondTable] with
+/// [LeapSecondTable::install], which `BuiltinLeapSeconds` then uses instead of the hard-coded
+/// data, or implement [LeapSecondsProvider] manually.
 #[derive(Debug)]
 pub struct BuiltinLeapSeconds;
 
 impl LeapSecondsProvider for BuiltinLeapSeconds {
     fn delta_tai_utc(&self, tai: Time<Tai>) -> Option<TimeDelta> {
-        find_leap_seconds_tai(&LEAP_SECOND_EPOCHS_TAI, &LEAP_SECONDS, tai)
+        with_active_table(|table| table.delta_tai_utc(tai))
+            .unwrap_or_else(|| find_leap_seconds_tai(&LEAP_SECOND_EPOCHS_TAI, &LEAP_SECONDS, tai))
     }
 
     fn delta_utc_tai(&self, utc: Utc) -> Option<TimeDelta> {
-        find_leap_seconds_utc(&LEAP_SECOND_EPOCHS_UTC, &LEAP_SECONDS, utc)
+        with_active_table(|table| table.delta_utc_tai(utc))
+            .unwrap_or_else(|| find_leap_seconds_utc(&LEAP_SECOND_EPOCHS_UTC, &LEAP_SECONDS, utc))
     }
 
     fn is_leap_second_date(&self, date: Date) -> bool {
-        is_leap_second_date(&LEAP_SECOND_EPOCHS_UTC, date)
+        with_active_table(|table| table.is_leap_second_date(date))
+            .unwrap_or_else(|| is_leap_second_date(&LEAP_SECOND_EPOCHS_UTC, date))
     }
 
     fn is_leap_second(&self, tai: Time<Tai>) -> bool {
-        is_leap_second(&LEAP_SECOND_EPOCHS_TAI, tai)
+        with_active_table(|table| table.is_leap_second(tai))
+            .unwrap_or_else(|| is_leap_second(&LEAP_SECOND_EPOCHS_TAI, tai))
     }
 }
 
@@ -185,6 +194,194 @@ impl LeapSecondsProvider for LeapSecondsKernel {
     }
 }
 
+/// The [LeapSecondTable] installed with [LeapSecondTable::install], if any.
+static ACTIVE_TABLE: RwLock<Option<LeapSecondTable>> = RwLock::new(None);
+
+/// Whether a [LeapSecondTable] is installed, which allows [BuiltinLeapSeconds] to skip the lock
+/// of [ACTIVE_TABLE] if no table is installed.
+static TABLE_INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// Applies `f` to the installed [LeapSecondTable] or returns `None` if no table is installed.
+fn with_active_table<T>(f: impl FnOnce(&LeapSecondTable) -> T) -> Option<T> {
+    if !TABLE_INSTALLED.load(Ordering::Acquire) {
+        return None;
+    }
+    ACTIVE_TABLE
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+        .map(f)
+}
+
+/// Error type related to parsing leap second tables.
+#[derive(Debug, Error)]
+pub enum LeapSecondTableError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("invalid leap second record `{0}`")]
+    InvalidRecord(String),
+    #[error("leap second records are not in chronological order at `{0}`")]
+    Unordered(String),
+    #[error("no leap seconds found in table")]
+    NoLeapSeconds,
+    #[error(transparent)]
+    DateError(#[from] DateError),
+}
+
+/// Leap second data parsed from the IERS [`Leap_Second.dat`][IERS] or the USNO
+/// [`tai-utc.dat`][USNO] formats.
+///
+/// Entries of `tai-utc.dat` before 1972, when the offset between UTC and TAI was not an integer
+/// number of seconds, are skipped in favour of Lox's built-in model of pre-1972 UTC.
+///
+/// [IERS]: https://hpiers.obspm.fr/iers/bul/bulc/Leap_Second.dat
+/// [USNO]: https://maia.usno.navy.mil/ser7/tai-utc.dat
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeapSecondTable {
+    epochs_utc: Vec<i64>,
+    epochs_tai: Vec<i64>,
+    leap_seconds: Vec<i64>,
+}
+
+impl LeapSecondTable {
+    /// Parse a leap second table from a string.
+    ///
+    /// # Errors
+    ///
+    /// - [LeapSecondTableError::InvalidRecord] if a line is neither a comment nor a valid record.
+    /// - [LeapSecondTableError::Unordered] if the records are not in chronological order.
+    /// - [LeapSecondTableError::NoLeapSeconds] if the table contains no leap seconds since 1972.
+    /// - [LeapSecondTableError::DateError] if a record contains an invalid date.
+    pub fn from_string(table: impl AsRef<str>) -> Result<Self, LeapSecondTableError> {
+        let mut epochs_utc: Vec<i64> = vec![];
+        let mut epochs_tai: Vec<i64> = vec![];
+        let mut leap_seconds: Vec<i64> = vec![];
+        for line in table.as_ref().lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((date, ls)) = parse_record(line)? else {
+                continue;
+            };
+            let epoch = date.j2000_day_number() * SECONDS_PER_DAY - SECONDS_PER_HALF_DAY;
+            if epochs_utc.last().is_some_and(|&last| last >= epoch) {
+                return Err(LeapSecondTableError::Unordered(line.to_string()));
+            }
+            epochs_utc.push(epoch);
+            epochs_tai.push(epoch + ls - 1);
+            leap_seconds.push(ls);
+        }
+        if leap_seconds.is_empty() {
+            return Err(LeapSecondTableError::NoLeapSeconds);
+        }
+        Ok(Self {
+            epochs_utc,
+            epochs_tai,
+            leap_seconds,
+        })
+    }
+
+    /// Parse a leap second table located at `path`.
+    ///
+    /// # Errors
+    ///
+    /// - [LeapSecondTableError::Io] if the file at `path` can't be read.
+    /// - See [LeapSecondTable::from_string] for all other errors.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, LeapSecondTableError> {
+        let table = read_to_string(path.as_ref())?;
+        Self::from_string(table)
+    }
+
+    /// Installs the table as the data source of [BuiltinLeapSeconds] and thereby of all
+    /// conversions between UTC and TAI which don't take an explicit [LeapSecondsProvider].
+    ///
+    /// The table replaces any previously installed table for the whole process.
+    pub fn install(self) {
+        *ACTIVE_TABLE.write().unwrap_or_else(PoisonError::into_inner) = Some(self);
+        TABLE_INSTALLED.store(true, Ordering::Release);
+    }
+
+    /// Removes the installed table, if any, such that [BuiltinLeapSeconds] reverts to its
+    /// hard-coded data, and returns it.
+    pub fn uninstall() -> Option<Self> {
+        let mut table = ACTIVE_TABLE.write().unwrap_or_else(PoisonError::into_inner);
+        TABLE_INSTALLED.store(false, Ordering::Release);
+        table.take()
+    }
+}
+
+impl LeapSecondsProvider for LeapSecondTable {
+    fn delta_tai_utc(&self, tai: Time<Tai>) -> Option<TimeDelta> {
+        find_leap_seconds_tai(&self.epochs_tai, &self.leap_seconds, tai)
+    }
+
+    fn delta_utc_tai(&self, utc: Utc) -> Option<TimeDelta> {
+        find_leap_seconds_utc(&self.epochs_utc, &self.leap_seconds, utc)
+    }
+
+    fn is_leap_second_date(&self, date: Date) -> bool {
+        is_leap_second_date(&self.epochs_utc, date)
+    }
+
+    fn is_leap_second(&self, tai: Time<Tai>) -> bool {
+        is_leap_second(&self.epochs_tai, tai)
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// Parses a record of either `Leap_Second.dat`, e.g. `41317.0 1 1 1972 10`, or `tai-utc.dat`,
+/// e.g. `1972 JAN  1 =JD 2441317.5  TAI-UTC=  10.0 S + (MJD - 41317.) X 0.0 S`, and returns
+/// `None` for records before 1972.
+fn parse_record(line: &str) -> Result<Option<(Date, i64)>, LeapSecondTableError> {
+    let invalid = || LeapSecondTableError::InvalidRecord(line.to_string());
+    let parse = |token: Option<&str>| -> Result<i64, LeapSecondTableError> {
+        token.and_then(|t| t.parse().ok()).ok_or_else(invalid)
+    };
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    let (year, month, day, offset) = if let Some((date, rest)) = line.split_once("TAI-UTC=") {
+        let mut date = date.split_whitespace();
+        let year = parse(date.next())?;
+        let month = date
+            .next()
+            .and_then(|m| MONTHS.iter().position(|&name| name == m))
+            .ok_or_else(invalid)? as u8
+            + 1;
+        let day = parse(date.next())?;
+        let offset: f64 = rest
+            .split_whitespace()
+            .next()
+            .and_then(|t| t.parse().ok())
+            .ok_or_else(invalid)?;
+        (year, month, day, offset)
+    } else if tokens.len() == 5 {
+        let day = parse(tokens.get(1).copied())?;
+        let month = parse(tokens.get(2).copied())?;
+        let year = parse(tokens.get(3).copied())?;
+        let offset = parse(tokens.get(4).copied())? as f64;
+        (
+            year,
+            u8::try_from(month).map_err(|_| invalid())?,
+            day,
+            offset,
+        )
+    } else {
+        return Err(invalid());
+    };
+    if year < 1972 {
+        return Ok(None);
+    }
+    if offset.fract() != 0.0 {
+        return Err(invalid());
+    }
+    let day = u8::try_from(day).map_err(|_| invalid())?;
+    let date = Date::new(year, month, day)?;
+    Ok(Some((date, offset as i64)))
+}
+
 fn find_leap_seconds(epochs: &[i64], leap_seconds: &[i64], seconds: i64) -> Option<TimeDelta> {
     if seconds < epochs[0] {
         return None;
@@ -232,11 +429,13 @@ mod tests {
 
     use crate::Time;
     use crate::deltas::TimeDelta;
+    use crate::test_helpers::data_dir;
     use crate::time;
     use crate::time_scales::Tai;
     use crate::utc;
     use crate::utc::LeapSecondsProvider;
     use crate::utc::Utc;
+    use crate::utc::transformations::ToUtc;
 
     #[rstest]
     #[case::j2000(Time::default(), Utc::default(), 32)]
@@ -323,6 +522,96 @@ mod tests {
         assert_eq!(lsk.epochs_tai, &LEAP_SECOND_EPOCHS_TAI);
     }
 
+    #[test]
+    fn test_leap_second_table_iers() {
+        let table = LeapSecondTable::from_file(data_dir().join("Leap_Second.dat")).unwrap();
+        assert_eq!(table.leap_seconds, &LEAP_SECONDS);
+        assert_eq!(table.epochs_utc, &LEAP_SECOND_EPOCHS_UTC);
+        assert_eq!(table.epochs_tai, &LEAP_SECOND_EPOCHS_TAI);
+    }
+
+    #[test]
+    fn test_leap_second_table_usno() {
+        let table = LeapSecondTable::from_string(TAI_UTC).unwrap();
+        assert_eq!(table.leap_seconds, &LEAP_SECONDS[..3]);
+        assert_eq!(table.epochs_utc, &LEAP_SECOND_EPOCHS_UTC[..3]);
+        assert_eq!(table.epochs_tai, &LEAP_SECOND_EPOCHS_TAI[..3]);
+    }
+
+    #[rstest]
+    #[case::empty("# no data", "no leap seconds found in table")]
+    #[case::invalid_record("41317.0 1 1 1972", "invalid leap second record `41317.0 1 1 1972`")]
+    #[case::fractional(
+        "1972 JAN  1 =JD 2441317.5  TAI-UTC=  10.5 S",
+        "invalid leap second record `1972 JAN  1 =JD 2441317.5  TAI-UTC=  10.5 S`"
+    )]
+    #[case::unordered(
+        "41499.0 1 7 1972 11\n41317.0 1 1 1972 10",
+        "leap second records are not in chronological order at `41317.0 1 1 1972 10`"
+    )]
+    #[case::invalid_date("41317.0 31 2 1972 10", "invalid date `1972-2-31`")]
+    fn test_leap_second_table_errors(#[case] table: &str, #[case] expected: &str) {
+        let err = LeapSecondTable::from_string(table).unwrap_err();
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[test]
+    fn test_leap_second_table_provider() {
+        // A hypothetical leap second at the beginning of 2035
+        let mut table = read_to_string(data_dir().join("Leap_Second.dat")).unwrap();
+        table.push_str("    64328.0    1  1 2035       38\n");
+        let path =
+            std::env::temp_dir().join(format!("lox-leap-seconds-{}.dat", std::process::id()));
+        std::fs::write(&path, table).unwrap();
+        let table = LeapSecondTable::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let before = utc!(2034, 12, 31, 23, 59, 59.0).unwrap();
+        let after = utc!(2035, 1, 1).unwrap();
+        assert_eq!(
+            after.to_time() - before.to_time(),
+            TimeDelta::from_seconds(1)
+        );
+        assert!(
+            Utc::builder()
+                .with_ymd(2034, 12, 31)
+                .with_hms(23, 59, 60.0)
+                .build()
+                .is_err()
+        );
+
+        let leap = Utc::builder()
+            .with_ymd(2034, 12, 31)
+            .with_hms(23, 59, 60.0)
+            .build_with_provider(&table)
+            .unwrap();
+        assert_eq!(
+            leap.to_time_with_provider(&table) - before.to_time_with_provider(&table),
+            TimeDelta::from_seconds(1)
+        );
+        assert_eq!(
+            after.to_time_with_provider(&table) - before.to_time_with_provider(&table),
+            TimeDelta::from_seconds(2)
+        );
+        assert_eq!(
+            table.delta_utc_tai(after),
+            Some(TimeDelta::from_seconds(-38))
+        );
+        assert_eq!(
+            leap.to_time_with_provider(&table)
+                .to_utc_with_provider(&table)
+                .unwrap(),
+            leap
+        );
+    }
+
+    const TAI_UTC: &str =
+        " 1968 FEB  1 =JD 2439887.5  TAI-UTC=   4.2131700 S + (MJD - 39126.) X 0.002592 S
+ 1972 JAN  1 =JD 2441317.5  TAI-UTC=  10.0       S + (MJD - 41317.) X 0.0      S
+ 1972 JUL  1 =JD 2441499.5  TAI-UTC=  11.0       S + (MJD - 41317.) X 0.0      S
+ 1973 JAN  1 =JD 2441683.5  TAI-UTC=  12.0       S + (MJD - 41317.) X 0.0      S
+";
+
     const KERNEL: &str = "KPL/LSK
 
 \\begindata
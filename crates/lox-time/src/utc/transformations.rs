@@ -6,15 +6,23 @@
  * file, you can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::convert::Infallible;
+use std::ops::{Add, Sub};
 use std::sync::OnceLock;
 
+use thiserror::Error;
+
 use crate::DynTime;
+use crate::constants::i64::SECONDS_PER_DAY;
 use crate::deltas::TimeDelta;
 use crate::deltas::ToDelta;
+use crate::subsecond::Subsecond;
 use crate::time_of_day::CivilTime;
 use crate::time_of_day::TimeOfDay;
+use crate::time_scales::offsets::Ut1Error;
 use crate::time_scales::{DynTimeScale, Tai};
-use crate::time_scales::{FromScale, TimeScale, ToScale, TryFromScale};
+use crate::time_scales::{FromScale, TimeScale, ToScale, TryFromScale, TryToScale};
+use crate::ut1::{DeltaUt1Tai, DeltaUt1TaiCursor};
 use crate::{Time, utc};
 
 use super::LeapSecondsProvider;
@@ -23,6 +31,90 @@ use super::{Utc, UtcError};
 
 mod before1972;
 
+/// The duration of the window over which [UtcModel::Smeared] spreads a leap second.
+const SMEAR_WINDOW: i64 = SECONDS_PER_DAY;
+
+/// Models of the relation between UTC and TAI around leap seconds.
+///
+/// The conversions provided by [Utc::to_time] and [ToUtc] use [UtcModel::Strict]. Use
+/// [Utc::to_time_with] and [Time::to_utc_with] to select a model at the call site.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum UtcModel {
+    /// Leap seconds are inserted as 23:59:60 at the end of the day.
+    #[default]
+    Strict,
+    /// Leap seconds are spread linearly over the 24 hours from noon to noon around the end of
+    /// the day of the leap second, such that the clock never shows 23:59:60 and each second
+    /// within the window lasts 1 + 1/86400 SI seconds.
+    ///
+    /// This matches the leap smear used by public NTP services of major cloud providers.
+    Smeared,
+}
+
+impl UtcModel {
+    /// Converts `utc` to TAI based on the leap seconds from `provider`.
+    pub fn utc_to_tai(&self, utc: &Utc, provider: &impl LeapSecondsProvider) -> Time<Tai> {
+        let strict = || Time::from_delta(Tai, utc.to_delta() - utc.offset_tai(provider));
+        match self {
+            UtcModel::Strict => strict(),
+            UtcModel::Smeared => {
+                let seconds = utc.to_delta();
+                let Some((start, leap_seconds)) = smear_window(seconds.seconds, provider) else {
+                    return strict();
+                };
+                let elapsed = (seconds - TimeDelta::from_seconds(start)).to_decimal_seconds();
+                let offset = TimeDelta::from_seconds(leap_seconds - 1)
+                    + TimeDelta::from_decimal_seconds(elapsed / SMEAR_WINDOW as f64);
+                Time::from_delta(Tai, seconds + offset)
+            }
+        }
+    }
+
+    /// Converts `tai` to UTC based on the leap seconds from `provider`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [UtcError::UtcUndefined] if `tai` is before 1960-01-01.
+    pub fn tai_to_utc(
+        &self,
+        tai: Time<Tai>,
+        provider: &impl LeapSecondsProvider,
+    ) -> Result<Utc, UtcError> {
+        let strict = tai.to_utc_with_provider(provider)?;
+        match self {
+            UtcModel::Strict => Ok(strict),
+            UtcModel::Smeared => {
+                // The strict UTC seconds are within the same window as the smeared ones, since
+                // both coincide at its boundaries
+                let seconds = strict.to_delta().seconds;
+                let Some((start, leap_seconds)) = smear_window(seconds, provider) else {
+                    return Ok(strict);
+                };
+                let start_tai = TimeDelta::from_seconds(start + leap_seconds - 1);
+                let elapsed = (tai.to_delta() - start_tai).to_decimal_seconds();
+                let offset = TimeDelta::from_seconds(leap_seconds - 1)
+                    + TimeDelta::from_decimal_seconds(elapsed / (SMEAR_WINDOW + 1) as f64);
+                Ok(Utc::from_delta(tai.to_delta() - offset))
+            }
+        }
+    }
+}
+
+/// Returns the start of the smear window containing `seconds`, the UTC seconds since J2000,
+/// and TAI − UTC after its leap second, or `None` if the window does not contain a leap second.
+fn smear_window(seconds: i64, provider: &impl LeapSecondsProvider) -> Option<(i64, i64)> {
+    // J2000 is at noon, i.e. the windows start at multiples of a day since J2000
+    let start = seconds.div_euclid(SMEAR_WINDOW) * SMEAR_WINDOW;
+    let midnight = start + SMEAR_WINDOW / 2;
+    let leap_seconds = -provider
+        .delta_utc_tai(Utc::from_delta(TimeDelta::from_seconds(midnight)))?
+        .seconds;
+    let leap = Time::new(Tai, midnight + leap_seconds - 1, Subsecond::default());
+    provider
+        .is_leap_second(leap)
+        .then_some((start, leap_seconds))
+}
+
 impl Utc {
     pub fn offset_tai(&self, provider: &impl LeapSecondsProvider) -> TimeDelta {
         if self < utc_1972_01_01() {
@@ -34,14 +126,20 @@ impl Utc {
     }
 
     pub fn to_time_with_provider(&self, provider: &impl LeapSecondsProvider) -> Time<Tai> {
-        let offset = self.offset_tai(provider);
-        Time::from_delta(Tai, self.to_delta() - offset)
+        UtcModel::Strict.utc_to_tai(self, provider)
     }
 
     pub fn to_time(&self) -> Time<Tai> {
         self.to_time_with_provider(&BuiltinLeapSeconds)
     }
 
+    /// Converts `self` to TAI using the given [UtcModel].
+    ///
+    /// [Utc::to_time] is equivalent to using [UtcModel::Strict].
+    pub fn to_time_with(&self, model: UtcModel) -> Time<Tai> {
+        model.utc_to_tai(self, &BuiltinLeapSeconds)
+    }
+
     pub fn to_dyn_time_with_provider(&self, provider: &impl LeapSecondsProvider) -> DynTime {
         let offset = self.offset_tai(provider);
         Time::from_delta(DynTimeScale::Tai, self.to_delta() - offset)
@@ -64,6 +162,67 @@ impl Utc {
     {
         Time::from_scale(scale, self.to_time())
     }
+
+    /// Converts all `utcs` to `scale`, using `provider` for transformations to and from UT1.
+    ///
+    /// Lookups in the EOP table of `provider` resume from the previous lookup, which makes the
+    /// conversion of chronologically ordered inputs considerably faster than calling
+    /// [Utc::try_to_scale] for each element. Unordered inputs yield the same results.
+    pub fn to_scale_batch<'a, T>(
+        utcs: &[Utc],
+        scale: T,
+        provider: Option<&'a DeltaUt1Tai>,
+    ) -> Result<Vec<Time<T>>, <T as TryFromScale<Tai, DeltaUt1TaiCursor<'a>>>::Error>
+    where
+        T: TimeScale + TryFromScale<Tai, DeltaUt1TaiCursor<'a>> + Copy,
+    {
+        let cursor = provider.map(DeltaUt1Tai::cursor);
+        utcs.iter()
+            .map(|utc| Time::try_from_scale(scale, utc.to_time(), cursor.as_ref()))
+            .collect()
+    }
+}
+
+impl Add<TimeDelta> for Utc {
+    type Output = Self;
+
+    /// Adds `rhs` as elapsed SI seconds, i.e. any leap seconds within the interval are accounted
+    /// for by performing the addition in TAI based on [BuiltinLeapSeconds].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is before 1960-01-01, where UTC is undefined.
+    fn add(self, rhs: TimeDelta) -> Self::Output {
+        (self.to_time() + rhs)
+            .to_utc()
+            .expect("UTC should be defined for the result")
+    }
+}
+
+impl Sub<TimeDelta> for Utc {
+    type Output = Self;
+
+    /// Subtracts `rhs` as elapsed SI seconds, i.e. any leap seconds within the interval are
+    /// accounted for by performing the subtraction in TAI based on [BuiltinLeapSeconds].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is before 1960-01-01, where UTC is undefined.
+    fn sub(self, rhs: TimeDelta) -> Self::Output {
+        (self.to_time() - rhs)
+            .to_utc()
+            .expect("UTC should be defined for the result")
+    }
+}
+
+impl Sub<Utc> for Utc {
+    type Output = TimeDelta;
+
+    /// Returns the elapsed SI seconds between two UTC instants including any intervening leap
+    /// seconds based on [BuiltinLeapSeconds].
+    fn sub(self, rhs: Utc) -> Self::Output {
+        self.to_time() - rhs.to_time()
+    }
 }
 
 pub trait ToUtc {
@@ -99,6 +258,65 @@ impl<T: TimeScale + ToScale<Tai>> ToUtc for Time<T> {
     }
 }
 
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+pub enum TryToUtcError {
+    #[error(transparent)]
+    Ut1(#[from] Ut1Error),
+    #[error(transparent)]
+    Utc(#[from] UtcError),
+}
+
+impl From<Infallible> for TryToUtcError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+/// Fallible conversion to [Utc] for times in scales whose transformation to TAI may fail, i.e.
+/// [Ut1](crate::time_scales::Ut1) and [DynTimeScale], which require a UT1-TAI provider.
+///
+/// The time is converted to TAI via `provider` and then to UTC via the leap seconds.
+pub trait TryToUtc<P> {
+    fn try_to_utc_with_provider(
+        &self,
+        provider: Option<&P>,
+        leap_seconds: &impl LeapSecondsProvider,
+    ) -> Result<Utc, TryToUtcError>;
+
+    fn try_to_utc(&self, provider: Option<&P>) -> Result<Utc, TryToUtcError> {
+        self.try_to_utc_with_provider(provider, &BuiltinLeapSeconds)
+    }
+}
+
+impl<T, P> TryToUtc<P> for Time<T>
+where
+    T: TimeScale + TryToScale<Tai, P>,
+    TryToUtcError: From<T::Error>,
+{
+    fn try_to_utc_with_provider(
+        &self,
+        provider: Option<&P>,
+        leap_seconds: &impl LeapSecondsProvider,
+    ) -> Result<Utc, TryToUtcError> {
+        Ok(self
+            .try_to_scale(Tai, provider)?
+            .to_utc_with_provider(leap_seconds)?)
+    }
+}
+
+impl<T: TimeScale + ToScale<Tai>> Time<T> {
+    /// Converts `self` to UTC using the given [UtcModel].
+    ///
+    /// [ToUtc::to_utc] is equivalent to using [UtcModel::Strict].
+    ///
+    /// # Errors
+    ///
+    /// Returns [UtcError::UtcUndefined] if `self` is before 1960-01-01.
+    pub fn to_utc_with(&self, model: UtcModel) -> Result<Utc, UtcError> {
+        model.tai_to_utc(self.to_scale(Tai), &BuiltinLeapSeconds)
+    }
+}
+
 fn utc_1972_01_01() -> &'static Utc {
     static UTC_1972: OnceLock<Utc> = OnceLock::new();
     UTC_1972.get_or_init(|| utc!(1972, 1, 1).unwrap())
@@ -119,13 +337,58 @@ fn tai_at_utc_1972_01_01() -> &'static Time<Tai> {
 mod test {
     use crate::test_helpers::delta_ut1_tai;
     use crate::time;
-    use crate::time_scales::{Tcb, Tcg, Tdb, Tt, Ut1};
+    use crate::time_scales::{Gps, Tcb, Tcg, Tdb, Tt, Ut1};
     use rstest::rstest;
 
     use crate::subsecond::Subsecond;
 
     use super::*;
 
+    #[test]
+    fn test_utc_arithmetic_across_leap_second() {
+        let before = utc!(2016, 12, 31, 23, 59, 59.5).unwrap();
+        let leap = utc!(2016, 12, 31, 23, 59, 60.5).unwrap();
+        let after = utc!(2017, 1, 1, 0, 0, 0.5).unwrap();
+        assert_eq!(before + TimeDelta::from_seconds(1), leap);
+        assert_eq!(before + TimeDelta::from_seconds(2), after);
+        assert_eq!(after - TimeDelta::from_seconds(1), leap);
+        assert_eq!(after - TimeDelta::from_seconds(2), before);
+        assert_eq!(after - before, TimeDelta::from_seconds(2));
+        assert_eq!(before - after, TimeDelta::from_seconds(-2));
+    }
+
+    #[test]
+    fn test_utc_arithmetic_without_leap_second() {
+        let utc0 = utc!(2000, 1, 1, 12, 0, 0.25).unwrap();
+        let utc1 = utc!(2000, 1, 2, 12, 0, 0.75).unwrap();
+        let delta = TimeDelta::from_decimal_seconds(86400.5);
+        assert_eq!(utc0 + delta, utc1);
+        assert_eq!(utc1 - delta, utc0);
+        assert_eq!(utc1 - utc0, delta);
+    }
+
+    #[test]
+    fn test_utc_to_scale_batch() {
+        let provider = delta_ut1_tai();
+        let forward: Vec<Utc> = (0..200)
+            .map(|i| {
+                utc!(2016, 12, 31).unwrap() + TimeDelta::from_decimal_seconds(i as f64 * 3600.5)
+            })
+            .collect();
+        let backward: Vec<Utc> = forward.iter().rev().copied().collect();
+        for utcs in [forward, backward] {
+            let actual = Utc::to_scale_batch(&utcs, Ut1, Some(provider)).unwrap();
+            let expected: Vec<Time<Ut1>> = utcs
+                .iter()
+                .map(|utc| utc.try_to_scale(Ut1, Some(provider)).unwrap())
+                .collect();
+            assert_eq!(actual, expected);
+        }
+        let actual = Utc::to_scale_batch(&[utc!(2000, 1, 1).unwrap()], Tt, None).unwrap();
+        assert_eq!(actual, vec![utc!(2000, 1, 1).unwrap().to_scale(Tt)]);
+        assert!(Utc::to_scale_batch(&[utc!(2000, 1, 1).unwrap()], Ut1, None).is_err());
+    }
+
     #[test]
     fn test_utc_to_utc() {
         let utc0 = utc!(2000, 1, 1).unwrap();
@@ -173,6 +436,9 @@ mod test {
         let tdb = tai.to_scale(Tdb);
         let act = tdb.to_utc().unwrap();
         assert_eq!(act, exp);
+        let gps = tai.to_scale(Gps);
+        let act = gps.to_utc().unwrap();
+        assert_eq!(act, exp);
         let ut1 = tai.try_to_scale(Ut1, Some(delta_ut1_tai())).unwrap();
         let act = ut1
             .try_to_scale(Tai, Some(delta_ut1_tai()))
@@ -182,6 +448,112 @@ mod test {
         assert_eq!(act, exp);
     }
 
+    #[rstest]
+    #[case::before_window(utc!(2016, 12, 31, 11, 59, 59.0).unwrap(), 36.0)]
+    #[case::window_start(utc!(2016, 12, 31, 12, 0, 0.0).unwrap(), 36.0)]
+    #[case::quarter(utc!(2016, 12, 31, 18, 0, 0.0).unwrap(), 36.25)]
+    #[case::midnight(utc!(2017, 1, 1).unwrap(), 36.5)]
+    #[case::window_end(utc!(2017, 1, 1, 12, 0, 0.0).unwrap(), 37.0)]
+    #[case::without_leap_second(utc!(2018, 1, 1).unwrap(), 37.0)]
+    #[case::before_1972(utc!(1971, 12, 31).unwrap(), 9.88965)]
+    fn test_utc_model_smeared(#[case] utc: Utc, #[case] expected: f64) {
+        let tai = utc.to_time_with(UtcModel::Smeared);
+        let act = (tai.to_delta() - utc.to_delta()).to_decimal_seconds();
+        assert!((act - expected).abs() < 1e-4, "{act} != {expected}");
+        let act = tai.to_utc_with(UtcModel::Smeared).unwrap();
+        assert!((act.to_delta() - utc.to_delta()).to_decimal_seconds().abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_utc_model_smeared_leap_second() {
+        let leap = utc!(2016, 12, 31, 23, 59, 60.25).unwrap();
+        let tai = leap.to_time();
+        let smeared = tai.to_utc_with(UtcModel::Smeared).unwrap();
+        assert_eq!(smeared.date, leap.date);
+        assert_eq!(
+            (smeared.hour(), smeared.minute(), smeared.second()),
+            (23, 59, 59)
+        );
+        // Halfway through the window, half of the leap second has been absorbed
+        let elapsed =
+            (tai - utc!(2016, 12, 31, 12, 0, 0.0).unwrap().to_time()).to_decimal_seconds();
+        let expected = utc!(2016, 12, 31, 12, 0, 0.0).unwrap().to_delta()
+            + TimeDelta::from_decimal_seconds(elapsed * 86400.0 / 86401.0);
+        assert!((smeared.to_delta() - expected).to_decimal_seconds().abs() < 1e-9);
+
+        // The smeared clock is continuous and monotonic across the window
+        let mut previous = utc!(2016, 12, 31, 11, 0, 0.0)
+            .unwrap()
+            .to_time_with(UtcModel::Smeared);
+        for minutes in 1..(26 * 60) {
+            let utc = Utc::from_delta(
+                utc!(2016, 12, 31, 11, 0, 0.0).unwrap().to_delta()
+                    + TimeDelta::from_seconds(60 * minutes),
+            );
+            let tai = utc.to_time_with(UtcModel::Smeared);
+            let step = (tai - previous).to_decimal_seconds();
+            assert!((60.0..=60.0 + 60.0 / 86400.0 + 1e-9).contains(&step));
+            previous = tai;
+        }
+    }
+
+    #[test]
+    fn test_utc_model_strict() {
+        assert_eq!(UtcModel::default(), UtcModel::Strict);
+        for utc in [
+            utc!(2016, 12, 31, 18, 0, 0.0).unwrap(),
+            utc!(2016, 12, 31, 23, 59, 60.5).unwrap(),
+            utc!(2017, 1, 1).unwrap(),
+        ] {
+            let tai = utc.to_time_with(UtcModel::Strict);
+            assert_eq!(tai, utc.to_time());
+            assert_eq!(tai.to_utc_with(UtcModel::Strict), tai.to_utc());
+        }
+    }
+
+    #[test]
+    fn test_utc_round_trips() {
+        let provider = Some(delta_ut1_tai());
+        let utc = utc!(2024, 12, 30, 10, 27, 13.145).unwrap();
+        for scale in [
+            DynTimeScale::Tai,
+            DynTimeScale::Gps,
+            DynTimeScale::Tcb,
+            DynTimeScale::Tcg,
+            DynTimeScale::Tdb,
+            DynTimeScale::Tt,
+            DynTimeScale::Ut1,
+        ] {
+            let time = utc.try_to_scale(scale, provider).unwrap();
+            let act = time.try_to_utc(provider).unwrap();
+            assert!(
+                (act - utc).to_decimal_seconds().abs() < 1e-9,
+                "UTC -> {scale:?} -> UTC"
+            );
+        }
+        let ut1 = utc.try_to_scale(Ut1, provider).unwrap();
+        assert_eq!(ut1.try_to_utc(provider), utc.to_utc().map_err(Into::into));
+        assert_eq!(
+            ut1.try_to_utc(None::<&DeltaUt1Tai>),
+            Err(TryToUtcError::Ut1(Ut1Error::MissingProvider))
+        );
+    }
+
+    #[rstest]
+    #[case::gps_epoch(utc!(1980, 1, 6).unwrap(), time!(Gps, 1980, 1, 6).unwrap())]
+    #[case::before_2017_leap_second(
+        utc!(2016, 12, 31, 23, 59, 59.0).unwrap(),
+        time!(Gps, 2017, 1, 1, 0, 0, 16.0).unwrap()
+    )]
+    #[case::after_2017_leap_second(
+        utc!(2017, 1, 1).unwrap(),
+        time!(Gps, 2017, 1, 1, 0, 0, 18.0).unwrap()
+    )]
+    fn test_utc_gps(#[case] utc: Utc, #[case] gps: Time<Gps>) {
+        assert_eq!(utc.to_scale(Gps), gps);
+        assert_eq!(gps.to_utc().unwrap(), utc);
+    }
+
     /*
         The following fixtures are derived from a mixture of direct calculation and, in the case
         where inherent rounding errors prevent exact calculation, by cross-referencing with the
@@ -0,0 +1,61 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Installing a leap second table affects the whole process, which is why this test lives in its
+// own test binary instead of racing the unit tests of `lox-time`.
+
+use lox_time::deltas::TimeDelta;
+use lox_time::utc;
+use lox_time::utc::leap_seconds::{BuiltinLeapSeconds, LeapSecondTable};
+use lox_time::utc::transformations::ToUtc;
+use lox_time::utc::{LeapSecondsProvider, Utc};
+
+#[test]
+fn test_leap_second_table_install() {
+    // A hypothetical leap second at the beginning of 2035
+    let mut table = std::fs::read_to_string("../../data/Leap_Second.dat").unwrap();
+    table.push_str("    64328.0    1  1 2035       38\n");
+    let table = LeapSecondTable::from_string(table).unwrap();
+
+    let before = utc!(2034, 12, 31, 23, 59, 59.0).unwrap();
+    let after = utc!(2035, 1, 1).unwrap();
+    assert_eq!(
+        after.to_time() - before.to_time(),
+        TimeDelta::from_seconds(1)
+    );
+
+    table.install();
+    let leap = utc!(2034, 12, 31, 23, 59, 60.0).unwrap();
+    assert_eq!(
+        leap.to_time() - before.to_time(),
+        TimeDelta::from_seconds(1)
+    );
+    assert_eq!(
+        after.to_time() - before.to_time(),
+        TimeDelta::from_seconds(2)
+    );
+    assert_eq!(
+        BuiltinLeapSeconds.delta_utc_tai(after),
+        Some(TimeDelta::from_seconds(-38))
+    );
+    assert_eq!(leap.to_time().to_utc().unwrap(), leap);
+
+    assert!(LeapSecondTable::uninstall().is_some());
+    assert!(LeapSecondTable::uninstall().is_none());
+    assert_eq!(
+        BuiltinLeapSeconds.delta_utc_tai(after),
+        Some(TimeDelta::from_seconds(-37))
+    );
+    assert!(
+        Utc::builder()
+            .with_ymd(2034, 12, 31)
+            .with_hms(23, 59, 60.0)
+            .build()
+            .is_err()
+    );
+}
@@ -9,10 +9,13 @@
 use std::path::PathBuf;
 
 use crate::origins::generate_bodies;
+use crate::tdb_tt::generate_tdb_tt;
+use lox_ephem::spk::parser::Spk;
 use lox_io::spice::Kernel;
 
 mod common;
 mod origins;
+mod tdb_tt;
 
 fn crates_dir() -> PathBuf {
     PathBuf::from(format!("{}/../../crates", env!("CARGO_MANIFEST_DIR")))
@@ -25,4 +28,7 @@ pub fn main() {
         .expect("parsing should succeed");
     let bodies_target_dir = crates_dir().join("lox-bodies/src/");
     generate_bodies(&bodies_target_dir, &pck, &gm);
+    let spk = Spk::open(crates_dir().join("../data/de440s.bsp")).expect("parsing should succeed");
+    let offsets_target_dir = crates_dir().join("lox-time/src/time_scales/offsets/");
+    generate_tdb_tt(&offsets_target_dir, &spk, &gm);
 }
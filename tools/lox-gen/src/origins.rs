@@ -1021,6 +1021,7 @@ pub fn generate_bodies(path: &Path, pck: &Kernel, gm: &Kernel) {
         use crate::TrySpheroid;
         use crate::TryTriaxialEllipsoid;
         use crate::UndefinedOriginPropertyError;
+        use crate::rotational_elements_slice;
         use std::fmt::Display;
         use std::fmt::Formatter;
     };
@@ -1240,6 +1241,14 @@ pub fn generate_bodies(path: &Path, pck: &Kernel, gm: &Kernel) {
                     fn rotational_element_rates(&self, t: f64) -> Elements {
                         (#ra_dot, #dec_dot, #pm_dot)
                     }
+                    fn rotational_elements_slice(&self, t: &[f64]) -> Vec<Elements> {
+                        rotational_elements_slice(
+                            &#ra_const_ident,
+                            &#dec_const_ident,
+                            &#pm_const_ident,
+                            t,
+                        )
+                    }
                 }
             });
 
@@ -0,0 +1,507 @@
+/*
+ * Copyright (c) 2025. Helge Eichhorn and the LOX contributors
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, you can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Fits a harmonic series to TDB − TT at the geocentre computed from the JPL DE440 ephemeris.
+//!
+//! TCB − TCG is obtained by integrating the geocentric relation of IAU 2000 Resolution B1.5,
+//! including the terms of order c⁻⁴, and is converted to TDB − TT with the defining constants of
+//! IAU 2006 Resolution B3 and IAU 2000 Resolution B1.9. The series has the form of the
+//! Fairhead & Bretagnon (1990) series, i.e. sums of sine terms multiplied by powers of time.
+
+use std::f64::consts::TAU;
+use std::path::Path;
+
+use lox_ephem::Ephemeris;
+use lox_ephem::spk::parser::Spk;
+use lox_io::spice::Kernel;
+use proc_macro2::Literal;
+use quote::{format_ident, quote};
+
+use crate::common::write_file;
+
+const SECONDS_PER_DAY: f64 = 86400.0;
+const SECONDS_PER_JULIAN_MILLENNIUM: f64 = 365250.0 * SECONDS_PER_DAY;
+const SPEED_OF_LIGHT: f64 = 299792.458;
+
+const LB: f64 = 1.550519768e-8;
+const LG: f64 = 6.969290134e-10;
+const TDB_0: f64 = -6.55e-5;
+/// 1977-01-01T00:00:32.184 TT in seconds since J2000.
+const T_0: f64 = (2443144.5003725 - 2451545.0) * SECONDS_PER_DAY;
+
+/// The barycentres of the planets and Pluto, the Sun, and the Moon.
+const BODIES: [i32; 10] = [1, 2, 4, 5, 6, 7, 8, 9, 10, 301];
+
+/// Abscissae and weights of the eight-point Gauss-Legendre quadrature.
+const GAUSS_LEGENDRE: [(f64, f64); 8] = [
+    (-0.9602898564975363, 0.1012285362903763),
+    (-0.7966664774136267, 0.2223810344533745),
+    (-0.525532409916329, 0.3137066458778873),
+    (-0.1834346424956498, 0.362683783378362),
+    (0.1834346424956498, 0.362683783378362),
+    (0.525532409916329, 0.3137066458778873),
+    (0.7966664774136267, 0.2223810344533745),
+    (0.9602898564975363, 0.1012285362903763),
+];
+
+struct TimeEphemeris<'a> {
+    spk: &'a Spk,
+    gm: Vec<(i32, f64)>,
+}
+
+impl TimeEphemeris<'_> {
+    fn barycentric_state(&self, t: f64, body: i32) -> ([f64; 3], [f64; 3]) {
+        let (center, target) = match body {
+            301 | 399 => (3, body),
+            _ => (0, body),
+        };
+        let (r, v) = self.spk.state(t, center, target).unwrap();
+        let (mut r, mut v) = ([r.0, r.1, r.2], [v.0, v.1, v.2]);
+        if center == 3 {
+            let (r_emb, v_emb) = self.spk.state(t, 0, 3).unwrap();
+            for (i, (r_emb, v_emb)) in [r_emb.0, r_emb.1, r_emb.2]
+                .into_iter()
+                .zip([v_emb.0, v_emb.1, v_emb.2])
+                .enumerate()
+            {
+                r[i] += r_emb;
+                v[i] += v_emb;
+            }
+        }
+        (r, v)
+    }
+
+    /// Returns d(TCB − TCG)/dTCB at the geocentre for `t` in TDB seconds since J2000.
+    fn rate(&self, t: f64) -> f64 {
+        let (r_earth, v_earth) = self.barycentric_state(t, 399);
+        let v2 = dot(v_earth, v_earth);
+        let mut w = 0.0;
+        let mut w_i = [0.0; 3];
+        for &(body, gm) in &self.gm {
+            let (r, v) = self.barycentric_state(t, body);
+            let d = sub(r, r_earth);
+            let d = dot(d, d).sqrt();
+            w += gm / d;
+            for i in 0..3 {
+                w_i[i] += gm * v[i] / d;
+            }
+        }
+        let c2 = SPEED_OF_LIGHT * SPEED_OF_LIGHT;
+        (0.5 * v2 + w) / c2
+            + (v2 * v2 / 8.0 + 1.5 * v2 * w - 4.0 * dot(v_earth, w_i) - 0.5 * w * w) / (c2 * c2)
+    }
+
+    /// Returns the integral of d(TCB − TCG)/dTCB between the TDB epochs `t0` and `t1`.
+    fn integrate(&self, t0: f64, t1: f64) -> f64 {
+        let h = 0.5 * (t1 - t0);
+        let sum: f64 = GAUSS_LEGENDRE
+            .iter()
+            .map(|(x, w)| w * self.rate(t0 + h * (x + 1.0)))
+            .sum();
+        h * sum / (1.0 - LB)
+    }
+
+    /// Returns TDB − TT at 0h TDB of the days between `first_day` and `last_day` since J2000.
+    fn tdb_minus_tt(&self, first_day: i64, last_day: i64) -> Vec<(f64, f64)> {
+        // TCB − TCG vanishes at T₀, which coincides with TDB = T₀ + TDB₀
+        let day_0 = (T_0 / SECONDS_PER_DAY).ceil() as i64;
+        let g_0 = self.integrate(T_0 + TDB_0, day_0 as f64 * SECONDS_PER_DAY);
+        let mut tcb_minus_tcg = vec![0.0; (last_day - first_day + 1) as usize];
+        let index = |day: i64| (day - first_day) as usize;
+        let mut g = g_0;
+        tcb_minus_tcg[index(day_0)] = g;
+        for day in day_0..last_day {
+            g += self.integrate(
+                day as f64 * SECONDS_PER_DAY,
+                (day + 1) as f64 * SECONDS_PER_DAY,
+            );
+            tcb_minus_tcg[index(day + 1)] = g;
+        }
+        g = g_0;
+        for day in (first_day..day_0).rev() {
+            g -= self.integrate(
+                day as f64 * SECONDS_PER_DAY,
+                (day + 1) as f64 * SECONDS_PER_DAY,
+            );
+            tcb_minus_tcg[index(day)] = g;
+        }
+        (first_day..=last_day)
+            .zip(tcb_minus_tcg)
+            .map(|(day, g)| {
+                let tdb = day as f64 * SECONDS_PER_DAY;
+                let tcb = T_0 + (tdb - T_0 - TDB_0) / (1.0 - LB);
+                let tdb_minus_tt = (LG - LB) * (tcb - T_0) + (1.0 - LG) * g + TDB_0;
+                (tdb / SECONDS_PER_JULIAN_MILLENNIUM, tdb_minus_tt)
+            })
+            .collect()
+    }
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+/// A term `t^power * amplitude * sin(frequency * t + phase)` of the series with `t` in Julian
+/// millennia.
+struct Term {
+    power: i32,
+    amplitude: f64,
+    frequency: f64,
+    phase: f64,
+}
+
+struct Series {
+    polynomial: Vec<f64>,
+    terms: Vec<Term>,
+}
+
+impl Series {
+    fn evaluate(&self, t: f64) -> f64 {
+        let polynomial = self.polynomial.iter().rev().fold(0.0, |p, c| p * t + c);
+        let periodic: f64 = self
+            .terms
+            .iter()
+            .map(|term| {
+                t.powi(term.power) * term.amplitude * (term.frequency * t + term.phase).sin()
+            })
+            .sum();
+        polynomial + periodic
+    }
+}
+
+/// The basis functions of a linear least-squares fit, which are orthonormalised incrementally
+/// with the classical Gram-Schmidt process with reorthogonalisation.
+struct LeastSquares {
+    t: Vec<f64>,
+    y: Vec<f64>,
+    /// The basis functions as (power, frequency, is_cosine)
+    basis: Vec<(i32, f64, bool)>,
+    q: Vec<Vec<f64>>,
+    r: Vec<Vec<f64>>,
+    residual: Vec<f64>,
+}
+
+impl LeastSquares {
+    fn new(t: Vec<f64>, y: Vec<f64>) -> Self {
+        let residual = y.clone();
+        Self {
+            t,
+            y,
+            basis: Vec::new(),
+            q: Vec::new(),
+            r: Vec::new(),
+            residual,
+        }
+    }
+
+    fn column(&self, (power, frequency, is_cosine): (i32, f64, bool)) -> Vec<f64> {
+        self.t
+            .iter()
+            .map(|&t| {
+                let f = if frequency == 0.0 {
+                    1.0
+                } else if is_cosine {
+                    (frequency * t).cos()
+                } else {
+                    (frequency * t).sin()
+                };
+                t.powi(power) * f
+            })
+            .collect()
+    }
+
+    /// Adds a basis function unless it is linearly dependent on the current basis.
+    fn push(&mut self, function: (i32, f64, bool)) -> bool {
+        let mut v = self.column(function);
+        let norm = dot_n(&v, &v).sqrt();
+        let mut r = vec![0.0; self.q.len() + 1];
+        for _ in 0..2 {
+            for (j, q) in self.q.iter().enumerate() {
+                let c = dot_n(q, &v);
+                r[j] += c;
+                for (v, q) in v.iter_mut().zip(q) {
+                    *v -= c * q;
+                }
+            }
+        }
+        let remaining = dot_n(&v, &v).sqrt();
+        if remaining < 1e-8 * norm {
+            return false;
+        }
+        v.iter_mut().for_each(|v| *v /= remaining);
+        r[self.q.len()] = remaining;
+        let c = dot_n(&v, &self.residual);
+        for (residual, v) in self.residual.iter_mut().zip(&v) {
+            *residual -= c * v;
+        }
+        self.basis.push(function);
+        self.q.push(v);
+        self.r.push(r);
+        true
+    }
+
+    fn coefficients(&self) -> Vec<f64> {
+        let n = self.q.len();
+        let qty: Vec<f64> = self.q.iter().map(|q| dot_n(q, &self.y)).collect();
+        let mut x = vec![0.0; n];
+        for i in (0..n).rev() {
+            let s: f64 = (i + 1..n).map(|j| self.r[j][i] * x[j]).sum();
+            x[i] = (qty[i] - s) / self.r[i][i];
+        }
+        x
+    }
+}
+
+fn dot_n(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// In-place radix-2 FFT of `re` and `im`, whose length must be a power of two.
+fn fft(re: &mut [f64], im: &mut [f64]) {
+    let n = re.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        let angle = -TAU / len as f64;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (s, c) = (angle * k as f64).sin_cos();
+                let (a, b) = (start + k, start + k + len / 2);
+                let (tr, ti) = (re[b] * c - im[b] * s, re[b] * s + im[b] * c);
+                re[b] = re[a] - tr;
+                im[b] = im[a] - ti;
+                re[a] += tr;
+                im[a] += ti;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Returns the frequency in radians per Julian millennium of the largest peak in the spectrum of
+/// the uniformly sampled `residual`.
+fn dominant_frequency(t: &[f64], residual: &[f64]) -> f64 {
+    let n = residual.len();
+    let dt = t[1] - t[0];
+    let span = t[n - 1] - t[0];
+    let window: Vec<f64> = (0..n)
+        .map(|i| 0.5 - 0.5 * (TAU * i as f64 / (n - 1) as f64).cos())
+        .collect();
+    let size = (4 * n).next_power_of_two();
+    let mut re = vec![0.0; size];
+    let mut im = vec![0.0; size];
+    for i in 0..n {
+        re[i] = residual[i] * window[i];
+    }
+    fft(&mut re, &mut im);
+    let bin = TAU / (size as f64 * dt);
+    let power = |k: usize| re[k] * re[k] + im[k] * im[k];
+    // Frequencies below the resolution of the data are covered by the polynomial terms
+    let first = (TAU / span / bin).ceil() as usize;
+    let k = (first..size / 2)
+        .max_by(|&a, &b| power(a).total_cmp(&power(b)))
+        .unwrap();
+    // Refine the peak by golden-section search for the frequency whose sine and cosine fit the
+    // residual best in the least-squares sense
+    let reduction = |frequency: f64| {
+        let (mut ss, mut sc, mut cc, mut sr, mut cr) = (0.0, 0.0, 0.0, 0.0, 0.0);
+        for i in 0..n {
+            let (sin, cos) = (frequency * t[i]).sin_cos();
+            ss += sin * sin;
+            sc += sin * cos;
+            cc += cos * cos;
+            sr += sin * residual[i];
+            cr += cos * residual[i];
+        }
+        let det = ss * cc - sc * sc;
+        (cc * sr * sr - 2.0 * sc * sr * cr + ss * cr * cr) / det
+    };
+    let ratio = 0.5 * (5f64.sqrt() - 1.0);
+    let (mut a, mut b) = ((k as f64 - 1.0) * bin, (k as f64 + 1.0) * bin);
+    for _ in 0..40 {
+        let x1 = b - ratio * (b - a);
+        let x2 = a + ratio * (b - a);
+        if reduction(x1) > reduction(x2) {
+            b = x2;
+        } else {
+            a = x1;
+        }
+    }
+    0.5 * (a + b)
+}
+
+/// Fits the series to `data` with the given degree of the polynomial part and the given known
+/// frequencies with their highest power of time until the maximum residual drops below
+/// `tolerance` or `max_frequencies` frequencies have been found.
+fn fit(
+    data: &[(f64, f64)],
+    polynomial_degree: i32,
+    known_frequencies: &[(f64, i32)],
+    tolerance: f64,
+    max_frequencies: usize,
+) -> Series {
+    let (t, y): (Vec<f64>, Vec<f64>) = data.iter().copied().unzip();
+    let span = t[t.len() - 1] - t[0];
+    let resolution = TAU / span;
+    let mut lsq = LeastSquares::new(t, y);
+    for power in 0..=polynomial_degree {
+        lsq.push((power, 0.0, false));
+    }
+    // The highest power of time of the terms of each frequency
+    let mut frequencies: Vec<(f64, i32)> = Vec::new();
+    for &(frequency, max_power) in known_frequencies {
+        for power in 0..=max_power {
+            lsq.push((power, frequency, false));
+            lsq.push((power, frequency, true));
+        }
+        frequencies.push((frequency, max_power));
+    }
+    let max_residual = |lsq: &LeastSquares| lsq.residual.iter().fold(0.0f64, |m, r| m.max(r.abs()));
+    while frequencies.len() < max_frequencies && max_residual(&lsq) > tolerance {
+        let frequency = dominant_frequency(&lsq.t, &lsq.residual);
+        // A peak next to a known frequency indicates a variation of its amplitude
+        let known = frequencies
+            .iter_mut()
+            .find(|(known, _)| (known - frequency).abs() < resolution);
+        match known {
+            Some((known, power)) if *power < 2 => {
+                *power += 1;
+                let (known, power) = (*known, *power);
+                lsq.push((power, known, false));
+                lsq.push((power, known, true));
+            }
+            _ => {
+                if lsq.push((0, frequency, false)) && lsq.push((0, frequency, true)) {
+                    frequencies.push((frequency, 0));
+                }
+            }
+        }
+        if frequencies.len().is_multiple_of(25) {
+            eprintln!(
+                "{} frequencies, {} functions, max residual {:e}",
+                frequencies.len(),
+                lsq.basis.len(),
+                max_residual(&lsq)
+            );
+        }
+    }
+    let coefficients = lsq.coefficients();
+    let mut polynomial = Vec::new();
+    let mut terms = Vec::new();
+    let mut i = 0;
+    while i < lsq.basis.len() {
+        let (power, frequency, _) = lsq.basis[i];
+        if frequency == 0.0 {
+            polynomial.push(coefficients[i]);
+            i += 1;
+        } else {
+            // a sin(ωt) + b cos(ωt) = A sin(ωt + φ)
+            let (a, b) = (coefficients[i], coefficients[i + 1]);
+            terms.push(Term {
+                power,
+                amplitude: a.hypot(b),
+                frequency,
+                phase: b.atan2(a).rem_euclid(TAU),
+            });
+            i += 2;
+        }
+    }
+    Series { polynomial, terms }
+}
+
+/// The first and last day since J2000 of the fit, which are about 1850 and 2150 and lie within
+/// the span of `de440s.bsp`.
+const FIRST_DAY: i64 = -54787;
+const LAST_DAY: i64 = 54422;
+
+/// The degree of the polynomial part of the series.
+const POLYNOMIAL_DEGREE: i32 = 3;
+
+/// The mean motion of the Earth and its first harmonic in radians per Julian millennium, which
+/// carry the secular terms of Fairhead & Bretagnon (1990), with their highest power of time.
+const KNOWN_FREQUENCIES: [(f64, i32); 2] = [(6283.075849991, 2), (12566.151699983, 1)];
+
+/// The maximum residual at which the fit stops.
+const TOLERANCE: f64 = 1e-9;
+
+/// The maximum number of frequencies of the series.
+const MAX_FREQUENCIES: usize = 400;
+
+pub fn generate_tdb_tt(path: &Path, spk: &Spk, gm: &Kernel) {
+    let gm = BODIES
+        .iter()
+        .map(|&body| {
+            let key = format!("BODY{body}_GM");
+            let gm = gm
+                .get_double_array(&key)
+                .and_then(|gm| gm.first().copied())
+                .expect("GM should be defined");
+            (body, gm)
+        })
+        .collect();
+    let data = TimeEphemeris { spk, gm }.tdb_minus_tt(FIRST_DAY, LAST_DAY);
+    // Fit every other day and keep the remaining days for validation
+    let sample: Vec<(f64, f64)> = data.iter().copied().step_by(2).collect();
+    let series = fit(
+        &sample,
+        POLYNOMIAL_DEGREE,
+        &KNOWN_FREQUENCIES,
+        TOLERANCE,
+        MAX_FREQUENCIES,
+    );
+    let max_residual = data
+        .iter()
+        .map(|&(t, tdb_minus_tt)| (series.evaluate(t) - tdb_minus_tt).abs())
+        .fold(0.0, f64::max);
+    println!(
+        "TDB - TT: {} terms, maximum residual {max_residual:e} s",
+        series.polynomial.len() + series.terms.len()
+    );
+
+    let polynomial = &series.polynomial;
+    let n_polynomial = Literal::usize_unsuffixed(polynomial.len());
+    let tables = (0..=2).map(|power| {
+        let ident = format_ident!("DE440_T{power}");
+        let mut terms: Vec<&Term> = series.terms.iter().filter(|t| t.power == power).collect();
+        terms.sort_by(|a, b| b.amplitude.total_cmp(&a.amplitude));
+        let n = Literal::usize_unsuffixed(terms.len());
+        let terms = terms.iter().map(|term| {
+            let (amplitude, frequency, phase) = (term.amplitude, term.frequency, term.phase);
+            quote! { (#amplitude, #frequency, #phase) }
+        });
+        quote! {
+            pub const #ident: [(f64, f64, f64); #n] = [#(#terms),*];
+        }
+    });
+    let code = quote! {
+        pub const DE440_POLYNOMIAL: [f64; #n_polynomial] = [#(#polynomial),*];
+        #(#tables)*
+    };
+    write_file(path, "generated.rs", code);
+
+    // Reference values on days which were not part of the fit for the tests of the series
+    for (day, &(_, tdb_minus_tt)) in (FIRST_DAY..).zip(&data).skip(1).step_by(10958) {
+        println!("day {day}: {tdb_minus_tt:e} s");
+    }
+}